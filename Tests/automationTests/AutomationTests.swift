@@ -130,4 +130,150 @@ final class AutomationTests: XCTestCase {
             XCTAssertEqual(keyframe.center.y, clampedCenter.y, accuracy: 0.0001)
         }
     }
+
+    func testPlannerMatchesSharedParityFixtures() throws {
+        let fixtures = try JSONDecoder().decode(PlannerParityFixtures.self, from: plannerParityFixtureData())
+        XCTAssertFalse(fixtures.cases.isEmpty)
+
+        for parityCase in fixtures.cases {
+            let name = parityCase.name
+            let constraints = parityCase.constraints.zoomConstraints
+            let samples = AttentionModel().samples(from: parityCase.events, constraints: constraints)
+            XCTAssertEqual(samples.count, parityCase.expected.samples.count, name)
+            for (actual, expected) in zip(samples, parityCase.expected.samples) {
+                XCTAssertEqual(actual.time, expected.time, accuracy: 1e-9, name)
+                XCTAssertEqual(Double(actual.position.x), expected.position.xValue, accuracy: 1e-9, name)
+                XCTAssertEqual(Double(actual.position.y), expected.position.yValue, accuracy: 1e-9, name)
+                XCTAssertEqual(actual.intensity, expected.intensity, accuracy: 1e-9, name)
+                XCTAssertEqual(actual.isDwell, expected.isDwell, name)
+                XCTAssertEqual(actual.isClick, expected.isClick, name)
+            }
+
+            let plan = VirtualCameraPlanner().plan(
+                events: parityCase.events,
+                sourceSize: parityCase.sourceSize.cgSize,
+                duration: parityCase.duration,
+                outputSize: parityCase.outputSize?.cgSize,
+                constraints: constraints
+            )
+            XCTAssertEqual(plan.duration, parityCase.expected.plan.duration, accuracy: 1e-9, name)
+            XCTAssertEqual(
+                plan.outputAspectRatio.map(Double.init),
+                parityCase.expected.plan.outputAspectRatio,
+                name
+            )
+            XCTAssertEqual(plan.keyframes.count, parityCase.expected.plan.keyframes.count, name)
+            for (actual, expected) in zip(plan.keyframes, parityCase.expected.plan.keyframes) {
+                XCTAssertEqual(actual.time, expected.time, accuracy: 1e-9, name)
+                XCTAssertEqual(Double(actual.center.x), expected.center.xValue, accuracy: 1e-6, name)
+                XCTAssertEqual(Double(actual.center.y), expected.center.yValue, accuracy: 1e-6, name)
+                XCTAssertEqual(Double(actual.zoom), expected.zoom, accuracy: 1e-9, name)
+            }
+        }
+    }
+}
+
+/// Cases shared with the native-foundation Rust port of the planner.
+private func plannerParityFixtureData() throws -> Data {
+    var url = URL(fileURLWithPath: #filePath)
+    for _ in 0 ..< 3 {
+        url.deleteLastPathComponent()
+    }
+    url.appendPathComponent("docs/fixtures/auto-zoom/planner-parity.json")
+    return try Data(contentsOf: url)
+}
+
+private struct PlannerParityFixtures: Decodable {
+    let cases: [PlannerParityCase]
+}
+
+private struct PlannerParityCase: Decodable {
+    struct Size: Decodable {
+        let width: Double
+        let height: Double
+
+        var cgSize: CGSize {
+            CGSize(width: width, height: height)
+        }
+    }
+
+    struct Point: Decodable {
+        let xValue: Double
+        let yValue: Double
+
+        private enum CodingKeys: String, CodingKey {
+            case xValue = "x"
+            case yValue = "y"
+        }
+    }
+
+    /// Omitted values keep the `ZoomConstraints` defaults.
+    struct Constraints: Decodable {
+        let maxZoom: Double?
+        let minVisibleAreaFraction: Double?
+        let safeMarginFraction: Double?
+        let dwellDuration: Double?
+        let dwellSpeedThreshold: Double?
+        let velocitySmoothingAlpha: Double?
+        let maxPanSpeed: Double?
+        let maxPanAcceleration: Double?
+        let idleZoom: Double?
+        let baseZoom: Double?
+        let minimumKeyframeInterval: Double?
+        let motionIntensity: Double?
+        let dwellIntensity: Double?
+        let clickIntensity: Double?
+
+        var zoomConstraints: ZoomConstraints {
+            var constraints = ZoomConstraints()
+            if let maxZoom { constraints.maxZoom = CGFloat(maxZoom) }
+            if let minVisibleAreaFraction { constraints.minVisibleAreaFraction = CGFloat(minVisibleAreaFraction) }
+            if let safeMarginFraction { constraints.safeMarginFraction = CGFloat(safeMarginFraction) }
+            if let dwellDuration { constraints.dwellDuration = dwellDuration }
+            if let dwellSpeedThreshold { constraints.dwellSpeedThreshold = CGFloat(dwellSpeedThreshold) }
+            if let velocitySmoothingAlpha { constraints.velocitySmoothingAlpha = CGFloat(velocitySmoothingAlpha) }
+            if let maxPanSpeed { constraints.maxPanSpeed = CGFloat(maxPanSpeed) }
+            if let maxPanAcceleration { constraints.maxPanAcceleration = CGFloat(maxPanAcceleration) }
+            if let idleZoom { constraints.idleZoom = CGFloat(idleZoom) }
+            if let baseZoom { constraints.baseZoom = CGFloat(baseZoom) }
+            if let minimumKeyframeInterval { constraints.minimumKeyframeInterval = minimumKeyframeInterval }
+            if let motionIntensity { constraints.motionIntensity = motionIntensity }
+            if let dwellIntensity { constraints.dwellIntensity = dwellIntensity }
+            if let clickIntensity { constraints.clickIntensity = clickIntensity }
+            return constraints
+        }
+    }
+
+    struct Sample: Decodable {
+        let time: Double
+        let position: Point
+        let intensity: Double
+        let isDwell: Bool
+        let isClick: Bool
+    }
+
+    struct Keyframe: Decodable {
+        let time: Double
+        let center: Point
+        let zoom: Double
+    }
+
+    struct Plan: Decodable {
+        let duration: Double
+        let outputAspectRatio: Double?
+        let keyframes: [Keyframe]
+    }
+
+    struct Expected: Decodable {
+        let samples: [Sample]
+        let plan: Plan
+    }
+
+    let name: String
+    let constraints: Constraints
+    let sourceSize: Size
+    let outputSize: Size?
+    let duration: Double
+    let events: [InputEvent]
+    let expected: Expected
 }
@@ -0,0 +1,125 @@
+import type { TimelineClipItem } from "@guerillaglass/engine-contract/shared/valueObjects";
+
+/**
+ * Desktop mirror of the engine's clip speed math (`ClipSpeed` in the native timeline). Ramps
+ * change the rate linearly over source time between real time and `playbackRate`.
+ */
+export type TimelineClipSpeed = Pick<
+  TimelineClipItem,
+  "sourceStartSeconds" | "sourceEndSeconds" | "playbackRate" | "rampInSeconds" | "rampOutSeconds"
+>;
+
+export type TimelineClipAudioMode = "normal" | "pitchPreserved" | "muted";
+
+/** Fastest rate audio stays audible at; faster clips are muted like in export. */
+export const maxPitchPreservedPlaybackRate = 4;
+
+function clipSourceSeconds(clip: TimelineClipSpeed): number {
+  return Math.max(0, clip.sourceEndSeconds - clip.sourceStartSeconds);
+}
+
+function clipRate(clip: TimelineClipSpeed): number {
+  return clip.playbackRate ?? 1;
+}
+
+function rampInSeconds(clip: TimelineClipSpeed): number {
+  return clip.rampInSeconds ?? 0;
+}
+
+function rampOutSeconds(clip: TimelineClipSpeed): number {
+  return clip.rampOutSeconds ?? 0;
+}
+
+function bodySeconds(clip: TimelineClipSpeed): number {
+  return Math.max(0, clipSourceSeconds(clip) - rampInSeconds(clip) - rampOutSeconds(clip));
+}
+
+function rampProgramSeconds(length: number, rate: number): number {
+  return rate === 1 ? length : (length * Math.log(rate)) / (rate - 1);
+}
+
+export function clipProgramSeconds(clip: TimelineClipSpeed): number {
+  const rate = clipRate(clip);
+  return (
+    rampProgramSeconds(rampInSeconds(clip), rate) +
+    bodySeconds(clip) / rate +
+    rampProgramSeconds(rampOutSeconds(clip), rate)
+  );
+}
+
+export function clipProgramOffset(clip: TimelineClipSpeed, sourceOffset: number): number {
+  const rate = clipRate(clip);
+  const rampIn = rampInSeconds(clip);
+  const rampOut = rampOutSeconds(clip);
+  const body = bodySeconds(clip);
+  const offset = Math.min(Math.max(sourceOffset, 0), clipSourceSeconds(clip));
+  if (rate === 1) {
+    return offset;
+  }
+  if (offset < rampIn) {
+    return (rampIn / (rate - 1)) * Math.log(1 + ((rate - 1) * offset) / rampIn);
+  }
+  const rampInProgram = rampProgramSeconds(rampIn, rate);
+  if (offset <= rampIn + body || rampOut <= 0) {
+    return rampInProgram + (offset - rampIn) / rate;
+  }
+  const intoRamp = offset - rampIn - body;
+  return (
+    rampInProgram +
+    body / rate +
+    (rampOut / (1 - rate)) * Math.log((rate + ((1 - rate) * intoRamp) / rampOut) / rate)
+  );
+}
+
+export function clipSourceOffset(clip: TimelineClipSpeed, programOffset: number): number {
+  const rate = clipRate(clip);
+  const rampIn = rampInSeconds(clip);
+  const rampOut = rampOutSeconds(clip);
+  const body = bodySeconds(clip);
+  const sourceSeconds = clipSourceSeconds(clip);
+  const offset = Math.min(Math.max(programOffset, 0), clipProgramSeconds(clip));
+  if (rate === 1) {
+    return offset;
+  }
+  const rampInProgram = rampProgramSeconds(rampIn, rate);
+  if (offset < rampInProgram) {
+    return (rampIn / (rate - 1)) * (Math.exp((offset * (rate - 1)) / rampIn) - 1);
+  }
+  const intoBody = offset - rampInProgram;
+  if (intoBody <= body / rate || rampOut <= 0) {
+    return Math.min(rampIn + intoBody * rate, sourceSeconds);
+  }
+  const intoRamp = intoBody - body / rate;
+  const ramped =
+    ((rampOut * rate) / (1 - rate)) * (Math.exp((intoRamp * (1 - rate)) / rampOut) - 1);
+  return Math.min(rampIn + body + ramped, sourceSeconds);
+}
+
+/** Rate the media plays at `sourceOffset` into the clip, following the ramps. */
+export function clipRateAtSourceOffset(clip: TimelineClipSpeed, sourceOffset: number): number {
+  const rate = clipRate(clip);
+  const rampIn = rampInSeconds(clip);
+  const rampOut = rampOutSeconds(clip);
+  const offset = Math.min(Math.max(sourceOffset, 0), clipSourceSeconds(clip));
+  if (offset < rampIn) {
+    return 1 + ((rate - 1) * offset) / rampIn;
+  }
+  const rampOutStart = rampIn + bodySeconds(clip);
+  if (rampOut > 0 && offset > rampOutStart) {
+    return rate + ((1 - rate) * (offset - rampOutStart)) / rampOut;
+  }
+  return rate;
+}
+
+export function clipAudioMode(
+  clip: Pick<TimelineClipItem, "playbackRate" | "speedAudio">,
+): TimelineClipAudioMode {
+  const rate = clip.playbackRate ?? 1;
+  if (rate === 1) {
+    return "normal";
+  }
+  if (clip.speedAudio === "mute" || rate > maxPitchPreservedPlaybackRate) {
+    return "muted";
+  }
+  return "pitchPreserved";
+}
@@ -5,6 +5,7 @@ import type {
   TimelineItem,
 } from "@guerillaglass/engine-contract/shared/valueObjects";
 import { timelineSegmentIdSchema } from "@guerillaglass/engine-contract/schema-primitives";
+import { clipProgramSeconds, clipSourceOffset } from "./timelineClipSpeed";
 import { compileTimelineItems } from "./timelineDomainModel";
 
 type TimelineIdFactory = () => string;
@@ -40,7 +41,7 @@ export function timelineItemDurationSeconds(item: TimelineItem): number {
   if (item.kind === "gap") {
     return Math.max(0, item.durationSeconds);
   }
-  return Math.max(0, item.sourceEndSeconds - item.sourceStartSeconds);
+  return clipProgramSeconds(item);
 }
 
 export function normalizeTimelineDocument(timeline: TimelineDocument): TimelineDocument {
@@ -122,6 +123,15 @@ function buildGapItem(durationSeconds: number, idFactory: TimelineIdFactory): Ti
   };
 }
 
+function speedFields(
+  clip: TimelineClipItem,
+): Pick<TimelineClipItem, "playbackRate" | "speedAudio"> {
+  return {
+    ...(clip.playbackRate !== undefined ? { playbackRate: clip.playbackRate } : {}),
+    ...(clip.speedAudio !== undefined ? { speedAudio: clip.speedAudio } : {}),
+  };
+}
+
 function normalizeIndex(index: number, minimum: number, maximum: number): number {
   return Math.min(Math.max(index, minimum), maximum);
 }
@@ -144,7 +154,9 @@ export function splitTimelineClipAtProgramTime(
     return { timeline, changed: false };
   }
 
-  const splitSeconds = target.sourceStartSeconds + (programSeconds - target.programStartSeconds);
+  const splitSeconds =
+    target.sourceStartSeconds +
+    clipSourceOffset(target, programSeconds - target.programStartSeconds);
   if (
     splitSeconds <= target.sourceStartSeconds + Number.EPSILON ||
     splitSeconds >= target.sourceEndSeconds - Number.EPSILON
@@ -152,12 +164,18 @@ export function splitTimelineClipAtProgramTime(
     return { timeline, changed: false };
   }
 
+  // Both halves keep the clip's speed; the left keeps the ramp in and the right the ramp out.
+  const speed = speedFields(target);
   const leftClip: TimelineClipItem = {
     kind: "clip",
     id: target.id,
     sourceAssetId: target.sourceAssetId,
     sourceStartSeconds: target.sourceStartSeconds,
     sourceEndSeconds: splitSeconds,
+    ...speed,
+    ...(target.rampInSeconds !== undefined
+      ? { rampInSeconds: Math.min(target.rampInSeconds, splitSeconds - target.sourceStartSeconds) }
+      : {}),
   };
   const rightClip: TimelineClipItem = {
     kind: "clip",
@@ -165,6 +183,10 @@ export function splitTimelineClipAtProgramTime(
     sourceAssetId: target.sourceAssetId,
     sourceStartSeconds: splitSeconds,
     sourceEndSeconds: target.sourceEndSeconds,
+    ...speed,
+    ...(target.rampOutSeconds !== undefined
+      ? { rampOutSeconds: Math.min(target.rampOutSeconds, target.sourceEndSeconds - splitSeconds) }
+      : {}),
   };
 
   return {
@@ -7,6 +7,7 @@ import type {
   TimelineItem,
   TimelineSegment,
 } from "@guerillaglass/engine-contract/shared/valueObjects";
+import { clipProgramOffset, clipProgramSeconds, clipSourceOffset } from "./timelineClipSpeed";
 
 export type TimelineClipSemantic = "screen" | "mix" | "gap";
 
@@ -248,7 +249,7 @@ function compiledDurationSeconds(item: TimelineItem): number {
   if (item.kind === "gap") {
     return Math.max(0, item.durationSeconds);
   }
-  return Math.max(0, item.sourceEndSeconds - item.sourceStartSeconds);
+  return clipProgramSeconds(item);
 }
 
 export function compileTimelineItems(
@@ -352,7 +353,8 @@ export function mapProgramSecondsToSourceTime(
   return {
     segment,
     sourceSeconds:
-      segment.sourceStartSeconds + (boundedProgramSeconds - segment.programStartSeconds),
+      segment.sourceStartSeconds +
+      clipSourceOffset(segment, boundedProgramSeconds - segment.programStartSeconds),
   };
 }
 
@@ -377,7 +379,9 @@ export function remapInputEventsToProgramTime(
       return [
         {
           ...event,
-          timestamp: segment.programStartSeconds + (event.timestamp - segment.sourceStartSeconds),
+          timestamp:
+            segment.programStartSeconds +
+            clipProgramOffset(segment, event.timestamp - segment.sourceStartSeconds),
         },
       ];
     }),
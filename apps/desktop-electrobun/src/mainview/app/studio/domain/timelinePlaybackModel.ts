@@ -3,6 +3,12 @@ import {
   timelineDurationSeconds,
   type CompiledTimelineItem,
 } from "./timelineDomainModel";
+import {
+  clipAudioMode,
+  clipProgramOffset,
+  clipRateAtSourceOffset,
+  clipSourceOffset,
+} from "./timelineClipSpeed";
 
 type CompiledTimelineClip = Extract<CompiledTimelineItem, { kind: "clip" }>;
 type CompiledTimelineGap = Extract<CompiledTimelineItem, { kind: "gap" }>;
@@ -60,7 +66,45 @@ export function resolveTimelinePlaybackAtProgramTime(
   return {
     kind: "clip",
     item,
-    sourceSeconds: item.sourceStartSeconds + (boundedProgramSeconds - item.programStartSeconds),
+    sourceSeconds:
+      item.sourceStartSeconds +
+      clipSourceOffset(item, boundedProgramSeconds - item.programStartSeconds),
+  };
+}
+
+/** Program time at which `clip` shows the media at `sourceSeconds`. */
+export function programSecondsAtClipSource(
+  clip: CompiledTimelineClip,
+  sourceSeconds: number,
+): number {
+  return (
+    clip.programStartSeconds + clipProgramOffset(clip, sourceSeconds - clip.sourceStartSeconds)
+  );
+}
+
+export type ClipMediaPlayback = {
+  playbackRate: number;
+  muted: boolean;
+};
+
+// HTMLMediaElement playback rates outside this range throw in Chromium and WebKit.
+const minMediaPlaybackRate = 0.0625;
+const maxMediaPlaybackRate = 16;
+
+/**
+ * How the media element plays `clip` at `sourceSeconds`: the transport rate times the clip's
+ * ramped rate, muted when the clip's speed silences its audio.
+ */
+export function clipMediaPlayback(
+  clip: CompiledTimelineClip,
+  sourceSeconds: number,
+  transportRate: number,
+): ClipMediaPlayback {
+  const rate =
+    transportRate * clipRateAtSourceOffset(clip, sourceSeconds - clip.sourceStartSeconds);
+  return {
+    playbackRate: Math.min(Math.max(rate, minMediaPlaybackRate), maxMediaPlaybackRate),
+    muted: clipAudioMode(clip) === "muted",
   };
 }
 
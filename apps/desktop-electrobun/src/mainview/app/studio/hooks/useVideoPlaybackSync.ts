@@ -3,7 +3,10 @@ import { recordStudioPlaybackActive, recordStudioPlaybackTick } from "@lib/studi
 import type { CompiledTimelineItem } from "../domain/timelineDomainModel";
 import { timelineDurationSeconds } from "../domain/timelineDomainModel";
 import {
+  type ClipMediaPlayback,
+  clipMediaPlayback,
   findNextPlayableClipAfterProgramTime,
+  programSecondsAtClipSource,
   resolveTimelinePlaybackAtProgramTime,
 } from "../domain/timelinePlaybackModel";
 import type { PlaybackTransportStore } from "./timeline/usePlaybackTransport";
@@ -66,12 +69,12 @@ export function useVideoPlaybackSync({
         return;
       }
       lastPlaybackRate = playbackRateRef.current;
-      media.playbackRate = playbackRateRef.current;
+      applyMediaPlayback(media, timelineItems, playheadSecondsRef.current, lastPlaybackRate);
     };
 
-    media.playbackRate = lastPlaybackRate;
+    applyMediaPlayback(media, timelineItems, playheadSecondsRef.current, lastPlaybackRate);
     return playbackStore.subscribe(syncPlaybackRate);
-  }, [mediaRef, playbackStore, recordingMediaSource]);
+  }, [mediaRef, playbackStore, recordingMediaSource, timelineItems]);
 
   useEffect(() => {
     const media = mediaRef.current;
@@ -239,8 +242,11 @@ export function useVideoPlaybackSync({
         return true;
       }
 
-      const nextProgramSeconds =
-        activeClip.programStartSeconds + (media.currentTime - activeClip.sourceStartSeconds);
+      setClipMediaPlayback(
+        media,
+        clipMediaPlayback(activeClip, media.currentTime, playbackRateRef.current),
+      );
+      const nextProgramSeconds = programSecondsAtClipSource(activeClip, media.currentTime);
       setDisplayPlayheadSecondsFromMedia(
         Math.max(
           activeClip.programStartSeconds,
@@ -324,9 +330,7 @@ export function useVideoPlaybackSync({
         return;
       }
 
-      const nextProgramSeconds =
-        resolution.item.programStartSeconds +
-        (media.currentTime - resolution.item.sourceStartSeconds);
+      const nextProgramSeconds = programSecondsAtClipSource(resolution.item, media.currentTime);
       setPlayheadSecondsFromMedia(
         Math.max(
           resolution.item.programStartSeconds,
@@ -410,6 +414,30 @@ export function useVideoPlaybackSync({
   ]);
 }
 
+function applyMediaPlayback(
+  media: HTMLVideoElement,
+  timelineItems: CompiledTimelineItem[],
+  playheadSeconds: number,
+  transportRate: number,
+): void {
+  const resolution = resolveTimelinePlaybackAtProgramTime(timelineItems, playheadSeconds);
+  setClipMediaPlayback(
+    media,
+    resolution.kind === "clip"
+      ? clipMediaPlayback(resolution.item, resolution.sourceSeconds, transportRate)
+      : { playbackRate: transportRate, muted: false },
+  );
+}
+
+function setClipMediaPlayback(media: HTMLVideoElement, playback: ClipMediaPlayback): void {
+  if (media.playbackRate !== playback.playbackRate) {
+    media.playbackRate = playback.playbackRate;
+  }
+  if (media.muted !== playback.muted) {
+    media.muted = playback.muted;
+  }
+}
+
 function setSourceVisibility(
   media: HTMLVideoElement,
   sourceCardRef: RefObject<HTMLDivElement | null> | undefined,
@@ -115,6 +115,45 @@ describe("timeline commands", () => {
     ]);
   });
 
+  test("splits a sped-up clip at the source time under the playhead", () => {
+    const timeline = makeTimeline([
+      {
+        kind: "clip",
+        id: "clip-a",
+        sourceAssetId: "recording",
+        sourceStartSeconds: 2,
+        sourceEndSeconds: 10,
+        playbackRate: 2,
+        rampInSeconds: 1,
+        rampOutSeconds: 1,
+        speedAudio: "mute",
+      },
+    ]);
+
+    const result = splitTimelineClipAtProgramTime(timeline, 3, makeIdFactory(["clip-b"]));
+    const rightClip = result.timeline.items[1]?.kind === "clip" ? result.timeline.items[1] : null;
+
+    expect(result.changed).toBe(true);
+    expect(rightClip?.sourceStartSeconds).toBeCloseTo(2 + 1 + (3 - Math.log(2)) * 2, 9);
+    expect(result.timeline.items).toMatchObject([
+      {
+        id: "clip-a",
+        playbackRate: 2,
+        rampInSeconds: 1,
+        speedAudio: "mute",
+      },
+      {
+        id: "clip-b",
+        sourceEndSeconds: 10,
+        playbackRate: 2,
+        rampOutSeconds: 1,
+        speedAudio: "mute",
+      },
+    ]);
+    expect(result.timeline.items[0]).not.toHaveProperty("rampOutSeconds");
+    expect(result.timeline.items[1]).not.toHaveProperty("rampInSeconds");
+  });
+
   test("lifts selected items into a gap with matching duration", () => {
     const timeline = makeTimeline([
       {
@@ -4,9 +4,17 @@ import type {
   TimelineClipItem,
   TimelineGapItem,
 } from "@guerillaglass/engine-contract/shared/valueObjects";
+import {
+  clipAudioMode,
+  clipProgramOffset,
+  clipProgramSeconds,
+  clipSourceOffset,
+} from "@studio/domain/timelineClipSpeed";
 import { compileTimelineItems } from "@studio/domain/timelineDomainModel";
 import {
+  clipMediaPlayback,
   findNextPlayableClipAfterProgramTime,
+  programSecondsAtClipSource,
   resolveTimelinePlaybackAtProgramTime,
 } from "@studio/domain/timelinePlaybackModel";
 
@@ -129,4 +137,94 @@ describe("timeline playback model", () => {
       durationSeconds: 2,
     });
   });
+
+  test("plays sped-up clips in less program time", () => {
+    const items = compile({
+      version: 2,
+      items: [
+        {
+          kind: "clip",
+          id: "clip-a",
+          sourceAssetId: "recording",
+          sourceStartSeconds: 2,
+          sourceEndSeconds: 12,
+          playbackRate: 4,
+        },
+        { kind: "gap", id: "gap-a", durationSeconds: 1 },
+      ],
+    });
+
+    expect(items[0]?.programEndSeconds).toBe(2.5);
+    expect(items[1]?.programStartSeconds).toBe(2.5);
+    const resolution = resolveTimelinePlaybackAtProgramTime(items, 1);
+    expect(resolution).toMatchObject({ kind: "clip", sourceSeconds: 6 });
+    if (resolution.kind !== "clip") {
+      throw new Error("expected a clip");
+    }
+    expect(programSecondsAtClipSource(resolution.item, 6)).toBe(1);
+    expect(clipMediaPlayback(resolution.item, 6, 1.5)).toEqual({
+      playbackRate: 6,
+      muted: false,
+    });
+  });
+
+  test("ramps ease between real time and the clip rate like the engine", () => {
+    const ramped = {
+      sourceStartSeconds: 0,
+      sourceEndSeconds: 10,
+      playbackRate: 4,
+      rampInSeconds: 1,
+      rampOutSeconds: 1,
+    };
+    const ramp = Math.log(4) / 3;
+    expect(clipProgramSeconds(ramped)).toBeCloseTo(2 + 2 * ramp, 12);
+    expect(clipProgramOffset(ramped, 1)).toBeCloseTo(ramp, 12);
+    expect(clipProgramOffset(ramped, 9)).toBeCloseTo(ramp + 2, 12);
+    expect(clipProgramOffset(ramped, 0.001)).toBeCloseTo(0.001, 5);
+
+    for (const clip of [
+      ramped,
+      { ...ramped, playbackRate: 0.5, rampInSeconds: 2, rampOutSeconds: 3 },
+      { ...ramped, playbackRate: 2, rampInSeconds: 0, rampOutSeconds: 4 },
+      { sourceStartSeconds: 0, sourceEndSeconds: 10 },
+    ]) {
+      for (let step = 0; step <= 20; step += 1) {
+        const source = step / 2;
+        expect(clipSourceOffset(clip, clipProgramOffset(clip, source))).toBeCloseTo(source, 9);
+      }
+    }
+  });
+
+  test("follows ramps in the media rate and mutes clips too fast to follow", () => {
+    const [clip] = compile({
+      version: 2,
+      items: [
+        {
+          kind: "clip",
+          id: "clip-a",
+          sourceAssetId: "recording",
+          sourceStartSeconds: 0,
+          sourceEndSeconds: 10,
+          playbackRate: 4,
+          rampInSeconds: 2,
+          rampOutSeconds: 2,
+        },
+      ],
+    });
+    if (clip?.kind !== "clip") {
+      throw new Error("expected a clip");
+    }
+
+    expect(clipMediaPlayback(clip, 0, 1).playbackRate).toBe(1);
+    expect(clipMediaPlayback(clip, 1, 1).playbackRate).toBe(2.5);
+    expect(clipMediaPlayback(clip, 5, 1).playbackRate).toBe(4);
+    expect(clipMediaPlayback(clip, 9, 1).playbackRate).toBe(2.5);
+    expect(clipMediaPlayback(clip, 5, 8).playbackRate).toBe(16);
+
+    expect(clipAudioMode({})).toBe("normal");
+    expect(clipAudioMode({ playbackRate: 2 })).toBe("pitchPreserved");
+    expect(clipAudioMode({ playbackRate: 0.5 })).toBe("pitchPreserved");
+    expect(clipAudioMode({ playbackRate: 8 })).toBe("muted");
+    expect(clipAudioMode({ playbackRate: 2, speedAudio: "mute" })).toBe("muted");
+  });
 });
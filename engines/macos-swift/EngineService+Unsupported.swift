@@ -0,0 +1,125 @@
+import EngineProtocol
+import Foundation
+
+/// Endpoints the native foundation engine serves that the macOS engine does not implement yet.
+extension EngineService {
+    func capture_period_captureStartRegion(
+        _: Operations.capture_period_captureStartRegion.Input
+    ) async throws -> Operations.capture_period_captureStartRegion.Output {
+        .badRequest(.init(body: .json(unsupported("Region capture is not supported by the macOS engine yet"))))
+    }
+
+    func capture_period_captureStartCamera(
+        _: Operations.capture_period_captureStartCamera.Input
+    ) async throws -> Operations.capture_period_captureStartCamera.Output {
+        .badRequest(.init(body: .json(unsupported("Camera capture is not supported by the macOS engine yet"))))
+    }
+
+    func capture_period_captureStopCamera(
+        _: Operations.capture_period_captureStopCamera.Input
+    ) async throws -> Operations.capture_period_captureStopCamera.Output {
+        .badRequest(.init(body: .json(unsupported("Camera capture is not supported by the macOS engine yet"))))
+    }
+
+    func recording_period_recordingPause(
+        _: Operations.recording_period_recordingPause.Input
+    ) async throws -> Operations.recording_period_recordingPause.Output {
+        .badRequest(.init(body: .json(unsupported("Pausing recordings is not supported by the macOS engine yet"))))
+    }
+
+    func recording_period_recordingResume(
+        _: Operations.recording_period_recordingResume.Input
+    ) async throws -> Operations.recording_period_recordingResume.Output {
+        .badRequest(.init(body: .json(unsupported("Pausing recordings is not supported by the macOS engine yet"))))
+    }
+
+    func recording_period_recordingAddMarker(
+        _: Operations.recording_period_recordingAddMarker.Input
+    ) async throws -> Operations.recording_period_recordingAddMarker.Output {
+        .badRequest(.init(body: .json(unsupported("Recording markers is not supported by the macOS engine yet"))))
+    }
+
+    func recording_period_recordingRecover(
+        _: Operations.recording_period_recordingRecover.Input
+    ) async throws -> Operations.recording_period_recordingRecover.Output {
+        .badRequest(.init(body: .json(unsupported("Recording recovery is not supported by the macOS engine yet"))))
+    }
+
+    func export_period_exportGuide(
+        _: Operations.export_period_exportGuide.Input
+    ) async throws -> Operations.export_period_exportGuide.Output {
+        .badRequest(.init(body: .json(unsupported("Guide export is not supported by the macOS engine yet"))))
+    }
+
+    func takes_period_takesList(
+        _: Operations.takes_period_takesList.Input
+    ) async throws -> Operations.takes_period_takesList.Output {
+        .badRequest(.init(body: .json(unsupported("Takes is not supported by the macOS engine yet"))))
+    }
+
+    func takes_period_takesRename(
+        _: Operations.takes_period_takesRename.Input
+    ) async throws -> Operations.takes_period_takesRename.Output {
+        .badRequest(.init(body: .json(unsupported("Takes is not supported by the macOS engine yet"))))
+    }
+
+    func takes_period_takesDelete(
+        _: Operations.takes_period_takesDelete.Input
+    ) async throws -> Operations.takes_period_takesDelete.Output {
+        .badRequest(.init(body: .json(unsupported("Takes is not supported by the macOS engine yet"))))
+    }
+
+    func takes_period_takesActivate(
+        _: Operations.takes_period_takesActivate.Input
+    ) async throws -> Operations.takes_period_takesActivate.Output {
+        .badRequest(.init(body: .json(unsupported("Takes is not supported by the macOS engine yet"))))
+    }
+
+    func cameraKeyframes_period_cameraKeyframesList(
+        _: Operations.cameraKeyframes_period_cameraKeyframesList.Input
+    ) async throws -> Operations.cameraKeyframes_period_cameraKeyframesList.Output {
+        .badRequest(.init(body: .json(unsupported("Camera keyframe editing is not supported by the macOS engine yet"))))
+    }
+
+    func cameraKeyframes_period_cameraKeyframesEdit(
+        _: Operations.cameraKeyframes_period_cameraKeyframesEdit.Input
+    ) async throws -> Operations.cameraKeyframes_period_cameraKeyframesEdit.Output {
+        .badRequest(.init(body: .json(unsupported("Camera keyframe editing is not supported by the macOS engine yet"))))
+    }
+
+    func cameraKeyframes_period_cameraKeyframesReplan(
+        _: Operations.cameraKeyframes_period_cameraKeyframesReplan.Input
+    ) async throws -> Operations.cameraKeyframes_period_cameraKeyframesReplan.Output {
+        .badRequest(.init(body: .json(unsupported("Camera keyframe editing is not supported by the macOS engine yet"))))
+    }
+
+    func timeline_period_timelineSuggestCuts(
+        _: Operations.timeline_period_timelineSuggestCuts.Input
+    ) async throws -> Operations.timeline_period_timelineSuggestCuts.Output {
+        .badRequest(.init(body: .json(unsupported("Idle-cut suggestions is not supported by the macOS engine yet"))))
+    }
+
+    func timeline_period_timelineApplyCuts(
+        _: Operations.timeline_period_timelineApplyCuts.Input
+    ) async throws -> Operations.timeline_period_timelineApplyCuts.Output {
+        .badRequest(.init(body: .json(unsupported("Idle-cut suggestions is not supported by the macOS engine yet"))))
+    }
+
+    func analysis_period_analysisVisualActivity(
+        _: Operations.analysis_period_analysisVisualActivity.Input
+    ) async throws -> Operations.analysis_period_analysisVisualActivity.Output {
+        .badRequest(.init(body: .json(unsupported("Visual activity analysis is not supported by the macOS engine yet"))))
+    }
+
+    func library_period_libraryDefaults(
+        _: Operations.library_period_libraryDefaults.Input
+    ) async throws -> Operations.library_period_libraryDefaults.Output {
+        .badRequest(.init(body: .json(unsupported("Library defaults is not supported by the macOS engine yet"))))
+    }
+
+    func library_period_librarySaveDefaults(
+        _: Operations.library_period_librarySaveDefaults.Input
+    ) async throws -> Operations.library_period_librarySaveDefaults.Output {
+        .badRequest(.init(body: .json(unsupported("Library defaults is not supported by the macOS engine yet"))))
+    }
+}
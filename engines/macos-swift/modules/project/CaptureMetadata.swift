@@ -53,6 +53,7 @@ public struct CaptureMetadata: Codable, Equatable {
     public enum Source: String, Codable {
         case display
         case window
+        case region
     }
 
     public var source: Source
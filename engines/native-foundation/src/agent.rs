@@ -1,18 +1,13 @@
 use crate::params::{AgentRunParams, JobParams};
 use crate::state::{now_iso8601, AgentRunState, PreflightSession, State};
-use crate::wire::{failure, success, EngineCallId, EngineResponse, ProtocolErrorCode};
+use crate::wire::{
+    decode_params, failure, success, EngineCallId, EngineResponse, ProtocolErrorCode,
+};
 use crate::PREFLIGHT_TOKEN_TTL_SECONDS;
 use serde_json::{json, Value};
 use std::fs;
 use time::OffsetDateTime;
 
-fn decode_params<T>(params: &Value) -> T
-where
-    T: for<'de> serde::Deserialize<'de> + Default,
-{
-    serde_json::from_value(params.clone()).unwrap_or_default()
-}
-
 pub(crate) fn transcription_provider(params: &Value) -> &'static str {
     match params
         .get("transcriptionProvider")
@@ -40,7 +35,8 @@ pub(crate) fn numeric_time(value: Option<&Value>) -> Option<f64> {
     value.and_then(Value::as_f64)
 }
 
-pub(crate) fn normalized_segment(entry: &Value) -> Option<String> {
+/// A transcript segment's start, end and text, if it has all three.
+pub(crate) fn timed_segment(entry: &Value) -> Option<(f64, f64, String)> {
     let text = entry
         .get("text")
         .and_then(Value::as_str)?
@@ -55,7 +51,23 @@ pub(crate) fn normalized_segment(entry: &Value) -> Option<String> {
     if text.is_empty() || start < 0.0 || end <= start {
         return None;
     }
-    Some(text)
+    Some((start, end, text))
+}
+
+pub(crate) fn normalized_segment(entry: &Value) -> Option<String> {
+    timed_segment(entry).map(|(_, _, text)| text)
+}
+
+/// The timed segments of an imported transcript; `None` when it cannot be read.
+pub(crate) fn imported_transcript_segments(path: &str) -> Option<Vec<(f64, f64, String)>> {
+    let parsed = imported_transcript_payload(path)?;
+    Some(
+        parsed
+            .get("segments")
+            .and_then(Value::as_array)
+            .map(|items| items.iter().filter_map(timed_segment).collect())
+            .unwrap_or_default(),
+    )
 }
 
 pub(crate) fn normalized_word(entry: &Value) -> Option<String> {
@@ -326,7 +338,10 @@ pub(crate) fn build_agent_run(
 }
 
 pub(crate) fn run(id: &EngineCallId, state: &mut State, params: &Value) -> EngineResponse {
-    let agent_params: AgentRunParams = decode_params(params);
+    let agent_params = match decode_params::<AgentRunParams>(id, params, "agent run") {
+        Ok(params) => params,
+        Err(response) => return response,
+    };
     let token = agent_params.preflight_token.as_deref().unwrap_or("");
     if let Err(message) = validate_preflight_token(state, token, params) {
         return failure(id, ProtocolErrorCode::InvalidParams, message);
@@ -414,7 +429,10 @@ pub(crate) fn run(id: &EngineCallId, state: &mut State, params: &Value) -> Engin
 }
 
 pub(crate) fn status(id: &EngineCallId, state: &State, params: &Value) -> EngineResponse {
-    let job_params: JobParams = decode_params(params);
+    let job_params = match decode_params::<JobParams>(id, params, "job") {
+        Ok(params) => params,
+        Err(response) => return response,
+    };
     let job_id = match job_params.job_id {
         Some(value) => value,
         None => return failure(id, ProtocolErrorCode::InvalidParams, "jobId is required"),
@@ -443,7 +461,10 @@ pub(crate) fn status(id: &EngineCallId, state: &State, params: &Value) -> Engine
 }
 
 pub(crate) fn apply(id: &EngineCallId, state: &mut State, params: &Value) -> EngineResponse {
-    let job_params: JobParams = decode_params(params);
+    let job_params = match decode_params::<JobParams>(id, params, "job") {
+        Ok(params) => params,
+        Err(response) => return response,
+    };
     let job_id = match job_params.job_id {
         Some(value) => value,
         None => return failure(id, ProtocolErrorCode::InvalidParams, "jobId is required"),
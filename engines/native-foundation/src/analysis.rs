@@ -0,0 +1,363 @@
+//! Visual activity of a take: how much the picture changes from frame to frame, where the
+//! scenes change, and the chapters, idle stretches and thumbnails that follow from it.
+
+use crate::params::VisualAnalysisParams;
+use crate::pipeline::{FrameSource, SyntheticFrameSource};
+use crate::recording_journal::{luma_difference, luma_differences, luma_plane};
+use crate::state::State;
+use crate::takes::{is_placeholder_recording, Take};
+use crate::wire::{
+    decode_params, failure, success, EngineCallId, EngineResponse, ProtocolErrorCode,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::path::Path;
+
+/// Version of the stored analysis; artifacts from other versions are dropped on open.
+pub(crate) const VISUAL_ANALYSIS_VERSION: u32 = 1;
+/// Rate the synthetic source is sampled at, the default capture rate.
+pub(crate) const SYNTHETIC_FPS: u64 = 30;
+/// Frame size the synthetic source renders when the take has no capture metadata.
+const SYNTHETIC_CONTENT_SIZE: (u32, u32) = (1920, 1080);
+/// Frames within this many seconds of a candidate decide how calm a thumbnail is.
+const THUMBNAIL_WINDOW_SECONDS: f64 = 0.5;
+
+/// Where the analyzed frames came from.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) enum AnalysisFrameSource {
+    /// Decoded from the take's recording.
+    Recording,
+    /// Rendered from the synthetic capture source, for recordings that cannot be decoded.
+    Synthetic,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct SceneChange {
+    pub(crate) time_seconds: f64,
+    /// Frame difference at the change.
+    pub(crate) score: f64,
+}
+
+/// A stretch between scene changes proposed as a chapter.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ChapterSuggestion {
+    pub(crate) id: String,
+    pub(crate) title: String,
+    pub(crate) start_seconds: f64,
+    pub(crate) end_seconds: f64,
+    /// Mean frame difference inside the chapter.
+    pub(crate) activity: f64,
+    /// The calmest moment of the chapter, away from its cuts.
+    pub(crate) thumbnail_seconds: f64,
+}
+
+/// Analysis artifact stored with the project, one per take, in the take's source time.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct VisualAnalysis {
+    pub(crate) version: u32,
+    pub(crate) take_id: String,
+    pub(crate) source: AnalysisFrameSource,
+    pub(crate) settings: VisualAnalysisParams,
+    pub(crate) fps: f64,
+    pub(crate) duration_seconds: f64,
+    /// Mean luma change from the previous frame as a fraction of full scale; `0` for the first.
+    pub(crate) frame_differences: Vec<f64>,
+    pub(crate) scene_changes: Vec<SceneChange>,
+    pub(crate) chapters: Vec<ChapterSuggestion>,
+    /// Thumbnail for the whole take: the thumbnail of its longest chapter.
+    pub(crate) thumbnail_seconds: f64,
+}
+
+/// Frame differences of the recording, or `None` for a placeholder recording made without a
+/// capture backend. A recording that is missing or cannot be decoded is an error.
+fn recording_differences(recording_url: &str) -> Result<Option<(f64, Vec<f64>)>, String> {
+    if is_placeholder_recording(recording_url) {
+        return Ok(None);
+    }
+    luma_differences(Path::new(recording_url))
+        .map(Some)
+        .map_err(|error| format!("Unable to analyze recording frames: {error}"))
+}
+
+/// The synthetic capture source sized like the recording described by `capture_metadata`.
+pub(crate) fn synthetic_source(capture_metadata: Option<&Value>) -> SyntheticFrameSource {
+    let content_rect = capture_metadata.and_then(|metadata| metadata.get("contentRect"));
+    let dimension = |key: &str| {
+        content_rect
+            .and_then(|rect| rect.get(key))
+            .and_then(Value::as_u64)
+            .and_then(|value| u32::try_from(value).ok())
+            .filter(|value| *value > 0)
+    };
+    let (width, height) = dimension("width")
+        .zip(dimension("height"))
+        .unwrap_or(SYNTHETIC_CONTENT_SIZE);
+    SyntheticFrameSource::new(width, height)
+}
+
+/// Frame differences of the synthetic source over the take, sized like its capture.
+fn synthetic_differences(take: &Take) -> Result<(f64, Vec<f64>), String> {
+    let mut source = synthetic_source(take.capture_metadata.as_ref());
+    let frame_count = (take.duration_seconds.max(0.0) * SYNTHETIC_FPS as f64).ceil() as u64;
+    let mut differences = Vec::with_capacity(frame_count as usize);
+    let mut previous: Option<Vec<u8>> = None;
+    for index in 0..frame_count {
+        let luma = luma_plane(&source.capture(index)?);
+        differences.push(
+            previous
+                .as_deref()
+                .map_or(0.0, |previous| luma_difference(&luma, previous)),
+        );
+        previous = Some(luma);
+    }
+    Ok((SYNTHETIC_FPS as f64, differences))
+}
+
+/// Frames where the difference peaks at or above the threshold. Changes closer than
+/// `min_scene_seconds` keep only the strongest.
+fn scene_changes(
+    differences: &[f64],
+    fps: f64,
+    settings: &VisualAnalysisParams,
+) -> Vec<SceneChange> {
+    let mut changes: Vec<SceneChange> = Vec::new();
+    for (frame, &score) in differences.iter().enumerate().skip(1) {
+        let after = differences.get(frame + 1).copied().unwrap_or(0.0);
+        if score < settings.scene_threshold || score < differences[frame - 1] || score < after {
+            continue;
+        }
+        let change = SceneChange {
+            time_seconds: frame as f64 / fps,
+            score,
+        };
+        match changes.last_mut() {
+            Some(last) if change.time_seconds - last.time_seconds < settings.min_scene_seconds => {
+                if score > last.score {
+                    *last = change;
+                }
+            }
+            _ => changes.push(change),
+        }
+    }
+    changes
+}
+
+/// Mean difference over the frames within `THUMBNAIL_WINDOW_SECONDS` of each frame.
+fn windowed_activity(differences: &[f64], fps: f64) -> Vec<f64> {
+    let radius = (THUMBNAIL_WINDOW_SECONDS * fps).round() as usize;
+    let mut prefix = Vec::with_capacity(differences.len() + 1);
+    prefix.push(0.0);
+    for difference in differences {
+        prefix.push(prefix[prefix.len() - 1] + difference);
+    }
+    (0..differences.len())
+        .map(|frame| {
+            let from = frame.saturating_sub(radius);
+            let to = (frame + radius + 1).min(differences.len());
+            (prefix[to] - prefix[from]) / (to - from) as f64
+        })
+        .collect()
+}
+
+/// The calmest frame between `from` and `to`, keeping clear of the cuts at either end when the
+/// chapter is long enough; ties go to the frame nearest the middle.
+fn thumbnail_frame(activity: &[f64], from: usize, to: usize, fps: f64) -> usize {
+    let radius = (THUMBNAIL_WINDOW_SECONDS * fps).round() as usize;
+    let interior = (from + radius)..to.saturating_sub(radius);
+    let candidates = if interior.is_empty() {
+        from..to
+    } else {
+        interior
+    };
+    let middle = (from + to) / 2;
+    candidates
+        .filter(|frame| *frame < activity.len())
+        .min_by(|left, right| {
+            activity[*left]
+                .total_cmp(&activity[*right])
+                .then(left.abs_diff(middle).cmp(&right.abs_diff(middle)))
+        })
+        .unwrap_or(middle)
+}
+
+/// One chapter per scene, leaving out changes closer than `min_scene_seconds` to either end.
+fn chapters(
+    differences: &[f64],
+    fps: f64,
+    duration: f64,
+    changes: &[SceneChange],
+    settings: &VisualAnalysisParams,
+) -> Vec<ChapterSuggestion> {
+    if duration <= 0.0 {
+        return Vec::new();
+    }
+    let activity = windowed_activity(differences, fps);
+    let bounds = std::iter::once(0.0)
+        .chain(
+            changes
+                .iter()
+                .map(|change| change.time_seconds)
+                .filter(|time| {
+                    *time >= settings.min_scene_seconds
+                        && duration - time >= settings.min_scene_seconds
+                }),
+        )
+        .chain(std::iter::once(duration))
+        .collect::<Vec<_>>();
+    bounds
+        .windows(2)
+        .enumerate()
+        .map(|(index, pair)| {
+            let (start, end) = (pair[0], pair[1]);
+            let from = ((start * fps).round() as usize).min(differences.len());
+            let to = ((end * fps).round() as usize).min(differences.len());
+            // The first frame of a chapter is its cut, which says nothing about the scene.
+            let inside = differences.get(from + 1..to).unwrap_or_default();
+            ChapterSuggestion {
+                id: format!("chapter-{}", index + 1),
+                title: format!("Chapter {}", index + 1),
+                start_seconds: start,
+                end_seconds: end,
+                activity: if inside.is_empty() {
+                    0.0
+                } else {
+                    inside.iter().sum::<f64>() / inside.len() as f64
+                },
+                thumbnail_seconds: thumbnail_frame(&activity, from, to, fps) as f64 / fps,
+            }
+        })
+        .collect()
+}
+
+/// Analyzes `take`'s recording, or the synthetic source when it only has a placeholder recording.
+fn analyze(take: &Take, settings: VisualAnalysisParams) -> Result<VisualAnalysis, String> {
+    let (source, (fps, differences)) = match recording_differences(&take.recording_url)? {
+        Some(frames) => (AnalysisFrameSource::Recording, frames),
+        None => (AnalysisFrameSource::Synthetic, synthetic_differences(take)?),
+    };
+    let duration = differences.len() as f64 / fps;
+    let scene_changes = scene_changes(&differences, fps, &settings);
+    let chapters = chapters(&differences, fps, duration, &scene_changes, &settings);
+    let thumbnail_seconds = chapters
+        .iter()
+        .max_by(|left, right| {
+            (left.end_seconds - left.start_seconds)
+                .total_cmp(&(right.end_seconds - right.start_seconds))
+                .then(right.start_seconds.total_cmp(&left.start_seconds))
+        })
+        .map_or(0.0, |chapter| chapter.thumbnail_seconds);
+    Ok(VisualAnalysis {
+        version: VISUAL_ANALYSIS_VERSION,
+        take_id: take.id.clone(),
+        source,
+        settings,
+        fps,
+        duration_seconds: duration,
+        frame_differences: differences,
+        scene_changes,
+        chapters,
+        thumbnail_seconds,
+    })
+}
+
+/// Frame differences of `take` for idle detection: its stored analysis, else its decoded
+/// recording. `None` when neither is available.
+pub(crate) fn take_frame_differences(
+    state: &State,
+    take: &Take,
+) -> Result<Option<(f64, Vec<f64>)>, String> {
+    if let Some(analysis) = state
+        .visual_analyses
+        .iter()
+        .find(|analysis| analysis.take_id == take.id)
+    {
+        return Ok(Some((analysis.fps, analysis.frame_differences.clone())));
+    }
+    recording_differences(&take.recording_url)
+}
+
+/// Analyzes the active take and stores the result with the project, replacing any earlier
+/// analysis of that take.
+pub(crate) fn visual_activity(
+    id: &EngineCallId,
+    state: &mut State,
+    params: &Value,
+) -> EngineResponse {
+    let settings = match decode_params::<VisualAnalysisParams>(id, params, "visual analysis") {
+        Ok(params) => params,
+        Err(response) => return response,
+    };
+    let settings = match settings.validated() {
+        Ok(settings) => settings,
+        Err(error) => return failure(id, ProtocolErrorCode::InvalidParams, error),
+    };
+    let Some(take) = state.active_take().cloned() else {
+        return failure(
+            id,
+            ProtocolErrorCode::InvalidParams,
+            "No active take to analyze",
+        );
+    };
+    let analysis = match analyze(&take, settings) {
+        Ok(analysis) => analysis,
+        Err(error) => return failure(id, ProtocolErrorCode::RuntimeError, error),
+    };
+    state
+        .visual_analyses
+        .retain(|stored| stored.take_id != take.id);
+    state.visual_analyses.push(analysis.clone());
+    state.unsaved_changes = true;
+    match serde_json::to_value(&analysis) {
+        Ok(result) => success(id, result),
+        Err(error) => failure(
+            id,
+            ProtocolErrorCode::RuntimeError,
+            format!("Unable to encode visual analysis: {error}"),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{chapters, scene_changes, windowed_activity, VisualAnalysisParams};
+
+    #[test]
+    fn close_scene_changes_keep_the_strongest_and_chapters_avoid_the_ends() {
+        let mut differences = vec![0.0; 100];
+        differences[20] = 0.3;
+        differences[25] = 0.5;
+        differences[60] = 0.05;
+        differences[95] = 0.4;
+        let settings = VisualAnalysisParams::default();
+        let changes = scene_changes(&differences, 10.0, &settings);
+        let times = changes
+            .iter()
+            .map(|change| (change.time_seconds, change.score))
+            .collect::<Vec<_>>();
+        assert_eq!(times, vec![(2.5, 0.5), (9.5, 0.4)]);
+
+        let chapters = chapters(&differences, 10.0, 10.0, &changes, &settings);
+        assert_eq!(chapters.len(), 2);
+        assert_eq!(
+            (chapters[0].start_seconds, chapters[0].end_seconds),
+            (0.0, 2.5)
+        );
+        assert_eq!(
+            (chapters[1].start_seconds, chapters[1].end_seconds),
+            (2.5, 10.0)
+        );
+        assert_eq!(chapters[1].title, "Chapter 2");
+        // The calm stretch nearest the middle, clear of the bump at 6 s.
+        assert_eq!(chapters[1].thumbnail_seconds, 6.6);
+    }
+
+    #[test]
+    fn windowed_activity_averages_the_surrounding_second() {
+        let activity = windowed_activity(&[0.0, 0.0, 0.75, 0.0, 0.0, 0.0], 2.0);
+        assert_eq!(activity, vec![0.0, 0.25, 0.25, 0.25, 0.0, 0.0]);
+    }
+}
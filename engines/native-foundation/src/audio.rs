@@ -0,0 +1,714 @@
+use crate::disk::RecordingDiskBudget;
+use crate::path_security::{create_file_no_symlink, reject_final_symlink};
+use crate::recording::RecordingWindow;
+use serde::{Deserialize, Serialize};
+use std::fs::{File, OpenOptions};
+use std::io::{self, Seek, SeekFrom, Write};
+#[cfg(unix)]
+use std::os::unix::fs::OpenOptionsExt;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+pub(crate) const AUDIO_SAMPLE_RATE: u32 = 48_000;
+pub(crate) const AUDIO_CHANNELS: u16 = 2;
+/// 10 ms reads keep pause/stop latency low and divide the meter window evenly.
+const CHUNK_FRAMES: usize = AUDIO_SAMPLE_RATE as usize / 100;
+const CHUNK_DURATION: Duration = Duration::from_millis(10);
+/// A source that keeps failing for this many reads in a row (~1 s) ends the track.
+const MAX_CONSECUTIVE_READ_ERRORS: u32 = 100;
+/// Live levels are published every 50 ms, i.e. at 20 Hz.
+const METER_WINDOW_SAMPLES: usize = (AUDIO_SAMPLE_RATE as usize / 20) * AUDIO_CHANNELS as usize;
+/// 16-bit PCM cannot represent anything quieter, so digital silence reports this level.
+pub(crate) const SILENCE_FLOOR_DBFS: f64 = -96.0;
+/// Meter windows peaking below this count as silence; a muted mic or idle monitor sits far
+/// below it, while room tone from a live mic usually does not.
+const SILENCE_THRESHOLD_DBFS: f64 = -60.0;
+pub(crate) const DEFAULT_SILENCE_WARNING_SECONDS: f64 = 5.0;
+const WAV_HEADER_BYTES: u64 = 44;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) enum AudioTrackKind {
+    Microphone,
+    SystemAudio,
+}
+
+impl AudioTrackKind {
+    pub(crate) const ALL: [AudioTrackKind; 2] =
+        [AudioTrackKind::Microphone, AudioTrackKind::SystemAudio];
+
+    /// Track file inside the recording directory, named after the Swift project's audio files.
+    pub(crate) fn file_name(self) -> &'static str {
+        match self {
+            Self::Microphone => "audio_mic.wav",
+            Self::SystemAudio => "audio_system.wav",
+        }
+    }
+
+    pub(crate) fn display_name(self) -> &'static str {
+        match self {
+            Self::Microphone => "Microphone",
+            Self::SystemAudio => "System audio",
+        }
+    }
+
+    pub(crate) fn label(self) -> &'static str {
+        match self {
+            Self::Microphone => "microphone",
+            Self::SystemAudio => "system audio",
+        }
+    }
+
+    /// PulseAudio's aliases for the default input and the default output's monitor.
+    #[cfg(target_os = "linux")]
+    pub(crate) fn default_device(self) -> &'static str {
+        match self {
+            Self::Microphone => "@DEFAULT_SOURCE@",
+            Self::SystemAudio => "@DEFAULT_MONITOR@",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct AudioInputDevice {
+    pub(crate) id: String,
+    pub(crate) name: String,
+    /// Monitor sources replay an output sink and feed the system-audio track.
+    pub(crate) is_monitor: bool,
+}
+
+/// Returns the audio inputs currently offered by the sound server.
+pub(crate) type AudioDeviceProbe = fn() -> Vec<AudioInputDevice>;
+
+#[cfg(target_os = "linux")]
+pub(crate) fn available_input_devices() -> Vec<AudioInputDevice> {
+    crate::pulse::list_sources()
+        .unwrap_or_default()
+        .into_iter()
+        .map(|source| AudioInputDevice {
+            is_monitor: source.name.ends_with(".monitor"),
+            name: source.description,
+            id: source.name,
+        })
+        .collect()
+}
+
+#[cfg(not(target_os = "linux"))]
+pub(crate) fn available_input_devices() -> Vec<AudioInputDevice> {
+    Vec::new()
+}
+
+/// Interleaved 16-bit stereo PCM at [`AUDIO_SAMPLE_RATE`]. `read` blocks until `samples` is
+/// full, which paces the recorder thread.
+pub(crate) trait AudioSource: Send {
+    fn read(&mut self, samples: &mut [i16]) -> Result<(), String>;
+}
+
+/// Opens a capture stream for a track, on `device` or the platform default.
+pub(crate) type AudioSourceOpener =
+    fn(AudioTrackKind, Option<&str>) -> Result<Box<dyn AudioSource>, String>;
+
+#[cfg(target_os = "linux")]
+struct PulseAudioSource {
+    stream: crate::pulse::PulseRecordStream,
+    bytes: Vec<u8>,
+}
+
+#[cfg(target_os = "linux")]
+impl AudioSource for PulseAudioSource {
+    fn read(&mut self, samples: &mut [i16]) -> Result<(), String> {
+        self.bytes.resize(samples.len() * 2, 0);
+        self.stream.read(&mut self.bytes)?;
+        for (sample, bytes) in samples.iter_mut().zip(self.bytes.chunks_exact(2)) {
+            *sample = i16::from_le_bytes([bytes[0], bytes[1]]);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(target_os = "linux")]
+pub(crate) fn open_source(
+    kind: AudioTrackKind,
+    device: Option<&str>,
+) -> Result<Box<dyn AudioSource>, String> {
+    let chunk_bytes = CHUNK_FRAMES * AUDIO_CHANNELS as usize * 2;
+    let stream = crate::pulse::PulseRecordStream::open(
+        device.unwrap_or(kind.default_device()),
+        kind.label(),
+        AUDIO_SAMPLE_RATE,
+        AUDIO_CHANNELS as u8,
+        chunk_bytes as u32,
+    )?;
+    Ok(Box::new(PulseAudioSource {
+        stream,
+        bytes: Vec::with_capacity(chunk_bytes),
+    }))
+}
+
+#[cfg(not(target_os = "linux"))]
+pub(crate) fn open_source(
+    _kind: AudioTrackKind,
+    _device: Option<&str>,
+) -> Result<Box<dyn AudioSource>, String> {
+    Err("audio capture is only available on Linux".to_string())
+}
+
+/// Real-time paced sine tone, standing in for a sound server in tests.
+#[cfg(test)]
+pub(crate) struct SyntheticAudioSource {
+    amplitude: f64,
+    phase: f64,
+}
+
+#[cfg(test)]
+impl SyntheticAudioSource {
+    pub(crate) fn new(amplitude: f64) -> Self {
+        Self {
+            amplitude,
+            phase: 0.0,
+        }
+    }
+}
+
+#[cfg(test)]
+impl AudioSource for SyntheticAudioSource {
+    fn read(&mut self, samples: &mut [i16]) -> Result<(), String> {
+        let step = std::f64::consts::TAU * 440.0 / AUDIO_SAMPLE_RATE as f64;
+        for frame in samples.chunks_exact_mut(AUDIO_CHANNELS as usize) {
+            let value = (self.phase.sin() * self.amplitude * i16::MAX as f64).round() as i16;
+            frame.fill(value);
+            self.phase = (self.phase + step) % std::f64::consts::TAU;
+        }
+        std::thread::sleep(Duration::from_secs_f64(
+            samples.len() as f64 / (AUDIO_SAMPLE_RATE as f64 * AUDIO_CHANNELS as f64),
+        ));
+        Ok(())
+    }
+}
+
+/// An audio track recorded next to the screen recording.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct AudioTrack {
+    pub(crate) kind: AudioTrackKind,
+    #[serde(rename = "recordingURL")]
+    pub(crate) recording_url: String,
+    pub(crate) device_id: Option<String>,
+    pub(crate) sample_rate: u32,
+    pub(crate) channels: u16,
+}
+
+/// Whole-recording levels for one audio track.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct AudioTrackTelemetry {
+    pub(crate) kind: AudioTrackKind,
+    pub(crate) captured_seconds: f64,
+    pub(crate) peak_dbfs: f64,
+    pub(crate) rms_dbfs: f64,
+    pub(crate) clipped_samples: u64,
+    pub(crate) read_errors: u64,
+}
+
+/// Live levels for one audio track, refreshed at 20 Hz while recording.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct AudioLevels {
+    pub(crate) kind: AudioTrackKind,
+    pub(crate) peak_dbfs: f64,
+    pub(crate) rms_dbfs: f64,
+    pub(crate) clipped_samples: u64,
+    /// How long the track has stayed below the silence threshold, not counting pauses.
+    pub(crate) silent_seconds: f64,
+    pub(crate) silence_warning: bool,
+}
+
+pub(crate) fn amplitude_to_dbfs(amplitude: f64) -> f64 {
+    if amplitude <= 0.0 {
+        return SILENCE_FLOOR_DBFS;
+    }
+    (20.0 * amplitude.log10()).max(SILENCE_FLOOR_DBFS)
+}
+
+fn is_clipped(sample: i16) -> bool {
+    sample == i16::MAX || sample == i16::MIN
+}
+
+/// Full-scale amplitude of `peak` and `sum_of_squares / samples`, as dBFS.
+fn levels_dbfs(peak: i32, sum_of_squares: f64, samples: u64) -> (f64, f64) {
+    let full_scale = -(i16::MIN as f64);
+    let rms = if samples == 0 {
+        0.0
+    } else {
+        (sum_of_squares / samples as f64).sqrt()
+    };
+    (
+        amplitude_to_dbfs(peak as f64 / full_scale),
+        amplitude_to_dbfs(rms / full_scale),
+    )
+}
+
+/// Windowed peak/RMS meter with clipping and sustained-silence detection.
+#[derive(Debug)]
+struct LevelMeter {
+    silence_warning_seconds: f64,
+    window_peak: i32,
+    window_sum_of_squares: f64,
+    window_samples: usize,
+    peak_dbfs: f64,
+    rms_dbfs: f64,
+    clipped_samples: u64,
+    silent_samples: u64,
+    /// Set once per silent stretch, so a long silence warns only once.
+    silence_warned: bool,
+    silence_warning_pending: bool,
+}
+
+impl LevelMeter {
+    fn new(silence_warning_seconds: f64) -> Self {
+        Self {
+            silence_warning_seconds,
+            window_peak: 0,
+            window_sum_of_squares: 0.0,
+            window_samples: 0,
+            peak_dbfs: SILENCE_FLOOR_DBFS,
+            rms_dbfs: SILENCE_FLOOR_DBFS,
+            clipped_samples: 0,
+            silent_samples: 0,
+            silence_warned: false,
+            silence_warning_pending: false,
+        }
+    }
+
+    fn silent_seconds(&self) -> f64 {
+        self.silent_samples as f64 / (AUDIO_SAMPLE_RATE as f64 * AUDIO_CHANNELS as f64)
+    }
+
+    /// Meters `chunk`; clipping and silence only count while `recording` (not paused).
+    fn measure(&mut self, chunk: &[i16], recording: bool) {
+        for &sample in chunk {
+            let value = i32::from(sample);
+            self.window_peak = self.window_peak.max(value.abs());
+            self.window_sum_of_squares += f64::from(value * value);
+            if recording && is_clipped(sample) {
+                self.clipped_samples += 1;
+            }
+        }
+        self.window_samples += chunk.len();
+        if self.window_samples < METER_WINDOW_SAMPLES {
+            return;
+        }
+        (self.peak_dbfs, self.rms_dbfs) = levels_dbfs(
+            self.window_peak,
+            self.window_sum_of_squares,
+            self.window_samples as u64,
+        );
+        if recording {
+            if self.peak_dbfs < SILENCE_THRESHOLD_DBFS {
+                self.silent_samples += self.window_samples as u64;
+                if !self.silence_warned && self.silent_seconds() >= self.silence_warning_seconds {
+                    self.silence_warned = true;
+                    self.silence_warning_pending = true;
+                }
+            } else {
+                self.silent_samples = 0;
+                self.silence_warned = false;
+            }
+        }
+        self.window_peak = 0;
+        self.window_sum_of_squares = 0.0;
+        self.window_samples = 0;
+    }
+
+    fn levels(&self, kind: AudioTrackKind) -> AudioLevels {
+        AudioLevels {
+            kind,
+            peak_dbfs: self.peak_dbfs,
+            rms_dbfs: self.rms_dbfs,
+            clipped_samples: self.clipped_samples,
+            silent_seconds: self.silent_seconds(),
+            silence_warning: self.silence_warned,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct AudioTrackStats {
+    samples: u64,
+    peak: i32,
+    sum_of_squares: f64,
+    read_errors: u64,
+    meter: LevelMeter,
+}
+
+impl AudioTrackStats {
+    fn new(silence_warning_seconds: f64) -> Self {
+        Self {
+            samples: 0,
+            peak: 0,
+            sum_of_squares: 0.0,
+            read_errors: 0,
+            meter: LevelMeter::new(silence_warning_seconds),
+        }
+    }
+
+    fn record(&mut self, chunk: &[i16]) {
+        for &sample in chunk {
+            let sample = i32::from(sample);
+            self.peak = self.peak.max(sample.abs());
+            self.sum_of_squares += f64::from(sample * sample);
+        }
+        self.samples += chunk.len() as u64;
+    }
+
+    fn telemetry(&self, kind: AudioTrackKind) -> AudioTrackTelemetry {
+        let (peak_dbfs, rms_dbfs) = levels_dbfs(self.peak, self.sum_of_squares, self.samples);
+        AudioTrackTelemetry {
+            kind,
+            captured_seconds: self.samples as f64
+                / (AUDIO_SAMPLE_RATE as f64 * AUDIO_CHANNELS as f64),
+            peak_dbfs,
+            rms_dbfs,
+            clipped_samples: self.meter.clipped_samples,
+            read_errors: self.read_errors,
+        }
+    }
+}
+
+/// Streaming 16-bit PCM WAV file. Sizes in the header are filled in by [`Self::finish`];
+/// an interrupted file is fixed up by [`repair_wav_header`].
+pub(crate) struct WavWriter {
+    file: File,
+    data_bytes: u64,
+    disk_budget: Option<Arc<RecordingDiskBudget>>,
+}
+
+fn wav_header(data_bytes: u32) -> [u8; WAV_HEADER_BYTES as usize] {
+    let block_align = AUDIO_CHANNELS * 2;
+    let mut header = [0u8; WAV_HEADER_BYTES as usize];
+    header[0..4].copy_from_slice(b"RIFF");
+    header[4..8].copy_from_slice(&data_bytes.saturating_add(36).to_le_bytes());
+    header[8..16].copy_from_slice(b"WAVEfmt ");
+    header[16..20].copy_from_slice(&16u32.to_le_bytes());
+    header[20..22].copy_from_slice(&1u16.to_le_bytes());
+    header[22..24].copy_from_slice(&AUDIO_CHANNELS.to_le_bytes());
+    header[24..28].copy_from_slice(&AUDIO_SAMPLE_RATE.to_le_bytes());
+    header[28..32].copy_from_slice(&(AUDIO_SAMPLE_RATE * u32::from(block_align)).to_le_bytes());
+    header[32..34].copy_from_slice(&block_align.to_le_bytes());
+    header[34..36].copy_from_slice(&16u16.to_le_bytes());
+    header[36..40].copy_from_slice(b"data");
+    header[40..44].copy_from_slice(&data_bytes.to_le_bytes());
+    header
+}
+
+impl WavWriter {
+    pub(crate) fn create(path: &Path) -> io::Result<Self> {
+        Self::open(path, None)
+    }
+
+    /// Creates a recording track whose appends draw from `disk_budget`, shared with the other
+    /// tracks of the recording.
+    pub(crate) fn create_for_recording(
+        path: &Path,
+        disk_budget: Arc<RecordingDiskBudget>,
+    ) -> io::Result<Self> {
+        Self::open(path, Some(disk_budget))
+    }
+
+    fn open(path: &Path, disk_budget: Option<Arc<RecordingDiskBudget>>) -> io::Result<Self> {
+        let mut file = create_file_no_symlink(path)?;
+        file.write_all(&wav_header(0))?;
+        Ok(Self {
+            file,
+            data_bytes: 0,
+            disk_budget,
+        })
+    }
+
+    pub(crate) fn append(&mut self, samples: &[i16]) -> io::Result<()> {
+        let bytes = samples
+            .iter()
+            .flat_map(|sample| sample.to_le_bytes())
+            .collect::<Vec<u8>>();
+        if let Some(disk_budget) = &self.disk_budget {
+            disk_budget.reserve(bytes.len() as u64)?;
+        }
+        self.file.write_all(&bytes)?;
+        self.data_bytes += bytes.len() as u64;
+        Ok(())
+    }
+
+    pub(crate) fn finish(mut self) -> io::Result<()> {
+        let data_bytes = u32::try_from(self.data_bytes).unwrap_or(u32::MAX);
+        self.file.seek(SeekFrom::Start(0))?;
+        self.file.write_all(&wav_header(data_bytes))?;
+        self.file.sync_all()
+    }
+}
+
+/// Rewrites the header sizes of a WAV track whose writer never finished, dropping a torn
+/// trailing sample frame. Returns the recovered duration in seconds.
+pub(crate) fn repair_wav_header(path: &Path) -> io::Result<f64> {
+    reject_final_symlink(path)?;
+    let mut options = OpenOptions::new();
+    options.read(true).write(true);
+    #[cfg(unix)]
+    {
+        options.custom_flags(libc::O_NOFOLLOW);
+    }
+    let mut file = options.open(path)?;
+    let block_align = u64::from(AUDIO_CHANNELS) * 2;
+    let data_bytes = file.metadata()?.len().saturating_sub(WAV_HEADER_BYTES);
+    let data_bytes = (data_bytes - data_bytes % block_align).min(u64::from(u32::MAX - 36));
+    file.set_len(WAV_HEADER_BYTES + data_bytes)?;
+    file.seek(SeekFrom::Start(0))?;
+    file.write_all(&wav_header(data_bytes as u32))?;
+    file.sync_all()?;
+    Ok(data_bytes as f64 / (block_align * u64::from(AUDIO_SAMPLE_RATE)) as f64)
+}
+
+/// Pulls one audio source on its own thread, writing to a WAV file while not paused.
+pub(crate) struct AudioTrackRecorder {
+    kind: AudioTrackKind,
+    stop: Arc<AtomicBool>,
+    paused: Arc<AtomicBool>,
+    stats: Arc<Mutex<AudioTrackStats>>,
+    worker: Mutex<Option<JoinHandle<io::Result<()>>>>,
+}
+
+impl AudioTrackRecorder {
+    /// Starts recording what `source` captures inside `window`; without a WAV writer the
+    /// track is only metered. Silence lasting `silence_warning_seconds` raises one warning per
+    /// silent stretch.
+    pub(crate) fn start(
+        kind: AudioTrackKind,
+        mut source: Box<dyn AudioSource>,
+        mut writer: Option<WavWriter>,
+        silence_warning_seconds: f64,
+        window: Arc<RecordingWindow>,
+    ) -> Self {
+        let stop = Arc::new(AtomicBool::new(false));
+        let paused = Arc::new(AtomicBool::new(false));
+        let stats = Arc::new(Mutex::new(AudioTrackStats::new(silence_warning_seconds)));
+        let worker = {
+            let (stop, paused, stats) = (stop.clone(), paused.clone(), stats.clone());
+            std::thread::spawn(move || {
+                let mut chunk = vec![0i16; CHUNK_FRAMES * AUDIO_CHANNELS as usize];
+                let mut consecutive_errors = 0;
+                let mut recorded = Ok(());
+                while recorded.is_ok() && !stop.load(Ordering::SeqCst) {
+                    if let Err(error) = source.read(&mut chunk) {
+                        lock(&stats).read_errors += 1;
+                        consecutive_errors += 1;
+                        if consecutive_errors >= MAX_CONSECUTIVE_READ_ERRORS {
+                            recorded = Err(io::Error::other(error));
+                        }
+                        std::thread::sleep(CHUNK_DURATION);
+                        continue;
+                    }
+                    consecutive_errors = 0;
+                    // Paused tracks keep draining the server so resuming does not replay
+                    // stale buffered audio, and keep the live meter moving; so do tracks
+                    // counting down or past their time limit.
+                    let is_recording =
+                        !paused.load(Ordering::SeqCst) && window.admits(Instant::now());
+                    lock(&stats).meter.measure(&chunk, is_recording);
+                    if !is_recording {
+                        continue;
+                    }
+                    if let Some(writer) = writer.as_mut() {
+                        recorded = writer.append(&chunk);
+                    }
+                    lock(&stats).record(&chunk);
+                }
+                let finished = writer.map_or(Ok(()), WavWriter::finish);
+                recorded.and(finished)
+            })
+        };
+        Self {
+            kind,
+            stop,
+            paused,
+            stats,
+            worker: Mutex::new(Some(worker)),
+        }
+    }
+
+    pub(crate) fn kind(&self) -> AudioTrackKind {
+        self.kind
+    }
+
+    pub(crate) fn set_paused(&self, paused: bool) {
+        self.paused.store(paused, Ordering::SeqCst);
+    }
+
+    pub(crate) fn telemetry(&self) -> AudioTrackTelemetry {
+        lock(&self.stats).telemetry(self.kind)
+    }
+
+    pub(crate) fn levels(&self) -> AudioLevels {
+        lock(&self.stats).meter.levels(self.kind)
+    }
+
+    /// Returns the silence that triggered a warning since the last call, in seconds.
+    pub(crate) fn take_silence_warning(&self) -> Option<f64> {
+        let mut stats = lock(&self.stats);
+        let meter = &mut stats.meter;
+        std::mem::take(&mut meter.silence_warning_pending).then_some(meter.silence_warning_seconds)
+    }
+
+    /// Stops the source and finalizes the WAV file, reporting any capture or write failure.
+    pub(crate) fn finish(&self) -> io::Result<()> {
+        self.stop.store(true, Ordering::SeqCst);
+        let Some(worker) = lock(&self.worker).take() else {
+            return Ok(());
+        };
+        worker
+            .join()
+            .unwrap_or_else(|_| Err(io::Error::other("audio recorder thread panicked")))
+    }
+}
+
+impl Drop for AudioTrackRecorder {
+    fn drop(&mut self) {
+        let _ = self.finish();
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    mutex
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        amplitude_to_dbfs, repair_wav_header, AudioSource, AudioTrackKind, AudioTrackRecorder,
+        LevelMeter, SyntheticAudioSource, WavWriter, DEFAULT_SILENCE_WARNING_SECONDS,
+        SILENCE_FLOOR_DBFS,
+    };
+    use crate::disk::RecordingDiskBudget;
+    use crate::recording::RecordingWindow;
+    use std::fs;
+    use std::sync::Arc;
+    use std::time::{Duration, Instant};
+
+    struct FailingSource;
+
+    fn unbounded_window() -> Arc<RecordingWindow> {
+        Arc::new(RecordingWindow::new(Instant::now(), None))
+    }
+
+    impl AudioSource for FailingSource {
+        fn read(&mut self, _samples: &mut [i16]) -> Result<(), String> {
+            Err("device unplugged".to_string())
+        }
+    }
+
+    #[test]
+    fn recorder_writes_a_playable_wav_and_reports_levels() {
+        let root = std::env::temp_dir().join(format!("gglass-audio-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        let path = root.join("audio_mic.wav");
+        let disk_budget = Arc::new(RecordingDiskBudget::new(&root, |_| None));
+        let writer = WavWriter::create_for_recording(&path, disk_budget).expect("create wav");
+        let recorder = AudioTrackRecorder::start(
+            AudioTrackKind::Microphone,
+            Box::new(SyntheticAudioSource::new(0.5)),
+            Some(writer),
+            DEFAULT_SILENCE_WARNING_SECONDS,
+            unbounded_window(),
+        );
+        std::thread::sleep(Duration::from_millis(120));
+        recorder.finish().expect("finish recorder");
+
+        let telemetry = recorder.telemetry();
+        assert!(telemetry.captured_seconds > 0.0);
+        assert!((telemetry.peak_dbfs - amplitude_to_dbfs(0.5)).abs() < 0.1);
+        assert!((telemetry.rms_dbfs - amplitude_to_dbfs(0.5 / 2f64.sqrt())).abs() < 0.2);
+
+        let data = fs::read(&path).expect("read wav");
+        assert_eq!(&data[0..4], b"RIFF");
+        let data_bytes = u32::from_le_bytes([data[40], data[41], data[42], data[43]]);
+        assert_eq!(data_bytes as usize, data.len() - 44);
+        assert_eq!(
+            data_bytes as f64 / (48_000.0 * 4.0),
+            telemetry.captured_seconds
+        );
+
+        fs::write(&path, &data[..data.len() - 3]).expect("tear wav");
+        let mut torn = fs::read(&path).expect("read torn wav");
+        torn[40..44].copy_from_slice(&0u32.to_le_bytes());
+        fs::write(&path, &torn).expect("zero header");
+        let recovered = repair_wav_header(&path).expect("repair wav");
+        let repaired = fs::read(&path).expect("read repaired wav");
+        assert_eq!(repaired.len(), data.len() - 4);
+        assert_eq!(
+            u32::from_le_bytes([repaired[40], repaired[41], repaired[42], repaired[43]]) as usize,
+            repaired.len() - 44
+        );
+        assert!((recovered - telemetry.captured_seconds).abs() < 0.001);
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn recorder_gives_up_on_a_source_that_keeps_failing() {
+        let recorder = AudioTrackRecorder::start(
+            AudioTrackKind::SystemAudio,
+            Box::new(FailingSource),
+            None,
+            DEFAULT_SILENCE_WARNING_SECONDS,
+            unbounded_window(),
+        );
+        std::thread::sleep(Duration::from_millis(1_500));
+        let error = recorder.finish().expect_err("source failure");
+        assert!(error.to_string().contains("device unplugged"));
+        let telemetry = recorder.telemetry();
+        assert!(telemetry.read_errors >= 100);
+        assert_eq!(telemetry.peak_dbfs, SILENCE_FLOOR_DBFS);
+    }
+
+    #[test]
+    fn meter_counts_clipping_and_warns_once_per_silent_stretch() {
+        let mut meter = LevelMeter::new(1.0);
+        let window = |value: i16| vec![value; 4_800];
+
+        meter.measure(&window(i16::MIN), true);
+        let levels = meter.levels(AudioTrackKind::Microphone);
+        assert_eq!(levels.peak_dbfs, 0.0);
+        assert_eq!(levels.clipped_samples, 4_800);
+
+        for _ in 0..19 {
+            meter.measure(&window(0), true);
+        }
+        assert!(!meter.silence_warning_pending);
+        // Paused silence neither counts toward the warning nor resets it.
+        meter.measure(&window(0), false);
+        meter.measure(&window(i16::MIN), false);
+        assert_eq!(
+            meter.levels(AudioTrackKind::Microphone).clipped_samples,
+            4_800
+        );
+        meter.measure(&window(0), true);
+        let levels = meter.levels(AudioTrackKind::Microphone);
+        assert_eq!(levels.peak_dbfs, SILENCE_FLOOR_DBFS);
+        assert!((levels.silent_seconds - 1.0).abs() < 1e-9);
+        assert!(levels.silence_warning);
+        assert!(std::mem::take(&mut meter.silence_warning_pending));
+
+        for _ in 0..40 {
+            meter.measure(&window(0), true);
+        }
+        assert!(!meter.silence_warning_pending);
+
+        meter.measure(&window(8_000), true);
+        let levels = meter.levels(AudioTrackKind::Microphone);
+        assert_eq!(levels.silent_seconds, 0.0);
+        assert!(!levels.silence_warning);
+        assert!((levels.rms_dbfs - amplitude_to_dbfs(8_000.0 / 32_768.0)).abs() < 1e-9);
+    }
+}
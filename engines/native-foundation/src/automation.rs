@@ -0,0 +1,923 @@
+//! Port of the Swift `Automation` module (`AttentionModel`, `ZoomConstraints`,
+//! `VirtualCameraPlanner`). Keep the arithmetic in step with the Swift sources: both engines are
+//! checked against `docs/fixtures/auto-zoom/planner-parity.json`.
+
+use crate::input_tracking::{InputEvent, InputEventType, InputPoint};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+/// Shortest time step used when deriving speeds, so coincident events never divide by zero.
+const MINIMUM_TIME_STEP: f64 = 0.0001;
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub(crate) struct Size {
+    pub(crate) width: f64,
+    pub(crate) height: f64,
+}
+
+/// Tuning for the attention model and the virtual camera; defaults match the Swift engine.
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub(crate) struct ZoomConstraints {
+    pub(crate) max_zoom: f64,
+    pub(crate) min_visible_area_fraction: f64,
+    pub(crate) safe_margin_fraction: f64,
+    pub(crate) dwell_duration: f64,
+    pub(crate) dwell_speed_threshold: f64,
+    pub(crate) velocity_smoothing_alpha: f64,
+    pub(crate) max_pan_speed: f64,
+    pub(crate) max_pan_acceleration: f64,
+    pub(crate) idle_zoom: f64,
+    pub(crate) base_zoom: f64,
+    pub(crate) minimum_keyframe_interval: f64,
+    pub(crate) motion_intensity: f64,
+    pub(crate) dwell_intensity: f64,
+    pub(crate) click_intensity: f64,
+}
+
+impl Default for ZoomConstraints {
+    fn default() -> Self {
+        Self {
+            max_zoom: 2.5,
+            min_visible_area_fraction: 0.4,
+            safe_margin_fraction: 0.1,
+            dwell_duration: 0.35,
+            dwell_speed_threshold: 40.0,
+            velocity_smoothing_alpha: 0.2,
+            max_pan_speed: 1400.0,
+            max_pan_acceleration: 3600.0,
+            idle_zoom: 1.05,
+            base_zoom: 1.0,
+            minimum_keyframe_interval: 1.0 / 30.0,
+            motion_intensity: 0.25,
+            dwell_intensity: 0.7,
+            click_intensity: 1.0,
+        }
+    }
+}
+
+impl ZoomConstraints {
+    /// Constraints for the project's auto-zoom settings, scaled the way the Swift export does.
+    /// Settings are clamped like `AutoZoomSettings.clamped()`.
+    pub(crate) fn for_auto_zoom(intensity: f64, minimum_keyframe_interval: f64) -> Self {
+        let intensity = if intensity.is_finite() {
+            intensity
+        } else {
+            1.0
+        };
+        let intensity = intensity.clamp(0.0, 1.0);
+        let interval = if minimum_keyframe_interval.is_finite() {
+            minimum_keyframe_interval
+        } else {
+            1.0 / 30.0
+        };
+        Self {
+            idle_zoom: 1.0 + 0.05 * intensity,
+            minimum_keyframe_interval: interval.clamp(1.0 / 60.0, 1.0 / 10.0),
+            motion_intensity: 0.25 * intensity,
+            dwell_intensity: 0.7 * intensity,
+            click_intensity: intensity,
+            ..Self::default()
+        }
+    }
+
+    /// Constraints for reframing into a narrower aspect ratio: the window never zooms, so it
+    /// spans the source's full height and only pans. `smoothing` slows the pan and spaces the
+    /// keyframes out; `safe_margin_fraction` keeps the cursor that far inside the window.
+    pub(crate) fn for_reframe(smoothing: f64, safe_margin_fraction: f64) -> Self {
+        let smoothing = smoothing.clamp(0.0, 1.0);
+        let defaults = Self::default();
+        Self {
+            max_zoom: 1.0,
+            idle_zoom: 1.0,
+            safe_margin_fraction,
+            max_pan_speed: defaults.max_pan_speed * (1.0 - 0.6 * smoothing),
+            max_pan_acceleration: defaults.max_pan_acceleration * (1.0 - 0.75 * smoothing),
+            minimum_keyframe_interval: 1.0 / 30.0 + 0.5 * smoothing,
+            ..defaults
+        }
+    }
+
+    pub(crate) fn clamped_zoom(&self, zoom: f64) -> f64 {
+        let min_area = self.min_visible_area_fraction.clamp(0.01, 1.0);
+        let max_by_area = 1.0 / min_area;
+        let allowed_max_zoom = self.max_zoom.min(max_by_area);
+        zoom.max(1.0).min(allowed_max_zoom)
+    }
+
+    /// Keeps focus points out of the outer safe margin of the source.
+    pub(crate) fn clamped_target(&self, point: InputPoint, source_size: Size) -> InputPoint {
+        if source_size.width <= 0.0 || source_size.height <= 0.0 {
+            return point;
+        }
+        let margin = self.safe_margin_fraction.clamp(0.0, 0.25);
+        let inset_x = source_size.width * margin;
+        let inset_y = source_size.height * margin;
+        InputPoint {
+            x: point.x.max(inset_x).min(source_size.width - inset_x),
+            y: point.y.max(inset_y).min(source_size.height - inset_y),
+        }
+    }
+
+    /// Moves `center` so the camera view stays inside the source.
+    pub(crate) fn clamped_center(
+        &self,
+        center: InputPoint,
+        source_size: Size,
+        zoom: f64,
+        output_aspect_ratio: Option<f64>,
+    ) -> InputPoint {
+        if source_size.width <= 0.0 || source_size.height <= 0.0 {
+            return center;
+        }
+        let view_size = self.camera_view_size(source_size, zoom, output_aspect_ratio);
+        let half_width = view_size.width / 2.0;
+        let half_height = view_size.height / 2.0;
+        InputPoint {
+            x: center.x.max(half_width).min(source_size.width - half_width),
+            y: center
+                .y
+                .max(half_height)
+                .min(source_size.height - half_height),
+        }
+    }
+
+    /// The camera center closest to `target` that keeps it inside the view's safe margin.
+    pub(crate) fn clamped_center_for_target(
+        &self,
+        target: InputPoint,
+        source_size: Size,
+        zoom: f64,
+        output_aspect_ratio: Option<f64>,
+    ) -> InputPoint {
+        if source_size.width <= 0.0 || source_size.height <= 0.0 {
+            return target;
+        }
+        let view_size = self.camera_view_size(source_size, zoom, output_aspect_ratio);
+        let half_width = view_size.width / 2.0;
+        let half_height = view_size.height / 2.0;
+
+        let margin = self.safe_margin_fraction.clamp(0.0, 0.25);
+        let inner_half_width = (half_width - view_size.width * margin).max(0.0);
+        let inner_half_height = (half_height - view_size.height * margin).max(0.0);
+
+        let min_allowed_x = half_width.max(target.x - inner_half_width);
+        let max_allowed_x = (source_size.width - half_width).min(target.x + inner_half_width);
+        let min_allowed_y = half_height.max(target.y - inner_half_height);
+        let max_allowed_y = (source_size.height - half_height).min(target.y + inner_half_height);
+
+        if min_allowed_x > max_allowed_x || min_allowed_y > max_allowed_y {
+            // Matches Swift, which falls back to source-aspect framing here.
+            return self.clamped_center(target, source_size, zoom, None);
+        }
+        InputPoint {
+            x: target.x.max(min_allowed_x).min(max_allowed_x),
+            y: target.y.max(min_allowed_y).min(max_allowed_y),
+        }
+    }
+
+    /// Source pixels visible at `zoom` for a viewport of `output_aspect_ratio` (the source's
+    /// own aspect ratio when `None`).
+    pub(crate) fn camera_view_size(
+        &self,
+        source_size: Size,
+        zoom: f64,
+        output_aspect_ratio: Option<f64>,
+    ) -> Size {
+        let source_aspect_ratio = source_size.width / source_size.height;
+        let aspect_ratio = output_aspect_ratio
+            .filter(|ratio| ratio.is_finite() && *ratio > 0.0)
+            .unwrap_or(source_aspect_ratio);
+        let base_size = if source_aspect_ratio > aspect_ratio {
+            Size {
+                width: source_size.height * aspect_ratio,
+                height: source_size.height,
+            }
+        } else {
+            Size {
+                width: source_size.width,
+                height: source_size.width / aspect_ratio,
+            }
+        };
+        let zoomed = self.clamped_zoom(zoom);
+        Size {
+            width: base_size.width / zoomed,
+            height: base_size.height / zoomed,
+        }
+    }
+}
+
+/// How strongly the camera should focus on a point at one instant.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct AttentionSample {
+    pub(crate) time: f64,
+    pub(crate) position: InputPoint,
+    pub(crate) intensity: f64,
+    pub(crate) is_dwell: bool,
+    pub(crate) is_click: bool,
+}
+
+/// Scores input events: clicks outrank dwells (the cursor resting), which outrank motion.
+pub(crate) fn attention_samples(
+    events: &[InputEvent],
+    constraints: &ZoomConstraints,
+) -> Vec<AttentionSample> {
+    let mut ordered = events.to_vec();
+    ordered.sort_by(|left, right| {
+        left.timestamp
+            .partial_cmp(&right.timestamp)
+            .unwrap_or(Ordering::Equal)
+    });
+
+    let mut samples = Vec::with_capacity(ordered.len());
+    let mut previous_move: Option<&InputEvent> = None;
+    let mut smoothed_speed = 0.0;
+    let mut dwell_start: Option<f64> = None;
+    let mut is_dwell = false;
+
+    for event in &ordered {
+        let is_click = matches!(
+            event.event_type,
+            InputEventType::MouseDown | InputEventType::MouseUp
+        );
+        if event.event_type == InputEventType::CursorMoved {
+            if let Some(previous) = previous_move {
+                let delta_time = (event.timestamp - previous.timestamp).max(MINIMUM_TIME_STEP);
+                let speed = distance(previous.position, event.position) / delta_time;
+                let alpha = constraints.velocity_smoothing_alpha.clamp(0.0, 1.0);
+                smoothed_speed = alpha * speed + (1.0 - alpha) * smoothed_speed;
+            }
+            previous_move = Some(event);
+
+            let threshold = constraints.dwell_speed_threshold.max(0.01);
+            if smoothed_speed < threshold {
+                let start = *dwell_start.get_or_insert(event.timestamp);
+                if event.timestamp - start >= constraints.dwell_duration {
+                    is_dwell = true;
+                }
+            } else {
+                dwell_start = None;
+                is_dwell = false;
+            }
+        }
+
+        let intensity = if is_click {
+            constraints.click_intensity
+        } else if is_dwell {
+            constraints.dwell_intensity
+        } else {
+            constraints.motion_intensity
+        };
+        samples.push(AttentionSample {
+            time: event.timestamp,
+            position: event.position,
+            intensity: intensity.clamp(0.0, 1.0),
+            is_dwell,
+            is_click,
+        });
+    }
+
+    coalesce_by_time(samples, |sample| sample.time, pick_best_sample)
+}
+
+fn sample_priority(sample: &AttentionSample) -> u8 {
+    if sample.is_click {
+        2
+    } else if sample.is_dwell {
+        1
+    } else {
+        0
+    }
+}
+
+fn pick_best_sample(first: AttentionSample, second: AttentionSample) -> AttentionSample {
+    pick_best(
+        first,
+        second,
+        sample_priority,
+        |sample: &AttentionSample| sample.intensity,
+    )
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub(crate) struct CameraKeyframe {
+    pub(crate) time: f64,
+    pub(crate) center: InputPoint,
+    pub(crate) zoom: f64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct CameraPlan {
+    pub(crate) source_size: Size,
+    /// Aspect ratio used to derive the camera viewport; `None` frames the full source.
+    pub(crate) output_aspect_ratio: Option<f64>,
+    pub(crate) keyframes: Vec<CameraKeyframe>,
+    pub(crate) duration: f64,
+}
+
+/// Plans camera keyframes that follow the viewer's attention through a recording.
+pub(crate) fn plan_camera(
+    events: &[InputEvent],
+    source_size: Size,
+    duration: f64,
+    output_size: Option<Size>,
+    constraints: &ZoomConstraints,
+) -> CameraPlan {
+    let latest_event = events
+        .iter()
+        .map(|event| event.timestamp)
+        .reduce(f64::max)
+        .unwrap_or(0.0);
+    let duration = duration.max(latest_event);
+    let output_aspect_ratio = valid_aspect_ratio(output_size);
+    let samples = attention_samples(events, constraints);
+
+    if samples.is_empty() {
+        let center = midpoint(source_size);
+        let zoom = constraints.clamped_zoom(constraints.idle_zoom);
+        return CameraPlan {
+            source_size,
+            output_aspect_ratio,
+            keyframes: idle_keyframes(center, zoom, duration),
+            duration,
+        };
+    }
+
+    let targets = anchored_targets(&samples, source_size, duration, constraints);
+    let targets = reduce_targets(targets, constraints.minimum_keyframe_interval.max(0.0));
+    let keyframes = targets
+        .iter()
+        .map(|target| keyframe(target, source_size, output_aspect_ratio, constraints))
+        .collect::<Vec<_>>();
+
+    CameraPlan {
+        source_size,
+        output_aspect_ratio,
+        keyframes: apply_pan_constraints(keyframes, source_size, output_aspect_ratio, constraints),
+        duration,
+    }
+}
+
+/// Moves recorded positions from screen coordinates into source pixels, given the screen
+/// rectangle the recording covers.
+pub(crate) fn map_events_to_source(
+    events: &[InputEvent],
+    content_rect: Option<(InputPoint, Size)>,
+    source_size: Size,
+) -> Vec<InputEvent> {
+    let Some((origin, size)) =
+        content_rect.filter(|(_, size)| size.width > 0.0 && size.height > 0.0)
+    else {
+        return events.to_vec();
+    };
+    events
+        .iter()
+        .map(|event| {
+            let normalized_x = ((event.position.x - origin.x) / size.width).clamp(0.0, 1.0);
+            let normalized_y = ((event.position.y - origin.y) / size.height).clamp(0.0, 1.0);
+            InputEvent {
+                position: InputPoint {
+                    x: normalized_x * source_size.width,
+                    y: normalized_y * source_size.height,
+                },
+                ..*event
+            }
+        })
+        .collect()
+}
+
+#[derive(Clone, Copy, Debug)]
+struct FocusTarget {
+    time: f64,
+    position: InputPoint,
+    intensity: f64,
+    is_click: bool,
+    is_dwell: bool,
+    is_anchor: bool,
+}
+
+impl FocusTarget {
+    fn with_anchor(self) -> Self {
+        Self {
+            is_anchor: true,
+            ..self
+        }
+    }
+}
+
+/// Pins the plan to the start and end of the recording.
+fn anchored_targets(
+    samples: &[AttentionSample],
+    source_size: Size,
+    duration: f64,
+    constraints: &ZoomConstraints,
+) -> Vec<FocusTarget> {
+    let mut targets = samples
+        .iter()
+        .map(|sample| FocusTarget {
+            time: sample.time,
+            position: sample.position,
+            intensity: sample.intensity,
+            is_click: sample.is_click,
+            is_dwell: sample.is_dwell,
+            is_anchor: false,
+        })
+        .collect::<Vec<_>>();
+    let minimum_interval = constraints.minimum_keyframe_interval.max(0.0);
+
+    if let Some(first) = targets.first().copied() {
+        if first.time == 0.0 {
+            targets[0] = first.with_anchor();
+        } else {
+            let use_first = first.time <= minimum_interval;
+            targets.insert(
+                0,
+                FocusTarget {
+                    time: 0.0,
+                    position: if use_first {
+                        first.position
+                    } else {
+                        midpoint(source_size)
+                    },
+                    intensity: if use_first { first.intensity } else { 0.0 },
+                    is_click: use_first && first.is_click,
+                    is_dwell: use_first && first.is_dwell,
+                    is_anchor: true,
+                },
+            );
+        }
+    }
+
+    if let Some(last) = targets.last().copied() {
+        if last.time == duration {
+            let index = targets.len() - 1;
+            targets[index] = last.with_anchor();
+        } else {
+            let use_last = duration - last.time <= minimum_interval;
+            targets.push(FocusTarget {
+                time: duration,
+                position: last.position,
+                intensity: last.intensity,
+                is_click: use_last && last.is_click,
+                is_dwell: use_last && last.is_dwell,
+                is_anchor: true,
+            });
+        }
+    }
+
+    targets
+}
+
+fn idle_keyframes(center: InputPoint, zoom: f64, duration: f64) -> Vec<CameraKeyframe> {
+    let start = CameraKeyframe {
+        time: 0.0,
+        center,
+        zoom,
+    };
+    if duration <= 0.0 {
+        return vec![start];
+    }
+    vec![
+        start,
+        CameraKeyframe {
+            time: duration,
+            ..start
+        },
+    ]
+}
+
+fn valid_aspect_ratio(output_size: Option<Size>) -> Option<f64> {
+    output_size
+        .filter(|size| {
+            size.width.is_finite()
+                && size.height.is_finite()
+                && size.width > 0.0
+                && size.height > 0.0
+        })
+        .map(|size| size.width / size.height)
+}
+
+fn keyframe(
+    target: &FocusTarget,
+    source_size: Size,
+    output_aspect_ratio: Option<f64>,
+    constraints: &ZoomConstraints,
+) -> CameraKeyframe {
+    let base_zoom = constraints.base_zoom.max(1.0);
+    let max_zoom = constraints.clamped_zoom(constraints.max_zoom);
+    let intensity = target.intensity.clamp(0.0, 1.0);
+    let zoom = constraints.clamped_zoom(base_zoom + (max_zoom - base_zoom) * intensity);
+    let clamped_target = constraints.clamped_target(target.position, source_size);
+    CameraKeyframe {
+        time: target.time,
+        center: constraints.clamped_center_for_target(
+            clamped_target,
+            source_size,
+            zoom,
+            output_aspect_ratio,
+        ),
+        zoom,
+    }
+}
+
+/// Limits how fast the camera pans and how quickly the pan speed changes.
+fn apply_pan_constraints(
+    keyframes: Vec<CameraKeyframe>,
+    source_size: Size,
+    output_aspect_ratio: Option<f64>,
+    constraints: &ZoomConstraints,
+) -> Vec<CameraKeyframe> {
+    if keyframes.len() <= 1 {
+        return keyframes;
+    }
+    let mut adjusted: Vec<CameraKeyframe> = Vec::with_capacity(keyframes.len());
+    let mut previous_velocity = InputPoint { x: 0.0, y: 0.0 };
+
+    for frame in keyframes {
+        let Some(last) = adjusted.last().copied() else {
+            adjusted.push(CameraKeyframe {
+                center: constraints.clamped_center(
+                    frame.center,
+                    source_size,
+                    frame.zoom,
+                    output_aspect_ratio,
+                ),
+                ..frame
+            });
+            continue;
+        };
+
+        let delta_time = (frame.time - last.time).max(MINIMUM_TIME_STEP);
+        let mut desired_center = frame.center;
+        let mut delta = subtract(desired_center, last.center);
+        let max_distance = constraints.max_pan_speed * delta_time;
+        if magnitude(delta) > max_distance {
+            delta = scaled_to(delta, max_distance);
+            desired_center = add(last.center, delta);
+        }
+
+        let mut velocity = divide(delta, delta_time);
+        let velocity_delta = subtract(velocity, previous_velocity);
+        let max_velocity_delta = constraints.max_pan_acceleration * delta_time;
+        if magnitude(velocity_delta) > max_velocity_delta {
+            velocity = add(
+                previous_velocity,
+                scaled_to(velocity_delta, max_velocity_delta),
+            );
+            desired_center = add(last.center, scale(velocity, delta_time));
+        }
+
+        adjusted.push(CameraKeyframe {
+            center: constraints.clamped_center(
+                desired_center,
+                source_size,
+                frame.zoom,
+                output_aspect_ratio,
+            ),
+            ..frame
+        });
+        previous_velocity = velocity;
+    }
+
+    adjusted
+}
+
+/// Keeps the best target in each `minimum_interval` bucket.
+fn reduce_targets(targets: Vec<FocusTarget>, minimum_interval: f64) -> Vec<FocusTarget> {
+    let time = |target: &FocusTarget| target.time;
+    if minimum_interval <= 0.0 {
+        return coalesce_by_time(targets, time, pick_best_target);
+    }
+    let mut targets = targets.into_iter();
+    let Some(first) = targets.next() else {
+        return Vec::new();
+    };
+
+    let mut reduced = Vec::new();
+    let mut bucket_start = first.time;
+    let mut bucket_best = first;
+    for target in targets {
+        if target.time - bucket_start < minimum_interval {
+            bucket_best = pick_best_target(bucket_best, target);
+        } else {
+            reduced.push(bucket_best);
+            bucket_start = target.time;
+            bucket_best = target;
+        }
+    }
+    reduced.push(bucket_best);
+    coalesce_by_time(reduced, time, pick_best_target)
+}
+
+fn target_priority(target: &FocusTarget) -> u8 {
+    if target.is_anchor {
+        3
+    } else if target.is_click {
+        2
+    } else if target.is_dwell {
+        1
+    } else {
+        0
+    }
+}
+
+fn pick_best_target(first: FocusTarget, second: FocusTarget) -> FocusTarget {
+    pick_best(first, second, target_priority, |target: &FocusTarget| {
+        target.intensity
+    })
+}
+
+/// Higher priority wins, then higher intensity; on a full tie the later item wins.
+fn pick_best<T>(first: T, second: T, priority: fn(&T) -> u8, intensity: fn(&T) -> f64) -> T {
+    let (first_priority, second_priority) = (priority(&first), priority(&second));
+    if second_priority > first_priority
+        || (second_priority == first_priority && intensity(&second) >= intensity(&first))
+    {
+        second
+    } else {
+        first
+    }
+}
+
+/// Merges consecutive items with the same timestamp, keeping the best of each run.
+fn coalesce_by_time<T: Copy>(items: Vec<T>, time: fn(&T) -> f64, best: fn(T, T) -> T) -> Vec<T> {
+    let mut items = items.into_iter();
+    let Some(mut current) = items.next() else {
+        return Vec::new();
+    };
+    let mut result = Vec::new();
+    for item in items {
+        if time(&item) == time(&current) {
+            current = best(current, item);
+        } else {
+            result.push(current);
+            current = item;
+        }
+    }
+    result.push(current);
+    result
+}
+
+fn midpoint(size: Size) -> InputPoint {
+    InputPoint {
+        x: size.width / 2.0,
+        y: size.height / 2.0,
+    }
+}
+
+fn distance(start: InputPoint, end: InputPoint) -> f64 {
+    magnitude(subtract(start, end))
+}
+
+fn add(left: InputPoint, right: InputPoint) -> InputPoint {
+    InputPoint {
+        x: left.x + right.x,
+        y: left.y + right.y,
+    }
+}
+
+fn subtract(left: InputPoint, right: InputPoint) -> InputPoint {
+    InputPoint {
+        x: left.x - right.x,
+        y: left.y - right.y,
+    }
+}
+
+fn scale(point: InputPoint, factor: f64) -> InputPoint {
+    InputPoint {
+        x: point.x * factor,
+        y: point.y * factor,
+    }
+}
+
+fn divide(point: InputPoint, divisor: f64) -> InputPoint {
+    InputPoint {
+        x: point.x / divisor,
+        y: point.y / divisor,
+    }
+}
+
+fn magnitude(point: InputPoint) -> f64 {
+    (point.x * point.x + point.y * point.y).sqrt()
+}
+
+fn scaled_to(point: InputPoint, length: f64) -> InputPoint {
+    let current = magnitude(point);
+    if current > 0.0 {
+        scale(point, length / current)
+    } else {
+        InputPoint { x: 0.0, y: 0.0 }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        attention_samples, map_events_to_source, plan_camera, AttentionSample, CameraKeyframe,
+        Size, ZoomConstraints,
+    };
+    use crate::input_tracking::{InputEvent, InputEventType, InputPoint};
+    use serde::Deserialize;
+
+    const PARITY_FIXTURES: &str =
+        include_str!("../../../docs/fixtures/auto-zoom/planner-parity.json");
+
+    #[derive(Deserialize)]
+    struct ParityFixtures {
+        cases: Vec<ParityCase>,
+    }
+
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct ParityCase {
+        name: String,
+        constraints: ZoomConstraints,
+        source_size: Size,
+        output_size: Option<Size>,
+        duration: f64,
+        events: Vec<InputEvent>,
+        expected: ParityExpectation,
+    }
+
+    #[derive(Deserialize)]
+    struct ParityExpectation {
+        samples: Vec<AttentionSample>,
+        plan: ExpectedPlan,
+    }
+
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct ExpectedPlan {
+        duration: f64,
+        output_aspect_ratio: Option<f64>,
+        keyframes: Vec<CameraKeyframe>,
+    }
+
+    fn assert_close(actual: f64, expected: f64, context: &str) {
+        assert!(
+            (actual - expected).abs() <= 1e-9 * expected.abs().max(1.0),
+            "{context}: expected {expected}, got {actual}"
+        );
+    }
+
+    #[test]
+    fn planner_matches_the_shared_swift_parity_fixtures() {
+        let fixtures: ParityFixtures =
+            serde_json::from_str(PARITY_FIXTURES).expect("parity fixtures decode");
+        assert!(!fixtures.cases.is_empty());
+        for case in fixtures.cases {
+            let name = case.name.as_str();
+            let samples = attention_samples(&case.events, &case.constraints);
+            assert_eq!(
+                samples.len(),
+                case.expected.samples.len(),
+                "{name}: samples"
+            );
+            for (index, (actual, expected)) in
+                samples.iter().zip(&case.expected.samples).enumerate()
+            {
+                let context = format!("{name}: sample {index}");
+                assert_close(actual.time, expected.time, &context);
+                assert_close(actual.position.x, expected.position.x, &context);
+                assert_close(actual.position.y, expected.position.y, &context);
+                assert_close(actual.intensity, expected.intensity, &context);
+                assert_eq!(actual.is_dwell, expected.is_dwell, "{context}");
+                assert_eq!(actual.is_click, expected.is_click, "{context}");
+            }
+
+            let plan = plan_camera(
+                &case.events,
+                case.source_size,
+                case.duration,
+                case.output_size,
+                &case.constraints,
+            );
+            assert_close(plan.duration, case.expected.plan.duration, name);
+            assert_eq!(
+                plan.output_aspect_ratio.is_some(),
+                case.expected.plan.output_aspect_ratio.is_some(),
+                "{name}: outputAspectRatio"
+            );
+            if let (Some(actual), Some(expected)) = (
+                plan.output_aspect_ratio,
+                case.expected.plan.output_aspect_ratio,
+            ) {
+                assert_close(actual, expected, name);
+            }
+            assert_eq!(
+                plan.keyframes.len(),
+                case.expected.plan.keyframes.len(),
+                "{name}: keyframes"
+            );
+            for (index, (actual, expected)) in plan
+                .keyframes
+                .iter()
+                .zip(&case.expected.plan.keyframes)
+                .enumerate()
+            {
+                let context = format!("{name}: keyframe {index}");
+                assert_close(actual.time, expected.time, &context);
+                assert_close(actual.center.x, expected.center.x, &context);
+                assert_close(actual.center.y, expected.center.y, &context);
+                assert_close(actual.zoom, expected.zoom, &context);
+            }
+        }
+    }
+
+    #[test]
+    fn auto_zoom_settings_scale_intensities_and_clamp_the_keyframe_interval() {
+        let constraints = ZoomConstraints::for_auto_zoom(0.5, 0.15);
+        assert_close(constraints.idle_zoom, 1.025, "idleZoom");
+        assert_close(constraints.motion_intensity, 0.125, "motionIntensity");
+        assert_close(constraints.dwell_intensity, 0.35, "dwellIntensity");
+        assert_close(constraints.click_intensity, 0.5, "clickIntensity");
+        assert_close(constraints.minimum_keyframe_interval, 0.1, "interval");
+        assert_eq!(constraints.max_zoom, ZoomConstraints::default().max_zoom);
+
+        let constraints = ZoomConstraints::for_auto_zoom(f64::NAN, f64::INFINITY);
+        assert_close(constraints.click_intensity, 1.0, "clickIntensity");
+        assert_close(
+            constraints.minimum_keyframe_interval,
+            1.0 / 30.0,
+            "interval",
+        );
+    }
+
+    #[test]
+    fn reframing_pans_a_full_height_window_that_slows_down_with_smoothing() {
+        let source = Size {
+            width: 1920.0,
+            height: 1080.0,
+        };
+        let vertical = Size {
+            width: 1080.0,
+            height: 1920.0,
+        };
+        // A one-second sweep across the screen, then the cursor rests on the right.
+        let events = (0..=60)
+            .map(|step| InputEvent {
+                event_type: InputEventType::CursorMoved,
+                timestamp: step as f64 / 60.0,
+                position: InputPoint {
+                    x: 200.0 + 25.0 * step as f64,
+                    y: 540.0,
+                },
+                button: None,
+            })
+            .collect::<Vec<_>>();
+        let fastest_pan = |smoothing: f64| {
+            let constraints = ZoomConstraints::for_reframe(smoothing, 0.15);
+            let plan = plan_camera(&events, source, 3.0, Some(vertical), &constraints);
+            assert_close(plan.output_aspect_ratio.expect("aspect"), 0.5625, "aspect");
+            assert!(plan.keyframes.iter().all(|keyframe| keyframe.zoom == 1.0));
+            assert!(plan
+                .keyframes
+                .iter()
+                .all(|keyframe| keyframe.center.y == 540.0));
+            let last = plan.keyframes.last().expect("last keyframe");
+            assert_eq!(last.time, 3.0);
+            assert!(last.center.x > 1300.0, "settled at {}", last.center.x);
+            plan.keyframes
+                .windows(2)
+                .map(|pair| {
+                    (pair[1].center.x - pair[0].center.x).abs() / (pair[1].time - pair[0].time)
+                })
+                .fold(0.0, f64::max)
+        };
+        let responsive = fastest_pan(0.0);
+        let smoothed = fastest_pan(1.0);
+        assert!(smoothed <= 1400.0 * 0.4 + 1e-6, "smoothed pan {smoothed}");
+        assert!(smoothed < responsive, "{smoothed} vs {responsive}");
+    }
+
+    #[test]
+    fn events_are_mapped_from_screen_coordinates_into_source_pixels() {
+        let event = |x: f64, y: f64| InputEvent {
+            event_type: InputEventType::CursorMoved,
+            timestamp: 0.5,
+            position: InputPoint { x, y },
+            button: None,
+        };
+        let region = Some((
+            InputPoint { x: 320.0, y: 180.0 },
+            Size {
+                width: 1280.0,
+                height: 720.0,
+            },
+        ));
+        let source = Size {
+            width: 640.0,
+            height: 360.0,
+        };
+        let mapped =
+            map_events_to_source(&[event(960.0, 540.0), event(0.0, 2000.0)], region, source);
+        assert_eq!(mapped[0].position, InputPoint { x: 320.0, y: 180.0 });
+        assert_eq!(mapped[1].position, InputPoint { x: 0.0, y: 360.0 });
+        assert_eq!(mapped[0].timestamp, 0.5);
+
+        let unmapped = map_events_to_source(&[event(5.0, 6.0)], None, source);
+        assert_eq!(unmapped[0].position, InputPoint { x: 5.0, y: 6.0 });
+    }
+}
@@ -0,0 +1,420 @@
+use crate::automation::{CameraKeyframe, CameraPlan};
+use crate::camera_path::CameraEasing;
+use crate::export::{auto_zoom_plan, recorded_input, DEFAULT_EXPORT_PRESET};
+use crate::input_tracking::InputPoint;
+use crate::params::{CameraKeyframesParams, MAX_CAMERA_KEYFRAMES, MAX_CAMERA_ZOOM};
+use crate::state::State;
+use crate::wire::{
+    decode_params, failure, success, EngineCallId, EngineResponse, ProtocolErrorCode,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::HashSet;
+
+/// Planned keyframes this close to an override or a deleted auto keyframe are dropped when the
+/// planner re-runs: one frame of the export preset.
+const OVERRIDE_TOLERANCE_SECONDS: f64 = 1.0 / 30.0;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) enum CameraKeyframeOrigin {
+    /// Planned by auto-zoom; replaced when the planner re-runs unless locked.
+    Auto,
+    /// Added by the user.
+    Manual,
+}
+
+/// One keyframe of the project's camera track.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct TrackKeyframe {
+    pub(crate) id: String,
+    pub(crate) time: f64,
+    pub(crate) center: InputPoint,
+    pub(crate) zoom: f64,
+    /// Motion from this keyframe to the next.
+    #[serde(default)]
+    pub(crate) easing: CameraEasing,
+    pub(crate) origin: CameraKeyframeOrigin,
+    #[serde(default)]
+    pub(crate) locked: bool,
+}
+
+impl TrackKeyframe {
+    /// Keyframes the user added or kept, which planner re-runs leave alone.
+    fn is_override(&self) -> bool {
+        self.origin == CameraKeyframeOrigin::Manual || self.locked
+    }
+}
+
+/// A change to the keyframes inside a time range.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(
+    tag = "action",
+    rename_all = "camelCase",
+    rename_all_fields = "camelCase"
+)]
+pub(crate) enum CameraKeyframeEdit {
+    /// Adds a manual keyframe, which must lie inside the range.
+    Add {
+        time: f64,
+        center: InputPoint,
+        zoom: f64,
+        #[serde(default)]
+        easing: CameraEasing,
+    },
+    Delete,
+    /// Keeps auto keyframes through planner re-runs; manual keyframes always are.
+    Lock,
+    Unlock,
+    /// Changing an auto keyframe's easing locks it, so the change survives re-runs.
+    SetEasing {
+        easing: CameraEasing,
+    },
+}
+
+/// The camera keyframes of the project timeline: auto-zoom's plan merged with the user's
+/// overrides, applied by export instead of planning from scratch.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub(crate) struct CameraKeyframeTrack {
+    /// Ordered by time.
+    pub(crate) keyframes: Vec<TrackKeyframe>,
+    /// Times of auto keyframes the user deleted, so re-runs do not bring them back.
+    pub(crate) deleted_auto_times: Vec<f64>,
+}
+
+impl CameraKeyframeTrack {
+    pub(crate) fn validated(self) -> Result<Self, &'static str> {
+        let mut ids = HashSet::new();
+        let valid = self.keyframes.iter().all(|keyframe| {
+            !keyframe.id.is_empty()
+                && ids.insert(keyframe.id.as_str())
+                && keyframe.time.is_finite()
+                && keyframe.time >= 0.0
+                && keyframe.center.x.is_finite()
+                && keyframe.center.y.is_finite()
+                && (1.0..=MAX_CAMERA_ZOOM).contains(&keyframe.zoom)
+        }) && self
+            .keyframes
+            .windows(2)
+            .all(|pair| pair[0].time <= pair[1].time)
+            && self.deleted_auto_times.iter().all(|time| time.is_finite());
+        if !valid {
+            return Err(
+                "timeline.cameraKeyframes need unique ids, ordered non-negative times and zoom between 1 and 10",
+            );
+        }
+        Ok(self)
+    }
+
+    pub(crate) fn camera_keyframes(&self) -> Vec<CameraKeyframe> {
+        self.keyframes
+            .iter()
+            .map(|keyframe| CameraKeyframe {
+                time: keyframe.time,
+                center: keyframe.center,
+                zoom: keyframe.zoom,
+            })
+            .collect()
+    }
+
+    /// Easing leaving each keyframe, in keyframe order.
+    pub(crate) fn segment_easings(&self) -> Vec<CameraEasing> {
+        self.keyframes
+            .iter()
+            .map(|keyframe| keyframe.easing)
+            .collect()
+    }
+
+    fn in_range(keyframe: &TrackKeyframe, start: f64, end: f64) -> bool {
+        (start..=end).contains(&keyframe.time)
+    }
+
+    fn next_id(&self) -> String {
+        (self.keyframes.len() + 1..)
+            .map(|number| format!("keyframe-{number}"))
+            .find(|id| !self.keyframes.iter().any(|keyframe| &keyframe.id == id))
+            .unwrap_or_default()
+    }
+
+    fn insert(&mut self, keyframe: TrackKeyframe) {
+        let index = self
+            .keyframes
+            .partition_point(|existing| existing.time <= keyframe.time);
+        self.keyframes.insert(index, keyframe);
+    }
+
+    /// Replaces the auto keyframes with a fresh plan, keeping every override. Planned keyframes
+    /// that would land on an override or on a deleted auto keyframe are skipped.
+    pub(crate) fn replan(&mut self, plan: Option<&CameraPlan>) {
+        self.keyframes.retain(TrackKeyframe::is_override);
+        let near = |times: &[f64], time: f64| {
+            times
+                .iter()
+                .any(|other| (other - time).abs() < OVERRIDE_TOLERANCE_SECONDS)
+        };
+        let overrides: Vec<f64> = self
+            .keyframes
+            .iter()
+            .map(|keyframe| keyframe.time)
+            .collect();
+        for planned in plan.map_or(&[][..], |plan| &plan.keyframes) {
+            if near(&overrides, planned.time) || near(&self.deleted_auto_times, planned.time) {
+                continue;
+            }
+            let keyframe = TrackKeyframe {
+                id: self.next_id(),
+                time: planned.time,
+                center: planned.center,
+                zoom: planned.zoom,
+                easing: CameraEasing::default(),
+                origin: CameraKeyframeOrigin::Auto,
+                locked: false,
+            };
+            self.insert(keyframe);
+        }
+    }
+
+    /// Applies one edit to the keyframes between `start` and `end`, inclusive.
+    pub(crate) fn apply(
+        &mut self,
+        start: f64,
+        end: f64,
+        edit: &CameraKeyframeEdit,
+    ) -> Result<(), &'static str> {
+        match *edit {
+            CameraKeyframeEdit::Add {
+                time,
+                center,
+                zoom,
+                easing,
+            } => {
+                if !(time.is_finite() && (start..=end).contains(&time)) {
+                    return Err("Added camera keyframes must lie inside the edited range");
+                }
+                if !(center.x.is_finite()
+                    && center.y.is_finite()
+                    && (1.0..=MAX_CAMERA_ZOOM).contains(&zoom))
+                {
+                    return Err(
+                        "Added camera keyframes need a finite center and zoom between 1 and 10",
+                    );
+                }
+                if self.keyframes.len() >= MAX_CAMERA_KEYFRAMES {
+                    return Err("The camera track must not contain more than 10000 keyframes");
+                }
+                let keyframe = TrackKeyframe {
+                    id: self.next_id(),
+                    time,
+                    center,
+                    zoom,
+                    easing,
+                    origin: CameraKeyframeOrigin::Manual,
+                    locked: false,
+                };
+                self.insert(keyframe);
+            }
+            CameraKeyframeEdit::Delete => {
+                let deleted_auto_times = self
+                    .keyframes
+                    .iter()
+                    .filter(|keyframe| {
+                        Self::in_range(keyframe, start, end)
+                            && keyframe.origin == CameraKeyframeOrigin::Auto
+                    })
+                    .map(|keyframe| keyframe.time)
+                    .collect::<Vec<_>>();
+                self.deleted_auto_times.extend(deleted_auto_times);
+                self.keyframes
+                    .retain(|keyframe| !Self::in_range(keyframe, start, end));
+            }
+            CameraKeyframeEdit::Lock | CameraKeyframeEdit::Unlock => {
+                let locked = matches!(edit, CameraKeyframeEdit::Lock);
+                for keyframe in &mut self.keyframes {
+                    if Self::in_range(keyframe, start, end) {
+                        keyframe.locked = locked;
+                    }
+                }
+            }
+            CameraKeyframeEdit::SetEasing { easing } => {
+                for keyframe in &mut self.keyframes {
+                    if Self::in_range(keyframe, start, end) {
+                        keyframe.easing = easing;
+                        keyframe.locked |= keyframe.origin == CameraKeyframeOrigin::Auto;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The requested range; an open end covers the rest of the track.
+fn time_range(params: &CameraKeyframesParams) -> Result<(f64, f64), &'static str> {
+    let start = params.start_seconds.unwrap_or(0.0);
+    let end = params.end_seconds.unwrap_or(f64::INFINITY);
+    if !(start.is_finite() && start >= 0.0 && !end.is_nan() && start <= end) {
+        return Err("startSeconds and endSeconds must be non-negative and ordered");
+    }
+    Ok((start, end))
+}
+
+fn keyframes_result(state: &State, start: f64, end: f64) -> Value {
+    let track = &state.camera_keyframes;
+    json!({
+        "startSeconds": start,
+        "endSeconds": end.is_finite().then_some(end),
+        "keyframes": track
+            .keyframes
+            .iter()
+            .filter(|keyframe| CameraKeyframeTrack::in_range(keyframe, start, end))
+            .collect::<Vec<_>>(),
+        "keyframeCount": track.keyframes.len(),
+    })
+}
+
+pub(crate) fn list(id: &EngineCallId, state: &State, params: &Value) -> EngineResponse {
+    let keyframe_params =
+        match decode_params::<CameraKeyframesParams>(id, params, "camera keyframes") {
+            Ok(params) => params,
+            Err(response) => return response,
+        };
+    match time_range(&keyframe_params) {
+        Ok((start, end)) => success(id, keyframes_result(state, start, end)),
+        Err(error) => failure(id, ProtocolErrorCode::InvalidParams, error),
+    }
+}
+
+/// Applies the edits in order; nothing changes unless all of them apply.
+pub(crate) fn edit(id: &EngineCallId, state: &mut State, params: &Value) -> EngineResponse {
+    let keyframe_params =
+        match decode_params::<CameraKeyframesParams>(id, params, "camera keyframe edit") {
+            Ok(params) => params,
+            Err(response) => return response,
+        };
+    if keyframe_params.start_seconds.is_none() || keyframe_params.end_seconds.is_none() {
+        return failure(
+            id,
+            ProtocolErrorCode::InvalidParams,
+            "startSeconds and endSeconds are required",
+        );
+    }
+    let (start, end) = match time_range(&keyframe_params) {
+        Ok(range) => range,
+        Err(error) => return failure(id, ProtocolErrorCode::InvalidParams, error),
+    };
+    let mut track = state.camera_keyframes.clone();
+    for edit in &keyframe_params.edits {
+        if let Err(error) = track.apply(start, end, edit) {
+            return failure(id, ProtocolErrorCode::InvalidParams, error);
+        }
+    }
+    state.camera_keyframes = track;
+    state.unsaved_changes = true;
+    success(id, keyframes_result(state, start, end))
+}
+
+/// Re-runs auto-zoom over the active recording with the project settings, keeping overrides.
+pub(crate) fn replan(id: &EngineCallId, state: &mut State) -> EngineResponse {
+    let events = match recorded_input(state) {
+        Ok(input) => input.map(|input| input.events).unwrap_or_default(),
+        Err(error) => return failure(id, ProtocolErrorCode::RuntimeError, error),
+    };
+    let plan = auto_zoom_plan(
+        state,
+        Default::default(),
+        &events,
+        DEFAULT_EXPORT_PRESET.size(),
+    );
+    state.camera_keyframes.replan(plan.as_ref());
+    state.unsaved_changes = true;
+    success(id, keyframes_result(state, 0.0, f64::INFINITY))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CameraKeyframeEdit, CameraKeyframeOrigin, CameraKeyframeTrack};
+    use crate::automation::{CameraKeyframe, CameraPlan, Size};
+    use crate::camera_path::CameraEasing;
+    use crate::input_tracking::InputPoint;
+
+    fn plan(times: &[f64]) -> CameraPlan {
+        CameraPlan {
+            source_size: Size {
+                width: 1920.0,
+                height: 1080.0,
+            },
+            output_aspect_ratio: None,
+            keyframes: times
+                .iter()
+                .map(|&time| CameraKeyframe {
+                    time,
+                    center: InputPoint { x: 960.0, y: 540.0 },
+                    zoom: 1.5,
+                })
+                .collect(),
+            duration: 4.0,
+        }
+    }
+
+    fn times(track: &CameraKeyframeTrack) -> Vec<(f64, CameraKeyframeOrigin)> {
+        track
+            .keyframes
+            .iter()
+            .map(|keyframe| (keyframe.time, keyframe.origin))
+            .collect()
+    }
+
+    #[test]
+    fn replanning_keeps_overrides_and_deletions() {
+        use CameraKeyframeOrigin::{Auto, Manual};
+
+        let mut track = CameraKeyframeTrack::default();
+        track.replan(Some(&plan(&[0.0, 1.0, 2.0, 3.0])));
+        track.apply(1.0, 1.0, &CameraKeyframeEdit::Lock).unwrap();
+        track.apply(2.0, 2.0, &CameraKeyframeEdit::Delete).unwrap();
+        track
+            .apply(
+                2.5,
+                3.5,
+                &CameraKeyframeEdit::Add {
+                    time: 3.0,
+                    center: InputPoint { x: 100.0, y: 100.0 },
+                    zoom: 3.0,
+                    easing: CameraEasing::Linear,
+                },
+            )
+            .unwrap();
+        track
+            .apply(
+                0.0,
+                0.0,
+                &CameraKeyframeEdit::SetEasing {
+                    easing: CameraEasing::Linear,
+                },
+            )
+            .unwrap();
+        assert_eq!(
+            times(&track),
+            vec![(0.0, Auto), (1.0, Auto), (3.0, Auto), (3.0, Manual)]
+        );
+
+        track.replan(Some(&plan(&[0.0, 1.01, 2.0, 3.0, 4.0])));
+        assert_eq!(
+            times(&track),
+            vec![(0.0, Auto), (1.0, Auto), (3.0, Manual), (4.0, Auto)]
+        );
+        assert_eq!(track.keyframes[0].easing, CameraEasing::Linear);
+        assert!(track.keyframes[0].locked && track.keyframes[1].locked);
+
+        track.replan(None);
+        assert_eq!(times(&track), vec![(0.0, Auto), (1.0, Auto), (3.0, Manual)]);
+        let ids = track
+            .keyframes
+            .iter()
+            .map(|keyframe| keyframe.id.as_str())
+            .collect::<std::collections::HashSet<_>>();
+        assert_eq!(ids.len(), 3);
+    }
+}
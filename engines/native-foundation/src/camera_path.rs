@@ -0,0 +1,288 @@
+use crate::automation::{CameraKeyframe, CameraPlan, Size};
+use crate::input_tracking::InputPoint;
+use crate::timeline::TakeFrame;
+use serde::{Deserialize, Serialize};
+
+/// How the camera moves between two keyframes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) enum CameraEasing {
+    Linear,
+    /// Smoothstep: starts and settles gently, so cuts between focus points do not jolt.
+    #[default]
+    EaseInOut,
+}
+
+impl CameraEasing {
+    fn apply(self, progress: f64) -> f64 {
+        let progress = progress.clamp(0.0, 1.0);
+        match self {
+            CameraEasing::Linear => progress,
+            CameraEasing::EaseInOut => progress * progress * (3.0 - 2.0 * progress),
+        }
+    }
+}
+
+/// Where an export's camera keyframes came from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) enum CameraPlanSource {
+    /// Keyframes sent with the export request.
+    Payload,
+    /// Planned from the recording's input events with the auto-zoom settings.
+    AutoZoom,
+    /// The project's camera keyframe track, with the user's overrides.
+    KeyframeTrack,
+    /// A narrower preset's window panned across the recording to follow the cursor.
+    Reframe,
+}
+
+/// The camera plan an export resolved, as recorded in its manifest for the renderer.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct PlannedCameraPlan {
+    pub(crate) source: CameraPlanSource,
+    pub(crate) easing: CameraEasing,
+    /// Easing from each keyframe to the next, where it differs per segment; `easing` applies
+    /// to segments without one.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub(crate) segment_easings: Vec<CameraEasing>,
+    #[serde(flatten)]
+    pub(crate) plan: CameraPlan,
+}
+
+impl PlannedCameraPlan {
+    /// Easing from keyframe `index` to the next.
+    pub(crate) fn segment_easing(&self, index: usize) -> CameraEasing {
+        self.segment_easings
+            .get(index)
+            .copied()
+            .unwrap_or(self.easing)
+    }
+}
+
+/// Camera position at `time`, holding the first and last keyframes outside the plan.
+/// `keyframes` must be sorted by time; `segment_easing` gives the easing leaving keyframe `i`.
+pub(crate) fn sample(
+    keyframes: &[CameraKeyframe],
+    segment_easing: impl Fn(usize) -> CameraEasing,
+    time: f64,
+) -> Option<CameraKeyframe> {
+    let first = keyframes.first()?;
+    let next_index = keyframes.partition_point(|keyframe| keyframe.time <= time);
+    if next_index == 0 {
+        return Some(CameraKeyframe { time, ..*first });
+    }
+    let previous = keyframes[next_index - 1];
+    let Some(next) = keyframes.get(next_index) else {
+        return Some(CameraKeyframe { time, ..previous });
+    };
+    let span = next.time - previous.time;
+    let progress = if span > 0.0 {
+        segment_easing(next_index - 1).apply((time - previous.time) / span)
+    } else {
+        1.0
+    };
+    let mix = |from: f64, to: f64| from + (to - from) * progress;
+    Some(CameraKeyframe {
+        time,
+        center: InputPoint {
+            x: mix(previous.center.x, next.center.x),
+            y: mix(previous.center.y, next.center.y),
+        },
+        zoom: mix(previous.zoom, next.zoom),
+    })
+}
+
+/// The source pixels one export frame shows and the scale that fills the output with them;
+/// background framing is applied to the scaled result.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct CameraCrop {
+    pub(crate) time: f64,
+    pub(crate) x: f64,
+    pub(crate) y: f64,
+    pub(crate) width: f64,
+    pub(crate) height: f64,
+    pub(crate) scale: f64,
+}
+
+/// Viewport of `keyframe` inside the source, like the Swift `cameraViewportRect`: the output
+/// aspect ratio fitted into the source, shrunk by the zoom and kept inside the source.
+pub(crate) fn crop(
+    keyframe: &CameraKeyframe,
+    source_size: Size,
+    output_size: Size,
+    output_aspect_ratio: Option<f64>,
+) -> CameraCrop {
+    let source_aspect_ratio = source_size.width / source_size.height;
+    let aspect_ratio = output_aspect_ratio
+        .filter(|ratio| ratio.is_finite() && *ratio > 0.0)
+        .unwrap_or(source_aspect_ratio);
+    let (base_width, base_height) = if source_aspect_ratio > aspect_ratio {
+        (source_size.height * aspect_ratio, source_size.height)
+    } else {
+        (source_size.width, source_size.width / aspect_ratio)
+    };
+    let zoom = keyframe.zoom.max(1.0);
+    let (width, height) = (base_width / zoom, base_height / zoom);
+    let (half_width, half_height) = (width / 2.0, height / 2.0);
+    let center_x = keyframe
+        .center
+        .x
+        .max(half_width)
+        .min(source_size.width - half_width);
+    let center_y = keyframe
+        .center
+        .y
+        .max(half_height)
+        .min(source_size.height - half_height);
+    CameraCrop {
+        time: keyframe.time,
+        x: center_x - half_width,
+        y: center_y - half_height,
+        width,
+        height,
+        scale: (output_size.width / width).min(output_size.height / height),
+    }
+}
+
+/// One crop per export frame of `frames`, sampled at the take time the frame shows and placed at
+/// its program time.
+pub(crate) fn frame_crops(
+    planned: &PlannedCameraPlan,
+    frames: &[TakeFrame],
+    output_size: Size,
+) -> Vec<CameraCrop> {
+    let plan = &planned.plan;
+    if plan.source_size.width <= 0.0 || plan.source_size.height <= 0.0 {
+        return Vec::new();
+    }
+    frames
+        .iter()
+        .filter_map(|frame| {
+            let keyframe = sample(
+                &plan.keyframes,
+                |index| planned.segment_easing(index),
+                frame.source_time,
+            )?;
+            Some(CameraCrop {
+                time: frame.program_time,
+                ..crop(
+                    &keyframe,
+                    plan.source_size,
+                    output_size,
+                    plan.output_aspect_ratio,
+                )
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{crop, frame_crops, sample, CameraEasing, CameraPlanSource, PlannedCameraPlan};
+    use crate::automation::{CameraKeyframe, CameraPlan, Size};
+    use crate::input_tracking::InputPoint;
+    use crate::timeline::take_frames;
+
+    fn keyframe(time: f64, x: f64, y: f64, zoom: f64) -> CameraKeyframe {
+        CameraKeyframe {
+            time,
+            center: InputPoint { x, y },
+            zoom,
+        }
+    }
+
+    const SOURCE: Size = Size {
+        width: 1920.0,
+        height: 1080.0,
+    };
+
+    #[test]
+    fn sampling_eases_between_keyframes_and_holds_the_ends() {
+        let keyframes = [
+            keyframe(1.0, 960.0, 540.0, 1.0),
+            keyframe(3.0, 1460.0, 540.0, 2.0),
+        ];
+
+        assert_eq!(
+            sample(&keyframes, |_| CameraEasing::EaseInOut, 0.0),
+            Some(keyframe(0.0, 960.0, 540.0, 1.0))
+        );
+        assert_eq!(
+            sample(&keyframes, |_| CameraEasing::EaseInOut, 2.0),
+            Some(keyframe(2.0, 1210.0, 540.0, 1.5))
+        );
+        let eased = sample(&keyframes, |_| CameraEasing::EaseInOut, 1.5).expect("eased sample");
+        let linear = sample(&keyframes, |_| CameraEasing::Linear, 1.5).expect("linear sample");
+        assert_eq!(linear.center.x, 1085.0);
+        assert!((eased.center.x - 1038.125).abs() < 1e-9);
+        assert_eq!(
+            sample(&keyframes, |_| CameraEasing::Linear, 9.0),
+            Some(keyframe(9.0, 1460.0, 540.0, 2.0))
+        );
+        assert_eq!(sample(&[], |_| CameraEasing::Linear, 1.0), None);
+    }
+
+    #[test]
+    fn crops_follow_the_output_aspect_ratio_and_stay_inside_the_source() {
+        let output = Size {
+            width: 1920.0,
+            height: 1080.0,
+        };
+        let zoomed = crop(&keyframe(0.0, 1900.0, 10.0, 2.0), SOURCE, output, None);
+        assert_eq!(
+            (
+                zoomed.x,
+                zoomed.y,
+                zoomed.width,
+                zoomed.height,
+                zoomed.scale
+            ),
+            (960.0, 0.0, 960.0, 540.0, 2.0)
+        );
+
+        let portrait = Size {
+            width: 1080.0,
+            height: 1920.0,
+        };
+        let vertical = crop(
+            &keyframe(0.0, 960.0, 540.0, 1.0),
+            SOURCE,
+            portrait,
+            Some(9.0 / 16.0),
+        );
+        assert_eq!((vertical.width, vertical.height), (607.5, 1080.0));
+        assert_eq!(vertical.x, 960.0 - 607.5 / 2.0);
+    }
+
+    #[test]
+    fn frame_crops_cover_every_output_frame_with_per_segment_easing() {
+        let planned = PlannedCameraPlan {
+            source: CameraPlanSource::KeyframeTrack,
+            easing: CameraEasing::EaseInOut,
+            segment_easings: vec![CameraEasing::Linear],
+            plan: CameraPlan {
+                source_size: SOURCE,
+                output_aspect_ratio: Some(16.0 / 9.0),
+                keyframes: vec![
+                    keyframe(0.0, 960.0, 540.0, 1.0),
+                    keyframe(1.0, 960.0, 540.0, 2.0),
+                    keyframe(2.0, 960.0, 540.0, 1.0),
+                ],
+                duration: 2.0,
+            },
+        };
+        let crops = frame_crops(&planned, &take_frames(&[], None, 30, 2.0), SOURCE);
+        assert_eq!(crops.len(), 60);
+        assert_eq!(crops[0].width, 1920.0);
+        assert_eq!(crops[15].time, 0.5);
+        assert_eq!(crops[15].width, 1920.0 / 1.5);
+        assert!(crops[..30]
+            .windows(2)
+            .all(|pair| pair[1].width <= pair[0].width));
+        // The second segment eases out of the zoom, so it lags the linear path a third in.
+        assert!(crops[40].width < 1920.0 / (2.0 - 1.0 / 3.0));
+    }
+}
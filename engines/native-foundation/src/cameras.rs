@@ -0,0 +1,148 @@
+use crate::pipeline::FrameSource;
+use serde::{Deserialize, Serialize};
+#[cfg(target_os = "linux")]
+use std::path::PathBuf;
+
+/// Webcam frames are recorded to their own track next to the screen recording.
+pub(crate) const CAMERA_TRACK_DIRECTORY: &str = "camera";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum CameraBackend {
+    #[cfg(target_os = "linux")]
+    V4l2(PathBuf),
+    /// Deterministic test pattern, for hosts without a camera or loopback device.
+    Synthetic,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct CameraDevice {
+    pub(crate) id: u64,
+    pub(crate) name: String,
+    pub(crate) backend: CameraBackend,
+}
+
+impl CameraDevice {
+    pub(crate) fn device_path(&self) -> Option<String> {
+        match &self.backend {
+            #[cfg(target_os = "linux")]
+            CameraBackend::V4l2(path) => Some(path.to_string_lossy().into_owned()),
+            CameraBackend::Synthetic => None,
+        }
+    }
+}
+
+/// The camera recording that accompanies the current screen recording.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct CameraTrack {
+    #[serde(rename = "recordingURL")]
+    pub(crate) recording_url: String,
+    pub(crate) width: u32,
+    pub(crate) height: u32,
+}
+
+/// Returns the cameras currently attached to the machine.
+pub(crate) type CameraProbe = fn() -> Vec<CameraDevice>;
+
+/// Set to `1` to list the synthetic camera, e.g. on CI hosts without v4l2loopback.
+const SYNTHETIC_CAMERA_ENV: &str = "GGLASS_SYNTHETIC_CAMERA";
+/// Kept clear of V4L2 ids, which are `/dev/videoN` indices.
+const SYNTHETIC_CAMERA_ID: u64 = 9000;
+
+pub(crate) fn synthetic_camera() -> CameraDevice {
+    CameraDevice {
+        id: SYNTHETIC_CAMERA_ID,
+        name: "Synthetic Camera".to_string(),
+        backend: CameraBackend::Synthetic,
+    }
+}
+
+/// V4L2 capture nodes keyed by their `/dev/videoN` index, plus the opt-in synthetic camera.
+pub(crate) fn available_cameras() -> Vec<CameraDevice> {
+    let mut cameras = platform_cameras();
+    if std::env::var(SYNTHETIC_CAMERA_ENV).is_ok_and(|value| value == "1") {
+        cameras.push(synthetic_camera());
+    }
+    cameras
+}
+
+#[cfg(target_os = "linux")]
+fn platform_cameras() -> Vec<CameraDevice> {
+    crate::v4l2::enumerate_devices()
+        .into_iter()
+        .map(|device| CameraDevice {
+            id: device.index,
+            name: device.name,
+            backend: CameraBackend::V4l2(device.path),
+        })
+        .collect()
+}
+
+#[cfg(not(target_os = "linux"))]
+fn platform_cameras() -> Vec<CameraDevice> {
+    Vec::new()
+}
+
+pub(crate) fn open_source(camera: &CameraDevice) -> Result<Box<dyn FrameSource>, String> {
+    match &camera.backend {
+        CameraBackend::Synthetic => Ok(Box::new(SyntheticCameraSource::default())),
+        #[cfg(target_os = "linux")]
+        CameraBackend::V4l2(path) => Ok(Box::new(crate::v4l2::V4l2CameraSource::open(path)?)),
+    }
+}
+
+/// 4:3 test pattern: a bright disc drifting over a dark backdrop.
+pub(crate) struct SyntheticCameraSource {
+    width: u32,
+    height: u32,
+}
+
+impl Default for SyntheticCameraSource {
+    fn default() -> Self {
+        Self {
+            width: 320,
+            height: 240,
+        }
+    }
+}
+
+impl FrameSource for SyntheticCameraSource {
+    fn dimensions(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+
+    fn capture(&mut self, index: u64) -> Result<Vec<u8>, String> {
+        let (width, height) = (self.width as i64, self.height as i64);
+        let center_x = width / 2 + (index as i64 % 40) - 20;
+        let center_y = height / 2;
+        let radius = height / 3;
+        let mut pixels = Vec::with_capacity((width * height * 3) as usize);
+        for y in 0..height {
+            for x in 0..width {
+                let (dx, dy) = (x - center_x, y - center_y);
+                if dx * dx + dy * dy <= radius * radius {
+                    pixels.extend_from_slice(&[224, 172, 140]);
+                } else {
+                    pixels.extend_from_slice(&[32, 40, (48 + y * 64 / height) as u8]);
+                }
+            }
+        }
+        Ok(pixels)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{open_source, synthetic_camera};
+
+    #[test]
+    fn synthetic_camera_streams_changing_rgb_frames() {
+        let camera = synthetic_camera();
+        assert_eq!(camera.device_path(), None);
+        let mut source = open_source(&camera).expect("open synthetic camera");
+        assert_eq!(source.dimensions(), (320, 240));
+        let first = source.capture(0).expect("first frame");
+        assert_eq!(first.len(), 320 * 240 * 3);
+        assert_ne!(first, source.capture(7).expect("later frame"));
+    }
+}
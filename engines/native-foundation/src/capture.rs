@@ -1,15 +1,44 @@
-use crate::params::{CaptureStartParams, RecordingStartParams};
+use crate::audio::{
+    repair_wav_header, AudioSource, AudioTrack, AudioTrackKind, AudioTrackRecorder, WavWriter,
+    AUDIO_CHANNELS, AUDIO_SAMPLE_RATE, DEFAULT_SILENCE_WARNING_SECONDS,
+};
+use crate::cameras::{self, CameraTrack, CAMERA_TRACK_DIRECTORY};
+use crate::disk::{
+    megabytes, required_recording_bytes, RecordingDiskBudget, LOW_DISK_SPACE_RESERVE_BYTES,
+};
+use crate::input_tracking::{align_to_recording, InputTracker, EVENTS_FILE_NAME};
+use crate::params::{
+    CaptureRectParams, CaptureStartCameraParams, CaptureStartParams, CaptureStartRegionParams,
+    RecordingAddMarkerParams, RecordingStartParams, SettingsGroup, WindowExclusionParams,
+    DEFAULT_MARKER_COLOR,
+};
+use crate::path_security::write_file_no_symlink;
+use crate::pipeline::{
+    CapturePipeline, FrameSource, SurfaceRect, SyntheticFrameSource, WriterFailure,
+};
+use crate::privacy_mask::{MaskedFrameSource, PrivacyMask};
+use crate::recording::{
+    RecordingMarker, RecordingMetadata, RecordingPauseRange, RecordingSchedule, RecordingWindow,
+};
+use crate::recording_journal::{
+    finalize_recording_directory, unfinished_recording_directories, FinalizedRecording,
+    JournaledRecordingWriter, RECORDINGS_DIRECTORY,
+};
+use crate::source_monitor::{CapturedSource, SourceChange};
+use crate::sources::{self, DisplaySource};
 use crate::state::State;
-use crate::wire::{failure, success, EngineCallId, EngineResponse, ProtocolErrorCode};
+use crate::takes::{self, PLACEHOLDER_RECORDING_PREFIX};
+use crate::wire::{
+    decode_params, failure, success, EngineCallId, EngineError, EngineResponse, ProtocolErrorCode,
+    RunningDuration,
+};
 use crate::DEFAULT_CAPTURE_FRAME_RATES;
 use serde_json::{json, Value};
-
-fn decode_params<T>(params: &Value) -> T
-where
-    T: for<'de> serde::Deserialize<'de> + Default,
-{
-    serde_json::from_value(params.clone()).unwrap_or_default()
-}
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+use time::format_description::well_known::Rfc3339;
+use time::OffsetDateTime;
 
 fn validate_capture_fps(id: &EngineCallId, capture_fps: u64) -> Result<(), EngineResponse> {
     if DEFAULT_CAPTURE_FRAME_RATES.contains(&capture_fps) {
@@ -24,22 +53,687 @@ fn validate_capture_fps(id: &EngineCallId, capture_fps: u64) -> Result<(), Engin
     ))
 }
 
+fn validate_region(
+    id: &EngineCallId,
+    display: &DisplaySource,
+    rect: CaptureRectParams,
+) -> Result<SurfaceRect, EngineResponse> {
+    let values = [rect.x, rect.y, rect.width, rect.height];
+    if values.iter().any(|value| !value.is_finite()) {
+        return Err(failure(
+            id,
+            ProtocolErrorCode::InvalidParams,
+            "rect values must be finite numbers",
+        ));
+    }
+    if rect.x < 0.0 || rect.y < 0.0 {
+        return Err(failure(
+            id,
+            ProtocolErrorCode::InvalidParams,
+            "rect origin must not be negative",
+        ));
+    }
+    if rect.width < 1.0 || rect.height < 1.0 {
+        return Err(failure(
+            id,
+            ProtocolErrorCode::InvalidParams,
+            "rect width and height must be at least 1 pixel",
+        ));
+    }
+    if rect.x + rect.width > display.width as f64 || rect.y + rect.height > display.height as f64 {
+        return Err(failure(
+            id,
+            ProtocolErrorCode::InvalidParams,
+            format!(
+                "rect must fit within display {} bounds ({}x{})",
+                display.id, display.width, display.height
+            ),
+        ));
+    }
+    Ok(SurfaceRect {
+        x: rect.x.round() as u32,
+        y: rect.y.round() as u32,
+        width: (rect.width.round() as u32).min(display.width - rect.x.round() as u32),
+        height: (rect.height.round() as u32).min(display.height - rect.y.round() as u32),
+    })
+}
+
+/// `windowExclusions` from a display or region capture request, or the project's (falling
+/// back to the library defaults) when the request has none.
+fn window_exclusions(
+    id: &EngineCallId,
+    state: &State,
+    params: &Value,
+) -> Result<WindowExclusionParams, EngineResponse> {
+    let Some(value) = params
+        .get("windowExclusions")
+        .filter(|value| !value.is_null())
+    else {
+        return Ok(state.effective_window_exclusions().clone());
+    };
+    serde_json::from_value::<WindowExclusionParams>(value.clone())
+        .map_err(|error| {
+            failure(
+                id,
+                ProtocolErrorCode::InvalidParams,
+                format!("Invalid windowExclusions settings: {error}"),
+            )
+        })?
+        .validated()
+        .map_err(|error| failure(id, ProtocolErrorCode::InvalidParams, error))
+}
+
+/// Starts capturing `region` of a display with excluded windows masked out of every frame.
+fn start_masked_capture_pipeline(
+    state: &mut State,
+    capture_fps: u64,
+    source: Box<dyn FrameSource>,
+    exclusions: WindowExclusionParams,
+    region: SurfaceRect,
+) {
+    if exclusions.rules.is_empty() {
+        start_capture_pipeline(state, capture_fps, source);
+        return;
+    }
+    let (source, mask) = MaskedFrameSource::new(source, exclusions, state.windows, region);
+    start_capture_pipeline(state, capture_fps, Box::new(source));
+    state.privacy_mask = Some(mask);
+}
+
+fn start_capture_pipeline(state: &mut State, capture_fps: u64, source: Box<dyn FrameSource>) {
+    finish_recording(state);
+    stop_capture_pipeline(state);
+    state.capture_pipeline = Some(Arc::new(CapturePipeline::start(source, capture_fps)));
+}
+
+fn stop_capture_pipeline(state: &mut State) {
+    if let Some(pipeline) = state.capture_pipeline.take() {
+        pipeline.stop();
+    }
+    state.privacy_mask = None;
+    state.captured_source = None;
+}
+
+fn stop_camera_pipeline(state: &mut State) {
+    if let Some(pipeline) = state.camera_pipeline.take() {
+        pipeline.stop();
+    }
+    state.camera_id = None;
+}
+
+/// Closes the open pause range, if any, and lets frames reach the writer again.
+fn close_recording_pause(state: &mut State) {
+    let Some(paused_at_seconds) = state.recording_paused_at_seconds.take() else {
+        return;
+    };
+    if let Some(pause) = state.recording_metadata.pauses.last_mut() {
+        pause.duration_seconds = (state.clock.elapsed_seconds() - paused_at_seconds).max(0.0);
+    }
+    for pipeline in state.recording_pipelines() {
+        pipeline.set_paused(false);
+        pipeline.update_recording_metadata(state.recording_metadata.clone());
+    }
+    for recorder in &state.audio_recorders {
+        recorder.set_paused(false);
+    }
+}
+
+/// Longest countdown `recording.start` accepts.
+const MAX_COUNTDOWN_SECONDS: f64 = 60.0;
+
+/// Validates the countdown and time limits of `recording.start` against the capture clock.
+fn recording_schedule(
+    id: &EngineCallId,
+    state: &State,
+    params: &RecordingStartParams,
+) -> Result<RecordingSchedule, EngineResponse> {
+    let countdown_seconds = params.countdown_seconds.unwrap_or(0.0);
+    if !(0.0..=MAX_COUNTDOWN_SECONDS).contains(&countdown_seconds) {
+        return Err(failure(
+            id,
+            ProtocolErrorCode::InvalidParams,
+            format!("countdownSeconds must be between 0 and {MAX_COUNTDOWN_SECONDS}"),
+        ));
+    }
+    if let Some(max_duration_seconds) = params.max_duration_seconds {
+        if !max_duration_seconds.is_finite() || max_duration_seconds <= 0.0 {
+            return Err(failure(
+                id,
+                ProtocolErrorCode::InvalidParams,
+                "maxDurationSeconds must be a positive number",
+            ));
+        }
+    }
+    let now_seconds = state.clock.elapsed_seconds();
+    let starts_at_seconds = now_seconds + countdown_seconds;
+    let stop_at_seconds = match &params.stop_at {
+        Some(stop_at) => {
+            let Ok(stop_at) = OffsetDateTime::parse(stop_at, &Rfc3339) else {
+                return Err(failure(
+                    id,
+                    ProtocolErrorCode::InvalidParams,
+                    "stopAt must be an RFC 3339 timestamp",
+                ));
+            };
+            let stop_at_seconds =
+                now_seconds + (stop_at - OffsetDateTime::now_utc()).as_seconds_f64();
+            if stop_at_seconds <= starts_at_seconds {
+                return Err(failure(
+                    id,
+                    ProtocolErrorCode::InvalidParams,
+                    "stopAt must be after the recording starts",
+                ));
+            }
+            Some(stop_at_seconds)
+        }
+        None => None,
+    };
+    Ok(RecordingSchedule {
+        starts_at_seconds,
+        max_duration_seconds: params.max_duration_seconds,
+        stop_at_seconds,
+        deadline_seconds: None,
+        window: Arc::new(RecordingWindow::new(
+            state.clock.instant_at(starts_at_seconds),
+            None,
+        )),
+    })
+}
+
+/// Recomputes when the recording stops after it starts, pauses or resumes, and moves every
+/// track's cut-off with it.
+fn refresh_recording_deadline(state: &mut State) {
+    let now_seconds = state.clock.elapsed_seconds();
+    let recorded_seconds = state.current_duration();
+    let paused = state.recording_paused_at_seconds.is_some();
+    let Some(schedule) = state.recording_schedule.as_mut() else {
+        return;
+    };
+    schedule.deadline_seconds =
+        schedule.next_deadline_seconds(now_seconds, recorded_seconds, paused);
+    schedule.window.set_stops_at(
+        schedule
+            .deadline_seconds
+            .map(|deadline_seconds| state.clock.instant_at(deadline_seconds)),
+    );
+}
+
+fn next_recording_id(recordings_root: &Path) -> String {
+    let millis = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_millis())
+        .unwrap_or_default();
+    let mut recording_id = format!("recording-{millis}");
+    let mut suffix = 1;
+    while recordings_root.join(&recording_id).exists() {
+        suffix += 1;
+        recording_id = format!("recording-{millis}-{suffix}");
+    }
+    recording_id
+}
+
+/// Refuses to start a journaled recording unless the project volume can hold a minute of
+/// media on top of the low-space reserve.
+fn check_recording_disk_space(id: &EngineCallId, state: &State) -> Result<(), EngineResponse> {
+    let (Some(project_path), Some(pipeline)) = (&state.project_path, &state.capture_pipeline)
+    else {
+        return Ok(());
+    };
+    let Some(available) = (state.disk_space)(Path::new(project_path)) else {
+        return Ok(());
+    };
+    let bytes_per_second = std::iter::once(pipeline)
+        .chain(state.camera_pipeline.iter())
+        .map(|pipeline| {
+            let (width, height) = pipeline.frame_dimensions();
+            JournaledRecordingWriter::estimated_disk_bytes_per_second(
+                width,
+                height,
+                pipeline.capture_fps(),
+            )
+        })
+        .sum();
+    let required = required_recording_bytes(bytes_per_second);
+    if available >= required {
+        return Ok(());
+    }
+    Err(failure(
+        id,
+        ProtocolErrorCode::InvalidParams,
+        format!(
+            "Not enough free disk space to record: {} MB available, {} MB required",
+            megabytes(available),
+            megabytes(required)
+        ),
+    ))
+}
+
+/// Opens a journaled writer inside the current project; sessions without a project are not
+/// persisted.
+fn open_recording_writer(
+    state: &State,
+) -> std::io::Result<Option<(String, JournaledRecordingWriter)>> {
+    let (Some(project_path), Some(pipeline)) = (&state.project_path, &state.capture_pipeline)
+    else {
+        return Ok(None);
+    };
+    let recordings_root = Path::new(project_path).join(RECORDINGS_DIRECTORY);
+    let recording_id = next_recording_id(&recordings_root);
+    let (width, height) = pipeline.frame_dimensions();
+    let writer = JournaledRecordingWriter::create(
+        &recordings_root,
+        &recording_id,
+        width,
+        height,
+        pipeline.capture_fps(),
+        Arc::new(RecordingDiskBudget::new(&recordings_root, state.disk_space)),
+    )?;
+    Ok(Some((recording_id, writer)))
+}
+
+/// Opens the camera track inside the screen recording's directory while a camera is running.
+fn open_camera_writer(
+    state: &State,
+    screen_writer: Option<&JournaledRecordingWriter>,
+) -> std::io::Result<Option<JournaledRecordingWriter>> {
+    let (Some(screen_writer), Some(camera)) = (screen_writer, &state.camera_pipeline) else {
+        return Ok(None);
+    };
+    let (width, height) = camera.frame_dimensions();
+    JournaledRecordingWriter::create(
+        screen_writer.directory(),
+        CAMERA_TRACK_DIRECTORY,
+        width,
+        height,
+        camera.capture_fps(),
+        Arc::clone(screen_writer.disk_budget()),
+    )
+    .map(Some)
+}
+
+/// An audio track requested by `recording.start`, opened before any journal is created.
+struct RequestedAudioTrack {
+    kind: AudioTrackKind,
+    device_id: Option<String>,
+    source: Box<dyn AudioSource>,
+    silence_warning_seconds: f64,
+}
+
+/// Checks requested device ids against the sound server's inputs and opens every requested
+/// track; the system-audio track only accepts monitor sources.
+fn open_audio_tracks(
+    id: &EngineCallId,
+    state: &State,
+    params: &RecordingStartParams,
+) -> Result<Vec<RequestedAudioTrack>, EngineResponse> {
+    let requested = [
+        (
+            AudioTrackKind::Microphone,
+            params.capture_microphone,
+            &params.microphone_device_id,
+            "microphoneDeviceId",
+        ),
+        (
+            AudioTrackKind::SystemAudio,
+            params.capture_system_audio,
+            &params.system_audio_device_id,
+            "systemAudioDeviceId",
+        ),
+    ];
+    let silence_warning_seconds = params
+        .silence_warning_seconds
+        .unwrap_or(DEFAULT_SILENCE_WARNING_SECONDS);
+    if !silence_warning_seconds.is_finite() || silence_warning_seconds <= 0.0 {
+        return Err(failure(
+            id,
+            ProtocolErrorCode::InvalidParams,
+            "silenceWarningSeconds must be a positive number",
+        ));
+    }
+    let mut tracks = Vec::new();
+    for (kind, enabled, device_id, field) in requested {
+        if !enabled.unwrap_or(false) {
+            continue;
+        }
+        if let Some(device_id) = device_id {
+            let devices = (state.audio_devices)();
+            let Some(device) = devices.iter().find(|device| &device.id == device_id) else {
+                return Err(failure(
+                    id,
+                    ProtocolErrorCode::InvalidParams,
+                    format!("Unknown {field}: {device_id}"),
+                ));
+            };
+            if kind == AudioTrackKind::SystemAudio && !device.is_monitor {
+                return Err(failure(
+                    id,
+                    ProtocolErrorCode::InvalidParams,
+                    format!("{field} {device_id} is not a monitor source"),
+                ));
+            }
+        }
+        match (state.audio_sources)(kind, device_id.as_deref()) {
+            Ok(source) => tracks.push(RequestedAudioTrack {
+                kind,
+                device_id: device_id.clone(),
+                source,
+                silence_warning_seconds,
+            }),
+            Err(error) => {
+                return Err(failure(
+                    id,
+                    ProtocolErrorCode::RuntimeError,
+                    format!("Unable to capture {} audio: {error}", kind.label()),
+                ))
+            }
+        }
+    }
+    Ok(tracks)
+}
+
+/// Starts the audio recorders, writing WAV tracks into the recording directory when the
+/// recording is journaled. Recorders started before a failure stop again when dropped.
+fn start_audio_tracks(
+    tracks: Vec<RequestedAudioTrack>,
+    take_id: &str,
+    recording: Option<&JournaledRecordingWriter>,
+    window: &Arc<RecordingWindow>,
+) -> std::io::Result<Vec<(Arc<AudioTrackRecorder>, AudioTrack)>> {
+    let mut started = Vec::new();
+    for track in tracks {
+        let wav_path = recording.map(|writer| writer.directory().join(track.kind.file_name()));
+        let wav_writer = wav_path
+            .as_deref()
+            .zip(recording)
+            .map(|(path, writer)| {
+                WavWriter::create_for_recording(path, Arc::clone(writer.disk_budget()))
+            })
+            .transpose()?;
+        let recorder = AudioTrackRecorder::start(
+            track.kind,
+            track.source,
+            wav_writer,
+            track.silence_warning_seconds,
+            Arc::clone(window),
+        );
+        started.push((
+            Arc::new(recorder),
+            AudioTrack {
+                kind: track.kind,
+                recording_url: match wav_path {
+                    Some(path) => path.to_string_lossy().into_owned(),
+                    None => format!(
+                        "{PLACEHOLDER_RECORDING_PREFIX}{take_id}-{}",
+                        track.kind.file_name()
+                    ),
+                },
+                device_id: track.device_id,
+                sample_rate: AUDIO_SAMPLE_RATE,
+                channels: AUDIO_CHANNELS,
+            },
+        ));
+    }
+    Ok(started)
+}
+
+fn finish_audio_tracks(state: &mut State) {
+    for recorder in std::mem::take(&mut state.audio_recorders) {
+        if let Err(error) = recorder.finish() {
+            state.last_error = Some(EngineError {
+                code: ProtocolErrorCode::RuntimeError,
+                message: format!(
+                    "Unable to record {} audio: {error}",
+                    recorder.kind().label()
+                ),
+            });
+        }
+    }
+}
+
+/// Journaled recordings get a real tracker writing next to the recording; sessions without a
+/// project keep the placeholder URL.
+fn start_input_tracking(
+    state: &mut State,
+    events_path: Option<PathBuf>,
+    track_keyboard_shortcuts: bool,
+) {
+    let Some(events_path) = events_path else {
+        state.events_url = Some("native://events/session-events.json".to_string());
+        return;
+    };
+    match InputTracker::start(state.clock.clone(), track_keyboard_shortcuts) {
+        Ok(tracker) => {
+            state.input_tracker = Some(Arc::new(tracker));
+            state.events_url = Some(events_path.to_string_lossy().into_owned());
+        }
+        Err(error) => {
+            state.last_error = Some(EngineError {
+                code: ProtocolErrorCode::RuntimeError,
+                message: format!("Input tracking unavailable: {error}"),
+            });
+        }
+    }
+}
+
+fn finish_input_tracking(state: &mut State, ended_at_seconds: f64) {
+    let Some(tracker) = state.input_tracker.take() else {
+        return;
+    };
+    let log = align_to_recording(
+        &tracker
+            .finish()
+            .within(state.recording_started_at_seconds, ended_at_seconds),
+        state.recording_started_at_seconds,
+        &state.recording_metadata.pauses,
+    );
+    let Some(events_url) = state.events_url.clone() else {
+        return;
+    };
+    let written = serde_json::to_vec_pretty(&log)
+        .map_err(std::io::Error::other)
+        .and_then(|data| write_file_no_symlink(Path::new(&events_url), &data));
+    if let Err(error) = written {
+        state.last_error = Some(EngineError {
+            code: ProtocolErrorCode::RuntimeError,
+            message: format!("Unable to write input events: {error}"),
+        });
+    }
+}
+
+fn finish_recording(state: &mut State) {
+    if !state.is_recording {
+        return;
+    }
+    // A recording stopped late by a time limit still ends exactly at the limit.
+    let now_seconds = state.clock.elapsed_seconds();
+    let ended_at_seconds = state
+        .recording_deadline_seconds()
+        .map_or(now_seconds, |deadline_seconds| {
+            deadline_seconds.min(now_seconds)
+        });
+    close_recording_pause(state);
+    state.last_recording_telemetry = Some(state.capture_telemetry());
+    let writer = state
+        .capture_pipeline
+        .as_ref()
+        .and_then(|pipeline| pipeline.end_recording());
+    let camera_writer = state
+        .camera_pipeline
+        .as_ref()
+        .and_then(|pipeline| pipeline.end_recording());
+    state.recording_duration.stop_at(ended_at_seconds);
+    state.is_recording = false;
+    state.recording_id = None;
+    state.recording_schedule = None;
+    finish_audio_tracks(state);
+    finish_input_tracking(state, ended_at_seconds);
+    // The camera track goes first, so a finalized screen journal implies a finalized camera.
+    if let Some(camera_writer) = camera_writer {
+        if let Err(error) = camera_writer.finalize(state.recording_metadata.clone()) {
+            state.last_error = Some(EngineError {
+                code: ProtocolErrorCode::RuntimeError,
+                message: format!("Unable to finalize camera recording: {error}"),
+            });
+        }
+    }
+    if let Some(writer) = writer {
+        if let Err(error) = writer.finalize(state.recording_metadata.clone()) {
+            state.last_error = Some(EngineError {
+                code: ProtocolErrorCode::RuntimeError,
+                message: format!("Unable to finalize recording: {error}"),
+            });
+        }
+    }
+    if let Some(take_id) = state.recording_take_id.take() {
+        takes::record_finished_take(state, take_id);
+    }
+}
+
+/// Why the running capture can no longer record its source, given the platform's changes.
+/// Records the source a capture is recording and watches it, so losing it ends the capture.
+fn watch_captured_source(state: &mut State, source: CapturedSource) {
+    match source {
+        CapturedSource::Display(_) => state.source_monitor.watch(),
+        CapturedSource::Window(window_id) => state.source_monitor.watch_window(window_id),
+    }
+    state.captured_source = Some(source);
+}
+
+fn lost_source(state: &State, changes: &[SourceChange]) -> Option<String> {
+    match state.captured_source? {
+        CapturedSource::Display(display_id) => {
+            let displays_changed = changes.contains(&SourceChange::DisplaysChanged);
+            (displays_changed && sources::display(state, Some(display_id)).is_none())
+                .then(|| format!("Capture stopped because display {display_id} was disconnected"))
+        }
+        CapturedSource::Window(window_id) => changes
+            .contains(&SourceChange::WindowClosed(window_id))
+            .then(|| format!("Capture stopped because window {window_id} was closed")),
+    }
+}
+
+/// Ends captures whose source disappeared, stops recordings whose frames can no longer be
+/// written, such as after running out of disk space, finalizes recordings that reached their
+/// time limit, and surfaces audio tracks that went silent mid-recording.
+pub(crate) fn reconcile_recording(state: &mut State) {
+    let changes = state.source_monitor.take_changes();
+    if let Some(message) = lost_source(state, &changes) {
+        if state.is_recording {
+            state.unsaved_changes = true;
+        }
+        end_capture(state);
+        state.last_error = Some(EngineError {
+            code: ProtocolErrorCode::RuntimeError,
+            message,
+        });
+        return;
+    }
+    if let Some(message) = state
+        .privacy_mask
+        .as_ref()
+        .and_then(PrivacyMask::take_failure)
+    {
+        if state.is_recording {
+            state.unsaved_changes = true;
+        }
+        end_capture(state);
+        state.last_error = Some(EngineError {
+            code: ProtocolErrorCode::RuntimeError,
+            message: format!("Capture stopped to keep excluded windows private. {message}"),
+        });
+        return;
+    }
+    for recorder in &state.audio_recorders {
+        if let Some(silent_seconds) = recorder.take_silence_warning() {
+            state.last_error = Some(EngineError {
+                code: ProtocolErrorCode::RuntimeError,
+                message: format!(
+                    "{} has been silent for {silent_seconds} seconds while recording",
+                    recorder.kind().display_name()
+                ),
+            });
+        }
+    }
+    let limit_reached = state.is_recording
+        && state
+            .recording_deadline_seconds()
+            .is_some_and(|deadline_seconds| state.clock.elapsed_seconds() >= deadline_seconds);
+    if limit_reached {
+        finish_recording(state);
+        state.unsaved_changes = true;
+        return;
+    }
+    let writer_failure = state
+        .is_recording
+        .then(|| {
+            state
+                .recording_pipelines()
+                .find_map(|pipeline| pipeline.writer_failure())
+        })
+        .flatten();
+    let Some(writer_failure) = writer_failure else {
+        return;
+    };
+    finish_recording(state);
+    state.unsaved_changes = true;
+    state.last_error = Some(EngineError {
+        code: ProtocolErrorCode::RuntimeError,
+        message: match writer_failure {
+            WriterFailure::OutOfDiskSpace => format!(
+                "Recording stopped because free disk space fell below {} MB",
+                megabytes(LOW_DISK_SPACE_RESERVE_BYTES)
+            ),
+            WriterFailure::Io(error) => {
+                format!("Recording stopped because a frame could not be written: {error}")
+            }
+        },
+    });
+}
+
 pub(crate) fn start_display(
     id: &EngineCallId,
     state: &mut State,
     params: &Value,
 ) -> EngineResponse {
-    let capture_params: CaptureStartParams = decode_params(params);
+    let capture_params = match decode_params::<CaptureStartParams>(id, params, "capture") {
+        Ok(params) => params,
+        Err(response) => return response,
+    };
     let capture_fps = capture_params.capture_fps.unwrap_or(30);
     if let Err(response) = validate_capture_fps(id, capture_fps) {
         return response;
     }
+    let exclusions = match window_exclusions(id, state, params) {
+        Ok(exclusions) => exclusions,
+        Err(response) => return response,
+    };
+    let Some(display) = sources::display(state, None) else {
+        return failure(
+            id,
+            ProtocolErrorCode::RuntimeError,
+            "No display is available",
+        );
+    };
+    start_masked_capture_pipeline(
+        state,
+        capture_fps,
+        Box::new(SyntheticFrameSource::new(display.width, display.height)),
+        exclusions,
+        SurfaceRect {
+            x: 0,
+            y: 0,
+            width: display.width,
+            height: display.height,
+        },
+    );
     state.is_running = true;
+    watch_captured_source(state, CapturedSource::Display(display.id));
     state.begin_capture_session();
     state.capture_metadata = Some(json!({
         "window": Value::Null,
         "source": "display",
-        "contentRect": { "x": 0, "y": 0, "width": 1920, "height": 1080 },
+        "displayId": display.id,
+        "contentRect": { "x": 0, "y": 0, "width": display.width, "height": display.height },
         "pixelScale": 1,
     }));
     success(id, state.capture_status())
@@ -50,12 +744,21 @@ pub(crate) fn start_current_window(
     state: &mut State,
     params: &Value,
 ) -> EngineResponse {
-    let capture_params: CaptureStartParams = decode_params(params);
+    let capture_params = match decode_params::<CaptureStartParams>(id, params, "capture") {
+        Ok(params) => params,
+        Err(response) => return response,
+    };
     let capture_fps = capture_params.capture_fps.unwrap_or(30);
     if let Err(response) = validate_capture_fps(id, capture_fps) {
         return response;
     }
+    start_capture_pipeline(
+        state,
+        capture_fps,
+        Box::new(SyntheticFrameSource::new(1280, 720)),
+    );
     state.is_running = true;
+    watch_captured_source(state, CapturedSource::Window(101));
     state.begin_capture_session();
     state.capture_metadata = Some(json!({
         "window": {
@@ -71,13 +774,22 @@ pub(crate) fn start_current_window(
 }
 
 pub(crate) fn start_window(id: &EngineCallId, state: &mut State, params: &Value) -> EngineResponse {
-    let capture_params: CaptureStartParams = decode_params(params);
+    let capture_params = match decode_params::<CaptureStartParams>(id, params, "capture") {
+        Ok(params) => params,
+        Err(response) => return response,
+    };
     let capture_fps = capture_params.capture_fps.unwrap_or(30);
     if let Err(response) = validate_capture_fps(id, capture_fps) {
         return response;
     }
     let window_id = capture_params.window_id.unwrap_or(101);
+    start_capture_pipeline(
+        state,
+        capture_fps,
+        Box::new(SyntheticFrameSource::new(1280, 720)),
+    );
     state.is_running = true;
+    watch_captured_source(state, CapturedSource::Window(window_id));
     state.begin_capture_session();
     state.capture_metadata = Some(json!({
         "window": {
@@ -92,11 +804,138 @@ pub(crate) fn start_window(id: &EngineCallId, state: &mut State, params: &Value)
     success(id, state.capture_status())
 }
 
-pub(crate) fn stop_capture(id: &EngineCallId, state: &mut State) -> EngineResponse {
-    state.recording_duration.stop(&state.clock);
-    state.is_recording = false;
+pub(crate) fn start_region(id: &EngineCallId, state: &mut State, params: &Value) -> EngineResponse {
+    let region_params =
+        match decode_params::<CaptureStartRegionParams>(id, params, "region capture") {
+            Ok(params) => params,
+            Err(response) => return response,
+        };
+    let capture_fps = region_params.capture_fps.unwrap_or(30);
+    if let Err(response) = validate_capture_fps(id, capture_fps) {
+        return response;
+    }
+    let Some(display) = sources::display(state, region_params.display_id) else {
+        return failure(
+            id,
+            ProtocolErrorCode::InvalidParams,
+            format!(
+                "Unknown displayId: {}",
+                region_params.display_id.unwrap_or_default()
+            ),
+        );
+    };
+    let Some(rect) = region_params.rect else {
+        return failure(id, ProtocolErrorCode::InvalidParams, "rect is required");
+    };
+    let region = match validate_region(id, &display, rect) {
+        Ok(region) => region,
+        Err(response) => return response,
+    };
+    let exclusions = match window_exclusions(id, state, params) {
+        Ok(exclusions) => exclusions,
+        Err(response) => return response,
+    };
+    start_masked_capture_pipeline(
+        state,
+        capture_fps,
+        Box::new(SyntheticFrameSource::region(
+            display.width,
+            display.height,
+            region,
+        )),
+        exclusions,
+        region,
+    );
+    state.is_running = true;
+    watch_captured_source(state, CapturedSource::Display(display.id));
+    state.begin_capture_session();
+    state.capture_metadata = Some(json!({
+        "window": Value::Null,
+        "source": "region",
+        "displayId": display.id,
+        "contentRect": {
+            "x": region.x,
+            "y": region.y,
+            "width": region.width,
+            "height": region.height,
+        },
+        "pixelScale": 1,
+    }));
+    success(id, state.capture_status())
+}
+
+/// Starts a webcam next to screen capture; recordings started afterwards get a camera track.
+pub(crate) fn start_camera(id: &EngineCallId, state: &mut State, params: &Value) -> EngineResponse {
+    let camera_params =
+        match decode_params::<CaptureStartCameraParams>(id, params, "camera capture") {
+            Ok(params) => params,
+            Err(response) => return response,
+        };
+    let capture_fps = camera_params.capture_fps.unwrap_or(30);
+    if let Err(response) = validate_capture_fps(id, capture_fps) {
+        return response;
+    }
+    if state.is_recording {
+        return failure(
+            id,
+            ProtocolErrorCode::InvalidParams,
+            "Stop recording before changing the camera",
+        );
+    }
+    let available = (state.cameras)();
+    let camera = match camera_params.camera_id {
+        Some(camera_id) => available.iter().find(|camera| camera.id == camera_id),
+        None => available.first(),
+    };
+    let Some(camera) = camera else {
+        return failure(
+            id,
+            ProtocolErrorCode::InvalidParams,
+            match camera_params.camera_id {
+                Some(camera_id) => format!("Unknown cameraId: {camera_id}"),
+                None => "No camera is available".to_string(),
+            },
+        );
+    };
+    stop_camera_pipeline(state);
+    let source = match cameras::open_source(camera) {
+        Ok(source) => source,
+        Err(error) => {
+            return failure(
+                id,
+                ProtocolErrorCode::RuntimeError,
+                format!("Unable to start camera {}: {error}", camera.name),
+            )
+        }
+    };
+    state.camera_pipeline = Some(Arc::new(CapturePipeline::start(source, capture_fps)));
+    state.camera_id = Some(camera.id);
+    success(id, state.capture_status())
+}
+
+pub(crate) fn stop_camera(id: &EngineCallId, state: &mut State) -> EngineResponse {
+    if state.is_recording {
+        return failure(
+            id,
+            ProtocolErrorCode::InvalidParams,
+            "Stop recording before changing the camera",
+        );
+    }
+    stop_camera_pipeline(state);
+    success(id, state.capture_status())
+}
+
+/// Finishes any recording and stops screen and camera capture.
+fn end_capture(state: &mut State) {
+    finish_recording(state);
+    stop_capture_pipeline(state);
+    stop_camera_pipeline(state);
     state.is_running = false;
     state.capture_session_id = None;
+}
+
+pub(crate) fn stop_capture(id: &EngineCallId, state: &mut State) -> EngineResponse {
+    end_capture(state);
     success(id, state.capture_status())
 }
 
@@ -105,7 +944,10 @@ pub(crate) fn start_recording(
     state: &mut State,
     params: &Value,
 ) -> EngineResponse {
-    let recording_params: RecordingStartParams = decode_params(params);
+    let recording_params = match decode_params::<RecordingStartParams>(id, params, "recording") {
+        Ok(params) => params,
+        Err(response) => return response,
+    };
     if !state.is_running {
         return failure(
             id,
@@ -113,22 +955,338 @@ pub(crate) fn start_recording(
             "Start capture before recording",
         );
     }
+    if !state.is_recording {
+        let schedule = match recording_schedule(id, state, &recording_params) {
+            Ok(schedule) => schedule,
+            Err(response) => return response,
+        };
+        if let Err(response) = check_recording_disk_space(id, state) {
+            return response;
+        }
+        let audio_tracks = match open_audio_tracks(id, state, &recording_params) {
+            Ok(tracks) => tracks,
+            Err(response) => return response,
+        };
+        let writer = match open_recording_writer(state) {
+            Ok(writer) => writer,
+            Err(error) => {
+                return failure(
+                    id,
+                    ProtocolErrorCode::PermissionDenied,
+                    format!("Unable to create recording journal safely: {error}"),
+                )
+            }
+        };
+        let camera_writer =
+            match open_camera_writer(state, writer.as_ref().map(|(_, writer)| writer)) {
+                Ok(writer) => writer,
+                Err(error) => {
+                    if let Some((_, writer)) = writer {
+                        writer.discard();
+                    }
+                    return failure(
+                        id,
+                        ProtocolErrorCode::PermissionDenied,
+                        format!("Unable to create camera recording journal safely: {error}"),
+                    );
+                }
+            };
+        let take_id = match &writer {
+            Some((recording_id, _)) => recording_id.clone(),
+            None => takes::next_session_take_id(state),
+        };
+        let audio_tracks = match start_audio_tracks(
+            audio_tracks,
+            &take_id,
+            writer.as_ref().map(|(_, writer)| writer),
+            &schedule.window,
+        ) {
+            Ok(tracks) => tracks,
+            Err(error) => {
+                drop(camera_writer);
+                if let Some((_, writer)) = writer {
+                    writer.discard();
+                }
+                return failure(
+                    id,
+                    ProtocolErrorCode::PermissionDenied,
+                    format!("Unable to create audio track safely: {error}"),
+                );
+            }
+        };
+        state.last_recording_telemetry = None;
+        state.last_error = None;
+        state.recording_metadata = RecordingMetadata::default();
+        state.recording_url = Some(match &writer {
+            Some((_, writer)) => writer.output_path().to_string_lossy().into_owned(),
+            None => format!("{PLACEHOLDER_RECORDING_PREFIX}{take_id}.y4m"),
+        });
+        state.recording_id = writer
+            .as_ref()
+            .map(|(recording_id, _)| recording_id.clone());
+        state.camera_track = state.camera_pipeline.as_ref().map(|camera| {
+            let (width, height) = camera.frame_dimensions();
+            CameraTrack {
+                recording_url: match &camera_writer {
+                    Some(writer) => writer.output_path().to_string_lossy().into_owned(),
+                    None => format!("{PLACEHOLDER_RECORDING_PREFIX}{take_id}-camera.y4m"),
+                },
+                width,
+                height,
+            }
+        });
+        state.recording_take_id = Some(take_id);
+        (state.audio_recorders, state.audio_tracks) = audio_tracks.into_iter().unzip();
+        state.events_url = None;
+        let track_keyboard_shortcuts = recording_params.track_keyboard_shortcuts.unwrap_or(false);
+        if recording_params.track_input_events.unwrap_or(false) || track_keyboard_shortcuts {
+            start_input_tracking(
+                state,
+                writer
+                    .as_ref()
+                    .map(|(_, writer)| writer.output_path().with_file_name(EVENTS_FILE_NAME)),
+                track_keyboard_shortcuts,
+            );
+        }
+        state.recording_started_at_seconds = schedule.starts_at_seconds;
+        if let Some(pipeline) = &state.capture_pipeline {
+            pipeline.begin_recording(
+                writer.map(|(_, writer)| writer),
+                Arc::clone(&schedule.window),
+            );
+        }
+        if let Some(camera) = &state.camera_pipeline {
+            camera.begin_recording(camera_writer, Arc::clone(&schedule.window));
+        }
+        state.recording_duration = RunningDuration::default();
+        state
+            .recording_duration
+            .start_at(schedule.starts_at_seconds);
+        state.recording_schedule = Some(schedule);
+    }
+    close_recording_pause(state);
     state.is_recording = true;
     state.recording_duration.start(&state.clock);
-    state.recording_url = Some("native://recordings/session.mp4".to_string());
-    if recording_params.track_input_events.unwrap_or(false) {
-        state.events_url = Some("native://events/session-events.json".to_string());
+    refresh_recording_deadline(state);
+    success(id, state.capture_status())
+}
+
+pub(crate) fn pause_recording(id: &EngineCallId, state: &mut State) -> EngineResponse {
+    if !state.is_recording {
+        return failure(
+            id,
+            ProtocolErrorCode::InvalidParams,
+            "Start recording before pausing",
+        );
+    }
+    if state.recording_paused_at_seconds.is_some() {
+        return failure(
+            id,
+            ProtocolErrorCode::InvalidParams,
+            "Recording is already paused",
+        );
+    }
+    if state.countdown_remaining_seconds().is_some() {
+        return failure(
+            id,
+            ProtocolErrorCode::InvalidParams,
+            "Recording has not started yet",
+        );
     }
+    state.recording_duration.stop(&state.clock);
+    state.recording_paused_at_seconds = Some(state.clock.elapsed_seconds());
+    state.recording_metadata.pauses.push(RecordingPauseRange {
+        at_seconds: state.current_duration(),
+        duration_seconds: 0.0,
+    });
+    for pipeline in state.recording_pipelines() {
+        pipeline.set_paused(true);
+        pipeline.update_recording_metadata(state.recording_metadata.clone());
+    }
+    for recorder in &state.audio_recorders {
+        recorder.set_paused(true);
+    }
+    refresh_recording_deadline(state);
+    success(id, state.capture_status())
+}
+
+pub(crate) fn resume_recording(id: &EngineCallId, state: &mut State) -> EngineResponse {
+    if !state.is_recording || state.recording_paused_at_seconds.is_none() {
+        return failure(
+            id,
+            ProtocolErrorCode::InvalidParams,
+            "Recording is not paused",
+        );
+    }
+    close_recording_pause(state);
+    state.recording_duration.start(&state.clock);
+    refresh_recording_deadline(state);
     success(id, state.capture_status())
 }
 
+/// Stamps a marker at the current recording time and journals it with the recording.
+pub(crate) fn add_marker(id: &EngineCallId, state: &mut State, params: &Value) -> EngineResponse {
+    let marker_params = match decode_params::<RecordingAddMarkerParams>(id, params, "marker") {
+        Ok(params) => params,
+        Err(response) => return response,
+    };
+    let marker_params = match marker_params.validated() {
+        Ok(params) => params,
+        Err(error) => return failure(id, ProtocolErrorCode::InvalidParams, error),
+    };
+    if !state.is_recording {
+        return failure(
+            id,
+            ProtocolErrorCode::InvalidParams,
+            "Start recording before adding a marker",
+        );
+    }
+    if state.countdown_remaining_seconds().is_some() {
+        return failure(
+            id,
+            ProtocolErrorCode::InvalidParams,
+            "Recording has not started yet",
+        );
+    }
+    let marker = RecordingMarker {
+        id: format!("marker-{}", state.recording_metadata.markers.len() + 1),
+        at_seconds: state.current_duration(),
+        label: marker_params.label,
+        color: marker_params
+            .color
+            .unwrap_or_else(|| DEFAULT_MARKER_COLOR.to_string()),
+    };
+    state.recording_metadata.markers.push(marker.clone());
+    for pipeline in state.recording_pipelines() {
+        pipeline.update_recording_metadata(state.recording_metadata.clone());
+    }
+    success(id, json!({ "marker": marker }))
+}
+
 pub(crate) fn stop_recording(id: &EngineCallId, state: &mut State) -> EngineResponse {
-    state.recording_duration.stop(&state.clock);
-    state.is_recording = false;
+    finish_recording(state);
     state.unsaved_changes = true;
     success(id, state.capture_status())
 }
 
+/// An interrupted recording together with the tracks recorded next to it.
+struct RecoveredRecording {
+    screen: FinalizedRecording,
+    camera: Option<FinalizedRecording>,
+    audio_tracks: Vec<AudioTrack>,
+}
+
+/// Finalizes an interrupted recording, its camera track and any audio tracks it had.
+fn recover_recording_directory(directory: &Path) -> std::io::Result<RecoveredRecording> {
+    let camera_directory = directory.join(CAMERA_TRACK_DIRECTORY);
+    let camera = if camera_directory.is_dir() {
+        Some(finalize_recording_directory(&camera_directory, true)?)
+    } else {
+        None
+    };
+    let mut audio_tracks = Vec::new();
+    for kind in AudioTrackKind::ALL {
+        let path = directory.join(kind.file_name());
+        if !path.is_file() {
+            continue;
+        }
+        repair_wav_header(&path)?;
+        audio_tracks.push(AudioTrack {
+            kind,
+            recording_url: path.to_string_lossy().into_owned(),
+            device_id: None,
+            sample_rate: AUDIO_SAMPLE_RATE,
+            channels: AUDIO_CHANNELS,
+        });
+    }
+    Ok(RecoveredRecording {
+        screen: finalize_recording_directory(directory, true)?,
+        camera,
+        audio_tracks,
+    })
+}
+
+/// Finalizes recordings whose journal was left open by an interrupted session and makes the
+/// latest one the project's recording.
+pub(crate) fn recover_recordings(id: &EngineCallId, state: &mut State) -> EngineResponse {
+    let Some(project_path) = state.project_path.clone() else {
+        return failure(
+            id,
+            ProtocolErrorCode::InvalidParams,
+            "Open a project before recovering recordings",
+        );
+    };
+    let recordings_root = Path::new(&project_path).join(RECORDINGS_DIRECTORY);
+    let mut recovered = Vec::new();
+    for directory in unfinished_recording_directories(&recordings_root) {
+        let is_active = directory
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| state.recording_id.as_deref() == Some(name));
+        if is_active {
+            continue;
+        }
+        match recover_recording_directory(&directory) {
+            Ok(recording) => recovered.push(recording),
+            Err(error) => {
+                return failure(
+                    id,
+                    ProtocolErrorCode::RuntimeError,
+                    format!("Unable to recover {}: {error}", directory.display()),
+                )
+            }
+        }
+    }
+
+    for recording in &recovered {
+        let events_path = recording
+            .screen
+            .output_path
+            .with_file_name(EVENTS_FILE_NAME);
+        let mut take = takes::new_take(
+            state,
+            recording.screen.recording_id.clone(),
+            recording.screen.output_path.to_string_lossy().into_owned(),
+            recording.screen.duration_seconds,
+        );
+        take.camera_track = recording.camera.as_ref().map(|camera| CameraTrack {
+            recording_url: camera.output_path.to_string_lossy().into_owned(),
+            width: camera.width,
+            height: camera.height,
+        });
+        take.audio_tracks = recording.audio_tracks.clone();
+        take.events_url = events_path
+            .is_file()
+            .then(|| events_path.to_string_lossy().into_owned());
+        take.recording_metadata = recording.screen.metadata.clone();
+        // The latest recovered recording becomes the project's recording.
+        if state.is_recording {
+            takes::insert_take(state, take);
+        } else {
+            takes::add_take(state, take);
+        }
+        state.unsaved_changes = true;
+    }
+    let recordings = recovered
+        .iter()
+        .map(|recording| {
+            json!({
+                "recordingId": recording.screen.recording_id,
+                "recordingURL": recording.screen.output_path.to_string_lossy(),
+                "cameraRecordingURL": recording
+                    .camera
+                    .as_ref()
+                    .map(|camera| camera.output_path.to_string_lossy()),
+                "audioTracks": recording.audio_tracks,
+                "frameCount": recording.screen.frame_count,
+                "durationSeconds": recording.screen.duration_seconds,
+                "recovered": recording.screen.metadata.recovered,
+            })
+        })
+        .collect::<Vec<Value>>();
+    success(id, json!({ "recordings": recordings }))
+}
+
 pub(crate) fn status(id: &EngineCallId, state: &State) -> EngineResponse {
     success(id, state.capture_status())
 }
@@ -0,0 +1,386 @@
+use crate::audio::{AUDIO_CHANNELS, AUDIO_SAMPLE_RATE};
+use crate::cursor::CursorPath;
+use crate::input_tracking::{InputEvent, InputEventType, MouseButton};
+use crate::params::{ClickEffectSegmentParams, ClickEffectsParams};
+use crate::timeline::{ResolvedTimelineClip, TakeFrame};
+use serde::Serialize;
+use std::f64::consts::TAU;
+
+/// Length of the synthesized click.
+const CLICK_SOUND_SECONDS: f64 = 0.03;
+const CLICK_SOUND_FREQUENCY: f64 = 2_000.0;
+/// Decay rate of the click envelope, per second.
+const CLICK_SOUND_DECAY: f64 = 150.0;
+
+/// An expanding ring drawn where a mouse button went down, in source pixels and seconds.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ClickRipple {
+    pub(crate) clip_id: Option<String>,
+    pub(crate) start_seconds: f64,
+    pub(crate) end_seconds: f64,
+    pub(crate) x: f64,
+    pub(crate) y: f64,
+    pub(crate) max_radius: f64,
+    pub(crate) color: String,
+    pub(crate) button: Option<MouseButton>,
+}
+
+/// Where the spotlight sits in one export frame; everything outside `radius + feather` is dimmed
+/// by `dim_opacity`.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct SpotlightFrame {
+    pub(crate) clip_id: Option<String>,
+    pub(crate) time: f64,
+    pub(crate) x: f64,
+    pub(crate) y: f64,
+    pub(crate) radius: f64,
+    pub(crate) feather: f64,
+    pub(crate) dim_opacity: f64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ClickSoundCue {
+    pub(crate) clip_id: Option<String>,
+    pub(crate) time: f64,
+    pub(crate) volume: f64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ClickEffects {
+    pub(crate) ripples: Vec<ClickRipple>,
+    pub(crate) spotlight: Vec<SpotlightFrame>,
+    pub(crate) click_sounds: Vec<ClickSoundCue>,
+}
+
+impl ClickEffects {
+    pub(crate) fn is_empty(&self) -> bool {
+        self.ripples.is_empty() && self.spotlight.is_empty() && self.click_sounds.is_empty()
+    }
+}
+
+/// A stretch of the recording with one set of effect toggles.
+struct Segment<'a> {
+    /// The timeline clip playing the stretch; `None` for the whole recording.
+    clip: Option<&'a ResolvedTimelineClip>,
+    ripple: bool,
+    spotlight: bool,
+    click_sound: bool,
+}
+
+impl Segment<'_> {
+    fn clip_id(&self) -> Option<String> {
+        self.clip.map(|clip| clip.clip_id.clone())
+    }
+
+    /// Program time at which `time` of the recording plays in this segment, if it does.
+    fn program_time(&self, time: f64) -> Option<f64> {
+        match self.clip {
+            None => Some(time),
+            Some(clip) if time < clip.source_end_seconds => clip.program_time(time),
+            Some(_) => None,
+        }
+    }
+}
+
+/// One segment per timeline clip of the recording the events came from, or the whole recording
+/// when the timeline is empty.
+fn segments<'a>(
+    settings: &ClickEffectsParams,
+    clips: &'a [ResolvedTimelineClip],
+    take_id: Option<&str>,
+) -> Vec<Segment<'a>> {
+    let segment = |clip: Option<&'a ResolvedTimelineClip>| {
+        let overrides = clip.and_then(|clip| {
+            settings
+                .segments
+                .iter()
+                .find(|segment| segment.clip_id == clip.clip_id)
+        });
+        let toggle = |pick: fn(&ClickEffectSegmentParams) -> Option<bool>, enabled: bool| {
+            overrides.and_then(pick).unwrap_or(enabled)
+        };
+        Segment {
+            clip,
+            ripple: toggle(|segment| segment.ripple, settings.ripple.enabled),
+            spotlight: toggle(|segment| segment.spotlight, settings.spotlight.enabled),
+            click_sound: toggle(|segment| segment.click_sound, settings.click_sound.enabled),
+        }
+    };
+    if clips.is_empty() {
+        return vec![segment(None)];
+    }
+    clips
+        .iter()
+        .filter(|clip| Some(clip.take_id.as_str()) == take_id)
+        .map(|clip| segment(Some(clip)))
+        .collect()
+}
+
+/// Plans ripples, spotlight frames and click sounds from pointer events in source pixels. Times
+/// are in program time, like the redrawn cursor: a click plays once for every timeline clip of
+/// `take_id` that shows it, and `clips` turn effects on or off per clip. The spotlight follows
+/// the cursor path smoothed by `smoothing` across `frames`.
+pub(crate) fn plan_click_effects(
+    events: &[InputEvent],
+    settings: &ClickEffectsParams,
+    clips: &[ResolvedTimelineClip],
+    take_id: Option<&str>,
+    smoothing: f64,
+    frames: &[TakeFrame],
+) -> ClickEffects {
+    let segments = segments(settings, clips, take_id);
+    let mut effects = ClickEffects::default();
+
+    let mut clicks = events
+        .iter()
+        .filter(|event| event.event_type == InputEventType::MouseDown)
+        .collect::<Vec<_>>();
+    clicks.sort_by(|left, right| left.timestamp.total_cmp(&right.timestamp));
+    for click in clicks {
+        for segment in &segments {
+            let Some(time) = segment.program_time(click.timestamp) else {
+                continue;
+            };
+            if segment.ripple {
+                effects.ripples.push(ClickRipple {
+                    clip_id: segment.clip_id(),
+                    start_seconds: time,
+                    end_seconds: time + settings.ripple.duration_seconds,
+                    x: click.position.x,
+                    y: click.position.y,
+                    max_radius: settings.ripple.max_radius,
+                    color: settings.ripple.color.clone(),
+                    button: click.button,
+                });
+            }
+            if segment.click_sound {
+                effects.click_sounds.push(ClickSoundCue {
+                    clip_id: segment.clip_id(),
+                    time,
+                    volume: settings.click_sound.volume,
+                });
+            }
+        }
+    }
+    effects
+        .ripples
+        .sort_by(|left, right| left.start_seconds.total_cmp(&right.start_seconds));
+    effects
+        .click_sounds
+        .sort_by(|left, right| left.time.total_cmp(&right.time));
+
+    if segments.iter().any(|segment| segment.spotlight) {
+        if let Some(path) = CursorPath::new(events, smoothing) {
+            effects.spotlight = frames
+                .iter()
+                .filter_map(|frame| {
+                    let segment = segments
+                        .iter()
+                        .find(|segment| {
+                            segment.clip.map(|clip| &clip.clip_id)
+                                == frame.clip.map(|clip| &clip.clip_id)
+                        })
+                        .filter(|segment| segment.spotlight)?;
+                    let position = path.at(frame.source_time);
+                    Some(SpotlightFrame {
+                        clip_id: segment.clip_id(),
+                        time: frame.program_time,
+                        x: position.x,
+                        y: position.y,
+                        radius: settings.spotlight.radius,
+                        feather: settings.spotlight.feather,
+                        dim_opacity: settings.spotlight.dim_opacity,
+                    })
+                })
+                .collect();
+        }
+    }
+    effects
+}
+
+/// Interleaved stereo samples at the audio sample rate with a short decaying tone at every cue,
+/// mixed into the export's audio. Overlapping clicks add up and saturate.
+pub(crate) fn click_sound_samples(cues: &[ClickSoundCue]) -> Vec<i16> {
+    let rate = AUDIO_SAMPLE_RATE as f64;
+    let channels = AUDIO_CHANNELS as usize;
+    let click_frames = (CLICK_SOUND_SECONDS * rate).round() as usize;
+    let frame_count = cues
+        .iter()
+        .map(|cue| (cue.time.max(0.0) * rate).round() as usize + click_frames)
+        .max()
+        .unwrap_or(0);
+    let mut samples = vec![0i16; frame_count * channels];
+    for cue in cues {
+        let start = (cue.time.max(0.0) * rate).round() as usize;
+        for frame in 0..click_frames {
+            let elapsed = frame as f64 / rate;
+            let value = cue.volume
+                * (-CLICK_SOUND_DECAY * elapsed).exp()
+                * (TAU * CLICK_SOUND_FREQUENCY * elapsed).sin()
+                * f64::from(i16::MAX);
+            let value = value.round() as i16;
+            for channel in 0..channels {
+                let sample = &mut samples[(start + frame) * channels + channel];
+                *sample = sample.saturating_add(value);
+            }
+        }
+    }
+    samples
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{click_sound_samples, plan_click_effects, ClickSoundCue};
+    use crate::input_tracking::{InputEventLog, MouseButton};
+    use crate::params::{ClickEffectSegmentParams, ClickEffectsParams, SettingsGroup};
+    use crate::timeline::{take_frames, ClipAudio, ClipSpeed, ResolvedTimelineClip};
+
+    const JITTERY_POINTER: &str =
+        include_str!("../../../docs/fixtures/cursor/jittery-pointer.json");
+
+    fn events() -> InputEventLog {
+        serde_json::from_str(JITTERY_POINTER).expect("fixture decodes")
+    }
+
+    fn enabled() -> ClickEffectsParams {
+        let mut settings = ClickEffectsParams::default();
+        settings.ripple.enabled = true;
+        settings.spotlight.enabled = true;
+        settings.click_sound.enabled = true;
+        settings.validated().expect("valid settings")
+    }
+
+    fn clip(clip_id: &str, start: f64, end: f64) -> ResolvedTimelineClip {
+        ResolvedTimelineClip {
+            clip_id: clip_id.to_string(),
+            take_id: "take-1".to_string(),
+            recording_url: "/tmp/take-1.mov".to_string(),
+            source_start_seconds: start,
+            source_end_seconds: end,
+            program_start_seconds: start,
+            program_end_seconds: end,
+            speed: ClipSpeed::default(),
+            audio: ClipAudio::Normal,
+        }
+    }
+
+    #[test]
+    fn clicks_produce_ripples_sounds_and_a_spotlight_that_follows_the_cursor() {
+        let log = events();
+        let frames = take_frames(&[], None, 30, 5.0);
+        let effects = plan_click_effects(&log.events, &enabled(), &[], None, 1.0, &frames);
+
+        assert_eq!(effects.ripples.len(), 2);
+        let first = &effects.ripples[0];
+        assert_eq!((first.start_seconds, first.x, first.y), (1.0, 500.0, 300.0));
+        assert_eq!(first.end_seconds, 1.4);
+        assert_eq!(first.button, Some(MouseButton::Left));
+        assert_eq!(effects.ripples[1].button, Some(MouseButton::Right));
+        assert_eq!(
+            effects
+                .click_sounds
+                .iter()
+                .map(|cue| cue.time)
+                .collect::<Vec<_>>(),
+            vec![1.0, 4.5]
+        );
+
+        assert_eq!(effects.spotlight.len(), 150);
+        let on_click = &effects.spotlight[30];
+        assert_eq!((on_click.x, on_click.y), (500.0, 300.0));
+        let end = effects.spotlight.last().expect("last frame");
+        assert_eq!((end.x, end.y, end.radius), (900.0, 600.0, 180.0));
+    }
+
+    #[test]
+    fn segment_toggles_override_the_project_settings_per_clip() {
+        let log = events();
+        let mut settings = enabled();
+        settings.spotlight.enabled = false;
+        settings.segments = vec![
+            ClickEffectSegmentParams {
+                clip_id: "intro".to_string(),
+                ripple: Some(false),
+                spotlight: None,
+                click_sound: None,
+            },
+            ClickEffectSegmentParams {
+                clip_id: "outro".to_string(),
+                ripple: None,
+                spotlight: Some(true),
+                click_sound: Some(false),
+            },
+        ];
+        let clips = [clip("intro", 0.0, 2.0), clip("outro", 4.0, 5.0)];
+        let frames = take_frames(&clips, Some("take-1"), 30, 0.0);
+        let effects =
+            plan_click_effects(&log.events, &settings, &clips, Some("take-1"), 0.5, &frames);
+
+        let ripple_clips = effects
+            .ripples
+            .iter()
+            .map(|ripple| ripple.clip_id.as_deref())
+            .collect::<Vec<_>>();
+        assert_eq!(ripple_clips, vec![Some("outro")]);
+        let sound_clips = effects
+            .click_sounds
+            .iter()
+            .map(|cue| cue.clip_id.as_deref())
+            .collect::<Vec<_>>();
+        assert_eq!(sound_clips, vec![Some("intro")]);
+        assert_eq!(effects.spotlight.len(), 30);
+        assert!(effects
+            .spotlight
+            .iter()
+            .all(|frame| frame.clip_id.as_deref() == Some("outro") && frame.time >= 4.0));
+
+        let other_take = plan_click_effects(
+            &log.events,
+            &settings,
+            &clips,
+            Some("take-2"),
+            0.5,
+            &take_frames(&clips, Some("take-2"), 30, 0.0),
+        );
+        assert!(other_take.is_empty());
+        let disabled = plan_click_effects(
+            &log.events,
+            &ClickEffectsParams::default(),
+            &[],
+            None,
+            0.5,
+            &take_frames(&[], None, 30, 5.0),
+        );
+        assert!(disabled.is_empty());
+    }
+
+    #[test]
+    fn click_sounds_are_short_decaying_bursts_at_each_cue() {
+        let cue = |time: f64| ClickSoundCue {
+            clip_id: None,
+            time,
+            volume: 1.0,
+        };
+        let samples = click_sound_samples(&[cue(0.0), cue(0.5)]);
+        // Half a second of stereo frames, then one more 30 ms click.
+        assert_eq!(samples.len(), (24_000 + 1_440) * 2);
+        let loudest = |range: std::ops::Range<usize>| {
+            samples[range]
+                .iter()
+                .map(|sample| sample.unsigned_abs())
+                .max()
+                .unwrap_or(0)
+        };
+        assert!(loudest(0..200) > 20_000);
+        assert!(loudest(2_400..2_880) < loudest(0..200) / 4);
+        assert_eq!(loudest(2_880..48_000), 0);
+        assert!(loudest(48_000..48_200) > 20_000);
+        assert_eq!(samples[0..96], samples[48_000..48_096]);
+        assert_eq!(click_sound_samples(&[cue(0.0), cue(0.5)]), samples);
+        assert!(click_sound_samples(&[]).is_empty());
+    }
+}
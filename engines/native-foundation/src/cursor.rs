@@ -0,0 +1,319 @@
+use crate::input_tracking::{InputEvent, InputEventType, InputPoint};
+use crate::params::CursorRenderingParams;
+use crate::timeline::TakeFrame;
+use serde::Serialize;
+
+/// Spacing of the spline's control points at full smoothing.
+const MAX_CONTROL_SPACING_SECONDS: f64 = 0.25;
+/// How long an idle cursor takes to fade out.
+const IDLE_FADE_SECONDS: f64 = 0.25;
+
+/// The redrawn cursor in one export frame, in source pixels; drawn before the camera crop so it
+/// zooms with the content.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct CursorFrame {
+    pub(crate) time: f64,
+    pub(crate) x: f64,
+    pub(crate) y: f64,
+    pub(crate) scale: f64,
+    pub(crate) opacity: f64,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct ControlPoint {
+    time: f64,
+    position: InputPoint,
+}
+
+/// Points the smoothed path passes through. Smoothing averages the recorded positions around
+/// evenly spaced times, which irons out jitter; clicks are kept exactly so the cursor lands
+/// where the button went down.
+fn control_points(events: &[InputEvent], smoothing: f64) -> Vec<ControlPoint> {
+    let raw = |event: &InputEvent| ControlPoint {
+        time: event.timestamp,
+        position: event.position,
+    };
+    let spacing = smoothing * MAX_CONTROL_SPACING_SECONDS;
+    let (Some(first), Some(last)) = (events.first(), events.last()) else {
+        return Vec::new();
+    };
+    if spacing <= 0.0 {
+        return events.iter().map(raw).collect();
+    }
+    let clicks = events
+        .iter()
+        .filter(|event| event.event_type == InputEventType::MouseDown)
+        .map(raw)
+        .collect::<Vec<_>>();
+    let half_window = spacing / 2.0;
+    let steps = ((last.timestamp - first.timestamp) / spacing).ceil() as usize;
+    let mut held = first.position;
+    let mut controls = Vec::with_capacity(steps + 1 + clicks.len());
+    for step in 0..=steps {
+        let time = (first.timestamp + step as f64 * spacing).min(last.timestamp);
+        let from = events.partition_point(|event| event.timestamp < time - half_window);
+        let to = events.partition_point(|event| event.timestamp < time + half_window);
+        let window = &events[from..to.max(from)];
+        if !window.is_empty() {
+            let count = window.len() as f64;
+            held = InputPoint {
+                x: window.iter().map(|event| event.position.x).sum::<f64>() / count,
+                y: window.iter().map(|event| event.position.y).sum::<f64>() / count,
+            };
+        }
+        if clicks
+            .iter()
+            .any(|click| (click.time - time).abs() < half_window)
+        {
+            continue;
+        }
+        controls.push(ControlPoint {
+            time,
+            position: held,
+        });
+    }
+    controls.extend(clicks);
+    controls.sort_by(|left, right| left.time.total_cmp(&right.time));
+    controls
+}
+
+/// The smoothed pointer path, shared by the redrawn cursor and effects that follow it.
+#[derive(Clone, Debug)]
+pub(crate) struct CursorPath {
+    controls: Vec<ControlPoint>,
+}
+
+impl CursorPath {
+    /// `None` when no pointer input was recorded.
+    pub(crate) fn new(events: &[InputEvent], smoothing: f64) -> Option<Self> {
+        let events = sorted(events);
+        let controls = control_points(&events, smoothing);
+        (!controls.is_empty()).then_some(Self { controls })
+    }
+
+    pub(crate) fn at(&self, time: f64) -> InputPoint {
+        position_at(&self.controls, time)
+    }
+}
+
+fn sorted(events: &[InputEvent]) -> Vec<InputEvent> {
+    let mut events = events.to_vec();
+    events.sort_by(|left, right| left.timestamp.total_cmp(&right.timestamp));
+    events
+}
+
+/// Cubic Hermite spline through `controls` with finite-difference (Catmull-Rom) tangents,
+/// holding the ends.
+fn position_at(controls: &[ControlPoint], time: f64) -> InputPoint {
+    let next = controls.partition_point(|control| control.time <= time);
+    if next == 0 {
+        return controls[0].position;
+    }
+    let Some(&end) = controls.get(next) else {
+        return controls[next - 1].position;
+    };
+    let start = controls[next - 1];
+    let span = end.time - start.time;
+    if span <= 0.0 {
+        return end.position;
+    }
+    let tangent = |index: usize| {
+        let before = controls[index.saturating_sub(1)];
+        let after = controls[(index + 1).min(controls.len() - 1)];
+        let elapsed = after.time - before.time;
+        if elapsed > 0.0 {
+            InputPoint {
+                x: (after.position.x - before.position.x) / elapsed * span,
+                y: (after.position.y - before.position.y) / elapsed * span,
+            }
+        } else {
+            InputPoint { x: 0.0, y: 0.0 }
+        }
+    };
+    let (start_tangent, end_tangent) = (tangent(next - 1), tangent(next));
+    let s = (time - start.time) / span;
+    let (s2, s3) = (s * s, s * s * s);
+    let h00 = 2.0 * s3 - 3.0 * s2 + 1.0;
+    let h10 = s3 - 2.0 * s2 + s;
+    let h01 = -2.0 * s3 + 3.0 * s2;
+    let h11 = s3 - s2;
+    InputPoint {
+        x: h00 * start.position.x
+            + h10 * start_tangent.x
+            + h01 * end.position.x
+            + h11 * end_tangent.x,
+        y: h00 * start.position.y
+            + h10 * start_tangent.y
+            + h01 * end.position.y
+            + h11 * end_tangent.y,
+    }
+}
+
+/// Full opacity until the pointer has been still for `hide_after_idle_seconds`, then a short
+/// fade; the recording start counts as activity.
+fn opacity_at(events: &[InputEvent], hide_after_idle_seconds: f64, time: f64) -> f64 {
+    if hide_after_idle_seconds <= 0.0 {
+        return 1.0;
+    }
+    let latest = events.partition_point(|event| event.timestamp <= time);
+    let last_activity = latest
+        .checked_sub(1)
+        .map_or(0.0, |index| events[index].timestamp);
+    let idle = time - last_activity - hide_after_idle_seconds;
+    (1.0 - idle / IDLE_FADE_SECONDS).clamp(0.0, 1.0)
+}
+
+/// Plans the redrawn cursor for every export frame of `frames` from pointer events in source
+/// pixels, placed at the frame's program time. `None` when cursor rendering is off or nothing
+/// was recorded.
+pub(crate) fn plan_cursor(
+    events: &[InputEvent],
+    settings: &CursorRenderingParams,
+    frames: &[TakeFrame],
+) -> Option<Vec<CursorFrame>> {
+    if !settings.enabled || frames.is_empty() {
+        return None;
+    }
+    let path = CursorPath::new(events, settings.smoothing)?;
+    let events = sorted(events);
+    Some(
+        frames
+            .iter()
+            .map(|frame| {
+                let position = path.at(frame.source_time);
+                CursorFrame {
+                    time: frame.program_time,
+                    x: position.x,
+                    y: position.y,
+                    scale: settings.scale,
+                    opacity: opacity_at(
+                        &events,
+                        settings.hide_after_idle_seconds,
+                        frame.source_time,
+                    ),
+                }
+            })
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{plan_cursor, CursorFrame};
+    use crate::input_tracking::InputEventLog;
+    use crate::params::{CursorRenderingParams, SettingsGroup};
+    use crate::timeline::{take_frames, ClipAudio, ClipSpeed, ResolvedTimelineClip};
+
+    const JITTERY_POINTER: &str =
+        include_str!("../../../docs/fixtures/cursor/jittery-pointer.json");
+
+    fn plan(settings: CursorRenderingParams) -> Vec<CursorFrame> {
+        let log: InputEventLog = serde_json::from_str(JITTERY_POINTER).expect("fixture decodes");
+        plan_cursor(&log.events, &settings, &take_frames(&[], None, 30, 5.0))
+            .expect("cursor overlay")
+    }
+
+    fn settings(smoothing: f64) -> CursorRenderingParams {
+        CursorRenderingParams {
+            enabled: true,
+            smoothing,
+            hide_after_idle_seconds: 1.0,
+            ..CursorRenderingParams::default()
+        }
+        .validated()
+        .expect("valid settings")
+    }
+
+    /// Largest distance from the point the jitter shakes around while it lasts.
+    fn jitter(frames: &[CursorFrame]) -> f64 {
+        frames
+            .iter()
+            .filter(|frame| (0.2..=1.8).contains(&frame.time))
+            .map(|frame| (frame.x - 500.0).hypot(frame.y - 300.0))
+            .fold(0.0, f64::max)
+    }
+
+    #[test]
+    fn smoothing_irons_out_jitter_and_lands_clicks_exactly() {
+        let raw = plan(settings(0.0));
+        let smoothed = plan(settings(1.0));
+        assert_eq!(raw.len(), 150);
+        assert!(jitter(&raw) >= 4.0, "raw jitter {}", jitter(&raw));
+        assert!(
+            jitter(&smoothed) < 1.0,
+            "smoothed jitter {}",
+            jitter(&smoothed)
+        );
+
+        for frames in [&raw, &smoothed] {
+            let click = frames[30];
+            assert_eq!((click.time, click.x, click.y), (1.0, 500.0, 300.0));
+            let end = frames.last().expect("last frame");
+            assert_eq!((end.x, end.y), (900.0, 600.0));
+        }
+        assert_eq!(plan(settings(1.0)), smoothed);
+    }
+
+    #[test]
+    fn idle_cursor_fades_out_and_returns_with_input() {
+        let frames = plan(settings(0.5));
+        let opacity = |frame: usize| frames[frame].opacity;
+        // The jitter stops at 1.9833 s, so the fade runs from 2.9833 s to 3.2333 s.
+        assert_eq!(opacity(89), 1.0);
+        assert!(opacity(93) > 0.0 && opacity(93) < 1.0);
+        assert_eq!(opacity(99), 0.0);
+        assert_eq!(opacity(120), 1.0);
+
+        let always_visible = plan(CursorRenderingParams {
+            hide_after_idle_seconds: 0.0,
+            ..settings(0.5)
+        });
+        assert!(always_visible.iter().all(|frame| frame.opacity == 1.0));
+    }
+
+    #[test]
+    fn frames_follow_the_speed_of_the_clip_that_plays_them() {
+        let clips = [ResolvedTimelineClip {
+            clip_id: "fast".to_string(),
+            take_id: "take-1".to_string(),
+            recording_url: "/tmp/take-1.mov".to_string(),
+            source_start_seconds: 0.0,
+            source_end_seconds: 4.0,
+            program_start_seconds: 0.0,
+            program_end_seconds: 2.0,
+            speed: ClipSpeed {
+                playback_rate: 2.0,
+                ..ClipSpeed::default()
+            },
+            audio: ClipAudio::PitchPreserved,
+        }];
+        let log: InputEventLog = serde_json::from_str(JITTERY_POINTER).expect("fixture decodes");
+        let frames = take_frames(&clips, Some("take-1"), 30, 0.0);
+        let fast = plan_cursor(&log.events, &settings(0.5), &frames).expect("cursor overlay");
+        let real_time = plan(settings(0.5));
+
+        assert_eq!(fast.len(), 60);
+        assert_eq!(fast[15].time, 0.5);
+        assert_eq!((fast[15].x, fast[15].y), (real_time[30].x, real_time[30].y));
+        assert!(take_frames(&clips, Some("take-2"), 30, 0.0).is_empty());
+    }
+
+    #[test]
+    fn frames_carry_the_cursor_scale_and_disabled_rendering_plans_nothing() {
+        let frames = plan(CursorRenderingParams {
+            scale: 2.0,
+            ..settings(0.5)
+        });
+        assert!(frames.iter().all(|frame| frame.scale == 2.0));
+
+        let disabled = CursorRenderingParams {
+            enabled: false,
+            ..settings(0.5)
+        };
+        let frames = take_frames(&[], None, 30, 1.0);
+        assert_eq!(plan_cursor(&[], &settings(0.5), &frames), None);
+        let log: InputEventLog = serde_json::from_str(JITTERY_POINTER).expect("fixture decodes");
+        assert_eq!(plan_cursor(&log.events, &disabled, &frames), None);
+    }
+}
@@ -0,0 +1,163 @@
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+/// Recordings stop before free space falls below this, leaving room to close and journal them.
+pub(crate) const LOW_DISK_SPACE_RESERVE_BYTES: u64 = 256 * 1024 * 1024;
+/// `recording.start` requires room for this much media on top of the reserve.
+const PREFLIGHT_RECORDING_SECONDS: u64 = 60;
+
+/// A recording re-reads free space this often; bytes written in between are deducted from the
+/// last reading.
+const RECORDING_DISK_PROBE_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Returns the bytes available to unprivileged writers on the volume holding `path`.
+pub(crate) type DiskSpaceProbe = fn(&Path) -> Option<u64>;
+
+/// Probes the nearest existing ancestor, so targets that have not been created yet still
+/// resolve to the volume they will land on.
+pub(crate) fn available_bytes(path: &Path) -> Option<u64> {
+    let existing = path.ancestors().find(|candidate| candidate.exists())?;
+    volume_available_bytes(existing)
+}
+
+#[cfg(unix)]
+#[allow(clippy::unnecessary_cast)]
+fn volume_available_bytes(path: &Path) -> Option<u64> {
+    use std::ffi::CString;
+    use std::os::unix::ffi::OsStrExt;
+
+    let path = CString::new(path.as_os_str().as_bytes()).ok()?;
+    let mut stats = std::mem::MaybeUninit::<libc::statvfs>::uninit();
+    // SAFETY: `path` is NUL-terminated and `stats` points to writable storage for statvfs.
+    if unsafe { libc::statvfs(path.as_ptr(), stats.as_mut_ptr()) } != 0 {
+        return None;
+    }
+    // SAFETY: statvfs returned success, so it initialised `stats`.
+    let stats = unsafe { stats.assume_init() };
+    Some((stats.f_bavail as u64).saturating_mul(stats.f_frsize as u64))
+}
+
+#[cfg(not(unix))]
+fn volume_available_bytes(_path: &Path) -> Option<u64> {
+    None
+}
+
+/// Free space `recording.start` insists on for a writer producing `bytes_per_second`.
+pub(crate) fn required_recording_bytes(bytes_per_second: u64) -> u64 {
+    LOW_DISK_SPACE_RESERVE_BYTES
+        .saturating_add(bytes_per_second.saturating_mul(PREFLIGHT_RECORDING_SECONDS))
+}
+
+pub(crate) fn megabytes(bytes: u64) -> u64 {
+    bytes / (1024 * 1024)
+}
+
+/// Free space shared by every track of one recording. The screen, camera and audio writers
+/// draw from one budget, so together they stop at the reserve instead of each passing its own
+/// check against the same free space.
+pub(crate) struct RecordingDiskBudget {
+    path: PathBuf,
+    probe: DiskSpaceProbe,
+    usage: Mutex<RecordingDiskUsage>,
+}
+
+#[derive(Default)]
+struct RecordingDiskUsage {
+    available: Option<u64>,
+    probed_at: Option<Instant>,
+    written_since_probe: u64,
+    exhausted: bool,
+}
+
+impl RecordingDiskBudget {
+    pub(crate) fn new(path: &Path, probe: DiskSpaceProbe) -> Self {
+        Self {
+            path: path.to_path_buf(),
+            probe,
+            usage: Mutex::default(),
+        }
+    }
+
+    /// Charges `bytes` any track is about to write. Fails with `StorageFull`, and keeps failing
+    /// for every track, once the write would leave less than the reserve free.
+    pub(crate) fn reserve(&self, bytes: u64) -> io::Result<()> {
+        let mut usage = self.usage.lock().unwrap_or_else(PoisonError::into_inner);
+        if !usage.exhausted {
+            if usage
+                .probed_at
+                .is_none_or(|probed_at| probed_at.elapsed() >= RECORDING_DISK_PROBE_INTERVAL)
+            {
+                usage.available = (self.probe)(&self.path);
+                usage.probed_at = Some(Instant::now());
+                usage.written_since_probe = 0;
+            }
+            usage.written_since_probe = usage.written_since_probe.saturating_add(bytes);
+            let Some(available) = usage.available else {
+                return Ok(());
+            };
+            if available.saturating_sub(usage.written_since_probe) >= LOW_DISK_SPACE_RESERVE_BYTES {
+                return Ok(());
+            }
+            usage.exhausted = true;
+        }
+        Err(io::Error::new(
+            io::ErrorKind::StorageFull,
+            format!(
+                "free disk space is below the {} MB recording reserve",
+                megabytes(LOW_DISK_SPACE_RESERVE_BYTES)
+            ),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        available_bytes, required_recording_bytes, RecordingDiskBudget,
+        LOW_DISK_SPACE_RESERVE_BYTES,
+    };
+    use std::io;
+    use std::path::Path;
+
+    #[test]
+    fn required_bytes_cover_reserve_plus_a_minute_of_media() {
+        assert_eq!(required_recording_bytes(0), LOW_DISK_SPACE_RESERVE_BYTES);
+        assert_eq!(
+            required_recording_bytes(1_000_000),
+            LOW_DISK_SPACE_RESERVE_BYTES + 60_000_000
+        );
+        assert_eq!(required_recording_bytes(u64::MAX), u64::MAX);
+    }
+
+    #[test]
+    fn tracks_sharing_a_budget_stop_together_at_the_reserve() {
+        let budget = RecordingDiskBudget::new(Path::new("/recordings"), |_| {
+            Some(LOW_DISK_SPACE_RESERVE_BYTES + 1_000)
+        });
+        budget.reserve(600).expect("screen frame fits");
+        let camera = budget
+            .reserve(600)
+            .expect_err("camera frame overdraws the reserve");
+        assert_eq!(camera.kind(), io::ErrorKind::StorageFull);
+        let audio = budget
+            .reserve(1)
+            .expect_err("audio stops with the other tracks");
+        assert_eq!(audio.kind(), io::ErrorKind::StorageFull);
+    }
+
+    #[test]
+    fn budgets_without_a_free_space_reading_never_stop_a_recording() {
+        let budget = RecordingDiskBudget::new(Path::new("/recordings"), |_| None);
+        budget.reserve(u64::MAX).expect("unknown free space");
+        budget.reserve(u64::MAX).expect("still unknown free space");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn available_bytes_resolves_missing_targets_to_an_existing_ancestor() {
+        let missing = std::env::temp_dir().join("gglass-disk-missing/a/b/c.gglassproj");
+        assert!(available_bytes(&missing).is_some());
+    }
+}
@@ -1,10 +1,93 @@
-use crate::params::{ExportRunCutPlanParams, ExportRunParams};
+use crate::audio::WavWriter;
+use crate::automation::{
+    map_events_to_source, plan_camera, CameraKeyframe, CameraPlan, Size, ZoomConstraints,
+};
+use crate::camera_path::{frame_crops, CameraEasing, CameraPlanSource, PlannedCameraPlan};
+use crate::click_effects::{click_sound_samples, plan_click_effects};
+use crate::cursor::plan_cursor;
+use crate::input_tracking::{
+    InputEvent, InputEventLog, InputPoint, INPUT_EVENT_LOG_SCHEMA_VERSION,
+};
+use crate::params::{
+    resolve_settings, AutoZoomParams, BackgroundFramingParams, CameraPlanParams,
+    ClickEffectsParams, CursorRenderingParams, ExportRunCutPlanParams, ExportRunParams,
+    PictureInPictureParams, ReframeParams, ShortcutOverlayParams,
+};
 use crate::path_security::{reject_final_symlink, write_file_no_symlink};
+use crate::picture_in_picture;
+use crate::shortcuts::{plan_badges, ShortcutBadge};
 use crate::state::State;
-use crate::wire::{failure, success, EngineCallId, EngineResponse, ProtocolErrorCode};
+use crate::timeline::{
+    program_duration, program_frames, program_spans, resolve_clips, take_frames,
+    ResolvedTimelineClip,
+};
+use crate::wire::{
+    decode_params, failure, success, EngineCallId, EngineResponse, ProtocolErrorCode,
+};
 use serde_json::{json, Value};
 use std::path::{Path, PathBuf};
 
+/// An output format offered by `export.info`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct ExportPreset {
+    id: &'static str,
+    name: &'static str,
+    width: u32,
+    height: u32,
+    fps: u32,
+}
+
+impl ExportPreset {
+    pub(crate) fn size(&self) -> Size {
+        Size {
+            width: self.width as f64,
+            height: self.height as f64,
+        }
+    }
+
+    /// Whether the preset is narrower than a landscape recording, so its window has to pan
+    /// across the source to keep the cursor in view.
+    fn reframes(&self) -> bool {
+        self.width * 9 < self.height * 16
+    }
+}
+
+/// Used when an export names no preset.
+pub(crate) const DEFAULT_EXPORT_PRESET: ExportPreset = ExportPreset {
+    id: "h264-1080p-30",
+    name: "1080p 30fps",
+    width: 1920,
+    height: 1080,
+    fps: 30,
+};
+const EXPORT_PRESETS: [ExportPreset; 4] = [
+    DEFAULT_EXPORT_PRESET,
+    ExportPreset {
+        id: "h264-vertical-1080x1920-30",
+        name: "Vertical 9:16 30fps",
+        width: 1080,
+        height: 1920,
+        fps: 30,
+    },
+    ExportPreset {
+        id: "h264-square-1080-30",
+        name: "Square 1:1 30fps",
+        width: 1080,
+        height: 1080,
+        fps: 30,
+    },
+    ExportPreset {
+        id: "h264-portrait-1080x1350-30",
+        name: "Portrait 4:5 30fps",
+        width: 1080,
+        height: 1350,
+        fps: 30,
+    },
+];
+
+/// Version of the manifest written next to each export.
+const EXPORT_MANIFEST_SCHEMA_VERSION: u32 = 1;
+
 fn validate_export_path(id: &EngineCallId, output_url: &str) -> Result<(), EngineResponse> {
     let path = Path::new(output_url);
     if !path.is_absolute() {
@@ -34,48 +117,316 @@ fn validate_export_path(id: &EngineCallId, output_url: &str) -> Result<(), Engin
     Ok(())
 }
 
-fn decode_params<T>(params: &Value) -> T
-where
-    T: for<'de> serde::Deserialize<'de> + Default,
-{
-    serde_json::from_value(params.clone()).unwrap_or_default()
+/// The current events log; placeholder recordings have none.
+pub(crate) fn recorded_input(state: &State) -> Result<Option<InputEventLog>, String> {
+    let Some(events_path) = state.events_url.as_deref().map(Path::new) else {
+        return Ok(None);
+    };
+    if !events_path.is_absolute() || !events_path.exists() {
+        return Ok(None);
+    }
+    reject_final_symlink(events_path)
+        .map_err(|error| format!("Input events failed symlink safety validation: {error}"))?;
+    let data = std::fs::read(events_path)
+        .map_err(|error| format!("Unable to read input events: {error}"))?;
+    serde_json::from_slice::<InputEventLog>(&data)
+        .map(Some)
+        .map_err(|error| format!("Unable to decode input events: {error}"))
+}
+
+/// Screen rectangle covered by the active recording, from its capture metadata.
+pub(crate) fn content_rect(state: &State) -> Option<(InputPoint, Size)> {
+    let metadata = state
+        .active_take()
+        .and_then(|take| take.capture_metadata.as_ref())
+        .or(state.capture_metadata.as_ref())?;
+    let rect = metadata.get("contentRect")?;
+    let value = |key: &str| rect.get(key).and_then(Value::as_f64);
+    Some((
+        InputPoint {
+            x: value("x")?,
+            y: value("y")?,
+        },
+        Size {
+            width: value("width")?,
+            height: value("height")?,
+        },
+    ))
+}
+
+fn export_preset(preset_id: Option<&str>) -> Option<ExportPreset> {
+    let Some(preset_id) = preset_id else {
+        return Some(DEFAULT_EXPORT_PRESET);
+    };
+    EXPORT_PRESETS
+        .into_iter()
+        .find(|preset| preset.id == preset_id)
+}
+
+/// Size of the recorded frame, which camera keyframes are expressed in.
+pub(crate) fn source_size(content_rect: Option<(InputPoint, Size)>) -> Size {
+    content_rect
+        .map(|(_, size)| size)
+        .filter(|size| size.width > 0.0 && size.height > 0.0)
+        .unwrap_or_else(|| DEFAULT_EXPORT_PRESET.size())
+}
+
+/// Recorded pointer input moved into source pixels.
+pub(crate) fn source_events(state: &State, events: &[InputEvent]) -> Vec<InputEvent> {
+    let content_rect = content_rect(state);
+    map_events_to_source(events, content_rect, source_size(content_rect))
+}
+
+fn recording_duration(state: &State) -> f64 {
+    state
+        .active_take()
+        .map(|take| take.duration_seconds)
+        .unwrap_or_else(|| state.current_duration())
+        .max(0.0)
+}
+
+/// Auto-zoom plan for the active recording with `overrides` applied to the project settings,
+/// planned like the Swift export: input is mapped into the recorded frame and framed for
+/// `output_size`. `None` when auto-zoom is off.
+pub(crate) fn auto_zoom_plan(
+    state: &State,
+    overrides: AutoZoomParams,
+    events: &[InputEvent],
+    output_size: Size,
+) -> Option<CameraPlan> {
+    if !overrides.is_enabled.unwrap_or(state.auto_zoom_enabled) {
+        return None;
+    }
+    let constraints = ZoomConstraints::for_auto_zoom(
+        overrides.intensity.unwrap_or(state.auto_zoom_intensity),
+        overrides
+            .minimum_keyframe_interval
+            .unwrap_or(state.auto_zoom_min_keyframe_interval),
+    );
+    let content_rect = content_rect(state);
+    let source_size = source_size(content_rect);
+    let events = if constraints.click_intensity > 0.0 {
+        map_events_to_source(events, content_rect, source_size)
+    } else {
+        Vec::new()
+    };
+    Some(plan_camera(
+        &events,
+        source_size,
+        recording_duration(state),
+        Some(output_size),
+        &constraints,
+    ))
+}
+
+/// Pans a window of the preset's aspect ratio across the recording to follow the cursor,
+/// planned with the attention model at a fixed zoom. `None` when reframing is off or the preset
+/// already matches a landscape recording.
+fn reframe_plan(
+    state: &State,
+    settings: &ReframeParams,
+    preset: ExportPreset,
+    events: &[InputEvent],
+) -> Option<CameraPlan> {
+    if !settings.enabled || !preset.reframes() {
+        return None;
+    }
+    let constraints =
+        ZoomConstraints::for_reframe(settings.smoothing, settings.safe_margin_fraction);
+    Some(plan_camera(
+        &source_events(state, events),
+        source_size(content_rect(state)),
+        recording_duration(state),
+        Some(preset.size()),
+        &constraints,
+    ))
+}
+
+/// The camera plan an export applies: keyframes sent with the request, then the project's
+/// keyframe track, then an auto-zoom plan from the recorded input, then a reframe for presets
+/// narrower than the recording.
+fn camera_plan(
+    state: &State,
+    preset: ExportPreset,
+    requested: Option<CameraPlanParams>,
+    auto_zoom: Option<AutoZoomParams>,
+    reframe: &ReframeParams,
+    events: &[InputEvent],
+) -> Option<PlannedCameraPlan> {
+    let output_size = preset.size();
+    let keyframed_plan = |keyframes: Vec<CameraKeyframe>| {
+        let last_keyframe = keyframes.last().map_or(0.0, |keyframe| keyframe.time);
+        CameraPlan {
+            source_size: source_size(content_rect(state)),
+            output_aspect_ratio: Some(output_size.width / output_size.height),
+            keyframes,
+            duration: recording_duration(state).max(last_keyframe),
+        }
+    };
+
+    if let Some(requested) = requested {
+        return Some(PlannedCameraPlan {
+            source: CameraPlanSource::Payload,
+            easing: requested.easing,
+            segment_easings: Vec::new(),
+            plan: keyframed_plan(requested.keyframes),
+        });
+    }
+    if !state.camera_keyframes.keyframes.is_empty() {
+        return Some(PlannedCameraPlan {
+            source: CameraPlanSource::KeyframeTrack,
+            easing: CameraEasing::default(),
+            segment_easings: state.camera_keyframes.segment_easings(),
+            plan: keyframed_plan(state.camera_keyframes.camera_keyframes()),
+        });
+    }
+    if let Some(plan) = auto_zoom_plan(state, auto_zoom.unwrap_or_default(), events, output_size) {
+        return Some(PlannedCameraPlan {
+            source: CameraPlanSource::AutoZoom,
+            easing: CameraEasing::default(),
+            segment_easings: Vec::new(),
+            plan,
+        });
+    }
+    reframe_plan(state, reframe, preset, events).map(|plan| PlannedCameraPlan {
+        source: CameraPlanSource::Reframe,
+        easing: CameraEasing::default(),
+        segment_easings: Vec::new(),
+        plan,
+    })
+}
+
+/// Written next to the export so previews and the renderer replay the same plan.
+fn manifest_path(output_path: &Path) -> PathBuf {
+    output_path.with_extension("manifest.json")
+}
+
+/// Click sounds rendered as a stem for the audio mix.
+fn click_sound_path(output_path: &Path) -> PathBuf {
+    output_path.with_extension("clicks.wav")
+}
+
+fn write_click_sounds(path: &Path, samples: &[i16]) -> std::io::Result<()> {
+    let mut writer = WavWriter::create(path)?;
+    writer.append(samples)?;
+    writer.finish()
+}
+
+/// Badges moved from the active take's time onto the program timeline, so they follow cuts and
+/// speed changes. Without clips the take plays as recorded.
+fn program_badges(
+    badges: Vec<ShortcutBadge>,
+    clips: &[ResolvedTimelineClip],
+    take_id: Option<&str>,
+) -> Vec<ShortcutBadge> {
+    if clips.is_empty() {
+        return badges;
+    }
+    let Some(take_id) = take_id else {
+        return Vec::new();
+    };
+    badges
+        .into_iter()
+        .flat_map(|badge| {
+            program_spans(clips, take_id, badge.start_seconds, badge.end_seconds)
+                .into_iter()
+                .map(move |(start_seconds, end_seconds)| ShortcutBadge {
+                    start_seconds,
+                    end_seconds,
+                    ..badge.clone()
+                })
+        })
+        .collect()
 }
 
 pub(crate) fn info(id: &EngineCallId) -> EngineResponse {
     success(
         id,
         json!({
-            "presets": [
-                {
-                    "id": "h264-1080p-30",
-                    "name": "1080p 30fps",
-                    "width": 1920,
-                    "height": 1080,
-                    "fps": 30,
-                    "fileType": "mp4"
-                }
-            ]
+            "presets": EXPORT_PRESETS
+                .iter()
+                .map(|preset| json!({
+                    "id": preset.id,
+                    "name": preset.name,
+                    "width": preset.width,
+                    "height": preset.height,
+                    "fps": preset.fps,
+                    "fileType": "mp4",
+                }))
+                .collect::<Vec<_>>()
         }),
     )
 }
 
+type ExportSettings = (
+    BackgroundFramingParams,
+    ShortcutOverlayParams,
+    CursorRenderingParams,
+    ClickEffectsParams,
+    PictureInPictureParams,
+    ReframeParams,
+);
+
+/// The settings groups an export renders with: the request's, validated, and the project's for
+/// any it leaves out.
+fn export_settings(
+    params: &mut ExportRunParams,
+    state: &State,
+) -> Result<ExportSettings, &'static str> {
+    Ok((
+        resolve_settings(params.background_framing.take(), &state.background_framing)?,
+        resolve_settings(params.shortcut_overlay.take(), &state.shortcut_overlay)?,
+        resolve_settings(params.cursor_rendering.take(), &state.cursor_rendering)?,
+        resolve_settings(params.click_effects.take(), &state.click_effects)?,
+        resolve_settings(params.picture_in_picture.take(), &state.picture_in_picture)?,
+        resolve_settings(params.reframe.take(), &state.reframe)?,
+    ))
+}
+
 pub(crate) fn run(id: &EngineCallId, state: &mut State, params: &Value) -> EngineResponse {
-    let export_params: ExportRunParams = match serde_json::from_value(params.clone()) {
+    let mut export_params = match decode_params::<ExportRunParams>(id, params, "export") {
         Ok(params) => params,
-        Err(error) => {
-            return failure(
-                id,
-                ProtocolErrorCode::InvalidParams,
-                format!("Invalid export payload: {error}"),
-            )
-        }
+        Err(response) => return response,
     };
-    let resolved_background_framing = match export_params.background_framing {
-        Some(settings) => match settings.validated() {
-            Ok(settings) => settings,
+    let (
+        resolved_background_framing,
+        resolved_shortcut_overlay,
+        resolved_cursor_rendering,
+        resolved_click_effects,
+        resolved_picture_in_picture,
+        resolved_reframe,
+    ) = match export_settings(&mut export_params, state) {
+        Ok(settings) => settings,
+        Err(error) => return failure(id, ProtocolErrorCode::InvalidParams, error),
+    };
+    let Some(preset) = export_preset(export_params.preset_id.as_deref()) else {
+        return failure(
+            id,
+            ProtocolErrorCode::InvalidParams,
+            "presetId must name a preset from export.info",
+        );
+    };
+    let timeline_items = match export_params.timeline {
+        Some(timeline) => match timeline.validated() {
+            Ok(items) => items,
             Err(error) => return failure(id, ProtocolErrorCode::InvalidParams, error),
         },
-        None => state.background_framing.clone(),
+        None => state.timeline_items.clone(),
+    };
+    let requested_camera_plan = match export_params.camera_plan {
+        Some(camera_plan) => match camera_plan.validated() {
+            Ok(camera_plan) => Some(camera_plan),
+            Err(error) => return failure(id, ProtocolErrorCode::InvalidParams, error),
+        },
+        None => None,
+    };
+    let timeline_clips = match resolve_clips(
+        &timeline_items,
+        &state.takes,
+        state.active_take_id.as_deref(),
+    ) {
+        Ok(clips) => clips,
+        Err(error) => return failure(id, ProtocolErrorCode::InvalidParams, error),
     };
     let output_url = match export_params.output_url {
         Some(value) => value,
@@ -92,6 +443,58 @@ pub(crate) fn run(id: &EngineCallId, state: &mut State, params: &Value) -> Engin
         return response;
     }
 
+    let input = match recorded_input(state) {
+        Ok(input) => input.unwrap_or_else(|| InputEventLog {
+            schema_version: INPUT_EVENT_LOG_SCHEMA_VERSION,
+            events: Vec::new(),
+            shortcuts: Vec::new(),
+        }),
+        Err(error) => return failure(id, ProtocolErrorCode::RuntimeError, error),
+    };
+    let shortcut_badges = program_badges(
+        plan_badges(&input.shortcuts, &resolved_shortcut_overlay),
+        &timeline_clips,
+        state.active_take_id.as_deref(),
+    );
+    let program_duration_seconds = if timeline_items.is_empty() {
+        recording_duration(state)
+    } else {
+        program_duration(&timeline_items)
+    };
+    let camera_plan = camera_plan(
+        state,
+        preset,
+        requested_camera_plan,
+        export_params.auto_zoom,
+        &resolved_reframe,
+        &input.events,
+    );
+    let pointer_events = source_events(state, &input.events);
+    let frames = take_frames(
+        &timeline_clips,
+        state.active_take_id.as_deref(),
+        preset.fps,
+        pointer_events
+            .iter()
+            .map(|event| event.timestamp)
+            .chain(camera_plan.as_ref().map(|planned| planned.plan.duration))
+            .fold(recording_duration(state), f64::max),
+    );
+    let camera_crops = camera_plan
+        .as_ref()
+        .map(|planned| frame_crops(planned, &frames, preset.size()));
+    let cursor = plan_cursor(&pointer_events, &resolved_cursor_rendering, &frames);
+    let click_effects = plan_click_effects(
+        &pointer_events,
+        &resolved_click_effects,
+        &timeline_clips,
+        state.active_take_id.as_deref(),
+        resolved_cursor_rendering.smoothing,
+        &frames,
+    );
+
+    // Frame rendering is deferred to the native export parity milestone (`docs/SPEC.md` §13):
+    // the output is a placeholder and the effects are only planned.
     let output_path = PathBuf::from(&output_url);
     if let Err(error) = write_file_no_symlink(&output_path, b"guerillaglass-native-export") {
         return failure(
@@ -101,7 +504,83 @@ pub(crate) fn run(id: &EngineCallId, state: &mut State, params: &Value) -> Engin
         );
     }
 
+    let click_sound_path = click_sound_path(&output_path);
+    let click_sound_url = if click_effects.click_sounds.is_empty() {
+        None
+    } else {
+        let samples = click_sound_samples(&click_effects.click_sounds);
+        if let Err(error) = write_click_sounds(&click_sound_path, &samples) {
+            return failure(
+                id,
+                ProtocolErrorCode::PermissionDenied,
+                format!("Unable to write click sound track safely: {error}"),
+            );
+        }
+        Some(click_sound_path.to_string_lossy().into_owned())
+    };
+
+    let picture_in_picture = state
+        .camera_track
+        .as_ref()
+        .filter(|_| resolved_picture_in_picture.enabled)
+        .map(|camera| {
+            picture_in_picture::layout(
+                &resolved_picture_in_picture,
+                (preset.width, preset.height),
+                (camera.width, camera.height),
+            )
+        });
+    let manifest = json!({
+        "schemaVersion": EXPORT_MANIFEST_SCHEMA_VERSION,
+        // Every effect below is only planned; see the placeholder output above.
+        "framesRendered": false,
+        "outputURL": output_url,
+        "presetId": preset.id,
+        "width": preset.width,
+        "height": preset.height,
+        "fps": preset.fps,
+        "cameraPlan": camera_plan,
+        "backgroundFraming": resolved_background_framing,
+        "timeline": timeline_clips,
+        "programDurationSeconds": program_duration_seconds,
+        "shortcutBadges": shortcut_badges,
+        "cursorRendering": resolved_cursor_rendering,
+        "clickEffects": resolved_click_effects,
+        "clickRipples": click_effects.ripples,
+        "spotlight": click_effects.spotlight,
+        "clickSounds": click_effects.click_sounds,
+        "clickSoundURL": click_sound_url,
+        "pictureInPicture": picture_in_picture,
+        "reframe": resolved_reframe,
+    });
+    let manifest_path = manifest_path(&output_path);
+    let manifest_data = match serde_json::to_vec_pretty(&manifest) {
+        Ok(data) => data,
+        Err(error) => {
+            return failure(
+                id,
+                ProtocolErrorCode::RuntimeError,
+                format!("Unable to encode export manifest: {error}"),
+            )
+        }
+    };
+    if let Err(error) = write_file_no_symlink(&manifest_path, &manifest_data) {
+        return failure(
+            id,
+            ProtocolErrorCode::PermissionDenied,
+            format!("Unable to write export manifest safely: {error}"),
+        );
+    }
+
     state.latest_export_background_framing = Some(resolved_background_framing);
+    state.latest_export_program_frames = Some(program_frames(&timeline_clips, preset.fps));
+    state.latest_export_timeline = Some(timeline_clips);
+    state.latest_export_shortcut_badges = Some(shortcut_badges);
+    state.latest_export_cursor = cursor;
+    state.latest_export_click_effects = (!click_effects.is_empty()).then_some(click_effects);
+    state.latest_export_camera_plan = camera_plan;
+    state.latest_export_camera_crops = camera_crops;
+    state.latest_export_picture_in_picture = picture_in_picture;
 
     success(
         id,
@@ -109,12 +588,17 @@ pub(crate) fn run(id: &EngineCallId, state: &mut State, params: &Value) -> Engin
             "jobId": format!("export-{}", id),
             "status": "succeeded",
             "outputURL": output_url,
+            "manifestURL": manifest_path.to_string_lossy(),
         }),
     )
 }
 
 pub(crate) fn run_cut_plan(id: &EngineCallId, state: &State, params: &Value) -> EngineResponse {
-    let export_params: ExportRunCutPlanParams = decode_params(params);
+    let export_params = match decode_params::<ExportRunCutPlanParams>(id, params, "cut plan export")
+    {
+        Ok(params) => params,
+        Err(response) => return response,
+    };
     let output_url = match export_params.output_url {
         Some(value) => value,
         None => {
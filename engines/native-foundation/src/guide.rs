@@ -0,0 +1,475 @@
+//! `export.guide`: turns the clicks of a recording into a how-to document with one step per
+//! click, each a screenshot cropped around the click with the click point ringed, written as a
+//! Markdown and HTML bundle next to its images.
+
+use crate::agent::imported_transcript_segments;
+use crate::analysis::{synthetic_source, AnalysisFrameSource, SYNTHETIC_FPS};
+use crate::export::{content_rect, recorded_input, source_events, source_size};
+use crate::input_tracking::{InputEventType, InputPoint, MouseButton};
+use crate::params::GuideParams;
+use crate::path_security::{
+    create_directory_all_no_symlink, reject_final_symlink, write_file_no_symlink,
+};
+use crate::pipeline::{FrameSource, SyntheticFrameSource};
+use crate::png;
+use crate::recording_journal::RecordingFrames;
+use crate::state::State;
+use crate::takes::is_placeholder_recording;
+use crate::wire::{
+    decode_params, failure, success, EngineCallId, EngineResponse, ProtocolErrorCode,
+};
+use serde_json::{json, Value};
+use std::path::Path;
+
+const IMAGE_DIRECTORY: &str = "images";
+const MARKDOWN_FILE: &str = "guide.md";
+const HTML_FILE: &str = "guide.html";
+/// Transcript segments overlapping this window around a click are quoted under its step.
+const TRANSCRIPT_LEAD_SECONDS: f64 = 1.0;
+const TRANSCRIPT_TRAIL_SECONDS: f64 = 2.0;
+/// Highlight ring radius as a fraction of the screenshot's shorter side.
+const HIGHLIGHT_RADIUS_FRACTION: f64 = 0.04;
+const MIN_HIGHLIGHT_RADIUS: f64 = 4.0;
+const MIN_HIGHLIGHT_THICKNESS: f64 = 2.0;
+
+/// Frames the screenshots are taken from: the decoded recording, or the synthetic capture
+/// source for placeholder recordings made without a capture backend.
+enum GuideFrames {
+    Recording(RecordingFrames),
+    Synthetic(SyntheticFrameSource),
+}
+
+impl GuideFrames {
+    fn open(state: &State, recording_url: &str) -> Result<Self, String> {
+        if is_placeholder_recording(recording_url) {
+            let metadata = state
+                .active_take()
+                .and_then(|take| take.capture_metadata.as_ref())
+                .or(state.capture_metadata.as_ref());
+            return Ok(Self::Synthetic(synthetic_source(metadata)));
+        }
+        RecordingFrames::open(Path::new(recording_url))
+            .map(Self::Recording)
+            .map_err(|error| format!("Unable to read recording frames: {error}"))
+    }
+
+    fn source(&self) -> AnalysisFrameSource {
+        match self {
+            Self::Recording(_) => AnalysisFrameSource::Recording,
+            Self::Synthetic(_) => AnalysisFrameSource::Synthetic,
+        }
+    }
+
+    fn dimensions(&self) -> (usize, usize) {
+        match self {
+            Self::Recording(frames) => (frames.width, frames.height),
+            Self::Synthetic(source) => {
+                let (width, height) = source.dimensions();
+                (width as usize, height as usize)
+            }
+        }
+    }
+
+    /// Packed RGB8 pixels of the frame showing `time_seconds`; the last frame for later times.
+    fn frame_at(&mut self, time_seconds: f64) -> Result<Vec<u8>, String> {
+        match self {
+            Self::Recording(frames) => {
+                let frame_count = frames
+                    .frame_count()
+                    .map_err(|error| format!("Unable to read recording frames: {error}"))?;
+                if frame_count == 0 {
+                    return Err("The recording has no frames".to_string());
+                }
+                let index = ((time_seconds * frames.fps).floor() as u64).min(frame_count - 1);
+                frames
+                    .rgb(index)
+                    .map_err(|error| format!("Unable to read recording frames: {error}"))?
+                    .ok_or_else(|| "The recording ended before its last frame".to_string())
+            }
+            Self::Synthetic(source) => {
+                source.capture((time_seconds * SYNTHETIC_FPS as f64).floor() as u64)
+            }
+        }
+    }
+}
+
+/// A screenshot cut from a frame, with the click position inside it.
+struct Screenshot {
+    width: usize,
+    height: usize,
+    pixels: Vec<u8>,
+    click: InputPoint,
+}
+
+/// Crops `frame` to `1 / zoom` of its size around `click`, keeping the window inside the frame.
+fn crop_around(
+    frame: &[u8],
+    (frame_width, frame_height): (usize, usize),
+    click: InputPoint,
+    zoom: f64,
+) -> Screenshot {
+    let width = ((frame_width as f64 / zoom).round() as usize).clamp(1, frame_width.max(1));
+    let height = ((frame_height as f64 / zoom).round() as usize).clamp(1, frame_height.max(1));
+    let window_origin = |center: f64, size: usize, limit: usize| {
+        (center - size as f64 / 2.0)
+            .round()
+            .clamp(0.0, limit.saturating_sub(size) as f64) as usize
+    };
+    let left = window_origin(click.x, width, frame_width);
+    let top = window_origin(click.y, height, frame_height);
+    let mut pixels = Vec::with_capacity(width * height * 3);
+    for row in top..top + height {
+        let start = (row * frame_width + left) * 3;
+        pixels.extend_from_slice(&frame[start..start + width * 3]);
+    }
+    Screenshot {
+        width,
+        height,
+        pixels,
+        click: InputPoint {
+            x: click.x - left as f64,
+            y: click.y - top as f64,
+        },
+    }
+}
+
+/// Draws a ring around the screenshot's click point.
+fn highlight_click(screenshot: &mut Screenshot, color: [u8; 3]) {
+    let radius = (screenshot.width.min(screenshot.height) as f64 * HIGHLIGHT_RADIUS_FRACTION)
+        .max(MIN_HIGHLIGHT_RADIUS);
+    let thickness = (radius / 4.0).max(MIN_HIGHLIGHT_THICKNESS);
+    let inner = radius - thickness;
+    for y in 0..screenshot.height {
+        for x in 0..screenshot.width {
+            let distance =
+                (x as f64 + 0.5 - screenshot.click.x).hypot(y as f64 + 0.5 - screenshot.click.y);
+            if (inner..=radius).contains(&distance) {
+                let offset = (y * screenshot.width + x) * 3;
+                screenshot.pixels[offset..offset + 3].copy_from_slice(&color);
+            }
+        }
+    }
+}
+
+fn parse_highlight_color(color: &str) -> [u8; 3] {
+    let channel = |range: std::ops::Range<usize>| {
+        color
+            .get(range)
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+            .unwrap_or(0)
+    };
+    [channel(1..3), channel(3..5), channel(5..7)]
+}
+
+/// `mm:ss.s`, with hours in front once the recording passes one.
+fn format_timestamp(seconds: f64) -> String {
+    let tenths = (seconds.max(0.0) * 10.0).round() as u64;
+    let (hours, minutes) = (tenths / 36_000, tenths / 600 % 60);
+    let (whole_seconds, tenth) = (tenths / 10 % 60, tenths % 10);
+    if hours > 0 {
+        format!("{hours}:{minutes:02}:{whole_seconds:02}.{tenth}")
+    } else {
+        format!("{minutes:02}:{whole_seconds:02}.{tenth}")
+    }
+}
+
+/// Text of the transcript segments spoken around `time_seconds`.
+fn transcript_snippet(segments: &[(f64, f64, String)], time_seconds: f64) -> Option<String> {
+    let (from, to) = (
+        time_seconds - TRANSCRIPT_LEAD_SECONDS,
+        time_seconds + TRANSCRIPT_TRAIL_SECONDS,
+    );
+    let snippet = segments
+        .iter()
+        .filter(|(start, end, _)| *start < to && *end > from)
+        .map(|(_, _, text)| text.as_str())
+        .collect::<Vec<_>>()
+        .join(" ");
+    (!snippet.is_empty()).then_some(snippet)
+}
+
+fn click_action(button: Option<MouseButton>) -> &'static str {
+    match button {
+        Some(MouseButton::Right) => "Right-click",
+        _ => "Click",
+    }
+}
+
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for character in text.chars() {
+        match character {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(character),
+        }
+    }
+    escaped
+}
+
+struct GuideStep {
+    number: usize,
+    time_seconds: f64,
+    action: &'static str,
+    image: String,
+    click: InputPoint,
+    transcript: Option<String>,
+}
+
+fn markdown(title: &str, steps: &[GuideStep]) -> String {
+    let mut document = format!("# {title}\n");
+    for step in steps {
+        let timestamp = format_timestamp(step.time_seconds);
+        document.push_str(&format!(
+            "\n## Step {number}\n\n![Step {number}]({image})\n\n{action} at {timestamp}.\n",
+            number = step.number,
+            image = step.image,
+            action = step.action,
+        ));
+        if let Some(transcript) = &step.transcript {
+            document.push_str(&format!("\n> {transcript}\n"));
+        }
+    }
+    document
+}
+
+fn html(title: &str, steps: &[GuideStep]) -> String {
+    let title = escape_html(title);
+    let mut document = format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<title>{title}</title>\n</head>\n<body>\n<h1>{title}</h1>\n"
+    );
+    for step in steps {
+        let timestamp = format_timestamp(step.time_seconds);
+        document.push_str(&format!(
+            "<section>\n<h2>Step {number}</h2>\n<img src=\"{image}\" alt=\"Step {number}\">\n<p>{action} at <time>{timestamp}</time>.</p>\n",
+            number = step.number,
+            image = escape_html(&step.image),
+            action = step.action,
+        ));
+        if let Some(transcript) = &step.transcript {
+            document.push_str(&format!(
+                "<blockquote>{}</blockquote>\n",
+                escape_html(transcript)
+            ));
+        }
+        document.push_str("</section>\n");
+    }
+    document.push_str("</body>\n</html>\n");
+    document
+}
+
+pub(crate) fn run(id: &EngineCallId, state: &State, params: &Value) -> EngineResponse {
+    let guide_params = match decode_params::<GuideParams>(id, params, "guide") {
+        Ok(params) => params,
+        Err(response) => return response,
+    };
+    let guide_params = match guide_params.validated() {
+        Ok(params) => params,
+        Err(error) => return failure(id, ProtocolErrorCode::InvalidParams, error),
+    };
+    let Some(output_url) = guide_params.output_url.clone() else {
+        return failure(
+            id,
+            ProtocolErrorCode::InvalidParams,
+            "outputURL is required",
+        );
+    };
+    let output_directory = Path::new(&output_url);
+    if !output_directory.is_absolute() {
+        return failure(
+            id,
+            ProtocolErrorCode::InvalidParams,
+            "outputURL must be an absolute path",
+        );
+    }
+    if let Err(error) = reject_final_symlink(output_directory) {
+        return failure(
+            id,
+            ProtocolErrorCode::PermissionDenied,
+            format!("outputURL failed symlink safety validation: {error}"),
+        );
+    }
+    let Some(recording_url) = state
+        .active_take()
+        .map(|take| take.recording_url.clone())
+        .or_else(|| state.recording_url.clone())
+    else {
+        return failure(
+            id,
+            ProtocolErrorCode::InvalidParams,
+            "No recording to build a guide from",
+        );
+    };
+    let transcript = match &guide_params.imported_transcript_path {
+        Some(path) => match imported_transcript_segments(path) {
+            Some(segments) => segments,
+            None => {
+                return failure(
+                    id,
+                    ProtocolErrorCode::InvalidParams,
+                    "importedTranscriptPath must point to a readable JSON transcript",
+                )
+            }
+        },
+        None => Vec::new(),
+    };
+    let events = match recorded_input(state) {
+        Ok(input) => input.map(|input| input.events).unwrap_or_default(),
+        Err(error) => return failure(id, ProtocolErrorCode::RuntimeError, error),
+    };
+    let clicks = source_events(state, &events)
+        .into_iter()
+        .filter(|event| {
+            event.event_type == InputEventType::MouseDown
+                && event.timestamp.is_finite()
+                && event.timestamp >= 0.0
+        })
+        .collect::<Vec<_>>();
+    if clicks.is_empty() {
+        return failure(
+            id,
+            ProtocolErrorCode::InvalidParams,
+            "The recording has no clicks to turn into steps",
+        );
+    }
+
+    let mut frames = match GuideFrames::open(state, &recording_url) {
+        Ok(frames) => frames,
+        Err(error) => return failure(id, ProtocolErrorCode::RuntimeError, error),
+    };
+    let frame_size = frames.dimensions();
+    let source = source_size(content_rect(state));
+    let highlight = parse_highlight_color(&guide_params.highlight_color);
+    let image_directory = output_directory.join(IMAGE_DIRECTORY);
+    if let Err(error) = create_directory_all_no_symlink(&image_directory) {
+        return failure(
+            id,
+            ProtocolErrorCode::PermissionDenied,
+            format!("Unable to create guide directory safely: {error}"),
+        );
+    }
+
+    let mut steps = Vec::with_capacity(clicks.len());
+    for (offset, click) in clicks.iter().enumerate() {
+        let frame = match frames.frame_at(click.timestamp) {
+            Ok(frame) => frame,
+            Err(error) => return failure(id, ProtocolErrorCode::RuntimeError, error),
+        };
+        let frame_click = InputPoint {
+            x: (click.position.x * frame_size.0 as f64 / source.width)
+                .clamp(0.0, frame_size.0 as f64),
+            y: (click.position.y * frame_size.1 as f64 / source.height)
+                .clamp(0.0, frame_size.1 as f64),
+        };
+        let mut screenshot = crop_around(&frame, frame_size, frame_click, guide_params.zoom);
+        highlight_click(&mut screenshot, highlight);
+        let number = offset + 1;
+        let image = format!("{IMAGE_DIRECTORY}/step-{number:02}.png");
+        let encoded = png::encode_rgb(
+            screenshot.width as u32,
+            screenshot.height as u32,
+            &screenshot.pixels,
+        );
+        if let Err(error) = write_file_no_symlink(&output_directory.join(&image), &encoded) {
+            return failure(
+                id,
+                ProtocolErrorCode::PermissionDenied,
+                format!("Unable to write guide screenshot safely: {error}"),
+            );
+        }
+        steps.push(GuideStep {
+            number,
+            time_seconds: click.timestamp,
+            action: click_action(click.button),
+            image,
+            click: click.position,
+            transcript: transcript_snippet(&transcript, click.timestamp),
+        });
+    }
+
+    let markdown_path = output_directory.join(MARKDOWN_FILE);
+    let html_path = output_directory.join(HTML_FILE);
+    for (path, document) in [
+        (&markdown_path, markdown(&guide_params.title, &steps)),
+        (&html_path, html(&guide_params.title, &steps)),
+    ] {
+        if let Err(error) = write_file_no_symlink(path, document.as_bytes()) {
+            return failure(
+                id,
+                ProtocolErrorCode::PermissionDenied,
+                format!("Unable to write guide safely: {error}"),
+            );
+        }
+    }
+
+    success(
+        id,
+        json!({
+            "outputURL": output_url,
+            "markdownURL": markdown_path.to_string_lossy(),
+            "htmlURL": html_path.to_string_lossy(),
+            "title": guide_params.title,
+            "source": frames.source(),
+            "steps": steps
+                .iter()
+                .map(|step| json!({
+                    "index": step.number,
+                    "timeSeconds": step.time_seconds,
+                    "timestamp": format_timestamp(step.time_seconds),
+                    "action": step.action,
+                    "imageURL": step.image,
+                    "click": { "x": step.click.x, "y": step.click.y },
+                    "transcript": step.transcript,
+                }))
+                .collect::<Vec<_>>(),
+        }),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{crop_around, format_timestamp, highlight_click, transcript_snippet};
+    use crate::input_tracking::InputPoint;
+
+    #[test]
+    fn timestamps_show_tenths_and_gain_hours_past_one() {
+        assert_eq!(format_timestamp(3.46), "00:03.5");
+        assert_eq!(format_timestamp(61.0), "01:01.0");
+        assert_eq!(format_timestamp(3_725.25), "1:02:05.3");
+    }
+
+    #[test]
+    fn screenshots_stay_inside_the_frame_and_ring_the_click() {
+        let frame = vec![0u8; 100 * 50 * 3];
+        let mut screenshot = crop_around(&frame, (100, 50), InputPoint { x: 95.0, y: 5.0 }, 2.0);
+        assert_eq!((screenshot.width, screenshot.height), (50, 25));
+        assert_eq!((screenshot.click.x, screenshot.click.y), (45.0, 5.0));
+
+        highlight_click(&mut screenshot, [255, 0, 0]);
+        let pixel = |x: usize, y: usize| {
+            let offset = (y * screenshot.width + x) * 3;
+            &screenshot.pixels[offset..offset + 3]
+        };
+        // A 4 px ring, 2 px thick: the click itself stays visible.
+        assert_eq!(pixel(45, 5), &[0, 0, 0]);
+        assert_eq!(pixel(48, 5), &[255, 0, 0]);
+        assert_eq!(pixel(45, 15), &[0, 0, 0]);
+    }
+
+    #[test]
+    fn snippets_quote_segments_overlapping_the_click() {
+        let segments = vec![
+            (0.0, 2.0, "Open settings.".to_string()),
+            (2.5, 4.0, "Pick a theme.".to_string()),
+            (9.0, 10.0, "Done.".to_string()),
+        ];
+        assert_eq!(
+            transcript_snippet(&segments, 2.2).as_deref(),
+            Some("Open settings. Pick a theme.")
+        );
+        assert_eq!(transcript_snippet(&segments, 6.5), None);
+    }
+}
@@ -1,7 +1,10 @@
 use crate::agent::agent_preflight;
 use crate::state::State;
 use crate::wire::{success, EngineMethod, EngineResponse};
-use crate::{capture, export, permissions, project, sources, system};
+use crate::{
+    analysis, camera_keyframes, capture, export, guide, idle_cuts, library, permissions, project,
+    sources, system, takes,
+};
 
 #[cfg(test)]
 use crate::wire::EngineRequest;
@@ -31,6 +34,7 @@ pub(crate) fn handle_method(
     params: &serde_json::Value,
 ) -> EngineResponse {
     let id = method.call_id();
+    capture::reconcile_recording(state);
     match method {
         EngineMethod::SystemPing => system::ping(id, platform),
         EngineMethod::EngineCapabilities => system::capabilities(id, platform),
@@ -45,21 +49,41 @@ pub(crate) fn handle_method(
         | EngineMethod::PermissionsOpenInputMonitoringSettings => {
             permissions::request_or_open_settings(id)
         }
-        EngineMethod::SourcesList => sources::list(id),
+        EngineMethod::SourcesList => sources::list(id, state),
         EngineMethod::CaptureStartDisplay => capture::start_display(id, state, params),
         EngineMethod::CaptureStartCurrentWindow => capture::start_current_window(id, state, params),
         EngineMethod::CaptureStartWindow => capture::start_window(id, state, params),
+        EngineMethod::CaptureStartRegion => capture::start_region(id, state, params),
+        EngineMethod::CaptureStartCamera => capture::start_camera(id, state, params),
+        EngineMethod::CaptureStopCamera => capture::stop_camera(id, state),
         EngineMethod::CaptureStop => capture::stop_capture(id, state),
         EngineMethod::RecordingStart => capture::start_recording(id, state, params),
+        EngineMethod::RecordingPause => capture::pause_recording(id, state),
+        EngineMethod::RecordingResume => capture::resume_recording(id, state),
+        EngineMethod::RecordingAddMarker => capture::add_marker(id, state, params),
         EngineMethod::RecordingStop => capture::stop_recording(id, state),
+        EngineMethod::RecordingRecover => capture::recover_recordings(id, state),
         EngineMethod::CaptureStatus => capture::status(id, state),
         EngineMethod::CapturePreviewFrame => capture::preview_frame(id),
         EngineMethod::ExportInfo => export::info(id),
         EngineMethod::ExportRun => export::run(id, state, params),
         EngineMethod::ExportRunCutPlan => export::run_cut_plan(id, state, params),
+        EngineMethod::ExportGuide => guide::run(id, state, params),
         EngineMethod::ProjectCurrent => project::current(id, state),
         EngineMethod::ProjectOpen => project::open(id, state, params),
         EngineMethod::ProjectSave => project::save(id, state, params),
         EngineMethod::ProjectRecents => project::recents(id, state, params),
+        EngineMethod::TakesList => takes::list(id, state),
+        EngineMethod::TakesRename => takes::rename(id, state, params),
+        EngineMethod::TakesDelete => takes::delete(id, state, params),
+        EngineMethod::TakesActivate => takes::activate(id, state, params),
+        EngineMethod::CameraKeyframesList => camera_keyframes::list(id, state, params),
+        EngineMethod::CameraKeyframesEdit => camera_keyframes::edit(id, state, params),
+        EngineMethod::CameraKeyframesReplan => camera_keyframes::replan(id, state),
+        EngineMethod::TimelineSuggestCuts => idle_cuts::suggest(id, state, params),
+        EngineMethod::TimelineApplyCuts => idle_cuts::apply(id, state, params),
+        EngineMethod::AnalysisVisualActivity => analysis::visual_activity(id, state, params),
+        EngineMethod::LibraryDefaults => library::defaults(id, state),
+        EngineMethod::LibrarySaveDefaults => library::save_defaults(id, state, params),
     }
 }
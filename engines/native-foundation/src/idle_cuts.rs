@@ -0,0 +1,529 @@
+use crate::analysis::take_frame_differences;
+use crate::export::recorded_input;
+use crate::params::{ApplyCutsParams, IdleCutsParams, TimelineParams};
+use crate::state::State;
+use crate::timeline::{ClipSpeed, TimelineItem, ACTIVE_TAKE_ASSET_ID};
+use crate::wire::{
+    decode_params, failure, success, EngineCallId, EngineResponse, ProtocolErrorCode,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::HashSet;
+
+/// How a suggested cut changes the timeline.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) enum CutOperation {
+    /// Removes the range and closes the hole.
+    #[default]
+    RippleDelete,
+    /// Removes the range and leaves a gap of the same length.
+    Lift,
+    /// Keeps the range but plays it faster.
+    SpeedUp,
+}
+
+/// An idle stretch of a take proposed for removal, in the take's source time.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct SuggestedCut {
+    pub(crate) id: String,
+    pub(crate) take_id: String,
+    pub(crate) start_seconds: f64,
+    pub(crate) end_seconds: f64,
+    pub(crate) operation: CutOperation,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) playback_rate: Option<f64>,
+}
+
+type TimeRange = (f64, f64);
+
+/// Longest ramp into and out of a sped-up idle stretch, in source seconds.
+const SPEED_UP_RAMP_SECONDS: f64 = 0.5;
+
+/// Stretches without input; the recording start and end bound the first and last.
+fn inactive_ranges(activity: &[f64], duration: f64) -> Vec<TimeRange> {
+    let mut times = activity
+        .iter()
+        .copied()
+        .filter(|time| time.is_finite())
+        .collect::<Vec<_>>();
+    times.sort_by(f64::total_cmp);
+    let bounds = std::iter::once(0.0)
+        .chain(times)
+        .chain(std::iter::once(duration))
+        .collect::<Vec<_>>();
+    bounds
+        .windows(2)
+        .map(|pair| (pair[0].max(0.0), pair[1].min(duration)))
+        .filter(|(start, end)| start < end)
+        .collect()
+}
+
+/// Stretches where the picture changes by no more than `threshold` from frame to frame.
+fn still_ranges(differences: &[f64], fps: f64, threshold: f64) -> Vec<TimeRange> {
+    let mut ranges = Vec::new();
+    let mut run_start = 0;
+    for (frame, difference) in differences.iter().enumerate().skip(1) {
+        if *difference > threshold {
+            ranges.push((run_start as f64 / fps, frame as f64 / fps));
+            run_start = frame;
+        }
+    }
+    if !differences.is_empty() {
+        ranges.push((run_start as f64 / fps, differences.len() as f64 / fps));
+    }
+    ranges
+}
+
+fn intersect(left: &[TimeRange], right: &[TimeRange]) -> Vec<TimeRange> {
+    let (mut left_index, mut right_index) = (0, 0);
+    let mut ranges = Vec::new();
+    while let (Some(&(left_start, left_end)), Some(&(right_start, right_end))) =
+        (left.get(left_index), right.get(right_index))
+    {
+        let (start, end) = (left_start.max(right_start), left_end.min(right_end));
+        if start < end {
+            ranges.push((start, end));
+        }
+        if left_end < right_end {
+            left_index += 1;
+        } else {
+            right_index += 1;
+        }
+    }
+    ranges
+}
+
+/// Idle stretches of `take_id` where every available signal agrees: no input in `activity`
+/// and no picture change in `still`. Nothing is suggested without any signal.
+fn plan_idle_cuts(
+    take_id: &str,
+    activity: Option<&[f64]>,
+    still: Option<&[TimeRange]>,
+    duration: f64,
+    settings: &IdleCutsParams,
+) -> Vec<SuggestedCut> {
+    let inactive = activity.map(|activity| inactive_ranges(activity, duration));
+    let idle = match (inactive, still) {
+        (Some(inactive), Some(still)) => intersect(&inactive, still),
+        (Some(inactive), None) => inactive,
+        (None, Some(still)) => still.to_vec(),
+        (None, None) => Vec::new(),
+    };
+    idle.into_iter()
+        .filter(|(start, end)| end - start >= settings.min_idle_seconds)
+        .map(|(start, end)| {
+            (
+                start + settings.padding_seconds,
+                end - settings.padding_seconds,
+            )
+        })
+        .enumerate()
+        .map(|(index, (start, end))| SuggestedCut {
+            id: format!("idle-{}", index + 1),
+            take_id: take_id.to_string(),
+            start_seconds: start,
+            end_seconds: end,
+            operation: settings.operation,
+            playback_rate: (settings.operation == CutOperation::SpeedUp)
+                .then_some(settings.playback_rate),
+        })
+        .collect()
+}
+
+/// `base`, or `base` with the first free numeric suffix.
+fn unique_id(base: &str, taken: &mut HashSet<String>) -> String {
+    let id = std::iter::once(base.to_string())
+        .chain((2..).map(|number| format!("{base}-{number}")))
+        .find(|id| !taken.contains(id))
+        .expect("unbounded id search");
+    taken.insert(id.clone());
+    id
+}
+
+/// Removes `cut` from every clip that plays `take_id`, splitting clips it falls inside. A
+/// speed-up keeps the range as its own clip ramping up to `playback_rate` and back.
+fn cut_items(
+    items: Vec<TimelineItem>,
+    take_id: &str,
+    active_take_id: Option<&str>,
+    cut: TimeRange,
+    operation: CutOperation,
+    playback_rate: f64,
+) -> Vec<TimelineItem> {
+    let mut taken = items
+        .iter()
+        .map(|item| match item {
+            TimelineItem::Clip { id, .. } | TimelineItem::Gap { id, .. } => id.clone(),
+        })
+        .collect::<HashSet<_>>();
+    let mut result = Vec::with_capacity(items.len() + 2);
+    for item in items {
+        let TimelineItem::Clip {
+            id,
+            source_asset_id,
+            source_start_seconds,
+            source_end_seconds,
+            speed,
+        } = &item
+        else {
+            result.push(item);
+            continue;
+        };
+        let plays_take = source_asset_id == take_id
+            || (source_asset_id == ACTIVE_TAKE_ASSET_ID && active_take_id == Some(take_id));
+        let (start, end) = (
+            cut.0.max(*source_start_seconds),
+            cut.1.min(*source_end_seconds),
+        );
+        if !plays_take || start >= end {
+            result.push(item);
+            continue;
+        }
+        let clip = |id: String, source_start_seconds: f64, source_end_seconds: f64, speed| {
+            TimelineItem::Clip {
+                id,
+                source_asset_id: source_asset_id.clone(),
+                source_start_seconds,
+                source_end_seconds,
+                speed,
+            }
+        };
+        let mut kept_id = Some(id.clone());
+        let mut next_id =
+            |taken: &mut HashSet<String>| kept_id.take().unwrap_or_else(|| unique_id(id, taken));
+        if *source_start_seconds < start {
+            // The head keeps the clip's ramp in and the tail its ramp out.
+            let head = ClipSpeed {
+                ramp_in_seconds: speed.ramp_in_seconds.min(start - source_start_seconds),
+                ramp_out_seconds: 0.0,
+                ..*speed
+            };
+            result.push(clip(
+                next_id(&mut taken),
+                *source_start_seconds,
+                start,
+                head,
+            ));
+        }
+        match operation {
+            CutOperation::RippleDelete => {}
+            CutOperation::Lift => result.push(TimelineItem::Gap {
+                id: unique_id(&format!("{id}-gap"), &mut taken),
+                duration_seconds: end - start,
+            }),
+            CutOperation::SpeedUp => {
+                let ramp = SPEED_UP_RAMP_SECONDS.min((end - start) / 4.0);
+                let sped_up = ClipSpeed {
+                    playback_rate,
+                    ramp_in_seconds: ramp,
+                    ramp_out_seconds: ramp,
+                    ..*speed
+                };
+                result.push(clip(next_id(&mut taken), start, end, sped_up));
+            }
+        }
+        if end < *source_end_seconds {
+            let tail = ClipSpeed {
+                ramp_in_seconds: 0.0,
+                ramp_out_seconds: speed.ramp_out_seconds.min(source_end_seconds - end),
+                ..*speed
+            };
+            result.push(clip(next_id(&mut taken), end, *source_end_seconds, tail));
+        }
+    }
+    result
+}
+
+/// Finds idle stretches of the active take and keeps them for review until the next analysis.
+pub(crate) fn suggest(id: &EngineCallId, state: &mut State, params: &Value) -> EngineResponse {
+    let settings =
+        match decode_params::<IdleCutsParams>(id, params, "idle cut").and_then(|settings| {
+            settings
+                .validated()
+                .map_err(|error| failure(id, ProtocolErrorCode::InvalidParams, error))
+        }) {
+            Ok(settings) => settings,
+            Err(response) => return response,
+        };
+    let Some(take) = state.active_take().cloned() else {
+        return failure(
+            id,
+            ProtocolErrorCode::InvalidParams,
+            "No active take to analyze",
+        );
+    };
+    let activity = match recorded_input(state) {
+        Ok(input) => input.map(|input| {
+            input
+                .events
+                .iter()
+                .map(|event| event.timestamp)
+                .chain(input.shortcuts.iter().map(|shortcut| shortcut.timestamp))
+                .collect::<Vec<_>>()
+        }),
+        Err(error) => return failure(id, ProtocolErrorCode::RuntimeError, error),
+    };
+    let frames = match take_frame_differences(state, &take) {
+        Ok(frames) => frames,
+        Err(error) => return failure(id, ProtocolErrorCode::RuntimeError, error),
+    };
+    let still = frames
+        .as_ref()
+        .map(|(fps, differences)| still_ranges(differences, *fps, settings.stillness_threshold));
+    let duration = frames
+        .as_ref()
+        .map_or(0.0, |(fps, differences)| differences.len() as f64 / fps)
+        .max(take.duration_seconds);
+    let suggestions = plan_idle_cuts(
+        &take.id,
+        activity.as_deref(),
+        still.as_deref(),
+        duration,
+        &settings,
+    );
+    let idle_seconds = suggestions
+        .iter()
+        .map(|cut| cut.end_seconds - cut.start_seconds)
+        .sum::<f64>();
+    state.suggested_cuts = suggestions;
+    success(
+        id,
+        json!({
+            "takeId": take.id,
+            "durationSeconds": duration,
+            "signals": {
+                "input": activity.is_some(),
+                "frames": frames.is_some(),
+            },
+            "idleSeconds": idle_seconds,
+            "suggestions": state.suggested_cuts,
+        }),
+    )
+}
+
+/// Applies reviewed suggestions to the timeline; nothing changes unless all of them apply.
+pub(crate) fn apply(id: &EngineCallId, state: &mut State, params: &Value) -> EngineResponse {
+    let apply_params = match decode_params::<ApplyCutsParams>(id, params, "apply cuts") {
+        Ok(params) => params,
+        Err(response) => return response,
+    };
+    if apply_params.suggestion_ids.is_empty() {
+        return failure(
+            id,
+            ProtocolErrorCode::InvalidParams,
+            "suggestionIds is required",
+        );
+    }
+    let mut cuts = Vec::with_capacity(apply_params.suggestion_ids.len());
+    for suggestion_id in &apply_params.suggestion_ids {
+        let Some(cut) = state
+            .suggested_cuts
+            .iter()
+            .find(|cut| &cut.id == suggestion_id)
+        else {
+            return failure(
+                id,
+                ProtocolErrorCode::InvalidParams,
+                format!("Unknown suggestion id: {suggestion_id}"),
+            );
+        };
+        if !cuts.contains(&cut) {
+            cuts.push(cut);
+        }
+    }
+    cuts.sort_by(|left, right| left.start_seconds.total_cmp(&right.start_seconds));
+
+    let mut items = state.timeline_items.clone();
+    for cut in &cuts {
+        let operation = apply_params.operation.unwrap_or(cut.operation);
+        let Some(take) = state.takes.iter().find(|take| take.id == cut.take_id) else {
+            return failure(
+                id,
+                ProtocolErrorCode::InvalidCutPlan,
+                format!(
+                    "Suggestion {} references deleted take {}",
+                    cut.id, cut.take_id
+                ),
+            );
+        };
+        if items.is_empty() {
+            // An empty timeline plays the whole active take.
+            let source_asset_id = if state.active_take_id.as_deref() == Some(take.id.as_str()) {
+                ACTIVE_TAKE_ASSET_ID.to_string()
+            } else {
+                take.id.clone()
+            };
+            items.push(TimelineItem::clip(
+                "clip-1".to_string(),
+                source_asset_id,
+                0.0,
+                take.duration_seconds,
+            ));
+        }
+        items = cut_items(
+            items,
+            &cut.take_id,
+            state.active_take_id.as_deref(),
+            (cut.start_seconds, cut.end_seconds),
+            operation,
+            cut.playback_rate
+                .unwrap_or_else(|| IdleCutsParams::default().playback_rate),
+        );
+    }
+    let items = match (TimelineParams {
+        version: 2.0,
+        items,
+    })
+    .validated()
+    {
+        Ok(items) => items,
+        Err(error) => return failure(id, ProtocolErrorCode::InvalidCutPlan, error),
+    };
+
+    let applied = cuts.iter().map(|cut| cut.id.clone()).collect::<Vec<_>>();
+    state
+        .suggested_cuts
+        .retain(|cut| !applied.contains(&cut.id));
+    state.timeline_items = items;
+    state.unsaved_changes = true;
+    success(
+        id,
+        json!({
+            "timeline": {
+                "version": 2,
+                "items": state.timeline_items,
+            },
+            "appliedSuggestionIds": applied,
+            "suggestions": state.suggested_cuts,
+        }),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{cut_items, plan_idle_cuts, still_ranges, CutOperation};
+    use crate::params::IdleCutsParams;
+    use crate::timeline::TimelineItem;
+
+    fn clip(id: &str, start: f64, end: f64) -> TimelineItem {
+        TimelineItem::clip(id.to_string(), "recording".to_string(), start, end)
+    }
+
+    #[test]
+    fn idle_needs_both_quiet_input_and_a_still_picture() {
+        // Input pauses from 2 s to 14 s; the picture changes once at 10 s, like a build log.
+        let mut differences = vec![0.0; 200];
+        differences[100] = 0.2;
+        let still = still_ranges(&differences, 10.0, 0.002);
+        assert_eq!(still, vec![(0.0, 10.0), (10.0, 20.0)]);
+        let settings = IdleCutsParams::default();
+
+        let cuts = plan_idle_cuts(
+            "take-1",
+            Some(&[1.0, 2.0, 14.0]),
+            Some(&still),
+            20.0,
+            &settings,
+        );
+        let ranges = cuts
+            .iter()
+            .map(|cut| (cut.id.as_str(), cut.start_seconds, cut.end_seconds))
+            .collect::<Vec<_>>();
+        assert_eq!(ranges, vec![("idle-1", 2.5, 9.5), ("idle-2", 14.5, 19.5)]);
+        assert!(cuts
+            .iter()
+            .all(|cut| cut.operation == CutOperation::RippleDelete && cut.playback_rate.is_none()));
+
+        let input_only = plan_idle_cuts("take-1", Some(&[1.0, 2.0, 14.0]), None, 20.0, &settings);
+        assert_eq!(input_only.len(), 2);
+        assert_eq!(
+            (input_only[0].start_seconds, input_only[0].end_seconds),
+            (2.5, 13.5)
+        );
+        assert!(plan_idle_cuts("take-1", None, None, 20.0, &settings).is_empty());
+
+        let speed_up = plan_idle_cuts(
+            "take-1",
+            Some(&[1.0, 2.0, 14.0]),
+            None,
+            20.0,
+            &IdleCutsParams {
+                operation: CutOperation::SpeedUp,
+                ..IdleCutsParams::default()
+            },
+        );
+        assert_eq!(speed_up[0].playback_rate, Some(4.0));
+    }
+
+    #[test]
+    fn cuts_split_the_clips_that_play_the_take() {
+        let items = vec![clip("intro", 0.0, 10.0), clip("outro", 12.0, 20.0)];
+        let rippled = cut_items(
+            items.clone(),
+            "take-1",
+            Some("take-1"),
+            (4.0, 14.0),
+            CutOperation::RippleDelete,
+            4.0,
+        );
+        assert_eq!(
+            rippled,
+            vec![clip("intro", 0.0, 4.0), clip("outro", 14.0, 20.0)]
+        );
+
+        let lifted = cut_items(
+            items.clone(),
+            "take-1",
+            Some("take-1"),
+            (4.0, 6.0),
+            CutOperation::Lift,
+            4.0,
+        );
+        assert_eq!(
+            lifted,
+            vec![
+                clip("intro", 0.0, 4.0),
+                TimelineItem::Gap {
+                    id: "intro-gap".to_string(),
+                    duration_seconds: 2.0,
+                },
+                clip("intro-2", 6.0, 10.0),
+                clip("outro", 12.0, 20.0),
+            ]
+        );
+
+        let other_take = cut_items(
+            items.clone(),
+            "take-1",
+            Some("take-2"),
+            (4.0, 6.0),
+            CutOperation::RippleDelete,
+            4.0,
+        );
+        assert_eq!(other_take, items);
+
+        let sped_up = cut_items(
+            items,
+            "take-1",
+            Some("take-1"),
+            (4.0, 6.0),
+            CutOperation::SpeedUp,
+            4.0,
+        );
+        let TimelineItem::Clip { id, speed, .. } = &sped_up[1] else {
+            panic!("expected a sped-up clip");
+        };
+        assert_eq!(id, "intro-2");
+        assert_eq!(
+            (
+                speed.playback_rate,
+                speed.ramp_in_seconds,
+                speed.ramp_out_seconds
+            ),
+            (4.0, 0.5, 0.5)
+        );
+        assert_eq!(sped_up[2], clip("intro-3", 6.0, 10.0));
+    }
+}
@@ -0,0 +1,328 @@
+use crate::recording::RecordingPauseRange;
+use crate::wire::CaptureClock;
+use serde::{Deserialize, Serialize};
+use std::sync::{Arc, Mutex};
+
+/// Written next to the recording output inside the recording directory.
+pub(crate) const EVENTS_FILE_NAME: &str = "events.json";
+pub(crate) const INPUT_EVENT_LOG_SCHEMA_VERSION: u32 = 1;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) enum InputEventType {
+    CursorMoved,
+    MouseDown,
+    MouseUp,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) enum MouseButton {
+    Left,
+    Right,
+    Other,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub(crate) struct InputPoint {
+    pub(crate) x: f64,
+    pub(crate) y: f64,
+}
+
+/// Mirrors the Swift `InputEvent`; `timestamp` is seconds on the recording timeline.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub(crate) struct InputEvent {
+    #[serde(rename = "type")]
+    pub(crate) event_type: InputEventType,
+    pub(crate) timestamp: f64,
+    pub(crate) position: InputPoint,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) button: Option<MouseButton>,
+}
+
+/// A modifier+key combo that passed the privacy filter, e.g. `["Ctrl", "Shift", "P"]`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub(crate) struct KeyboardShortcutEvent {
+    pub(crate) timestamp: f64,
+    pub(crate) keys: Vec<String>,
+}
+
+/// Mirrors the Swift `InputEventLog` written to `eventsURL`. `shortcuts` is only present when
+/// keyboard shortcut tracking was requested, so pointer-only logs keep the Swift shape.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct InputEventLog {
+    pub(crate) schema_version: u32,
+    pub(crate) events: Vec<InputEvent>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) shortcuts: Vec<KeyboardShortcutEvent>,
+}
+
+/// An input event stamped with capture-clock seconds, before alignment to the recording.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct CapturedInputEvent {
+    pub(crate) captured_at_seconds: f64,
+    pub(crate) event_type: InputEventType,
+    pub(crate) position: InputPoint,
+    pub(crate) button: Option<MouseButton>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct CapturedShortcut {
+    pub(crate) captured_at_seconds: f64,
+    pub(crate) keys: Vec<String>,
+}
+
+/// Everything a tracker observed, in capture-clock time.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct CapturedInput {
+    pub(crate) events: Vec<CapturedInputEvent>,
+    pub(crate) shortcuts: Vec<CapturedShortcut>,
+}
+
+impl CapturedInput {
+    /// Input captured between `from_seconds` and `to_seconds`, e.g. without a countdown or
+    /// anything after a time limit cut the recording.
+    pub(crate) fn within(&self, from_seconds: f64, to_seconds: f64) -> CapturedInput {
+        let inside =
+            |captured_at_seconds: f64| (from_seconds..=to_seconds).contains(&captured_at_seconds);
+        CapturedInput {
+            events: self
+                .events
+                .iter()
+                .filter(|event| inside(event.captured_at_seconds))
+                .copied()
+                .collect(),
+            shortcuts: self
+                .shortcuts
+                .iter()
+                .filter(|shortcut| inside(shortcut.captured_at_seconds))
+                .cloned()
+                .collect(),
+        }
+    }
+}
+
+/// Recording-timeline seconds for a capture-clock instant, or `None` while paused.
+fn recording_offset(
+    captured_at_seconds: f64,
+    recording_started_at_seconds: f64,
+    pauses: &[RecordingPauseRange],
+) -> Option<f64> {
+    let mut offset = (captured_at_seconds - recording_started_at_seconds).max(0.0);
+    for pause in pauses {
+        if offset <= pause.at_seconds {
+            break;
+        }
+        if offset < pause.at_seconds + pause.duration_seconds {
+            return None;
+        }
+        offset -= pause.duration_seconds;
+    }
+    Some(offset)
+}
+
+/// Maps capture-clock input onto the gapless recording timeline: input during a pause is
+/// dropped and later input moves back by the paused time.
+pub(crate) fn align_to_recording(
+    captured: &CapturedInput,
+    recording_started_at_seconds: f64,
+    pauses: &[RecordingPauseRange],
+) -> InputEventLog {
+    let offset = |captured_at_seconds: f64| {
+        recording_offset(captured_at_seconds, recording_started_at_seconds, pauses)
+    };
+    let events = captured
+        .events
+        .iter()
+        .filter_map(|event| {
+            Some(InputEvent {
+                event_type: event.event_type,
+                timestamp: offset(event.captured_at_seconds)?,
+                position: event.position,
+                button: event.button,
+            })
+        })
+        .collect();
+    let shortcuts = captured
+        .shortcuts
+        .iter()
+        .filter_map(|shortcut| {
+            Some(KeyboardShortcutEvent {
+                timestamp: offset(shortcut.captured_at_seconds)?,
+                keys: shortcut.keys.clone(),
+            })
+        })
+        .collect();
+    InputEventLog {
+        schema_version: INPUT_EVENT_LOG_SCHEMA_VERSION,
+        events,
+        shortcuts,
+    }
+}
+
+/// Background input capture for the duration of a recording.
+pub(crate) struct InputTracker {
+    captured: Arc<Mutex<CapturedInput>>,
+    #[cfg(target_os = "linux")]
+    thread: Mutex<Option<crate::xinput::TrackerThread>>,
+}
+
+impl InputTracker {
+    /// Connects to the platform input source up front so failures surface to the caller.
+    /// Keyboard shortcuts are only observed when `track_keyboard_shortcuts` opts in.
+    #[cfg(target_os = "linux")]
+    pub(crate) fn start(
+        clock: CaptureClock,
+        track_keyboard_shortcuts: bool,
+    ) -> Result<Self, String> {
+        let captured = Arc::new(Mutex::new(CapturedInput::default()));
+        let thread = crate::xinput::spawn_tracker(
+            None,
+            clock,
+            track_keyboard_shortcuts,
+            Arc::clone(&captured),
+        )?;
+        Ok(Self {
+            captured,
+            thread: Mutex::new(Some(thread)),
+        })
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    pub(crate) fn start(
+        _clock: CaptureClock,
+        _track_keyboard_shortcuts: bool,
+    ) -> Result<Self, String> {
+        Err("input tracking is not implemented on this platform".to_string())
+    }
+
+    /// Stops tracking and returns everything observed since `start`.
+    pub(crate) fn finish(&self) -> CapturedInput {
+        #[cfg(target_os = "linux")]
+        if let Some(thread) = self.thread.lock().ok().and_then(|mut thread| thread.take()) {
+            thread.stop();
+        }
+        self.captured
+            .lock()
+            .map(|mut captured| std::mem::take(&mut *captured))
+            .unwrap_or_default()
+    }
+}
+
+impl Drop for InputTracker {
+    fn drop(&mut self) {
+        self.finish();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        align_to_recording, CapturedInput, CapturedInputEvent, CapturedShortcut, InputEvent,
+        InputEventLog, InputEventType, InputPoint, KeyboardShortcutEvent, MouseButton,
+    };
+    use crate::recording::RecordingPauseRange;
+    use serde_json::json;
+
+    fn captured(captured_at_seconds: f64, event_type: InputEventType) -> CapturedInputEvent {
+        CapturedInputEvent {
+            captured_at_seconds,
+            event_type,
+            position: InputPoint { x: 10.0, y: 20.0 },
+            button: (event_type != InputEventType::CursorMoved).then_some(MouseButton::Left),
+        }
+    }
+
+    #[test]
+    fn alignment_removes_paused_time_and_events_inside_pauses() {
+        let shortcut = |captured_at_seconds: f64| CapturedShortcut {
+            captured_at_seconds,
+            keys: vec!["Ctrl".to_string(), "S".to_string()],
+        };
+        let input = CapturedInput {
+            events: vec![
+                captured(9.5, InputEventType::CursorMoved),
+                captured(11.0, InputEventType::MouseDown),
+                captured(13.5, InputEventType::MouseUp),
+                captured(16.0, InputEventType::CursorMoved),
+                captured(23.0, InputEventType::MouseDown),
+            ],
+            shortcuts: vec![shortcut(11.5), shortcut(12.5)],
+        };
+        let pauses = [
+            RecordingPauseRange {
+                at_seconds: 2.0,
+                duration_seconds: 3.0,
+            },
+            RecordingPauseRange {
+                at_seconds: 4.0,
+                duration_seconds: 5.0,
+            },
+        ];
+        let log = align_to_recording(&input, 10.0, &pauses);
+        let timestamps = log
+            .events
+            .iter()
+            .map(|event| event.timestamp)
+            .collect::<Vec<_>>();
+        assert_eq!(timestamps, vec![0.0, 1.0, 3.0, 5.0]);
+        assert_eq!(log.events[3].event_type, InputEventType::MouseDown);
+        assert_eq!(
+            log.shortcuts,
+            vec![KeyboardShortcutEvent {
+                timestamp: 1.5,
+                keys: vec!["Ctrl".to_string(), "S".to_string()],
+            }]
+        );
+    }
+
+    #[test]
+    fn event_log_matches_swift_input_event_log_encoding() {
+        let log = InputEventLog {
+            schema_version: 1,
+            events: vec![
+                InputEvent {
+                    event_type: InputEventType::CursorMoved,
+                    timestamp: 0.5,
+                    position: InputPoint { x: 1.0, y: 2.0 },
+                    button: None,
+                },
+                InputEvent {
+                    event_type: InputEventType::MouseDown,
+                    timestamp: 0.75,
+                    position: InputPoint { x: 1.0, y: 2.0 },
+                    button: Some(MouseButton::Right),
+                },
+            ],
+            shortcuts: Vec::new(),
+        };
+        assert_eq!(
+            serde_json::to_value(&log).expect("encode log"),
+            json!({
+                "schemaVersion": 1,
+                "events": [
+                    { "type": "cursorMoved", "timestamp": 0.5, "position": { "x": 1.0, "y": 2.0 } },
+                    {
+                        "type": "mouseDown",
+                        "timestamp": 0.75,
+                        "position": { "x": 1.0, "y": 2.0 },
+                        "button": "right"
+                    }
+                ]
+            })
+        );
+
+        let with_shortcuts = InputEventLog {
+            shortcuts: vec![KeyboardShortcutEvent {
+                timestamp: 1.25,
+                keys: vec!["Ctrl".to_string(), "Shift".to_string(), "P".to_string()],
+            }],
+            ..log
+        };
+        assert_eq!(
+            serde_json::to_value(&with_shortcuts).expect("encode log")["shortcuts"],
+            json!([{ "timestamp": 1.25, "keys": ["Ctrl", "Shift", "P"] }])
+        );
+    }
+}
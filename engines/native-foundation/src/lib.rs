@@ -1,18 +1,52 @@
 use std::path::PathBuf;
 
 mod agent;
+mod analysis;
+mod audio;
+mod automation;
+mod camera_keyframes;
+mod camera_path;
+mod cameras;
 mod capture;
+mod click_effects;
+mod cursor;
+mod disk;
 mod export;
+mod guide;
 mod handlers;
+mod idle_cuts;
+mod input_tracking;
+mod library;
 mod params;
 mod path_security;
 mod permissions;
+mod picture_in_picture;
+mod pipeline;
+mod png;
+mod privacy_mask;
 mod project;
+#[cfg(target_os = "linux")]
+mod pulse;
+mod recording;
+mod recording_journal;
+mod shortcuts;
+mod source_monitor;
 mod sources;
 mod state;
 mod system;
+mod takes;
+mod telemetry;
+mod timeline;
 mod transport;
+#[cfg(target_os = "linux")]
+mod v4l2;
 mod wire;
+#[cfg(target_os = "linux")]
+mod x11_sources;
+#[cfg(target_os = "linux")]
+mod x11_windows;
+#[cfg(target_os = "linux")]
+mod xinput;
 
 #[cfg(test)]
 pub(crate) use handlers::handle_request;
@@ -43,6 +77,7 @@ pub fn run_engine(config: EngineRuntimeConfig) {
 #[cfg(test)]
 mod tests {
     use super::{handle_request, record_recent_project, State};
+    use crate::camera_path::CameraPlanSource;
     use crate::params::BackgroundFramingParams;
     use crate::state::{
         is_valid_recent_project_item, load_recent_projects, save_recent_projects,
@@ -52,6 +87,7 @@ mod tests {
     use serde_json::{json, Value};
     use std::fs;
     use std::path::{Path, PathBuf};
+    use std::sync::atomic::{AtomicU64, Ordering};
     use std::time::{SystemTime, UNIX_EPOCH};
 
     fn test_root(label: &str) -> PathBuf {
@@ -73,6 +109,7 @@ mod tests {
         fs::create_dir_all(&root).expect("create test root");
         let recents_path = root.join("Library").join("library.native.json");
         let mut state = State::new(recents_path);
+        state.displays = crate::sources::synthetic_displays;
         let result = callback(&mut state, &root);
         let _ = fs::remove_dir_all(root);
         result
@@ -241,7 +278,7 @@ mod tests {
             assert_eq!(recording_result["isRecording"], json!(true));
             assert_eq!(
                 recording_result["recordingURL"],
-                json!("native://recordings/session.mp4")
+                json!("native://recordings/session-1.y4m")
             );
             assert_eq!(
                 recording_result["eventsURL"],
@@ -267,191 +304,3291 @@ mod tests {
         });
     }
 
+    #[test]
+    fn recording_stop_populates_last_recording_telemetry_from_pipeline() {
+        with_state("recording-telemetry", |state, _| {
+            let _ = expect_success(handle_request(
+                "linux",
+                state,
+                &request(
+                    "t1",
+                    EngineMethod::CaptureStartDisplay,
+                    json!({ "captureFps": 60 }),
+                ),
+            ));
+            let recording = expect_success(handle_request(
+                "linux",
+                state,
+                &request("t2", EngineMethod::RecordingStart, json!({})),
+            ));
+            assert_eq!(recording["lastRecordingTelemetry"], Value::Null);
+
+            std::thread::sleep(std::time::Duration::from_millis(300));
+            let status = expect_success(handle_request(
+                "linux",
+                state,
+                &request("t3", EngineMethod::CaptureStatus, json!({})),
+            ));
+            assert!(status["telemetry"]["achievedFps"].as_f64().unwrap_or(0.0) > 0.0);
+            #[cfg(target_os = "linux")]
+            {
+                assert!(status["telemetry"]["memoryBytes"].as_u64().unwrap_or(0) > 0);
+                assert!(status["telemetry"]["cpuPercent"].is_number());
+            }
+
+            let stopped = expect_success(handle_request(
+                "linux",
+                state,
+                &request("t4", EngineMethod::RecordingStop, json!({})),
+            ));
+            let summary = &stopped["lastRecordingTelemetry"];
+            assert!(summary["achievedFps"].as_f64().unwrap_or(0.0) > 0.0);
+            for key in [
+                "sourceDroppedFrames",
+                "writerDroppedFrames",
+                "writerBackpressureDrops",
+                "captureCallbackMs",
+                "recordQueueLagMs",
+                "writerAppendMs",
+            ] {
+                assert!(summary[key].is_number(), "{key}");
+            }
+
+            let capture_stopped = expect_success(handle_request(
+                "linux",
+                state,
+                &request("t5", EngineMethod::CaptureStop, json!({})),
+            ));
+            assert_eq!(capture_stopped["telemetry"]["achievedFps"], json!(0.0));
+            assert_eq!(capture_stopped["lastRecordingTelemetry"], *summary);
+        });
+    }
+
+    #[test]
+    fn recording_pause_resume_records_pause_ranges_and_excludes_paused_time() {
+        with_state("recording-pause-resume", |state, root| {
+            let project_path = root.join("pause-project.gglassproj");
+            let premature = handle_request(
+                "linux",
+                state,
+                &request("p0", EngineMethod::RecordingPause, json!({})),
+            );
+            let message = expect_error(premature, ProtocolErrorCode::InvalidParams);
+            assert_eq!(message, "Start recording before pausing");
+
+            let _ = expect_success(handle_request(
+                "linux",
+                state,
+                &request("p1", EngineMethod::CaptureStartDisplay, json!({})),
+            ));
+            let _ = expect_success(handle_request(
+                "linux",
+                state,
+                &request("p2", EngineMethod::RecordingStart, json!({})),
+            ));
+            std::thread::sleep(std::time::Duration::from_millis(100));
+
+            let paused = expect_success(handle_request(
+                "linux",
+                state,
+                &request("p3", EngineMethod::RecordingPause, json!({})),
+            ));
+            assert_eq!(paused["isRecording"], json!(true));
+            assert_eq!(paused["isPaused"], json!(true));
+            let paused_duration = paused["recordingDurationSeconds"]
+                .as_f64()
+                .expect("paused duration");
+            let message = expect_error(
+                handle_request(
+                    "linux",
+                    state,
+                    &request("p4", EngineMethod::RecordingPause, json!({})),
+                ),
+                ProtocolErrorCode::InvalidParams,
+            );
+            assert_eq!(message, "Recording is already paused");
+
+            std::thread::sleep(std::time::Duration::from_millis(200));
+            let still_paused = expect_success(handle_request(
+                "linux",
+                state,
+                &request("p5", EngineMethod::CaptureStatus, json!({})),
+            ));
+            assert_eq!(
+                still_paused["recordingDurationSeconds"].as_f64(),
+                Some(paused_duration)
+            );
+
+            let resumed = expect_success(handle_request(
+                "linux",
+                state,
+                &request("p6", EngineMethod::RecordingResume, json!({})),
+            ));
+            assert_eq!(resumed["isPaused"], json!(false));
+            let pauses = resumed["recordingMetadata"]["pauses"]
+                .as_array()
+                .expect("pause ranges")
+                .clone();
+            assert_eq!(pauses.len(), 1);
+            assert_eq!(pauses[0]["atSeconds"].as_f64(), Some(paused_duration));
+            assert!(pauses[0]["durationSeconds"].as_f64().unwrap_or(0.0) >= 0.2);
+
+            let stopped = expect_success(handle_request(
+                "linux",
+                state,
+                &request("p7", EngineMethod::RecordingStop, json!({})),
+            ));
+            assert_eq!(
+                stopped["recordingURL"],
+                json!("native://recordings/session-1.y4m")
+            );
+            assert!(stopped["recordingDurationSeconds"].as_f64().unwrap_or(1.0) < 0.3);
+            let message = expect_error(
+                handle_request(
+                    "linux",
+                    state,
+                    &request("p8", EngineMethod::RecordingResume, json!({})),
+                ),
+                ProtocolErrorCode::InvalidParams,
+            );
+            assert_eq!(message, "Recording is not paused");
+
+            let _ = expect_success(handle_request(
+                "linux",
+                state,
+                &request(
+                    "p9",
+                    EngineMethod::ProjectSave,
+                    json!({ "projectPath": project_path.to_string_lossy() }),
+                ),
+            ));
+            state.recording_metadata = Default::default();
+            let opened = expect_success(handle_request(
+                "linux",
+                state,
+                &request(
+                    "p10",
+                    EngineMethod::ProjectOpen,
+                    json!({ "projectPath": project_path.to_string_lossy() }),
+                ),
+            ));
+            let reopened_pauses = opened["recordingMetadata"]["pauses"]
+                .as_array()
+                .expect("reopened pause ranges");
+            assert_eq!(reopened_pauses.len(), 1);
+            for key in ["atSeconds", "durationSeconds"] {
+                let saved = pauses[0][key].as_f64().unwrap_or_default();
+                let reopened = reopened_pauses[0][key].as_f64().unwrap_or_default();
+                assert!((saved - reopened).abs() < 1e-9, "{key}");
+            }
+        });
+    }
+
+    #[test]
+    fn recording_markers_are_journaled_and_surface_on_the_timeline() {
+        with_state("recording-markers", |state, root| {
+            let message = expect_error(
+                handle_request(
+                    "linux",
+                    state,
+                    &request("m0", EngineMethod::RecordingAddMarker, json!({})),
+                ),
+                ProtocolErrorCode::InvalidParams,
+            );
+            assert_eq!(message, "Start recording before adding a marker");
+            let project_path = root.join("markers-project.gglassproj");
+            let _ = expect_success(handle_request(
+                "linux",
+                state,
+                &request(
+                    "m1",
+                    EngineMethod::ProjectSave,
+                    json!({ "projectPath": project_path.to_string_lossy() }),
+                ),
+            ));
+            let _ = expect_success(handle_request(
+                "linux",
+                state,
+                &request("m2", EngineMethod::CaptureStartDisplay, json!({})),
+            ));
+            let started = expect_success(handle_request(
+                "linux",
+                state,
+                &request("m3", EngineMethod::RecordingStart, json!({})),
+            ));
+            let journal_path = PathBuf::from(
+                started["recordingURL"]
+                    .as_str()
+                    .expect("recording path inside project"),
+            )
+            .with_file_name("journal.json");
+
+            std::thread::sleep(std::time::Duration::from_millis(100));
+            let first = expect_success(handle_request(
+                "linux",
+                state,
+                &request(
+                    "m4",
+                    EngineMethod::RecordingAddMarker,
+                    json!({ "label": "  chapter start " }),
+                ),
+            ));
+            assert_eq!(first["marker"]["id"], json!("marker-1"));
+            assert_eq!(first["marker"]["label"], json!("chapter start"));
+            assert_eq!(first["marker"]["color"], json!("#F59E0B"));
+            assert!(first["marker"]["atSeconds"].as_f64().unwrap_or_default() >= 0.1);
+            let message = expect_error(
+                handle_request(
+                    "linux",
+                    state,
+                    &request(
+                        "m5",
+                        EngineMethod::RecordingAddMarker,
+                        json!({ "color": "red" }),
+                    ),
+                ),
+                ProtocolErrorCode::InvalidParams,
+            );
+            assert_eq!(message, "color must be #RRGGBB");
+
+            let paused = expect_success(handle_request(
+                "linux",
+                state,
+                &request("m6", EngineMethod::RecordingPause, json!({})),
+            ));
+            let second = expect_success(handle_request(
+                "linux",
+                state,
+                &request(
+                    "m7",
+                    EngineMethod::RecordingAddMarker,
+                    json!({ "label": "mistake here", "color": "#22c55e" }),
+                ),
+            ));
+            assert_eq!(second["marker"]["color"], json!("#22C55E"));
+            assert_eq!(
+                second["marker"]["atSeconds"],
+                paused["recordingMetadata"]["pauses"][0]["atSeconds"]
+            );
+            // The writer thread journals metadata updates between frames.
+            std::thread::sleep(std::time::Duration::from_millis(150));
+            let journal: Value =
+                serde_json::from_slice(&fs::read(&journal_path).expect("journal while recording"))
+                    .expect("decode journal");
+            assert_eq!(
+                journal["metadata"]["markers"].as_array().map(Vec::len),
+                Some(2)
+            );
+
+            let _ = expect_success(handle_request(
+                "linux",
+                state,
+                &request("m8", EngineMethod::RecordingResume, json!({})),
+            ));
+            let _ = expect_success(handle_request(
+                "linux",
+                state,
+                &request("m9", EngineMethod::RecordingStop, json!({})),
+            ));
+            let _ = expect_success(handle_request(
+                "linux",
+                state,
+                &request("m10", EngineMethod::ProjectSave, json!({})),
+            ));
+            let reopened = expect_success(handle_request(
+                "linux",
+                state,
+                &request(
+                    "m11",
+                    EngineMethod::ProjectOpen,
+                    json!({ "projectPath": project_path.to_string_lossy() }),
+                ),
+            ));
+            let markers = &reopened["timeline"]["markers"];
+            assert_eq!(markers.as_array().map(Vec::len), Some(2));
+            assert_eq!(markers[0]["label"], json!("chapter start"));
+            assert_eq!(markers[1]["label"], json!("mistake here"));
+            assert_eq!(reopened["recordingMetadata"]["markers"], *markers);
+        });
+    }
+
+    #[test]
+    fn recordings_become_takes_that_can_be_renamed_activated_deleted_and_edited() {
+        with_state("takes", |state, root| {
+            let project_path = root.join("takes-project.gglassproj");
+            let _ = expect_success(handle_request(
+                "linux",
+                state,
+                &request(
+                    "t1",
+                    EngineMethod::ProjectSave,
+                    json!({ "projectPath": project_path.to_string_lossy() }),
+                ),
+            ));
+            let _ = expect_success(handle_request(
+                "linux",
+                state,
+                &request("t2", EngineMethod::CaptureStartDisplay, json!({})),
+            ));
+            let mut recording_urls = Vec::new();
+            for take in 0..2 {
+                let started = expect_success(handle_request(
+                    "linux",
+                    state,
+                    &request(
+                        &format!("t3-{take}"),
+                        EngineMethod::RecordingStart,
+                        json!({}),
+                    ),
+                ));
+                recording_urls.push(started["recordingURL"].clone());
+                std::thread::sleep(std::time::Duration::from_millis(100));
+                let _ = expect_success(handle_request(
+                    "linux",
+                    state,
+                    &request(
+                        &format!("t4-{take}"),
+                        EngineMethod::RecordingStop,
+                        json!({}),
+                    ),
+                ));
+            }
+            assert_ne!(recording_urls[0], recording_urls[1]);
+
+            let listed = expect_success(handle_request(
+                "linux",
+                state,
+                &request("t5", EngineMethod::TakesList, json!({})),
+            ));
+            let takes = listed["takes"].as_array().expect("takes").clone();
+            assert_eq!(takes.len(), 2);
+            assert_eq!(takes[0]["name"], json!("Take 1"));
+            assert_eq!(takes[1]["name"], json!("Take 2"));
+            assert_eq!(takes[0]["recordingURL"], recording_urls[0]);
+            assert_eq!(takes[0]["captureMetadata"]["source"], json!("display"));
+            assert!(takes[0]["durationSeconds"].as_f64().unwrap_or_default() >= 0.1);
+            assert!(takes[0]["telemetry"]["achievedFps"].is_number());
+            let first = takes[0]["id"].as_str().expect("take id").to_string();
+            let second = takes[1]["id"].as_str().expect("take id").to_string();
+            assert!(recording_urls[0]
+                .as_str()
+                .is_some_and(|url| url.contains(&first)));
+            assert_eq!(listed["activeTakeId"], json!(second));
+
+            let message = expect_error(
+                handle_request(
+                    "linux",
+                    state,
+                    &request(
+                        "t6",
+                        EngineMethod::TakesRename,
+                        json!({ "takeId": first, "name": "  " }),
+                    ),
+                ),
+                ProtocolErrorCode::InvalidParams,
+            );
+            assert_eq!(message, "name must be between 1 and 120 characters");
+            let renamed = expect_success(handle_request(
+                "linux",
+                state,
+                &request(
+                    "t7",
+                    EngineMethod::TakesRename,
+                    json!({ "takeId": first, "name": "Intro" }),
+                ),
+            ));
+            assert_eq!(renamed["takes"][0]["name"], json!("Intro"));
+            let _ = expect_success(handle_request(
+                "linux",
+                state,
+                &request(
+                    "t8",
+                    EngineMethod::TakesActivate,
+                    json!({ "takeId": first }),
+                ),
+            ));
+            let project = expect_success(handle_request(
+                "linux",
+                state,
+                &request("t9", EngineMethod::ProjectCurrent, json!({})),
+            ));
+            assert_eq!(project["recordingURL"], recording_urls[0]);
+            assert_eq!(project["activeTakeId"], json!(first));
+
+            let clip = |id: &str, source: &str| {
+                json!({
+                    "kind": "clip",
+                    "id": id,
+                    "sourceAssetId": source,
+                    "sourceStartSeconds": 0,
+                    "sourceEndSeconds": 0.05,
+                })
+            };
+            let message = expect_error(
+                handle_request(
+                    "linux",
+                    state,
+                    &request(
+                        "t10",
+                        EngineMethod::ProjectSave,
+                        json!({ "timeline": { "version": 2, "items": [clip("a", "missing")] } }),
+                    ),
+                ),
+                ProtocolErrorCode::InvalidParams,
+            );
+            assert_eq!(message, "Timeline clip a references unknown take missing");
+            let saved = expect_success(handle_request(
+                "linux",
+                state,
+                &request(
+                    "t11",
+                    EngineMethod::ProjectSave,
+                    json!({ "timeline": { "version": 2, "items": [
+                        clip("a", "recording"),
+                        { "kind": "gap", "id": "g", "durationSeconds": 1 },
+                        clip("b", &second),
+                    ] } }),
+                ),
+            ));
+            assert_eq!(
+                saved["timeline"]["items"][2]["sourceAssetId"],
+                json!(second)
+            );
+
+            let message = expect_error(
+                handle_request(
+                    "linux",
+                    state,
+                    &request(
+                        "t12",
+                        EngineMethod::TakesDelete,
+                        json!({ "takeId": second, "destructiveIntent": true }),
+                    ),
+                ),
+                ProtocolErrorCode::InvalidParams,
+            );
+            assert_eq!(
+                message,
+                format!("Take {second} is used by 1 timeline clips")
+            );
+            let _ = expect_error(
+                handle_request(
+                    "linux",
+                    state,
+                    &request("t13", EngineMethod::TakesDelete, json!({ "takeId": first })),
+                ),
+                ProtocolErrorCode::NeedsConfirmation,
+            );
+            let first_directory = project_path.join("recordings").join(&first);
+            assert!(first_directory.is_dir());
+            let deleted = expect_success(handle_request(
+                "linux",
+                state,
+                &request(
+                    "t14",
+                    EngineMethod::TakesDelete,
+                    json!({ "takeId": first, "destructiveIntent": true }),
+                ),
+            ));
+            assert!(!first_directory.exists());
+            assert_eq!(deleted["takes"].as_array().map(Vec::len), Some(1));
+            assert_eq!(deleted["activeTakeId"], json!(second));
+
+            let _ = expect_success(handle_request(
+                "linux",
+                state,
+                &request(
+                    "t15",
+                    EngineMethod::ExportRun,
+                    json!({ "outputURL": root.join("takes.mp4").to_string_lossy() }),
+                ),
+            ));
+            let exported = state
+                .latest_export_timeline
+                .clone()
+                .expect("export timeline");
+            assert_eq!(exported.len(), 2);
+            assert!(exported.iter().all(
+                |clip| clip.take_id == second && json!(clip.recording_url) == recording_urls[1]
+            ));
+
+            let _ = expect_success(handle_request(
+                "linux",
+                state,
+                &request("t16", EngineMethod::ProjectSave, json!({})),
+            ));
+            let reopened = expect_success(handle_request(
+                "linux",
+                state,
+                &request(
+                    "t17",
+                    EngineMethod::ProjectOpen,
+                    json!({ "projectPath": project_path.to_string_lossy() }),
+                ),
+            ));
+            assert_eq!(reopened["takes"].as_array().map(Vec::len), Some(1));
+            assert_eq!(reopened["activeTakeId"], json!(second));
+            assert_eq!(reopened["recordingURL"], recording_urls[1]);
+            assert_eq!(
+                reopened["timeline"]["items"].as_array().map(Vec::len),
+                Some(3)
+            );
+        });
+    }
+
+    #[test]
+    fn disconnected_displays_and_closed_windows_stop_capture_with_an_error() {
+        with_state("source-changes", |state, _| {
+            let sources = expect_success(handle_request(
+                "linux",
+                state,
+                &request("s1", EngineMethod::SourcesList, json!({})),
+            ));
+            let revision = sources["sourcesRevision"]
+                .as_u64()
+                .expect("sources revision");
+            let _ = expect_success(handle_request(
+                "linux",
+                state,
+                &request(
+                    "s2",
+                    EngineMethod::CaptureStartRegion,
+                    json!({
+                        "displayId": 1,
+                        "rect": { "x": 0, "y": 0, "width": 640, "height": 360 }
+                    }),
+                ),
+            ));
+            let _ = expect_success(handle_request(
+                "linux",
+                state,
+                &request("s3", EngineMethod::RecordingStart, json!({})),
+            ));
+
+            // A window closing elsewhere leaves the display capture alone.
+            state
+                .source_monitor
+                .publish(crate::source_monitor::SourceChange::WindowClosed(555));
+            let status = expect_success(handle_request(
+                "linux",
+                state,
+                &request("s4", EngineMethod::CaptureStatus, json!({})),
+            ));
+            assert_eq!(status["isRecording"], json!(true));
+            assert_eq!(status["sourcesRevision"], json!(revision + 1));
+
+            state.displays = Vec::new;
+            state
+                .source_monitor
+                .publish(crate::source_monitor::SourceChange::DisplaysChanged);
+            let status = expect_success(handle_request(
+                "linux",
+                state,
+                &request("s5", EngineMethod::CaptureStatus, json!({})),
+            ));
+            assert_eq!(status["isRunning"], json!(false));
+            assert_eq!(status["isRecording"], json!(false));
+            assert_eq!(status["captureSessionId"], Value::Null);
+            assert_eq!(status["sourcesRevision"], json!(revision + 2));
+            assert_eq!(
+                status["lastError"],
+                json!({
+                    "code": "runtime_error",
+                    "message": "Capture stopped because display 1 was disconnected",
+                })
+            );
+            assert_eq!(state.takes.len(), 1);
+
+            let _ = expect_success(handle_request(
+                "linux",
+                state,
+                &request(
+                    "s6",
+                    EngineMethod::CaptureStartWindow,
+                    json!({ "windowId": 555 }),
+                ),
+            ));
+            state
+                .source_monitor
+                .publish(crate::source_monitor::SourceChange::WindowClosed(555));
+            let status = expect_success(handle_request(
+                "linux",
+                state,
+                &request("s7", EngineMethod::CaptureStatus, json!({})),
+            ));
+            assert_eq!(status["isRunning"], json!(false));
+            assert_eq!(
+                status["lastError"]["message"],
+                json!("Capture stopped because window 555 was closed")
+            );
+        });
+    }
+
+    #[test]
+    fn recording_in_a_project_is_journaled_and_finalized_on_stop() {
+        with_state("recording-journal-finalize", |state, root| {
+            let project_path = root.join("journal-project.gglassproj");
+            let _ = expect_success(handle_request(
+                "linux",
+                state,
+                &request(
+                    "j1",
+                    EngineMethod::ProjectSave,
+                    json!({ "projectPath": project_path.to_string_lossy() }),
+                ),
+            ));
+            let _ = expect_success(handle_request(
+                "linux",
+                state,
+                &request("j2", EngineMethod::CaptureStartDisplay, json!({})),
+            ));
+            let started = expect_success(handle_request(
+                "linux",
+                state,
+                &request("j3", EngineMethod::RecordingStart, json!({})),
+            ));
+            let recording_path = PathBuf::from(
+                started["recordingURL"]
+                    .as_str()
+                    .expect("recording path inside project"),
+            );
+            assert!(recording_path.starts_with(project_path.join("recordings")));
+            let journal_path = recording_path.with_file_name("journal.json");
+            let journal: Value =
+                serde_json::from_slice(&fs::read(&journal_path).expect("journal while recording"))
+                    .expect("decode journal");
+            assert_eq!(journal["status"], json!("recording"));
+
+            std::thread::sleep(std::time::Duration::from_millis(250));
+            let _ = expect_success(handle_request(
+                "linux",
+                state,
+                &request("j4", EngineMethod::RecordingStop, json!({})),
+            ));
+            let output = fs::read(&recording_path).expect("finalized recording");
+            assert!(output.starts_with(b"YUV4MPEG2 W320 H180 F30:1"));
+            assert!(output.windows(6).any(|window| window == b"FRAME\n"));
+            let journal: Value =
+                serde_json::from_slice(&fs::read(&journal_path).expect("finalized journal"))
+                    .expect("decode journal");
+            assert_eq!(journal["status"], json!("finalized"));
+            assert_eq!(journal["metadata"]["recovered"], json!(false));
+        });
+    }
+
+    #[test]
+    fn camera_capture_records_a_second_track_and_export_places_it_picture_in_picture() {
+        with_state("camera-track", |state, root| {
+            state.cameras = || vec![crate::cameras::synthetic_camera()];
+            let sources = expect_success(handle_request(
+                "linux",
+                state,
+                &request("c0", EngineMethod::SourcesList, json!({})),
+            ));
+            assert_eq!(sources["cameras"][0]["id"], json!(9000));
+            assert_eq!(sources["cameras"][0]["devicePath"], Value::Null);
+
+            let unknown = handle_request(
+                "linux",
+                state,
+                &request(
+                    "c1",
+                    EngineMethod::CaptureStartCamera,
+                    json!({ "cameraId": 3 }),
+                ),
+            );
+            let message = expect_error(unknown, ProtocolErrorCode::InvalidParams);
+            assert!(message.contains("Unknown cameraId: 3"));
+
+            let project_path = root.join("camera-project.gglassproj");
+            let _ = expect_success(handle_request(
+                "linux",
+                state,
+                &request(
+                    "c2",
+                    EngineMethod::ProjectSave,
+                    json!({
+                        "projectPath": project_path.to_string_lossy(),
+                        "pictureInPicture": {
+                            "version": 1,
+                            "enabled": true,
+                            "corner": "topLeft",
+                            "sizeFraction": 0.25,
+                            "marginFraction": 0.02,
+                            "shape": "circle",
+                            "borderWidthFraction": 0.005,
+                            "borderColor": "#ff0000"
+                        }
+                    }),
+                ),
+            ));
+            let _ = expect_success(handle_request(
+                "linux",
+                state,
+                &request("c3", EngineMethod::CaptureStartDisplay, json!({})),
+            ));
+            let camera = expect_success(handle_request(
+                "linux",
+                state,
+                &request("c4", EngineMethod::CaptureStartCamera, json!({})),
+            ));
+            assert_eq!(camera["cameraId"], json!(9000));
+
+            let started = expect_success(handle_request(
+                "linux",
+                state,
+                &request("c5", EngineMethod::RecordingStart, json!({})),
+            ));
+            let recording_path = PathBuf::from(started["recordingURL"].as_str().expect("url"));
+            let camera_path = PathBuf::from(
+                started["cameraTrack"]["recordingURL"]
+                    .as_str()
+                    .expect("camera track url"),
+            );
+            assert_eq!(
+                camera_path,
+                recording_path
+                    .with_file_name("camera")
+                    .join("recording.y4m")
+            );
+            let locked = handle_request(
+                "linux",
+                state,
+                &request("c6", EngineMethod::CaptureStopCamera, json!({})),
+            );
+            expect_error(locked, ProtocolErrorCode::InvalidParams);
+
+            std::thread::sleep(std::time::Duration::from_millis(250));
+            let _ = expect_success(handle_request(
+                "linux",
+                state,
+                &request("c7", EngineMethod::RecordingStop, json!({})),
+            ));
+            let camera_output = fs::read(&camera_path).expect("finalized camera track");
+            assert!(camera_output.starts_with(b"YUV4MPEG2 W320 H240 F30:1"));
+            assert!(camera_output.windows(6).any(|window| window == b"FRAME\n"));
+
+            let output = root.join("pip.mp4");
+            let _ = expect_success(handle_request(
+                "linux",
+                state,
+                &request(
+                    "c8",
+                    EngineMethod::ExportRun,
+                    json!({ "outputURL": output.to_string_lossy() }),
+                ),
+            ));
+            let layout = state
+                .latest_export_picture_in_picture
+                .as_ref()
+                .expect("picture-in-picture layout");
+            assert_eq!(
+                (layout.x, layout.y, layout.width, layout.height),
+                (38, 38, 480, 480)
+            );
+            assert_eq!(layout.border_color, "#FF0000");
+
+            let stopped = expect_success(handle_request(
+                "linux",
+                state,
+                &request("c9", EngineMethod::CaptureStop, json!({})),
+            ));
+            assert_eq!(stopped["cameraId"], Value::Null);
+            assert!(state.camera_pipeline.is_none());
+        });
+    }
+
+    #[test]
+    fn audio_capture_records_microphone_and_system_audio_as_separate_tracks() {
+        with_state("audio-tracks", |state, root| {
+            state.audio_devices = || {
+                vec![
+                    crate::audio::AudioInputDevice {
+                        id: "alsa_input.usb-mic".to_string(),
+                        name: "USB Microphone".to_string(),
+                        is_monitor: false,
+                    },
+                    crate::audio::AudioInputDevice {
+                        id: "null.monitor".to_string(),
+                        name: "Monitor of Null Output".to_string(),
+                        is_monitor: true,
+                    },
+                ]
+            };
+            state.audio_sources = |_, _| Err("connection refused".to_string());
+            let sources = expect_success(handle_request(
+                "linux",
+                state,
+                &request("a0", EngineMethod::SourcesList, json!({})),
+            ));
+            assert_eq!(
+                sources["audioInputs"],
+                json!([
+                    { "id": "alsa_input.usb-mic", "displayName": "USB Microphone", "kind": "microphone" },
+                    { "id": "null.monitor", "displayName": "Monitor of Null Output", "kind": "monitor" }
+                ])
+            );
+
+            let project_path = root.join("audio-project.gglassproj");
+            let _ = expect_success(handle_request(
+                "linux",
+                state,
+                &request(
+                    "a1",
+                    EngineMethod::ProjectSave,
+                    json!({ "projectPath": project_path.to_string_lossy() }),
+                ),
+            ));
+            let _ = expect_success(handle_request(
+                "linux",
+                state,
+                &request("a2", EngineMethod::CaptureStartDisplay, json!({})),
+            ));
+            let message = expect_error(
+                handle_request(
+                    "linux",
+                    state,
+                    &request(
+                        "a3",
+                        EngineMethod::RecordingStart,
+                        json!({ "captureMicrophone": true, "microphoneDeviceId": "missing" }),
+                    ),
+                ),
+                ProtocolErrorCode::InvalidParams,
+            );
+            assert_eq!(message, "Unknown microphoneDeviceId: missing");
+            let message = expect_error(
+                handle_request(
+                    "linux",
+                    state,
+                    &request(
+                        "a4",
+                        EngineMethod::RecordingStart,
+                        json!({
+                            "captureSystemAudio": true,
+                            "systemAudioDeviceId": "alsa_input.usb-mic"
+                        }),
+                    ),
+                ),
+                ProtocolErrorCode::InvalidParams,
+            );
+            assert_eq!(
+                message,
+                "systemAudioDeviceId alsa_input.usb-mic is not a monitor source"
+            );
+            let message = expect_error(
+                handle_request(
+                    "linux",
+                    state,
+                    &request(
+                        "a5",
+                        EngineMethod::RecordingStart,
+                        json!({ "captureMicrophone": true }),
+                    ),
+                ),
+                ProtocolErrorCode::RuntimeError,
+            );
+            assert_eq!(
+                message,
+                "Unable to capture microphone audio: connection refused"
+            );
+            assert!(!state.is_recording);
+
+            state.audio_sources = |kind, _| {
+                let amplitude = match kind {
+                    crate::audio::AudioTrackKind::Microphone => 0.5,
+                    crate::audio::AudioTrackKind::SystemAudio => 0.25,
+                };
+                Ok(Box::new(crate::audio::SyntheticAudioSource::new(amplitude)))
+            };
+            let started = expect_success(handle_request(
+                "linux",
+                state,
+                &request(
+                    "a6",
+                    EngineMethod::RecordingStart,
+                    json!({
+                        "captureMicrophone": true,
+                        "captureSystemAudio": true,
+                        "systemAudioDeviceId": "null.monitor"
+                    }),
+                ),
+            ));
+            let recording_path = PathBuf::from(started["recordingURL"].as_str().expect("url"));
+            let tracks = started["audioTracks"].as_array().expect("audio tracks");
+            assert_eq!(tracks.len(), 2);
+            assert_eq!(tracks[0]["kind"], json!("microphone"));
+            assert_eq!(tracks[0]["deviceId"], Value::Null);
+            assert_eq!(
+                tracks[0]["recordingURL"],
+                json!(recording_path
+                    .with_file_name("audio_mic.wav")
+                    .to_string_lossy())
+            );
+            assert_eq!(tracks[1]["kind"], json!("systemAudio"));
+            assert_eq!(tracks[1]["deviceId"], json!("null.monitor"));
+            assert_eq!(tracks[1]["sampleRate"], json!(48_000));
+
+            std::thread::sleep(std::time::Duration::from_millis(200));
+            let stopped = expect_success(handle_request(
+                "linux",
+                state,
+                &request("a7", EngineMethod::RecordingStop, json!({})),
+            ));
+            assert_eq!(stopped["lastError"], Value::Null);
+            assert!(state.audio_recorders.is_empty());
+            let levels = stopped["lastRecordingTelemetry"]["audioTracks"]
+                .as_array()
+                .expect("audio telemetry");
+            assert_eq!(levels.len(), 2);
+            let microphone_peak = levels[0]["peakDbfs"].as_f64().expect("peak");
+            let system_peak = levels[1]["peakDbfs"].as_f64().expect("peak");
+            assert!((microphone_peak + 6.02).abs() < 0.1);
+            assert!((system_peak + 12.04).abs() < 0.1);
+            for (track, file_name) in [
+                (&levels[0], "audio_mic.wav"),
+                (&levels[1], "audio_system.wav"),
+            ] {
+                assert!(track["capturedSeconds"].as_f64().unwrap_or(0.0) > 0.0);
+                let wav = fs::read(recording_path.with_file_name(file_name)).expect("audio track");
+                assert!(wav.starts_with(b"RIFF"));
+                assert!(wav.len() > 44);
+            }
+        });
+    }
+
+    #[test]
+    fn capture_status_meters_audio_live_and_warns_about_a_silent_microphone() {
+        with_state("audio-levels", |state, _root| {
+            state.audio_sources = |_, _| Ok(Box::new(crate::audio::SyntheticAudioSource::new(0.0)));
+            let _ = expect_success(handle_request(
+                "linux",
+                state,
+                &request("l0", EngineMethod::CaptureStartDisplay, json!({})),
+            ));
+            let message = expect_error(
+                handle_request(
+                    "linux",
+                    state,
+                    &request(
+                        "l1",
+                        EngineMethod::RecordingStart,
+                        json!({ "captureMicrophone": true, "silenceWarningSeconds": 0 }),
+                    ),
+                ),
+                ProtocolErrorCode::InvalidParams,
+            );
+            assert_eq!(message, "silenceWarningSeconds must be a positive number");
+
+            let started = expect_success(handle_request(
+                "linux",
+                state,
+                &request(
+                    "l2",
+                    EngineMethod::RecordingStart,
+                    json!({ "captureMicrophone": true, "silenceWarningSeconds": 0.2 }),
+                ),
+            ));
+            assert_eq!(started["audioLevels"][0]["kind"], json!("microphone"));
+            assert_eq!(started["lastError"], Value::Null);
+
+            std::thread::sleep(std::time::Duration::from_millis(400));
+            let status = expect_success(handle_request(
+                "linux",
+                state,
+                &request("l3", EngineMethod::CaptureStatus, json!({})),
+            ));
+            let levels = &status["audioLevels"][0];
+            assert_eq!(levels["peakDbfs"], json!(-96.0));
+            assert_eq!(levels["clippedSamples"], json!(0));
+            assert_eq!(levels["silenceWarning"], json!(true));
+            assert!(levels["silentSeconds"].as_f64().unwrap_or(0.0) >= 0.2);
+            assert_eq!(
+                status["lastError"]["message"],
+                json!("Microphone has been silent for 0.2 seconds while recording")
+            );
+
+            let stopped = expect_success(handle_request(
+                "linux",
+                state,
+                &request("l4", EngineMethod::RecordingStop, json!({})),
+            ));
+            assert_eq!(stopped["audioLevels"], json!([]));
+            assert_eq!(
+                stopped["lastRecordingTelemetry"]["audioTracks"][0]["clippedSamples"],
+                json!(0)
+            );
+        });
+    }
+
+    fn test_windows() -> Result<Vec<crate::privacy_mask::WindowInfo>, String> {
+        Ok(vec![
+            crate::privacy_mask::WindowInfo {
+                id: 7,
+                title: "Passwords.kdbx - KeePassXC".to_string(),
+                wm_class: vec!["keepassxc".to_string(), "KeePassXC".to_string()],
+                x: 0,
+                y: 0,
+                width: 960,
+                height: 540,
+            },
+            crate::privacy_mask::WindowInfo {
+                id: 9,
+                title: "Terminal".to_string(),
+                wm_class: vec!["xterm".to_string(), "XTerm".to_string()],
+                x: 960,
+                y: 540,
+                width: 960,
+                height: 540,
+            },
+        ])
+    }
+
+    #[test]
+    fn display_capture_stops_when_excluded_windows_cannot_be_located() {
+        with_state("window-exclusions-fail-closed", |state, _| {
+            state.windows = || Err("X11 connection lost".to_string());
+            let capture = expect_success(handle_request(
+                "linux",
+                state,
+                &request(
+                    "wf1",
+                    EngineMethod::CaptureStartDisplay,
+                    json!({ "windowExclusions": { "rules": [{ "wmClass": "keepassxc" }] } }),
+                ),
+            ));
+            assert_eq!(capture["privacyMask"]["ruleCount"], json!(1));
+            std::thread::sleep(std::time::Duration::from_millis(150));
+            let status = expect_success(handle_request(
+                "linux",
+                state,
+                &request("wf2", EngineMethod::CaptureStatus, json!({})),
+            ));
+            assert_eq!(status["isRunning"], json!(false));
+            assert_eq!(status["privacyMask"], Value::Null);
+            let message = status["lastError"]["message"].as_str().unwrap_or_default();
+            assert!(message.contains("X11 connection lost"));
+        });
+    }
+
+    #[test]
+    fn excluded_windows_are_masked_in_display_capture_and_persisted_as_defaults() {
+        with_state("window-exclusions", |state, root| {
+            state.windows = test_windows;
+            let message = expect_error(
+                handle_request(
+                    "linux",
+                    state,
+                    &request(
+                        "w0",
+                        EngineMethod::LibrarySaveDefaults,
+                        json!({ "windowExclusions": { "rules": [{ "titlePattern": " " }] } }),
+                    ),
+                ),
+                ProtocolErrorCode::InvalidParams,
+            );
+            assert_eq!(
+                message,
+                "windowExclusions.rules entries need a windowId, titlePattern or wmClass"
+            );
+            let defaults = expect_success(handle_request(
+                "linux",
+                state,
+                &request(
+                    "w1",
+                    EngineMethod::LibrarySaveDefaults,
+                    json!({ "windowExclusions": {
+                        "rules": [{ "wmClass": "KEEPASSXC" }],
+                        "fillColor": "#ffffff",
+                    } }),
+                ),
+            ));
+            assert_eq!(defaults["windowExclusions"]["fillColor"], json!("#FFFFFF"));
+            assert_eq!(
+                defaults["windowExclusions"]["maskStyle"],
+                json!("solidFill")
+            );
+
+            let project_path = root.join("privacy-project.gglassproj");
+            let saved = expect_success(handle_request(
+                "linux",
+                state,
+                &request(
+                    "w2",
+                    EngineMethod::ProjectSave,
+                    json!({ "projectPath": project_path.to_string_lossy() }),
+                ),
+            ));
+            assert_eq!(saved["windowExclusions"], Value::Null);
+            let index: Value = serde_json::from_slice(
+                &fs::read(root.join("Library").join("library.native.json")).expect("library index"),
+            )
+            .expect("decode library index");
+            assert_eq!(index["items"].as_array().map(Vec::len), Some(1));
+            assert_eq!(
+                index["defaults"]["windowExclusions"]["rules"],
+                json!([{ "wmClass": "KEEPASSXC" }])
+            );
+
+            let sources = expect_success(handle_request(
+                "linux",
+                state,
+                &request("w3", EngineMethod::SourcesList, json!({})),
+            ));
+            assert_eq!(sources["windows"][1]["id"], json!(7));
+            assert_eq!(
+                sources["windows"][1]["wmClass"],
+                json!(["keepassxc", "KeePassXC"])
+            );
+
+            let capture = expect_success(handle_request(
+                "linux",
+                state,
+                &request("w4", EngineMethod::CaptureStartDisplay, json!({})),
+            ));
+            assert_eq!(capture["privacyMask"]["ruleCount"], json!(1));
+            let started = expect_success(handle_request(
+                "linux",
+                state,
+                &request("w5", EngineMethod::RecordingStart, json!({})),
+            ));
+            let recording_path = PathBuf::from(
+                started["recordingURL"]
+                    .as_str()
+                    .expect("recording path inside project"),
+            );
+            std::thread::sleep(std::time::Duration::from_millis(250));
+            let status = expect_success(handle_request(
+                "linux",
+                state,
+                &request("w6", EngineMethod::CaptureStatus, json!({})),
+            ));
+            assert_eq!(status["privacyMask"]["maskedWindowIds"], json!([7]));
+            let _ = expect_success(handle_request(
+                "linux",
+                state,
+                &request("w7", EngineMethod::RecordingStop, json!({})),
+            ));
+
+            // The KeePassXC window covers the top-left quarter of the 320x180 proxy frames and
+            // is filled white (limited-range luma 235), which the test pattern never reaches.
+            let output = fs::read(&recording_path).expect("finalized recording");
+            let frame_start = output
+                .windows(6)
+                .position(|window| window == b"FRAME\n")
+                .expect("recorded frame")
+                + 6;
+            let luma = &output[frame_start..frame_start + 320 * 180];
+            assert!((0..90).all(|y| luma[y * 320..y * 320 + 160]
+                .iter()
+                .all(|value| *value == 235)));
+            assert!(luma[100 * 320..].iter().all(|value| *value != 235));
+
+            let saved = expect_success(handle_request(
+                "linux",
+                state,
+                &request(
+                    "w8",
+                    EngineMethod::ProjectSave,
+                    json!({ "windowExclusions": {
+                        "rules": [{ "windowId": 9 }],
+                        "maskStyle": "blur",
+                    } }),
+                ),
+            ));
+            assert_eq!(
+                saved["windowExclusions"]["rules"],
+                json!([{ "windowId": 9 }])
+            );
+            let reopened = expect_success(handle_request(
+                "linux",
+                state,
+                &request(
+                    "w9",
+                    EngineMethod::ProjectOpen,
+                    json!({ "projectPath": project_path.to_string_lossy() }),
+                ),
+            ));
+            assert_eq!(reopened["windowExclusions"]["maskStyle"], json!("blur"));
+            let region = expect_success(handle_request(
+                "linux",
+                state,
+                &request(
+                    "w10",
+                    EngineMethod::CaptureStartRegion,
+                    json!({ "rect": { "x": 800, "y": 400, "width": 400, "height": 300 } }),
+                ),
+            ));
+            assert_eq!(region["privacyMask"]["maskStyle"], json!("blur"));
+            let message = expect_error(
+                handle_request(
+                    "linux",
+                    state,
+                    &request(
+                        "w11",
+                        EngineMethod::CaptureStartDisplay,
+                        json!({ "windowExclusions": { "version": 2 } }),
+                    ),
+                ),
+                ProtocolErrorCode::InvalidParams,
+            );
+            assert_eq!(message, "windowExclusions.version must be 1");
+            let window = expect_success(handle_request(
+                "linux",
+                state,
+                &request("w12", EngineMethod::CaptureStartWindow, json!({})),
+            ));
+            assert_eq!(window["privacyMask"], Value::Null);
+
+            let restarted = State::new(state.recents_index_path.clone());
+            assert_eq!(
+                restarted.default_window_exclusions,
+                state.default_window_exclusions
+            );
+            assert_eq!(restarted.recent_projects.len(), 1);
+        });
+    }
+
+    #[test]
+    fn recording_counts_down_and_stops_itself_at_the_time_limit() {
+        with_state("recording-schedule", |state, root| {
+            let project_path = root.join("schedule-project.gglassproj");
+            let _ = expect_success(handle_request(
+                "linux",
+                state,
+                &request(
+                    "s0",
+                    EngineMethod::ProjectSave,
+                    json!({ "projectPath": project_path.to_string_lossy() }),
+                ),
+            ));
+            let _ = expect_success(handle_request(
+                "linux",
+                state,
+                &request("s1", EngineMethod::CaptureStartDisplay, json!({})),
+            ));
+            for (params, expected) in [
+                (
+                    json!({ "countdownSeconds": 90 }),
+                    "countdownSeconds must be between 0 and 60",
+                ),
+                (
+                    json!({ "maxDurationSeconds": -1 }),
+                    "maxDurationSeconds must be a positive number",
+                ),
+                (
+                    json!({ "stopAt": "tomorrow" }),
+                    "stopAt must be an RFC 3339 timestamp",
+                ),
+                (
+                    json!({ "stopAt": "2001-01-01T00:00:00Z" }),
+                    "stopAt must be after the recording starts",
+                ),
+            ] {
+                let message = expect_error(
+                    handle_request(
+                        "linux",
+                        state,
+                        &request("s2", EngineMethod::RecordingStart, params),
+                    ),
+                    ProtocolErrorCode::InvalidParams,
+                );
+                assert_eq!(message, expected);
+            }
+
+            let started = expect_success(handle_request(
+                "linux",
+                state,
+                &request(
+                    "s3",
+                    EngineMethod::RecordingStart,
+                    json!({ "countdownSeconds": 0.3, "maxDurationSeconds": 0.5 }),
+                ),
+            ));
+            assert_eq!(started["isCountingDown"], json!(true));
+            let countdown = started["countdownRemainingSeconds"]
+                .as_f64()
+                .expect("countdown");
+            assert!(countdown > 0.2 && countdown <= 0.3);
+            assert_eq!(started["recordingRemainingSeconds"], json!(0.5));
+            assert_eq!(started["recordingDurationSeconds"], json!(0.0));
+            let message = expect_error(
+                handle_request(
+                    "linux",
+                    state,
+                    &request("s4", EngineMethod::RecordingPause, json!({})),
+                ),
+                ProtocolErrorCode::InvalidParams,
+            );
+            assert_eq!(message, "Recording has not started yet");
+            let recording_path = PathBuf::from(started["recordingURL"].as_str().expect("url"));
+
+            std::thread::sleep(std::time::Duration::from_millis(1_100));
+            let status = expect_success(handle_request(
+                "linux",
+                state,
+                &request("s5", EngineMethod::CaptureStatus, json!({})),
+            ));
+            assert_eq!(status["isRecording"], json!(false));
+            assert_eq!(status["isCountingDown"], json!(false));
+            assert_eq!(status["recordingRemainingSeconds"], Value::Null);
+            assert_eq!(status["lastError"], Value::Null);
+            let duration = status["recordingDurationSeconds"]
+                .as_f64()
+                .expect("duration");
+            assert!((duration - 0.5).abs() < 1e-6, "duration {duration}");
+            let output = fs::read(&recording_path).expect("finalized recording");
+            let frames = output
+                .windows(6)
+                .filter(|window| window == b"FRAME\n")
+                .count();
+            assert!(
+                (10..=16).contains(&frames),
+                "{frames} frames in 0.5 s at 30 fps"
+            );
+
+            let stop_at = time::OffsetDateTime::now_utc() + time::Duration::milliseconds(400);
+            let started = expect_success(handle_request(
+                "linux",
+                state,
+                &request(
+                    "s6",
+                    EngineMethod::RecordingStart,
+                    json!({
+                        "stopAt": stop_at
+                            .format(&time::format_description::well_known::Rfc3339)
+                            .expect("format stopAt")
+                    }),
+                ),
+            ));
+            let remaining = started["recordingRemainingSeconds"]
+                .as_f64()
+                .expect("remaining");
+            assert!(remaining > 0.3 && remaining <= 0.4);
+            std::thread::sleep(std::time::Duration::from_millis(700));
+            let status = expect_success(handle_request(
+                "linux",
+                state,
+                &request("s7", EngineMethod::CaptureStatus, json!({})),
+            ));
+            assert_eq!(status["isRecording"], json!(false));
+            let duration = status["recordingDurationSeconds"]
+                .as_f64()
+                .expect("duration");
+            assert!((duration - 0.4).abs() < 0.05, "duration {duration}");
+        });
+    }
+
+    #[test]
+    fn recording_recover_finalizes_interrupted_sessions_as_recovered() {
+        with_state("recording-recover", |state, root| {
+            let project_path = root.join("recover-project.gglassproj");
+            let message = expect_error(
+                handle_request(
+                    "linux",
+                    state,
+                    &request("rc0", EngineMethod::RecordingRecover, json!({})),
+                ),
+                ProtocolErrorCode::InvalidParams,
+            );
+            assert_eq!(message, "Open a project before recovering recordings");
+
+            let _ = expect_success(handle_request(
+                "linux",
+                state,
+                &request(
+                    "rc1",
+                    EngineMethod::ProjectSave,
+                    json!({ "projectPath": project_path.to_string_lossy() }),
+                ),
+            ));
+            let _ = expect_success(handle_request(
+                "linux",
+                state,
+                &request("rc2", EngineMethod::CaptureStartDisplay, json!({})),
+            ));
+            let started = expect_success(handle_request(
+                "linux",
+                state,
+                &request("rc3", EngineMethod::RecordingStart, json!({})),
+            ));
+            let recording_url = started["recordingURL"].clone();
+            std::thread::sleep(std::time::Duration::from_millis(250));
+
+            // Simulate the sidecar dying: the pipeline goes away without finalizing.
+            let crashed_recording = state.recording_id.take().expect("journaled recording id");
+            state.capture_pipeline = None;
+            state.is_recording = false;
+            state.recording_url = None;
+
+            let recovered = expect_success(handle_request(
+                "linux",
+                state,
+                &request("rc4", EngineMethod::RecordingRecover, json!({})),
+            ));
+            let recordings = recovered["recordings"].as_array().expect("recordings");
+            assert_eq!(recordings.len(), 1);
+            assert_eq!(recordings[0]["recordingId"], json!(crashed_recording));
+            assert_eq!(recordings[0]["recordingURL"], recording_url);
+            assert_eq!(recordings[0]["recovered"], json!(true));
+            assert!(recordings[0]["frameCount"].as_u64().unwrap_or(0) > 0);
+            let output = fs::read(recording_url.as_str().expect("recording path"))
+                .expect("recovered recording");
+            assert!(output.starts_with(b"YUV4MPEG2 "));
+
+            let project = expect_success(handle_request(
+                "linux",
+                state,
+                &request("rc5", EngineMethod::ProjectCurrent, json!({})),
+            ));
+            assert_eq!(project["recordingURL"], recording_url);
+            assert_eq!(project["recordingMetadata"]["recovered"], json!(true));
+
+            let again = expect_success(handle_request(
+                "linux",
+                state,
+                &request("rc6", EngineMethod::RecordingRecover, json!({})),
+            ));
+            assert_eq!(again["recordings"], json!([]));
+        });
+    }
+
+    #[test]
+    fn recording_start_refuses_when_the_project_volume_is_low_on_space() {
+        with_state("recording-disk-preflight", |state, root| {
+            state.project_path = Some(
+                root.join("low-space.gglassproj")
+                    .to_string_lossy()
+                    .into_owned(),
+            );
+            state.disk_space = |_| Some(300 * 1024 * 1024);
+            let _ = expect_success(handle_request(
+                "linux",
+                state,
+                &request("d1", EngineMethod::CaptureStartDisplay, json!({})),
+            ));
+            let message = expect_error(
+                handle_request(
+                    "linux",
+                    state,
+                    &request("d2", EngineMethod::RecordingStart, json!({})),
+                ),
+                ProtocolErrorCode::InvalidParams,
+            );
+            assert_eq!(
+                message,
+                "Not enough free disk space to record: 300 MB available, 552 MB required"
+            );
+            assert!(!root.join("low-space.gglassproj").exists());
+        });
+    }
+
+    #[test]
+    fn recording_stops_cleanly_with_last_error_when_disk_space_runs_low() {
+        static AVAILABLE_BYTES: AtomicU64 = AtomicU64::new(4 * 1024 * 1024 * 1024);
+        with_state("recording-disk-auto-stop", |state, root| {
+            state.project_path = Some(
+                root.join("auto-stop.gglassproj")
+                    .to_string_lossy()
+                    .into_owned(),
+            );
+            state.disk_space = |_| Some(AVAILABLE_BYTES.load(Ordering::SeqCst));
+            let _ = expect_success(handle_request(
+                "linux",
+                state,
+                &request("a1", EngineMethod::CaptureStartDisplay, json!({})),
+            ));
+            let started = expect_success(handle_request(
+                "linux",
+                state,
+                &request("a2", EngineMethod::RecordingStart, json!({})),
+            ));
+            assert_eq!(started["lastError"], Value::Null);
+
+            AVAILABLE_BYTES.store(64 * 1024 * 1024, Ordering::SeqCst);
+            std::thread::sleep(std::time::Duration::from_millis(1_300));
+            let status = expect_success(handle_request(
+                "linux",
+                state,
+                &request("a3", EngineMethod::CaptureStatus, json!({})),
+            ));
+            assert_eq!(status["isRecording"], json!(false));
+            assert_eq!(status["isRunning"], json!(true));
+            assert_eq!(
+                status["lastError"],
+                json!({
+                    "code": "runtime_error",
+                    "message": "Recording stopped because free disk space fell below 256 MB"
+                })
+            );
+            assert!(status["lastRecordingTelemetry"].is_object());
+            let recording_path = status["recordingURL"].as_str().expect("recording path");
+            let output = fs::read(recording_path).expect("finalized recording");
+            assert!(output.starts_with(b"YUV4MPEG2 "));
+        });
+    }
+
+    #[test]
+    fn recording_with_input_tracking_writes_an_event_log_beside_the_recording() {
+        with_state("recording-input-events", |state, root| {
+            state.project_path = Some(
+                root.join("events.gglassproj")
+                    .to_string_lossy()
+                    .into_owned(),
+            );
+            let _ = expect_success(handle_request(
+                "linux",
+                state,
+                &request("e1", EngineMethod::CaptureStartDisplay, json!({})),
+            ));
+            let started = expect_success(handle_request(
+                "linux",
+                state,
+                &request(
+                    "e2",
+                    EngineMethod::RecordingStart,
+                    json!({ "trackInputEvents": true }),
+                ),
+            ));
+            let stopped = expect_success(handle_request(
+                "linux",
+                state,
+                &request("e3", EngineMethod::RecordingStop, json!({})),
+            ));
+
+            // Without an X server (or off Linux) the recording proceeds and reports why.
+            let Some(events_url) = started["eventsURL"].as_str() else {
+                let message = stopped["lastError"]["message"].as_str().unwrap_or_default();
+                assert!(
+                    message.starts_with("Input tracking unavailable: "),
+                    "{message}"
+                );
+                return;
+            };
+            let recording_url = started["recordingURL"].as_str().expect("recording path");
+            assert_eq!(
+                Path::new(events_url),
+                Path::new(recording_url).with_file_name("events.json")
+            );
+            let log: Value =
+                serde_json::from_slice(&fs::read(events_url).expect("event log written"))
+                    .expect("decode event log");
+            assert_eq!(log["schemaVersion"], json!(1));
+            assert!(log["events"].is_array());
+        });
+    }
+
     #[test]
     fn capture_session_id_changes_across_capture_restarts() {
         with_state("capture-session-ids", |state, _| {
             let first_capture = handle_request(
                 "linux",
                 state,
-                &request("r3", EngineMethod::CaptureStartDisplay, json!({})),
+                &request("r3", EngineMethod::CaptureStartDisplay, json!({})),
+            );
+            let first_capture_result = expect_success(first_capture);
+            assert_eq!(
+                first_capture_result["captureSessionId"],
+                json!("capture-session-1")
+            );
+
+            let _ = handle_request(
+                "linux",
+                state,
+                &request("r4", EngineMethod::CaptureStop, json!({})),
+            );
+
+            let second_capture = handle_request(
+                "linux",
+                state,
+                &request("r5", EngineMethod::CaptureStartDisplay, json!({})),
+            );
+            let second_capture_result = expect_success(second_capture);
+            assert_eq!(
+                second_capture_result["captureSessionId"],
+                json!("capture-session-2")
+            );
+        });
+    }
+
+    #[test]
+    fn sources_list_includes_pixel_scale_for_displays_and_windows() {
+        with_state("sources-list-pixel-scale", |state, _| {
+            let response = handle_request(
+                "linux",
+                state,
+                &request("r3a", EngineMethod::SourcesList, json!({})),
+            );
+            let result = expect_success(response);
+            let displays = result["displays"].as_array().expect("displays");
+            let windows = result["windows"].as_array().expect("windows");
+
+            assert_eq!(displays[0]["pixelScale"], json!(1.0));
+            assert_eq!(windows[0]["pixelScale"], json!(1.0));
+        });
+    }
+
+    #[test]
+    fn capture_start_window_uses_default_window_id_when_missing() {
+        with_state("capture-window-default-id", |state, _| {
+            let response = handle_request(
+                "linux",
+                state,
+                &request("r7", EngineMethod::CaptureStartWindow, json!({})),
+            );
+            let result = expect_success(response);
+            assert_eq!(result["captureMetadata"]["window"]["id"], json!(101));
+            assert_eq!(result["captureMetadata"]["source"], json!("window"));
+        });
+    }
+
+    #[test]
+    fn capture_start_region_reports_region_metadata() {
+        with_state("capture-region", |state, _| {
+            let response = handle_request(
+                "linux",
+                state,
+                &request(
+                    "region-1",
+                    EngineMethod::CaptureStartRegion,
+                    json!({
+                        "displayId": 1,
+                        "rect": { "x": 320, "y": 180, "width": 1280, "height": 720 },
+                        "captureFps": 30
+                    }),
+                ),
+            );
+            let result = expect_success(response);
+            assert_eq!(result["isRunning"], json!(true));
+            assert_eq!(result["captureMetadata"]["source"], json!("region"));
+            assert_eq!(
+                result["captureMetadata"]["contentRect"],
+                json!({ "x": 320, "y": 180, "width": 1280, "height": 720 })
+            );
+
+            let recording = handle_request(
+                "linux",
+                state,
+                &request("region-2", EngineMethod::RecordingStart, json!({})),
+            );
+            assert_eq!(expect_success(recording)["isRecording"], json!(true));
+        });
+    }
+
+    #[test]
+    fn capture_start_region_validates_display_and_bounds() {
+        with_state("capture-region-invalid", |state, _| {
+            for (params, expected) in [
+                (
+                    json!({ "displayId": 9, "rect": { "x": 0, "y": 0, "width": 10, "height": 10 } }),
+                    "Unknown displayId: 9",
+                ),
+                (json!({ "displayId": 1 }), "rect is required"),
+                (
+                    json!({ "rect": { "x": 1000, "y": 0, "width": 1280, "height": 720 } }),
+                    "rect must fit within display 1 bounds (1920x1080)",
+                ),
+                (
+                    json!({ "rect": { "x": -1, "y": 0, "width": 100, "height": 100 } }),
+                    "rect origin must not be negative",
+                ),
+                (
+                    json!({ "rect": { "x": 0, "y": 0, "width": 0, "height": 100 } }),
+                    "rect width and height must be at least 1 pixel",
+                ),
+            ] {
+                let response = handle_request(
+                    "linux",
+                    state,
+                    &request("region-invalid", EngineMethod::CaptureStartRegion, params),
+                );
+                assert_eq!(
+                    expect_error(response, ProtocolErrorCode::InvalidParams),
+                    expected
+                );
+            }
+            assert!(!state.is_running);
+        });
+    }
+
+    #[test]
+    fn capture_rejects_unsupported_high_frame_rate() {
+        with_state("capture-unsupported-high-fps", |state, _| {
+            let response = handle_request(
+                "linux",
+                state,
+                &request(
+                    "r8",
+                    EngineMethod::CaptureStartDisplay,
+                    json!({ "captureFps": 120 }),
+                ),
+            );
+            let message = expect_error(response, ProtocolErrorCode::InvalidParams);
+            assert!(message.contains("Supported values: 24, 30, 60"));
+        });
+    }
+
+    #[test]
+    fn export_run_requires_output_url() {
+        with_state("export-run-missing-output", |state, _| {
+            let response = handle_request(
+                "linux",
+                state,
+                &request("r9", EngineMethod::ExportRun, json!({})),
+            );
+            let message = expect_error(response, ProtocolErrorCode::InvalidParams);
+            assert_eq!(message, "outputURL is required");
+        });
+    }
+
+    #[test]
+    fn export_run_writes_output_file() {
+        with_state("export-run-write-file", |state, root| {
+            let output_url = root.join("exports").join("result.mp4");
+            let response = handle_request(
+                "linux",
+                state,
+                &request(
+                    "r10",
+                    EngineMethod::ExportRun,
+                    json!({ "outputURL": output_url.to_string_lossy() }),
+                ),
+            );
+            let result = expect_success(response);
+            assert_eq!(result["outputURL"], json!(output_url.to_string_lossy()));
+            assert!(output_url.exists(), "expected export output file to exist");
+            let content = fs::read(output_url).expect("read export output");
+            assert_eq!(content, b"guerillaglass-native-export");
+        });
+    }
+
+    #[test]
+    fn export_run_resolves_background_framing_override_then_persisted_settings() {
+        with_state("export-run-background-framing", |state, root| {
+            let project_path = root.join("framing.gglassproj");
+            let save = handle_request(
+                "linux",
+                state,
+                &request(
+                    "framing-save",
+                    EngineMethod::ProjectSave,
+                    json!({
+                        "projectPath": project_path.to_string_lossy(),
+                        "backgroundFraming": {
+                            "version": 1,
+                            "enabled": true,
+                            "backgroundColor": "#112233",
+                            "paddingFraction": 0.1,
+                            "cornerRadiusFraction": 0.04,
+                            "shadowStrength": 0.5
+                        }
+                    }),
+                ),
+            );
+            expect_success(save);
+
+            let override_output = root.join("override.mp4");
+            let override_export = handle_request(
+                "linux",
+                state,
+                &request(
+                    "framing-override",
+                    EngineMethod::ExportRun,
+                    json!({
+                        "outputURL": override_output.to_string_lossy(),
+                        "backgroundFraming": {
+                            "version": 1,
+                            "enabled": true,
+                            "backgroundColor": "#abcdef",
+                            "paddingFraction": 0.2,
+                            "cornerRadiusFraction": 0.08,
+                            "shadowStrength": 0.8
+                        }
+                    }),
+                ),
+            );
+            expect_success(override_export);
+            let resolved_override = state
+                .latest_export_background_framing
+                .as_ref()
+                .expect("resolved override");
+            assert_eq!(resolved_override.background_color, "#ABCDEF");
+            assert_eq!(resolved_override.padding_fraction, 0.2);
+
+            let persisted_output = root.join("persisted.mp4");
+            let persisted_export = handle_request(
+                "linux",
+                state,
+                &request(
+                    "framing-persisted",
+                    EngineMethod::ExportRun,
+                    json!({ "outputURL": persisted_output.to_string_lossy() }),
+                ),
             );
-            let first_capture_result = expect_success(first_capture);
+            expect_success(persisted_export);
+            let resolved_persisted = state
+                .latest_export_background_framing
+                .as_ref()
+                .expect("resolved persisted settings");
+            assert_eq!(resolved_persisted.background_color, "#112233");
+            assert_eq!(resolved_persisted.padding_fraction, 0.1);
+        });
+    }
+
+    #[test]
+    fn shortcut_overlay_settings_persist_and_drive_export_badges() {
+        with_state("shortcut-overlay", |state, root| {
+            let project_path = root.join("shortcuts.gglassproj");
+            let saved = expect_success(handle_request(
+                "linux",
+                state,
+                &request(
+                    "overlay-save",
+                    EngineMethod::ProjectSave,
+                    json!({
+                        "projectPath": project_path.to_string_lossy(),
+                        "shortcutOverlay": {
+                            "version": 1,
+                            "enabled": true,
+                            "displayDurationSeconds": 2.0,
+                            "position": "topRight",
+                            "backgroundColor": "#000000",
+                            "textColor": "#ffcc00",
+                            "backgroundOpacity": 0.6,
+                            "fontScale": 1.5
+                        }
+                    }),
+                ),
+            ));
+            assert_eq!(saved["shortcutOverlay"]["textColor"], "#FFCC00");
+
+            state.shortcut_overlay = Default::default();
+            let opened = expect_success(handle_request(
+                "linux",
+                state,
+                &request(
+                    "overlay-open",
+                    EngineMethod::ProjectOpen,
+                    json!({ "projectPath": project_path.to_string_lossy() }),
+                ),
+            ));
+            assert_eq!(opened["shortcutOverlay"]["position"], "topRight");
+            assert_eq!(opened["shortcutOverlay"]["displayDurationSeconds"], 2.0);
+
+            let rejected = handle_request(
+                "linux",
+                state,
+                &request(
+                    "overlay-invalid",
+                    EngineMethod::ProjectSave,
+                    json!({
+                        "shortcutOverlay": {
+                            "version": 1,
+                            "enabled": true,
+                            "displayDurationSeconds": 30.0,
+                            "position": "bottomCenter",
+                            "backgroundColor": "#000000",
+                            "textColor": "#FFFFFF",
+                            "backgroundOpacity": 0.6,
+                            "fontScale": 1.0
+                        }
+                    }),
+                ),
+            );
+            let message = expect_error(rejected, ProtocolErrorCode::InvalidParams);
+            assert!(message.contains("displayDurationSeconds"));
+
+            let events_path = root.join("events.json");
+            fs::write(
+                &events_path,
+                json!({
+                    "schemaVersion": 1,
+                    "events": [],
+                    "shortcuts": [
+                        { "timestamp": 1.0, "keys": ["Ctrl", "Shift", "P"] },
+                        { "timestamp": 2.5, "keys": ["Ctrl", "S"] }
+                    ]
+                })
+                .to_string(),
+            )
+            .expect("write events log");
+            state.events_url = Some(events_path.to_string_lossy().into_owned());
+
+            let output = root.join("shortcuts.mp4");
+            expect_success(handle_request(
+                "linux",
+                state,
+                &request(
+                    "overlay-export",
+                    EngineMethod::ExportRun,
+                    json!({ "outputURL": output.to_string_lossy() }),
+                ),
+            ));
+            let badges = state
+                .latest_export_shortcut_badges
+                .as_ref()
+                .expect("planned badges")
+                .iter()
+                .map(|badge| (badge.label.as_str(), badge.start_seconds, badge.end_seconds))
+                .collect::<Vec<_>>();
             assert_eq!(
-                first_capture_result["captureSessionId"],
-                json!("capture-session-1")
+                badges,
+                vec![("Ctrl+Shift+P", 1.0, 2.5), ("Ctrl+S", 2.5, 4.5)]
             );
+        });
+    }
 
-            let _ = handle_request(
+    #[test]
+    fn cursor_rendering_settings_persist_and_redraw_the_cursor_on_export() {
+        with_state("cursor-rendering", |state, root| {
+            let project_path = root.join("cursor.gglassproj");
+            let mut cursor_rendering = json!({
+                "version": 1.0,
+                "enabled": true,
+                "smoothing": 0.8,
+                "scale": 1.5,
+                "hideAfterIdleSeconds": 1.0
+            });
+            expect_success(handle_request(
                 "linux",
                 state,
-                &request("r4", EngineMethod::CaptureStop, json!({})),
+                &request(
+                    "cursor-save",
+                    EngineMethod::ProjectSave,
+                    json!({
+                        "projectPath": project_path.to_string_lossy(),
+                        "cursorRendering": cursor_rendering,
+                    }),
+                ),
+            ));
+            state.cursor_rendering = Default::default();
+            let opened = expect_success(handle_request(
+                "linux",
+                state,
+                &request(
+                    "cursor-open",
+                    EngineMethod::ProjectOpen,
+                    json!({ "projectPath": project_path.to_string_lossy() }),
+                ),
+            ));
+            assert_eq!(opened["cursorRendering"], cursor_rendering);
+
+            let mut oversized = cursor_rendering.clone();
+            oversized["scale"] = json!(9.0);
+            let message = expect_error(
+                handle_request(
+                    "linux",
+                    state,
+                    &request(
+                        "cursor-invalid",
+                        EngineMethod::ProjectSave,
+                        json!({ "cursorRendering": oversized }),
+                    ),
+                ),
+                ProtocolErrorCode::InvalidParams,
+            );
+            assert!(message.contains("cursorRendering.scale"));
+
+            state.events_url = Some(
+                Path::new(env!("CARGO_MANIFEST_DIR"))
+                    .join("../../docs/fixtures/cursor/jittery-pointer.json")
+                    .to_string_lossy()
+                    .into_owned(),
+            );
+            state.capture_metadata = Some(json!({
+                "source": "region",
+                "contentRect": { "x": 100, "y": 50, "width": 1000, "height": 600 },
+            }));
+            let output = root.join("cursor.mp4");
+            let export = |state: &mut State, id: &str, params: Value| {
+                expect_success(handle_request(
+                    "linux",
+                    state,
+                    &request(id, EngineMethod::ExportRun, params),
+                ))
+            };
+            export(
+                state,
+                "cursor-export",
+                json!({ "outputURL": output.to_string_lossy() }),
+            );
+            let cursor = state.latest_export_cursor.as_ref().expect("redrawn cursor");
+            assert_eq!(cursor.len(), 135);
+            let click = cursor[30];
+            assert_eq!((click.x, click.y, click.scale), (400.0, 250.0, 1.5));
+            assert_eq!(cursor[99].opacity, 0.0);
+            let manifest: Value = serde_json::from_slice(
+                &fs::read(root.join("cursor.manifest.json")).expect("read manifest"),
+            )
+            .expect("decode manifest");
+            assert_eq!(manifest["cursorRendering"], cursor_rendering);
+
+            cursor_rendering["enabled"] = json!(false);
+            export(
+                state,
+                "cursor-export-off",
+                json!({
+                    "outputURL": output.to_string_lossy(),
+                    "cursorRendering": cursor_rendering,
+                }),
+            );
+            assert!(state.latest_export_cursor.is_none());
+            assert!(state.cursor_rendering.enabled);
+        });
+    }
+
+    #[test]
+    fn click_effects_persist_and_export_per_clip_ripples_spotlight_and_click_sounds() {
+        with_state("click-effects", |state, root| {
+            let events_url = Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("../../docs/fixtures/cursor/jittery-pointer.json")
+                .to_string_lossy()
+                .into_owned();
+            state.takes = vec![serde_json::from_value(json!({
+                "id": "take-1",
+                "name": "Take 1",
+                "recordingURL": root.join("take-1.mov").to_string_lossy(),
+                "eventsURL": events_url,
+                "captureMetadata": {
+                    "source": "region",
+                    "contentRect": { "x": 100, "y": 50, "width": 1000, "height": 600 },
+                },
+                "durationSeconds": 5.0,
+                "createdAt": "2026-01-01T00:00:00Z",
+            }))
+            .expect("take decodes")];
+            state.active_take_id = Some("take-1".to_string());
+            state.events_url = Some(events_url);
+
+            let project_path = root.join("clicks.gglassproj");
+            let click_effects = json!({
+                "version": 1.0,
+                "ripple": {
+                    "enabled": true,
+                    "color": "#22C55E",
+                    "maxRadius": 48.0,
+                    "durationSeconds": 0.5
+                },
+                "spotlight": {
+                    "enabled": false,
+                    "radius": 200.0,
+                    "feather": 40.0,
+                    "dimOpacity": 0.6
+                },
+                "clickSound": { "enabled": true, "volume": 0.8 },
+                "segments": [
+                    { "clipId": "intro", "clickSound": false },
+                    { "clipId": "outro", "spotlight": true }
+                ]
+            });
+            expect_success(handle_request(
+                "linux",
+                state,
+                &request(
+                    "clicks-save",
+                    EngineMethod::ProjectSave,
+                    json!({
+                        "projectPath": project_path.to_string_lossy(),
+                        "clickEffects": click_effects,
+                        "timeline": {
+                            "version": 2.0,
+                            "items": [
+                                {
+                                    "kind": "clip",
+                                    "id": "intro",
+                                    "sourceAssetId": "recording",
+                                    "sourceStartSeconds": 0.0,
+                                    "sourceEndSeconds": 2.0
+                                },
+                                {
+                                    "kind": "clip",
+                                    "id": "outro",
+                                    "sourceAssetId": "recording",
+                                    "sourceStartSeconds": 4.0,
+                                    "sourceEndSeconds": 5.0
+                                }
+                            ]
+                        },
+                    }),
+                ),
+            ));
+            state.click_effects = Default::default();
+            let opened = expect_success(handle_request(
+                "linux",
+                state,
+                &request(
+                    "clicks-open",
+                    EngineMethod::ProjectOpen,
+                    json!({ "projectPath": project_path.to_string_lossy() }),
+                ),
+            ));
+            assert_eq!(opened["clickEffects"], click_effects);
+
+            let mut duplicated = click_effects.clone();
+            duplicated["segments"][1]["clipId"] = json!("intro");
+            let message = expect_error(
+                handle_request(
+                    "linux",
+                    state,
+                    &request(
+                        "clicks-invalid",
+                        EngineMethod::ProjectSave,
+                        json!({ "clickEffects": duplicated }),
+                    ),
+                ),
+                ProtocolErrorCode::InvalidParams,
+            );
+            assert!(message.contains("clickEffects.segments"));
+
+            let output = root.join("clicks.mp4");
+            expect_success(handle_request(
+                "linux",
+                state,
+                &request(
+                    "clicks-export",
+                    EngineMethod::ExportRun,
+                    json!({ "outputURL": output.to_string_lossy() }),
+                ),
+            ));
+            let effects = state
+                .latest_export_click_effects
+                .as_ref()
+                .expect("click effects");
+            assert_eq!(effects.ripples.len(), 2);
+            assert_eq!((effects.ripples[0].x, effects.ripples[0].y), (400.0, 250.0));
+            assert_eq!(effects.click_sounds.len(), 1);
+            assert_eq!(effects.click_sounds[0].clip_id.as_deref(), Some("outro"));
+            assert_eq!(effects.click_sounds[0].time, 2.5);
+            assert_eq!(effects.spotlight.len(), 30);
+            assert_eq!(effects.spotlight[0].time, 2.0);
+
+            let manifest: Value = serde_json::from_slice(
+                &fs::read(root.join("clicks.manifest.json")).expect("read manifest"),
+            )
+            .expect("decode manifest");
+            assert_eq!(manifest["clickEffects"], click_effects);
+            assert_eq!(manifest["clickRipples"][1]["clipId"], "outro");
+            assert_eq!(manifest["spotlight"][0]["clipId"], "outro");
+            let click_track = root.join("clicks.clicks.wav");
+            assert_eq!(
+                manifest["clickSoundURL"],
+                json!(click_track.to_string_lossy())
+            );
+            let wav = fs::read(&click_track).expect("read click track");
+            assert_eq!(&wav[0..4], b"RIFF");
+            // The outro plays from 2 s in the program, so its 4.5 s click lands after 2.5 s of
+            // silence, then one 30 ms click, as 16-bit stereo.
+            assert_eq!(wav.len(), 44 + (120_000 + 1_440) * 4);
+
+            let mut muted = click_effects.clone();
+            muted["clickSound"]["enabled"] = json!(false);
+            muted["ripple"]["enabled"] = json!(false);
+            muted["segments"] = json!([]);
+            expect_success(handle_request(
+                "linux",
+                state,
+                &request(
+                    "clicks-export-off",
+                    EngineMethod::ExportRun,
+                    json!({
+                        "outputURL": root.join("plain.mp4").to_string_lossy(),
+                        "clickEffects": muted,
+                    }),
+                ),
+            ));
+            assert!(state.latest_export_click_effects.is_none());
+            assert!(!root.join("plain.clicks.wav").exists());
+        });
+    }
+
+    #[test]
+    fn idle_stretches_become_reviewable_cuts_that_apply_to_the_timeline() {
+        with_state("idle-cuts", |state, root| {
+            // 20 s at 10 fps whose picture changes once, at 10 s.
+            let recording = root.join("take-1.y4m");
+            let mut stream = b"YUV4MPEG2 W2 H1 F10:1 Ip A1:1 C444\n".to_vec();
+            for frame in 0..200 {
+                stream.extend_from_slice(b"FRAME\n");
+                stream.extend_from_slice(&[if frame < 100 { 16 } else { 235 }; 2]);
+                stream.extend_from_slice(&[128; 4]);
+            }
+            fs::write(&recording, stream).expect("write recording");
+            let events = root.join("take-1.events.json");
+            let moved = |timestamp: f64| json!({ "type": "cursorMoved", "timestamp": timestamp, "position": { "x": 1, "y": 1 } });
+            fs::write(
+                &events,
+                json!({
+                    "schemaVersion": 1,
+                    "events": [moved(1.0), moved(2.0), moved(14.0)],
+                })
+                .to_string(),
+            )
+            .expect("write events");
+            state.takes = vec![serde_json::from_value(json!({
+                "id": "take-1",
+                "name": "Take 1",
+                "recordingURL": recording.to_string_lossy(),
+                "eventsURL": events.to_string_lossy(),
+                "durationSeconds": 20.0,
+                "createdAt": "2026-01-01T00:00:00Z",
+            }))
+            .expect("take decodes")];
+            state.active_take_id = Some("take-1".to_string());
+            state.events_url = Some(events.to_string_lossy().into_owned());
+
+            let message = expect_error(
+                handle_request(
+                    "linux",
+                    state,
+                    &request(
+                        "idle-invalid",
+                        EngineMethod::TimelineSuggestCuts,
+                        json!({ "minIdleSeconds": 0.1 }),
+                    ),
+                ),
+                ProtocolErrorCode::InvalidParams,
+            );
+            assert!(message.contains("minIdleSeconds"));
+
+            let suggested = expect_success(handle_request(
+                "linux",
+                state,
+                &request("idle-suggest", EngineMethod::TimelineSuggestCuts, json!({})),
+            ));
+            assert_eq!(
+                suggested["signals"],
+                json!({ "input": true, "frames": true })
+            );
+            assert_eq!(suggested["idleSeconds"], 12.0);
+            let ranges = suggested["suggestions"]
+                .as_array()
+                .expect("suggestions")
+                .iter()
+                .map(|cut| {
+                    (
+                        cut["id"].clone(),
+                        cut["startSeconds"].clone(),
+                        cut["endSeconds"].clone(),
+                        cut["operation"].clone(),
+                    )
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(
+                ranges,
+                vec![
+                    (
+                        json!("idle-1"),
+                        json!(2.5),
+                        json!(9.5),
+                        json!("rippleDelete")
+                    ),
+                    (
+                        json!("idle-2"),
+                        json!(14.5),
+                        json!(19.5),
+                        json!("rippleDelete")
+                    ),
+                ]
+            );
+            assert!(state.timeline_items.is_empty());
+
+            let apply = |state: &mut State, id: &str, params: Value| {
+                handle_request(
+                    "linux",
+                    state,
+                    &request(id, EngineMethod::TimelineApplyCuts, params),
+                )
+            };
+            let message = expect_error(
+                apply(
+                    state,
+                    "idle-unknown",
+                    json!({ "suggestionIds": ["idle-9"] }),
+                ),
+                ProtocolErrorCode::InvalidParams,
+            );
+            assert!(message.contains("idle-9"));
+            assert!(state.timeline_items.is_empty());
+
+            let rippled = expect_success(apply(
+                state,
+                "idle-ripple",
+                json!({ "suggestionIds": ["idle-2"] }),
+            ));
+            assert_eq!(rippled["appliedSuggestionIds"], json!(["idle-2"]));
+            assert_eq!(rippled["suggestions"].as_array().map(Vec::len), Some(1));
+            let lifted = expect_success(apply(
+                state,
+                "idle-lift",
+                json!({ "suggestionIds": ["idle-1"], "operation": "lift" }),
+            ));
+            assert_eq!(
+                lifted["timeline"]["items"],
+                json!([
+                    { "kind": "clip", "id": "clip-1", "sourceAssetId": "recording",
+                      "sourceStartSeconds": 0.0, "sourceEndSeconds": 2.5 },
+                    { "kind": "gap", "id": "clip-1-gap", "durationSeconds": 7.0 },
+                    { "kind": "clip", "id": "clip-1-3", "sourceAssetId": "recording",
+                      "sourceStartSeconds": 9.5, "sourceEndSeconds": 14.5 },
+                    { "kind": "clip", "id": "clip-1-2", "sourceAssetId": "recording",
+                      "sourceStartSeconds": 19.5, "sourceEndSeconds": 20.0 },
+                ])
+            );
+            assert!(state.suggested_cuts.is_empty());
+            assert!(state.unsaved_changes);
+        });
+    }
+
+    #[test]
+    fn visual_analysis_finds_scenes_and_feeds_chapters_thumbnails_and_idle_cuts() {
+        with_state("visual-analysis", |state, root| {
+            // 13 s at 10 fps with a bright scene from 4 s to 7 s.
+            let recording = root.join("take-1.y4m");
+            let mut stream = b"YUV4MPEG2 W2 H1 F10:1 Ip A1:1 C444\n".to_vec();
+            for frame in 0..130 {
+                stream.extend_from_slice(b"FRAME\n");
+                stream.extend_from_slice(&[if (40..70).contains(&frame) { 235 } else { 16 }; 2]);
+                stream.extend_from_slice(&[128; 4]);
+            }
+            fs::write(&recording, stream).expect("write recording");
+            state.takes = vec![
+                serde_json::from_value(json!({
+                    "id": "take-1",
+                    "name": "Take 1",
+                    "recordingURL": recording.to_string_lossy(),
+                    "durationSeconds": 13.0,
+                    "createdAt": "2026-01-01T00:00:00Z",
+                }))
+                .expect("take decodes"),
+                serde_json::from_value(json!({
+                    "id": "take-2",
+                    "name": "Take 2",
+                    "recordingURL": "native://recordings/take-2.y4m",
+                    "captureMetadata": {
+                        "source": "display",
+                        "contentRect": { "x": 0, "y": 0, "width": 640, "height": 360 },
+                    },
+                    "durationSeconds": 1.0,
+                    "createdAt": "2026-01-01T00:00:01Z",
+                }))
+                .expect("take decodes"),
+            ];
+            state.active_take_id = Some("take-1".to_string());
+            let analyze = |state: &mut State, id: &str, params: Value| {
+                handle_request(
+                    "linux",
+                    state,
+                    &request(id, EngineMethod::AnalysisVisualActivity, params),
+                )
+            };
+
+            let message = expect_error(
+                analyze(state, "va1", json!({ "sceneThreshold": 0.0 })),
+                ProtocolErrorCode::InvalidParams,
+            );
+            assert!(message.contains("sceneThreshold"));
+
+            let analysis = expect_success(analyze(state, "va2", json!({})));
+            assert_eq!(analysis["takeId"], "take-1");
+            assert_eq!(analysis["source"], "recording");
+            assert_eq!(analysis["fps"], 10.0);
+            assert_eq!(analysis["durationSeconds"], 13.0);
+            assert_eq!(
+                analysis["frameDifferences"].as_array().map(Vec::len),
+                Some(130)
+            );
+            assert_eq!(
+                analysis["sceneChanges"],
+                json!([
+                    { "timeSeconds": 4.0, "score": 219.0 / 255.0 },
+                    { "timeSeconds": 7.0, "score": 219.0 / 255.0 }
+                ])
+            );
+            let chapters = analysis["chapters"]
+                .as_array()
+                .expect("chapters")
+                .iter()
+                .map(|chapter| {
+                    (
+                        chapter["startSeconds"].as_f64().unwrap_or_default(),
+                        chapter["endSeconds"].as_f64().unwrap_or_default(),
+                        chapter["thumbnailSeconds"].as_f64().unwrap_or_default(),
+                    )
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(
+                chapters,
+                vec![(0.0, 4.0, 2.0), (4.0, 7.0, 5.5), (7.0, 13.0, 10.0)]
+            );
+            assert_eq!(analysis["chapters"][1]["title"], "Chapter 2");
+            assert_eq!(analysis["thumbnailSeconds"], 10.0);
+            assert!(state.unsaved_changes);
+
+            // Idle detection reads the stored analysis, so the recording is not decoded again.
+            fs::write(&recording, b"guerillaglass-native-recording").expect("replace recording");
+            let suggested = expect_success(handle_request(
+                "linux",
+                state,
+                &request("va3", EngineMethod::TimelineSuggestCuts, json!({})),
+            ));
+            assert_eq!(
+                suggested["signals"],
+                json!({ "input": false, "frames": true })
+            );
+            assert_eq!(suggested["suggestions"][0]["startSeconds"], 7.5);
+            assert_eq!(suggested["suggestions"][0]["endSeconds"], 12.5);
+
+            // A recording that cannot be decoded is an error, not a synthetic stand-in.
+            let message = expect_error(
+                analyze(state, "va3-corrupt", json!({})),
+                ProtocolErrorCode::RuntimeError,
+            );
+            assert!(message.contains("Unable to analyze recording frames"));
+
+            state.active_take_id = Some("take-2".to_string());
+            let synthetic = expect_success(analyze(state, "va4", json!({})));
+            assert_eq!(synthetic["source"], "synthetic");
+            assert_eq!(synthetic["fps"], 30.0);
+            assert_eq!(
+                synthetic["frameDifferences"].as_array().map(Vec::len),
+                Some(30)
+            );
+            assert_eq!(synthetic["sceneChanges"], json!([]));
+            assert_eq!(synthetic["chapters"].as_array().map(Vec::len), Some(1));
+            assert_eq!(state.visual_analyses.len(), 2);
+
+            let project_path = root.join("analysis.gglassproj");
+            let saved = expect_success(handle_request(
+                "linux",
+                state,
+                &request(
+                    "va5",
+                    EngineMethod::ProjectSave,
+                    json!({ "projectPath": project_path.to_string_lossy() }),
+                ),
+            ));
+            assert_eq!(saved["visualAnalyses"][0]["thumbnailSeconds"], 10.0);
+            let stored = state.visual_analyses.clone();
+            state.visual_analyses.clear();
+            expect_success(handle_request(
+                "linux",
+                state,
+                &request(
+                    "va6",
+                    EngineMethod::ProjectOpen,
+                    json!({ "projectPath": project_path.to_string_lossy() }),
+                ),
+            ));
+            assert_eq!(state.visual_analyses.len(), 2);
+            assert_eq!(state.visual_analyses[0], stored[0]);
+
+            expect_success(handle_request(
+                "linux",
+                state,
+                &request(
+                    "va7",
+                    EngineMethod::TakesDelete,
+                    json!({ "takeId": "take-2", "destructiveIntent": true }),
+                ),
+            ));
+            assert_eq!(state.visual_analyses.len(), 1);
+            assert_eq!(state.visual_analyses[0].take_id, "take-1");
+        });
+    }
+
+    #[test]
+    fn clip_playback_rates_retime_the_program_and_its_overlays_on_export() {
+        with_state("speed-ramps", |state, root| {
+            let events = root.join("take-1.events.json");
+            fs::write(
+                &events,
+                json!({
+                    "schemaVersion": 1,
+                    "events": [],
+                    "shortcuts": [
+                        { "timestamp": 1.0, "keys": ["Ctrl", "S"] },
+                        { "timestamp": 12.0, "keys": ["Ctrl", "P"] },
+                    ],
+                })
+                .to_string(),
+            )
+            .expect("write events");
+            state.takes = vec![serde_json::from_value(json!({
+                "id": "take-1",
+                "name": "Take 1",
+                "recordingURL": root.join("take-1.mov").to_string_lossy(),
+                "eventsURL": events.to_string_lossy(),
+                "durationSeconds": 20.0,
+                "createdAt": "2026-01-01T00:00:00Z",
+            }))
+            .expect("take decodes")];
+            state.active_take_id = Some("take-1".to_string());
+            state.events_url = Some(events.to_string_lossy().into_owned());
+
+            let clip = |id: &str, start: f64, end: f64| {
+                json!({
+                    "kind": "clip",
+                    "id": id,
+                    "sourceAssetId": "recording",
+                    "sourceStartSeconds": start,
+                    "sourceEndSeconds": end,
+                })
+            };
+            let mut typing = clip("typing", 16.0, 20.0);
+            typing["playbackRate"] = json!(8.0);
+            typing["rampInSeconds"] = json!(1.0);
+            typing["rampOutSeconds"] = json!(1.0);
+            let mut build = clip("build", 4.0, 16.0);
+            build["playbackRate"] = json!(4.0);
+            let items = json!([
+                clip("intro", 0.0, 4.0),
+                { "kind": "gap", "id": "pause", "durationSeconds": 1.0 },
+                build,
+                typing,
+            ]);
+            let save = |state: &mut State, id: &str, items: &Value| {
+                handle_request(
+                    "linux",
+                    state,
+                    &request(
+                        id,
+                        EngineMethod::ProjectSave,
+                        json!({ "timeline": { "version": 2.0, "items": items } }),
+                    ),
+                )
+            };
+            let saved = expect_success(save(state, "speed-save", &items));
+            assert_eq!(saved["timeline"]["items"], items);
+
+            for (field, value, expected) in [
+                ("playbackRate", json!(20.0), "playbackRate"),
+                ("playbackRate", json!(0.1), "playbackRate"),
+                ("rampInSeconds", json!(3.5), "ramps"),
+            ] {
+                let mut invalid = items.clone();
+                invalid[3][field] = value;
+                let message = expect_error(
+                    save(state, "speed-invalid", &invalid),
+                    ProtocolErrorCode::InvalidParams,
+                );
+                assert!(message.contains(expected), "{message}");
+            }
+
+            let output = root.join("speed.mp4");
+            expect_success(handle_request(
+                "linux",
+                state,
+                &request(
+                    "speed-export",
+                    EngineMethod::ExportRun,
+                    json!({ "outputURL": output.to_string_lossy() }),
+                ),
+            ));
+            let manifest: Value = serde_json::from_slice(
+                &fs::read(root.join("speed.manifest.json")).expect("read manifest"),
+            )
+            .expect("decode manifest");
+            let timeline = manifest["timeline"].as_array().expect("timeline");
+            let placement = timeline
+                .iter()
+                .map(|clip| {
+                    (
+                        clip["clipId"].as_str().unwrap_or_default(),
+                        clip["programStartSeconds"].as_f64().unwrap_or_default(),
+                        clip["audio"].as_str().unwrap_or_default(),
+                    )
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(
+                placement,
+                vec![
+                    ("intro", 0.0, "normal"),
+                    ("build", 5.0, "pitchPreserved"),
+                    ("typing", 8.0, "muted"),
+                ]
+            );
+            // Two one-second ramps between real time and 8x, plus two seconds at 8x.
+            let ramps = 2.0 * 8.0_f64.ln() / 7.0;
+            let typing_end = timeline[2]["programEndSeconds"].as_f64().expect("end");
+            assert!((typing_end - (8.25 + ramps)).abs() < 1e-9);
+            let duration = manifest["programDurationSeconds"]
+                .as_f64()
+                .expect("duration");
+            assert!((duration - typing_end).abs() < 1e-9);
+
+            let frames = state
+                .latest_export_program_frames
+                .as_ref()
+                .expect("program frames");
+            // 4 s of intro at 30 fps, then the gap shows nothing until the build clip at 5 s,
+            // which plays four source seconds for every second of program.
+            assert_eq!(frames[119].source_time, 119.0 / 30.0);
+            assert_eq!(
+                (frames[120].clip_id.as_str(), frames[120].program_time),
+                ("build", 5.0)
             );
+            assert!((frames[121].source_time - (4.0 + 4.0 / 30.0)).abs() < 1e-9);
 
-            let second_capture = handle_request(
-                "linux",
-                state,
-                &request("r5", EngineMethod::CaptureStartDisplay, json!({})),
-            );
-            let second_capture_result = expect_success(second_capture);
+            let badges = manifest["shortcutBadges"]
+                .as_array()
+                .expect("badges")
+                .iter()
+                .map(|badge| {
+                    (
+                        badge["label"].clone(),
+                        badge["startSeconds"].clone(),
+                        badge["endSeconds"].clone(),
+                    )
+                })
+                .collect::<Vec<_>>();
             assert_eq!(
-                second_capture_result["captureSessionId"],
-                json!("capture-session-2")
+                badges,
+                vec![
+                    (json!("Ctrl+S"), json!(1.0), json!(2.5)),
+                    (json!("Ctrl+P"), json!(7.0), json!(7.375)),
+                ]
             );
         });
     }
 
     #[test]
-    fn sources_list_includes_pixel_scale_for_displays_and_windows() {
-        with_state("sources-list-pixel-scale", |state, _| {
-            let response = handle_request(
-                "linux",
-                state,
-                &request("r3a", EngineMethod::SourcesList, json!({})),
-            );
-            let result = expect_success(response);
-            let displays = result["displays"].as_array().expect("displays");
-            let windows = result["windows"].as_array().expect("windows");
+    fn auto_zoom_settings_plan_export_camera_keyframes_from_input_events() {
+        with_state("auto-zoom-export", |state, root| {
+            let project_path = root.join("auto-zoom.gglassproj");
+            let save_auto_zoom = |state: &mut State, id: &str, is_enabled: bool| {
+                expect_success(handle_request(
+                    "linux",
+                    state,
+                    &request(
+                        id,
+                        EngineMethod::ProjectSave,
+                        json!({
+                            "projectPath": project_path.to_string_lossy(),
+                            "autoZoom": {
+                                "isEnabled": is_enabled,
+                                "intensity": 1.0,
+                                "minimumKeyframeInterval": 0.05
+                            }
+                        }),
+                    ),
+                ))
+            };
+            save_auto_zoom(state, "z1", true);
 
-            assert_eq!(displays[0]["pixelScale"], json!(1.0));
-            assert_eq!(windows[0]["pixelScale"], json!(1.0));
+            let events_path = root.join("events.json");
+            fs::write(
+                &events_path,
+                json!({
+                    "schemaVersion": 1,
+                    "events": [
+                        { "type": "cursorMoved", "timestamp": 0.2, "position": { "x": 400, "y": 300 } },
+                        { "type": "mouseDown", "timestamp": 0.5, "position": { "x": 960, "y": 540 }, "button": "left" },
+                        { "type": "cursorMoved", "timestamp": 2.0, "position": { "x": 1500, "y": 800 } }
+                    ]
+                })
+                .to_string(),
+            )
+            .expect("write events log");
+            state.events_url = Some(events_path.to_string_lossy().into_owned());
+            state.capture_metadata = Some(json!({
+                "source": "region",
+                "contentRect": { "x": 320, "y": 180, "width": 1280, "height": 720 },
+            }));
+
+            let output = root.join("auto-zoom.mp4");
+            let export = |state: &mut State, id: &str| {
+                expect_success(handle_request(
+                    "linux",
+                    state,
+                    &request(
+                        id,
+                        EngineMethod::ExportRun,
+                        json!({ "outputURL": output.to_string_lossy() }),
+                    ),
+                ))
+            };
+            export(state, "z2");
+            let applied = state
+                .latest_export_camera_plan
+                .as_ref()
+                .expect("auto-zoom camera plan");
+            assert_eq!(applied.source, CameraPlanSource::AutoZoom);
+            let plan = &applied.plan;
+            assert_eq!(plan.source_size.width, 1280.0);
+            assert_eq!(plan.source_size.height, 720.0);
+            assert_eq!(plan.duration, 2.0);
+            let first = plan.keyframes.first().expect("first keyframe");
+            let last = plan.keyframes.last().expect("last keyframe");
+            assert_eq!((first.time, last.time), (0.0, 2.0));
+            let click = plan
+                .keyframes
+                .iter()
+                .find(|keyframe| keyframe.time == 0.5)
+                .expect("click keyframe");
+            assert_eq!(click.zoom, 2.5);
+            assert!(plan
+                .keyframes
+                .iter()
+                .all(|keyframe| keyframe.zoom >= 1.0 && keyframe.zoom <= 2.5));
+
+            save_auto_zoom(state, "z3", false);
+            export(state, "z4");
+            assert!(state.latest_export_camera_plan.is_none());
         });
     }
 
     #[test]
-    fn capture_start_window_uses_default_window_id_when_missing() {
-        with_state("capture-window-default-id", |state, _| {
-            let response = handle_request(
-                "linux",
+    fn export_applies_payload_camera_keyframes_and_records_them_in_the_manifest() {
+        with_state("camera-plan-export", |state, root| {
+            state.auto_zoom_enabled = true;
+            let output = root.join("camera-plan.mp4");
+            let export = |state: &mut State, id: &str, extra: Value| {
+                let mut params = json!({ "outputURL": output.to_string_lossy() });
+                params
+                    .as_object_mut()
+                    .expect("export params")
+                    .extend(extra.as_object().expect("extra params").clone());
+                handle_request(
+                    "linux",
+                    state,
+                    &request(id, EngineMethod::ExportRun, params),
+                )
+            };
+
+            let result = expect_success(export(
                 state,
-                &request("r7", EngineMethod::CaptureStartWindow, json!({})),
+                "c1",
+                json!({
+                    "cameraPlan": {
+                        "easing": "linear",
+                        "keyframes": [
+                            { "time": 0.0, "center": { "x": 960, "y": 540 }, "zoom": 1.0 },
+                            { "time": 2.0, "center": { "x": 1200, "y": 600 }, "zoom": 2.0 }
+                        ]
+                    }
+                }),
+            ));
+            let applied = state
+                .latest_export_camera_plan
+                .as_ref()
+                .expect("payload camera plan");
+            assert_eq!(applied.source, CameraPlanSource::Payload);
+            assert_eq!(applied.plan.duration, 2.0);
+            let crops = state
+                .latest_export_camera_crops
+                .as_ref()
+                .expect("camera crops");
+            assert_eq!(crops.len(), 60);
+            assert_eq!(crops[30].time, 1.0);
+            assert_eq!(crops[30].width, 1920.0 / 1.5);
+
+            let manifest_url = result["manifestURL"].as_str().expect("manifest url");
+            assert_eq!(
+                Path::new(manifest_url),
+                root.join("camera-plan.manifest.json")
             );
-            let result = expect_success(response);
-            assert_eq!(result["captureMetadata"]["window"]["id"], json!(101));
-            assert_eq!(result["captureMetadata"]["source"], json!("window"));
-        });
-    }
+            let manifest: Value =
+                serde_json::from_slice(&fs::read(manifest_url).expect("read manifest"))
+                    .expect("decode manifest");
+            assert_eq!(manifest["fps"], 30);
+            assert_eq!(manifest["cameraPlan"]["source"], "payload");
+            assert_eq!(manifest["cameraPlan"]["easing"], "linear");
+            assert_eq!(manifest["cameraPlan"]["keyframes"][1]["zoom"], 2.0);
 
-    #[test]
-    fn capture_rejects_unsupported_high_frame_rate() {
-        with_state("capture-unsupported-high-fps", |state, _| {
-            let response = handle_request(
-                "linux",
+            expect_success(export(
                 state,
-                &request(
-                    "r8",
-                    EngineMethod::CaptureStartDisplay,
-                    json!({ "captureFps": 120 }),
+                "c2",
+                json!({ "autoZoom": { "isEnabled": false } }),
+            ));
+            assert!(state.latest_export_camera_plan.is_none());
+            assert!(state.latest_export_camera_crops.is_none());
+            let manifest: Value =
+                serde_json::from_slice(&fs::read(manifest_url).expect("read manifest"))
+                    .expect("decode manifest");
+            assert_eq!(manifest["cameraPlan"], Value::Null);
+
+            let message = expect_error(
+                export(
+                    state,
+                    "c3",
+                    json!({
+                        "cameraPlan": {
+                            "keyframes": [
+                                { "time": 1.0, "center": { "x": 0, "y": 0 }, "zoom": 1.0 },
+                                { "time": 0.5, "center": { "x": 0, "y": 0 }, "zoom": 1.0 }
+                            ]
+                        }
+                    }),
                 ),
+                ProtocolErrorCode::InvalidParams,
             );
-            let message = expect_error(response, ProtocolErrorCode::InvalidParams);
-            assert!(message.contains("Supported values: 24, 30, 60"));
+            assert_eq!(message, "cameraPlan.keyframes must be ordered by time");
         });
     }
 
     #[test]
-    fn export_run_requires_output_url() {
-        with_state("export-run-missing-output", |state, _| {
-            let response = handle_request(
-                "linux",
+    fn camera_keyframe_overrides_survive_replanning_and_reach_export() {
+        with_state("camera-keyframes", |state, root| {
+            state.auto_zoom_enabled = true;
+            state.auto_zoom_intensity = 1.0;
+            let events_path = root.join("events.json");
+            fs::write(
+                &events_path,
+                json!({
+                    "schemaVersion": 1,
+                    "events": [
+                        { "type": "mouseDown", "timestamp": 0.5, "position": { "x": 960, "y": 540 }, "button": "left" },
+                        { "type": "mouseDown", "timestamp": 1.5, "position": { "x": 400, "y": 300 }, "button": "left" },
+                        { "type": "cursorMoved", "timestamp": 3.0, "position": { "x": 1500, "y": 800 } }
+                    ]
+                })
+                .to_string(),
+            )
+            .expect("write events log");
+            state.events_url = Some(events_path.to_string_lossy().into_owned());
+            let call = |state: &mut State, id: &str, method: EngineMethod, params: Value| {
+                expect_success(handle_request("linux", state, &request(id, method, params)))
+            };
+            let times = |result: &Value| {
+                result["keyframes"]
+                    .as_array()
+                    .expect("keyframes")
+                    .iter()
+                    .map(|keyframe| {
+                        (
+                            keyframe["time"].as_f64().expect("time"),
+                            keyframe["origin"].as_str().expect("origin").to_string(),
+                        )
+                    })
+                    .collect::<Vec<_>>()
+            };
+
+            let auto = |time: f64| (time, "auto".to_string());
+            let manual = |time: f64| (time, "manual".to_string());
+
+            let planned = call(state, "k1", EngineMethod::CameraKeyframesReplan, json!({}));
+            assert_eq!(
+                times(&planned),
+                vec![auto(0.0), auto(0.5), auto(1.5), auto(3.0)]
+            );
+
+            let eased = call(
                 state,
-                &request("r9", EngineMethod::ExportRun, json!({})),
+                "k2",
+                EngineMethod::CameraKeyframesEdit,
+                json!({
+                    "startSeconds": 0.5,
+                    "endSeconds": 0.5,
+                    "edits": [{ "action": "setEasing", "easing": "linear" }]
+                }),
             );
-            let message = expect_error(response, ProtocolErrorCode::InvalidParams);
-            assert_eq!(message, "outputURL is required");
-        });
-    }
+            assert_eq!(eased["keyframes"][0]["easing"], "linear");
+            assert_eq!(eased["keyframes"][0]["locked"], true);
+            let edited = call(
+                state,
+                "k3",
+                EngineMethod::CameraKeyframesEdit,
+                json!({
+                    "startSeconds": 1.0,
+                    "endSeconds": 2.0,
+                    "edits": [
+                        { "action": "delete" },
+                        { "action": "add", "time": 1.2, "center": { "x": 200, "y": 150 }, "zoom": 3.0 }
+                    ]
+                }),
+            );
+            assert_eq!(times(&edited), vec![manual(1.2)]);
+            assert!(state.unsaved_changes);
 
-    #[test]
-    fn export_run_writes_output_file() {
-        with_state("export-run-write-file", |state, root| {
-            let output_url = root.join("exports").join("result.mp4");
-            let response = handle_request(
-                "linux",
+            let replanned = call(state, "k4", EngineMethod::CameraKeyframesReplan, json!({}));
+            assert_eq!(
+                times(&replanned),
+                vec![auto(0.0), auto(0.5), manual(1.2), auto(3.0)]
+            );
+            assert_eq!(replanned["keyframes"][1]["easing"], "linear");
+            let listed = call(
                 state,
-                &request(
-                    "r10",
-                    EngineMethod::ExportRun,
-                    json!({ "outputURL": output_url.to_string_lossy() }),
+                "k5",
+                EngineMethod::CameraKeyframesList,
+                json!({ "startSeconds": 1.0, "endSeconds": 2.0 }),
+            );
+            assert_eq!(times(&listed), vec![manual(1.2)]);
+            assert_eq!(listed["keyframeCount"], 4);
+
+            let message = expect_error(
+                handle_request(
+                    "linux",
+                    state,
+                    &request(
+                        "k6",
+                        EngineMethod::CameraKeyframesEdit,
+                        json!({
+                            "startSeconds": 0.0,
+                            "endSeconds": 1.0,
+                            "edits": [
+                                { "action": "delete" },
+                                { "action": "add", "time": 5.0, "center": { "x": 0, "y": 0 }, "zoom": 1.0 }
+                            ]
+                        }),
+                    ),
                 ),
+                ProtocolErrorCode::InvalidParams,
             );
-            let result = expect_success(response);
-            assert_eq!(result["outputURL"], json!(output_url.to_string_lossy()));
-            assert!(output_url.exists(), "expected export output file to exist");
-            let content = fs::read(output_url).expect("read export output");
-            assert_eq!(content, b"guerillaglass-native-export");
+            assert_eq!(
+                message,
+                "Added camera keyframes must lie inside the edited range"
+            );
+            assert_eq!(state.camera_keyframes.keyframes.len(), 4);
+
+            let output = root.join("camera-keyframes.mp4");
+            call(
+                state,
+                "k7",
+                EngineMethod::ExportRun,
+                json!({ "outputURL": output.to_string_lossy() }),
+            );
+            let applied = state
+                .latest_export_camera_plan
+                .as_ref()
+                .expect("keyframe track plan");
+            assert_eq!(applied.source, CameraPlanSource::KeyframeTrack);
+            assert_eq!(applied.plan.keyframes[2].zoom, 3.0);
+            let manifest: Value = serde_json::from_slice(
+                &fs::read(root.join("camera-keyframes.manifest.json")).expect("read manifest"),
+            )
+            .expect("decode manifest");
+            assert_eq!(
+                manifest["cameraPlan"]["segmentEasings"],
+                json!(["easeInOut", "linear", "easeInOut", "easeInOut"])
+            );
+
+            let project_path = root.join("camera-keyframes.gglassproj");
+            call(
+                state,
+                "k8",
+                EngineMethod::ProjectSave,
+                json!({ "projectPath": project_path.to_string_lossy() }),
+            );
+            let saved = state.camera_keyframes.clone();
+            state.camera_keyframes = Default::default();
+            call(
+                state,
+                "k9",
+                EngineMethod::ProjectOpen,
+                json!({ "projectPath": project_path.to_string_lossy() }),
+            );
+            assert_eq!(state.camera_keyframes, saved);
         });
     }
 
     #[test]
-    fn export_run_resolves_background_framing_override_then_persisted_settings() {
-        with_state("export-run-background-framing", |state, root| {
-            let project_path = root.join("framing.gglassproj");
-            let save = handle_request(
+    fn narrow_presets_reframe_the_recording_around_the_cursor() {
+        with_state("reframe-export", |state, root| {
+            let info = expect_success(handle_request(
                 "linux",
                 state,
-                &request(
-                    "framing-save",
-                    EngineMethod::ProjectSave,
+                &request("r1", EngineMethod::ExportInfo, json!({})),
+            ));
+            let presets = info["presets"].as_array().expect("presets");
+            assert_eq!(presets.len(), 4);
+            let vertical = presets
+                .iter()
+                .find(|preset| preset["width"] == 1080 && preset["height"] == 1920)
+                .expect("vertical preset");
+            // The ExportPreset contract is closed, so presets never advertise reframing.
+            assert!(presets.iter().all(|preset| preset.get("reframe").is_none()));
+            let vertical_id = vertical["id"].as_str().expect("preset id").to_string();
+
+            // The cursor sweeps from the left of the screen to the right over two seconds.
+            let events = (0..=60)
+                .map(|step| {
                     json!({
-                        "projectPath": project_path.to_string_lossy(),
-                        "backgroundFraming": {
-                            "version": 1,
-                            "enabled": true,
-                            "backgroundColor": "#112233",
-                            "paddingFraction": 0.1,
-                            "cornerRadiusFraction": 0.04,
-                            "shadowStrength": 0.5
-                        }
-                    }),
-                ),
+                        "type": "cursorMoved",
+                        "timestamp": step as f64 / 30.0,
+                        "position": { "x": 200.0 + 25.0 * step as f64, "y": 540 }
+                    })
+                })
+                .collect::<Vec<_>>();
+            let events_path = root.join("events.json");
+            fs::write(
+                &events_path,
+                json!({ "schemaVersion": 1, "events": events }).to_string(),
+            )
+            .expect("write events log");
+            state.events_url = Some(events_path.to_string_lossy().into_owned());
+
+            let output = root.join("vertical.mp4");
+            let export = |state: &mut State, id: &str, preset_id: &str| {
+                handle_request(
+                    "linux",
+                    state,
+                    &request(
+                        id,
+                        EngineMethod::ExportRun,
+                        json!({ "outputURL": output.to_string_lossy(), "presetId": preset_id }),
+                    ),
+                )
+            };
+            let result = expect_success(export(state, "r2", &vertical_id));
+            let applied = state
+                .latest_export_camera_plan
+                .as_ref()
+                .expect("reframe camera plan");
+            assert_eq!(applied.source, CameraPlanSource::Reframe);
+            assert!(applied
+                .plan
+                .keyframes
+                .iter()
+                .all(|keyframe| keyframe.zoom == 1.0));
+            let crops = state
+                .latest_export_camera_crops
+                .as_ref()
+                .expect("camera crops");
+            assert_eq!(crops.len(), 60);
+            assert!(crops
+                .iter()
+                .all(|crop| crop.height == 1080.0 && crop.width == 1080.0 * 9.0 / 16.0));
+            let first = crops.first().expect("first crop");
+            let last = crops.last().expect("last crop");
+            assert!(first.x <= 200.0 && 200.0 <= first.x + first.width);
+            assert!(last.x > first.x + 600.0, "window stayed at {}", last.x);
+
+            let manifest: Value = serde_json::from_slice(
+                &fs::read(result["manifestURL"].as_str().expect("manifest url"))
+                    .expect("read manifest"),
+            )
+            .expect("manifest json");
+            assert_eq!(manifest["presetId"], vertical_id.as_str());
+            assert_eq!(
+                (manifest["width"].clone(), manifest["height"].clone()),
+                (json!(1080), json!(1920))
             );
-            expect_success(save);
+            assert_eq!(manifest["framesRendered"], false);
+            assert_eq!(manifest["cameraPlan"]["source"], "reframe");
+            assert_eq!(manifest["reframe"]["safeMarginFraction"], 0.15);
 
-            let override_output = root.join("override.mp4");
-            let override_export = handle_request(
-                "linux",
+            expect_success(export(state, "r3", "h264-1080p-30"));
+            assert!(state.latest_export_camera_plan.is_none());
+
+            let message = expect_error(
+                export(state, "r4", "h264-8k-120"),
+                ProtocolErrorCode::InvalidParams,
+            );
+            assert!(message.contains("presetId"));
+
+            let project_path = root.join("reframe.gglassproj");
+            let save = |state: &mut State, id: &str, reframe: Value| {
+                handle_request(
+                    "linux",
+                    state,
+                    &request(
+                        id,
+                        EngineMethod::ProjectSave,
+                        json!({
+                            "projectPath": project_path.to_string_lossy(),
+                            "reframe": reframe
+                        }),
+                    ),
+                )
+            };
+            let message = expect_error(
+                save(
+                    state,
+                    "r5",
+                    json!({ "version": 1.0, "enabled": true, "smoothing": 2.0, "safeMarginFraction": 0.1 }),
+                ),
+                ProtocolErrorCode::InvalidParams,
+            );
+            assert!(message.contains("reframe.smoothing"));
+            let saved = expect_success(save(
                 state,
-                &request(
-                    "framing-override",
-                    EngineMethod::ExportRun,
+                "r6",
+                json!({ "version": 1.0, "enabled": false, "smoothing": 0.2, "safeMarginFraction": 0.1 }),
+            ));
+            assert_eq!(saved["reframe"]["enabled"], false);
+            expect_success(export(state, "r7", &vertical_id));
+            assert!(state.latest_export_camera_plan.is_none());
+        });
+    }
+
+    #[test]
+    fn guides_write_a_highlighted_screenshot_and_transcript_per_click() {
+        with_state("export-guide", |state, root| {
+            // 3 s at 10 fps, an 8x4 frame that turns from black to white at 1.5 s.
+            let recording = root.join("recording.y4m");
+            let mut stream = b"YUV4MPEG2 W8 H4 F10:1 Ip A1:1 C444\n".to_vec();
+            for frame in 0..30 {
+                stream.extend_from_slice(b"FRAME\n");
+                stream.extend_from_slice(&[if frame < 15 { 16 } else { 235 }; 32]);
+                stream.extend_from_slice(&[128; 64]);
+            }
+            fs::write(&recording, stream).expect("write recording");
+            state.recording_url = Some(recording.to_string_lossy().into_owned());
+            state.capture_metadata = Some(json!({
+                "source": "display",
+                "contentRect": { "x": 100, "y": 50, "width": 80, "height": 40 },
+            }));
+            let events_path = root.join("events.json");
+            fs::write(
+                &events_path,
+                json!({
+                    "schemaVersion": 1,
+                    "events": [
+                        { "type": "cursorMoved", "timestamp": 0.2, "position": { "x": 105, "y": 55 } },
+                        { "type": "mouseDown", "timestamp": 0.5, "position": { "x": 110, "y": 60 }, "button": "left" },
+                        { "type": "mouseUp", "timestamp": 0.6, "position": { "x": 110, "y": 60 }, "button": "left" },
+                        { "type": "mouseDown", "timestamp": 1.8, "position": { "x": 170, "y": 80 }, "button": "right" },
+                    ]
+                })
+                .to_string(),
+            )
+            .expect("write events log");
+            state.events_url = Some(events_path.to_string_lossy().into_owned());
+            let transcript_path = root.join("transcript.json");
+            fs::write(
+                &transcript_path,
+                json!({
+                    "segments": [
+                        { "startSeconds": 0.0, "endSeconds": 0.6, "text": "Open the menu." },
+                        { "startSeconds": 2.6, "endSeconds": 2.9, "text": "Rename it." },
+                    ]
+                })
+                .to_string(),
+            )
+            .expect("write transcript");
+
+            let output = root.join("guide");
+            let guide = |state: &mut State, id: &str, params: Value| {
+                handle_request(
+                    "linux",
+                    state,
+                    &request(id, EngineMethod::ExportGuide, params),
+                )
+            };
+            let message = expect_error(
+                guide(state, "g1", json!({})),
+                ProtocolErrorCode::InvalidParams,
+            );
+            assert!(message.contains("outputURL"));
+            let message = expect_error(
+                guide(state, "g2", json!({ "outputURL": "guide", "zoom": 2.0 })),
+                ProtocolErrorCode::InvalidParams,
+            );
+            assert!(message.contains("absolute"));
+            let message = expect_error(
+                guide(
+                    state,
+                    "g3",
+                    json!({ "outputURL": output.to_string_lossy(), "zoom": 5.0 }),
+                ),
+                ProtocolErrorCode::InvalidParams,
+            );
+            assert!(message.contains("zoom"));
+            let message = expect_error(
+                guide(
+                    state,
+                    "g4",
                     json!({
-                        "outputURL": override_output.to_string_lossy(),
-                        "backgroundFraming": {
-                            "version": 1,
-                            "enabled": true,
-                            "backgroundColor": "#abcdef",
-                            "paddingFraction": 0.2,
-                            "cornerRadiusFraction": 0.08,
-                            "shadowStrength": 0.8
-                        }
+                        "outputURL": output.to_string_lossy(),
+                        "importedTranscriptPath": root.join("missing.json").to_string_lossy(),
                     }),
                 ),
+                ProtocolErrorCode::InvalidParams,
             );
-            expect_success(override_export);
-            let resolved_override = state
-                .latest_export_background_framing
-                .as_ref()
-                .expect("resolved override");
-            assert_eq!(resolved_override.background_color, "#ABCDEF");
-            assert_eq!(resolved_override.padding_fraction, 0.2);
+            assert!(message.contains("importedTranscriptPath"));
+            assert!(!output.exists());
 
-            let persisted_output = root.join("persisted.mp4");
-            let persisted_export = handle_request(
-                "linux",
+            let result = expect_success(guide(
                 state,
-                &request(
-                    "framing-persisted",
-                    EngineMethod::ExportRun,
-                    json!({ "outputURL": persisted_output.to_string_lossy() }),
+                "g5",
+                json!({
+                    "outputURL": output.to_string_lossy(),
+                    "title": "Rename <files> & folders",
+                    "importedTranscriptPath": transcript_path.to_string_lossy(),
+                    "zoom": 2.0,
+                }),
+            ));
+            assert_eq!(result["source"], "recording");
+            let steps = result["steps"]
+                .as_array()
+                .expect("steps")
+                .iter()
+                .map(|step| {
+                    (
+                        step["timestamp"].clone(),
+                        step["action"].clone(),
+                        step["imageURL"].clone(),
+                        step["click"].clone(),
+                        step["transcript"].clone(),
+                    )
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(
+                steps,
+                vec![
+                    (
+                        json!("00:00.5"),
+                        json!("Click"),
+                        json!("images/step-01.png"),
+                        json!({ "x": 10.0, "y": 10.0 }),
+                        json!("Open the menu."),
+                    ),
+                    (
+                        json!("00:01.8"),
+                        json!("Right-click"),
+                        json!("images/step-02.png"),
+                        json!({ "x": 70.0, "y": 30.0 }),
+                        json!("Rename it."),
+                    ),
+                ]
+            );
+
+            // Stored deflate keeps the scanlines readable: after the IHDR chunk and the IDAT,
+            // zlib and block headers, each 4 px row is a filter byte and 12 bytes of RGB.
+            let pixel = |png: &[u8], x: usize, y: usize| png[48 + y * 13 + 1 + x * 3];
+            let first = fs::read(output.join("images/step-01.png")).expect("first screenshot");
+            let second = fs::read(output.join("images/step-02.png")).expect("second screenshot");
+            for png in [&first, &second] {
+                assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+                assert_eq!(&png[16..24], &[0, 0, 0, 4, 0, 0, 0, 2]);
+            }
+            // Each click sits inside its ring, on the frame shown at that moment.
+            assert_eq!(pixel(&first, 1, 1), 0);
+            assert_eq!(pixel(&first, 3, 1), 0xFA);
+            assert_eq!(pixel(&second, 3, 1), 255);
+            assert_eq!(pixel(&second, 0, 1), 0xFA);
+
+            let markdown = fs::read_to_string(output.join("guide.md")).expect("markdown guide");
+            assert!(markdown.starts_with("# Rename <files> & folders\n"));
+            assert!(markdown.contains("![Step 1](images/step-01.png)"));
+            assert!(markdown.contains("Right-click at 00:01.8."));
+            assert!(markdown.contains("> Rename it."));
+            let html = fs::read_to_string(output.join("guide.html")).expect("html guide");
+            assert!(html.contains("<h1>Rename &lt;files&gt; &amp; folders</h1>"));
+            assert!(html.contains("<img src=\"images/step-02.png\" alt=\"Step 2\">"));
+            assert!(html.contains("<blockquote>Open the menu.</blockquote>"));
+            assert_eq!(
+                result["htmlURL"],
+                json!(output.join("guide.html").to_string_lossy())
+            );
+
+            fs::write(
+                &events_path,
+                json!({ "schemaVersion": 1, "events": [
+                    { "type": "cursorMoved", "timestamp": 0.2, "position": { "x": 105, "y": 55 } },
+                ] })
+                .to_string(),
+            )
+            .expect("rewrite events log");
+            let message = expect_error(
+                guide(
+                    state,
+                    "g6",
+                    json!({ "outputURL": output.to_string_lossy() }),
                 ),
+                ProtocolErrorCode::InvalidParams,
             );
-            expect_success(persisted_export);
-            let resolved_persisted = state
-                .latest_export_background_framing
-                .as_ref()
-                .expect("resolved persisted settings");
-            assert_eq!(resolved_persisted.background_color, "#112233");
-            assert_eq!(resolved_persisted.padding_fraction, 0.1);
+            assert!(message.contains("no clicks"));
         });
     }
 
@@ -745,6 +3882,52 @@ mod tests {
         });
     }
 
+    #[test]
+    fn project_open_migrates_a_legacy_recording_into_a_take_and_clears_it_for_empty_projects() {
+        with_state("project-open-legacy-take", |state, root| {
+            let legacy_path = root.join("legacy.gglassproj");
+            fs::create_dir_all(&legacy_path).expect("create legacy project");
+            fs::write(
+                legacy_path.join("project.native.json"),
+                json!({
+                    "projectPath": legacy_path.to_string_lossy(),
+                    "recordingURL": "native://recordings/session.mp4",
+                    "eventsURL": "native://events/session-1.json",
+                })
+                .to_string(),
+            )
+            .expect("write legacy snapshot");
+            let opened = expect_success(handle_request(
+                "linux",
+                state,
+                &request(
+                    "legacy-open",
+                    EngineMethod::ProjectOpen,
+                    json!({ "projectPath": legacy_path.to_string_lossy() }),
+                ),
+            ));
+            assert_eq!(opened["activeTakeId"], "legacy");
+            assert_eq!(opened["recordingURL"], "native://recordings/session.mp4");
+            assert_eq!(opened["eventsURL"], "native://events/session-1.json");
+            assert_eq!(opened["takes"].as_array().map(Vec::len), Some(1));
+            assert_eq!(opened["takes"][0]["name"], "Take 1");
+
+            let empty_path = root.join("empty.gglassproj");
+            let opened = expect_success(handle_request(
+                "linux",
+                state,
+                &request(
+                    "empty-open",
+                    EngineMethod::ProjectOpen,
+                    json!({ "projectPath": empty_path.to_string_lossy() }),
+                ),
+            ));
+            assert_eq!(opened["activeTakeId"], Value::Null);
+            assert_eq!(opened["recordingURL"], Value::Null);
+            assert_eq!(opened["eventsURL"], Value::Null);
+        });
+    }
+
     #[test]
     fn project_save_clamps_auto_zoom_and_writes_snapshot() {
         with_state("project-save-clamps-autoz", |state, root| {
@@ -0,0 +1,35 @@
+use crate::params::{LibraryDefaultsParams, SettingsGroup};
+use crate::state::{save_library_defaults, State};
+use crate::wire::{
+    decode_params, failure, success, EngineCallId, EngineResponse, ProtocolErrorCode,
+};
+use serde_json::{json, Value};
+
+fn defaults_result(state: &State) -> Value {
+    json!({ "windowExclusions": state.default_window_exclusions })
+}
+
+/// Settings new projects start from, stored in the library index next to the recents.
+pub(crate) fn defaults(id: &EngineCallId, state: &State) -> EngineResponse {
+    success(id, defaults_result(state))
+}
+
+pub(crate) fn save_defaults(
+    id: &EngineCallId,
+    state: &mut State,
+    params: &Value,
+) -> EngineResponse {
+    let defaults_params =
+        match decode_params::<LibraryDefaultsParams>(id, params, "library defaults") {
+            Ok(params) => params,
+            Err(response) => return response,
+        };
+    if let Some(window_exclusions) = defaults_params.window_exclusions {
+        match window_exclusions.validated() {
+            Ok(settings) => state.default_window_exclusions = settings,
+            Err(error) => return failure(id, ProtocolErrorCode::InvalidParams, error),
+        }
+    }
+    save_library_defaults(state);
+    success(id, defaults_result(state))
+}
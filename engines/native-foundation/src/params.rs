@@ -1,4 +1,27 @@
+use crate::automation::CameraKeyframe;
+use crate::camera_keyframes::CameraKeyframeEdit;
+use crate::camera_path::CameraEasing;
+use crate::idle_cuts::CutOperation;
+use crate::timeline::{TimelineItem, MAX_PLAYBACK_RATE};
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// A group of project settings stored under its own key in project snapshots, which checks its
+/// own invariants before it is stored or rendered.
+pub(crate) trait SettingsGroup: DeserializeOwned + Clone {
+    const KEY: &'static str;
+
+    fn validated(self) -> Result<Self, &'static str>;
+}
+
+/// Validates the settings group a request carries, keeping `current` when it carries none.
+pub(crate) fn resolve_settings<T: SettingsGroup>(
+    settings: Option<T>,
+    current: &T,
+) -> Result<T, &'static str> {
+    settings.map_or_else(|| Ok(current.clone()), T::validated)
+}
 
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -24,16 +47,14 @@ impl Default for BackgroundFramingParams {
     }
 }
 
-impl BackgroundFramingParams {
-    pub(crate) fn validated(mut self) -> Result<Self, &'static str> {
+impl SettingsGroup for BackgroundFramingParams {
+    const KEY: &'static str = "backgroundFraming";
+
+    fn validated(mut self) -> Result<Self, &'static str> {
         if self.version != 1.0 {
             return Err("backgroundFraming.version must be 1");
         }
-        let color = self.background_color.as_bytes();
-        if color.len() != 7
-            || color.first() != Some(&b'#')
-            || !color[1..].iter().all(u8::is_ascii_hexdigit)
-        {
+        if !valid_hex_color(&self.background_color) {
             return Err("backgroundFraming.backgroundColor must be #RRGGBB");
         }
         if !valid_fraction(self.padding_fraction, 0.25) {
@@ -52,6 +73,446 @@ impl BackgroundFramingParams {
     }
 }
 
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) enum ShortcutOverlayPosition {
+    TopLeft,
+    TopCenter,
+    TopRight,
+    BottomLeft,
+    #[default]
+    BottomCenter,
+    BottomRight,
+}
+
+/// Styling for the keyboard-shortcut badges drawn over exported video.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ShortcutOverlayParams {
+    pub(crate) version: f64,
+    pub(crate) enabled: bool,
+    pub(crate) display_duration_seconds: f64,
+    pub(crate) position: ShortcutOverlayPosition,
+    pub(crate) background_color: String,
+    pub(crate) text_color: String,
+    pub(crate) background_opacity: f64,
+    pub(crate) font_scale: f64,
+}
+
+impl Default for ShortcutOverlayParams {
+    fn default() -> Self {
+        Self {
+            version: 1.0,
+            enabled: true,
+            display_duration_seconds: 1.5,
+            position: ShortcutOverlayPosition::default(),
+            background_color: "#18181B".to_string(),
+            text_color: "#FAFAFA".to_string(),
+            background_opacity: 0.85,
+            font_scale: 1.0,
+        }
+    }
+}
+
+impl SettingsGroup for ShortcutOverlayParams {
+    const KEY: &'static str = "shortcutOverlay";
+
+    fn validated(mut self) -> Result<Self, &'static str> {
+        if self.version != 1.0 {
+            return Err("shortcutOverlay.version must be 1");
+        }
+        if !self.display_duration_seconds.is_finite()
+            || !(0.25..=10.0).contains(&self.display_duration_seconds)
+        {
+            return Err(
+                "shortcutOverlay.displayDurationSeconds must be finite and between 0.25 and 10",
+            );
+        }
+        if !valid_hex_color(&self.background_color) {
+            return Err("shortcutOverlay.backgroundColor must be #RRGGBB");
+        }
+        if !valid_hex_color(&self.text_color) {
+            return Err("shortcutOverlay.textColor must be #RRGGBB");
+        }
+        if !valid_fraction(self.background_opacity, 1.0) {
+            return Err("shortcutOverlay.backgroundOpacity must be finite and between 0 and 1");
+        }
+        if !self.font_scale.is_finite() || !(0.5..=3.0).contains(&self.font_scale) {
+            return Err("shortcutOverlay.fontScale must be finite and between 0.5 and 3");
+        }
+        self.background_color.make_ascii_uppercase();
+        self.text_color.make_ascii_uppercase();
+        Ok(self)
+    }
+}
+
+/// How export redraws the cursor from the recorded input in place of the captured one.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct CursorRenderingParams {
+    pub(crate) version: f64,
+    pub(crate) enabled: bool,
+    /// 0 follows the recorded path; 1 smooths it the most.
+    pub(crate) smoothing: f64,
+    pub(crate) scale: f64,
+    /// Seconds without pointer input before the cursor fades out; 0 keeps it visible.
+    pub(crate) hide_after_idle_seconds: f64,
+}
+
+impl Default for CursorRenderingParams {
+    fn default() -> Self {
+        Self {
+            version: 1.0,
+            enabled: false,
+            smoothing: 0.5,
+            scale: 1.0,
+            hide_after_idle_seconds: 3.0,
+        }
+    }
+}
+
+impl SettingsGroup for CursorRenderingParams {
+    const KEY: &'static str = "cursorRendering";
+
+    fn validated(self) -> Result<Self, &'static str> {
+        if self.version != 1.0 {
+            return Err("cursorRendering.version must be 1");
+        }
+        if !valid_fraction(self.smoothing, 1.0) {
+            return Err("cursorRendering.smoothing must be finite and between 0 and 1");
+        }
+        if !self.scale.is_finite() || !(0.5..=4.0).contains(&self.scale) {
+            return Err("cursorRendering.scale must be finite and between 0.5 and 4");
+        }
+        if !valid_fraction(self.hide_after_idle_seconds, 60.0) {
+            return Err("cursorRendering.hideAfterIdleSeconds must be finite and between 0 and 60");
+        }
+        Ok(self)
+    }
+}
+
+/// How presets narrower than the recording pan their window across it to follow the cursor.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ReframeParams {
+    pub(crate) version: f64,
+    pub(crate) enabled: bool,
+    /// 0 pans as fast as the planner allows; 1 pans the slowest.
+    pub(crate) smoothing: f64,
+    /// Fraction of the window kept between the cursor and its edges.
+    pub(crate) safe_margin_fraction: f64,
+}
+
+impl Default for ReframeParams {
+    fn default() -> Self {
+        Self {
+            version: 1.0,
+            enabled: true,
+            smoothing: 0.5,
+            safe_margin_fraction: 0.15,
+        }
+    }
+}
+
+impl SettingsGroup for ReframeParams {
+    const KEY: &'static str = "reframe";
+
+    fn validated(self) -> Result<Self, &'static str> {
+        if self.version != 1.0 {
+            return Err("reframe.version must be 1");
+        }
+        if !valid_fraction(self.smoothing, 1.0) {
+            return Err("reframe.smoothing must be finite and between 0 and 1");
+        }
+        if !valid_fraction(self.safe_margin_fraction, 0.25) {
+            return Err("reframe.safeMarginFraction must be finite and between 0 and 0.25");
+        }
+        Ok(self)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ClickRippleParams {
+    pub(crate) enabled: bool,
+    pub(crate) color: String,
+    /// Radius the ring grows to, in source pixels.
+    pub(crate) max_radius: f64,
+    pub(crate) duration_seconds: f64,
+}
+
+/// Dims everything further than `radius` source pixels from the cursor.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct SpotlightParams {
+    pub(crate) enabled: bool,
+    pub(crate) radius: f64,
+    /// Width of the soft edge outside `radius`, in source pixels.
+    pub(crate) feather: f64,
+    pub(crate) dim_opacity: f64,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ClickSoundParams {
+    pub(crate) enabled: bool,
+    pub(crate) volume: f64,
+}
+
+/// Turns effects on or off for one timeline clip; unset toggles follow the project settings.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ClickEffectSegmentParams {
+    pub(crate) clip_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) ripple: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) spotlight: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) click_sound: Option<bool>,
+}
+
+/// Effects synthesized from recorded clicks and pointer movement.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ClickEffectsParams {
+    pub(crate) version: f64,
+    pub(crate) ripple: ClickRippleParams,
+    pub(crate) spotlight: SpotlightParams,
+    pub(crate) click_sound: ClickSoundParams,
+    #[serde(default)]
+    pub(crate) segments: Vec<ClickEffectSegmentParams>,
+}
+
+impl Default for ClickEffectsParams {
+    fn default() -> Self {
+        Self {
+            version: 1.0,
+            ripple: ClickRippleParams {
+                enabled: false,
+                color: "#FACC15".to_string(),
+                max_radius: 36.0,
+                duration_seconds: 0.4,
+            },
+            spotlight: SpotlightParams {
+                enabled: false,
+                radius: 180.0,
+                feather: 60.0,
+                dim_opacity: 0.55,
+            },
+            click_sound: ClickSoundParams {
+                enabled: false,
+                volume: 0.6,
+            },
+            segments: Vec::new(),
+        }
+    }
+}
+
+impl SettingsGroup for ClickEffectsParams {
+    const KEY: &'static str = "clickEffects";
+
+    fn validated(mut self) -> Result<Self, &'static str> {
+        if self.version != 1.0 {
+            return Err("clickEffects.version must be 1");
+        }
+        if !valid_hex_color(&self.ripple.color) {
+            return Err("clickEffects.ripple.color must be #RRGGBB");
+        }
+        if !self.ripple.max_radius.is_finite() || !(8.0..=200.0).contains(&self.ripple.max_radius) {
+            return Err("clickEffects.ripple.maxRadius must be finite and between 8 and 200");
+        }
+        if !self.ripple.duration_seconds.is_finite()
+            || !(0.1..=2.0).contains(&self.ripple.duration_seconds)
+        {
+            return Err("clickEffects.ripple.durationSeconds must be finite and between 0.1 and 2");
+        }
+        if !self.spotlight.radius.is_finite() || !(20.0..=1000.0).contains(&self.spotlight.radius) {
+            return Err("clickEffects.spotlight.radius must be finite and between 20 and 1000");
+        }
+        if !valid_fraction(self.spotlight.feather, 400.0) {
+            return Err("clickEffects.spotlight.feather must be finite and between 0 and 400");
+        }
+        if !valid_fraction(self.spotlight.dim_opacity, 1.0) {
+            return Err("clickEffects.spotlight.dimOpacity must be finite and between 0 and 1");
+        }
+        if !valid_fraction(self.click_sound.volume, 1.0) {
+            return Err("clickEffects.clickSound.volume must be finite and between 0 and 1");
+        }
+        let mut clip_ids = HashSet::new();
+        if !self
+            .segments
+            .iter()
+            .all(|segment| !segment.clip_id.is_empty() && clip_ids.insert(&segment.clip_id))
+        {
+            return Err("clickEffects.segments clipIds must be unique and not empty");
+        }
+        self.ripple.color.make_ascii_uppercase();
+        Ok(self)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) enum PictureInPictureCorner {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    #[default]
+    BottomRight,
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) enum PictureInPictureShape {
+    Rectangle,
+    #[default]
+    RoundedRectangle,
+    Circle,
+}
+
+/// Where and how the camera track is composited over the screen recording on export.
+/// Fractions are relative to the export width.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct PictureInPictureParams {
+    pub(crate) version: f64,
+    pub(crate) enabled: bool,
+    pub(crate) corner: PictureInPictureCorner,
+    pub(crate) size_fraction: f64,
+    pub(crate) margin_fraction: f64,
+    pub(crate) shape: PictureInPictureShape,
+    pub(crate) border_width_fraction: f64,
+    pub(crate) border_color: String,
+}
+
+impl Default for PictureInPictureParams {
+    fn default() -> Self {
+        Self {
+            version: 1.0,
+            enabled: true,
+            corner: PictureInPictureCorner::default(),
+            size_fraction: 0.22,
+            margin_fraction: 0.025,
+            shape: PictureInPictureShape::default(),
+            border_width_fraction: 0.002,
+            border_color: "#FFFFFF".to_string(),
+        }
+    }
+}
+
+impl SettingsGroup for PictureInPictureParams {
+    const KEY: &'static str = "pictureInPicture";
+
+    fn validated(mut self) -> Result<Self, &'static str> {
+        if self.version != 1.0 {
+            return Err("pictureInPicture.version must be 1");
+        }
+        if !self.size_fraction.is_finite() || !(0.1..=0.5).contains(&self.size_fraction) {
+            return Err("pictureInPicture.sizeFraction must be finite and between 0.1 and 0.5");
+        }
+        if !valid_fraction(self.margin_fraction, 0.10) {
+            return Err("pictureInPicture.marginFraction must be finite and between 0 and 0.10");
+        }
+        if !valid_fraction(self.border_width_fraction, 0.02) {
+            return Err(
+                "pictureInPicture.borderWidthFraction must be finite and between 0 and 0.02",
+            );
+        }
+        if !valid_hex_color(&self.border_color) {
+            return Err("pictureInPicture.borderColor must be #RRGGBB");
+        }
+        self.border_color.make_ascii_uppercase();
+        Ok(self)
+    }
+}
+
+/// Upper bound on exclusion rules, which are matched against every window several times a second.
+pub(crate) const MAX_WINDOW_EXCLUSION_RULES: usize = 64;
+
+/// Picks windows to hide from display and region capture. Every criterion that is set has
+/// to match; `titlePattern` is a case-insensitive glob (`*`, `?`) and `wmClass` matches
+/// either half of `WM_CLASS` case-insensitively.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct WindowExclusionRule {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) window_id: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) title_pattern: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) wm_class: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) enum PrivacyMaskStyle {
+    #[default]
+    SolidFill,
+    Blur,
+}
+
+/// Windows masked out of captured frames, persisted per project and as library defaults.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase", default)]
+pub(crate) struct WindowExclusionParams {
+    pub(crate) version: f64,
+    pub(crate) rules: Vec<WindowExclusionRule>,
+    pub(crate) mask_style: PrivacyMaskStyle,
+    pub(crate) fill_color: String,
+}
+
+impl Default for WindowExclusionParams {
+    fn default() -> Self {
+        Self {
+            version: 1.0,
+            rules: Vec::new(),
+            mask_style: PrivacyMaskStyle::default(),
+            fill_color: "#000000".to_string(),
+        }
+    }
+}
+
+impl SettingsGroup for WindowExclusionParams {
+    const KEY: &'static str = "windowExclusions";
+
+    fn validated(mut self) -> Result<Self, &'static str> {
+        if self.version != 1.0 {
+            return Err("windowExclusions.version must be 1");
+        }
+        if self.rules.len() > MAX_WINDOW_EXCLUSION_RULES {
+            return Err("windowExclusions.rules must not contain more than 64 rules");
+        }
+        for rule in &mut self.rules {
+            rule.title_pattern = rule
+                .title_pattern
+                .take()
+                .map(|pattern| pattern.trim().to_string())
+                .filter(|pattern| !pattern.is_empty());
+            rule.wm_class = rule
+                .wm_class
+                .take()
+                .map(|class| class.trim().to_string())
+                .filter(|class| !class.is_empty());
+            if rule.window_id.is_none() && rule.title_pattern.is_none() && rule.wm_class.is_none() {
+                return Err(
+                    "windowExclusions.rules entries need a windowId, titlePattern or wmClass",
+                );
+            }
+        }
+        if !valid_hex_color(&self.fill_color) {
+            return Err("windowExclusions.fillColor must be #RRGGBB");
+        }
+        self.fill_color.make_ascii_uppercase();
+        Ok(self)
+    }
+}
+
+fn valid_hex_color(color: &str) -> bool {
+    let color = color.as_bytes();
+    color.len() == 7 && color[0] == b'#' && color[1..].iter().all(u8::is_ascii_hexdigit)
+}
+
 fn valid_fraction(value: f64, maximum: f64) -> bool {
     value.is_finite() && (0.0..=maximum).contains(&value)
 }
@@ -79,10 +540,77 @@ pub(crate) struct CaptureStartParams {
     pub(crate) capture_fps: Option<u64>,
 }
 
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct CaptureStartCameraParams {
+    pub(crate) camera_id: Option<u64>,
+    pub(crate) capture_fps: Option<u64>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct CaptureStartRegionParams {
+    pub(crate) display_id: Option<u64>,
+    pub(crate) rect: Option<CaptureRectParams>,
+    pub(crate) capture_fps: Option<u64>,
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize)]
+pub(crate) struct CaptureRectParams {
+    pub(crate) x: f64,
+    pub(crate) y: f64,
+    pub(crate) width: f64,
+    pub(crate) height: f64,
+}
+
 #[derive(Debug, Default, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub(crate) struct RecordingStartParams {
     pub(crate) track_input_events: Option<bool>,
+    pub(crate) track_keyboard_shortcuts: Option<bool>,
+    pub(crate) capture_microphone: Option<bool>,
+    pub(crate) microphone_device_id: Option<String>,
+    pub(crate) capture_system_audio: Option<bool>,
+    pub(crate) system_audio_device_id: Option<String>,
+    pub(crate) silence_warning_seconds: Option<f64>,
+    pub(crate) countdown_seconds: Option<f64>,
+    pub(crate) max_duration_seconds: Option<f64>,
+    /// RFC 3339 wall-clock time at which the recording stops.
+    pub(crate) stop_at: Option<String>,
+}
+
+/// Longest marker label accepted by `recording.addMarker`, in characters.
+pub(crate) const MAX_MARKER_LABEL_CHARS: usize = 120;
+pub(crate) const DEFAULT_MARKER_COLOR: &str = "#F59E0B";
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct RecordingAddMarkerParams {
+    pub(crate) label: Option<String>,
+    pub(crate) color: Option<String>,
+}
+
+impl RecordingAddMarkerParams {
+    pub(crate) fn validated(mut self) -> Result<Self, &'static str> {
+        self.label = self
+            .label
+            .map(|label| label.trim().to_string())
+            .filter(|label| !label.is_empty());
+        if self
+            .label
+            .as_ref()
+            .is_some_and(|label| label.chars().count() > MAX_MARKER_LABEL_CHARS)
+        {
+            return Err("label must be at most 120 characters");
+        }
+        if let Some(color) = &mut self.color {
+            if !valid_hex_color(color) {
+                return Err("color must be #RRGGBB");
+            }
+            color.make_ascii_uppercase();
+        }
+        Ok(self)
+    }
 }
 
 #[derive(Debug, Default, Deserialize)]
@@ -90,7 +618,64 @@ pub(crate) struct RecordingStartParams {
 pub(crate) struct ExportRunParams {
     #[serde(rename = "outputURL")]
     pub(crate) output_url: Option<String>,
+    /// One of the presets from `export.info`; the 1080p preset when omitted.
+    pub(crate) preset_id: Option<String>,
     pub(crate) background_framing: Option<BackgroundFramingParams>,
+    pub(crate) shortcut_overlay: Option<ShortcutOverlayParams>,
+    pub(crate) cursor_rendering: Option<CursorRenderingParams>,
+    pub(crate) click_effects: Option<ClickEffectsParams>,
+    pub(crate) picture_in_picture: Option<PictureInPictureParams>,
+    pub(crate) reframe: Option<ReframeParams>,
+    pub(crate) timeline: Option<TimelineParams>,
+    /// Overrides the project's auto-zoom settings for this export.
+    pub(crate) auto_zoom: Option<AutoZoomParams>,
+    /// Explicit camera keyframes, used instead of planning from input events.
+    pub(crate) camera_plan: Option<CameraPlanParams>,
+}
+
+/// Most camera keyframes accepted with an export.
+pub(crate) const MAX_CAMERA_KEYFRAMES: usize = 10_000;
+/// Highest camera zoom accepted with an export.
+pub(crate) const MAX_CAMERA_ZOOM: f64 = 10.0;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct CameraPlanParams {
+    pub(crate) keyframes: Vec<CameraKeyframe>,
+    #[serde(default)]
+    pub(crate) easing: CameraEasing,
+}
+
+impl CameraPlanParams {
+    pub(crate) fn validated(self) -> Result<Self, &'static str> {
+        if self.keyframes.is_empty() {
+            return Err("cameraPlan.keyframes must not be empty");
+        }
+        if self.keyframes.len() > MAX_CAMERA_KEYFRAMES {
+            return Err("cameraPlan.keyframes must not contain more than 10000 keyframes");
+        }
+        let valid = self.keyframes.iter().all(|keyframe| {
+            keyframe.time.is_finite()
+                && keyframe.time >= 0.0
+                && keyframe.center.x.is_finite()
+                && keyframe.center.y.is_finite()
+                && keyframe.zoom.is_finite()
+                && (1.0..=MAX_CAMERA_ZOOM).contains(&keyframe.zoom)
+        });
+        if !valid {
+            return Err(
+                "cameraPlan.keyframes need finite, non-negative times and centers with zoom between 1 and 10",
+            );
+        }
+        if self
+            .keyframes
+            .windows(2)
+            .any(|pair| pair[1].time < pair[0].time)
+        {
+            return Err("cameraPlan.keyframes must be ordered by time");
+        }
+        Ok(self)
+    }
 }
 
 #[derive(Debug, Default, Deserialize)]
@@ -114,6 +699,88 @@ pub(crate) struct ProjectSaveParams {
     pub(crate) project_path: Option<String>,
     pub(crate) auto_zoom: Option<AutoZoomParams>,
     pub(crate) background_framing: Option<BackgroundFramingParams>,
+    pub(crate) shortcut_overlay: Option<ShortcutOverlayParams>,
+    pub(crate) cursor_rendering: Option<CursorRenderingParams>,
+    pub(crate) click_effects: Option<ClickEffectsParams>,
+    pub(crate) picture_in_picture: Option<PictureInPictureParams>,
+    pub(crate) reframe: Option<ReframeParams>,
+    pub(crate) window_exclusions: Option<WindowExclusionParams>,
+    pub(crate) timeline: Option<TimelineParams>,
+}
+
+/// Versioned timeline document saved with the project or sent with an export.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct TimelineParams {
+    pub(crate) version: f64,
+    pub(crate) items: Vec<TimelineItem>,
+}
+
+impl TimelineParams {
+    pub(crate) fn validated(self) -> Result<Vec<TimelineItem>, String> {
+        if self.version != 2.0 {
+            return Err("timeline.version must be 2".to_string());
+        }
+        let mut ids = HashSet::new();
+        for item in &self.items {
+            let (id, valid) = match item {
+                TimelineItem::Clip {
+                    id,
+                    source_asset_id,
+                    source_start_seconds,
+                    source_end_seconds,
+                    ..
+                } => (
+                    id,
+                    !source_asset_id.is_empty()
+                        && source_start_seconds.is_finite()
+                        && source_end_seconds.is_finite()
+                        && 0.0 <= *source_start_seconds
+                        && source_start_seconds <= source_end_seconds,
+                ),
+                TimelineItem::Gap {
+                    id,
+                    duration_seconds,
+                } => (id, duration_seconds.is_finite() && *duration_seconds >= 0.0),
+            };
+            if id.is_empty() || !ids.insert(id) {
+                return Err("timeline.items ids must be unique and not empty".to_string());
+            }
+            if !valid {
+                return Err(
+                    "timeline.items need a sourceAssetId and finite, non-negative, ordered times"
+                        .to_string(),
+                );
+            }
+            if let TimelineItem::Clip {
+                source_start_seconds,
+                source_end_seconds,
+                speed,
+                ..
+            } = item
+            {
+                speed.validated(source_end_seconds - source_start_seconds)?;
+            }
+        }
+        Ok(self.items)
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct TakeParams {
+    pub(crate) take_id: Option<String>,
+    pub(crate) name: Option<String>,
+    pub(crate) destructive_intent: Option<bool>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct CameraKeyframesParams {
+    pub(crate) start_seconds: Option<f64>,
+    pub(crate) end_seconds: Option<f64>,
+    #[serde(default)]
+    pub(crate) edits: Vec<CameraKeyframeEdit>,
 }
 
 #[derive(Debug, Default, Deserialize)]
@@ -129,3 +796,157 @@ pub(crate) struct AutoZoomParams {
 pub(crate) struct ProjectRecentsParams {
     pub(crate) limit: Option<u64>,
 }
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct LibraryDefaultsParams {
+    pub(crate) window_exclusions: Option<WindowExclusionParams>,
+}
+
+/// Thresholds for finding idle stretches of the active take.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase", default)]
+pub(crate) struct IdleCutsParams {
+    pub(crate) min_idle_seconds: f64,
+    /// Largest mean luma change between frames, as a fraction of full scale, that still counts
+    /// as a still picture.
+    pub(crate) stillness_threshold: f64,
+    /// Context kept on each side of an idle stretch.
+    pub(crate) padding_seconds: f64,
+    pub(crate) operation: CutOperation,
+    /// Playback rate proposed for `speedUp` suggestions.
+    pub(crate) playback_rate: f64,
+}
+
+impl Default for IdleCutsParams {
+    fn default() -> Self {
+        Self {
+            min_idle_seconds: 5.0,
+            stillness_threshold: 0.002,
+            padding_seconds: 0.5,
+            operation: CutOperation::default(),
+            playback_rate: 4.0,
+        }
+    }
+}
+
+impl IdleCutsParams {
+    pub(crate) fn validated(self) -> Result<Self, String> {
+        if !self.min_idle_seconds.is_finite() || !(0.5..=600.0).contains(&self.min_idle_seconds) {
+            return Err("minIdleSeconds must be finite and between 0.5 and 600".to_string());
+        }
+        if !valid_fraction(self.stillness_threshold, 1.0) {
+            return Err("stillnessThreshold must be finite and between 0 and 1".to_string());
+        }
+        if !valid_fraction(self.padding_seconds, 5.0) {
+            return Err("paddingSeconds must be finite and between 0 and 5".to_string());
+        }
+        if !self.playback_rate.is_finite()
+            || !(1.0..=MAX_PLAYBACK_RATE).contains(&self.playback_rate)
+        {
+            return Err(format!(
+                "playbackRate must be finite and between 1 and {MAX_PLAYBACK_RATE}"
+            ));
+        }
+        if self.padding_seconds * 2.0 >= self.min_idle_seconds {
+            return Err("paddingSeconds must leave part of minIdleSeconds to cut".to_string());
+        }
+        Ok(self)
+    }
+}
+
+/// Thresholds for finding scene changes in the active take.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase", default)]
+pub(crate) struct VisualAnalysisParams {
+    /// Smallest mean luma change between frames, as a fraction of full scale, that counts as a
+    /// scene change.
+    pub(crate) scene_threshold: f64,
+    /// Shortest scene; closer changes keep only the strongest.
+    pub(crate) min_scene_seconds: f64,
+}
+
+impl Default for VisualAnalysisParams {
+    fn default() -> Self {
+        Self {
+            scene_threshold: 0.1,
+            min_scene_seconds: 2.0,
+        }
+    }
+}
+
+impl VisualAnalysisParams {
+    pub(crate) fn validated(self) -> Result<Self, &'static str> {
+        if !self.scene_threshold.is_finite() || !(0.001..=1.0).contains(&self.scene_threshold) {
+            return Err("sceneThreshold must be finite and between 0.001 and 1");
+        }
+        if !self.min_scene_seconds.is_finite() || !(0.1..=600.0).contains(&self.min_scene_seconds) {
+            return Err("minSceneSeconds must be finite and between 0.1 and 600");
+        }
+        Ok(self)
+    }
+}
+
+/// Longest guide title accepted by `export.guide`, in characters.
+pub(crate) const MAX_GUIDE_TITLE_CHARS: usize = 200;
+/// Highest screenshot zoom accepted by `export.guide`.
+pub(crate) const MAX_GUIDE_ZOOM: f64 = 4.0;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub(crate) struct GuideParams {
+    /// Directory the guide bundle is written into; created when missing.
+    #[serde(rename = "outputURL")]
+    pub(crate) output_url: Option<String>,
+    pub(crate) title: String,
+    /// Transcript whose segments around each click are quoted under its step.
+    pub(crate) imported_transcript_path: Option<String>,
+    /// How far each screenshot is cropped in around its click.
+    pub(crate) zoom: f64,
+    pub(crate) highlight_color: String,
+}
+
+impl Default for GuideParams {
+    fn default() -> Self {
+        Self {
+            output_url: None,
+            title: "Step-by-step guide".to_string(),
+            imported_transcript_path: None,
+            zoom: 1.5,
+            highlight_color: "#FACC15".to_string(),
+        }
+    }
+}
+
+impl GuideParams {
+    pub(crate) fn validated(mut self) -> Result<Self, &'static str> {
+        self.title = self.title.trim().to_string();
+        if self.title.is_empty() {
+            return Err("title must not be empty");
+        }
+        if self.title.chars().count() > MAX_GUIDE_TITLE_CHARS {
+            return Err("title must be at most 200 characters");
+        }
+        self.imported_transcript_path = self
+            .imported_transcript_path
+            .map(|path| path.trim().to_string())
+            .filter(|path| !path.is_empty());
+        if !self.zoom.is_finite() || !(1.0..=MAX_GUIDE_ZOOM).contains(&self.zoom) {
+            return Err("zoom must be finite and between 1 and 4");
+        }
+        if !valid_hex_color(&self.highlight_color) {
+            return Err("highlightColor must be #RRGGBB");
+        }
+        self.highlight_color.make_ascii_uppercase();
+        Ok(self)
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ApplyCutsParams {
+    #[serde(default)]
+    pub(crate) suggestion_ids: Vec<String>,
+    /// Applies every listed suggestion with this operation instead of the suggested one.
+    pub(crate) operation: Option<CutOperation>,
+}
@@ -1,4 +1,4 @@
-use std::fs::{self, OpenOptions};
+use std::fs::{self, File, OpenOptions};
 use std::io::{self, Write};
 #[cfg(unix)]
 use std::os::unix::fs::OpenOptionsExt;
@@ -47,7 +47,8 @@ pub(crate) fn create_directory_all_no_symlink(path: &Path) -> io::Result<()> {
     reject_symlink_components(path)
 }
 
-pub(crate) fn write_file_no_symlink(path: &Path, contents: &[u8]) -> io::Result<()> {
+/// Creates (or truncates by recreating) a regular file without following symlinks.
+pub(crate) fn create_file_no_symlink(path: &Path) -> io::Result<File> {
     if let Some(parent) = path.parent() {
         create_directory_all_no_symlink(parent)?;
     }
@@ -75,12 +76,26 @@ pub(crate) fn write_file_no_symlink(path: &Path, contents: &[u8]) -> io::Result<
     {
         options.custom_flags(libc::O_NOFOLLOW);
     }
-    let mut file = options.open(path)?;
+    options.open(path)
+}
+
+pub(crate) fn write_file_no_symlink(path: &Path, contents: &[u8]) -> io::Result<()> {
+    let mut file = create_file_no_symlink(path)?;
     file.write_all(contents)?;
     file.sync_all()?;
     Ok(())
 }
 
+/// Writes `contents` beside `path` and renames it into place, so readers never see a torn file.
+pub(crate) fn replace_file_no_symlink(path: &Path, contents: &[u8]) -> io::Result<()> {
+    let mut staging_name = path.file_name().unwrap_or_default().to_os_string();
+    staging_name.push(".tmp");
+    let staging_path = path.with_file_name(staging_name);
+    write_file_no_symlink(&staging_path, contents)?;
+    reject_symlink_components(path)?;
+    fs::rename(&staging_path, path)
+}
+
 #[cfg(all(test, unix))]
 mod tests {
     use super::write_file_no_symlink;
@@ -0,0 +1,106 @@
+use crate::params::{PictureInPictureCorner, PictureInPictureParams, PictureInPictureShape};
+use serde::Serialize;
+
+/// Camera placement in export pixels, ready for the compositor.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct PictureInPictureLayout {
+    pub(crate) x: u32,
+    pub(crate) y: u32,
+    pub(crate) width: u32,
+    pub(crate) height: u32,
+    pub(crate) shape: PictureInPictureShape,
+    pub(crate) corner_radius: u32,
+    pub(crate) border_width: u32,
+    pub(crate) border_color: String,
+}
+
+/// Sizes the camera box from the camera aspect ratio (square for circles), shrinking it to
+/// fit the output height, and pins it to the configured corner.
+pub(crate) fn layout(
+    settings: &PictureInPictureParams,
+    output: (u32, u32),
+    camera: (u32, u32),
+) -> PictureInPictureLayout {
+    let (output_width, output_height) = (output.0.max(1) as f64, output.1.max(1) as f64);
+    let aspect = match settings.shape {
+        PictureInPictureShape::Circle => 1.0,
+        _ => camera.0.max(1) as f64 / camera.1.max(1) as f64,
+    };
+    let margin = (settings.margin_fraction * output_width).round();
+    let mut width = settings.size_fraction * output_width;
+    let mut height = width / aspect;
+    let max_height = (output_height - 2.0 * margin).max(1.0);
+    if height > max_height {
+        height = max_height;
+        width = height * aspect;
+    }
+    let (width, height) = (width.round().max(1.0), height.round().max(1.0));
+    let x = match settings.corner {
+        PictureInPictureCorner::TopLeft | PictureInPictureCorner::BottomLeft => margin,
+        _ => output_width - margin - width,
+    };
+    let y = match settings.corner {
+        PictureInPictureCorner::TopLeft | PictureInPictureCorner::TopRight => margin,
+        _ => output_height - margin - height,
+    };
+    let corner_radius = match settings.shape {
+        PictureInPictureShape::Rectangle => 0.0,
+        PictureInPictureShape::RoundedRectangle => (width.min(height) * 0.12).round(),
+        PictureInPictureShape::Circle => (width / 2.0).floor(),
+    };
+    PictureInPictureLayout {
+        x: x.max(0.0) as u32,
+        y: y.max(0.0) as u32,
+        width: width as u32,
+        height: height as u32,
+        shape: settings.shape,
+        corner_radius: corner_radius as u32,
+        border_width: (settings.border_width_fraction * output_width).round() as u32,
+        border_color: settings.border_color.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::layout;
+    use crate::params::{PictureInPictureCorner, PictureInPictureParams, PictureInPictureShape};
+
+    #[test]
+    fn layout_pins_camera_box_to_corner_with_camera_aspect() {
+        let settings = PictureInPictureParams::default();
+        let placed = layout(&settings, (1920, 1080), (640, 480));
+        assert_eq!(
+            (placed.x, placed.y, placed.width, placed.height),
+            (1450, 715, 422, 317)
+        );
+        assert_eq!(placed.corner_radius, 38);
+        assert_eq!(placed.border_width, 4);
+
+        let circle = PictureInPictureParams {
+            corner: PictureInPictureCorner::TopLeft,
+            shape: PictureInPictureShape::Circle,
+            ..settings
+        };
+        let placed = layout(&circle, (1920, 1080), (640, 480));
+        assert_eq!(
+            (placed.x, placed.y, placed.width, placed.height),
+            (48, 48, 422, 422)
+        );
+        assert_eq!(placed.corner_radius, 211);
+    }
+
+    #[test]
+    fn layout_shrinks_portrait_cameras_to_fit_the_output_height() {
+        let settings = PictureInPictureParams {
+            size_fraction: 0.5,
+            shape: PictureInPictureShape::Rectangle,
+            ..PictureInPictureParams::default()
+        };
+        let placed = layout(&settings, (1920, 1080), (1080, 1920));
+        assert_eq!(placed.height, 984);
+        assert_eq!(placed.width, 554);
+        assert_eq!(placed.y, 48);
+        assert_eq!(placed.corner_radius, 0);
+    }
+}
@@ -0,0 +1,498 @@
+use crate::recording::{RecordingMetadata, RecordingWindow};
+use crate::recording_journal::JournaledRecordingWriter;
+use crate::telemetry::{CaptureTelemetrySnapshot, CaptureTelemetryStore, WriterAppendOutcome};
+use std::io;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, SyncSender, TrySendError};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+/// Frames waiting for the writer before the capture stage starts dropping them.
+const RECORD_QUEUE_CAPACITY: usize = 8;
+/// Synthetic frames are rendered at proxy resolution; metadata keeps the real content size.
+const PROXY_FRAME_MAX_WIDTH: u32 = 320;
+
+#[derive(Debug)]
+pub(crate) struct Frame {
+    pub(crate) captured_at: Instant,
+    /// Packed RGB8 pixels, row-major.
+    pub(crate) pixels: Vec<u8>,
+}
+
+pub(crate) trait FrameSource: Send {
+    /// Size of the packed RGB8 frames returned by `capture`.
+    fn dimensions(&self) -> (u32, u32);
+    fn capture(&mut self, index: u64) -> Result<Vec<u8>, String>;
+}
+
+/// Everything the writer stage consumes, in capture order, so control changes apply between
+/// frames and never reorder them.
+enum WriterMessage {
+    Frame(Frame),
+    Begin(Box<JournaledRecordingWriter>),
+    Metadata(RecordingMetadata),
+    Finish(mpsc::Sender<Option<Box<JournaledRecordingWriter>>>),
+}
+
+/// Why the writer stage stopped appending frames to the current recording.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum WriterFailure {
+    OutOfDiskSpace,
+    Io(String),
+}
+
+impl From<io::Error> for WriterFailure {
+    fn from(error: io::Error) -> Self {
+        match error.kind() {
+            io::ErrorKind::StorageFull => Self::OutOfDiskSpace,
+            _ => Self::Io(error.to_string()),
+        }
+    }
+}
+
+/// Pixel rectangle on a capture surface (display or window), in surface coordinates.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct SurfaceRect {
+    pub(crate) x: u32,
+    pub(crate) y: u32,
+    pub(crate) width: u32,
+    pub(crate) height: u32,
+}
+
+/// Deterministic moving test pattern used until platform grabbers land.
+pub(crate) struct SyntheticFrameSource {
+    surface_width: u32,
+    surface_height: u32,
+    region: SurfaceRect,
+    width: u32,
+    height: u32,
+}
+
+impl SyntheticFrameSource {
+    pub(crate) fn new(content_width: u32, content_height: u32) -> Self {
+        Self::region(
+            content_width,
+            content_height,
+            SurfaceRect {
+                x: 0,
+                y: 0,
+                width: content_width,
+                height: content_height,
+            },
+        )
+    }
+
+    /// Renders only `region` of a `surface_width`×`surface_height` surface.
+    pub(crate) fn region(surface_width: u32, surface_height: u32, region: SurfaceRect) -> Self {
+        let (width, height) = proxy_dimensions(region.width, region.height);
+        Self {
+            surface_width: surface_width.max(1),
+            surface_height: surface_height.max(1),
+            region,
+            width,
+            height,
+        }
+    }
+}
+
+impl FrameSource for SyntheticFrameSource {
+    fn dimensions(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+
+    fn capture(&mut self, index: u64) -> Result<Vec<u8>, String> {
+        let (width, height) = (self.width as u64, self.height as u64);
+        let region = self.region;
+        let mut pixels = Vec::with_capacity((width * height * 3) as usize);
+        for y in 0..height {
+            let surface_y = region.y as u64 + y * region.height.max(1) as u64 / height.max(1);
+            for x in 0..width {
+                let surface_x = region.x as u64 + x * region.width.max(1) as u64 / width.max(1);
+                pixels.extend_from_slice(&synthetic_pixel(
+                    surface_x as u32,
+                    surface_y as u32,
+                    index,
+                    self.surface_width,
+                    self.surface_height,
+                ));
+            }
+        }
+        Ok(pixels)
+    }
+}
+
+/// Colour of the synthetic pattern at a surface coordinate: a gradient crossed by a moving bar.
+pub(crate) fn synthetic_pixel(
+    surface_x: u32,
+    surface_y: u32,
+    index: u64,
+    surface_width: u32,
+    surface_height: u32,
+) -> [u8; 3] {
+    let bar_x = (index * 24 % surface_width.max(1) as u64) as u32;
+    if surface_x.abs_diff(bar_x) < 36 {
+        return [240, 240, 240];
+    }
+    [
+        (surface_x as u64 * 255 / surface_width.max(1) as u64) as u8,
+        (surface_y as u64 * 255 / surface_height.max(1) as u64) as u8,
+        48,
+    ]
+}
+
+pub(crate) fn proxy_dimensions(content_width: u32, content_height: u32) -> (u32, u32) {
+    let content_width = content_width.max(1);
+    let content_height = content_height.max(1);
+    if content_width <= PROXY_FRAME_MAX_WIDTH {
+        return (content_width, content_height);
+    }
+    let height =
+        (content_height as u64 * PROXY_FRAME_MAX_WIDTH as u64 / content_width as u64).max(1) as u32;
+    (PROXY_FRAME_MAX_WIDTH, height)
+}
+
+struct PipelineFlags {
+    stop: AtomicBool,
+    recording: AtomicBool,
+    paused: AtomicBool,
+    writer_failure: Mutex<Option<WriterFailure>>,
+    window: Mutex<Option<Arc<RecordingWindow>>>,
+}
+
+impl PipelineFlags {
+    fn admits(&self, captured_at: Instant) -> bool {
+        self.window
+            .lock()
+            .map(|window| {
+                window
+                    .as_ref()
+                    .is_none_or(|window| window.admits(captured_at))
+            })
+            .unwrap_or(true)
+    }
+}
+
+/// Capture stage → bounded record queue → writer stage, each instrumented for telemetry.
+pub(crate) struct CapturePipeline {
+    telemetry: Arc<CaptureTelemetryStore>,
+    flags: Arc<PipelineFlags>,
+    frame_dimensions: (u32, u32),
+    capture_fps: u64,
+    control: Mutex<Option<SyncSender<WriterMessage>>>,
+    threads: Mutex<Vec<JoinHandle<()>>>,
+}
+
+impl CapturePipeline {
+    pub(crate) fn start(source: Box<dyn FrameSource>, capture_fps: u64) -> Self {
+        let telemetry = Arc::new(CaptureTelemetryStore::default());
+        telemetry.reset();
+        let flags = Arc::new(PipelineFlags {
+            stop: AtomicBool::new(false),
+            recording: AtomicBool::new(false),
+            paused: AtomicBool::new(false),
+            writer_failure: Mutex::new(None),
+            window: Mutex::new(None),
+        });
+        let frame_dimensions = source.dimensions();
+        let (sender, receiver) = mpsc::sync_channel::<WriterMessage>(RECORD_QUEUE_CAPACITY);
+        let control = sender.clone();
+
+        let capture_thread = {
+            let telemetry = Arc::clone(&telemetry);
+            let flags = Arc::clone(&flags);
+            thread::spawn(move || run_capture_stage(source, capture_fps, sender, telemetry, flags))
+        };
+        let writer_thread = {
+            let telemetry = Arc::clone(&telemetry);
+            let flags = Arc::clone(&flags);
+            thread::spawn(move || run_writer_stage(receiver, telemetry, flags))
+        };
+
+        Self {
+            telemetry,
+            flags,
+            frame_dimensions,
+            capture_fps,
+            control: Mutex::new(Some(control)),
+            threads: Mutex::new(vec![capture_thread, writer_thread]),
+        }
+    }
+
+    pub(crate) fn frame_dimensions(&self) -> (u32, u32) {
+        self.frame_dimensions
+    }
+
+    pub(crate) fn capture_fps(&self) -> u64 {
+        self.capture_fps
+    }
+
+    /// Starts feeding the writer stage with frames captured inside `window`; without a
+    /// journaled writer frames are only measured.
+    pub(crate) fn begin_recording(
+        &self,
+        writer: Option<JournaledRecordingWriter>,
+        window: Arc<RecordingWindow>,
+    ) {
+        self.telemetry.reset();
+        self.flags.paused.store(false, Ordering::SeqCst);
+        if let Ok(mut failure) = self.flags.writer_failure.lock() {
+            *failure = None;
+        }
+        if let Ok(mut current) = self.flags.window.lock() {
+            *current = Some(window);
+        }
+        if let Some(writer) = writer {
+            self.send_control(WriterMessage::Begin(Box::new(writer)));
+        }
+        self.flags.recording.store(true, Ordering::SeqCst);
+    }
+
+    /// Stops feeding the writer and hands back the journaled writer once queued frames land.
+    pub(crate) fn end_recording(&self) -> Option<JournaledRecordingWriter> {
+        self.flags.recording.store(false, Ordering::SeqCst);
+        self.flags.paused.store(false, Ordering::SeqCst);
+        let (reply, response) = mpsc::channel();
+        if !self.send_control(WriterMessage::Finish(reply)) {
+            return None;
+        }
+        response.recv().ok().flatten().map(|writer| *writer)
+    }
+
+    /// Set by the writer stage once the journaled writer fails to append a frame; the rest of
+    /// the recording is dropped until the caller stops it.
+    pub(crate) fn writer_failure(&self) -> Option<WriterFailure> {
+        self.flags
+            .writer_failure
+            .lock()
+            .ok()
+            .and_then(|failure| failure.clone())
+    }
+
+    pub(crate) fn update_recording_metadata(&self, metadata: RecordingMetadata) {
+        self.send_control(WriterMessage::Metadata(metadata));
+    }
+
+    /// While paused the capture stage keeps running (preview, telemetry) but nothing reaches
+    /// the writer, so the output resumes without a gap.
+    pub(crate) fn set_paused(&self, paused: bool) {
+        self.flags.paused.store(paused, Ordering::SeqCst);
+    }
+
+    pub(crate) fn telemetry(&self, recording_duration_seconds: f64) -> CaptureTelemetrySnapshot {
+        self.telemetry.snapshot(recording_duration_seconds)
+    }
+
+    fn send_control(&self, message: WriterMessage) -> bool {
+        self.control
+            .lock()
+            .ok()
+            .and_then(|control| control.as_ref().map(|sender| sender.send(message).is_ok()))
+            .unwrap_or(false)
+    }
+
+    pub(crate) fn stop(&self) {
+        self.flags.stop.store(true, Ordering::SeqCst);
+        if let Ok(mut control) = self.control.lock() {
+            control.take();
+        }
+        let threads = self
+            .threads
+            .lock()
+            .map(|mut threads| std::mem::take(&mut *threads))
+            .unwrap_or_default();
+        for thread in threads {
+            let _ = thread.join();
+        }
+    }
+}
+
+impl Drop for CapturePipeline {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+fn run_capture_stage(
+    mut source: Box<dyn FrameSource>,
+    capture_fps: u64,
+    sender: SyncSender<WriterMessage>,
+    telemetry: Arc<CaptureTelemetryStore>,
+    flags: Arc<PipelineFlags>,
+) {
+    let interval = Duration::from_secs_f64(1.0 / capture_fps.max(1) as f64);
+    let started_at = Instant::now();
+    let mut index = 0u64;
+    while !flags.stop.load(Ordering::SeqCst) {
+        let deadline = started_at + interval.mul_f64(index as f64);
+        let now = Instant::now();
+        if deadline > now {
+            thread::sleep(deadline - now);
+        }
+
+        let callback_started_at = Instant::now();
+        let pixels = source.capture(index);
+        let pts_seconds = callback_started_at.duration_since(started_at).as_secs_f64();
+        match pixels {
+            Ok(pixels) => {
+                telemetry.record_complete_frame(pts_seconds, capture_fps);
+                if flags.recording.load(Ordering::SeqCst)
+                    && !flags.paused.load(Ordering::SeqCst)
+                    && flags.admits(callback_started_at)
+                {
+                    let frame = Frame {
+                        captured_at: Instant::now(),
+                        pixels,
+                    };
+                    match sender.try_send(WriterMessage::Frame(frame)) {
+                        Ok(()) => {}
+                        Err(TrySendError::Full(_)) => telemetry.record_writer_append(
+                            WriterAppendOutcome::DroppedBackpressure,
+                            0.0,
+                            0,
+                        ),
+                        Err(TrySendError::Disconnected(_)) => break,
+                    }
+                }
+            }
+            Err(_) => telemetry.record_source_drop(),
+        }
+        telemetry.record_capture_callback(callback_started_at.elapsed().as_secs_f64() * 1_000.0);
+
+        // Resynchronise to the next slot instead of bursting to catch up after a stall.
+        let elapsed_slots = (started_at.elapsed().as_secs_f64() / interval.as_secs_f64()) as u64;
+        index = (index + 1).max(elapsed_slots);
+    }
+}
+
+fn run_writer_stage(
+    receiver: Receiver<WriterMessage>,
+    telemetry: Arc<CaptureTelemetryStore>,
+    flags: Arc<PipelineFlags>,
+) {
+    let mut writer: Option<Box<JournaledRecordingWriter>> = None;
+    let mut failed = false;
+    while let Ok(message) = receiver.recv() {
+        match message {
+            WriterMessage::Frame(frame) => {
+                telemetry.record_queue_lag(frame.captured_at.elapsed().as_secs_f64() * 1_000.0);
+                let append_started_at = Instant::now();
+                let (outcome, appended_bytes) = match writer.as_mut() {
+                    Some(_) if failed => (WriterAppendOutcome::Failed, 0),
+                    Some(writer) => match writer.append(&frame.pixels) {
+                        Ok(bytes) => (WriterAppendOutcome::Appended, bytes),
+                        Err(error) => {
+                            failed = true;
+                            if let Ok(mut failure) = flags.writer_failure.lock() {
+                                *failure = Some(WriterFailure::from(error));
+                            }
+                            (WriterAppendOutcome::Failed, 0)
+                        }
+                    },
+                    // Sessions without a project have nowhere to persist; account for the payload.
+                    None => (WriterAppendOutcome::Appended, frame.pixels.len() as u64),
+                };
+                telemetry.record_writer_append(
+                    outcome,
+                    append_started_at.elapsed().as_secs_f64() * 1_000.0,
+                    appended_bytes,
+                );
+            }
+            WriterMessage::Begin(next) => {
+                writer = Some(next);
+                failed = false;
+            }
+            WriterMessage::Metadata(metadata) => {
+                if let Some(writer) = writer.as_mut() {
+                    let _ = writer.update_metadata(metadata);
+                }
+            }
+            WriterMessage::Finish(reply) => {
+                let _ = reply.send(writer.take());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        proxy_dimensions, synthetic_pixel, CapturePipeline, FrameSource, SurfaceRect,
+        SyntheticFrameSource, WriterFailure,
+    };
+    use crate::disk::RecordingDiskBudget;
+    use crate::recording::RecordingWindow;
+    use crate::recording_journal::JournaledRecordingWriter;
+    use std::sync::Arc;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn proxy_dimensions_preserve_aspect_ratio() {
+        assert_eq!(proxy_dimensions(1920, 1080), (320, 180));
+        assert_eq!(proxy_dimensions(1280, 720), (320, 180));
+        assert_eq!(proxy_dimensions(200, 100), (200, 100));
+        assert_eq!(proxy_dimensions(0, 0), (1, 1));
+    }
+
+    #[test]
+    fn synthetic_source_renders_packed_rgb_frames_that_change_over_time() {
+        let mut source = SyntheticFrameSource::new(1920, 1080);
+        let first = source.capture(0).expect("first frame");
+        let second = source.capture(10).expect("second frame");
+        assert_eq!(first.len(), 320 * 180 * 3);
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn synthetic_region_samples_the_surface_pattern_at_region_offsets() {
+        let region = SurfaceRect {
+            x: 600,
+            y: 300,
+            width: 200,
+            height: 100,
+        };
+        let mut source = SyntheticFrameSource::region(1920, 1080, region);
+        let frame = source.capture(3).expect("region frame");
+        assert_eq!(frame.len(), 200 * 100 * 3);
+        for (x, y) in [(0, 0), (199, 0), (57, 42), (199, 99)] {
+            let offset = ((y * 200 + x) * 3) as usize;
+            assert_eq!(
+                frame[offset..offset + 3],
+                synthetic_pixel(600 + x, 300 + y, 3, 1920, 1080)
+            );
+        }
+    }
+
+    #[test]
+    fn writer_failures_other_than_running_out_of_space_are_reported() {
+        let root = std::env::temp_dir().join(format!("gglass-pipeline-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        let pipeline = CapturePipeline::start(Box::new(SyntheticFrameSource::new(4, 2)), 30);
+        // Frames from the 4×2 source never match a 2×2 writer, so every append fails.
+        let disk_budget = Arc::new(RecordingDiskBudget::new(&root, |_| None));
+        let writer = JournaledRecordingWriter::create(&root, "take-1", 2, 2, 30, disk_budget)
+            .expect("create writer");
+        pipeline.begin_recording(
+            Some(writer),
+            Arc::new(RecordingWindow::new(Instant::now(), None)),
+        );
+        let deadline = Instant::now() + Duration::from_secs(2);
+        while pipeline.writer_failure().is_none() && Instant::now() < deadline {
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        assert_eq!(
+            pipeline.writer_failure(),
+            Some(WriterFailure::Io(
+                "frame has 24 bytes, expected 12".to_string()
+            ))
+        );
+        let finalized = pipeline
+            .end_recording()
+            .expect("writer handed back")
+            .finalize(Default::default())
+            .expect("finalize");
+        assert_eq!(finalized.frame_count, 0);
+        pipeline.stop();
+        let _ = std::fs::remove_dir_all(&root);
+    }
+}
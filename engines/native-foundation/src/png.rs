@@ -0,0 +1,108 @@
+//! Minimal PNG encoder for stills the engine writes itself: 8-bit RGB, no interlacing, and
+//! stored (uncompressed) deflate blocks, so no compression library is needed.
+
+const SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+/// Largest payload of one stored deflate block.
+const MAX_STORED_BLOCK: usize = 65_535;
+
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = 0xFFFF_FFFFu32;
+    for byte in bytes {
+        crc ^= u32::from(*byte);
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+fn adler32(bytes: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for byte in bytes {
+        a = (a + u32::from(*byte)) % 65_521;
+        b = (b + a) % 65_521;
+    }
+    (b << 16) | a
+}
+
+fn push_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    let start = png.len();
+    png.extend_from_slice(kind);
+    png.extend_from_slice(data);
+    let crc = crc32(&png[start..]);
+    png.extend_from_slice(&crc.to_be_bytes());
+}
+
+/// zlib stream of `bytes` in stored blocks.
+fn zlib_stored(bytes: &[u8]) -> Vec<u8> {
+    let block_count = bytes.len().div_ceil(MAX_STORED_BLOCK).max(1);
+    let mut stream = Vec::with_capacity(bytes.len() + block_count * 5 + 6);
+    stream.extend_from_slice(&[0x78, 0x01]);
+    let mut blocks = bytes.chunks(MAX_STORED_BLOCK).peekable();
+    if blocks.peek().is_none() {
+        stream.extend_from_slice(&[1, 0, 0, 0xFF, 0xFF]);
+    }
+    while let Some(block) = blocks.next() {
+        let length = block.len() as u16;
+        stream.push(u8::from(blocks.peek().is_none()));
+        stream.extend_from_slice(&length.to_le_bytes());
+        stream.extend_from_slice(&(!length).to_le_bytes());
+        stream.extend_from_slice(block);
+    }
+    stream.extend_from_slice(&adler32(bytes).to_be_bytes());
+    stream
+}
+
+/// Encodes packed RGB8 `pixels`, row-major, as a PNG file.
+pub(crate) fn encode_rgb(width: u32, height: u32, pixels: &[u8]) -> Vec<u8> {
+    let row_bytes = width as usize * 3;
+    let mut scanlines = Vec::with_capacity((row_bytes + 1) * height as usize);
+    for row in pixels.chunks_exact(row_bytes.max(1)).take(height as usize) {
+        // Filter type 0: the row is stored as is.
+        scanlines.push(0);
+        scanlines.extend_from_slice(row);
+    }
+    let mut header = Vec::with_capacity(13);
+    header.extend_from_slice(&width.to_be_bytes());
+    header.extend_from_slice(&height.to_be_bytes());
+    // 8 bits per channel, truecolor, deflate, adaptive filtering, no interlace.
+    header.extend_from_slice(&[8, 2, 0, 0, 0]);
+
+    let mut png = SIGNATURE.to_vec();
+    push_chunk(&mut png, b"IHDR", &header);
+    push_chunk(&mut png, b"IDAT", &zlib_stored(&scanlines));
+    push_chunk(&mut png, b"IEND", &[]);
+    png
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{adler32, crc32, encode_rgb};
+
+    #[test]
+    fn checksums_match_reference_values() {
+        assert_eq!(crc32(b"IEND"), 0xAE42_6082);
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+        assert_eq!(adler32(b"Wikipedia"), 0x11E6_0398);
+    }
+
+    #[test]
+    fn encodes_rows_as_unfiltered_stored_scanlines() {
+        let png = encode_rgb(2, 1, &[255, 0, 0, 0, 0, 255]);
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+        assert_eq!(&png[12..16], b"IHDR");
+        assert_eq!(&png[16..29], &[0, 0, 0, 2, 0, 0, 0, 1, 8, 2, 0, 0, 0]);
+        assert_eq!(&png[37..41], b"IDAT");
+        // zlib header, one final stored block of 7 bytes, the scanline, then the checksum.
+        assert_eq!(
+            &png[41..59],
+            &[0x78, 0x01, 1, 7, 0, 0xF8, 0xFF, 0, 255, 0, 0, 0, 0, 255, 0x07, 0x00, 0x01, 0xFF]
+        );
+        assert_eq!(&png[png.len() - 12..], b"\0\0\0\0IEND\xAE\x42\x60\x82");
+    }
+}
@@ -0,0 +1,429 @@
+use crate::params::{PrivacyMaskStyle, WindowExclusionParams, WindowExclusionRule};
+use crate::pipeline::{FrameSource, SurfaceRect};
+use serde_json::{json, Value};
+use std::sync::{Arc, Mutex};
+
+/// Side of the mosaic cells drawn by the blur style, in frame pixels.
+const BLUR_CELL_SIZE: u32 = 8;
+
+/// A top-level window as currently shown, with its frame in root (display) coordinates.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct WindowInfo {
+    pub(crate) id: u64,
+    pub(crate) title: String,
+    /// `WM_CLASS` instance and class names.
+    pub(crate) wm_class: Vec<String>,
+    pub(crate) x: i32,
+    pub(crate) y: i32,
+    pub(crate) width: u32,
+    pub(crate) height: u32,
+}
+
+/// Returns the windows currently mapped on screen, or why they cannot be located.
+pub(crate) type WindowProbe = fn() -> Result<Vec<WindowInfo>, String>;
+
+pub(crate) fn available_windows() -> Result<Vec<WindowInfo>, String> {
+    #[cfg(target_os = "linux")]
+    {
+        crate::x11_windows::list_windows()
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        Err("windows cannot be located on this platform".to_string())
+    }
+}
+
+/// Case-insensitive glob match supporting `*` (any run) and `?` (any one character).
+pub(crate) fn glob_matches(pattern: &str, text: &str) -> bool {
+    let pattern = pattern.to_lowercase().chars().collect::<Vec<_>>();
+    let text = text.to_lowercase().chars().collect::<Vec<_>>();
+    let (mut p, mut t) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+    while t < text.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == text[t]) {
+            p += 1;
+            t += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            backtrack = Some((p, t));
+            p += 1;
+        } else if let Some((star, matched)) = backtrack {
+            p = star + 1;
+            t = matched + 1;
+            backtrack = Some((star, matched + 1));
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|character| *character == '*')
+}
+
+pub(crate) fn rule_matches(rule: &WindowExclusionRule, window: &WindowInfo) -> bool {
+    rule.window_id.is_none_or(|id| id == window.id)
+        && rule
+            .title_pattern
+            .as_deref()
+            .is_none_or(|pattern| glob_matches(pattern, &window.title))
+        && rule.wm_class.as_deref().is_none_or(|class| {
+            window
+                .wm_class
+                .iter()
+                .any(|name| name.eq_ignore_ascii_case(class))
+        })
+}
+
+/// Windows matching any rule, clipped to `region` and returned relative to its origin.
+pub(crate) fn masked_windows(
+    rules: &[WindowExclusionRule],
+    windows: &[WindowInfo],
+    region: SurfaceRect,
+) -> Vec<(u64, SurfaceRect)> {
+    windows
+        .iter()
+        .filter(|window| rules.iter().any(|rule| rule_matches(rule, window)))
+        .filter_map(|window| {
+            let left = (window.x as i64).max(region.x as i64);
+            let top = (window.y as i64).max(region.y as i64);
+            let right =
+                (window.x as i64 + window.width as i64).min(region.x as i64 + region.width as i64);
+            let bottom = (window.y as i64 + window.height as i64)
+                .min(region.y as i64 + region.height as i64);
+            (left < right && top < bottom).then(|| {
+                (
+                    window.id,
+                    SurfaceRect {
+                        x: (left - region.x as i64) as u32,
+                        y: (top - region.y as i64) as u32,
+                        width: (right - left) as u32,
+                        height: (bottom - top) as u32,
+                    },
+                )
+            })
+        })
+        .collect()
+}
+
+/// Scales a region-relative rect to frame pixels, rounding outwards so no edge leaks.
+fn frame_rect(rect: SurfaceRect, region: SurfaceRect, frame: (u32, u32)) -> SurfaceRect {
+    let scale = |value: u32, frame_size: u32, region_size: u32, round_up: bool| {
+        let scaled = value as u64 * frame_size as u64;
+        let region_size = region_size.max(1) as u64;
+        let value = if round_up {
+            scaled.div_ceil(region_size)
+        } else {
+            scaled / region_size
+        };
+        (value as u32).min(frame_size)
+    };
+    let left = scale(rect.x, frame.0, region.width, false);
+    let top = scale(rect.y, frame.1, region.height, false);
+    let right = scale(rect.x + rect.width, frame.0, region.width, true);
+    let bottom = scale(rect.y + rect.height, frame.1, region.height, true);
+    SurfaceRect {
+        x: left,
+        y: top,
+        width: right.saturating_sub(left),
+        height: bottom.saturating_sub(top),
+    }
+}
+
+/// Covers `rect` of a packed RGB8 frame with `fill`, or with a coarse mosaic that keeps the
+/// window's colours but none of its text.
+pub(crate) fn apply_mask(
+    pixels: &mut [u8],
+    frame: (u32, u32),
+    rect: SurfaceRect,
+    style: PrivacyMaskStyle,
+    fill: [u8; 3],
+) {
+    let (width, height) = (frame.0 as usize, frame.1 as usize);
+    let right = ((rect.x + rect.width) as usize).min(width);
+    let bottom = ((rect.y + rect.height) as usize).min(height);
+    let (left, top) = (rect.x as usize, rect.y as usize);
+    if pixels.len() < width * height * 3 || left >= right || top >= bottom {
+        return;
+    }
+    let cell = match style {
+        PrivacyMaskStyle::SolidFill => right.max(bottom),
+        PrivacyMaskStyle::Blur => BLUR_CELL_SIZE as usize,
+    };
+    for cell_top in (top..bottom).step_by(cell) {
+        let cell_bottom = (cell_top + cell).min(bottom);
+        for cell_left in (left..right).step_by(cell) {
+            let cell_right = (cell_left + cell).min(right);
+            let color = match style {
+                PrivacyMaskStyle::SolidFill => fill,
+                PrivacyMaskStyle::Blur => {
+                    let mut sums = [0u64; 3];
+                    for y in cell_top..cell_bottom {
+                        for x in cell_left..cell_right {
+                            let offset = (y * width + x) * 3;
+                            for channel in 0..3 {
+                                sums[channel] += pixels[offset + channel] as u64;
+                            }
+                        }
+                    }
+                    let count = ((cell_bottom - cell_top) * (cell_right - cell_left)) as u64;
+                    sums.map(|sum| (sum / count) as u8)
+                }
+            };
+            for y in cell_top..cell_bottom {
+                for x in cell_left..cell_right {
+                    let offset = (y * width + x) * 3;
+                    pixels[offset..offset + 3].copy_from_slice(&color);
+                }
+            }
+        }
+    }
+}
+
+fn parse_fill_color(color: &str) -> [u8; 3] {
+    let channel = |range: std::ops::Range<usize>| {
+        color
+            .get(range)
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+            .unwrap_or(0)
+    };
+    [channel(1..3), channel(3..5), channel(5..7)]
+}
+
+/// Exclusions applied to the running capture, the windows masked in the latest frame, and why
+/// masking last failed.
+#[derive(Clone, Debug)]
+pub(crate) struct PrivacyMask {
+    pub(crate) settings: WindowExclusionParams,
+    masked_window_ids: Arc<Mutex<Vec<u64>>>,
+    failure: Arc<Mutex<Option<String>>>,
+}
+
+impl PrivacyMask {
+    /// Why excluded windows could not be located, once; frames are withheld while it persists.
+    pub(crate) fn take_failure(&self) -> Option<String> {
+        self.failure
+            .lock()
+            .ok()
+            .and_then(|mut failure| failure.take())
+    }
+
+    pub(crate) fn status(&self) -> Value {
+        let masked_window_ids = self
+            .masked_window_ids
+            .lock()
+            .map(|ids| ids.clone())
+            .unwrap_or_default();
+        json!({
+            "ruleCount": self.settings.rules.len(),
+            "maskStyle": self.settings.mask_style,
+            "maskedWindowIds": masked_window_ids,
+        })
+    }
+}
+
+/// Masks excluded windows in every frame of a display or region capture. `region` is the
+/// captured part of the display in root coordinates. Window geometry is re-read for every frame,
+/// and a frame whose windows cannot be located is withheld rather than recorded unmasked.
+pub(crate) struct MaskedFrameSource {
+    inner: Box<dyn FrameSource>,
+    settings: WindowExclusionParams,
+    fill: [u8; 3],
+    windows: WindowProbe,
+    region: SurfaceRect,
+    masks: Vec<SurfaceRect>,
+    masked_window_ids: Arc<Mutex<Vec<u64>>>,
+    failure: Arc<Mutex<Option<String>>>,
+}
+
+impl MaskedFrameSource {
+    pub(crate) fn new(
+        inner: Box<dyn FrameSource>,
+        settings: WindowExclusionParams,
+        windows: WindowProbe,
+        region: SurfaceRect,
+    ) -> (Self, PrivacyMask) {
+        let masked_window_ids = Arc::new(Mutex::new(Vec::new()));
+        let failure = Arc::new(Mutex::new(None));
+        let mask = PrivacyMask {
+            settings: settings.clone(),
+            masked_window_ids: Arc::clone(&masked_window_ids),
+            failure: Arc::clone(&failure),
+        };
+        let source = Self {
+            inner,
+            fill: parse_fill_color(&settings.fill_color),
+            settings,
+            windows,
+            region,
+            masks: Vec::new(),
+            masked_window_ids,
+            failure,
+        };
+        (source, mask)
+    }
+
+    fn refresh_masks(&mut self) -> Result<(), String> {
+        let windows = (self.windows)().map_err(|error| {
+            let message = format!("Excluded windows could not be located: {error}");
+            if let Ok(mut failure) = self.failure.lock() {
+                *failure = Some(message.clone());
+            }
+            message
+        })?;
+        let frame = self.inner.dimensions();
+        let matched = masked_windows(&self.settings.rules, &windows, self.region);
+        self.masks = matched
+            .iter()
+            .map(|(_, rect)| frame_rect(*rect, self.region, frame))
+            .collect();
+        if let Ok(mut ids) = self.masked_window_ids.lock() {
+            *ids = matched.into_iter().map(|(id, _)| id).collect();
+        }
+        Ok(())
+    }
+}
+
+impl FrameSource for MaskedFrameSource {
+    fn dimensions(&self) -> (u32, u32) {
+        self.inner.dimensions()
+    }
+
+    fn capture(&mut self, index: u64) -> Result<Vec<u8>, String> {
+        self.refresh_masks()?;
+        let mut pixels = self.inner.capture(index)?;
+        let frame = self.inner.dimensions();
+        for rect in &self.masks {
+            apply_mask(
+                &mut pixels,
+                frame,
+                *rect,
+                self.settings.mask_style,
+                self.fill,
+            );
+        }
+        Ok(pixels)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{apply_mask, glob_matches, masked_windows, WindowInfo};
+    use crate::params::{PrivacyMaskStyle, WindowExclusionRule};
+    use crate::pipeline::SurfaceRect;
+
+    fn window(id: u64, title: &str, wm_class: &[&str], x: i32, y: i32) -> WindowInfo {
+        WindowInfo {
+            id,
+            title: title.to_string(),
+            wm_class: wm_class.iter().map(|name| name.to_string()).collect(),
+            x,
+            y,
+            width: 400,
+            height: 300,
+        }
+    }
+
+    #[test]
+    fn title_patterns_are_case_insensitive_globs() {
+        assert!(glob_matches("*password*", "KeePassXC - Passwords.kdbx"));
+        assert!(glob_matches("slack | ?eneral*", "Slack | general | Acme"));
+        assert!(glob_matches("*", ""));
+        assert!(!glob_matches("slack*", "Chat - Slack"));
+        assert!(!glob_matches("a?c", "ac"));
+    }
+
+    #[test]
+    fn rules_match_by_id_title_or_wm_class_and_clip_to_the_region() {
+        let windows = [
+            window(7, "Vault", &["keepassxc", "KeePassXC"], -100, 50),
+            window(8, "general | Slack", &["slack", "Slack"], 1700, 900),
+            window(9, "Terminal", &["xterm", "XTerm"], 10, 10),
+        ];
+        let rules = [
+            WindowExclusionRule {
+                wm_class: Some("KEEPASSXC".to_string()),
+                ..WindowExclusionRule::default()
+            },
+            WindowExclusionRule {
+                window_id: Some(8),
+                title_pattern: Some("*slack".to_string()),
+                ..WindowExclusionRule::default()
+            },
+        ];
+        let region = SurfaceRect {
+            x: 0,
+            y: 0,
+            width: 1920,
+            height: 1080,
+        };
+        let masked = masked_windows(&rules, &windows, region);
+        assert_eq!(
+            masked,
+            vec![
+                (
+                    7,
+                    SurfaceRect {
+                        x: 0,
+                        y: 50,
+                        width: 300,
+                        height: 300
+                    }
+                ),
+                (
+                    8,
+                    SurfaceRect {
+                        x: 1700,
+                        y: 900,
+                        width: 220,
+                        height: 180
+                    }
+                ),
+            ]
+        );
+        let corner = SurfaceRect {
+            x: 1000,
+            y: 0,
+            width: 500,
+            height: 500,
+        };
+        assert!(masked_windows(&rules, &windows, corner).is_empty());
+    }
+
+    #[test]
+    fn blur_mask_averages_cells_and_fill_mask_paints_the_colour() {
+        let frame = (16, 8);
+        let mut pixels = (0..16 * 8)
+            .flat_map(|index| {
+                let value = if index % 2 == 0 { 0 } else { 200 };
+                [value, value, value]
+            })
+            .collect::<Vec<u8>>();
+        let left_half = SurfaceRect {
+            x: 0,
+            y: 0,
+            width: 8,
+            height: 8,
+        };
+        apply_mask(
+            &mut pixels,
+            frame,
+            left_half,
+            PrivacyMaskStyle::Blur,
+            [0; 3],
+        );
+        assert!(pixels[..3].iter().all(|value| *value == 100));
+        assert_eq!(&pixels[24..27], &[0, 0, 0]);
+        assert_eq!(&pixels[27..30], &[200, 200, 200]);
+
+        let right_half = SurfaceRect { x: 8, ..left_half };
+        apply_mask(
+            &mut pixels,
+            frame,
+            right_half,
+            PrivacyMaskStyle::SolidFill,
+            [255, 0, 128],
+        );
+        assert_eq!(&pixels[24..27], &[255, 0, 128]);
+        assert_eq!(&pixels[(16 * 7 + 15) * 3..], &[255, 0, 128]);
+        assert!(pixels[(16 * 7 + 7) * 3..(16 * 7 + 8) * 3]
+            .iter()
+            .all(|value| *value == 100));
+    }
+}
@@ -1,16 +1,30 @@
+use crate::analysis::{VisualAnalysis, VISUAL_ANALYSIS_VERSION};
+use crate::audio::AudioTrack;
+use crate::camera_keyframes::CameraKeyframeTrack;
+use crate::cameras::CameraTrack;
+use crate::params::TimelineParams;
 use crate::params::{
-    BackgroundFramingParams, ProjectOpenParams, ProjectRecentsParams, ProjectSaveParams,
+    resolve_settings, ProjectOpenParams, ProjectRecentsParams, ProjectSaveParams, SettingsGroup,
 };
 use crate::path_security::{
     create_directory_all_no_symlink, reject_final_symlink, write_file_no_symlink,
 };
-use crate::state::{record_recent_project, State};
-use crate::wire::{failure, success, EngineCallId, EngineResponse, ProtocolErrorCode};
+use crate::recording::RecordingMetadata;
+use crate::recording_journal::RecordingFrames;
+use crate::state::{now_iso8601, record_recent_project, State};
+use crate::takes::{self, Take};
+use crate::timeline::{check_take_references, TimelineItem};
+use crate::wire::{
+    decode_params, failure, success, EngineCallId, EngineResponse, ProtocolErrorCode,
+};
 use crate::DEFAULT_RECENTS_LIMIT;
 use serde_json::{json, Value};
 use std::fs;
 use std::path::{Path, PathBuf};
 
+/// Id of the take migrated from a snapshot saved before projects kept takes.
+const LEGACY_TAKE_ID: &str = "legacy";
+
 fn validate_project_path(id: &EngineCallId, project_path: &str) -> Result<(), EngineResponse> {
     let path = Path::new(project_path);
     if !path.is_absolute() {
@@ -37,31 +51,174 @@ fn validate_project_path(id: &EngineCallId, project_path: &str) -> Result<(), En
     Ok(())
 }
 
-fn load_background_framing(project_path: &str) -> Result<BackgroundFramingParams, String> {
+fn read_project_snapshot(project_path: &str) -> Result<Option<Value>, String> {
     let snapshot_path = Path::new(project_path).join("project.native.json");
     if !snapshot_path.exists() {
-        return Ok(BackgroundFramingParams::default());
+        return Ok(None);
     }
     reject_final_symlink(&snapshot_path)
         .map_err(|error| format!("Project snapshot failed symlink safety validation: {error}"))?;
     let data = fs::read_to_string(&snapshot_path)
         .map_err(|error| format!("Unable to read project snapshot: {error}"))?;
-    let snapshot: Value = serde_json::from_str(&data)
-        .map_err(|error| format!("Unable to decode project snapshot: {error}"))?;
-    let Some(value) = snapshot.get("backgroundFraming") else {
-        return Ok(BackgroundFramingParams::default());
+    serde_json::from_str(&data)
+        .map(Some)
+        .map_err(|error| format!("Unable to decode project snapshot: {error}"))
+}
+
+/// Reads a settings group from a snapshot; `None` when the snapshot does not carry it.
+fn load_settings<T: SettingsGroup>(snapshot: Option<&Value>) -> Result<Option<T>, String> {
+    let Some(value) = snapshot
+        .and_then(|snapshot| snapshot.get(T::KEY))
+        .filter(|value| !value.is_null())
+    else {
+        return Ok(None);
+    };
+    serde_json::from_value::<T>(value.clone())
+        .map_err(|error| format!("Invalid {} settings: {error}", T::KEY))?
+        .validated()
+        .map(Some)
+        .map_err(str::to_string)
+}
+
+/// Replaces every settings group with the snapshot's, falling back to defaults for the groups it
+/// does not carry. Projects without their own exclusions follow the library defaults.
+fn load_settings_groups(snapshot: Option<&Value>, state: &mut State) -> Result<(), String> {
+    state.background_framing = load_settings(snapshot)?.unwrap_or_default();
+    state.shortcut_overlay = load_settings(snapshot)?.unwrap_or_default();
+    state.cursor_rendering = load_settings(snapshot)?.unwrap_or_default();
+    state.click_effects = load_settings(snapshot)?.unwrap_or_default();
+    state.picture_in_picture = load_settings(snapshot)?.unwrap_or_default();
+    state.reframe = load_settings(snapshot)?.unwrap_or_default();
+    state.window_exclusions = load_settings(snapshot)?;
+    Ok(())
+}
+
+/// Replaces the settings groups a save request carries, validating each first.
+fn apply_settings_groups(
+    state: &mut State,
+    params: &mut ProjectSaveParams,
+) -> Result<(), &'static str> {
+    state.background_framing =
+        resolve_settings(params.background_framing.take(), &state.background_framing)?;
+    state.shortcut_overlay =
+        resolve_settings(params.shortcut_overlay.take(), &state.shortcut_overlay)?;
+    state.cursor_rendering =
+        resolve_settings(params.cursor_rendering.take(), &state.cursor_rendering)?;
+    state.click_effects = resolve_settings(params.click_effects.take(), &state.click_effects)?;
+    state.picture_in_picture =
+        resolve_settings(params.picture_in_picture.take(), &state.picture_in_picture)?;
+    state.reframe = resolve_settings(params.reframe.take(), &state.reframe)?;
+    if let Some(window_exclusions) = params.window_exclusions.take() {
+        state.window_exclusions = Some(window_exclusions.validated()?);
+    }
+    Ok(())
+}
+
+/// Snapshots saved before projects kept takes carry their one recording at the top level; it is
+/// migrated into a take that opens as the active one.
+fn load_legacy_take(snapshot: Option<&Value>) -> Result<Option<Take>, String> {
+    let Some(snapshot) = snapshot else {
+        return Ok(None);
+    };
+    let Some(recording_url) = snapshot
+        .get("recordingURL")
+        .and_then(Value::as_str)
+        .filter(|url| !url.is_empty())
+    else {
+        return Ok(None);
+    };
+    let field = |key: &str| snapshot.get(key).cloned().unwrap_or(Value::Null);
+    let camera_track = serde_json::from_value::<Option<CameraTrack>>(field("cameraTrack"))
+        .map_err(|error| format!("Invalid cameraTrack: {error}"))?;
+    let audio_tracks = serde_json::from_value::<Option<Vec<AudioTrack>>>(field("audioTracks"))
+        .map_err(|error| format!("Invalid audioTracks: {error}"))?
+        .unwrap_or_default();
+    let duration_seconds = RecordingFrames::open(Path::new(recording_url))
+        .and_then(|frames| Ok(frames.frame_count()? as f64 / frames.fps))
+        .unwrap_or(0.0);
+    Ok(Some(Take {
+        id: LEGACY_TAKE_ID.to_string(),
+        name: "Take 1".to_string(),
+        recording_url: recording_url.to_string(),
+        camera_track,
+        audio_tracks,
+        events_url: snapshot
+            .get("eventsURL")
+            .and_then(Value::as_str)
+            .map(str::to_string),
+        capture_metadata: None,
+        recording_metadata: load_recording_metadata(Some(snapshot))?,
+        duration_seconds,
+        telemetry: None,
+        created_at: now_iso8601(),
+    }))
+}
+
+fn load_takes(snapshot: Option<&Value>) -> Result<(Vec<Take>, Option<String>), String> {
+    let takes = match snapshot.and_then(|snapshot| snapshot.get("takes")) {
+        Some(value) => serde_json::from_value::<Vec<Take>>(value.clone())
+            .map_err(|error| format!("Invalid takes: {error}"))?,
+        None => {
+            let takes = load_legacy_take(snapshot)?.into_iter().collect::<Vec<_>>();
+            let active_take_id = takes.first().map(|take| take.id.clone());
+            return Ok((takes, active_take_id));
+        }
+    };
+    let active_take_id = snapshot
+        .and_then(|snapshot| snapshot.get("activeTakeId"))
+        .and_then(Value::as_str)
+        .filter(|take_id| takes.iter().any(|take| take.id == *take_id))
+        .map(str::to_string);
+    Ok((takes, active_take_id))
+}
+
+fn load_timeline(snapshot: Option<&Value>, takes: &[Take]) -> Result<Vec<TimelineItem>, String> {
+    let Some(value) = snapshot.and_then(|snapshot| snapshot.get("timeline")) else {
+        return Ok(Vec::new());
+    };
+    let items = serde_json::from_value::<TimelineParams>(value.clone())
+        .map_err(|error| format!("Invalid timeline: {error}"))?
+        .validated()?;
+    check_take_references(&items, takes)?;
+    Ok(items)
+}
+
+fn load_camera_keyframes(snapshot: Option<&Value>) -> Result<CameraKeyframeTrack, String> {
+    let Some(value) = snapshot.and_then(|snapshot| snapshot.pointer("/timeline/cameraKeyframes"))
+    else {
+        return Ok(CameraKeyframeTrack::default());
     };
-    serde_json::from_value::<BackgroundFramingParams>(value.clone())
-        .map_err(|error| format!("Invalid backgroundFraming settings: {error}"))?
+    serde_json::from_value::<CameraKeyframeTrack>(value.clone())
+        .map_err(|error| format!("Invalid camera keyframes: {error}"))?
         .validated()
         .map_err(str::to_string)
 }
 
-fn decode_params<T>(params: &Value) -> T
-where
-    T: for<'de> serde::Deserialize<'de> + Default,
-{
-    serde_json::from_value(params.clone()).unwrap_or_default()
+/// Stored analyses of the project's takes; analyses of other versions are left to be redone.
+fn load_visual_analyses(
+    snapshot: Option<&Value>,
+    takes: &[Take],
+) -> Result<Vec<VisualAnalysis>, String> {
+    let Some(value) = snapshot.and_then(|snapshot| snapshot.get("visualAnalyses")) else {
+        return Ok(Vec::new());
+    };
+    let analyses = serde_json::from_value::<Vec<VisualAnalysis>>(value.clone())
+        .map_err(|error| format!("Invalid visual analyses: {error}"))?;
+    Ok(analyses
+        .into_iter()
+        .filter(|analysis| {
+            analysis.version == VISUAL_ANALYSIS_VERSION
+                && takes.iter().any(|take| take.id == analysis.take_id)
+        })
+        .collect())
+}
+
+fn load_recording_metadata(snapshot: Option<&Value>) -> Result<RecordingMetadata, String> {
+    let Some(value) = snapshot.and_then(|snapshot| snapshot.get("recordingMetadata")) else {
+        return Ok(RecordingMetadata::default());
+    };
+    serde_json::from_value(value.clone())
+        .map_err(|error| format!("Invalid recordingMetadata: {error}"))
 }
 
 pub(crate) fn current(id: &EngineCallId, state: &State) -> EngineResponse {
@@ -69,7 +226,10 @@ pub(crate) fn current(id: &EngineCallId, state: &State) -> EngineResponse {
 }
 
 pub(crate) fn open(id: &EngineCallId, state: &mut State, params: &Value) -> EngineResponse {
-    let project_params: ProjectOpenParams = decode_params(params);
+    let project_params = match decode_params::<ProjectOpenParams>(id, params, "project open") {
+        Ok(params) => params,
+        Err(response) => return response,
+    };
     let project_path = match project_params.project_path {
         Some(value) => value,
         None => {
@@ -83,36 +243,67 @@ pub(crate) fn open(id: &EngineCallId, state: &mut State, params: &Value) -> Engi
     if let Err(response) = validate_project_path(id, &project_path) {
         return response;
     }
-    let background_framing = match load_background_framing(&project_path) {
-        Ok(settings) => settings,
+    let snapshot = match read_project_snapshot(&project_path) {
+        Ok(snapshot) => snapshot,
+        Err(error) => return failure(id, ProtocolErrorCode::InvalidParams, error),
+    };
+    let mut next_state = state.clone();
+    if let Err(error) = load_settings_groups(snapshot.as_ref(), &mut next_state) {
+        return failure(id, ProtocolErrorCode::InvalidParams, error);
+    }
+    let (project_takes, active_take_id) = match load_takes(snapshot.as_ref()) {
+        Ok(takes) => takes,
+        Err(error) => return failure(id, ProtocolErrorCode::InvalidParams, error),
+    };
+    let timeline_items = match load_timeline(snapshot.as_ref(), &project_takes) {
+        Ok(items) => items,
+        Err(error) => return failure(id, ProtocolErrorCode::InvalidParams, error),
+    };
+    let visual_analyses = match load_visual_analyses(snapshot.as_ref(), &project_takes) {
+        Ok(analyses) => analyses,
         Err(error) => return failure(id, ProtocolErrorCode::InvalidParams, error),
     };
-    state.project_path = Some(project_path.clone());
-    state.background_framing = background_framing;
-    state.unsaved_changes = false;
-    record_recent_project(state, &project_path);
+    let camera_keyframes = match load_camera_keyframes(snapshot.as_ref()) {
+        Ok(track) => track,
+        Err(error) => return failure(id, ProtocolErrorCode::InvalidParams, error),
+    };
+    next_state.project_path = Some(project_path.clone());
+    next_state.takes = project_takes;
+    next_state.timeline_items = timeline_items;
+    next_state.camera_keyframes = camera_keyframes;
+    next_state.visual_analyses = visual_analyses;
+    match active_take_id
+        .and_then(|take_id| next_state.takes.iter().find(|take| take.id == take_id))
+        .cloned()
+    {
+        Some(take) => takes::activate_take(&mut next_state, &take),
+        None => takes::clear_active_take(&mut next_state),
+    }
+    next_state.unsaved_changes = false;
+    record_recent_project(&mut next_state, &project_path);
+    *state = next_state;
     success(id, state.project_state())
 }
 
 pub(crate) fn save(id: &EngineCallId, state: &mut State, params: &Value) -> EngineResponse {
-    let project_params: ProjectSaveParams = match serde_json::from_value(params.clone()) {
+    let mut project_params = match decode_params::<ProjectSaveParams>(id, params, "project save") {
         Ok(params) => params,
-        Err(error) => {
-            return failure(
-                id,
-                ProtocolErrorCode::InvalidParams,
-                format!("Invalid project save payload: {error}"),
-            )
-        }
+        Err(response) => return response,
     };
-    let background_framing = match project_params.background_framing {
-        Some(settings) => match settings.validated() {
-            Ok(settings) => Some(settings),
+    let timeline_items = match project_params.timeline.take() {
+        Some(timeline) => match timeline
+            .validated()
+            .and_then(|items| check_take_references(&items, &state.takes).map(|()| items))
+        {
+            Ok(items) => Some(items),
             Err(error) => return failure(id, ProtocolErrorCode::InvalidParams, error),
         },
         None => None,
     };
     let mut next_state = state.clone();
+    if let Err(error) = apply_settings_groups(&mut next_state, &mut project_params) {
+        return failure(id, ProtocolErrorCode::InvalidParams, error);
+    }
     if let Some(project_path) = project_params.project_path {
         if let Err(response) = validate_project_path(id, &project_path) {
             return response;
@@ -120,8 +311,8 @@ pub(crate) fn save(id: &EngineCallId, state: &mut State, params: &Value) -> Engi
         next_state.project_path = Some(project_path);
     }
 
-    if let Some(background_framing) = background_framing {
-        next_state.background_framing = background_framing;
+    if let Some(timeline_items) = timeline_items {
+        next_state.timeline_items = timeline_items;
     }
 
     if let Some(auto_zoom) = project_params.auto_zoom {
@@ -165,7 +356,11 @@ pub(crate) fn save(id: &EngineCallId, state: &mut State, params: &Value) -> Engi
 }
 
 pub(crate) fn recents(id: &EngineCallId, state: &State, params: &Value) -> EngineResponse {
-    let project_params: ProjectRecentsParams = decode_params(params);
+    let project_params = match decode_params::<ProjectRecentsParams>(id, params, "project recents")
+    {
+        Ok(params) => params,
+        Err(response) => return response,
+    };
     let limit = project_params
         .limit
         .map(|value| value.min(100) as usize)
@@ -0,0 +1,356 @@
+use std::ffi::{c_char, c_int, c_void, CStr, CString};
+use std::sync::OnceLock;
+use std::time::{Duration, Instant};
+
+/// libpulse is loaded at runtime so the engine still starts on hosts without PulseAudio or
+/// pipewire-pulse; audio capture then reports the library as unavailable.
+const LIBPULSE: &CStr = c"libpulse.so.0";
+const LIBPULSE_SIMPLE: &CStr = c"libpulse-simple.so.0";
+const CLIENT_NAME: &CStr = c"Guerillaglass";
+
+const PA_SAMPLE_S16LE: c_int = 3;
+const PA_STREAM_RECORD: c_int = 2;
+const PA_CONTEXT_NOAUTOSPAWN: c_int = 1;
+const PA_CONTEXT_READY: c_int = 4;
+const PA_CONTEXT_FAILED: c_int = 5;
+const PA_CONTEXT_TERMINATED: c_int = 6;
+const PA_OPERATION_RUNNING: c_int = 0;
+/// Server round trips during enumeration give up after this long.
+const SERVER_TIMEOUT: Duration = Duration::from_secs(2);
+
+#[repr(C)]
+struct SampleSpec {
+    format: c_int,
+    rate: u32,
+    channels: u8,
+}
+
+#[repr(C)]
+struct BufferAttr {
+    maxlength: u32,
+    tlength: u32,
+    prebuf: u32,
+    minreq: u32,
+    fragsize: u32,
+}
+
+/// Leading fields of `pa_source_info`; the rest of the struct is never read.
+#[repr(C)]
+struct SourceInfoPrefix {
+    name: *const c_char,
+    index: u32,
+    description: *const c_char,
+}
+
+type SourceInfoCallback =
+    unsafe extern "C" fn(*mut c_void, *const SourceInfoPrefix, c_int, *mut c_void);
+
+struct PulseApi {
+    simple_new: unsafe extern "C" fn(
+        *const c_char,
+        *const c_char,
+        c_int,
+        *const c_char,
+        *const c_char,
+        *const SampleSpec,
+        *const c_void,
+        *const BufferAttr,
+        *mut c_int,
+    ) -> *mut c_void,
+    simple_read: unsafe extern "C" fn(*mut c_void, *mut c_void, usize, *mut c_int) -> c_int,
+    simple_free: unsafe extern "C" fn(*mut c_void),
+    strerror: unsafe extern "C" fn(c_int) -> *const c_char,
+    mainloop_new: unsafe extern "C" fn() -> *mut c_void,
+    mainloop_get_api: unsafe extern "C" fn(*mut c_void) -> *mut c_void,
+    mainloop_iterate: unsafe extern "C" fn(*mut c_void, c_int, *mut c_int) -> c_int,
+    mainloop_free: unsafe extern "C" fn(*mut c_void),
+    context_new: unsafe extern "C" fn(*mut c_void, *const c_char) -> *mut c_void,
+    context_connect:
+        unsafe extern "C" fn(*mut c_void, *const c_char, c_int, *const c_void) -> c_int,
+    context_get_state: unsafe extern "C" fn(*mut c_void) -> c_int,
+    context_disconnect: unsafe extern "C" fn(*mut c_void),
+    context_unref: unsafe extern "C" fn(*mut c_void),
+    context_get_source_info_list:
+        unsafe extern "C" fn(*mut c_void, SourceInfoCallback, *mut c_void) -> *mut c_void,
+    operation_get_state: unsafe extern "C" fn(*mut c_void) -> c_int,
+    operation_unref: unsafe extern "C" fn(*mut c_void),
+}
+
+fn open_library(name: &CStr) -> Result<*mut c_void, String> {
+    // SAFETY: `name` is NUL-terminated; the handle is intentionally never closed.
+    let handle = unsafe { libc::dlopen(name.as_ptr(), libc::RTLD_NOW | libc::RTLD_LOCAL) };
+    if handle.is_null() {
+        return Err(format!("{} is not installed", name.to_string_lossy()));
+    }
+    Ok(handle)
+}
+
+/// # Safety
+/// `T` must be the `extern "C"` function pointer type of the symbol called `name`.
+unsafe fn symbol<T: Copy>(library: *mut c_void, name: &CStr) -> Result<T, String> {
+    let pointer = libc::dlsym(library, name.as_ptr());
+    if pointer.is_null() {
+        return Err(format!("libpulse is missing {}", name.to_string_lossy()));
+    }
+    Ok(std::mem::transmute_copy(&pointer))
+}
+
+fn load_api() -> Result<PulseApi, String> {
+    let pulse = open_library(LIBPULSE)?;
+    let simple = open_library(LIBPULSE_SIMPLE)?;
+    // SAFETY: every field type matches the documented libpulse prototype of its symbol.
+    unsafe {
+        Ok(PulseApi {
+            simple_new: symbol(simple, c"pa_simple_new")?,
+            simple_read: symbol(simple, c"pa_simple_read")?,
+            simple_free: symbol(simple, c"pa_simple_free")?,
+            strerror: symbol(pulse, c"pa_strerror")?,
+            mainloop_new: symbol(pulse, c"pa_mainloop_new")?,
+            mainloop_get_api: symbol(pulse, c"pa_mainloop_get_api")?,
+            mainloop_iterate: symbol(pulse, c"pa_mainloop_iterate")?,
+            mainloop_free: symbol(pulse, c"pa_mainloop_free")?,
+            context_new: symbol(pulse, c"pa_context_new")?,
+            context_connect: symbol(pulse, c"pa_context_connect")?,
+            context_get_state: symbol(pulse, c"pa_context_get_state")?,
+            context_disconnect: symbol(pulse, c"pa_context_disconnect")?,
+            context_unref: symbol(pulse, c"pa_context_unref")?,
+            context_get_source_info_list: symbol(pulse, c"pa_context_get_source_info_list")?,
+            operation_get_state: symbol(pulse, c"pa_operation_get_state")?,
+            operation_unref: symbol(pulse, c"pa_operation_unref")?,
+        })
+    }
+}
+
+fn api() -> Result<&'static PulseApi, String> {
+    static API: OnceLock<Result<PulseApi, String>> = OnceLock::new();
+    API.get_or_init(load_api).as_ref().map_err(Clone::clone)
+}
+
+fn lossy_string(pointer: *const c_char) -> String {
+    if pointer.is_null() {
+        return String::new();
+    }
+    // SAFETY: libpulse hands out NUL-terminated strings valid for the callback's duration.
+    unsafe { CStr::from_ptr(pointer) }
+        .to_string_lossy()
+        .into_owned()
+}
+
+fn error_message(api: &PulseApi, error: c_int) -> String {
+    // SAFETY: pa_strerror returns a static string for any error code.
+    lossy_string(unsafe { (api.strerror)(error) })
+}
+
+/// A PulseAudio source: a microphone, or the `.monitor` of an output sink.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct PulseSource {
+    pub(crate) name: String,
+    pub(crate) description: String,
+}
+
+unsafe extern "C" fn collect_source(
+    _context: *mut c_void,
+    info: *const SourceInfoPrefix,
+    eol: c_int,
+    userdata: *mut c_void,
+) {
+    if eol != 0 || info.is_null() {
+        return;
+    }
+    let sources = &mut *(userdata as *mut Vec<PulseSource>);
+    let info = &*info;
+    sources.push(PulseSource {
+        name: lossy_string(info.name),
+        description: lossy_string(info.description),
+    });
+}
+
+/// Iterates `mainloop` without blocking until `done` holds or the server timeout passes.
+///
+/// # Safety
+/// `mainloop` must be a live `pa_mainloop`.
+unsafe fn iterate_until(
+    api: &PulseApi,
+    mainloop: *mut c_void,
+    mut done: impl FnMut() -> Result<bool, String>,
+) -> Result<(), String> {
+    let deadline = Instant::now() + SERVER_TIMEOUT;
+    loop {
+        (api.mainloop_iterate)(mainloop, 0, std::ptr::null_mut());
+        if done()? {
+            return Ok(());
+        }
+        if Instant::now() >= deadline {
+            return Err("PulseAudio server did not respond".to_string());
+        }
+        std::thread::sleep(Duration::from_millis(5));
+    }
+}
+
+/// # Safety
+/// `mainloop` and `context` must be live and belong together.
+unsafe fn list_sources_on(
+    api: &PulseApi,
+    mainloop: *mut c_void,
+    context: *mut c_void,
+) -> Result<Vec<PulseSource>, String> {
+    if (api.context_connect)(
+        context,
+        std::ptr::null(),
+        PA_CONTEXT_NOAUTOSPAWN,
+        std::ptr::null(),
+    ) < 0
+    {
+        return Err("PulseAudio server is unavailable".to_string());
+    }
+    iterate_until(api, mainloop, || match (api.context_get_state)(context) {
+        PA_CONTEXT_READY => Ok(true),
+        PA_CONTEXT_FAILED | PA_CONTEXT_TERMINATED => {
+            Err("PulseAudio server is unavailable".to_string())
+        }
+        _ => Ok(false),
+    })?;
+
+    let mut sources: Vec<PulseSource> = Vec::new();
+    let operation = (api.context_get_source_info_list)(
+        context,
+        collect_source,
+        &mut sources as *mut Vec<PulseSource> as *mut c_void,
+    );
+    if operation.is_null() {
+        return Err("PulseAudio refused to list sources".to_string());
+    }
+    let listed = iterate_until(api, mainloop, || {
+        Ok((api.operation_get_state)(operation) != PA_OPERATION_RUNNING)
+    });
+    (api.operation_unref)(operation);
+    listed.map(|()| sources)
+}
+
+/// Lists every capture source the PulseAudio (or pipewire-pulse) server exposes.
+pub(crate) fn list_sources() -> Result<Vec<PulseSource>, String> {
+    let api = api()?;
+    // SAFETY: the mainloop and context are created, used and released in this scope only.
+    unsafe {
+        let mainloop = (api.mainloop_new)();
+        if mainloop.is_null() {
+            return Err("unable to create a PulseAudio main loop".to_string());
+        }
+        let context = (api.context_new)((api.mainloop_get_api)(mainloop), CLIENT_NAME.as_ptr());
+        let result = if context.is_null() {
+            Err("unable to create a PulseAudio context".to_string())
+        } else {
+            let result = list_sources_on(api, mainloop, context);
+            (api.context_disconnect)(context);
+            (api.context_unref)(context);
+            result
+        };
+        (api.mainloop_free)(mainloop);
+        result
+    }
+}
+
+/// Blocking interleaved S16LE capture from one PulseAudio source.
+pub(crate) struct PulseRecordStream {
+    api: &'static PulseApi,
+    stream: *mut c_void,
+}
+
+// SAFETY: a pa_simple stream may be used from any one thread at a time.
+unsafe impl Send for PulseRecordStream {}
+
+impl PulseRecordStream {
+    /// Opens `device` (a source name or `@DEFAULT_SOURCE@` / `@DEFAULT_MONITOR@`), asking
+    /// the server to deliver `fragment_bytes` at a time to keep level meters responsive.
+    pub(crate) fn open(
+        device: &str,
+        stream_name: &str,
+        sample_rate: u32,
+        channels: u8,
+        fragment_bytes: u32,
+    ) -> Result<Self, String> {
+        let api = api()?;
+        let device = CString::new(device).map_err(|error| error.to_string())?;
+        let stream_name = CString::new(stream_name).map_err(|error| error.to_string())?;
+        let spec = SampleSpec {
+            format: PA_SAMPLE_S16LE,
+            rate: sample_rate,
+            channels,
+        };
+        let attributes = BufferAttr {
+            maxlength: u32::MAX,
+            tlength: u32::MAX,
+            prebuf: u32::MAX,
+            minreq: u32::MAX,
+            fragsize: fragment_bytes,
+        };
+        let mut error = 0;
+        // SAFETY: all pointers reference live, NUL-terminated or fully initialised values.
+        let stream = unsafe {
+            (api.simple_new)(
+                std::ptr::null(),
+                CLIENT_NAME.as_ptr(),
+                PA_STREAM_RECORD,
+                device.as_ptr(),
+                stream_name.as_ptr(),
+                &spec,
+                std::ptr::null(),
+                &attributes,
+                &mut error,
+            )
+        };
+        if stream.is_null() {
+            return Err(error_message(api, error));
+        }
+        Ok(Self { api, stream })
+    }
+
+    pub(crate) fn read(&mut self, buffer: &mut [u8]) -> Result<(), String> {
+        let mut error = 0;
+        // SAFETY: `buffer` is writable for its full length and the stream is live.
+        let result = unsafe {
+            (self.api.simple_read)(
+                self.stream,
+                buffer.as_mut_ptr() as *mut c_void,
+                buffer.len(),
+                &mut error,
+            )
+        };
+        if result < 0 {
+            return Err(error_message(self.api, error));
+        }
+        Ok(())
+    }
+}
+
+impl Drop for PulseRecordStream {
+    fn drop(&mut self) {
+        // SAFETY: the stream was created by pa_simple_new and is freed exactly once.
+        unsafe { (self.api.simple_free)(self.stream) };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{list_sources, PulseRecordStream};
+
+    /// Needs a PulseAudio or pipewire-pulse server, e.g. with
+    /// `pactl load-module module-null-sink`; skipped otherwise.
+    #[test]
+    fn records_from_a_null_sink_monitor_when_a_server_is_running() {
+        let Ok(sources) = list_sources() else {
+            return;
+        };
+        let Some(monitor) = sources
+            .iter()
+            .find(|source| source.name.ends_with(".monitor"))
+        else {
+            return;
+        };
+        assert!(!monitor.description.is_empty());
+        let mut stream =
+            PulseRecordStream::open(&monitor.name, "test", 48_000, 2, 3_840).expect("open monitor");
+        let mut buffer = vec![0u8; 3_840];
+        stream.read(&mut buffer).expect("read monitor audio");
+    }
+}
@@ -0,0 +1,172 @@
+use serde::{Deserialize, Serialize};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+/// A span removed from the recording by `recording.pause`/`recording.resume`.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct RecordingPauseRange {
+    /// Position in the gapless output timeline where the pause was spliced out.
+    pub(crate) at_seconds: f64,
+    /// Wall-clock time spent paused; none of it is present in the output.
+    pub(crate) duration_seconds: f64,
+}
+
+/// A point of interest dropped with `recording.addMarker` while recording.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct RecordingMarker {
+    pub(crate) id: String,
+    /// Position in the gapless output timeline; markers dropped while paused sit on the splice.
+    pub(crate) at_seconds: f64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) label: Option<String>,
+    pub(crate) color: String,
+}
+
+/// Editor-facing facts about the latest recording, persisted with the project snapshot.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub(crate) struct RecordingMetadata {
+    pub(crate) pauses: Vec<RecordingPauseRange>,
+    pub(crate) markers: Vec<RecordingMarker>,
+    /// Set when the recording was rebuilt by `recording.recover` after an interrupted session.
+    pub(crate) recovered: bool,
+}
+
+/// Capture-time bounds of the active recording, shared by every track writer so a countdown
+/// or time limit cuts the screen, camera and audio tracks at the same instant.
+#[derive(Debug)]
+pub(crate) struct RecordingWindow {
+    starts_at: Instant,
+    stops_at: Mutex<Option<Instant>>,
+}
+
+impl RecordingWindow {
+    pub(crate) fn new(starts_at: Instant, stops_at: Option<Instant>) -> Self {
+        Self {
+            starts_at,
+            stops_at: Mutex::new(stops_at),
+        }
+    }
+
+    /// Moves the stop instant, e.g. when a pause pushes a maximum duration back.
+    pub(crate) fn set_stops_at(&self, stops_at: Option<Instant>) {
+        if let Ok(mut current) = self.stops_at.lock() {
+            *current = stops_at;
+        }
+    }
+
+    /// Whether media captured at `at` belongs in the recording.
+    pub(crate) fn admits(&self, at: Instant) -> bool {
+        at >= self.starts_at
+            && self
+                .stops_at
+                .lock()
+                .map(|stops_at| stops_at.is_none_or(|stops_at| at < stops_at))
+                .unwrap_or(true)
+    }
+}
+
+/// Countdown and time limits requested by `recording.start`, in capture-clock seconds.
+#[derive(Clone, Debug)]
+pub(crate) struct RecordingSchedule {
+    pub(crate) starts_at_seconds: f64,
+    pub(crate) max_duration_seconds: Option<f64>,
+    pub(crate) stop_at_seconds: Option<f64>,
+    /// When the recording stops, as of the last start, pause or resume.
+    pub(crate) deadline_seconds: Option<f64>,
+    pub(crate) window: Arc<RecordingWindow>,
+}
+
+impl RecordingSchedule {
+    pub(crate) fn countdown_remaining_seconds(&self, now_seconds: f64) -> Option<f64> {
+        (now_seconds < self.starts_at_seconds).then_some(self.starts_at_seconds - now_seconds)
+    }
+
+    /// Capture-clock time at which the recording has to stop given `recorded_seconds` so far;
+    /// while paused only an absolute stop time applies.
+    pub(crate) fn next_deadline_seconds(
+        &self,
+        now_seconds: f64,
+        recorded_seconds: f64,
+        paused: bool,
+    ) -> Option<f64> {
+        let by_duration = self
+            .max_duration_seconds
+            .filter(|_| !paused)
+            .map(|max| now_seconds.max(self.starts_at_seconds) + (max - recorded_seconds).max(0.0));
+        match (by_duration, self.stop_at_seconds) {
+            (Some(left), Some(right)) => Some(left.min(right)),
+            (deadline, None) | (None, deadline) => deadline,
+        }
+    }
+
+    /// Recording time left before a limit stops the recording.
+    pub(crate) fn remaining_seconds(&self, now_seconds: f64, recorded_seconds: f64) -> Option<f64> {
+        let by_duration = self
+            .max_duration_seconds
+            .map(|max| (max - recorded_seconds).max(0.0));
+        let by_stop_time = self
+            .stop_at_seconds
+            .map(|stop_at| (stop_at - now_seconds.max(self.starts_at_seconds)).max(0.0));
+        match (by_duration, by_stop_time) {
+            (Some(left), Some(right)) => Some(left.min(right)),
+            (remaining, None) | (None, remaining) => remaining,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RecordingSchedule, RecordingWindow};
+    use std::sync::Arc;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn window_admits_media_between_countdown_and_time_limit() {
+        let origin = Instant::now();
+        let window = RecordingWindow::new(origin + Duration::from_secs(3), None);
+        assert!(!window.admits(origin + Duration::from_secs(2)));
+        assert!(window.admits(origin + Duration::from_secs(60)));
+        window.set_stops_at(Some(origin + Duration::from_secs(10)));
+        assert!(window.admits(origin + Duration::from_millis(9_999)));
+        assert!(!window.admits(origin + Duration::from_secs(10)));
+    }
+
+    #[test]
+    fn schedule_deadline_tracks_recorded_time_and_the_earlier_limit() {
+        let schedule = RecordingSchedule {
+            starts_at_seconds: 13.0,
+            max_duration_seconds: Some(30.0),
+            stop_at_seconds: None,
+            deadline_seconds: None,
+            window: Arc::new(RecordingWindow::new(Instant::now(), None)),
+        };
+        assert_eq!(schedule.countdown_remaining_seconds(10.0), Some(3.0));
+        assert_eq!(schedule.countdown_remaining_seconds(13.0), None);
+        assert_eq!(schedule.next_deadline_seconds(10.0, 0.0, false), Some(43.0));
+        // 20 s recorded after a 5 s pause: 10 s left from now.
+        assert_eq!(
+            schedule.next_deadline_seconds(38.0, 20.0, false),
+            Some(48.0)
+        );
+        assert_eq!(schedule.next_deadline_seconds(38.0, 20.0, true), None);
+        assert_eq!(schedule.remaining_seconds(38.0, 20.0), Some(10.0));
+
+        let with_stop_time = RecordingSchedule {
+            stop_at_seconds: Some(40.0),
+            ..schedule
+        };
+        assert_eq!(
+            with_stop_time.next_deadline_seconds(38.0, 20.0, false),
+            Some(40.0)
+        );
+        assert_eq!(
+            with_stop_time.next_deadline_seconds(38.0, 20.0, true),
+            Some(40.0)
+        );
+        assert_eq!(with_stop_time.remaining_seconds(38.0, 20.0), Some(2.0));
+        assert_eq!(with_stop_time.remaining_seconds(10.0, 0.0), Some(27.0));
+    }
+}
@@ -0,0 +1,690 @@
+use crate::disk::RecordingDiskBudget;
+use crate::path_security::{
+    create_directory_all_no_symlink, create_file_no_symlink, reject_final_symlink,
+    replace_file_no_symlink,
+};
+use crate::recording::RecordingMetadata;
+use crate::state::now_iso8601;
+use serde::{Deserialize, Serialize};
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufRead, BufReader, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Project-relative directory holding one sub-directory per recording.
+pub(crate) const RECORDINGS_DIRECTORY: &str = "recordings";
+const JOURNAL_FILE_NAME: &str = "journal.json";
+const OUTPUT_FILE_NAME: &str = "recording.y4m";
+/// The output is fsynced and its frame count journaled each time it grows by this much media.
+const CHECKPOINT_SECONDS: u64 = 2;
+const FRAME_MARKER: &[u8] = b"FRAME\n";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+enum JournalStatus {
+    Recording,
+    Finalized,
+}
+
+/// On-disk record of a recording in progress; the source of truth for recovery.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RecordingJournal {
+    version: u32,
+    recording_id: String,
+    status: JournalStatus,
+    started_at: String,
+    width: u32,
+    height: u32,
+    fps: u64,
+    #[serde(default)]
+    metadata: RecordingMetadata,
+    /// Frames known to be on disk: every frame while finalized, the last checkpoint while
+    /// recording.
+    frame_count: u64,
+}
+
+impl RecordingJournal {
+    fn frame_bytes(&self) -> usize {
+        self.width as usize * self.height as usize * 3
+    }
+
+    fn frame_record_bytes(&self) -> u64 {
+        (FRAME_MARKER.len() + self.frame_bytes()) as u64
+    }
+
+    fn stream_header(&self) -> String {
+        format!(
+            "YUV4MPEG2 W{} H{} F{}:1 Ip A1:1 C444\n",
+            self.width, self.height, self.fps
+        )
+    }
+
+    /// Length of the output file when it holds exactly `frame_count` frames.
+    fn output_len(&self, frame_count: u64) -> u64 {
+        self.stream_header().len() as u64 + frame_count * self.frame_record_bytes()
+    }
+}
+
+/// A finished recording and the playable file holding it.
+#[derive(Clone, Debug)]
+pub(crate) struct FinalizedRecording {
+    pub(crate) recording_id: String,
+    pub(crate) output_path: PathBuf,
+    pub(crate) width: u32,
+    pub(crate) height: u32,
+    pub(crate) frame_count: u64,
+    pub(crate) duration_seconds: f64,
+    pub(crate) metadata: RecordingMetadata,
+}
+
+impl FinalizedRecording {
+    fn new(directory: &Path, journal: RecordingJournal) -> Self {
+        Self {
+            output_path: directory.join(OUTPUT_FILE_NAME),
+            width: journal.width,
+            height: journal.height,
+            duration_seconds: journal.frame_count as f64 / journal.fps.max(1) as f64,
+            frame_count: journal.frame_count,
+            recording_id: journal.recording_id,
+            metadata: journal.metadata,
+        }
+    }
+}
+
+/// Writes frames straight into one YUV4MPEG2 file and journals a checkpoint every
+/// `CHECKPOINT_SECONDS`, so a crash loses at most the frames that never reached the disk and
+/// finishing a recording never copies its media.
+pub(crate) struct JournaledRecordingWriter {
+    directory: PathBuf,
+    journal: RecordingJournal,
+    output: File,
+    disk_budget: Arc<RecordingDiskBudget>,
+    appended_frames: u64,
+}
+
+impl JournaledRecordingWriter {
+    /// Disk bytes a recording consumes per second.
+    pub(crate) fn estimated_disk_bytes_per_second(width: u32, height: u32, fps: u64) -> u64 {
+        let frame_record_bytes = FRAME_MARKER.len() as u64 + width as u64 * height as u64 * 3;
+        frame_record_bytes.saturating_mul(fps)
+    }
+
+    pub(crate) fn create(
+        recordings_root: &Path,
+        recording_id: &str,
+        width: u32,
+        height: u32,
+        fps: u64,
+        disk_budget: Arc<RecordingDiskBudget>,
+    ) -> io::Result<Self> {
+        let directory = recordings_root.join(recording_id);
+        create_directory_all_no_symlink(&directory)?;
+        let journal = RecordingJournal {
+            version: 2,
+            recording_id: recording_id.to_string(),
+            status: JournalStatus::Recording,
+            started_at: now_iso8601(),
+            width: width.max(1),
+            height: height.max(1),
+            fps: fps.max(1),
+            metadata: RecordingMetadata::default(),
+            frame_count: 0,
+        };
+        let created =
+            create_file_no_symlink(&directory.join(OUTPUT_FILE_NAME)).and_then(|mut output| {
+                output.write_all(journal.stream_header().as_bytes())?;
+                write_journal(&directory, &journal)?;
+                Ok(output)
+            });
+        match created {
+            Ok(output) => Ok(Self {
+                directory,
+                journal,
+                output,
+                disk_budget,
+                appended_frames: 0,
+            }),
+            Err(error) => {
+                let _ = fs::remove_dir_all(&directory);
+                Err(error)
+            }
+        }
+    }
+
+    pub(crate) fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    /// Free space this recording draws from; every other track of the take shares it.
+    pub(crate) fn disk_budget(&self) -> &Arc<RecordingDiskBudget> {
+        &self.disk_budget
+    }
+
+    /// Deletes the directory of a recording that never started, along with any track
+    /// directories created inside it, so recovery does not mistake it for an interrupted take.
+    pub(crate) fn discard(self) {
+        let _ = fs::remove_dir_all(&self.directory);
+    }
+
+    pub(crate) fn output_path(&self) -> PathBuf {
+        self.directory.join(OUTPUT_FILE_NAME)
+    }
+
+    /// Appends one packed RGB8 frame and returns the number of bytes written. Fails with
+    /// `StorageFull`, and keeps failing, once the recording's disk budget is spent.
+    pub(crate) fn append(&mut self, pixels: &[u8]) -> io::Result<u64> {
+        if pixels.len() != self.journal.frame_bytes() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "frame has {} bytes, expected {}",
+                    pixels.len(),
+                    self.journal.frame_bytes()
+                ),
+            ));
+        }
+        self.disk_budget
+            .reserve(self.journal.frame_record_bytes())?;
+        let frame = encode_y4m_frame(pixels);
+        self.output.write_all(&frame)?;
+        self.appended_frames += 1;
+        if self.appended_frames - self.journal.frame_count >= CHECKPOINT_SECONDS * self.journal.fps
+        {
+            self.checkpoint()?;
+        }
+        Ok(frame.len() as u64)
+    }
+
+    pub(crate) fn update_metadata(&mut self, metadata: RecordingMetadata) -> io::Result<()> {
+        self.journal.metadata = metadata;
+        self.write_journal()
+    }
+
+    /// Marks the recording finished in place; the output file is already playable.
+    pub(crate) fn finalize(
+        mut self,
+        metadata: RecordingMetadata,
+    ) -> io::Result<FinalizedRecording> {
+        self.journal.metadata = metadata;
+        // A failed append can leave part of a frame behind.
+        self.output
+            .set_len(self.journal.output_len(self.appended_frames))?;
+        self.output.sync_all()?;
+        self.journal.frame_count = self.appended_frames;
+        self.journal.status = JournalStatus::Finalized;
+        self.write_journal()?;
+        Ok(FinalizedRecording::new(&self.directory, self.journal))
+    }
+
+    fn checkpoint(&mut self) -> io::Result<()> {
+        self.output.sync_data()?;
+        self.journal.frame_count = self.appended_frames;
+        self.write_journal()
+    }
+
+    fn write_journal(&self) -> io::Result<()> {
+        write_journal(&self.directory, &self.journal)
+    }
+}
+
+fn write_journal(directory: &Path, journal: &RecordingJournal) -> io::Result<()> {
+    let data = serde_json::to_vec_pretty(journal).map_err(io::Error::other)?;
+    replace_file_no_symlink(&directory.join(JOURNAL_FILE_NAME), &data)
+}
+
+fn read_journal(directory: &Path) -> io::Result<RecordingJournal> {
+    let path = directory.join(JOURNAL_FILE_NAME);
+    reject_final_symlink(&path)?;
+    let data = fs::read(&path)?;
+    serde_json::from_slice(&data).map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))
+}
+
+/// BT.601 limited-range luma of one RGB8 pixel.
+fn rgb_luma(rgb: &[u8]) -> u8 {
+    let (r, g, b) = (rgb[0] as i32, rgb[1] as i32, rgb[2] as i32);
+    (((66 * r + 129 * g + 25 * b + 128) >> 8) + 16) as u8
+}
+
+/// The luma plane a recording would store for packed RGB8 `pixels`.
+pub(crate) fn luma_plane(pixels: &[u8]) -> Vec<u8> {
+    pixels.chunks_exact(3).map(rgb_luma).collect()
+}
+
+/// Mean absolute difference between two luma planes as a fraction of full scale.
+pub(crate) fn luma_difference(current: &[u8], previous: &[u8]) -> f64 {
+    if current.is_empty() {
+        return 0.0;
+    }
+    let total = current
+        .iter()
+        .zip(previous)
+        .map(|(current, previous)| u64::from(current.abs_diff(*previous)))
+        .sum::<u64>();
+    total as f64 / (current.len() as f64 * 255.0)
+}
+
+/// BT.601 limited-range conversion of packed RGB8 into a planar 4:4:4 `FRAME` record.
+fn encode_y4m_frame(pixels: &[u8]) -> Vec<u8> {
+    let pixel_count = pixels.len() / 3;
+    let mut frame = Vec::with_capacity(FRAME_MARKER.len() + pixel_count * 3);
+    frame.extend_from_slice(FRAME_MARKER);
+    frame.resize(FRAME_MARKER.len() + pixel_count * 3, 0);
+    let (luma, chroma) = frame[FRAME_MARKER.len()..].split_at_mut(pixel_count);
+    let (cb, cr) = chroma.split_at_mut(pixel_count);
+    for (index, rgb) in pixels.chunks_exact(3).enumerate() {
+        let (r, g, b) = (rgb[0] as i32, rgb[1] as i32, rgb[2] as i32);
+        luma[index] = rgb_luma(rgb);
+        cb[index] = (((-38 * r - 74 * g + 112 * b + 128) >> 8) + 128) as u8;
+        cr[index] = (((112 * r - 94 * g - 18 * b + 128) >> 8) + 128) as u8;
+    }
+    frame
+}
+
+fn invalid_stream(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.to_string())
+}
+
+/// Reads the stream header of a 4:4:4 YUV4MPEG2 recording: width, height and frame rate.
+fn read_stream_header(reader: &mut impl BufRead) -> io::Result<(usize, usize, f64)> {
+    let mut header = Vec::new();
+    reader.read_until(b'\n', &mut header)?;
+    let header = std::str::from_utf8(&header).map_err(|_| invalid_stream("header is not UTF-8"))?;
+    let mut tokens = header.split_ascii_whitespace();
+    if tokens.next() != Some("YUV4MPEG2") {
+        return Err(invalid_stream("not a YUV4MPEG2 stream"));
+    }
+    let (mut width, mut height, mut fps, mut colorspace) = (0usize, 0usize, 0.0, None);
+    for token in tokens {
+        let (tag, value) = token.split_at(1);
+        match tag {
+            "W" => width = value.parse().map_err(|_| invalid_stream("invalid width"))?,
+            "H" => {
+                height = value
+                    .parse()
+                    .map_err(|_| invalid_stream("invalid height"))?
+            }
+            "F" => {
+                let (numerator, denominator) = value
+                    .split_once(':')
+                    .ok_or_else(|| invalid_stream("invalid frame rate"))?;
+                let numerator = numerator
+                    .parse::<f64>()
+                    .map_err(|_| invalid_stream("invalid frame rate"))?;
+                let denominator = denominator
+                    .parse::<f64>()
+                    .map_err(|_| invalid_stream("invalid frame rate"))?;
+                fps = numerator / denominator;
+            }
+            "C" => colorspace = Some(value),
+            _ => {}
+        }
+    }
+    if colorspace != Some("444") {
+        return Err(invalid_stream("only 4:4:4 recordings can be analyzed"));
+    }
+    if width == 0 || height == 0 || !fps.is_finite() || fps <= 0.0 {
+        return Err(invalid_stream(
+            "stream header is missing its size or frame rate",
+        ));
+    }
+    Ok((width, height, fps))
+}
+
+/// How much the picture changes between consecutive frames of a 4:4:4 YUV4MPEG2 recording:
+/// the mean absolute luma difference from the previous frame as a fraction of full scale, `0`
+/// for the first frame. Returned with the stream's frame rate; a torn final frame is ignored.
+pub(crate) fn luma_differences(path: &Path) -> io::Result<(f64, Vec<f64>)> {
+    reject_final_symlink(path)?;
+    let mut reader = BufReader::new(File::open(path)?);
+    let (width, height, fps) = read_stream_header(&mut reader)?;
+    let pixel_count = width * height;
+    let mut record = vec![0u8; pixel_count * 3];
+    let mut previous: Option<Vec<u8>> = None;
+    let mut differences = Vec::new();
+    loop {
+        let mut marker = Vec::new();
+        if reader.read_until(b'\n', &mut marker)? == 0 || !marker.starts_with(b"FRAME") {
+            break;
+        }
+        match reader.read_exact(&mut record) {
+            Ok(()) => {}
+            Err(error) if error.kind() == io::ErrorKind::UnexpectedEof => break,
+            Err(error) => return Err(error),
+        }
+        let luma = &record[..pixel_count];
+        let difference = previous
+            .as_deref()
+            .map_or(0.0, |previous| luma_difference(luma, previous));
+        differences.push(difference);
+        previous = Some(luma.to_vec());
+    }
+    Ok((fps, differences))
+}
+
+/// Random access to the frames of a 4:4:4 YUV4MPEG2 recording written by
+/// `JournaledRecordingWriter`, whose frame markers carry no parameters.
+pub(crate) struct RecordingFrames {
+    reader: BufReader<File>,
+    frames_start: u64,
+    pub(crate) width: usize,
+    pub(crate) height: usize,
+    pub(crate) fps: f64,
+}
+
+impl RecordingFrames {
+    pub(crate) fn open(path: &Path) -> io::Result<Self> {
+        reject_final_symlink(path)?;
+        let mut reader = BufReader::new(File::open(path)?);
+        let (width, height, fps) = read_stream_header(&mut reader)?;
+        let frames_start = reader.stream_position()?;
+        Ok(Self {
+            reader,
+            frames_start,
+            width,
+            height,
+            fps,
+        })
+    }
+
+    fn record_bytes(&self) -> u64 {
+        (FRAME_MARKER.len() + self.width * self.height * 3) as u64
+    }
+
+    /// Number of whole frames in the stream.
+    pub(crate) fn frame_count(&self) -> io::Result<u64> {
+        let length = self.reader.get_ref().metadata()?.len();
+        Ok(length.saturating_sub(self.frames_start) / self.record_bytes())
+    }
+
+    /// Packed RGB8 pixels of frame `index`, or `None` past the last whole frame.
+    pub(crate) fn rgb(&mut self, index: u64) -> io::Result<Option<Vec<u8>>> {
+        let pixel_count = self.width * self.height;
+        let record_bytes = self.record_bytes();
+        self.reader
+            .seek(SeekFrom::Start(self.frames_start + index * record_bytes))?;
+        let mut record = vec![0u8; FRAME_MARKER.len() + pixel_count * 3];
+        match self.reader.read_exact(&mut record) {
+            Ok(()) => {}
+            Err(error) if error.kind() == io::ErrorKind::UnexpectedEof => return Ok(None),
+            Err(error) => return Err(error),
+        }
+        let Some(planes) = record.strip_prefix(FRAME_MARKER) else {
+            return Err(invalid_stream(
+                "frame markers with parameters are not supported",
+            ));
+        };
+        let (luma, chroma) = planes.split_at(pixel_count);
+        let (cb, cr) = chroma.split_at(pixel_count);
+        let mut pixels = Vec::with_capacity(pixel_count * 3);
+        for index in 0..pixel_count {
+            let c = 298 * (luma[index] as i32 - 16);
+            let (d, e) = (cb[index] as i32 - 128, cr[index] as i32 - 128);
+            pixels.extend_from_slice(&[
+                ((c + 409 * e + 128) >> 8).clamp(0, 255) as u8,
+                ((c - 100 * d - 208 * e + 128) >> 8).clamp(0, 255) as u8,
+                ((c + 516 * d + 128) >> 8).clamp(0, 255) as u8,
+            ]);
+        }
+        Ok(Some(pixels))
+    }
+}
+
+/// Finalizes an interrupted recording in place: keeps the checkpointed frames plus any whole
+/// frames written after the last checkpoint, drops a torn tail and marks the journal finalized.
+/// Already-finalized recordings are returned unchanged.
+pub(crate) fn finalize_recording_directory(
+    directory: &Path,
+    recovered: bool,
+) -> io::Result<FinalizedRecording> {
+    let mut journal = read_journal(directory)?;
+    if journal.status == JournalStatus::Recording {
+        let output_path = directory.join(OUTPUT_FILE_NAME);
+        reject_final_symlink(&output_path)?;
+        let mut output = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .open(&output_path)?;
+        let record_bytes = journal.frame_record_bytes();
+        let whole_frames = output
+            .metadata()?
+            .len()
+            .saturating_sub(journal.output_len(0))
+            / record_bytes;
+        let mut frame_count = journal.frame_count.min(whole_frames);
+        let mut marker = [0u8; FRAME_MARKER.len()];
+        while frame_count < whole_frames {
+            output.seek(SeekFrom::Start(journal.output_len(frame_count)))?;
+            output.read_exact(&mut marker)?;
+            if marker != FRAME_MARKER {
+                break;
+            }
+            frame_count += 1;
+        }
+        output.set_len(journal.output_len(frame_count))?;
+        output.sync_all()?;
+
+        journal.status = JournalStatus::Finalized;
+        journal.frame_count = frame_count;
+        journal.metadata.recovered = recovered;
+        write_journal(directory, &journal)?;
+    }
+    Ok(FinalizedRecording::new(directory, journal))
+}
+
+/// Recording directories under `recordings_root` whose journal was never finalized.
+pub(crate) fn unfinished_recording_directories(recordings_root: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(recordings_root) else {
+        return Vec::new();
+    };
+    let mut directories = entries
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().map(|kind| kind.is_dir()).unwrap_or(false))
+        .map(|entry| entry.path())
+        .filter(|directory| is_unfinished_recording(directory))
+        .collect::<Vec<_>>();
+    directories.sort();
+    directories
+}
+
+/// Whether `directory` holds a recording journal that was never finalized.
+fn is_unfinished_recording(directory: &Path) -> bool {
+    read_journal(directory)
+        .map(|journal| journal.status == JournalStatus::Recording)
+        .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        encode_y4m_frame, finalize_recording_directory, luma_differences,
+        unfinished_recording_directories, JournaledRecordingWriter, RecordingFrames, FRAME_MARKER,
+    };
+    use crate::disk::{DiskSpaceProbe, RecordingDiskBudget, LOW_DISK_SPACE_RESERVE_BYTES};
+    use std::fs::{self, OpenOptions};
+    use std::io::{ErrorKind, Write};
+    use std::path::{Path, PathBuf};
+    use std::sync::Arc;
+
+    fn test_root(label: &str) -> PathBuf {
+        let root = std::env::temp_dir()
+            .canonicalize()
+            .unwrap_or_else(|_| std::env::temp_dir())
+            .join(format!(
+                "gglass-recording-journal-{label}-{}",
+                std::process::id()
+            ));
+        let _ = fs::remove_dir_all(&root);
+        root
+    }
+
+    fn disk_budget(root: &Path, probe: DiskSpaceProbe) -> Arc<RecordingDiskBudget> {
+        Arc::new(RecordingDiskBudget::new(root, probe))
+    }
+
+    #[test]
+    fn encodes_rgb_as_limited_range_planar_yuv() {
+        let frame = encode_y4m_frame(&[0, 0, 0, 255, 255, 255]);
+        assert_eq!(&frame[..FRAME_MARKER.len()], FRAME_MARKER);
+        assert_eq!(&frame[FRAME_MARKER.len()..], &[16, 235, 128, 128, 128, 128]);
+    }
+
+    #[test]
+    fn luma_differences_measure_change_between_frames() {
+        let root = test_root("luma-differences");
+        fs::create_dir_all(&root).expect("create test root");
+        let path = root.join("recording.y4m");
+        let mut stream = b"YUV4MPEG2 W2 H1 F10:1 Ip A1:1 C444\n".to_vec();
+        for pixels in [[0u8; 6], [0; 6], [0, 0, 0, 255, 255, 255]] {
+            stream.extend(encode_y4m_frame(&pixels));
+        }
+        stream.extend_from_slice(b"FRAME\n\x10");
+        fs::write(&path, &stream).expect("write stream");
+
+        let (fps, differences) = luma_differences(&path).expect("analyze stream");
+        assert_eq!(fps, 10.0);
+        assert_eq!(differences, vec![0.0, 0.0, 219.0 / 510.0]);
+
+        fs::write(&path, b"guerillaglass-native-recording").expect("write placeholder");
+        assert!(luma_differences(&path).is_err());
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn recording_frames_seek_to_a_frame_and_convert_it_back_to_rgb() {
+        let root = test_root("recording-frames");
+        fs::create_dir_all(&root).expect("create test root");
+        let path = root.join("recording.y4m");
+        let mut stream = b"YUV4MPEG2 W2 H1 F10:1 Ip A1:1 C444\n".to_vec();
+        for pixels in [[0u8; 6], [0, 0, 0, 255, 255, 255], [255, 0, 0, 0, 0, 0]] {
+            stream.extend(encode_y4m_frame(&pixels));
+        }
+        fs::write(&path, &stream).expect("write stream");
+
+        let mut frames = RecordingFrames::open(&path).expect("open stream");
+        assert_eq!((frames.width, frames.height, frames.fps), (2, 1, 10.0));
+        assert_eq!(frames.frame_count().expect("count frames"), 3);
+        assert_eq!(
+            frames.rgb(2).expect("read frame"),
+            Some(vec![255, 1, 0, 0, 0, 0])
+        );
+        assert_eq!(
+            frames.rgb(1).expect("read frame"),
+            Some(vec![0, 0, 0, 255, 255, 255])
+        );
+        assert_eq!(frames.rgb(3).expect("read past the end"), None);
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn writer_refuses_frames_once_free_space_falls_below_the_reserve() {
+        let root = test_root("low-disk");
+        let pixels = vec![0u8; 2 * 2 * 3];
+        let roomy_budget = disk_budget(&root, |_| Some(LOW_DISK_SPACE_RESERVE_BYTES + 1_000));
+        let mut roomy = JournaledRecordingWriter::create(&root, "roomy", 2, 2, 2, roomy_budget)
+            .expect("create roomy writer");
+        for _ in 0..4 {
+            roomy.append(&pixels).expect("append with headroom");
+        }
+
+        let starved_budget = disk_budget(&root, |_| Some(LOW_DISK_SPACE_RESERVE_BYTES - 1));
+        let mut starved =
+            JournaledRecordingWriter::create(&root, "starved", 2, 2, 2, starved_budget)
+                .expect("create starved writer");
+        for _ in 0..3 {
+            let error = starved
+                .append(&pixels)
+                .expect_err("append without headroom");
+            assert_eq!(error.kind(), ErrorKind::StorageFull);
+        }
+        let finalized = starved
+            .finalize(Default::default())
+            .expect("finalize starved writer");
+        assert_eq!(finalized.frame_count, 0);
+
+        let _ = fs::remove_dir_all(root);
+    }
+
+    #[test]
+    fn finalizing_keeps_the_output_in_place_without_copying_it() {
+        let root = test_root("finalize");
+        let pixels = vec![40u8; 2 * 2 * 3];
+        let mut writer = JournaledRecordingWriter::create(
+            &root,
+            "take-1",
+            2,
+            2,
+            1,
+            disk_budget(&root, |_| None),
+        )
+        .expect("create writer");
+        for _ in 0..3 {
+            writer.append(&pixels).expect("append frame");
+        }
+        let output_path = writer.output_path();
+        let live = fs::metadata(&output_path).expect("output while recording");
+
+        let finalized = writer
+            .finalize(Default::default())
+            .expect("finalize writer");
+        assert_eq!(finalized.output_path, output_path);
+        assert_eq!(finalized.frame_count, 3);
+        assert_eq!(
+            fs::metadata(&output_path).expect("finalized output").len(),
+            live.len()
+        );
+        let entries = fs::read_dir(root.join("take-1"))
+            .expect("read recording directory")
+            .filter_map(Result::ok)
+            .map(|entry| entry.file_name().into_string().unwrap_or_default())
+            .collect::<std::collections::BTreeSet<_>>();
+        assert_eq!(
+            entries,
+            ["journal.json", "recording.y4m"].map(str::to_string).into()
+        );
+
+        let _ = fs::remove_dir_all(root);
+    }
+
+    #[test]
+    fn interrupted_recording_recovers_complete_frames_past_the_last_checkpoint() {
+        let root = test_root("recover");
+        let pixels = vec![90u8; 4 * 2 * 3];
+        let mut writer = JournaledRecordingWriter::create(
+            &root,
+            "take-1",
+            4,
+            2,
+            1,
+            disk_budget(&root, |_| None),
+        )
+        .expect("create writer");
+        for _ in 0..5 {
+            writer.append(&pixels).expect("append frame");
+        }
+        let directory = root.join("take-1");
+        drop(writer);
+        // A crash mid-append leaves a torn frame at the tail of the output.
+        OpenOptions::new()
+            .append(true)
+            .open(directory.join("recording.y4m"))
+            .and_then(|mut file| file.write_all(b"FRAME\n\x10\x10"))
+            .expect("append torn frame");
+
+        assert_eq!(
+            unfinished_recording_directories(&root),
+            vec![directory.clone()]
+        );
+        let recovered = finalize_recording_directory(&directory, true).expect("recover");
+        assert_eq!(recovered.frame_count, 5);
+        assert_eq!(recovered.duration_seconds, 5.0);
+        assert!(recovered.metadata.recovered);
+        let output = fs::read(&recovered.output_path).expect("read recovered output");
+        let header = b"YUV4MPEG2 W4 H2 F1:1 Ip A1:1 C444\n";
+        assert!(output.starts_with(header));
+        assert_eq!(output.len(), header.len() + 5 * (FRAME_MARKER.len() + 24));
+        assert!(unfinished_recording_directories(&root).is_empty());
+
+        let _ = fs::remove_dir_all(root);
+    }
+}
@@ -0,0 +1,264 @@
+use crate::input_tracking::KeyboardShortcutEvent;
+use crate::params::ShortcutOverlayParams;
+use serde::Serialize;
+
+const XK_SHIFT_L: u32 = 0xffe1;
+const XK_SHIFT_R: u32 = 0xffe2;
+const XK_CONTROL_L: u32 = 0xffe3;
+const XK_CONTROL_R: u32 = 0xffe4;
+const XK_META_L: u32 = 0xffe7;
+const XK_META_R: u32 = 0xffe8;
+const XK_ALT_L: u32 = 0xffe9;
+const XK_ALT_R: u32 = 0xffea;
+const XK_SUPER_L: u32 = 0xffeb;
+const XK_SUPER_R: u32 = 0xffec;
+const XK_F1: u32 = 0xffbe;
+const XK_F24: u32 = 0xffd5;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Modifier {
+    Control,
+    Alt,
+    Shift,
+    Super,
+}
+
+impl Modifier {
+    /// Badge order, matching the usual "Ctrl+Alt+Shift+Super+Key" convention.
+    const ORDER: [Modifier; 4] = [
+        Modifier::Control,
+        Modifier::Alt,
+        Modifier::Shift,
+        Modifier::Super,
+    ];
+
+    fn from_keysym(keysym: u32) -> Option<Self> {
+        match keysym {
+            XK_CONTROL_L | XK_CONTROL_R => Some(Self::Control),
+            XK_ALT_L | XK_ALT_R | XK_META_L | XK_META_R => Some(Self::Alt),
+            XK_SHIFT_L | XK_SHIFT_R => Some(Self::Shift),
+            XK_SUPER_L | XK_SUPER_R => Some(Self::Super),
+            _ => None,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::Control => "Ctrl",
+            Self::Alt => "Alt",
+            Self::Shift => "Shift",
+            Self::Super => "Super",
+        }
+    }
+
+    fn bit(self) -> u8 {
+        1 << self as u8
+    }
+}
+
+/// Badge label for a non-modifier X keysym (unshifted level), or `None` for keys a shortcut
+/// badge cannot name.
+fn key_label(keysym: u32) -> Option<String> {
+    let named = match keysym {
+        0x20 => "Space",
+        0xff08 => "Backspace",
+        0xff09 => "Tab",
+        0xff0d => "Enter",
+        0xff1b => "Esc",
+        0xff50 => "Home",
+        0xff51 => "Left",
+        0xff52 => "Up",
+        0xff53 => "Right",
+        0xff54 => "Down",
+        0xff55 => "PageUp",
+        0xff56 => "PageDown",
+        0xff57 => "End",
+        0xff63 => "Insert",
+        0xffff => "Delete",
+        XK_F1..=XK_F24 => return Some(format!("F{}", keysym - XK_F1 + 1)),
+        0x21..=0x7e => {
+            return char::from_u32(keysym).map(|key| key.to_ascii_uppercase().to_string());
+        }
+        _ => return None,
+    };
+    Some(named.to_string())
+}
+
+/// Turns raw key presses into shortcut combos. The privacy filter only lets through keys
+/// pressed while Ctrl, Alt or Super is held, plus bare function keys, so typed text
+/// (including Shift-only capitals) is never recorded.
+#[derive(Debug, Default)]
+pub(crate) struct ShortcutRecognizer {
+    held_modifiers: u8,
+}
+
+impl ShortcutRecognizer {
+    pub(crate) fn key_event(&mut self, keysym: u32, pressed: bool) -> Option<Vec<String>> {
+        if let Some(modifier) = Modifier::from_keysym(keysym) {
+            if pressed {
+                self.held_modifiers |= modifier.bit();
+            } else {
+                self.held_modifiers &= !modifier.bit();
+            }
+            return None;
+        }
+        if !pressed {
+            return None;
+        }
+        let command_held = [Modifier::Control, Modifier::Alt, Modifier::Super]
+            .iter()
+            .any(|modifier| self.held_modifiers & modifier.bit() != 0);
+        let function_key = (XK_F1..=XK_F24).contains(&keysym);
+        if !command_held && !function_key {
+            return None;
+        }
+        let key = key_label(keysym)?;
+        let mut keys = Modifier::ORDER
+            .iter()
+            .filter(|modifier| self.held_modifiers & modifier.bit() != 0)
+            .map(|modifier| modifier.label().to_string())
+            .collect::<Vec<_>>();
+        keys.push(key);
+        Some(keys)
+    }
+}
+
+/// One on-screen key badge in an exported video.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ShortcutBadge {
+    pub(crate) label: String,
+    pub(crate) start_seconds: f64,
+    pub(crate) end_seconds: f64,
+    pub(crate) repeat_count: u32,
+}
+
+/// Lays shortcuts out as non-overlapping badges: each shows for `displayDurationSeconds`, a
+/// newer shortcut replaces the current badge, and repeats of the same combo extend it.
+pub(crate) fn plan_badges(
+    shortcuts: &[KeyboardShortcutEvent],
+    settings: &ShortcutOverlayParams,
+) -> Vec<ShortcutBadge> {
+    if !settings.enabled {
+        return Vec::new();
+    }
+    let mut badges: Vec<ShortcutBadge> = Vec::new();
+    for shortcut in shortcuts {
+        let label = shortcut.keys.join("+");
+        let start_seconds = shortcut.timestamp;
+        let end_seconds = start_seconds + settings.display_duration_seconds;
+        if let Some(current) = badges.last_mut() {
+            if start_seconds < current.end_seconds {
+                if current.label == label {
+                    current.end_seconds = end_seconds;
+                    current.repeat_count += 1;
+                    continue;
+                }
+                current.end_seconds = start_seconds;
+            }
+        }
+        badges.push(ShortcutBadge {
+            label,
+            start_seconds,
+            end_seconds,
+            repeat_count: 1,
+        });
+    }
+    badges.retain(|badge| badge.end_seconds > badge.start_seconds);
+    badges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{plan_badges, ShortcutBadge, ShortcutRecognizer};
+    use crate::input_tracking::KeyboardShortcutEvent;
+    use crate::params::ShortcutOverlayParams;
+
+    const CONTROL_L: u32 = 0xffe3;
+    const SHIFT_L: u32 = 0xffe1;
+    const ALT_L: u32 = 0xffe9;
+
+    fn press_release(recognizer: &mut ShortcutRecognizer, keysym: u32) -> Option<Vec<String>> {
+        let combo = recognizer.key_event(keysym, true);
+        recognizer.key_event(keysym, false);
+        combo
+    }
+
+    #[test]
+    fn recognizer_records_modifier_combos_and_never_plain_text() {
+        let mut recognizer = ShortcutRecognizer::default();
+        for keysym in "hunter2".chars().map(u32::from) {
+            assert_eq!(press_release(&mut recognizer, keysym), None);
+        }
+        recognizer.key_event(SHIFT_L, true);
+        assert_eq!(press_release(&mut recognizer, u32::from('p')), None);
+
+        recognizer.key_event(CONTROL_L, true);
+        assert_eq!(
+            press_release(&mut recognizer, u32::from('p')),
+            Some(vec!["Ctrl".into(), "Shift".into(), "P".into()])
+        );
+        recognizer.key_event(SHIFT_L, false);
+        recognizer.key_event(CONTROL_L, false);
+        assert_eq!(press_release(&mut recognizer, u32::from('p')), None);
+
+        recognizer.key_event(ALT_L, true);
+        assert_eq!(
+            press_release(&mut recognizer, 0xff09),
+            Some(vec!["Alt".into(), "Tab".into()])
+        );
+        recognizer.key_event(ALT_L, false);
+        assert_eq!(
+            press_release(&mut recognizer, 0xffc2),
+            Some(vec!["F5".into()])
+        );
+        assert_eq!(press_release(&mut recognizer, 0xff0d), None);
+    }
+
+    #[test]
+    fn badges_replace_each_other_and_merge_repeats() {
+        let shortcut = |timestamp: f64, keys: &[&str]| KeyboardShortcutEvent {
+            timestamp,
+            keys: keys.iter().map(|key| key.to_string()).collect(),
+        };
+        let shortcuts = [
+            shortcut(1.0, &["Ctrl", "C"]),
+            shortcut(1.5, &["Ctrl", "V"]),
+            shortcut(2.0, &["Ctrl", "V"]),
+            shortcut(6.0, &["F5"]),
+        ];
+        let settings = ShortcutOverlayParams {
+            enabled: true,
+            display_duration_seconds: 1.2,
+            ..ShortcutOverlayParams::default()
+        };
+        assert_eq!(
+            plan_badges(&shortcuts, &settings),
+            vec![
+                ShortcutBadge {
+                    label: "Ctrl+C".into(),
+                    start_seconds: 1.0,
+                    end_seconds: 1.5,
+                    repeat_count: 1,
+                },
+                ShortcutBadge {
+                    label: "Ctrl+V".into(),
+                    start_seconds: 1.5,
+                    end_seconds: 3.2,
+                    repeat_count: 2,
+                },
+                ShortcutBadge {
+                    label: "F5".into(),
+                    start_seconds: 6.0,
+                    end_seconds: 7.2,
+                    repeat_count: 1,
+                },
+            ]
+        );
+        let disabled = ShortcutOverlayParams {
+            enabled: false,
+            ..settings
+        };
+        assert!(plan_badges(&shortcuts, &disabled).is_empty());
+    }
+}
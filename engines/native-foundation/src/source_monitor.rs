@@ -0,0 +1,93 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+#[cfg(target_os = "linux")]
+use std::sync::OnceLock;
+use std::sync::{Arc, Mutex};
+
+/// A change to the capturable sources reported by the platform watcher.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum SourceChange {
+    /// Monitors were connected, disconnected or reconfigured.
+    DisplaysChanged,
+    /// Top-level windows appeared or disappeared.
+    WindowsChanged,
+    /// The top-level window with this id was closed.
+    WindowClosed(u64),
+}
+
+/// The source a running capture records.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum CapturedSource {
+    Display(u64),
+    Window(u64),
+}
+
+/// Collects source changes from the platform watcher thread until the engine reconciles them.
+/// `revision` grows with every change so clients can tell when `sources.list` is stale.
+#[derive(Default)]
+pub(crate) struct SourceMonitor {
+    revision: AtomicU64,
+    changes: Mutex<Vec<SourceChange>>,
+    /// Started by the first `watch`; holds `None` when there is no display server.
+    #[cfg(target_os = "linux")]
+    watcher: OnceLock<Option<crate::x11_sources::SourceWatcher>>,
+}
+
+impl SourceMonitor {
+    /// Starts watching the platform's displays and windows unless already watching; without a
+    /// display server the monitor only reports changes published by hand.
+    pub(crate) fn watch(self: &Arc<Self>) {
+        #[cfg(target_os = "linux")]
+        self.watcher
+            .get_or_init(|| crate::x11_sources::spawn_watcher(Arc::downgrade(self)).ok());
+    }
+
+    /// Watches the platform and, where it can, the closing of the window with `window_id`.
+    pub(crate) fn watch_window(self: &Arc<Self>, window_id: u64) {
+        self.watch();
+        #[cfg(target_os = "linux")]
+        if let Some(Some(watcher)) = self.watcher.get() {
+            watcher.watch_window(window_id);
+        }
+        #[cfg(not(target_os = "linux"))]
+        let _ = window_id;
+    }
+
+    pub(crate) fn publish(&self, change: SourceChange) {
+        if let Ok(mut changes) = self.changes.lock() {
+            changes.push(change);
+        }
+        self.revision.fetch_add(1, Ordering::SeqCst);
+    }
+
+    pub(crate) fn revision(&self) -> u64 {
+        self.revision.load(Ordering::SeqCst)
+    }
+
+    /// Changes published since the last call.
+    pub(crate) fn take_changes(&self) -> Vec<SourceChange> {
+        self.changes
+            .lock()
+            .map(|mut changes| std::mem::take(&mut *changes))
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SourceChange, SourceMonitor};
+
+    #[test]
+    fn published_changes_bump_the_revision_and_are_taken_once() {
+        let monitor = SourceMonitor::default();
+        monitor.publish(SourceChange::WindowClosed(7));
+        monitor.publish(SourceChange::WindowsChanged);
+
+        assert_eq!(monitor.revision(), 2);
+        assert_eq!(
+            monitor.take_changes(),
+            vec![SourceChange::WindowClosed(7), SourceChange::WindowsChanged]
+        );
+        assert!(monitor.take_changes().is_empty());
+        assert_eq!(monitor.revision(), 2);
+    }
+}
@@ -1,36 +1,131 @@
+use crate::state::State;
 use crate::wire::{success, EngineCallId, EngineResponse};
 use crate::DEFAULT_CAPTURE_FRAME_RATES;
 use serde_json::json;
 
-pub(crate) fn list(id: &EngineCallId) -> EngineResponse {
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct DisplaySource {
+    pub(crate) id: u64,
+    pub(crate) display_name: String,
+    pub(crate) is_primary: bool,
+    pub(crate) width: u32,
+    pub(crate) height: u32,
+}
+
+/// Returns the displays currently connected.
+pub(crate) type DisplayProbe = fn() -> Vec<DisplaySource>;
+
+/// The display reported when the platform cannot enumerate monitors.
+pub(crate) fn synthetic_displays() -> Vec<DisplaySource> {
+    vec![DisplaySource {
+        id: 1,
+        display_name: "Primary Display".to_string(),
+        is_primary: true,
+        width: 1920,
+        height: 1080,
+    }]
+}
+
+pub(crate) fn available_displays() -> Vec<DisplaySource> {
+    #[cfg(target_os = "linux")]
+    {
+        crate::x11_sources::list_displays()
+            .ok()
+            .filter(|displays| !displays.is_empty())
+            .unwrap_or_else(synthetic_displays)
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        synthetic_displays()
+    }
+}
+
+/// The display with `display_id`, or the primary display when no id is given.
+pub(crate) fn display(state: &State, display_id: Option<u64>) -> Option<DisplaySource> {
+    let displays = (state.displays)();
+    match display_id {
+        Some(display_id) => displays
+            .into_iter()
+            .find(|display| display.id == display_id),
+        None => {
+            let primary = displays.iter().position(|display| display.is_primary);
+            displays.into_iter().nth(primary.unwrap_or(0))
+        }
+    }
+}
+
+pub(crate) fn list(id: &EngineCallId, state: &State) -> EngineResponse {
+    // Listing sources is what makes `sourcesRevision` worth watching.
+    state.source_monitor.watch();
+    let displays = (state.displays)()
+        .iter()
+        .map(|display| {
+            json!({
+                "id": display.id,
+                "displayName": display.display_name,
+                "isPrimary": display.is_primary,
+                "width": display.width,
+                "height": display.height,
+                "pixelScale": 1.0,
+                "refreshHz": 60.0,
+                "supportedCaptureFrameRates": DEFAULT_CAPTURE_FRAME_RATES
+            })
+        })
+        .collect::<Vec<_>>();
+    let cameras = (state.cameras)()
+        .iter()
+        .map(|camera| {
+            json!({
+                "id": camera.id,
+                "displayName": camera.name,
+                "devicePath": camera.device_path(),
+            })
+        })
+        .collect::<Vec<_>>();
+    let audio_inputs = (state.audio_devices)()
+        .iter()
+        .map(|device| {
+            json!({
+                "id": device.id,
+                "displayName": device.name,
+                "kind": if device.is_monitor { "monitor" } else { "microphone" },
+            })
+        })
+        .collect::<Vec<_>>();
+    let mut windows = vec![json!({
+        "id": 101,
+        "title": "Desktop",
+        "appName": "System",
+        "width": 1280,
+        "height": 720,
+        "isOnScreen": true,
+        "pixelScale": 1.0,
+        "refreshHz": 60.0,
+        "supportedCaptureFrameRates": DEFAULT_CAPTURE_FRAME_RATES
+    })];
+    // Listed so clients can pick windows to exclude from display capture by id.
+    windows.extend((state.windows)().unwrap_or_default().iter().map(|window| {
+        json!({
+            "id": window.id,
+            "title": window.title,
+            "appName": window.wm_class.last().cloned().unwrap_or_default(),
+            "wmClass": window.wm_class,
+            "width": window.width,
+            "height": window.height,
+            "isOnScreen": true,
+            "pixelScale": 1.0,
+            "refreshHz": 60.0,
+            "supportedCaptureFrameRates": DEFAULT_CAPTURE_FRAME_RATES
+        })
+    }));
     success(
         id,
         json!({
-            "displays": [
-                {
-                    "id": 1,
-                    "displayName": "Primary Display",
-                    "isPrimary": true,
-                    "width": 1920,
-                    "height": 1080,
-                    "pixelScale": 1.0,
-                    "refreshHz": 60.0,
-                    "supportedCaptureFrameRates": DEFAULT_CAPTURE_FRAME_RATES
-                }
-            ],
-            "windows": [
-                {
-                    "id": 101,
-                    "title": "Desktop",
-                    "appName": "System",
-                    "width": 1280,
-                    "height": 720,
-                    "isOnScreen": true,
-                    "pixelScale": 1.0,
-                    "refreshHz": 60.0,
-                    "supportedCaptureFrameRates": DEFAULT_CAPTURE_FRAME_RATES
-                }
-            ]
+            "displays": displays,
+            "windows": windows,
+            "cameras": cameras,
+            "audioInputs": audio_inputs,
+            "sourcesRevision": state.source_monitor.revision(),
         }),
     )
 }
@@ -1,10 +1,34 @@
-use crate::params::BackgroundFramingParams;
+use crate::analysis::VisualAnalysis;
+use crate::audio::{self, AudioDeviceProbe, AudioSourceOpener, AudioTrack, AudioTrackRecorder};
+use crate::camera_keyframes::CameraKeyframeTrack;
+use crate::camera_path::{CameraCrop, PlannedCameraPlan};
+use crate::cameras::{self, CameraProbe, CameraTrack};
+use crate::click_effects::ClickEffects;
+use crate::cursor::CursorFrame;
+use crate::disk::{self, DiskSpaceProbe};
+use crate::idle_cuts::SuggestedCut;
+use crate::input_tracking::InputTracker;
+use crate::params::{
+    BackgroundFramingParams, ClickEffectsParams, CursorRenderingParams, PictureInPictureParams,
+    ReframeParams, SettingsGroup, ShortcutOverlayParams, WindowExclusionParams,
+};
 use crate::path_security::{create_directory_all_no_symlink, write_file_no_symlink};
-use crate::wire::{CaptureClock, RunningDuration};
+use crate::picture_in_picture::PictureInPictureLayout;
+use crate::pipeline::CapturePipeline;
+use crate::privacy_mask::{self, PrivacyMask, WindowProbe};
+use crate::recording::{RecordingMetadata, RecordingSchedule};
+use crate::shortcuts::ShortcutBadge;
+use crate::source_monitor::{CapturedSource, SourceMonitor};
+use crate::sources::{self, DisplayProbe};
+use crate::takes::Take;
+use crate::telemetry::CaptureTelemetrySnapshot;
+use crate::timeline::{ProgramFrame, ResolvedTimelineClip, TimelineItem};
+use crate::wire::{CaptureClock, EngineError, RunningDuration};
 use serde_json::{json, Value};
 use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use time::format_description::well_known::Rfc3339;
 use time::OffsetDateTime;
 
@@ -39,16 +63,76 @@ pub(crate) struct State {
     pub(crate) is_recording: bool,
     pub(crate) capture_session_id: Option<String>,
     pub(crate) next_capture_session_id: u64,
+    pub(crate) capture_pipeline: Option<Arc<CapturePipeline>>,
+    pub(crate) camera_pipeline: Option<Arc<CapturePipeline>>,
+    pub(crate) camera_id: Option<u64>,
+    pub(crate) cameras: CameraProbe,
+    pub(crate) audio_devices: AudioDeviceProbe,
+    pub(crate) audio_sources: AudioSourceOpener,
+    pub(crate) audio_recorders: Vec<Arc<AudioTrackRecorder>>,
+    pub(crate) windows: WindowProbe,
+    pub(crate) displays: DisplayProbe,
+    /// Display and window changes reported by the platform since the last request.
+    pub(crate) source_monitor: Arc<SourceMonitor>,
+    /// The display or window the running capture records, watched for disconnects.
+    pub(crate) captured_source: Option<CapturedSource>,
+    /// Window exclusions masking the running display or region capture.
+    pub(crate) privacy_mask: Option<PrivacyMask>,
+    pub(crate) last_recording_telemetry: Option<CaptureTelemetrySnapshot>,
     pub(crate) recording_duration: RunningDuration,
+    /// Capture-clock time at which the active recording was paused.
+    pub(crate) recording_paused_at_seconds: Option<f64>,
+    pub(crate) recording_metadata: RecordingMetadata,
+    /// Capture-clock time at which the current recording started (or starts, during a
+    /// countdown).
+    pub(crate) recording_started_at_seconds: f64,
+    pub(crate) recording_schedule: Option<RecordingSchedule>,
+    pub(crate) input_tracker: Option<Arc<InputTracker>>,
+    /// Identifier of the journaled recording being written, when a project is open.
+    pub(crate) recording_id: Option<String>,
+    /// Id the recording in progress gets as a take once it finishes.
+    pub(crate) recording_take_id: Option<String>,
+    pub(crate) takes: Vec<Take>,
+    pub(crate) active_take_id: Option<String>,
+    pub(crate) timeline_items: Vec<TimelineItem>,
+    /// Idle stretches from the latest `timeline.suggestCuts` that have not been applied.
+    pub(crate) suggested_cuts: Vec<SuggestedCut>,
+    /// Latest `analysis.visualActivity` result per take, saved with the project.
+    pub(crate) visual_analyses: Vec<VisualAnalysis>,
+    pub(crate) camera_keyframes: CameraKeyframeTrack,
+    pub(crate) latest_export_timeline: Option<Vec<ResolvedTimelineClip>>,
+    /// Take frame shown by each frame of the latest export, after cuts and speed changes.
+    pub(crate) latest_export_program_frames: Option<Vec<ProgramFrame>>,
     pub(crate) recording_url: Option<String>,
+    pub(crate) camera_track: Option<CameraTrack>,
+    pub(crate) audio_tracks: Vec<AudioTrack>,
     pub(crate) events_url: Option<String>,
-    pub(crate) last_error: Option<String>,
+    pub(crate) last_error: Option<EngineError>,
+    pub(crate) disk_space: DiskSpaceProbe,
     pub(crate) project_path: Option<String>,
     pub(crate) auto_zoom_enabled: bool,
     pub(crate) auto_zoom_intensity: f64,
     pub(crate) auto_zoom_min_keyframe_interval: f64,
     pub(crate) background_framing: BackgroundFramingParams,
     pub(crate) latest_export_background_framing: Option<BackgroundFramingParams>,
+    pub(crate) shortcut_overlay: ShortcutOverlayParams,
+    pub(crate) latest_export_shortcut_badges: Option<Vec<ShortcutBadge>>,
+    pub(crate) cursor_rendering: CursorRenderingParams,
+    /// The cursor the latest export redrew from the recorded input.
+    pub(crate) latest_export_cursor: Option<Vec<CursorFrame>>,
+    pub(crate) click_effects: ClickEffectsParams,
+    /// Ripples, spotlight frames and click sounds the latest export synthesized.
+    pub(crate) latest_export_click_effects: Option<ClickEffects>,
+    /// Camera keyframes the latest export planned, from its payload, auto-zoom or a reframe.
+    pub(crate) latest_export_camera_plan: Option<PlannedCameraPlan>,
+    /// Per-frame source crops the latest export derived from its camera plan.
+    pub(crate) latest_export_camera_crops: Option<Vec<CameraCrop>>,
+    pub(crate) picture_in_picture: PictureInPictureParams,
+    pub(crate) latest_export_picture_in_picture: Option<PictureInPictureLayout>,
+    pub(crate) reframe: ReframeParams,
+    /// Project-specific window exclusions; the library defaults apply when unset.
+    pub(crate) window_exclusions: Option<WindowExclusionParams>,
+    pub(crate) default_window_exclusions: WindowExclusionParams,
     pub(crate) capture_metadata: Option<Value>,
     pub(crate) recent_projects: Vec<Value>,
     pub(crate) recents_index_path: PathBuf,
@@ -60,22 +144,67 @@ pub(crate) struct State {
 impl State {
     pub(crate) fn new(recents_index_path: PathBuf) -> Self {
         let recent_projects = load_recent_projects(&recents_index_path);
+        let default_window_exclusions = load_default_window_exclusions(&recents_index_path);
         Self {
             clock: CaptureClock::default(),
             is_running: false,
             is_recording: false,
             capture_session_id: None,
             next_capture_session_id: 0,
+            capture_pipeline: None,
+            camera_pipeline: None,
+            camera_id: None,
+            cameras: cameras::available_cameras,
+            audio_devices: audio::available_input_devices,
+            audio_sources: audio::open_source,
+            audio_recorders: Vec::new(),
+            windows: privacy_mask::available_windows,
+            displays: sources::available_displays,
+            source_monitor: Arc::default(),
+            captured_source: None,
+            privacy_mask: None,
+            last_recording_telemetry: None,
             recording_duration: RunningDuration::default(),
+            recording_paused_at_seconds: None,
+            recording_metadata: RecordingMetadata::default(),
+            recording_started_at_seconds: 0.0,
+            recording_schedule: None,
+            input_tracker: None,
+            recording_id: None,
+            recording_take_id: None,
+            takes: Vec::new(),
+            active_take_id: None,
+            timeline_items: Vec::new(),
+            suggested_cuts: Vec::new(),
+            visual_analyses: Vec::new(),
+            camera_keyframes: CameraKeyframeTrack::default(),
+            latest_export_timeline: None,
+            latest_export_program_frames: None,
             recording_url: None,
+            camera_track: None,
+            audio_tracks: Vec::new(),
             events_url: None,
             last_error: None,
+            disk_space: disk::available_bytes,
             project_path: None,
             auto_zoom_enabled: false,
             auto_zoom_intensity: 0.55,
             auto_zoom_min_keyframe_interval: 0.15,
             background_framing: BackgroundFramingParams::default(),
             latest_export_background_framing: None,
+            shortcut_overlay: ShortcutOverlayParams::default(),
+            latest_export_shortcut_badges: None,
+            cursor_rendering: CursorRenderingParams::default(),
+            latest_export_cursor: None,
+            click_effects: ClickEffectsParams::default(),
+            latest_export_click_effects: None,
+            latest_export_camera_plan: None,
+            latest_export_camera_crops: None,
+            picture_in_picture: PictureInPictureParams::default(),
+            latest_export_picture_in_picture: None,
+            reframe: ReframeParams::default(),
+            window_exclusions: None,
+            default_window_exclusions,
             capture_metadata: None,
             recent_projects,
             recents_index_path,
@@ -89,34 +218,89 @@ impl State {
         self.recording_duration.current(&self.clock)
     }
 
+    pub(crate) fn countdown_remaining_seconds(&self) -> Option<f64> {
+        self.recording_schedule
+            .as_ref()
+            .and_then(|schedule| schedule.countdown_remaining_seconds(self.clock.elapsed_seconds()))
+    }
+
+    /// Capture-clock time at which the active recording hits its time limit, if it has one.
+    pub(crate) fn recording_deadline_seconds(&self) -> Option<f64> {
+        self.recording_schedule
+            .as_ref()
+            .and_then(|schedule| schedule.deadline_seconds)
+    }
+
     pub(crate) fn begin_capture_session(&mut self) {
         self.next_capture_session_id += 1;
         self.capture_session_id = Some(format!("capture-session-{}", self.next_capture_session_id));
     }
 
+    /// Pipelines that feed the active recording: the screen, then the camera when running.
+    pub(crate) fn recording_pipelines(&self) -> impl Iterator<Item = &Arc<CapturePipeline>> {
+        self.capture_pipeline
+            .iter()
+            .chain(self.camera_pipeline.iter())
+    }
+
+    pub(crate) fn capture_telemetry(&self) -> CaptureTelemetrySnapshot {
+        let mut telemetry = self
+            .capture_pipeline
+            .as_ref()
+            .map(|pipeline| pipeline.telemetry(self.current_duration()))
+            .unwrap_or_default();
+        telemetry.audio_tracks = self
+            .audio_recorders
+            .iter()
+            .map(|recorder| recorder.telemetry())
+            .collect();
+        telemetry
+    }
+
+    pub(crate) fn active_take(&self) -> Option<&Take> {
+        let take_id = self.active_take_id.as_deref()?;
+        self.takes.iter().find(|take| take.id == take_id)
+    }
+
+    /// Exclusions used when a capture request does not bring its own.
+    pub(crate) fn effective_window_exclusions(&self) -> &WindowExclusionParams {
+        self.window_exclusions
+            .as_ref()
+            .unwrap_or(&self.default_window_exclusions)
+    }
+
     pub(crate) fn capture_status(&self) -> Value {
         json!({
             "isRunning": self.is_running,
             "isRecording": self.is_recording,
+            "isPaused": self.recording_paused_at_seconds.is_some(),
+            "isCountingDown": self.countdown_remaining_seconds().is_some(),
+            "countdownRemainingSeconds": self.countdown_remaining_seconds(),
+            "recordingRemainingSeconds": self.recording_schedule.as_ref().and_then(|schedule| {
+                schedule.remaining_seconds(self.clock.elapsed_seconds(), self.current_duration())
+            }),
             "captureSessionId": self.capture_session_id,
+            "sourcesRevision": self.source_monitor.revision(),
             "recordingDurationSeconds": self.current_duration(),
             "recordingURL": self.recording_url,
+            "cameraId": self.camera_id,
+            "cameraTrack": self.camera_track,
+            "audioTracks": self.audio_tracks,
+            "audioLevels": self
+                .audio_recorders
+                .iter()
+                .map(|recorder| recorder.levels())
+                .collect::<Vec<_>>(),
+            "privacyMask": self.privacy_mask.as_ref().map(PrivacyMask::status),
             "captureMetadata": self.capture_metadata,
-            "lastError": self.last_error,
+            "recordingMetadata": self.recording_metadata,
+            "lastError": self.last_error.as_ref().map(|error| json!({
+                "code": error.code.as_str(),
+                "message": error.message,
+            })),
             "eventsURL": self.events_url,
-            "lastRecordingTelemetry": Value::Null,
-            "telemetry": {
-                "sourceDroppedFrames": 0,
-                "writerDroppedFrames": 0,
-                "writerBackpressureDrops": 0,
-                "achievedFps": 0.0,
-                "cpuPercent": Value::Null,
-                "memoryBytes": Value::Null,
-                "recordingBitrateMbps": Value::Null,
-                "captureCallbackMs": 0.0,
-                "recordQueueLagMs": 0.0,
-                "writerAppendMs": 0.0,
-            },
+            "lastRecordingTelemetry": self.last_recording_telemetry,
+            "telemetry": self.capture_telemetry(),
         })
     }
 
@@ -129,6 +313,8 @@ impl State {
         json!({
             "projectPath": self.project_path,
             "recordingURL": self.recording_url,
+            "cameraTrack": self.camera_track,
+            "audioTracks": self.audio_tracks,
             "eventsURL": self.events_url,
             "autoZoom": {
                 "isEnabled": self.auto_zoom_enabled,
@@ -136,10 +322,22 @@ impl State {
                 "minimumKeyframeInterval": self.auto_zoom_min_keyframe_interval,
             },
             "backgroundFraming": self.background_framing,
+            "shortcutOverlay": self.shortcut_overlay,
+            "cursorRendering": self.cursor_rendering,
+            "clickEffects": self.click_effects,
+            "pictureInPicture": self.picture_in_picture,
+            "reframe": self.reframe,
+            "windowExclusions": self.window_exclusions,
             "captureMetadata": self.capture_metadata,
+            "recordingMetadata": self.recording_metadata,
+            "takes": self.takes,
+            "activeTakeId": self.active_take_id,
+            "visualAnalyses": self.visual_analyses,
             "timeline": {
                 "version": 2,
-                "items": [],
+                "items": self.timeline_items,
+                "markers": self.recording_metadata.markers,
+                "cameraKeyframes": self.camera_keyframes,
                 "updatedAt": now_iso8601(),
             },
             "agentAnalysis": {
@@ -158,14 +356,26 @@ pub(crate) fn now_iso8601() -> String {
         .unwrap_or_else(|_| "1970-01-01T00:00:00Z".to_string())
 }
 
+fn load_library_index(index_path: &Path) -> Option<Value> {
+    let data = fs::read_to_string(index_path).ok()?;
+    serde_json::from_str::<Value>(&data).ok()
+}
+
+/// Replaces one top-level key of the library index, keeping the others.
+fn update_library_index(index_path: &Path, key: &str, value: Value) {
+    let mut index = load_library_index(index_path)
+        .filter(Value::is_object)
+        .unwrap_or_else(|| json!({}));
+    index[key] = value;
+    if let Some(parent) = index_path.parent() {
+        let _ = create_directory_all_no_symlink(parent);
+    }
+    let _ = write_file_no_symlink(index_path, index.to_string().as_bytes());
+}
+
 pub(crate) fn load_recent_projects(index_path: &Path) -> Vec<Value> {
-    let data = match fs::read_to_string(index_path) {
-        Ok(data) => data,
-        Err(_) => return Vec::new(),
-    };
-    let parsed = match serde_json::from_str::<Value>(&data) {
-        Ok(value) => value,
-        Err(_) => return Vec::new(),
+    let Some(parsed) = load_library_index(index_path) else {
+        return Vec::new();
     };
     parsed
         .get("items")
@@ -182,10 +392,24 @@ pub(crate) fn load_recent_projects(index_path: &Path) -> Vec<Value> {
 }
 
 pub(crate) fn save_recent_projects(index_path: &Path, items: &[Value]) {
-    if let Some(parent) = index_path.parent() {
-        let _ = create_directory_all_no_symlink(parent);
-    }
-    let _ = write_file_no_symlink(index_path, json!({ "items": items }).to_string().as_bytes());
+    update_library_index(index_path, "items", json!(items));
+}
+
+/// Library-wide window exclusions; invalid stored settings fall back to none.
+pub(crate) fn load_default_window_exclusions(index_path: &Path) -> WindowExclusionParams {
+    load_library_index(index_path)
+        .and_then(|index| index.pointer("/defaults/windowExclusions").cloned())
+        .and_then(|value| serde_json::from_value::<WindowExclusionParams>(value).ok())
+        .and_then(|settings| settings.validated().ok())
+        .unwrap_or_default()
+}
+
+pub(crate) fn save_library_defaults(state: &State) {
+    update_library_index(
+        &state.recents_index_path,
+        "defaults",
+        json!({ "windowExclusions": state.default_window_exclusions }),
+    );
 }
 
 pub(crate) fn is_valid_recent_project_item(item: &Value) -> bool {
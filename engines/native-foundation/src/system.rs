@@ -24,19 +24,36 @@ pub(crate) fn capabilities(id: &EngineCallId, platform: &str) -> EngineResponse
             "capture": {
                 "display": true,
                 "window": true,
+                "region": true,
                 "systemAudio": true,
                 "microphone": true,
+                "camera": cfg!(target_os = "linux"),
+                "windowExclusion": cfg!(target_os = "linux"),
+                "sourceChangeNotifications": cfg!(target_os = "linux"),
             },
             "recording": {
-                "inputTracking": true,
+                "inputTracking": cfg!(target_os = "linux"),
+                "pauseResume": true,
+                "crashRecovery": true,
+                "keyboardShortcuts": cfg!(target_os = "linux"),
+                "markers": true,
             },
             "export": {
                 "presets": true,
                 "cutPlan": true,
                 "backgroundFraming": false,
+                "shortcutOverlay": false,
+                "pictureInPicture": false,
+                "reframe": false,
+                "guides": true,
             },
             "project": {
                 "openSave": true,
+                "takes": true,
+                "cameraKeyframes": true,
+                "idleCutSuggestions": true,
+                "clipSpeedRamps": true,
+                "visualAnalysis": true,
             },
             "agent": {
                 "preflight": true,
@@ -0,0 +1,315 @@
+use crate::audio::AudioTrack;
+use crate::cameras::CameraTrack;
+use crate::params::TakeParams;
+use crate::path_security::reject_final_symlink;
+use crate::recording::RecordingMetadata;
+use crate::recording_journal::RECORDINGS_DIRECTORY;
+use crate::state::{now_iso8601, State};
+use crate::telemetry::CaptureTelemetrySnapshot;
+use crate::wire::{
+    decode_params, failure, success, EngineCallId, EngineResponse, ProtocolErrorCode,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Prefix of the placeholder URLs given to recordings made without a project. No media is written
+/// behind them, so their frames are rendered from the synthetic capture source.
+pub(crate) const PLACEHOLDER_RECORDING_PREFIX: &str = "native://recordings/";
+
+/// Longest take name accepted by `takes.rename`, in characters.
+const MAX_TAKE_NAME_CHARS: usize = 120;
+
+/// Capture health of a finished take, kept once the live telemetry is gone.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct TakeTelemetrySummary {
+    pub(crate) achieved_fps: f64,
+    pub(crate) source_dropped_frames: u64,
+    pub(crate) writer_dropped_frames: u64,
+    pub(crate) writer_backpressure_drops: u64,
+    pub(crate) recording_bitrate_mbps: Option<f64>,
+}
+
+impl From<&CaptureTelemetrySnapshot> for TakeTelemetrySummary {
+    fn from(telemetry: &CaptureTelemetrySnapshot) -> Self {
+        Self {
+            achieved_fps: telemetry.achieved_fps,
+            source_dropped_frames: telemetry.source_dropped_frames,
+            writer_dropped_frames: telemetry.writer_dropped_frames,
+            writer_backpressure_drops: telemetry.writer_backpressure_drops,
+            recording_bitrate_mbps: telemetry.recording_bitrate_mbps,
+        }
+    }
+}
+
+/// One recording of a project. Project takes live in `recordings/<id>/` inside the package;
+/// takes recorded without a project only have placeholder URLs.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Take {
+    pub(crate) id: String,
+    pub(crate) name: String,
+    #[serde(rename = "recordingURL")]
+    pub(crate) recording_url: String,
+    #[serde(default)]
+    pub(crate) camera_track: Option<CameraTrack>,
+    #[serde(default)]
+    pub(crate) audio_tracks: Vec<AudioTrack>,
+    #[serde(rename = "eventsURL", default)]
+    pub(crate) events_url: Option<String>,
+    #[serde(default)]
+    pub(crate) capture_metadata: Option<Value>,
+    #[serde(default)]
+    pub(crate) recording_metadata: RecordingMetadata,
+    pub(crate) duration_seconds: f64,
+    #[serde(default)]
+    pub(crate) telemetry: Option<TakeTelemetrySummary>,
+    pub(crate) created_at: String,
+}
+
+/// Whether `recording_url` is a placeholder with no media behind it.
+pub(crate) fn is_placeholder_recording(recording_url: &str) -> bool {
+    recording_url.starts_with(PLACEHOLDER_RECORDING_PREFIX)
+}
+
+/// Id for a take recorded without a project, where there is no recording directory to name it.
+pub(crate) fn next_session_take_id(state: &State) -> String {
+    (state.takes.len() + 1..)
+        .map(|number| format!("session-{number}"))
+        .find(|id| !state.takes.iter().any(|take| &take.id == id))
+        .unwrap_or_default()
+}
+
+fn next_take_name(takes: &[Take]) -> String {
+    (takes.len() + 1..)
+        .map(|number| format!("Take {number}"))
+        .find(|name| !takes.iter().any(|take| &take.name == name))
+        .unwrap_or_default()
+}
+
+/// A take for media finished outside a live recording, such as a recovered session.
+pub(crate) fn new_take(
+    state: &State,
+    id: String,
+    recording_url: String,
+    duration_seconds: f64,
+) -> Take {
+    Take {
+        id,
+        name: next_take_name(&state.takes),
+        recording_url,
+        camera_track: None,
+        audio_tracks: Vec::new(),
+        events_url: None,
+        capture_metadata: None,
+        recording_metadata: RecordingMetadata::default(),
+        duration_seconds,
+        telemetry: None,
+        created_at: now_iso8601(),
+    }
+}
+
+/// Turns the recording that just finished into the project's newest, active take.
+pub(crate) fn record_finished_take(state: &mut State, take_id: String) {
+    let Some(recording_url) = state.recording_url.clone() else {
+        return;
+    };
+    let mut take = new_take(state, take_id, recording_url, state.current_duration());
+    take.camera_track = state.camera_track.clone();
+    take.audio_tracks = state.audio_tracks.clone();
+    take.events_url = state.events_url.clone();
+    take.capture_metadata = state.capture_metadata.clone();
+    take.recording_metadata = state.recording_metadata.clone();
+    take.telemetry = state
+        .last_recording_telemetry
+        .as_ref()
+        .map(TakeTelemetrySummary::from);
+    add_take(state, take);
+}
+
+/// Adds a take, replacing any take with the same id.
+pub(crate) fn insert_take(state: &mut State, take: Take) {
+    state.takes.retain(|existing| existing.id != take.id);
+    state.takes.push(take);
+}
+
+/// Adds a take and makes it the active one.
+pub(crate) fn add_take(state: &mut State, take: Take) {
+    insert_take(state, take.clone());
+    activate_take(state, &take);
+}
+
+/// Points the project's current recording at `take`.
+pub(crate) fn activate_take(state: &mut State, take: &Take) {
+    state.active_take_id = Some(take.id.clone());
+    state.recording_url = Some(take.recording_url.clone());
+    state.camera_track = take.camera_track.clone();
+    state.audio_tracks = take.audio_tracks.clone();
+    state.events_url = take.events_url.clone();
+    state.recording_metadata = take.recording_metadata.clone();
+}
+
+/// Leaves the project without a current recording.
+pub(crate) fn clear_active_take(state: &mut State) {
+    state.active_take_id = None;
+    state.recording_url = None;
+    state.camera_track = None;
+    state.audio_tracks.clear();
+    state.events_url = None;
+    state.recording_metadata = RecordingMetadata::default();
+}
+
+/// The take's directory inside the open project, if its media lives there.
+fn take_directory(state: &State, take: &Take) -> Option<PathBuf> {
+    let project_path = state.project_path.as_ref()?;
+    let directory = Path::new(project_path)
+        .join(RECORDINGS_DIRECTORY)
+        .join(&take.id);
+    Path::new(&take.recording_url)
+        .starts_with(&directory)
+        .then_some(directory)
+}
+
+fn takes_result(state: &State) -> Value {
+    json!({
+        "takes": state.takes,
+        "activeTakeId": state.active_take_id,
+    })
+}
+
+fn find_take(
+    id: &EngineCallId,
+    state: &State,
+    params: &TakeParams,
+) -> Result<usize, EngineResponse> {
+    let Some(take_id) = params.take_id.as_deref() else {
+        return Err(failure(
+            id,
+            ProtocolErrorCode::InvalidParams,
+            "takeId is required",
+        ));
+    };
+    state
+        .takes
+        .iter()
+        .position(|take| take.id == take_id)
+        .ok_or_else(|| {
+            failure(
+                id,
+                ProtocolErrorCode::InvalidParams,
+                format!("Unknown takeId: {take_id}"),
+            )
+        })
+}
+
+pub(crate) fn list(id: &EngineCallId, state: &State) -> EngineResponse {
+    success(id, takes_result(state))
+}
+
+pub(crate) fn rename(id: &EngineCallId, state: &mut State, params: &Value) -> EngineResponse {
+    let take_params = match decode_params::<TakeParams>(id, params, "take") {
+        Ok(params) => params,
+        Err(response) => return response,
+    };
+    let index = match find_take(id, state, &take_params) {
+        Ok(index) => index,
+        Err(response) => return response,
+    };
+    let name = take_params.name.as_deref().map(str::trim).unwrap_or("");
+    if name.is_empty() || name.chars().count() > MAX_TAKE_NAME_CHARS {
+        return failure(
+            id,
+            ProtocolErrorCode::InvalidParams,
+            "name must be between 1 and 120 characters",
+        );
+    }
+    state.takes[index].name = name.to_string();
+    state.unsaved_changes = true;
+    success(id, takes_result(state))
+}
+
+pub(crate) fn activate(id: &EngineCallId, state: &mut State, params: &Value) -> EngineResponse {
+    let take_params = match decode_params::<TakeParams>(id, params, "take") {
+        Ok(params) => params,
+        Err(response) => return response,
+    };
+    let index = match find_take(id, state, &take_params) {
+        Ok(index) => index,
+        Err(response) => return response,
+    };
+    if state.is_recording {
+        return failure(
+            id,
+            ProtocolErrorCode::InvalidParams,
+            "Stop recording before switching takes",
+        );
+    }
+    let take = state.takes[index].clone();
+    activate_take(state, &take);
+    state.unsaved_changes = true;
+    success(id, takes_result(state))
+}
+
+/// Removes a take and its media from the project package.
+pub(crate) fn delete(id: &EngineCallId, state: &mut State, params: &Value) -> EngineResponse {
+    let take_params = match decode_params::<TakeParams>(id, params, "take") {
+        Ok(params) => params,
+        Err(response) => return response,
+    };
+    let index = match find_take(id, state, &take_params) {
+        Ok(index) => index,
+        Err(response) => return response,
+    };
+    if state.is_recording {
+        return failure(
+            id,
+            ProtocolErrorCode::InvalidParams,
+            "Stop recording before deleting takes",
+        );
+    }
+    let take = state.takes[index].clone();
+    let clips = state
+        .timeline_items
+        .iter()
+        .filter(|item| item.source_asset_id() == Some(take.id.as_str()))
+        .count();
+    if clips > 0 {
+        return failure(
+            id,
+            ProtocolErrorCode::InvalidParams,
+            format!("Take {} is used by {clips} timeline clips", take.id),
+        );
+    }
+    if !take_params.destructive_intent.unwrap_or(false) {
+        return failure(
+            id,
+            ProtocolErrorCode::NeedsConfirmation,
+            "Deleting a take removes its media. Retry with destructiveIntent=true to continue.",
+        );
+    }
+    if let Some(directory) = take_directory(state, &take).filter(|directory| directory.exists()) {
+        let removed =
+            reject_final_symlink(&directory).and_then(|()| fs::remove_dir_all(&directory));
+        if let Err(error) = removed {
+            return failure(
+                id,
+                ProtocolErrorCode::PermissionDenied,
+                format!("Unable to delete take media safely: {error}"),
+            );
+        }
+    }
+    state.takes.remove(index);
+    state
+        .visual_analyses
+        .retain(|analysis| analysis.take_id != take.id);
+    if state.active_take_id.as_deref() == Some(take.id.as_str()) {
+        match state.takes.last().cloned() {
+            Some(latest) => activate_take(state, &latest),
+            None => clear_active_take(state),
+        }
+    }
+    state.unsaved_changes = true;
+    success(id, takes_result(state))
+}
@@ -0,0 +1,419 @@
+use crate::audio::AudioTrackTelemetry;
+use serde::Serialize;
+use std::sync::Mutex;
+use std::time::Instant;
+
+const CAPTURE_CALLBACK_SMOOTHING: f64 = 0.2;
+const RECORD_QUEUE_LAG_SMOOTHING: f64 = 0.2;
+const WRITER_APPEND_SMOOTHING: f64 = 0.18;
+const CPU_SMOOTHING: f64 = 0.22;
+const BITRATE_SMOOTHING: f64 = 0.3;
+
+/// Capture health snapshot, field-compatible with the Swift `CaptureTelemetrySummary`.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct CaptureTelemetrySnapshot {
+    pub(crate) source_dropped_frames: u64,
+    pub(crate) writer_dropped_frames: u64,
+    pub(crate) writer_backpressure_drops: u64,
+    pub(crate) achieved_fps: f64,
+    pub(crate) cpu_percent: Option<f64>,
+    pub(crate) memory_bytes: Option<u64>,
+    pub(crate) recording_bitrate_mbps: Option<f64>,
+    pub(crate) capture_callback_ms: f64,
+    pub(crate) record_queue_lag_ms: f64,
+    pub(crate) writer_append_ms: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) preview_encode_ms: Option<f64>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub(crate) audio_tracks: Vec<AudioTrackTelemetry>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum WriterAppendOutcome {
+    Appended,
+    DroppedBackpressure,
+    Failed,
+}
+
+pub(crate) fn estimate_missed_frames(delta_seconds: f64, expected_interval_seconds: f64) -> u64 {
+    if !delta_seconds.is_finite()
+        || !expected_interval_seconds.is_finite()
+        || expected_interval_seconds <= 0.0
+        || delta_seconds <= expected_interval_seconds * 1.5
+    {
+        return 0;
+    }
+    let estimated_frames = (delta_seconds / expected_interval_seconds).floor() as u64;
+    estimated_frames.saturating_sub(1)
+}
+
+pub(crate) fn achieved_frames_per_second(
+    frame_count: u64,
+    first_pts_seconds: Option<f64>,
+    last_pts_seconds: Option<f64>,
+) -> f64 {
+    let (Some(first), Some(last)) = (first_pts_seconds, last_pts_seconds) else {
+        return 0.0;
+    };
+    let span = last - first;
+    if frame_count <= 1 || !span.is_finite() || span <= 0.0 {
+        return 0.0;
+    }
+    (frame_count - 1) as f64 / span
+}
+
+#[derive(Clone, Copy, Debug)]
+struct TimingMetric {
+    smoothed_ms: Option<f64>,
+    smoothing_factor: f64,
+}
+
+impl TimingMetric {
+    fn new(smoothing_factor: f64) -> Self {
+        Self {
+            smoothed_ms: None,
+            smoothing_factor,
+        }
+    }
+
+    fn value(&self) -> f64 {
+        self.smoothed_ms.unwrap_or(0.0)
+    }
+
+    fn record(&mut self, raw_ms: f64) {
+        let clamped = raw_ms.max(0.0);
+        self.smoothed_ms = Some(match self.smoothed_ms {
+            Some(smoothed) => smoothed + (clamped - smoothed) * self.smoothing_factor,
+            None => clamped,
+        });
+    }
+}
+
+#[derive(Debug)]
+struct TelemetryCounters {
+    complete_frames: u64,
+    source_status_dropped_frames: u64,
+    source_timing_dropped_frames: u64,
+    writer_backpressure_drops: u64,
+    writer_failed_drops: u64,
+    written_bytes: u64,
+    first_frame_pts_seconds: Option<f64>,
+    last_frame_pts_seconds: Option<f64>,
+    capture_callback: TimingMetric,
+    record_queue_lag: TimingMetric,
+    writer_append: TimingMetric,
+}
+
+impl Default for TelemetryCounters {
+    fn default() -> Self {
+        Self {
+            complete_frames: 0,
+            source_status_dropped_frames: 0,
+            source_timing_dropped_frames: 0,
+            writer_backpressure_drops: 0,
+            writer_failed_drops: 0,
+            written_bytes: 0,
+            first_frame_pts_seconds: None,
+            last_frame_pts_seconds: None,
+            capture_callback: TimingMetric::new(CAPTURE_CALLBACK_SMOOTHING),
+            record_queue_lag: TimingMetric::new(RECORD_QUEUE_LAG_SMOOTHING),
+            writer_append: TimingMetric::new(WRITER_APPEND_SMOOTHING),
+        }
+    }
+}
+
+/// Counters shared by the capture and writer stages of the pipeline.
+#[derive(Debug, Default)]
+pub(crate) struct CaptureTelemetryStore {
+    counters: Mutex<TelemetryCounters>,
+    runtime: Mutex<RuntimeTelemetryMonitor>,
+}
+
+impl CaptureTelemetryStore {
+    fn with_counters(&self, callback: impl FnOnce(&mut TelemetryCounters)) {
+        if let Ok(mut counters) = self.counters.lock() {
+            callback(&mut counters);
+        }
+    }
+
+    pub(crate) fn reset(&self) {
+        self.with_counters(|counters| *counters = TelemetryCounters::default());
+        if let Ok(mut runtime) = self.runtime.lock() {
+            runtime.reset();
+            runtime.sample(0, 0.0);
+        }
+    }
+
+    pub(crate) fn record_source_drop(&self) {
+        self.with_counters(|counters| counters.source_status_dropped_frames += 1);
+    }
+
+    pub(crate) fn record_complete_frame(&self, pts_seconds: f64, capture_fps: u64) {
+        let expected_interval_seconds = 1.0 / capture_fps.max(1) as f64;
+        self.with_counters(|counters| {
+            counters.complete_frames += 1;
+            match counters.last_frame_pts_seconds {
+                Some(previous) => {
+                    counters.source_timing_dropped_frames += estimate_missed_frames(
+                        (pts_seconds - previous).max(0.0),
+                        expected_interval_seconds,
+                    );
+                }
+                None => counters.first_frame_pts_seconds = Some(pts_seconds),
+            }
+            counters.last_frame_pts_seconds = Some(pts_seconds);
+        });
+    }
+
+    pub(crate) fn record_capture_callback(&self, duration_ms: f64) {
+        self.with_counters(|counters| counters.capture_callback.record(duration_ms));
+    }
+
+    pub(crate) fn record_queue_lag(&self, lag_ms: f64) {
+        self.with_counters(|counters| counters.record_queue_lag.record(lag_ms));
+    }
+
+    pub(crate) fn record_writer_append(
+        &self,
+        outcome: WriterAppendOutcome,
+        duration_ms: f64,
+        bytes: u64,
+    ) {
+        self.with_counters(|counters| {
+            counters.writer_append.record(duration_ms);
+            match outcome {
+                WriterAppendOutcome::Appended => counters.written_bytes += bytes,
+                WriterAppendOutcome::DroppedBackpressure => counters.writer_backpressure_drops += 1,
+                WriterAppendOutcome::Failed => counters.writer_failed_drops += 1,
+            }
+        });
+    }
+
+    /// Samples process usage and folds it into the current pipeline counters.
+    pub(crate) fn snapshot(&self, recording_duration_seconds: f64) -> CaptureTelemetrySnapshot {
+        let Ok(counters) = self.counters.lock() else {
+            return CaptureTelemetrySnapshot::default();
+        };
+        let runtime = self
+            .runtime
+            .lock()
+            .map(|mut runtime| runtime.sample(counters.written_bytes, recording_duration_seconds))
+            .unwrap_or_default();
+        CaptureTelemetrySnapshot {
+            source_dropped_frames: counters.source_status_dropped_frames
+                + counters.source_timing_dropped_frames,
+            writer_dropped_frames: counters.writer_backpressure_drops
+                + counters.writer_failed_drops,
+            writer_backpressure_drops: counters.writer_backpressure_drops,
+            achieved_fps: achieved_frames_per_second(
+                counters.complete_frames,
+                counters.first_frame_pts_seconds,
+                counters.last_frame_pts_seconds,
+            ),
+            cpu_percent: runtime.cpu_percent,
+            memory_bytes: runtime.memory_bytes,
+            recording_bitrate_mbps: runtime.recording_bitrate_mbps,
+            capture_callback_ms: counters.capture_callback.value(),
+            record_queue_lag_ms: counters.record_queue_lag.value(),
+            writer_append_ms: counters.writer_append.value(),
+            preview_encode_ms: None,
+            audio_tracks: Vec::new(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+struct RuntimeTelemetrySample {
+    cpu_percent: Option<f64>,
+    memory_bytes: Option<u64>,
+    recording_bitrate_mbps: Option<f64>,
+}
+
+#[derive(Debug, Default)]
+struct RuntimeTelemetryMonitor {
+    previous_sample_at: Option<Instant>,
+    previous_cpu_seconds: Option<f64>,
+    previous_output_bytes: Option<u64>,
+    smoothed_cpu_percent: Option<f64>,
+    smoothed_bitrate_mbps: Option<f64>,
+}
+
+impl RuntimeTelemetryMonitor {
+    fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    fn sample(
+        &mut self,
+        output_bytes: u64,
+        recording_duration_seconds: f64,
+    ) -> RuntimeTelemetrySample {
+        let now = Instant::now();
+        let cpu_seconds = process_cpu_seconds();
+        let elapsed_seconds = self
+            .previous_sample_at
+            .map(|previous| now.duration_since(previous).as_secs_f64());
+
+        let cpu_percent = match (cpu_seconds, self.previous_cpu_seconds, elapsed_seconds) {
+            (Some(current), Some(previous), Some(elapsed)) if elapsed > 0.0 => {
+                let processors = std::thread::available_parallelism()
+                    .map(|count| count.get())
+                    .unwrap_or(1) as f64;
+                let raw = ((current - previous).max(0.0) / elapsed * 100.0)
+                    .clamp(0.0, processors * 100.0);
+                let next = match self.smoothed_cpu_percent {
+                    Some(smoothed) => smoothed + (raw - smoothed) * CPU_SMOOTHING,
+                    None => raw,
+                };
+                self.smoothed_cpu_percent = Some(next);
+                Some(next)
+            }
+            (Some(_), Some(_), Some(_)) => self.smoothed_cpu_percent,
+            _ => None,
+        };
+
+        let recording_bitrate_mbps = if recording_duration_seconds > 0.1 {
+            let raw = match (self.previous_output_bytes, elapsed_seconds) {
+                (Some(previous), Some(elapsed)) if elapsed > 0.0 && output_bytes >= previous => {
+                    Some((output_bytes - previous) as f64 * 8.0 / elapsed / 1_000_000.0)
+                }
+                (Some(_), _) => None,
+                (None, _) => {
+                    Some(output_bytes as f64 * 8.0 / recording_duration_seconds / 1_000_000.0)
+                }
+            };
+            match raw {
+                Some(raw) => {
+                    let next = match self.smoothed_bitrate_mbps {
+                        Some(smoothed) => smoothed + (raw - smoothed) * BITRATE_SMOOTHING,
+                        None => raw,
+                    };
+                    self.smoothed_bitrate_mbps = Some(next);
+                    Some(next)
+                }
+                None => self.smoothed_bitrate_mbps,
+            }
+        } else {
+            self.smoothed_bitrate_mbps = None;
+            None
+        };
+
+        self.previous_sample_at = Some(now);
+        self.previous_cpu_seconds = cpu_seconds;
+        self.previous_output_bytes = Some(output_bytes);
+
+        RuntimeTelemetrySample {
+            cpu_percent,
+            memory_bytes: resident_memory_bytes(),
+            recording_bitrate_mbps,
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn clock_ticks_per_second() -> Option<f64> {
+    // SAFETY: sysconf has no memory-safety preconditions.
+    let ticks = unsafe { libc::sysconf(libc::_SC_CLK_TCK) };
+    (ticks > 0).then_some(ticks as f64)
+}
+
+#[cfg(target_os = "linux")]
+fn page_size_bytes() -> Option<u64> {
+    // SAFETY: sysconf has no memory-safety preconditions.
+    let page_size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) };
+    (page_size > 0).then_some(page_size as u64)
+}
+
+/// Parses utime + stime from `/proc/self/stat`, skipping the parenthesised command name.
+#[cfg(target_os = "linux")]
+pub(crate) fn parse_proc_stat_cpu_ticks(stat: &str) -> Option<u64> {
+    let fields = stat
+        .get(stat.rfind(')')? + 1..)?
+        .split_whitespace()
+        .collect::<Vec<&str>>();
+    // After the command name, field 3 (state) is index 0, so utime (14) and stime (15) are 11 and 12.
+    let user_ticks = fields.get(11)?.parse::<u64>().ok()?;
+    let system_ticks = fields.get(12)?.parse::<u64>().ok()?;
+    Some(user_ticks + system_ticks)
+}
+
+#[cfg(target_os = "linux")]
+fn process_cpu_seconds() -> Option<f64> {
+    let stat = std::fs::read_to_string("/proc/self/stat").ok()?;
+    Some(parse_proc_stat_cpu_ticks(&stat)? as f64 / clock_ticks_per_second()?)
+}
+
+#[cfg(target_os = "linux")]
+fn resident_memory_bytes() -> Option<u64> {
+    let statm = std::fs::read_to_string("/proc/self/statm").ok()?;
+    let resident_pages = statm.split_whitespace().nth(1)?.parse::<u64>().ok()?;
+    Some(resident_pages * page_size_bytes()?)
+}
+
+#[cfg(not(target_os = "linux"))]
+fn process_cpu_seconds() -> Option<f64> {
+    None
+}
+
+#[cfg(not(target_os = "linux"))]
+fn resident_memory_bytes() -> Option<u64> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        achieved_frames_per_second, estimate_missed_frames, CaptureTelemetryStore,
+        WriterAppendOutcome,
+    };
+
+    #[test]
+    fn missed_frames_only_count_gaps_beyond_one_and_a_half_intervals() {
+        let interval = 1.0 / 30.0;
+        assert_eq!(estimate_missed_frames(interval, interval), 0);
+        assert_eq!(estimate_missed_frames(interval * 1.4, interval), 0);
+        assert_eq!(estimate_missed_frames(interval * 3.2, interval), 2);
+        assert_eq!(estimate_missed_frames(f64::NAN, interval), 0);
+        assert_eq!(estimate_missed_frames(1.0, 0.0), 0);
+    }
+
+    #[test]
+    fn achieved_fps_uses_first_and_last_presentation_timestamps() {
+        assert_eq!(achieved_frames_per_second(1, Some(0.0), Some(0.0)), 0.0);
+        assert_eq!(achieved_frames_per_second(31, Some(1.0), Some(2.0)), 30.0);
+        assert_eq!(achieved_frames_per_second(10, None, Some(2.0)), 0.0);
+    }
+
+    #[test]
+    fn store_aggregates_source_and_writer_drops() {
+        let store = CaptureTelemetryStore::default();
+        store.reset();
+        let interval = 1.0 / 30.0;
+        for index in [0, 1, 2, 5] {
+            store.record_complete_frame(index as f64 * interval, 30);
+        }
+        store.record_source_drop();
+        store.record_writer_append(WriterAppendOutcome::Appended, 2.0, 1_000);
+        store.record_writer_append(WriterAppendOutcome::DroppedBackpressure, 0.0, 0);
+        store.record_writer_append(WriterAppendOutcome::DroppedBackpressure, 0.0, 0);
+        store.record_capture_callback(4.0);
+        store.record_capture_callback(9.0);
+
+        let snapshot = store.snapshot(0.0);
+        assert_eq!(snapshot.source_dropped_frames, 3);
+        assert_eq!(snapshot.writer_dropped_frames, 2);
+        assert_eq!(snapshot.writer_backpressure_drops, 2);
+        assert!((snapshot.achieved_fps - 18.0).abs() < 1e-9);
+        assert!((snapshot.capture_callback_ms - 5.0).abs() < 1e-9);
+        assert_eq!(snapshot.recording_bitrate_mbps, None);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn proc_stat_parser_handles_command_names_with_spaces() {
+        let stat = "4242 (gg engine) S 1 2 3 4 5 6 7 8 9 10 120 30 0 0 20 0 8 0 100 0 0";
+        assert_eq!(super::parse_proc_stat_cpu_ticks(stat), Some(150));
+        assert_eq!(super::parse_proc_stat_cpu_ticks("garbage"), None);
+    }
+}
@@ -0,0 +1,554 @@
+use crate::takes::Take;
+use serde::{Deserialize, Serialize};
+
+/// `sourceAssetId` of clips that play whichever take is active.
+pub(crate) const ACTIVE_TAKE_ASSET_ID: &str = "recording";
+
+/// Slowest and fastest rates a clip may play at.
+pub(crate) const MIN_PLAYBACK_RATE: f64 = 0.25;
+pub(crate) const MAX_PLAYBACK_RATE: f64 = 16.0;
+/// Above this rate pitch-corrected speech stops being intelligible, so the audio is muted.
+pub(crate) const MAX_PITCH_PRESERVED_RATE: f64 = 4.0;
+
+/// What happens to a clip's audio while it does not play in real time.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) enum SpeedAudio {
+    #[default]
+    PreservePitch,
+    Mute,
+}
+
+/// How an export renders a clip's audio.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) enum ClipAudio {
+    Normal,
+    PitchPreserved,
+    Muted,
+}
+
+fn real_time() -> f64 {
+    1.0
+}
+
+fn is_real_time(rate: &f64) -> bool {
+    *rate == 1.0
+}
+
+fn is_zero(seconds: &f64) -> bool {
+    *seconds == 0.0
+}
+
+fn is_default_speed_audio(audio: &SpeedAudio) -> bool {
+    *audio == SpeedAudio::default()
+}
+
+/// Playback speed of a clip. Between its ramps the clip plays at `playback_rate`; over the
+/// ramps the rate changes linearly in source time from real time and back. Ramp lengths are in
+/// source seconds.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ClipSpeed {
+    #[serde(default = "real_time", skip_serializing_if = "is_real_time")]
+    pub(crate) playback_rate: f64,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub(crate) ramp_in_seconds: f64,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub(crate) ramp_out_seconds: f64,
+    #[serde(default, skip_serializing_if = "is_default_speed_audio")]
+    pub(crate) speed_audio: SpeedAudio,
+}
+
+impl Default for ClipSpeed {
+    fn default() -> Self {
+        Self {
+            playback_rate: 1.0,
+            ramp_in_seconds: 0.0,
+            ramp_out_seconds: 0.0,
+            speed_audio: SpeedAudio::default(),
+        }
+    }
+}
+
+/// Program seconds spent ramping between real time and `rate` over `length` source seconds.
+fn ramp_program_seconds(length: f64, rate: f64) -> f64 {
+    if rate == 1.0 {
+        length
+    } else {
+        length * rate.ln() / (rate - 1.0)
+    }
+}
+
+impl ClipSpeed {
+    pub(crate) fn validated(self, source_seconds: f64) -> Result<Self, String> {
+        if !self.playback_rate.is_finite()
+            || !(MIN_PLAYBACK_RATE..=MAX_PLAYBACK_RATE).contains(&self.playback_rate)
+        {
+            return Err(format!(
+                "timeline.items playbackRate must be between {MIN_PLAYBACK_RATE} and {MAX_PLAYBACK_RATE}"
+            ));
+        }
+        let ramps = [self.ramp_in_seconds, self.ramp_out_seconds];
+        if ramps.iter().any(|ramp| !ramp.is_finite() || *ramp < 0.0)
+            || ramps.iter().sum::<f64>() > source_seconds
+        {
+            return Err(
+                "timeline.items ramps must be non-negative and fit inside the clip".to_string(),
+            );
+        }
+        Ok(self)
+    }
+
+    /// Real-time playback plays everything as recorded.
+    pub(crate) fn is_real_time(&self) -> bool {
+        self.playback_rate == 1.0
+    }
+
+    /// Source seconds played at the full rate between the ramps.
+    fn body_seconds(&self, source_seconds: f64) -> f64 {
+        (source_seconds - self.ramp_in_seconds - self.ramp_out_seconds).max(0.0)
+    }
+
+    /// How long `source_seconds` of media take to play.
+    pub(crate) fn program_seconds(&self, source_seconds: f64) -> f64 {
+        let rate = self.playback_rate;
+        ramp_program_seconds(self.ramp_in_seconds, rate)
+            + self.body_seconds(source_seconds) / rate
+            + ramp_program_seconds(self.ramp_out_seconds, rate)
+    }
+
+    /// Program offset at which the media `source_offset` into the clip plays.
+    pub(crate) fn program_offset(&self, source_offset: f64, source_seconds: f64) -> f64 {
+        let rate = self.playback_rate;
+        let (ramp_in, body) = (self.ramp_in_seconds, self.body_seconds(source_seconds));
+        let offset = source_offset.clamp(0.0, source_seconds);
+        if rate == 1.0 {
+            return offset;
+        }
+        if offset < ramp_in {
+            return ramp_in / (rate - 1.0) * (1.0 + (rate - 1.0) * offset / ramp_in).ln();
+        }
+        let ramp_in_program = ramp_program_seconds(ramp_in, rate);
+        if offset <= ramp_in + body || self.ramp_out_seconds <= 0.0 {
+            return ramp_in_program + (offset - ramp_in) / rate;
+        }
+        let ramp_out = self.ramp_out_seconds;
+        let into_ramp = offset - ramp_in - body;
+        ramp_in_program
+            + body / rate
+            + ramp_out / (1.0 - rate) * ((rate + (1.0 - rate) * into_ramp / ramp_out) / rate).ln()
+    }
+
+    /// Source offset into the clip shown `program_offset` into its playback; the inverse of
+    /// [`ClipSpeed::program_offset`].
+    pub(crate) fn source_offset(&self, program_offset: f64, source_seconds: f64) -> f64 {
+        let rate = self.playback_rate;
+        let (ramp_in, body) = (self.ramp_in_seconds, self.body_seconds(source_seconds));
+        let offset = program_offset.clamp(0.0, self.program_seconds(source_seconds));
+        if rate == 1.0 {
+            return offset;
+        }
+        let ramp_in_program = ramp_program_seconds(ramp_in, rate);
+        if offset < ramp_in_program {
+            return ramp_in / (rate - 1.0) * ((offset * (rate - 1.0) / ramp_in).exp() - 1.0);
+        }
+        let into_body = offset - ramp_in_program;
+        if into_body <= body / rate || self.ramp_out_seconds <= 0.0 {
+            return (ramp_in + into_body * rate).min(source_seconds);
+        }
+        let ramp_out = self.ramp_out_seconds;
+        let into_ramp = into_body - body / rate;
+        let ramped =
+            ramp_out * rate / (1.0 - rate) * ((into_ramp * (1.0 - rate) / ramp_out).exp() - 1.0);
+        (ramp_in + body + ramped).min(source_seconds)
+    }
+
+    /// Audio handling for the clip: untouched in real time, otherwise pitch-preserved up to
+    /// [`MAX_PITCH_PRESERVED_RATE`] unless muting was asked for.
+    pub(crate) fn audio(&self) -> ClipAudio {
+        if self.is_real_time() {
+            ClipAudio::Normal
+        } else if self.speed_audio == SpeedAudio::Mute
+            || self.playback_rate > MAX_PITCH_PRESERVED_RATE
+        {
+            ClipAudio::Muted
+        } else {
+            ClipAudio::PitchPreserved
+        }
+    }
+}
+
+/// A project timeline entry, field-compatible with the contract's `timelineItemSchema`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(
+    tag = "kind",
+    rename_all = "camelCase",
+    rename_all_fields = "camelCase"
+)]
+pub(crate) enum TimelineItem {
+    Clip {
+        id: String,
+        /// A take id, or `recording` for the active take.
+        source_asset_id: String,
+        source_start_seconds: f64,
+        source_end_seconds: f64,
+        #[serde(flatten)]
+        speed: ClipSpeed,
+    },
+    Gap {
+        id: String,
+        duration_seconds: f64,
+    },
+}
+
+impl TimelineItem {
+    /// A clip that plays `source_asset_id` from `start` to `end` in real time.
+    pub(crate) fn clip(id: String, source_asset_id: String, start: f64, end: f64) -> Self {
+        TimelineItem::Clip {
+            id,
+            source_asset_id,
+            source_start_seconds: start,
+            source_end_seconds: end,
+            speed: ClipSpeed::default(),
+        }
+    }
+
+    pub(crate) fn source_asset_id(&self) -> Option<&str> {
+        match self {
+            TimelineItem::Clip {
+                source_asset_id, ..
+            } => Some(source_asset_id),
+            TimelineItem::Gap { .. } => None,
+        }
+    }
+}
+
+/// Checks that clips naming a take explicitly point at one of `takes`; clips playing the active
+/// take are resolved at export.
+pub(crate) fn check_take_references(items: &[TimelineItem], takes: &[Take]) -> Result<(), String> {
+    for item in items {
+        if let TimelineItem::Clip {
+            id,
+            source_asset_id,
+            ..
+        } = item
+        {
+            if source_asset_id != ACTIVE_TAKE_ASSET_ID
+                && !takes.iter().any(|take| &take.id == source_asset_id)
+            {
+                return Err(format!(
+                    "Timeline clip {id} references unknown take {source_asset_id}"
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// A timeline clip resolved to the media of the take it plays, placed on the program timeline.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ResolvedTimelineClip {
+    pub(crate) clip_id: String,
+    pub(crate) take_id: String,
+    #[serde(rename = "recordingURL")]
+    pub(crate) recording_url: String,
+    pub(crate) source_start_seconds: f64,
+    pub(crate) source_end_seconds: f64,
+    pub(crate) program_start_seconds: f64,
+    pub(crate) program_end_seconds: f64,
+    #[serde(flatten)]
+    pub(crate) speed: ClipSpeed,
+    pub(crate) audio: ClipAudio,
+}
+
+impl ResolvedTimelineClip {
+    fn source_seconds(&self) -> f64 {
+        self.source_end_seconds - self.source_start_seconds
+    }
+
+    /// Program time at which `source_time` of the take plays, if this clip plays it.
+    pub(crate) fn program_time(&self, source_time: f64) -> Option<f64> {
+        (self.source_start_seconds..=self.source_end_seconds)
+            .contains(&source_time)
+            .then(|| {
+                self.program_start_seconds
+                    + self.speed.program_offset(
+                        source_time - self.source_start_seconds,
+                        self.source_seconds(),
+                    )
+            })
+    }
+
+    /// Take time shown at `program_time`, if it falls inside this clip.
+    pub(crate) fn source_time(&self, program_time: f64) -> Option<f64> {
+        (self.program_start_seconds..=self.program_end_seconds)
+            .contains(&program_time)
+            .then(|| {
+                self.source_start_seconds
+                    + self.speed.source_offset(
+                        program_time - self.program_start_seconds,
+                        self.source_seconds(),
+                    )
+            })
+    }
+}
+
+/// Resolves every clip to its take and lays the timeline out in program time, failing on the
+/// first clip whose source is unknown.
+pub(crate) fn resolve_clips(
+    items: &[TimelineItem],
+    takes: &[Take],
+    active_take_id: Option<&str>,
+) -> Result<Vec<ResolvedTimelineClip>, String> {
+    let mut program_time = 0.0;
+    let mut clips = Vec::new();
+    for item in items {
+        let (id, source_asset_id, start, end, speed) = match item {
+            TimelineItem::Clip {
+                id,
+                source_asset_id,
+                source_start_seconds,
+                source_end_seconds,
+                speed,
+            } => (
+                id,
+                source_asset_id,
+                *source_start_seconds,
+                *source_end_seconds,
+                *speed,
+            ),
+            TimelineItem::Gap {
+                duration_seconds, ..
+            } => {
+                program_time += duration_seconds;
+                continue;
+            }
+        };
+        let take_id = if source_asset_id == ACTIVE_TAKE_ASSET_ID {
+            active_take_id.ok_or_else(|| {
+                format!("Timeline clip {id} plays the active take but no take is active")
+            })?
+        } else {
+            source_asset_id
+        };
+        let take = takes
+            .iter()
+            .find(|take| take.id == take_id)
+            .ok_or_else(|| format!("Timeline clip {id} references unknown take {take_id}"))?;
+        let program_end = program_time + speed.program_seconds(end - start);
+        clips.push(ResolvedTimelineClip {
+            clip_id: id.clone(),
+            take_id: take.id.clone(),
+            recording_url: take.recording_url.clone(),
+            source_start_seconds: start,
+            source_end_seconds: end,
+            program_start_seconds: program_time,
+            program_end_seconds: program_end,
+            speed,
+            audio: speed.audio(),
+        });
+        program_time = program_end;
+    }
+    Ok(clips)
+}
+
+/// The take frame an export frame shows.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ProgramFrame {
+    pub(crate) program_time: f64,
+    pub(crate) clip_id: String,
+    pub(crate) take_id: String,
+    pub(crate) source_time: f64,
+}
+
+/// Samples the program at `fps`, mapping each frame through its clip's speed to the take time it
+/// shows. Frames inside gaps show no take and are left out.
+pub(crate) fn program_frames(clips: &[ResolvedTimelineClip], fps: u32) -> Vec<ProgramFrame> {
+    let Some(end) = clips
+        .iter()
+        .map(|clip| clip.program_end_seconds)
+        .reduce(f64::max)
+    else {
+        return Vec::new();
+    };
+    let frame_count = (end * fps as f64).ceil() as usize;
+    (0..frame_count)
+        .filter_map(|frame| {
+            let program_time = frame as f64 / fps as f64;
+            clips
+                .iter()
+                .filter(|clip| program_time < clip.program_end_seconds)
+                .find_map(|clip| {
+                    Some(ProgramFrame {
+                        program_time,
+                        clip_id: clip.clip_id.clone(),
+                        take_id: clip.take_id.clone(),
+                        source_time: clip.source_time(program_time)?,
+                    })
+                })
+        })
+        .collect()
+}
+
+/// An export frame that shows the take overlays are planned from.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct TakeFrame<'a> {
+    pub(crate) program_time: f64,
+    pub(crate) source_time: f64,
+    /// The clip playing the frame; `None` when there is no timeline and the take plays as recorded.
+    pub(crate) clip: Option<&'a ResolvedTimelineClip>,
+}
+
+/// Samples the program at `fps` and keeps the frames that show `take_id`, so overlays planned in
+/// the take's time land on the frames that show it. Without clips the take plays as recorded for
+/// `duration_seconds`.
+pub(crate) fn take_frames<'a>(
+    clips: &'a [ResolvedTimelineClip],
+    take_id: Option<&str>,
+    fps: u32,
+    duration_seconds: f64,
+) -> Vec<TakeFrame<'a>> {
+    if fps == 0 {
+        return Vec::new();
+    }
+    if clips.is_empty() {
+        let frame_count = (duration_seconds.max(0.0) * fps as f64).ceil().max(1.0) as usize;
+        return (0..frame_count)
+            .map(|frame| {
+                let time = frame as f64 / fps as f64;
+                TakeFrame {
+                    program_time: time,
+                    source_time: time,
+                    clip: None,
+                }
+            })
+            .collect();
+    }
+    let Some(take_id) = take_id else {
+        return Vec::new();
+    };
+    program_frames(clips, fps)
+        .into_iter()
+        .filter(|frame| frame.take_id == take_id)
+        .filter_map(|frame| {
+            Some(TakeFrame {
+                program_time: frame.program_time,
+                source_time: frame.source_time,
+                clip: Some(clips.iter().find(|clip| clip.clip_id == frame.clip_id)?),
+            })
+        })
+        .collect()
+}
+
+/// Length of the edited program: gaps plus every clip at its playback speed.
+pub(crate) fn program_duration(items: &[TimelineItem]) -> f64 {
+    items
+        .iter()
+        .map(|item| match item {
+            TimelineItem::Clip {
+                source_start_seconds,
+                source_end_seconds,
+                speed,
+                ..
+            } => speed.program_seconds(source_end_seconds - source_start_seconds),
+            TimelineItem::Gap {
+                duration_seconds, ..
+            } => *duration_seconds,
+        })
+        .sum()
+}
+
+/// Moves a span of `take_id`'s source time onto the program timeline so timed overlays such as
+/// shortcut badges follow edits: one span per clip that plays part of it, none once it is cut.
+/// Captions are out of scope until there is a caption track (timeline design doc, §7.4).
+pub(crate) fn program_spans(
+    clips: &[ResolvedTimelineClip],
+    take_id: &str,
+    start: f64,
+    end: f64,
+) -> Vec<(f64, f64)> {
+    clips
+        .iter()
+        .filter(|clip| clip.take_id == take_id)
+        .filter_map(|clip| {
+            let (from, to) = (
+                start.max(clip.source_start_seconds),
+                end.min(clip.source_end_seconds),
+            );
+            if from >= to {
+                return None;
+            }
+            Some((clip.program_time(from)?, clip.program_time(to)?))
+        })
+        .filter(|(from, to)| from < to)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ClipAudio, ClipSpeed, SpeedAudio};
+
+    fn speed(playback_rate: f64, ramp_in_seconds: f64, ramp_out_seconds: f64) -> ClipSpeed {
+        ClipSpeed {
+            playback_rate,
+            ramp_in_seconds,
+            ramp_out_seconds,
+            ..ClipSpeed::default()
+        }
+    }
+
+    #[test]
+    fn ramps_ease_between_real_time_and_the_clip_rate() {
+        assert_eq!(speed(4.0, 0.0, 0.0).program_seconds(10.0), 2.5);
+        let ramped = speed(4.0, 1.0, 1.0);
+        let ramp = 4.0_f64.ln() / 3.0;
+        assert!((ramped.program_seconds(10.0) - (2.0 + 2.0 * ramp)).abs() < 1e-12);
+        assert!((ramped.program_offset(1.0, 10.0) - ramp).abs() < 1e-12);
+        assert!((ramped.program_offset(9.0, 10.0) - (ramp + 2.0)).abs() < 1e-12);
+        // The rate starts in real time, so the first frames barely speed up.
+        assert!((ramped.program_offset(0.001, 10.0) - 0.001).abs() < 1e-5);
+
+        for speed in [
+            ramped,
+            speed(0.5, 2.0, 3.0),
+            speed(2.0, 0.0, 4.0),
+            speed(1.0, 0.0, 0.0),
+        ] {
+            for step in 0..=20 {
+                let source = step as f64 / 2.0;
+                let program = speed.program_offset(source, 10.0);
+                assert!(
+                    (speed.source_offset(program, 10.0) - source).abs() < 1e-9,
+                    "{speed:?} at {source}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn validation_rejects_unsafe_rates_and_oversized_ramps() {
+        assert!(speed(0.25, 0.0, 0.0).validated(1.0).is_ok());
+        assert!(speed(16.0, 0.5, 0.5).validated(1.0).is_ok());
+        assert!(speed(0.2, 0.0, 0.0).validated(1.0).is_err());
+        assert!(speed(17.0, 0.0, 0.0).validated(1.0).is_err());
+        assert!(speed(f64::NAN, 0.0, 0.0).validated(1.0).is_err());
+        assert!(speed(2.0, 0.6, 0.6).validated(1.0).is_err());
+        assert!(speed(2.0, -0.1, 0.0).validated(1.0).is_err());
+    }
+
+    #[test]
+    fn fast_clips_keep_pitch_until_they_are_too_fast_to_follow() {
+        assert_eq!(speed(1.0, 0.0, 0.0).audio(), ClipAudio::Normal);
+        assert_eq!(speed(2.0, 0.0, 0.0).audio(), ClipAudio::PitchPreserved);
+        assert_eq!(speed(0.5, 0.0, 0.0).audio(), ClipAudio::PitchPreserved);
+        assert_eq!(speed(8.0, 0.0, 0.0).audio(), ClipAudio::Muted);
+        let muted = ClipSpeed {
+            speed_audio: SpeedAudio::Mute,
+            ..speed(2.0, 0.0, 0.0)
+        };
+        assert_eq!(muted.audio(), ClipAudio::Muted);
+    }
+}
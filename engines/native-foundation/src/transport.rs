@@ -1,3 +1,4 @@
+use crate::capture;
 use crate::handlers::handle_method;
 use crate::wire::{EngineMethod, EngineResponse, ProtocolErrorCode};
 use crate::{EngineRuntimeConfig, State};
@@ -11,10 +12,15 @@ use protocol_rust::{apis, models, server};
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 use serde_json::{json, Value};
-use std::sync::{Arc, Mutex};
+use std::sync::{Arc, Mutex, Weak};
+use std::thread;
+use std::time::Duration;
 use tower_http::limit::RequestBodyLimitLayer;
 
 const MAX_HTTP_BODY_BYTES: usize = 2 * 1024 * 1024;
+/// How often recording stops decided off the request path, such as a writer running out of
+/// disk space, are applied while no request arrives.
+const RECONCILE_INTERVAL: Duration = Duration::from_millis(250);
 
 #[derive(Clone)]
 struct NativeFoundationApi {
@@ -31,9 +37,11 @@ impl AsRef<NativeFoundationApi> for NativeFoundationApi {
 
 impl NativeFoundationApi {
     fn new(config: EngineRuntimeConfig, bearer_token: String) -> Self {
+        let state = Arc::new(Mutex::new(State::new(config.recents_index_path)));
+        spawn_recording_reconciler(Arc::downgrade(&state));
         Self {
             platform: config.platform,
-            state: Arc::new(Mutex::new(State::new(config.recents_index_path))),
+            state,
             bearer_token,
         }
     }
@@ -69,6 +77,20 @@ impl NativeFoundationApi {
     }
 }
 
+/// Keeps capture status current between requests; exits once the API holding `state` is gone.
+fn spawn_recording_reconciler(state: Weak<Mutex<State>>) {
+    thread::spawn(move || loop {
+        thread::sleep(RECONCILE_INTERVAL);
+        let Some(state) = state.upgrade() else {
+            return;
+        };
+        let Ok(mut state) = state.lock() else {
+            return;
+        };
+        capture::reconcile_recording(&mut state);
+    });
+}
+
 fn bad_request(
     code: impl Into<String>,
     message: impl Into<String>,
@@ -89,6 +111,36 @@ fn params_with_job_id(job_id: &str) -> Value {
     json!({ "jobId": job_id })
 }
 
+fn params_with_take_id(mut params: Value, take_id: &str) -> Value {
+    if let Value::Object(ref mut object) = params {
+        object.insert("takeId".to_string(), json!(take_id));
+    }
+    params
+}
+
+/// Reads an optional seconds query parameter; a value that is present but not a finite number is
+/// rejected rather than treated as an open range.
+fn query_seconds(
+    name: &str,
+    value: &Option<String>,
+) -> Result<Option<f64>, models::EngineBadRequestError> {
+    value
+        .as_deref()
+        .map(|value| {
+            value
+                .parse::<f64>()
+                .ok()
+                .filter(|seconds| seconds.is_finite())
+                .ok_or_else(|| {
+                    bad_request(
+                        ProtocolErrorCode::InvalidParams.as_str(),
+                        format!("{name} must be a number of seconds"),
+                    )
+                })
+        })
+        .transpose()
+}
+
 fn bad_or_runtime(error: models::EngineBadRequestError) -> bool {
     error.code == ProtocolErrorCode::RuntimeError.as_str()
 }
@@ -172,6 +224,22 @@ impl apis::capture::Capture<()> for NativeFoundationApi {
         map_response!(self.model(EngineMethod::CapturePreviewFrame, json!({})), apis::capture::CaptureCapturePreviewFrameResponse::Status200_CapturePreviewFrameResult, apis::capture::CaptureCapturePreviewFrameResponse::Status400_EngineBadRequestErrorResponseBody, apis::capture::CaptureCapturePreviewFrameResponse::Status500_EngineRuntimeErrorResponseBody)
     }
 
+    async fn capture_capture_start_camera(
+        &self,
+        _: &Method,
+        _: &headers::Host,
+        _: &axum_extra::extract::CookieJar,
+        _: &Self::Claims,
+        body: &models::CaptureStartCameraPayload,
+    ) -> Result<apis::capture::CaptureCaptureStartCameraResponse, ()> {
+        map_response!(
+            params_from_body(body).and_then(|params| self.model(EngineMethod::CaptureStartCamera, params)),
+            apis::capture::CaptureCaptureStartCameraResponse::Status200_CaptureStatusResult,
+            apis::capture::CaptureCaptureStartCameraResponse::Status400_EngineBadRequestErrorResponseBody,
+            apis::capture::CaptureCaptureStartCameraResponse::Status500_EngineRuntimeErrorResponseBody
+        )
+    }
+
     async fn capture_capture_start_current_window(
         &self,
         _: &Method,
@@ -194,6 +262,22 @@ impl apis::capture::Capture<()> for NativeFoundationApi {
         map_response!(params_from_body(body).and_then(|params| self.model(EngineMethod::CaptureStartDisplay, params)), apis::capture::CaptureCaptureStartDisplayResponse::Status200_CaptureStatusResult, apis::capture::CaptureCaptureStartDisplayResponse::Status400_EngineBadRequestErrorResponseBody, apis::capture::CaptureCaptureStartDisplayResponse::Status500_EngineRuntimeErrorResponseBody)
     }
 
+    async fn capture_capture_start_region(
+        &self,
+        _: &Method,
+        _: &headers::Host,
+        _: &axum_extra::extract::CookieJar,
+        _: &Self::Claims,
+        body: &models::CaptureStartRegionPayload,
+    ) -> Result<apis::capture::CaptureCaptureStartRegionResponse, ()> {
+        map_response!(
+            params_from_body(body).and_then(|params| self.model(EngineMethod::CaptureStartRegion, params)),
+            apis::capture::CaptureCaptureStartRegionResponse::Status200_CaptureStatusResult,
+            apis::capture::CaptureCaptureStartRegionResponse::Status400_EngineBadRequestErrorResponseBody,
+            apis::capture::CaptureCaptureStartRegionResponse::Status500_EngineRuntimeErrorResponseBody
+        )
+    }
+
     async fn capture_capture_start_window(
         &self,
         _: &Method,
@@ -229,11 +313,83 @@ impl apis::capture::Capture<()> for NativeFoundationApi {
             apis::capture::CaptureCaptureStopResponse::Status500_EngineRuntimeErrorResponseBody
         )
     }
+
+    async fn capture_capture_stop_camera(
+        &self,
+        _: &Method,
+        _: &headers::Host,
+        _: &axum_extra::extract::CookieJar,
+        _: &Self::Claims,
+    ) -> Result<apis::capture::CaptureCaptureStopCameraResponse, ()> {
+        map_response!(
+            self.model(EngineMethod::CaptureStopCamera, json!({})),
+            apis::capture::CaptureCaptureStopCameraResponse::Status200_CaptureStatusResult,
+            apis::capture::CaptureCaptureStopCameraResponse::Status400_EngineBadRequestErrorResponseBody,
+            apis::capture::CaptureCaptureStopCameraResponse::Status500_EngineRuntimeErrorResponseBody
+        )
+    }
 }
 
 #[async_trait]
 impl apis::recording::Recording<()> for NativeFoundationApi {
     type Claims = ();
+    async fn recording_recording_add_marker(
+        &self,
+        _: &Method,
+        _: &headers::Host,
+        _: &axum_extra::extract::CookieJar,
+        _: &Self::Claims,
+        body: &models::RecordingAddMarkerPayload,
+    ) -> Result<apis::recording::RecordingRecordingAddMarkerResponse, ()> {
+        map_response!(
+            params_from_body(body).and_then(|params| self.model(EngineMethod::RecordingAddMarker, params)),
+            apis::recording::RecordingRecordingAddMarkerResponse::Status200_RecordingAddMarkerResult,
+            apis::recording::RecordingRecordingAddMarkerResponse::Status400_EngineBadRequestErrorResponseBody,
+            apis::recording::RecordingRecordingAddMarkerResponse::Status500_EngineRuntimeErrorResponseBody
+        )
+    }
+    async fn recording_recording_pause(
+        &self,
+        _: &Method,
+        _: &headers::Host,
+        _: &axum_extra::extract::CookieJar,
+        _: &Self::Claims,
+    ) -> Result<apis::recording::RecordingRecordingPauseResponse, ()> {
+        map_response!(
+            self.model(EngineMethod::RecordingPause, json!({})),
+            apis::recording::RecordingRecordingPauseResponse::Status200_CaptureStatusResult,
+            apis::recording::RecordingRecordingPauseResponse::Status400_EngineBadRequestErrorResponseBody,
+            apis::recording::RecordingRecordingPauseResponse::Status500_EngineRuntimeErrorResponseBody
+        )
+    }
+    async fn recording_recording_recover(
+        &self,
+        _: &Method,
+        _: &headers::Host,
+        _: &axum_extra::extract::CookieJar,
+        _: &Self::Claims,
+    ) -> Result<apis::recording::RecordingRecordingRecoverResponse, ()> {
+        map_response!(
+            self.model(EngineMethod::RecordingRecover, json!({})),
+            apis::recording::RecordingRecordingRecoverResponse::Status200_RecordingRecoverResult,
+            apis::recording::RecordingRecordingRecoverResponse::Status400_EngineBadRequestErrorResponseBody,
+            apis::recording::RecordingRecordingRecoverResponse::Status500_EngineRuntimeErrorResponseBody
+        )
+    }
+    async fn recording_recording_resume(
+        &self,
+        _: &Method,
+        _: &headers::Host,
+        _: &axum_extra::extract::CookieJar,
+        _: &Self::Claims,
+    ) -> Result<apis::recording::RecordingRecordingResumeResponse, ()> {
+        map_response!(
+            self.model(EngineMethod::RecordingResume, json!({})),
+            apis::recording::RecordingRecordingResumeResponse::Status200_CaptureStatusResult,
+            apis::recording::RecordingRecordingResumeResponse::Status400_EngineBadRequestErrorResponseBody,
+            apis::recording::RecordingRecordingResumeResponse::Status500_EngineRuntimeErrorResponseBody
+        )
+    }
     async fn recording_recording_start(
         &self,
         _: &Method,
@@ -425,6 +581,21 @@ impl apis::export::Export<()> for NativeFoundationApi {
             apis::export::ExportExportGetResponse::Status500_EngineRuntimeErrorResponseBody
         )
     }
+    async fn export_export_guide(
+        &self,
+        _: &Method,
+        _: &headers::Host,
+        _: &axum_extra::extract::CookieJar,
+        _: &Self::Claims,
+        body: &models::ExportGuidePayload,
+    ) -> Result<apis::export::ExportExportGuideResponse, ()> {
+        map_response!(
+            params_from_body(body).and_then(|params| self.model(EngineMethod::ExportGuide, params)),
+            apis::export::ExportExportGuideResponse::Status200_ExportGuideResult,
+            apis::export::ExportExportGuideResponse::Status400_EngineBadRequestErrorResponseBody,
+            apis::export::ExportExportGuideResponse::Status500_EngineRuntimeErrorResponseBody
+        )
+    }
     async fn export_export_info(
         &self,
         _: &Method,
@@ -522,6 +693,225 @@ impl apis::project::Project<()> for NativeFoundationApi {
     }
 }
 
+#[async_trait]
+impl apis::takes::Takes<()> for NativeFoundationApi {
+    type Claims = ();
+    async fn takes_takes_activate(
+        &self,
+        _: &Method,
+        _: &headers::Host,
+        _: &axum_extra::extract::CookieJar,
+        _: &Self::Claims,
+        path: &models::TakesTakesActivatePathParams,
+    ) -> Result<apis::takes::TakesTakesActivateResponse, ()> {
+        map_response!(
+            self.model(
+                EngineMethod::TakesActivate,
+                params_with_take_id(json!({}), &path.take_id)
+            ),
+            apis::takes::TakesTakesActivateResponse::Status200_TakesResult,
+            apis::takes::TakesTakesActivateResponse::Status400_EngineBadRequestErrorResponseBody,
+            apis::takes::TakesTakesActivateResponse::Status500_EngineRuntimeErrorResponseBody
+        )
+    }
+    async fn takes_takes_delete(
+        &self,
+        _: &Method,
+        _: &headers::Host,
+        _: &axum_extra::extract::CookieJar,
+        _: &Self::Claims,
+        path: &models::TakesTakesDeletePathParams,
+        body: &models::TakesDeletePayload,
+    ) -> Result<apis::takes::TakesTakesDeleteResponse, ()> {
+        map_response!(
+            params_from_body(body).and_then(|params| self.model(
+                EngineMethod::TakesDelete,
+                params_with_take_id(params, &path.take_id)
+            )),
+            apis::takes::TakesTakesDeleteResponse::Status200_TakesResult,
+            apis::takes::TakesTakesDeleteResponse::Status400_EngineBadRequestErrorResponseBody,
+            apis::takes::TakesTakesDeleteResponse::Status500_EngineRuntimeErrorResponseBody
+        )
+    }
+    async fn takes_takes_list(
+        &self,
+        _: &Method,
+        _: &headers::Host,
+        _: &axum_extra::extract::CookieJar,
+        _: &Self::Claims,
+    ) -> Result<apis::takes::TakesTakesListResponse, ()> {
+        map_response!(
+            self.model(EngineMethod::TakesList, json!({})),
+            apis::takes::TakesTakesListResponse::Status200_TakesResult,
+            apis::takes::TakesTakesListResponse::Status400_EngineBadRequestErrorResponseBody,
+            apis::takes::TakesTakesListResponse::Status500_EngineRuntimeErrorResponseBody
+        )
+    }
+    async fn takes_takes_rename(
+        &self,
+        _: &Method,
+        _: &headers::Host,
+        _: &axum_extra::extract::CookieJar,
+        _: &Self::Claims,
+        path: &models::TakesTakesRenamePathParams,
+        body: &models::TakesRenamePayload,
+    ) -> Result<apis::takes::TakesTakesRenameResponse, ()> {
+        map_response!(
+            params_from_body(body).and_then(|params| self.model(
+                EngineMethod::TakesRename,
+                params_with_take_id(params, &path.take_id)
+            )),
+            apis::takes::TakesTakesRenameResponse::Status200_TakesResult,
+            apis::takes::TakesTakesRenameResponse::Status400_EngineBadRequestErrorResponseBody,
+            apis::takes::TakesTakesRenameResponse::Status500_EngineRuntimeErrorResponseBody
+        )
+    }
+}
+
+#[async_trait]
+impl apis::camera_keyframes::CameraKeyframes<()> for NativeFoundationApi {
+    type Claims = ();
+    async fn camera_keyframes_camera_keyframes_edit(
+        &self,
+        _: &Method,
+        _: &headers::Host,
+        _: &axum_extra::extract::CookieJar,
+        _: &Self::Claims,
+        body: &models::CameraKeyframesEditPayload,
+    ) -> Result<apis::camera_keyframes::CameraKeyframesCameraKeyframesEditResponse, ()> {
+        map_response!(
+            params_from_body(body).and_then(|params| self.model(EngineMethod::CameraKeyframesEdit, params)),
+            apis::camera_keyframes::CameraKeyframesCameraKeyframesEditResponse::Status200_CameraKeyframesResult,
+            apis::camera_keyframes::CameraKeyframesCameraKeyframesEditResponse::Status400_EngineBadRequestErrorResponseBody,
+            apis::camera_keyframes::CameraKeyframesCameraKeyframesEditResponse::Status500_EngineRuntimeErrorResponseBody
+        )
+    }
+    async fn camera_keyframes_camera_keyframes_list(
+        &self,
+        _: &Method,
+        _: &headers::Host,
+        _: &axum_extra::extract::CookieJar,
+        _: &Self::Claims,
+        query: &models::CameraKeyframesCameraKeyframesListQueryParams,
+    ) -> Result<apis::camera_keyframes::CameraKeyframesCameraKeyframesListResponse, ()> {
+        let params = query_seconds("startSeconds", &query.start_seconds).and_then(|start| {
+            Ok(json!({
+                "startSeconds": start,
+                "endSeconds": query_seconds("endSeconds", &query.end_seconds)?,
+            }))
+        });
+        map_response!(
+            params.and_then(|params| self.model(EngineMethod::CameraKeyframesList, params)),
+            apis::camera_keyframes::CameraKeyframesCameraKeyframesListResponse::Status200_CameraKeyframesResult,
+            apis::camera_keyframes::CameraKeyframesCameraKeyframesListResponse::Status400_EngineBadRequestErrorResponseBody,
+            apis::camera_keyframes::CameraKeyframesCameraKeyframesListResponse::Status500_EngineRuntimeErrorResponseBody
+        )
+    }
+    async fn camera_keyframes_camera_keyframes_replan(
+        &self,
+        _: &Method,
+        _: &headers::Host,
+        _: &axum_extra::extract::CookieJar,
+        _: &Self::Claims,
+    ) -> Result<apis::camera_keyframes::CameraKeyframesCameraKeyframesReplanResponse, ()> {
+        map_response!(
+            self.model(EngineMethod::CameraKeyframesReplan, json!({})),
+            apis::camera_keyframes::CameraKeyframesCameraKeyframesReplanResponse::Status200_CameraKeyframesResult,
+            apis::camera_keyframes::CameraKeyframesCameraKeyframesReplanResponse::Status400_EngineBadRequestErrorResponseBody,
+            apis::camera_keyframes::CameraKeyframesCameraKeyframesReplanResponse::Status500_EngineRuntimeErrorResponseBody
+        )
+    }
+}
+
+#[async_trait]
+impl apis::timeline::Timeline<()> for NativeFoundationApi {
+    type Claims = ();
+    async fn timeline_timeline_apply_cuts(
+        &self,
+        _: &Method,
+        _: &headers::Host,
+        _: &axum_extra::extract::CookieJar,
+        _: &Self::Claims,
+        body: &models::TimelineApplyCutsPayload,
+    ) -> Result<apis::timeline::TimelineTimelineApplyCutsResponse, ()> {
+        map_response!(
+            params_from_body(body).and_then(|params| self.model(EngineMethod::TimelineApplyCuts, params)),
+            apis::timeline::TimelineTimelineApplyCutsResponse::Status200_TimelineApplyCutsResult,
+            apis::timeline::TimelineTimelineApplyCutsResponse::Status400_EngineBadRequestErrorResponseBody,
+            apis::timeline::TimelineTimelineApplyCutsResponse::Status500_EngineRuntimeErrorResponseBody
+        )
+    }
+    async fn timeline_timeline_suggest_cuts(
+        &self,
+        _: &Method,
+        _: &headers::Host,
+        _: &axum_extra::extract::CookieJar,
+        _: &Self::Claims,
+        body: &models::TimelineSuggestCutsPayload,
+    ) -> Result<apis::timeline::TimelineTimelineSuggestCutsResponse, ()> {
+        map_response!(
+            params_from_body(body).and_then(|params| self.model(EngineMethod::TimelineSuggestCuts, params)),
+            apis::timeline::TimelineTimelineSuggestCutsResponse::Status200_TimelineSuggestCutsResult,
+            apis::timeline::TimelineTimelineSuggestCutsResponse::Status400_EngineBadRequestErrorResponseBody,
+            apis::timeline::TimelineTimelineSuggestCutsResponse::Status500_EngineRuntimeErrorResponseBody
+        )
+    }
+}
+
+#[async_trait]
+impl apis::analysis::Analysis<()> for NativeFoundationApi {
+    type Claims = ();
+    async fn analysis_analysis_visual_activity(
+        &self,
+        _: &Method,
+        _: &headers::Host,
+        _: &axum_extra::extract::CookieJar,
+        _: &Self::Claims,
+        body: &models::AnalysisVisualActivityPayload,
+    ) -> Result<apis::analysis::AnalysisAnalysisVisualActivityResponse, ()> {
+        map_response!(
+            params_from_body(body).and_then(|params| self.model(EngineMethod::AnalysisVisualActivity, params)),
+            apis::analysis::AnalysisAnalysisVisualActivityResponse::Status200_VisualActivityResult,
+            apis::analysis::AnalysisAnalysisVisualActivityResponse::Status400_EngineBadRequestErrorResponseBody,
+            apis::analysis::AnalysisAnalysisVisualActivityResponse::Status500_EngineRuntimeErrorResponseBody
+        )
+    }
+}
+
+#[async_trait]
+impl apis::library::Library<()> for NativeFoundationApi {
+    type Claims = ();
+    async fn library_library_defaults(
+        &self,
+        _: &Method,
+        _: &headers::Host,
+        _: &axum_extra::extract::CookieJar,
+        _: &Self::Claims,
+    ) -> Result<apis::library::LibraryLibraryDefaultsResponse, ()> {
+        map_response!(
+            self.model(EngineMethod::LibraryDefaults, json!({})),
+            apis::library::LibraryLibraryDefaultsResponse::Status200_LibraryDefaultsResult,
+            apis::library::LibraryLibraryDefaultsResponse::Status400_EngineBadRequestErrorResponseBody,
+            apis::library::LibraryLibraryDefaultsResponse::Status500_EngineRuntimeErrorResponseBody
+        )
+    }
+    async fn library_library_save_defaults(
+        &self,
+        _: &Method,
+        _: &headers::Host,
+        _: &axum_extra::extract::CookieJar,
+        _: &Self::Claims,
+        body: &models::LibrarySaveDefaultsPayload,
+    ) -> Result<apis::library::LibraryLibrarySaveDefaultsResponse, ()> {
+        map_response!(
+            params_from_body(body).and_then(|params| self.model(EngineMethod::LibrarySaveDefaults, params)),
+            apis::library::LibraryLibrarySaveDefaultsResponse::Status200_LibraryDefaultsResult,
+            apis::library::LibraryLibrarySaveDefaultsResponse::Status400_EngineBadRequestErrorResponseBody,
+            apis::library::LibraryLibrarySaveDefaultsResponse::Status500_EngineRuntimeErrorResponseBody
+        )
+    }
+}
+
 fn is_loopback_host(value: &str) -> bool {
     matches!(
         value.to_ascii_lowercase().as_str(),
@@ -632,6 +1022,7 @@ mod tests {
     use http::Request;
     use serde_json::Value;
     use std::path::PathBuf;
+    use std::sync::atomic::{AtomicU64, Ordering};
     use tower::ServiceExt;
 
     fn config() -> EngineRuntimeConfig {
@@ -748,6 +1139,71 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn http_transport_rejects_pause_without_an_active_recording() {
+        let (status, body) = authorized_json("POST", "/v1/recording/pause", Body::empty()).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "invalid_params");
+    }
+
+    #[tokio::test]
+    async fn http_transport_serves_library_defaults() {
+        let (status, defaults) =
+            authorized_json("GET", "/v1/library/defaults", Body::empty()).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(defaults["windowExclusions"]["version"], 1.0);
+        assert_eq!(defaults["windowExclusions"]["maskStyle"], "solidFill");
+    }
+
+    #[tokio::test]
+    async fn http_transport_serves_takes_endpoints() {
+        let (takes_status, takes) = authorized_json("GET", "/v1/takes", Body::empty()).await;
+        assert_eq!(takes_status, StatusCode::OK);
+        assert_eq!(takes["takes"], json!([]));
+
+        let (activate_status, activate) =
+            authorized_json("POST", "/v1/takes/take-missing/activate", Body::empty()).await;
+        assert_eq!(activate_status, StatusCode::BAD_REQUEST);
+        assert_eq!(activate["message"], "Unknown takeId: take-missing");
+    }
+
+    #[tokio::test]
+    async fn http_transport_serves_camera_keyframes_in_a_range() {
+        let (status, keyframes) = authorized_json(
+            "GET",
+            "/v1/camera-keyframes?startSeconds=1&endSeconds=4",
+            Body::empty(),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(keyframes["startSeconds"], 1.0);
+        assert_eq!(keyframes["endSeconds"], 4.0);
+        assert_eq!(keyframes["keyframeCount"], 0);
+
+        let (invalid_status, invalid) = authorized_json(
+            "GET",
+            "/v1/camera-keyframes?startSeconds=soon&endSeconds=4",
+            Body::empty(),
+        )
+        .await;
+        assert_eq!(invalid_status, StatusCode::BAD_REQUEST);
+        assert_eq!(invalid["code"], "invalid_params");
+    }
+
+    #[tokio::test]
+    async fn http_transport_keeps_recording_fields_in_capture_status() {
+        let (status_code, status) =
+            authorized_json("GET", "/v1/capture/status", Body::empty()).await;
+        assert_eq!(status_code, StatusCode::OK);
+        assert_eq!(status["isPaused"], false);
+        assert_eq!(status["isCountingDown"], false);
+        assert!(status["sourcesRevision"].is_u64());
+        assert_eq!(status["audioTracks"], json!([]));
+        assert_eq!(status["audioLevels"], json!([]));
+        assert_eq!(status["recordingMetadata"]["pauses"], json!([]));
+        assert_eq!(status["recordingMetadata"]["recovered"], false);
+    }
+
     #[tokio::test]
     async fn http_transport_installs_request_body_limit() {
         let oversized_body = format!(
@@ -788,6 +1244,190 @@ mod tests {
         assert_eq!(json["message"], "Start capture before recording");
     }
 
+    #[test]
+    fn out_of_space_recordings_stop_without_waiting_for_a_request() {
+        static AVAILABLE_BYTES: AtomicU64 = AtomicU64::new(4 * 1024 * 1024 * 1024);
+        let root = std::env::temp_dir().join(format!(
+            "guerillaglass-transport-reconcile-{}",
+            std::process::id()
+        ));
+        let api = NativeFoundationApi::new(config(), "test-token".to_string());
+        {
+            let mut state = api.state.lock().unwrap();
+            state.displays = crate::sources::synthetic_displays;
+            state.disk_space = |_| Some(AVAILABLE_BYTES.load(Ordering::SeqCst));
+            state.project_path = Some(root.join("reconcile.gglassproj").display().to_string());
+        }
+        api.call(EngineMethod::CaptureStartDisplay, json!({}))
+            .unwrap();
+        api.call(EngineMethod::RecordingStart, json!({})).unwrap();
+
+        AVAILABLE_BYTES.store(64 * 1024 * 1024, Ordering::SeqCst);
+        std::thread::sleep(Duration::from_millis(1_600));
+        {
+            let state = api.state.lock().unwrap();
+            assert!(!state.is_recording);
+            assert_eq!(
+                state
+                    .last_error
+                    .as_ref()
+                    .map(|error| error.message.as_str()),
+                Some("Recording stopped because free disk space fell below 256 MB")
+            );
+        }
+        api.call(EngineMethod::CaptureStop, json!({})).unwrap();
+        let _ = std::fs::remove_dir_all(root);
+    }
+
+    #[test]
+    fn recording_start_payload_keeps_every_recording_option() {
+        let payload: models::RecordingStartPayload = serde_json::from_value(json!({
+            "trackInputEvents": true,
+            "trackKeyboardShortcuts": true,
+            "captureMicrophone": true,
+            "microphoneDeviceId": "alsa_input.usb",
+            "captureSystemAudio": true,
+            "systemAudioDeviceId": "alsa_output.monitor",
+            "silenceWarningSeconds": 8.0,
+            "countdownSeconds": 3.0,
+            "maxDurationSeconds": 90.0,
+            "stopAt": "2030-01-01T00:00:00Z",
+        }))
+        .unwrap();
+
+        let params: crate::params::RecordingStartParams =
+            serde_json::from_value(params_from_body(&payload).unwrap()).unwrap();
+        assert_eq!(params.track_keyboard_shortcuts, Some(true));
+        assert_eq!(params.capture_microphone, Some(true));
+        assert_eq!(
+            params.microphone_device_id.as_deref(),
+            Some("alsa_input.usb")
+        );
+        assert_eq!(params.capture_system_audio, Some(true));
+        assert_eq!(
+            params.system_audio_device_id.as_deref(),
+            Some("alsa_output.monitor")
+        );
+        assert_eq!(params.silence_warning_seconds, Some(8.0));
+        assert_eq!(params.countdown_seconds, Some(3.0));
+        assert_eq!(params.max_duration_seconds, Some(90.0));
+        assert_eq!(params.stop_at.as_deref(), Some("2030-01-01T00:00:00Z"));
+    }
+
+    #[test]
+    fn project_payloads_keep_every_project_setting() {
+        let settings = json!({
+            "shortcutOverlay": {
+                "version": 1,
+                "enabled": false,
+                "displayDurationSeconds": 2.0,
+                "position": "topRight",
+                "backgroundColor": "#000000",
+                "textColor": "#FFFFFF",
+                "backgroundOpacity": 0.5,
+                "fontScale": 1.5,
+            },
+            "pictureInPicture": {
+                "version": 1,
+                "enabled": true,
+                "corner": "topLeft",
+                "sizeFraction": 0.3,
+                "marginFraction": 0.05,
+                "shape": "circle",
+                "borderWidthFraction": 0.0,
+                "borderColor": "#FF0000",
+            },
+            "cursorRendering": {
+                "version": 1,
+                "enabled": true,
+                "smoothing": 0.8,
+                "scale": 2.0,
+                "hideAfterIdleSeconds": 0.0,
+            },
+            "clickEffects": {
+                "version": 1,
+                "ripple": {
+                    "enabled": true,
+                    "color": "#00FF00",
+                    "maxRadius": 48.0,
+                    "durationSeconds": 0.5,
+                },
+                "spotlight": {
+                    "enabled": true,
+                    "radius": 240.0,
+                    "feather": 40.0,
+                    "dimOpacity": 0.4,
+                },
+                "clickSound": { "enabled": true, "volume": 0.3 },
+                "segments": [{ "clipId": "clip-1", "clickSound": false }],
+            },
+            "reframe": {
+                "version": 1,
+                "enabled": false,
+                "smoothing": 0.2,
+                "safeMarginFraction": 0.1,
+            },
+            "windowExclusions": {
+                "version": 1,
+                "rules": [{ "wmClass": "keepassxc" }],
+                "maskStyle": "blur",
+                "fillColor": "#000000",
+            },
+        });
+        let save: models::ProjectSavePayload = serde_json::from_value(settings.clone()).unwrap();
+        let save: crate::params::ProjectSaveParams =
+            serde_json::from_value(params_from_body(&save).unwrap()).unwrap();
+        let mut export = settings.clone();
+        export["outputURL"] = json!("/tmp/out.mp4");
+        export["presetId"] = json!("h264-1080p-30");
+        let export: models::ExportRunPayload = serde_json::from_value(export).unwrap();
+        let export: crate::params::ExportRunParams =
+            serde_json::from_value(params_from_body(&export).unwrap()).unwrap();
+
+        assert_eq!(save.shortcut_overlay.unwrap().font_scale, 1.5);
+        assert_eq!(
+            export.shortcut_overlay.unwrap().position,
+            crate::params::ShortcutOverlayPosition::TopRight
+        );
+        assert_eq!(save.picture_in_picture.unwrap().size_fraction, 0.3);
+        assert_eq!(
+            export.picture_in_picture.unwrap().shape,
+            crate::params::PictureInPictureShape::Circle
+        );
+        assert_eq!(save.cursor_rendering.unwrap().scale, 2.0);
+        assert_eq!(export.cursor_rendering.unwrap().smoothing, 0.8);
+        let click_effects = save.click_effects.unwrap();
+        assert_eq!(click_effects.spotlight.radius, 240.0);
+        assert_eq!(click_effects.segments[0].click_sound, Some(false));
+        assert_eq!(export.click_effects.unwrap().click_sound.volume, 0.3);
+        assert!(!save.reframe.unwrap().enabled);
+        assert_eq!(export.reframe.unwrap().safe_margin_fraction, 0.1);
+        let window_exclusions = save.window_exclusions.unwrap();
+        assert_eq!(
+            window_exclusions.rules[0].wm_class.as_deref(),
+            Some("keepassxc")
+        );
+        assert_eq!(
+            window_exclusions.mask_style,
+            crate::params::PrivacyMaskStyle::Blur
+        );
+    }
+
+    #[tokio::test]
+    async fn http_transport_reports_project_settings_in_project_state() {
+        let (status, project) = authorized_json("GET", "/v1/project/current", Body::empty()).await;
+        assert_eq!(status, StatusCode::OK);
+        let project: models::ProjectState = serde_json::from_value(project).unwrap();
+        let shortcut_overlay = project.shortcut_overlay.unwrap();
+        assert!(shortcut_overlay.enabled);
+        assert_eq!(shortcut_overlay.position, "bottomCenter");
+        assert_eq!(project.picture_in_picture.unwrap().corner, "bottomRight");
+        assert!(!project.cursor_rendering.unwrap().enabled);
+        assert_eq!(project.click_effects.unwrap().ripple.color, "#FACC15");
+        assert_eq!(project.reframe.unwrap().safe_margin_fraction, 0.15);
+        assert!(project.window_exclusions.is_none());
+    }
+
     #[test]
     fn readiness_envelope_reports_loopback_host_and_port() {
         let envelope = readiness_envelope(49152);
@@ -0,0 +1,511 @@
+use crate::pipeline::FrameSource;
+use std::ffi::CString;
+use std::fs;
+use std::io;
+use std::os::unix::ffi::OsStrExt;
+use std::path::{Path, PathBuf};
+
+const V4L2_CAP_VIDEO_CAPTURE: u32 = 0x0000_0001;
+const V4L2_CAP_STREAMING: u32 = 0x0400_0000;
+const V4L2_CAP_DEVICE_CAPS: u32 = 0x8000_0000;
+const V4L2_BUF_TYPE_VIDEO_CAPTURE: u32 = 1;
+const V4L2_MEMORY_MMAP: u32 = 1;
+const V4L2_FIELD_ANY: u32 = 0;
+const V4L2_PIX_FMT_YUYV: u32 = fourcc(b"YUYV");
+
+/// Cameras are asked for VGA; drivers answer with the nearest size they support.
+const REQUESTED_WIDTH: u32 = 640;
+const REQUESTED_HEIGHT: u32 = 480;
+const BUFFER_COUNT: u32 = 4;
+const FRAME_TIMEOUT_MILLISECONDS: i32 = 500;
+
+const fn fourcc(code: &[u8; 4]) -> u32 {
+    code[0] as u32 | (code[1] as u32) << 8 | (code[2] as u32) << 16 | (code[3] as u32) << 24
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct Capability {
+    driver: [u8; 16],
+    card: [u8; 32],
+    bus_info: [u8; 32],
+    version: u32,
+    capabilities: u32,
+    device_caps: u32,
+    reserved: [u32; 3],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct PixFormat {
+    width: u32,
+    height: u32,
+    pixelformat: u32,
+    field: u32,
+    bytesperline: u32,
+    sizeimage: u32,
+    colorspace: u32,
+    private: u32,
+    flags: u32,
+    ycbcr_enc: u32,
+    quantization: u32,
+    xfer_func: u32,
+}
+
+/// `struct v4l2_format`'s union holds pointers, which sets its alignment.
+#[repr(C)]
+#[derive(Clone, Copy)]
+union FormatUnion {
+    pix: PixFormat,
+    raw_data: [u8; 200],
+    _align: *const libc::c_void,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct Format {
+    buffer_type: u32,
+    fmt: FormatUnion,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct RequestBuffers {
+    count: u32,
+    buffer_type: u32,
+    memory: u32,
+    capabilities: u32,
+    flags: u8,
+    reserved: [u8; 3],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct Timecode {
+    timecode_type: u32,
+    flags: u32,
+    frames: u8,
+    seconds: u8,
+    minutes: u8,
+    hours: u8,
+    userbits: [u8; 4],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+union BufferLocation {
+    offset: u32,
+    userptr: libc::c_ulong,
+    planes: *mut libc::c_void,
+    fd: i32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct Buffer {
+    index: u32,
+    buffer_type: u32,
+    bytesused: u32,
+    flags: u32,
+    field: u32,
+    timestamp: libc::timeval,
+    timecode: Timecode,
+    sequence: u32,
+    memory: u32,
+    m: BufferLocation,
+    length: u32,
+    reserved2: u32,
+    request_fd: i32,
+}
+
+const fn ioc(direction: u32, number: u32, size: usize) -> u32 {
+    direction << 30 | (size as u32) << 16 | (b'V' as u32) << 8 | number
+}
+
+const IOC_WRITE: u32 = 1;
+const IOC_READ: u32 = 2;
+const VIDIOC_QUERYCAP: u32 = ioc(IOC_READ, 0, std::mem::size_of::<Capability>());
+const VIDIOC_S_FMT: u32 = ioc(IOC_READ | IOC_WRITE, 5, std::mem::size_of::<Format>());
+const VIDIOC_REQBUFS: u32 = ioc(
+    IOC_READ | IOC_WRITE,
+    8,
+    std::mem::size_of::<RequestBuffers>(),
+);
+const VIDIOC_QUERYBUF: u32 = ioc(IOC_READ | IOC_WRITE, 9, std::mem::size_of::<Buffer>());
+const VIDIOC_QBUF: u32 = ioc(IOC_READ | IOC_WRITE, 15, std::mem::size_of::<Buffer>());
+const VIDIOC_DQBUF: u32 = ioc(IOC_READ | IOC_WRITE, 17, std::mem::size_of::<Buffer>());
+const VIDIOC_STREAMON: u32 = ioc(IOC_WRITE, 18, std::mem::size_of::<libc::c_int>());
+const VIDIOC_STREAMOFF: u32 = ioc(IOC_WRITE, 19, std::mem::size_of::<libc::c_int>());
+
+/// Owned V4L2 device file descriptor.
+struct Device {
+    fd: libc::c_int,
+}
+
+impl Device {
+    fn open(path: &Path) -> io::Result<Self> {
+        let path = CString::new(path.as_os_str().as_bytes())
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidInput, error))?;
+        // SAFETY: `path` is NUL-terminated; the returned descriptor is owned by `Device`.
+        let fd = unsafe {
+            libc::open(
+                path.as_ptr(),
+                libc::O_RDWR | libc::O_NONBLOCK | libc::O_CLOEXEC,
+            )
+        };
+        if fd < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(Self { fd })
+    }
+
+    /// Issues `request` with `argument`, retrying on `EINTR`.
+    fn ioctl<T>(&self, request: u32, argument: &mut T) -> io::Result<()> {
+        loop {
+            // SAFETY: every request constant encodes the size of the `T` it is used with.
+            let result = unsafe { libc::ioctl(self.fd, request as _, argument as *mut T) };
+            if result != -1 {
+                return Ok(());
+            }
+            let error = io::Error::last_os_error();
+            if error.kind() != io::ErrorKind::Interrupted {
+                return Err(error);
+            }
+        }
+    }
+
+    fn capability(&self) -> io::Result<Capability> {
+        // SAFETY: `Capability` is plain old data; all-zero is a valid value.
+        let mut capability: Capability = unsafe { std::mem::zeroed() };
+        self.ioctl(VIDIOC_QUERYCAP, &mut capability)?;
+        Ok(capability)
+    }
+}
+
+impl Drop for Device {
+    fn drop(&mut self) {
+        // SAFETY: `fd` is owned by this `Device` and closed exactly once.
+        unsafe { libc::close(self.fd) };
+    }
+}
+
+fn device_caps(capability: &Capability) -> u32 {
+    if capability.capabilities & V4L2_CAP_DEVICE_CAPS != 0 {
+        capability.device_caps
+    } else {
+        capability.capabilities
+    }
+}
+
+fn c_string(bytes: &[u8]) -> String {
+    let end = bytes
+        .iter()
+        .position(|byte| *byte == 0)
+        .unwrap_or(bytes.len());
+    String::from_utf8_lossy(&bytes[..end]).trim().to_string()
+}
+
+/// A `/dev/videoN` node that can stream video capture.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct V4l2Device {
+    pub(crate) index: u64,
+    pub(crate) name: String,
+    pub(crate) path: PathBuf,
+}
+
+/// Lists capture-capable V4L2 nodes in `/dev`. Metadata and output-only nodes (UVC cameras
+/// expose one of each) are skipped.
+pub(crate) fn enumerate_devices() -> Vec<V4l2Device> {
+    let Ok(entries) = fs::read_dir("/dev") else {
+        return Vec::new();
+    };
+    let mut devices = entries
+        .filter_map(Result::ok)
+        .filter_map(|entry| {
+            let name = entry.file_name();
+            let index = name.to_str()?.strip_prefix("video")?.parse::<u64>().ok()?;
+            let path = entry.path();
+            let capability = Device::open(&path).ok()?.capability().ok()?;
+            let caps = device_caps(&capability);
+            if caps & V4L2_CAP_VIDEO_CAPTURE == 0 || caps & V4L2_CAP_STREAMING == 0 {
+                return None;
+            }
+            Some(V4l2Device {
+                index,
+                name: c_string(&capability.card),
+                path,
+            })
+        })
+        .collect::<Vec<_>>();
+    devices.sort_by_key(|device| device.index);
+    devices
+}
+
+struct MappedBuffer {
+    pointer: *mut libc::c_void,
+    length: usize,
+}
+
+/// Streams YUYV frames from a V4L2 camera through memory-mapped buffers.
+pub(crate) struct V4l2CameraSource {
+    device: Device,
+    buffers: Vec<MappedBuffer>,
+    width: u32,
+    height: u32,
+    bytes_per_line: u32,
+}
+
+// SAFETY: the mapped buffers are only touched by the thread that owns the source.
+unsafe impl Send for V4l2CameraSource {}
+
+impl V4l2CameraSource {
+    pub(crate) fn open(path: &Path) -> Result<Self, String> {
+        let device = Device::open(path)
+            .map_err(|error| format!("unable to open {}: {error}", path.display()))?;
+        let capability = device
+            .capability()
+            .map_err(|error| format!("{} is not a V4L2 device: {error}", path.display()))?;
+        let caps = device_caps(&capability);
+        if caps & V4L2_CAP_VIDEO_CAPTURE == 0 || caps & V4L2_CAP_STREAMING == 0 {
+            return Err(format!(
+                "{} does not support streaming video capture",
+                path.display()
+            ));
+        }
+
+        // SAFETY: `Format` is plain old data; all-zero is a valid value.
+        let mut format: Format = unsafe { std::mem::zeroed() };
+        format.buffer_type = V4L2_BUF_TYPE_VIDEO_CAPTURE;
+        format.fmt.pix = PixFormat {
+            width: REQUESTED_WIDTH,
+            height: REQUESTED_HEIGHT,
+            pixelformat: V4L2_PIX_FMT_YUYV,
+            field: V4L2_FIELD_ANY,
+            // SAFETY: the union was zero-initialised above.
+            ..unsafe { format.fmt.pix }
+        };
+        device
+            .ioctl(VIDIOC_S_FMT, &mut format)
+            .map_err(|error| format!("unable to set the camera format: {error}"))?;
+        // SAFETY: VIDIOC_S_FMT on a capture buffer type fills in `pix`.
+        let pix = unsafe { format.fmt.pix };
+        if pix.pixelformat != V4L2_PIX_FMT_YUYV {
+            return Err("camera does not support YUYV capture".to_string());
+        }
+
+        let mut source = Self {
+            device,
+            buffers: Vec::new(),
+            width: pix.width,
+            height: pix.height,
+            bytes_per_line: pix.bytesperline.max(pix.width * 2),
+        };
+        source
+            .start_streaming()
+            .map_err(|error| format!("unable to start camera streaming: {error}"))?;
+        Ok(source)
+    }
+
+    fn start_streaming(&mut self) -> io::Result<()> {
+        let mut request = RequestBuffers {
+            count: BUFFER_COUNT,
+            buffer_type: V4L2_BUF_TYPE_VIDEO_CAPTURE,
+            memory: V4L2_MEMORY_MMAP,
+            capabilities: 0,
+            flags: 0,
+            reserved: [0; 3],
+        };
+        self.device.ioctl(VIDIOC_REQBUFS, &mut request)?;
+        for index in 0..request.count {
+            let mut buffer = capture_buffer(index);
+            self.device.ioctl(VIDIOC_QUERYBUF, &mut buffer)?;
+            // SAFETY: QUERYBUF reports the offset and length to map for this buffer.
+            let pointer = unsafe {
+                libc::mmap(
+                    std::ptr::null_mut(),
+                    buffer.length as usize,
+                    libc::PROT_READ | libc::PROT_WRITE,
+                    libc::MAP_SHARED,
+                    self.device.fd,
+                    buffer.m.offset as libc::off_t,
+                )
+            };
+            if pointer == libc::MAP_FAILED {
+                return Err(io::Error::last_os_error());
+            }
+            self.buffers.push(MappedBuffer {
+                pointer,
+                length: buffer.length as usize,
+            });
+            self.device.ioctl(VIDIOC_QBUF, &mut buffer)?;
+        }
+        let mut buffer_type = V4L2_BUF_TYPE_VIDEO_CAPTURE as libc::c_int;
+        self.device.ioctl(VIDIOC_STREAMON, &mut buffer_type)
+    }
+
+    fn wait_readable(&self) -> io::Result<bool> {
+        let mut poll = libc::pollfd {
+            fd: self.device.fd,
+            events: libc::POLLIN,
+            revents: 0,
+        };
+        // SAFETY: `poll` points to one initialised pollfd.
+        let ready = unsafe { libc::poll(&mut poll, 1, FRAME_TIMEOUT_MILLISECONDS) };
+        if ready < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(ready > 0)
+    }
+
+    /// Dequeues the next filled buffer, or `None` when the driver has nothing queued.
+    fn dequeue(&self) -> io::Result<Option<Buffer>> {
+        let mut buffer = capture_buffer(0);
+        match self.device.ioctl(VIDIOC_DQBUF, &mut buffer) {
+            Ok(()) => Ok(Some(buffer)),
+            Err(error) if error.kind() == io::ErrorKind::WouldBlock => Ok(None),
+            Err(error) => Err(error),
+        }
+    }
+}
+
+fn capture_buffer(index: u32) -> Buffer {
+    // SAFETY: `Buffer` is plain old data; all-zero is a valid value.
+    let mut buffer: Buffer = unsafe { std::mem::zeroed() };
+    buffer.index = index;
+    buffer.buffer_type = V4L2_BUF_TYPE_VIDEO_CAPTURE;
+    buffer.memory = V4L2_MEMORY_MMAP;
+    buffer
+}
+
+impl FrameSource for V4l2CameraSource {
+    fn dimensions(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+
+    /// Returns the newest frame the camera has produced, requeueing any older ones so a
+    /// camera running faster than the capture rate never builds up latency.
+    fn capture(&mut self, _index: u64) -> Result<Vec<u8>, String> {
+        if !self.wait_readable().map_err(|error| error.to_string())? {
+            return Err("camera frame timed out".to_string());
+        }
+        let mut newest: Option<Buffer> = None;
+        while let Some(buffer) = self.dequeue().map_err(|error| error.to_string())? {
+            if let Some(mut older) = newest.replace(buffer) {
+                self.device
+                    .ioctl(VIDIOC_QBUF, &mut older)
+                    .map_err(|error| error.to_string())?;
+            }
+        }
+        let Some(mut buffer) = newest else {
+            return Err("camera produced no frame".to_string());
+        };
+        let mapped = &self.buffers[buffer.index as usize];
+        // SAFETY: the buffer is dequeued, so the driver will not write it until it is requeued.
+        let data = unsafe {
+            std::slice::from_raw_parts(
+                mapped.pointer as *const u8,
+                (buffer.bytesused as usize).min(mapped.length),
+            )
+        };
+        let frame = yuyv_to_rgb(data, self.width, self.height, self.bytes_per_line);
+        self.device
+            .ioctl(VIDIOC_QBUF, &mut buffer)
+            .map_err(|error| error.to_string())?;
+        frame.ok_or_else(|| "camera frame was truncated".to_string())
+    }
+}
+
+impl Drop for V4l2CameraSource {
+    fn drop(&mut self) {
+        let mut buffer_type = V4L2_BUF_TYPE_VIDEO_CAPTURE as libc::c_int;
+        let _ = self.device.ioctl(VIDIOC_STREAMOFF, &mut buffer_type);
+        for buffer in &self.buffers {
+            // SAFETY: each mapping was created in `start_streaming` and is unmapped once.
+            unsafe { libc::munmap(buffer.pointer, buffer.length) };
+        }
+    }
+}
+
+/// Converts packed YUYV (4:2:2, BT.601 limited range) to packed RGB8.
+pub(crate) fn yuyv_to_rgb(
+    data: &[u8],
+    width: u32,
+    height: u32,
+    bytes_per_line: u32,
+) -> Option<Vec<u8>> {
+    let (width, height, stride) = (width as usize, height as usize, bytes_per_line as usize);
+    if height == 0 || data.len() < stride * (height - 1) + width * 2 {
+        return None;
+    }
+    let mut rgb = Vec::with_capacity(width * height * 3);
+    for row in data.chunks(stride).take(height) {
+        for x in 0..width {
+            let pair = (x / 2) * 4;
+            let y = row[pair + if x % 2 == 0 { 0 } else { 2 }];
+            rgb.extend_from_slice(&ycbcr_to_rgb(y, row[pair + 1], row[pair + 3]));
+        }
+    }
+    Some(rgb)
+}
+
+fn ycbcr_to_rgb(y: u8, cb: u8, cr: u8) -> [u8; 3] {
+    let c = (y as i32 - 16).max(0) * 298;
+    let d = cb as i32 - 128;
+    let e = cr as i32 - 128;
+    let clamp = |value: i32| ((value + 128) >> 8).clamp(0, 255) as u8;
+    [
+        clamp(c + 409 * e),
+        clamp(c - 100 * d - 208 * e),
+        clamp(c + 516 * d),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        enumerate_devices, yuyv_to_rgb, Buffer, Capability, Format, RequestBuffers,
+        V4l2CameraSource,
+    };
+    use crate::pipeline::FrameSource;
+
+    #[cfg(target_pointer_width = "64")]
+    #[test]
+    fn ioctl_structs_match_kernel_abi_sizes() {
+        assert_eq!(std::mem::size_of::<Capability>(), 104);
+        assert_eq!(std::mem::size_of::<Format>(), 208);
+        assert_eq!(std::mem::size_of::<RequestBuffers>(), 20);
+        assert_eq!(std::mem::size_of::<Buffer>(), 88);
+    }
+
+    #[test]
+    fn converts_yuyv_rows_with_padding_to_rgb() {
+        // Two rows of two pixels: black/white, then pure red/blue chroma pairs.
+        let stride = 8;
+        let data = [
+            16, 128, 235, 128, 0, 0, 0, 0, //
+            82, 90, 82, 240, 0, 0, 0, 0,
+        ];
+        let rgb = yuyv_to_rgb(&data, 2, 2, stride).expect("convert");
+        assert_eq!(&rgb[0..3], &[0, 0, 0]);
+        assert_eq!(&rgb[3..6], &[255, 255, 255]);
+        let red = &rgb[6..9];
+        assert!(red[0] > 240 && red[1] < 10 && red[2] < 10, "{red:?}");
+        assert_eq!(&rgb[6..9], &rgb[9..12]);
+        assert_eq!(yuyv_to_rgb(&data[..10], 2, 2, stride), None);
+    }
+
+    /// Exercises the first capture device (a v4l2loopback node in CI); skipped without one.
+    #[test]
+    fn streams_frames_from_an_available_camera() {
+        let Some(device) = enumerate_devices().into_iter().next() else {
+            return;
+        };
+        let Ok(mut source) = V4l2CameraSource::open(&device.path) else {
+            return;
+        };
+        let (width, height) = source.dimensions();
+        if let Ok(frame) = source.capture(0) {
+            assert_eq!(frame.len(), (width * height * 3) as usize);
+        }
+    }
+}
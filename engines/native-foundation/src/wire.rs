@@ -1,5 +1,6 @@
-use serde_json::Value;
-use std::time::Instant;
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+use std::time::{Duration, Instant};
 
 pub(crate) const PROTOCOL_VERSION: &str = "2";
 
@@ -69,6 +70,30 @@ pub(crate) fn failure(
     }
 }
 
+/// Decodes a method's params, answering `invalid_params` when they do not fit its shape. Absent
+/// params decode like an empty object so methods whose fields all default can be called bare.
+pub(crate) fn decode_params<T>(
+    id: &EngineCallId,
+    params: &Value,
+    what: &str,
+) -> Result<T, EngineResponse>
+where
+    T: DeserializeOwned,
+{
+    let params = if params.is_null() {
+        json!({})
+    } else {
+        params.clone()
+    };
+    serde_json::from_value(params).map_err(|error| {
+        failure(
+            id,
+            ProtocolErrorCode::InvalidParams,
+            format!("Invalid {what} payload: {error}"),
+        )
+    })
+}
+
 #[cfg(test)]
 #[derive(Debug, Clone)]
 pub(crate) struct EngineRequest {
@@ -94,18 +119,38 @@ pub(crate) enum EngineMethod {
     CaptureStartDisplay,
     CaptureStartCurrentWindow,
     CaptureStartWindow,
+    CaptureStartRegion,
+    CaptureStartCamera,
+    CaptureStopCamera,
     CaptureStop,
     RecordingStart,
+    RecordingPause,
+    RecordingResume,
+    RecordingAddMarker,
     RecordingStop,
+    RecordingRecover,
     CaptureStatus,
     CapturePreviewFrame,
     ExportInfo,
     ExportRun,
     ExportRunCutPlan,
+    ExportGuide,
     ProjectCurrent,
     ProjectOpen,
     ProjectSave,
     ProjectRecents,
+    TakesList,
+    TakesRename,
+    TakesDelete,
+    TakesActivate,
+    CameraKeyframesList,
+    CameraKeyframesEdit,
+    CameraKeyframesReplan,
+    TimelineSuggestCuts,
+    TimelineApplyCuts,
+    AnalysisVisualActivity,
+    LibraryDefaults,
+    LibrarySaveDefaults,
 }
 
 impl EngineMethod {
@@ -128,18 +173,38 @@ impl EngineMethod {
             EngineMethod::CaptureStartDisplay => "capture.startDisplay",
             EngineMethod::CaptureStartCurrentWindow => "capture.startCurrentWindow",
             EngineMethod::CaptureStartWindow => "capture.startWindow",
+            EngineMethod::CaptureStartRegion => "capture.startRegion",
+            EngineMethod::CaptureStartCamera => "capture.startCamera",
+            EngineMethod::CaptureStopCamera => "capture.stopCamera",
             EngineMethod::CaptureStop => "capture.stop",
             EngineMethod::RecordingStart => "recording.start",
+            EngineMethod::RecordingPause => "recording.pause",
+            EngineMethod::RecordingResume => "recording.resume",
+            EngineMethod::RecordingAddMarker => "recording.addMarker",
             EngineMethod::RecordingStop => "recording.stop",
+            EngineMethod::RecordingRecover => "recording.recover",
             EngineMethod::CaptureStatus => "capture.status",
             EngineMethod::CapturePreviewFrame => "capture.previewFrame",
             EngineMethod::ExportInfo => "export.info",
             EngineMethod::ExportRun => "export.run",
             EngineMethod::ExportRunCutPlan => "export.runCutPlan",
+            EngineMethod::ExportGuide => "export.guide",
             EngineMethod::ProjectCurrent => "project.current",
             EngineMethod::ProjectOpen => "project.open",
             EngineMethod::ProjectSave => "project.save",
             EngineMethod::ProjectRecents => "project.recents",
+            EngineMethod::TakesList => "takes.list",
+            EngineMethod::TakesRename => "takes.rename",
+            EngineMethod::TakesDelete => "takes.delete",
+            EngineMethod::TakesActivate => "takes.activate",
+            EngineMethod::CameraKeyframesList => "cameraKeyframes.list",
+            EngineMethod::CameraKeyframesEdit => "cameraKeyframes.edit",
+            EngineMethod::CameraKeyframesReplan => "cameraKeyframes.replan",
+            EngineMethod::TimelineSuggestCuts => "timeline.suggestCuts",
+            EngineMethod::TimelineApplyCuts => "timeline.applyCuts",
+            EngineMethod::AnalysisVisualActivity => "analysis.visualActivity",
+            EngineMethod::LibraryDefaults => "library.defaults",
+            EngineMethod::LibrarySaveDefaults => "library.saveDefaults",
         }
     }
 }
@@ -158,9 +223,14 @@ impl Default for CaptureClock {
 }
 
 impl CaptureClock {
-    fn elapsed_seconds(&self) -> f64 {
+    pub(crate) fn elapsed_seconds(&self) -> f64 {
         self.started.elapsed().as_secs_f64()
     }
+
+    /// The instant `seconds` into the clock, for handing capture-clock deadlines to threads.
+    pub(crate) fn instant_at(&self, seconds: f64) -> Instant {
+        self.started + Duration::from_secs_f64(seconds.max(0.0))
+    }
 }
 
 #[derive(Debug, Clone, Default)]
@@ -171,14 +241,23 @@ pub(crate) struct RunningDuration {
 
 impl RunningDuration {
     pub(crate) fn start(&mut self, clock: &CaptureClock) {
+        self.start_at(clock.elapsed_seconds());
+    }
+
+    /// Starts counting at a capture-clock time, which may lie ahead (e.g. after a countdown).
+    pub(crate) fn start_at(&mut self, started_at_seconds: f64) {
         if self.started_at_seconds.is_none() {
-            self.started_at_seconds = Some(clock.elapsed_seconds());
+            self.started_at_seconds = Some(started_at_seconds);
         }
     }
 
     pub(crate) fn stop(&mut self, clock: &CaptureClock) {
+        self.stop_at(clock.elapsed_seconds());
+    }
+
+    pub(crate) fn stop_at(&mut self, stopped_at_seconds: f64) {
         if let Some(started_at_seconds) = self.started_at_seconds.take() {
-            self.accumulated_seconds += clock.elapsed_seconds() - started_at_seconds;
+            self.accumulated_seconds += (stopped_at_seconds - started_at_seconds).max(0.0);
         }
     }
 
@@ -186,7 +265,7 @@ impl RunningDuration {
         self.accumulated_seconds
             + self
                 .started_at_seconds
-                .map(|started_at_seconds| clock.elapsed_seconds() - started_at_seconds)
+                .map(|started_at_seconds| (clock.elapsed_seconds() - started_at_seconds).max(0.0))
                 .unwrap_or(0.0)
     }
 }
@@ -0,0 +1,277 @@
+use crate::source_monitor::{SourceChange, SourceMonitor};
+use crate::sources::DisplaySource;
+use crate::x11_windows::{client_list, create_wake_window, send_wake};
+use std::collections::HashSet;
+use std::sync::{Arc, Weak};
+use std::thread;
+use x11rb::connection::Connection;
+use x11rb::errors::ReplyError;
+use x11rb::protocol::randr::{self, ConnectionExt as _};
+use x11rb::protocol::xproto::{self, ChangeWindowAttributesAux, ConnectionExt as _, Window};
+use x11rb::protocol::Event;
+use x11rb::rust_connection::RustConnection;
+
+fn connect() -> Result<(RustConnection, Window), String> {
+    let (connection, screen_number) = x11rb::connect(None)
+        .map_err(|error| format!("unable to connect to the X server: {error}"))?;
+    let root = connection
+        .setup()
+        .roots
+        .get(screen_number)
+        .map(|screen| screen.root)
+        .ok_or_else(|| "X server reported no screens".to_string())?;
+    // RRGetMonitors needs RandR 1.5.
+    let version = connection
+        .randr_query_version(1, 5)
+        .map_err(ReplyError::from)
+        .and_then(|cookie| cookie.reply())
+        .map_err(|error| format!("RandR is unavailable: {error}"))?;
+    if (version.major_version, version.minor_version) < (1, 5) {
+        return Err(format!(
+            "RandR 1.5 is unavailable: server supports {}.{}",
+            version.major_version, version.minor_version
+        ));
+    }
+    Ok((connection, root))
+}
+
+fn intern(connection: &RustConnection, name: &[u8]) -> Result<xproto::Atom, String> {
+    connection
+        .intern_atom(false, name)
+        .map_err(ReplyError::from)
+        .and_then(|cookie| cookie.reply())
+        .map(|reply| reply.atom)
+        .map_err(|error| error.to_string())
+}
+
+/// Active RandR monitors in root coordinates. A monitor's id is its name atom, which stays the
+/// same while the X server runs, so a replugged monitor keeps its id.
+pub(crate) fn list_displays() -> Result<Vec<DisplaySource>, String> {
+    let (connection, root) = connect()?;
+    let monitors = connection
+        .randr_get_monitors(root, true)
+        .map_err(ReplyError::from)
+        .and_then(|cookie| cookie.reply())
+        .map_err(|error| format!("unable to list monitors: {error}"))?
+        .monitors;
+    Ok(monitors
+        .into_iter()
+        .map(|monitor| {
+            let display_name = connection
+                .get_atom_name(monitor.name)
+                .map_err(ReplyError::from)
+                .and_then(|cookie| cookie.reply())
+                .map(|reply| String::from_utf8_lossy(&reply.name).into_owned())
+                .unwrap_or_else(|_| format!("Display {}", monitor.name));
+            DisplaySource {
+                id: u64::from(monitor.name),
+                display_name,
+                is_primary: monitor.primary,
+                width: u32::from(monitor.width),
+                height: u32::from(monitor.height),
+            }
+        })
+        .collect())
+}
+
+/// The connection of the source watcher thread. Dropping it wakes the thread so it exits.
+pub(crate) struct SourceWatcher {
+    connection: Arc<RustConnection>,
+    /// Unmapped window the thread is woken through when the watcher is dropped.
+    wake: Window,
+}
+
+impl SourceWatcher {
+    /// Also reports the destruction of `window_id`, which the window manager's client list
+    /// misses for unmanaged and override-redirect windows.
+    pub(crate) fn watch_window(&self, window_id: u64) {
+        let Ok(window) = Window::try_from(window_id) else {
+            return;
+        };
+        // A window that is already gone cannot be watched; its close was published already.
+        // Checked so the selection is in place before the caller relies on it.
+        let _ = self
+            .connection
+            .change_window_attributes(
+                window,
+                &ChangeWindowAttributesAux::new().event_mask(xproto::EventMask::STRUCTURE_NOTIFY),
+            )
+            .map(|cookie| cookie.check());
+    }
+}
+
+impl Drop for SourceWatcher {
+    fn drop(&mut self) {
+        send_wake(&self.connection, self.wake);
+    }
+}
+
+/// Watches RandR configuration changes and the window manager's client list on a background
+/// thread, publishing them to `monitor` until the returned watcher is dropped.
+pub(crate) fn spawn_watcher(monitor: Weak<SourceMonitor>) -> Result<SourceWatcher, String> {
+    let (connection, root) = connect()?;
+    let net_client_list = intern(&connection, b"_NET_CLIENT_LIST")?;
+    connection
+        .randr_select_input(
+            root,
+            randr::NotifyMask::SCREEN_CHANGE
+                | randr::NotifyMask::CRTC_CHANGE
+                | randr::NotifyMask::OUTPUT_CHANGE,
+        )
+        .map_err(ReplyError::from)
+        .and_then(|cookie| cookie.check())
+        .map_err(|error| format!("unable to select RandR events: {error}"))?;
+    connection
+        .change_window_attributes(
+            root,
+            &ChangeWindowAttributesAux::new().event_mask(xproto::EventMask::PROPERTY_CHANGE),
+        )
+        .map_err(ReplyError::from)
+        .and_then(|cookie| cookie.check())
+        .map_err(|error| format!("unable to watch the window list: {error}"))?;
+    let wake = create_wake_window(&connection, root)?;
+    let clients = client_list(&connection, root, net_client_list)
+        .map_err(|error| format!("unable to read the window list: {error}"))?;
+
+    let connection = Arc::new(connection);
+    let thread_connection = Arc::clone(&connection);
+    thread::spawn(move || {
+        run_watcher(
+            &thread_connection,
+            root,
+            wake,
+            net_client_list,
+            clients,
+            monitor,
+        )
+    });
+    Ok(SourceWatcher { connection, wake })
+}
+
+/// Blocks until the X server reports events, then publishes what they changed.
+fn run_watcher(
+    connection: &RustConnection,
+    root: Window,
+    wake: Window,
+    net_client_list: xproto::Atom,
+    clients: Vec<Window>,
+    monitor: Weak<SourceMonitor>,
+) {
+    let mut clients: HashSet<Window> = clients.into_iter().collect();
+    loop {
+        let mut displays_changed = false;
+        let mut clients_changed = false;
+        let mut destroyed = Vec::new();
+        let mut next = connection.wait_for_event().map(Some);
+        loop {
+            match next {
+                Ok(Some(Event::ClientMessage(message))) if message.window == wake => return,
+                Ok(Some(Event::RandrScreenChangeNotify(_) | Event::RandrNotify(_))) => {
+                    displays_changed = true;
+                }
+                Ok(Some(Event::PropertyNotify(event))) if event.atom == net_client_list => {
+                    clients_changed = true;
+                }
+                Ok(Some(Event::DestroyNotify(event))) => destroyed.push(event.window),
+                Ok(Some(_)) => {}
+                Ok(None) => break,
+                Err(_) => return,
+            }
+            next = connection.poll_for_event();
+        }
+
+        let Some(monitor) = monitor.upgrade() else {
+            return;
+        };
+        if displays_changed {
+            monitor.publish(SourceChange::DisplaysChanged);
+        }
+        for window in &destroyed {
+            monitor.publish(SourceChange::WindowClosed(u64::from(*window)));
+        }
+        if clients_changed {
+            let Ok(current) = client_list(connection, root, net_client_list) else {
+                return;
+            };
+            let current: HashSet<Window> = current.into_iter().collect();
+            for closed in clients.difference(&current) {
+                if !destroyed.contains(closed) {
+                    monitor.publish(SourceChange::WindowClosed(u64::from(*closed)));
+                }
+            }
+            monitor.publish(SourceChange::WindowsChanged);
+            clients = current;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{list_displays, spawn_watcher};
+    use crate::source_monitor::{SourceChange, SourceMonitor};
+    use std::sync::Arc;
+    use std::time::{Duration, Instant};
+    use x11rb::connection::Connection;
+    use x11rb::protocol::xproto::{ConnectionExt as _, CreateWindowAux, WindowClass};
+
+    #[test]
+    fn lists_monitors_when_an_x_server_is_available() {
+        if std::env::var_os("DISPLAY").is_none() {
+            return;
+        }
+        let Ok(displays) = list_displays() else {
+            // Servers without RandR 1.5 fall back to the synthetic display.
+            return;
+        };
+        assert!(displays
+            .iter()
+            .all(|display| display.width > 0 && display.height > 0));
+    }
+
+    #[test]
+    fn destroying_a_watched_unmanaged_window_is_published() {
+        if std::env::var_os("DISPLAY").is_none() {
+            return;
+        }
+        let monitor = Arc::new(SourceMonitor::default());
+        let Ok(watcher) = spawn_watcher(Arc::downgrade(&monitor)) else {
+            return;
+        };
+        let (connection, screen_number) = x11rb::connect(None).expect("connect to X");
+        let root = connection.setup().roots[screen_number].root;
+        let window = connection.generate_id().expect("window id");
+        connection
+            .create_window(
+                0,
+                window,
+                root,
+                0,
+                0,
+                10,
+                10,
+                0,
+                WindowClass::INPUT_ONLY,
+                0,
+                &CreateWindowAux::new().override_redirect(1),
+            )
+            .expect("send create")
+            .check()
+            .expect("create window");
+        watcher.watch_window(u64::from(window));
+        connection
+            .destroy_window(window)
+            .expect("send destroy")
+            .check()
+            .expect("destroy window");
+
+        let deadline = Instant::now() + Duration::from_secs(2);
+        let mut changes = Vec::new();
+        while !changes.contains(&SourceChange::WindowClosed(u64::from(window)))
+            && Instant::now() < deadline
+        {
+            changes.extend(monitor.take_changes());
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        assert!(changes.contains(&SourceChange::WindowClosed(u64::from(window))));
+    }
+}
@@ -0,0 +1,228 @@
+use crate::privacy_mask::WindowInfo;
+use std::sync::Mutex;
+use x11rb::connection::Connection;
+use x11rb::errors::ReplyError;
+use x11rb::protocol::xproto::{
+    self, AtomEnum, ClientMessageEvent, ConnectionExt as _, CreateWindowAux, MapState, Window,
+    WindowClass,
+};
+use x11rb::rust_connection::RustConnection;
+
+/// Longest window title read, in 32-bit units.
+const TITLE_PROPERTY_LENGTH: u32 = 256;
+/// Longest `_NET_CLIENT_LIST` read, in windows.
+const CLIENT_LIST_LENGTH: u32 = 4096;
+
+struct Session {
+    connection: RustConnection,
+    root: Window,
+    net_client_list: xproto::Atom,
+    net_wm_name: xproto::Atom,
+    utf8_string: xproto::Atom,
+}
+
+/// Creates an unmapped window that a thread blocked reading `connection` is woken through.
+pub(crate) fn create_wake_window(
+    connection: &RustConnection,
+    root: Window,
+) -> Result<Window, String> {
+    let window = connection
+        .generate_id()
+        .map_err(|error| format!("unable to allocate a wake window: {error}"))?;
+    connection
+        .create_window(
+            0,
+            window,
+            root,
+            0,
+            0,
+            1,
+            1,
+            0,
+            WindowClass::INPUT_ONLY,
+            0,
+            &CreateWindowAux::new(),
+        )
+        .map_err(ReplyError::from)
+        .and_then(|cookie| cookie.check())
+        .map_err(|error| format!("unable to create a wake window: {error}"))?;
+    Ok(window)
+}
+
+/// Wakes the thread reading `connection` with a client message on its wake `window`. With an
+/// empty event mask the message is delivered to the window's creator, that same connection.
+pub(crate) fn send_wake(connection: &RustConnection, window: Window) {
+    let message = ClientMessageEvent::new(32, window, AtomEnum::NONE, [0u32; 5]);
+    let _ = connection.send_event(false, window, xproto::EventMask::NO_EVENT, message);
+    let _ = connection.flush();
+}
+
+/// Top-level windows managed by the window manager, read from `_NET_CLIENT_LIST` on `root`.
+pub(crate) fn client_list(
+    connection: &RustConnection,
+    root: Window,
+    net_client_list: xproto::Atom,
+) -> Result<Vec<Window>, ReplyError> {
+    let reply = connection
+        .get_property(
+            false,
+            root,
+            net_client_list,
+            AtomEnum::WINDOW,
+            0,
+            CLIENT_LIST_LENGTH,
+        )?
+        .reply()?;
+    Ok(reply
+        .value32()
+        .map(|windows| windows.collect())
+        .unwrap_or_default())
+}
+
+/// Kept open between calls: masking re-reads window geometry several times a second.
+static SESSION: Mutex<Option<Session>> = Mutex::new(None);
+
+impl Session {
+    fn connect() -> Result<Self, String> {
+        let (connection, screen_number) = x11rb::connect(None)
+            .map_err(|error| format!("unable to connect to the X server: {error}"))?;
+        let root = connection
+            .setup()
+            .roots
+            .get(screen_number)
+            .map(|screen| screen.root)
+            .ok_or_else(|| "X server reported no screens".to_string())?;
+        let intern = |name: &[u8]| -> Result<xproto::Atom, String> {
+            connection
+                .intern_atom(false, name)
+                .map_err(|error| error.to_string())?
+                .reply()
+                .map(|reply| reply.atom)
+                .map_err(|error| error.to_string())
+        };
+        let net_client_list = intern(b"_NET_CLIENT_LIST")?;
+        let net_wm_name = intern(b"_NET_WM_NAME")?;
+        let utf8_string = intern(b"UTF8_STRING")?;
+        Ok(Self {
+            connection,
+            root,
+            net_client_list,
+            net_wm_name,
+            utf8_string,
+        })
+    }
+
+    fn client_windows(&self) -> Result<Vec<Window>, ReplyError> {
+        client_list(&self.connection, self.root, self.net_client_list)
+    }
+
+    fn text_property(
+        &self,
+        window: Window,
+        property: impl Into<xproto::Atom>,
+        kind: impl Into<xproto::Atom>,
+    ) -> Result<Vec<u8>, ReplyError> {
+        Ok(self
+            .connection
+            .get_property(false, window, property, kind, 0, TITLE_PROPERTY_LENGTH)?
+            .reply()?
+            .value)
+    }
+
+    fn title(&self, window: Window) -> Result<String, ReplyError> {
+        let title = self.text_property(window, self.net_wm_name, self.utf8_string)?;
+        if !title.is_empty() {
+            return Ok(String::from_utf8_lossy(&title).into_owned());
+        }
+        // ICCCM WM_NAME is Latin-1.
+        let title = self.text_property(window, AtomEnum::WM_NAME, AtomEnum::STRING)?;
+        Ok(title.into_iter().map(char::from).collect())
+    }
+
+    /// The window manager's frame around `window` (its child of the root window), so title
+    /// bars that repeat the window title are masked too.
+    fn frame(&self, window: Window) -> Result<Window, ReplyError> {
+        let mut current = window;
+        loop {
+            let parent = self.connection.query_tree(current)?.reply()?.parent;
+            if parent == self.root || parent == x11rb::NONE {
+                return Ok(current);
+            }
+            current = parent;
+        }
+    }
+
+    fn window(&self, window: Window) -> Result<Option<WindowInfo>, ReplyError> {
+        let attributes = self.connection.get_window_attributes(window)?.reply()?;
+        if attributes.map_state != MapState::VIEWABLE {
+            return Ok(None);
+        }
+        let frame = self.frame(window)?;
+        let geometry = self.connection.get_geometry(frame)?.reply()?;
+        let wm_class = self.text_property(window, AtomEnum::WM_CLASS, AtomEnum::STRING)?;
+        let border = geometry.border_width as u32 * 2;
+        Ok(Some(WindowInfo {
+            id: window as u64,
+            title: self.title(window)?,
+            wm_class: wm_class
+                .split(|byte| *byte == 0)
+                .filter(|name| !name.is_empty())
+                .map(|name| String::from_utf8_lossy(name).into_owned())
+                .collect(),
+            x: geometry.x as i32,
+            y: geometry.y as i32,
+            width: geometry.width as u32 + border,
+            height: geometry.height as u32 + border,
+        }))
+    }
+
+    fn windows(&self) -> Result<Vec<WindowInfo>, ReplyError> {
+        let mut windows = Vec::new();
+        for window in self.client_windows()? {
+            match self.window(window) {
+                Ok(Some(info)) => windows.push(info),
+                Ok(None) => {}
+                // Destroyed between listing and querying it.
+                Err(ReplyError::X11Error(_)) => {}
+                Err(error) => return Err(error),
+            }
+        }
+        Ok(windows)
+    }
+}
+
+/// Mapped top-level windows from the window manager's `_NET_CLIENT_LIST`, with their frames
+/// in root coordinates.
+pub(crate) fn list_windows() -> Result<Vec<WindowInfo>, String> {
+    let mut session = SESSION
+        .lock()
+        .map_err(|_| "X11 window session lock poisoned".to_string())?;
+    if session.is_none() {
+        *session = Some(Session::connect()?);
+    }
+    let result = session
+        .as_ref()
+        .map(Session::windows)
+        .unwrap_or_else(|| Ok(Vec::new()));
+    result.map_err(|error| {
+        // Reconnect on the next call, e.g. after the X server restarted.
+        *session = None;
+        format!("unable to list windows: {error}")
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::list_windows;
+
+    #[test]
+    fn lists_windows_when_an_x_server_is_available() {
+        if std::env::var_os("DISPLAY").is_none() {
+            return;
+        }
+        let windows = list_windows().expect("X server should list windows");
+        assert!(windows
+            .iter()
+            .all(|window| window.width > 0 && window.height > 0));
+    }
+}
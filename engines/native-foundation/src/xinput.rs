@@ -0,0 +1,431 @@
+use crate::input_tracking::{
+    CapturedInput, CapturedInputEvent, CapturedShortcut, InputEventType, InputPoint, MouseButton,
+};
+use crate::shortcuts::ShortcutRecognizer;
+use crate::wire::CaptureClock;
+use crate::x11_windows::{create_wake_window, send_wake};
+use std::io;
+use std::os::fd::AsRawFd;
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+use x11rb::connection::Connection;
+use x11rb::errors::ReplyError;
+use x11rb::protocol::xinput::{self, ConnectionExt as _};
+use x11rb::protocol::xproto::{ConnectionExt as _, Mapping, Window};
+use x11rb::protocol::Event;
+use x11rb::rust_connection::RustConnection;
+
+/// `XIAllMasterDevices`: raw events from every master pointer and keyboard.
+const ALL_MASTER_DEVICES: xinput::DeviceId = 1;
+/// Cursor positions are sampled at most at 60 Hz, like the macOS cursor sampler.
+const CURSOR_SAMPLE_INTERVAL: Duration = Duration::from_micros(16_667);
+
+/// The tracker thread and the connection it blocks on.
+pub(crate) struct TrackerThread {
+    connection: Arc<RustConnection>,
+    /// Unmapped window the thread is woken through to stop it.
+    wake: Window,
+    thread: JoinHandle<()>,
+}
+
+impl TrackerThread {
+    /// Wakes the thread and waits for it to finish recording.
+    pub(crate) fn stop(self) {
+        send_wake(&self.connection, self.wake);
+        let _ = self.thread.join();
+    }
+}
+
+/// Selects XInput2 raw pointer events (and raw key events when `track_keys` is set) on the
+/// root window of `display` (or `$DISPLAY`) and records them on a background thread until it
+/// is stopped.
+pub(crate) fn spawn_tracker(
+    display: Option<&str>,
+    clock: CaptureClock,
+    track_keys: bool,
+    captured: Arc<Mutex<CapturedInput>>,
+) -> Result<TrackerThread, String> {
+    let (connection, screen_number) = x11rb::connect(display)
+        .map_err(|error| format!("unable to connect to the X server: {error}"))?;
+    let root = connection
+        .setup()
+        .roots
+        .get(screen_number)
+        .map(|screen| screen.root)
+        .ok_or_else(|| "X server reported no screens".to_string())?;
+    let version = connection
+        .xinput_xi_query_version(2, 2)
+        .map_err(|error| format!("XInput2 is unavailable: {error}"))?
+        .reply()
+        .map_err(|error| format!("XInput2 is unavailable: {error}"))?;
+    if version.major_version < 2 {
+        return Err(format!(
+            "XInput2 is unavailable: server supports {}.{}",
+            version.major_version, version.minor_version
+        ));
+    }
+    let keymap = if track_keys {
+        Some(Keymap::fetch(&connection)?)
+    } else {
+        None
+    };
+    let mut mask = xinput::XIEventMask::RAW_MOTION
+        | xinput::XIEventMask::RAW_BUTTON_PRESS
+        | xinput::XIEventMask::RAW_BUTTON_RELEASE;
+    if track_keys {
+        mask = mask | xinput::XIEventMask::RAW_KEY_PRESS | xinput::XIEventMask::RAW_KEY_RELEASE;
+    }
+    connection
+        .xinput_xi_select_events(
+            root,
+            &[xinput::EventMask {
+                deviceid: ALL_MASTER_DEVICES,
+                mask: vec![mask],
+            }],
+        )
+        .map_err(ReplyError::from)
+        .and_then(|cookie| cookie.check())
+        .map_err(|error| format!("unable to select XInput2 raw events: {error}"))?;
+    let wake = create_wake_window(&connection, root)?;
+    // Raw events carry no root coordinates, so clicks reuse the latest sampled position.
+    let position = pointer_position(&connection, root);
+
+    let connection = Arc::new(connection);
+    let tracker = Tracker {
+        connection: Arc::clone(&connection),
+        root,
+        wake,
+        clock,
+        keymap,
+        captured,
+        recognizer: ShortcutRecognizer::default(),
+        motion_pending: false,
+        sampled_at: None,
+        position,
+    };
+    let thread = thread::spawn(move || tracker.run());
+    Ok(TrackerThread {
+        connection,
+        wake,
+        thread,
+    })
+}
+
+/// Unshifted keysym per keycode, so badges read "Ctrl+Shift+P" rather than "Ctrl+Shift+p"
+/// or "Ctrl+Shift+{" regardless of the modifiers held.
+struct Keymap {
+    min_keycode: u8,
+    keysyms_per_keycode: usize,
+    keysyms: Vec<u32>,
+}
+
+impl Keymap {
+    fn fetch(connection: &RustConnection) -> Result<Self, String> {
+        let setup = connection.setup();
+        let min_keycode = setup.min_keycode;
+        let count = setup
+            .max_keycode
+            .saturating_sub(min_keycode)
+            .saturating_add(1);
+        let reply = connection
+            .get_keyboard_mapping(min_keycode, count)
+            .map_err(ReplyError::from)
+            .and_then(|cookie| cookie.reply())
+            .map_err(|error| format!("unable to read the keyboard mapping: {error}"))?;
+        Ok(Self {
+            min_keycode,
+            keysyms_per_keycode: usize::from(reply.keysyms_per_keycode),
+            keysyms: reply.keysyms,
+        })
+    }
+
+    fn keysym(&self, keycode: u32) -> Option<u32> {
+        let index = keycode.checked_sub(u32::from(self.min_keycode))? as usize;
+        self.keysyms
+            .get(index * self.keysyms_per_keycode)
+            .copied()
+            .filter(|keysym| *keysym != 0)
+    }
+}
+
+/// State of the tracker thread.
+struct Tracker {
+    connection: Arc<RustConnection>,
+    root: Window,
+    wake: Window,
+    clock: CaptureClock,
+    keymap: Option<Keymap>,
+    captured: Arc<Mutex<CapturedInput>>,
+    recognizer: ShortcutRecognizer,
+    /// Raw motion arrived since the cursor was last sampled.
+    motion_pending: bool,
+    sampled_at: Option<Instant>,
+    position: Option<InputPoint>,
+}
+
+impl Tracker {
+    /// Blocks on the connection, waking early only to sample pending motion at the sample rate.
+    fn run(mut self) {
+        loop {
+            loop {
+                let event = match self.connection.poll_for_event() {
+                    Ok(Some(event)) => event,
+                    Ok(None) => break,
+                    Err(_) => return,
+                };
+                if matches!(&event, Event::ClientMessage(message) if message.window == self.wake) {
+                    return;
+                }
+                self.handle(event);
+            }
+
+            let next_sample_in = self.sampled_at.map_or(Duration::ZERO, |at| {
+                CURSOR_SAMPLE_INTERVAL.saturating_sub(at.elapsed())
+            });
+            if self.motion_pending && next_sample_in.is_zero() {
+                // Waiting for the pointer reply may queue events, so drain again before blocking.
+                self.sample_cursor();
+                continue;
+            }
+            let timeout = self.motion_pending.then_some(next_sample_in);
+            if wait_readable(&self.connection, timeout).is_err() {
+                return;
+            }
+        }
+    }
+
+    fn handle(&mut self, event: Event) {
+        let (event_type, detail) = match event {
+            Event::XinputRawMotion(_) => {
+                self.motion_pending = true;
+                return;
+            }
+            Event::XinputRawButtonPress(event) => (InputEventType::MouseDown, event.detail),
+            Event::XinputRawButtonRelease(event) => (InputEventType::MouseUp, event.detail),
+            Event::XinputRawKeyPress(event) | Event::XinputRawKeyRelease(event) => {
+                self.key_event(
+                    event.detail,
+                    event.event_type == xinput::RAW_KEY_PRESS_EVENT,
+                );
+                return;
+            }
+            Event::MappingNotify(event) if event.request == Mapping::KEYBOARD => {
+                if self.keymap.is_some() {
+                    if let Ok(keymap) = Keymap::fetch(&self.connection) {
+                        self.keymap = Some(keymap);
+                    }
+                }
+                return;
+            }
+            _ => return,
+        };
+        let Some(button) = mouse_button(detail) else {
+            return;
+        };
+        let captured_at_seconds = self.clock.elapsed_seconds();
+        // Motion not sampled yet moved the pointer to where this click happened.
+        if self.motion_pending {
+            self.sample_cursor();
+        }
+        if let Some(position) = self.position {
+            self.record(CapturedInputEvent {
+                captured_at_seconds,
+                event_type,
+                position,
+                button: Some(button),
+            });
+        }
+    }
+
+    fn key_event(&mut self, keycode: u32, pressed: bool) {
+        let keysym = self
+            .keymap
+            .as_ref()
+            .and_then(|keymap| keymap.keysym(keycode));
+        let Some(keys) = keysym.and_then(|keysym| self.recognizer.key_event(keysym, pressed))
+        else {
+            return;
+        };
+        let captured_at_seconds = self.clock.elapsed_seconds();
+        if let Ok(mut captured) = self.captured.lock() {
+            captured.shortcuts.push(CapturedShortcut {
+                captured_at_seconds,
+                keys,
+            });
+        }
+    }
+
+    fn sample_cursor(&mut self) {
+        self.motion_pending = false;
+        self.sampled_at = Some(Instant::now());
+        let captured_at_seconds = self.clock.elapsed_seconds();
+        let Some(position) = pointer_position(&self.connection, self.root) else {
+            return;
+        };
+        if self.position != Some(position) {
+            self.position = Some(position);
+            self.record(CapturedInputEvent {
+                captured_at_seconds,
+                event_type: InputEventType::CursorMoved,
+                position,
+                button: None,
+            });
+        }
+    }
+
+    fn record(&self, event: CapturedInputEvent) {
+        if let Ok(mut captured) = self.captured.lock() {
+            captured.events.push(event);
+        }
+    }
+}
+
+/// Waits until the connection has data to read, or until `timeout` passes when one is given.
+fn wait_readable(connection: &RustConnection, timeout: Option<Duration>) -> io::Result<()> {
+    let mut descriptor = libc::pollfd {
+        fd: connection.stream().as_raw_fd(),
+        events: libc::POLLIN,
+        revents: 0,
+    };
+    let timeout_ms = timeout.map_or(-1, |timeout| {
+        timeout.as_millis().clamp(1, libc::c_int::MAX as u128) as libc::c_int
+    });
+    loop {
+        // SAFETY: `descriptor` is a valid pollfd for the duration of the call.
+        if unsafe { libc::poll(&mut descriptor, 1, timeout_ms) } >= 0 {
+            return Ok(());
+        }
+        let error = io::Error::last_os_error();
+        if error.kind() != io::ErrorKind::Interrupted {
+            return Err(error);
+        }
+    }
+}
+
+/// Core pointer buttons 4–7 are scroll wheel steps, which are not clicks.
+fn mouse_button(detail: u32) -> Option<MouseButton> {
+    match detail {
+        1 => Some(MouseButton::Left),
+        3 => Some(MouseButton::Right),
+        4..=7 => None,
+        _ => Some(MouseButton::Other),
+    }
+}
+
+fn pointer_position(connection: &RustConnection, root: Window) -> Option<InputPoint> {
+    let reply = connection.query_pointer(root).ok()?.reply().ok()?;
+    Some(InputPoint {
+        x: reply.root_x as f64,
+        y: reply.root_y as f64,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{mouse_button, spawn_tracker};
+    use crate::input_tracking::{CapturedInput, InputEventType, InputPoint, MouseButton};
+    use crate::wire::CaptureClock;
+    use std::path::PathBuf;
+    use std::process::{Command, Stdio};
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    fn find_executable(name: &str) -> Option<PathBuf> {
+        std::env::split_paths(&std::env::var_os("PATH")?)
+            .map(|directory| directory.join(name))
+            .find(|candidate| candidate.is_file())
+    }
+
+    #[test]
+    fn maps_core_buttons_and_ignores_scroll_steps() {
+        assert_eq!(mouse_button(1), Some(MouseButton::Left));
+        assert_eq!(mouse_button(2), Some(MouseButton::Other));
+        assert_eq!(mouse_button(3), Some(MouseButton::Right));
+        assert_eq!(mouse_button(5), None);
+        assert_eq!(mouse_button(8), Some(MouseButton::Other));
+    }
+
+    #[test]
+    #[ignore = "needs Xvfb and xdotool on PATH; run with `cargo test -- --ignored`"]
+    fn records_xdotool_motion_clicks_and_shortcuts_under_xvfb() {
+        let xvfb = find_executable("Xvfb").expect("Xvfb on PATH");
+        let xdotool = find_executable("xdotool").expect("xdotool on PATH");
+        let display = format!(":{}", 70 + std::process::id() % 200);
+        let mut server = Command::new(xvfb)
+            .args([
+                display.as_str(),
+                "-screen",
+                "0",
+                "640x480x24",
+                "-nolisten",
+                "tcp",
+            ])
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .expect("spawn Xvfb");
+
+        let captured = Arc::new(Mutex::new(CapturedInput::default()));
+        let mut tracker = None;
+        for _ in 0..50 {
+            match spawn_tracker(
+                Some(&display),
+                CaptureClock::default(),
+                true,
+                Arc::clone(&captured),
+            ) {
+                Ok(thread) => {
+                    tracker = Some(thread);
+                    break;
+                }
+                Err(_) => std::thread::sleep(Duration::from_millis(100)),
+            }
+        }
+        let tracker = tracker.expect("connect to Xvfb");
+
+        let status = Command::new(xdotool)
+            .env("DISPLAY", &display)
+            .args(["mousemove", "100", "120", "sleep", "0.1", "click", "1"])
+            .args(["sleep", "0.1", "click", "3"])
+            .args(["sleep", "0.1", "type", "ab"])
+            .args(["sleep", "0.1", "key", "ctrl+shift+p"])
+            .status()
+            .expect("run xdotool");
+        assert!(status.success());
+        std::thread::sleep(Duration::from_millis(200));
+        tracker.stop();
+        let _ = server.kill();
+        let _ = server.wait();
+
+        let captured = captured.lock().expect("captured input").clone();
+        let keys = captured
+            .shortcuts
+            .iter()
+            .map(|shortcut| shortcut.keys.join("+"))
+            .collect::<Vec<_>>();
+        assert_eq!(keys, vec!["Ctrl+Shift+P".to_string()]);
+        let events = captured.events;
+        let at = InputPoint { x: 100.0, y: 120.0 };
+        assert!(events
+            .iter()
+            .any(|event| event.event_type == InputEventType::CursorMoved && event.position == at));
+        let clicks = events
+            .iter()
+            .filter(|event| event.event_type != InputEventType::CursorMoved)
+            .map(|event| (event.event_type, event.button, event.position))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            clicks,
+            vec![
+                (InputEventType::MouseDown, Some(MouseButton::Left), at),
+                (InputEventType::MouseUp, Some(MouseButton::Left), at),
+                (InputEventType::MouseDown, Some(MouseButton::Right), at),
+                (InputEventType::MouseUp, Some(MouseButton::Right), at),
+            ]
+        );
+        assert!(events
+            .windows(2)
+            .all(|pair| pair[0].captured_at_seconds <= pair[1].captured_at_seconds));
+    }
+}
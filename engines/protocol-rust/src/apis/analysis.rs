@@ -0,0 +1,49 @@
+use async_trait::async_trait;
+use axum::extract::*;
+use axum_extra::extract::CookieJar;
+use bytes::Bytes;
+use headers::Host;
+use http::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{models, types::*};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum AnalysisAnalysisVisualActivityResponse {
+    /// VisualActivityResult
+    Status200_VisualActivityResult(models::VisualActivityResult),
+    /// EngineBadRequestError response body.
+    Status400_EngineBadRequestErrorResponseBody(models::EngineBadRequestError),
+    /// EngineUnauthorizedError response body.
+    Status401_EngineUnauthorizedErrorResponseBody(models::AgentAgentPreflight401Response),
+    /// EngineForbiddenError response body.
+    Status403_EngineForbiddenErrorResponseBody(models::EngineForbiddenError),
+    /// EngineConflictError response body.
+    Status409_EngineConflictErrorResponseBody(models::EngineConflictError),
+    /// EngineUnprocessableError response body.
+    Status422_EngineUnprocessableErrorResponseBody(models::EngineUnprocessableError),
+    /// EngineRuntimeError response body.
+    Status500_EngineRuntimeErrorResponseBody(models::EngineRuntimeError),
+}
+
+/// Analysis
+#[async_trait]
+#[allow(clippy::ptr_arg)]
+pub trait Analysis<E: std::fmt::Debug + Send + Sync + 'static = ()>:
+    super::ErrorHandler<E>
+{
+    type Claims;
+
+    /// AnalysisAnalysisVisualActivity - POST /v1/analysis/visual-activity
+    async fn analysis_analysis_visual_activity(
+        &self,
+
+        method: &Method,
+        host: &Host,
+        cookies: &CookieJar,
+        claims: &Self::Claims,
+        body: &models::AnalysisVisualActivityPayload,
+    ) -> Result<AnalysisAnalysisVisualActivityResponse, E>;
+}
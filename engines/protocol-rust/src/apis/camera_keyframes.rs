@@ -0,0 +1,106 @@
+use async_trait::async_trait;
+use axum::extract::*;
+use axum_extra::extract::CookieJar;
+use bytes::Bytes;
+use headers::Host;
+use http::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{models, types::*};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum CameraKeyframesCameraKeyframesEditResponse {
+    /// CameraKeyframesResult
+    Status200_CameraKeyframesResult(models::CameraKeyframesResult),
+    /// EngineBadRequestError response body.
+    Status400_EngineBadRequestErrorResponseBody(models::EngineBadRequestError),
+    /// EngineUnauthorizedError response body.
+    Status401_EngineUnauthorizedErrorResponseBody(models::AgentAgentPreflight401Response),
+    /// EngineForbiddenError response body.
+    Status403_EngineForbiddenErrorResponseBody(models::EngineForbiddenError),
+    /// EngineConflictError response body.
+    Status409_EngineConflictErrorResponseBody(models::EngineConflictError),
+    /// EngineUnprocessableError response body.
+    Status422_EngineUnprocessableErrorResponseBody(models::EngineUnprocessableError),
+    /// EngineRuntimeError response body.
+    Status500_EngineRuntimeErrorResponseBody(models::EngineRuntimeError),
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum CameraKeyframesCameraKeyframesListResponse {
+    /// CameraKeyframesResult
+    Status200_CameraKeyframesResult(models::CameraKeyframesResult),
+    /// EngineBadRequestError response body.
+    Status400_EngineBadRequestErrorResponseBody(models::EngineBadRequestError),
+    /// EngineUnauthorizedError response body.
+    Status401_EngineUnauthorizedErrorResponseBody(models::AgentAgentPreflight401Response),
+    /// EngineForbiddenError response body.
+    Status403_EngineForbiddenErrorResponseBody(models::EngineForbiddenError),
+    /// EngineRuntimeError response body.
+    Status500_EngineRuntimeErrorResponseBody(models::EngineRuntimeError),
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum CameraKeyframesCameraKeyframesReplanResponse {
+    /// CameraKeyframesResult
+    Status200_CameraKeyframesResult(models::CameraKeyframesResult),
+    /// EngineBadRequestError response body.
+    Status400_EngineBadRequestErrorResponseBody(models::EngineBadRequestError),
+    /// EngineUnauthorizedError response body.
+    Status401_EngineUnauthorizedErrorResponseBody(models::AgentAgentPreflight401Response),
+    /// EngineForbiddenError response body.
+    Status403_EngineForbiddenErrorResponseBody(models::EngineForbiddenError),
+    /// EngineConflictError response body.
+    Status409_EngineConflictErrorResponseBody(models::EngineConflictError),
+    /// EngineUnprocessableError response body.
+    Status422_EngineUnprocessableErrorResponseBody(models::EngineUnprocessableError),
+    /// EngineRuntimeError response body.
+    Status500_EngineRuntimeErrorResponseBody(models::EngineRuntimeError),
+}
+
+/// CameraKeyframes
+#[async_trait]
+#[allow(clippy::ptr_arg)]
+pub trait CameraKeyframes<E: std::fmt::Debug + Send + Sync + 'static = ()>:
+    super::ErrorHandler<E>
+{
+    type Claims;
+
+    /// CameraKeyframesCameraKeyframesEdit - POST /v1/camera-keyframes/edit
+    async fn camera_keyframes_camera_keyframes_edit(
+        &self,
+
+        method: &Method,
+        host: &Host,
+        cookies: &CookieJar,
+        claims: &Self::Claims,
+        body: &models::CameraKeyframesEditPayload,
+    ) -> Result<CameraKeyframesCameraKeyframesEditResponse, E>;
+
+    /// CameraKeyframesCameraKeyframesList - GET /v1/camera-keyframes
+    async fn camera_keyframes_camera_keyframes_list(
+        &self,
+
+        method: &Method,
+        host: &Host,
+        cookies: &CookieJar,
+        claims: &Self::Claims,
+        query_params: &models::CameraKeyframesCameraKeyframesListQueryParams,
+    ) -> Result<CameraKeyframesCameraKeyframesListResponse, E>;
+
+    /// CameraKeyframesCameraKeyframesReplan - POST /v1/camera-keyframes/replan
+    async fn camera_keyframes_camera_keyframes_replan(
+        &self,
+
+        method: &Method,
+        host: &Host,
+        cookies: &CookieJar,
+        claims: &Self::Claims,
+    ) -> Result<CameraKeyframesCameraKeyframesReplanResponse, E>;
+}
@@ -24,6 +24,26 @@ pub enum CaptureCapturePreviewFrameResponse {
     Status500_EngineRuntimeErrorResponseBody(models::EngineRuntimeError),
 }
 
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum CaptureCaptureStartCameraResponse {
+    /// CaptureStatusResult
+    Status200_CaptureStatusResult(models::CaptureStatusResult),
+    /// EngineBadRequestError response body.
+    Status400_EngineBadRequestErrorResponseBody(models::EngineBadRequestError),
+    /// EngineUnauthorizedError response body.
+    Status401_EngineUnauthorizedErrorResponseBody(models::AgentAgentPreflight401Response),
+    /// EngineForbiddenError response body.
+    Status403_EngineForbiddenErrorResponseBody(models::EngineForbiddenError),
+    /// EngineConflictError response body.
+    Status409_EngineConflictErrorResponseBody(models::EngineConflictError),
+    /// EngineUnprocessableError response body.
+    Status422_EngineUnprocessableErrorResponseBody(models::EngineUnprocessableError),
+    /// EngineRuntimeError response body.
+    Status500_EngineRuntimeErrorResponseBody(models::EngineRuntimeError),
+}
+
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 #[must_use]
 #[allow(clippy::large_enum_variant)]
@@ -64,6 +84,26 @@ pub enum CaptureCaptureStartDisplayResponse {
     Status500_EngineRuntimeErrorResponseBody(models::EngineRuntimeError),
 }
 
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum CaptureCaptureStartRegionResponse {
+    /// CaptureStatusResult
+    Status200_CaptureStatusResult(models::CaptureStatusResult),
+    /// EngineBadRequestError response body.
+    Status400_EngineBadRequestErrorResponseBody(models::EngineBadRequestError),
+    /// EngineUnauthorizedError response body.
+    Status401_EngineUnauthorizedErrorResponseBody(models::AgentAgentPreflight401Response),
+    /// EngineForbiddenError response body.
+    Status403_EngineForbiddenErrorResponseBody(models::EngineForbiddenError),
+    /// EngineConflictError response body.
+    Status409_EngineConflictErrorResponseBody(models::EngineConflictError),
+    /// EngineUnprocessableError response body.
+    Status422_EngineUnprocessableErrorResponseBody(models::EngineUnprocessableError),
+    /// EngineRuntimeError response body.
+    Status500_EngineRuntimeErrorResponseBody(models::EngineRuntimeError),
+}
+
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 #[must_use]
 #[allow(clippy::large_enum_variant)]
@@ -120,6 +160,26 @@ pub enum CaptureCaptureStopResponse {
     Status500_EngineRuntimeErrorResponseBody(models::EngineRuntimeError),
 }
 
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum CaptureCaptureStopCameraResponse {
+    /// CaptureStatusResult
+    Status200_CaptureStatusResult(models::CaptureStatusResult),
+    /// EngineBadRequestError response body.
+    Status400_EngineBadRequestErrorResponseBody(models::EngineBadRequestError),
+    /// EngineUnauthorizedError response body.
+    Status401_EngineUnauthorizedErrorResponseBody(models::AgentAgentPreflight401Response),
+    /// EngineForbiddenError response body.
+    Status403_EngineForbiddenErrorResponseBody(models::EngineForbiddenError),
+    /// EngineConflictError response body.
+    Status409_EngineConflictErrorResponseBody(models::EngineConflictError),
+    /// EngineUnprocessableError response body.
+    Status422_EngineUnprocessableErrorResponseBody(models::EngineUnprocessableError),
+    /// EngineRuntimeError response body.
+    Status500_EngineRuntimeErrorResponseBody(models::EngineRuntimeError),
+}
+
 /// Capture
 #[async_trait]
 #[allow(clippy::ptr_arg)]
@@ -136,6 +196,17 @@ pub trait Capture<E: std::fmt::Debug + Send + Sync + 'static = ()>: super::Error
         claims: &Self::Claims,
     ) -> Result<CaptureCapturePreviewFrameResponse, E>;
 
+    /// CaptureCaptureStartCamera - POST /v1/capture/start-camera
+    async fn capture_capture_start_camera(
+        &self,
+
+        method: &Method,
+        host: &Host,
+        cookies: &CookieJar,
+        claims: &Self::Claims,
+        body: &models::CaptureStartCameraPayload,
+    ) -> Result<CaptureCaptureStartCameraResponse, E>;
+
     /// CaptureCaptureStartCurrentWindow - POST /v1/capture/start-current-window
     async fn capture_capture_start_current_window(
         &self,
@@ -158,6 +229,17 @@ pub trait Capture<E: std::fmt::Debug + Send + Sync + 'static = ()>: super::Error
         body: &models::CaptureStartDisplayPayload,
     ) -> Result<CaptureCaptureStartDisplayResponse, E>;
 
+    /// CaptureCaptureStartRegion - POST /v1/capture/start-region
+    async fn capture_capture_start_region(
+        &self,
+
+        method: &Method,
+        host: &Host,
+        cookies: &CookieJar,
+        claims: &Self::Claims,
+        body: &models::CaptureStartRegionPayload,
+    ) -> Result<CaptureCaptureStartRegionResponse, E>;
+
     /// CaptureCaptureStartWindow - POST /v1/capture/start-window
     async fn capture_capture_start_window(
         &self,
@@ -188,4 +270,14 @@ pub trait Capture<E: std::fmt::Debug + Send + Sync + 'static = ()>: super::Error
         cookies: &CookieJar,
         claims: &Self::Claims,
     ) -> Result<CaptureCaptureStopResponse, E>;
+
+    /// CaptureCaptureStopCamera - POST /v1/capture/stop-camera
+    async fn capture_capture_stop_camera(
+        &self,
+
+        method: &Method,
+        host: &Host,
+        cookies: &CookieJar,
+        claims: &Self::Claims,
+    ) -> Result<CaptureCaptureStopCameraResponse, E>;
 }
@@ -26,6 +26,26 @@ pub enum ExportExportGetResponse {
     Status500_EngineRuntimeErrorResponseBody(models::EngineRuntimeError),
 }
 
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum ExportExportGuideResponse {
+    /// ExportGuideResult
+    Status200_ExportGuideResult(models::ExportGuideResult),
+    /// EngineBadRequestError response body.
+    Status400_EngineBadRequestErrorResponseBody(models::EngineBadRequestError),
+    /// EngineUnauthorizedError response body.
+    Status401_EngineUnauthorizedErrorResponseBody(models::AgentAgentPreflight401Response),
+    /// EngineForbiddenError response body.
+    Status403_EngineForbiddenErrorResponseBody(models::EngineForbiddenError),
+    /// EngineConflictError response body.
+    Status409_EngineConflictErrorResponseBody(models::EngineConflictError),
+    /// EngineUnprocessableError response body.
+    Status422_EngineUnprocessableErrorResponseBody(models::EngineUnprocessableError),
+    /// EngineRuntimeError response body.
+    Status500_EngineRuntimeErrorResponseBody(models::EngineRuntimeError),
+}
+
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 #[must_use]
 #[allow(clippy::large_enum_variant)]
@@ -99,6 +119,17 @@ pub trait Export<E: std::fmt::Debug + Send + Sync + 'static = ()>: super::ErrorH
         path_params: &models::ExportExportGetPathParams,
     ) -> Result<ExportExportGetResponse, E>;
 
+    /// ExportExportGuide - POST /v1/exports/guide
+    async fn export_export_guide(
+        &self,
+
+        method: &Method,
+        host: &Host,
+        cookies: &CookieJar,
+        claims: &Self::Claims,
+        body: &models::ExportGuidePayload,
+    ) -> Result<ExportExportGuideResponse, E>;
+
     /// ExportExportInfo - GET /v1/export/info
     async fn export_export_info(
         &self,
@@ -0,0 +1,73 @@
+use async_trait::async_trait;
+use axum::extract::*;
+use axum_extra::extract::CookieJar;
+use bytes::Bytes;
+use headers::Host;
+use http::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{models, types::*};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum LibraryLibraryDefaultsResponse {
+    /// LibraryDefaultsResult
+    Status200_LibraryDefaultsResult(models::LibraryDefaultsResult),
+    /// EngineBadRequestError response body.
+    Status400_EngineBadRequestErrorResponseBody(models::EngineBadRequestError),
+    /// EngineUnauthorizedError response body.
+    Status401_EngineUnauthorizedErrorResponseBody(models::AgentAgentPreflight401Response),
+    /// EngineForbiddenError response body.
+    Status403_EngineForbiddenErrorResponseBody(models::EngineForbiddenError),
+    /// EngineRuntimeError response body.
+    Status500_EngineRuntimeErrorResponseBody(models::EngineRuntimeError),
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum LibraryLibrarySaveDefaultsResponse {
+    /// LibraryDefaultsResult
+    Status200_LibraryDefaultsResult(models::LibraryDefaultsResult),
+    /// EngineBadRequestError response body.
+    Status400_EngineBadRequestErrorResponseBody(models::EngineBadRequestError),
+    /// EngineUnauthorizedError response body.
+    Status401_EngineUnauthorizedErrorResponseBody(models::AgentAgentPreflight401Response),
+    /// EngineForbiddenError response body.
+    Status403_EngineForbiddenErrorResponseBody(models::EngineForbiddenError),
+    /// EngineConflictError response body.
+    Status409_EngineConflictErrorResponseBody(models::EngineConflictError),
+    /// EngineUnprocessableError response body.
+    Status422_EngineUnprocessableErrorResponseBody(models::EngineUnprocessableError),
+    /// EngineRuntimeError response body.
+    Status500_EngineRuntimeErrorResponseBody(models::EngineRuntimeError),
+}
+
+/// Library
+#[async_trait]
+#[allow(clippy::ptr_arg)]
+pub trait Library<E: std::fmt::Debug + Send + Sync + 'static = ()>: super::ErrorHandler<E> {
+    type Claims;
+
+    /// LibraryLibraryDefaults - GET /v1/library/defaults
+    async fn library_library_defaults(
+        &self,
+
+        method: &Method,
+        host: &Host,
+        cookies: &CookieJar,
+        claims: &Self::Claims,
+    ) -> Result<LibraryLibraryDefaultsResponse, E>;
+
+    /// LibraryLibrarySaveDefaults - POST /v1/library/defaults/save
+    async fn library_library_save_defaults(
+        &self,
+
+        method: &Method,
+        host: &Host,
+        cookies: &CookieJar,
+        claims: &Self::Claims,
+        body: &models::LibrarySaveDefaultsPayload,
+    ) -> Result<LibraryLibrarySaveDefaultsResponse, E>;
+}
@@ -1,11 +1,16 @@
 pub mod agent;
+pub mod analysis;
+pub mod camera_keyframes;
 pub mod capture;
 pub mod export;
+pub mod library;
 pub mod permissions;
 pub mod project;
 pub mod recording;
 pub mod sources;
 pub mod system;
+pub mod takes;
+pub mod timeline;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[non_exhaustive]
@@ -80,6 +80,18 @@ pub struct AgentAgentStatusPathParams {
     pub job_id: String,
 }
 
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct CameraKeyframesCameraKeyframesListQueryParams {
+    #[serde(rename = "startSeconds")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_seconds: Option<String>,
+
+    #[serde(rename = "endSeconds")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_seconds: Option<String>,
+}
+
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
 pub struct ExportExportGetPathParams {
@@ -94,6 +106,24 @@ pub struct ProjectProjectRecentsQueryParams {
     pub limit: Option<String>,
 }
 
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct TakesTakesActivatePathParams {
+    pub take_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct TakesTakesDeletePathParams {
+    pub take_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct TakesTakesRenamePathParams {
+    pub take_id: String,
+}
+
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
 pub struct ActionResult {
@@ -1845,47 +1875,40 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<AgentRunSumm
     }
 }
 
-/// User-configurable automatic zoom settings stored with a project or export override.
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct AutoZoomSettings {
-    #[serde(rename = "isEnabled")]
-    pub is_enabled: bool,
-
-    #[serde(rename = "intensity")]
-    pub intensity: f64,
+pub struct AnalysisVisualActivityPayload {
+    #[serde(rename = "sceneThreshold")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scene_threshold: Option<f64>,
 
-    #[serde(rename = "minimumKeyframeInterval")]
-    pub minimum_keyframe_interval: f64,
+    #[serde(rename = "minSceneSeconds")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_scene_seconds: Option<f64>,
 }
 
-impl AutoZoomSettings {
+impl AnalysisVisualActivityPayload {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new(
-        is_enabled: bool,
-        intensity: f64,
-        minimum_keyframe_interval: f64,
-    ) -> AutoZoomSettings {
-        AutoZoomSettings {
-            is_enabled,
-            intensity,
-            minimum_keyframe_interval,
+    pub fn new() -> AnalysisVisualActivityPayload {
+        AnalysisVisualActivityPayload {
+            scene_threshold: None,
+            min_scene_seconds: None,
         }
     }
 }
 
-/// Converts the AutoZoomSettings value to the Query Parameters representation (style=form, explode=false)
+/// Converts the AnalysisVisualActivityPayload value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for AutoZoomSettings {
+impl std::fmt::Display for AnalysisVisualActivityPayload {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
-            Some("isEnabled".to_string()),
-            Some(self.is_enabled.to_string()),
-            Some("intensity".to_string()),
-            Some(self.intensity.to_string()),
-            Some("minimumKeyframeInterval".to_string()),
-            Some(self.minimum_keyframe_interval.to_string()),
+            self.scene_threshold.as_ref().map(|scene_threshold| {
+                ["sceneThreshold".to_string(), scene_threshold.to_string()].join(",")
+            }),
+            self.min_scene_seconds.as_ref().map(|min_scene_seconds| {
+                ["minSceneSeconds".to_string(), min_scene_seconds.to_string()].join(",")
+            }),
         ];
 
         write!(
@@ -1896,10 +1919,10 @@ impl std::fmt::Display for AutoZoomSettings {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a AutoZoomSettings value
+/// Converts Query Parameters representation (style=form, explode=false) to a AnalysisVisualActivityPayload value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for AutoZoomSettings {
+impl std::str::FromStr for AnalysisVisualActivityPayload {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -1907,9 +1930,8 @@ impl std::str::FromStr for AutoZoomSettings {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub is_enabled: Vec<bool>,
-            pub intensity: Vec<f64>,
-            pub minimum_keyframe_interval: Vec<f64>,
+            pub scene_threshold: Vec<f64>,
+            pub min_scene_seconds: Vec<f64>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -1923,7 +1945,7 @@ impl std::str::FromStr for AutoZoomSettings {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing AutoZoomSettings".to_string(),
+                        "Missing value while parsing AnalysisVisualActivityPayload".to_string(),
                     );
                 }
             };
@@ -1932,20 +1954,17 @@ impl std::str::FromStr for AutoZoomSettings {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "isEnabled" => intermediate_rep.is_enabled.push(
-                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "intensity" => intermediate_rep.intensity.push(
+                    "sceneThreshold" => intermediate_rep.scene_threshold.push(
                         <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "minimumKeyframeInterval" => intermediate_rep.minimum_keyframe_interval.push(
+                    "minSceneSeconds" => intermediate_rep.min_scene_seconds.push(
                         <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing AutoZoomSettings".to_string(),
+                            "Unexpected key while parsing AnalysisVisualActivityPayload"
+                                .to_string(),
                         );
                     }
                 }
@@ -1956,58 +1975,45 @@ impl std::str::FromStr for AutoZoomSettings {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(AutoZoomSettings {
-            is_enabled: intermediate_rep
-                .is_enabled
-                .into_iter()
-                .next()
-                .ok_or_else(|| "isEnabled missing in AutoZoomSettings".to_string())?,
-            intensity: intermediate_rep
-                .intensity
-                .into_iter()
-                .next()
-                .ok_or_else(|| "intensity missing in AutoZoomSettings".to_string())?,
-            minimum_keyframe_interval: intermediate_rep
-                .minimum_keyframe_interval
-                .into_iter()
-                .next()
-                .ok_or_else(|| "minimumKeyframeInterval missing in AutoZoomSettings".to_string())?,
+        std::result::Result::Ok(AnalysisVisualActivityPayload {
+            scene_threshold: intermediate_rep.scene_threshold.into_iter().next(),
+            min_scene_seconds: intermediate_rep.min_scene_seconds.into_iter().next(),
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<AutoZoomSettings> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<AnalysisVisualActivityPayload> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<AutoZoomSettings>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<AnalysisVisualActivityPayload>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<AutoZoomSettings>,
+        hdr_value: header::IntoHeaderValue<AnalysisVisualActivityPayload>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                r#"Invalid header value for AutoZoomSettings - value: {hdr_value} is invalid {e}"#
+                r#"Invalid header value for AnalysisVisualActivityPayload - value: {hdr_value} is invalid {e}"#
             )),
         }
     }
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<AutoZoomSettings> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<AnalysisVisualActivityPayload> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <AutoZoomSettings as std::str::FromStr>::from_str(value) {
+                match <AnalysisVisualActivityPayload as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        r#"Unable to convert header value '{value}' into AutoZoomSettings - {err}"#
+                        r#"Unable to convert header value '{value}' into AnalysisVisualActivityPayload - {err}"#
                     )),
                 }
             }
@@ -2018,70 +2024,69 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<AutoZoomSett
     }
 }
 
-/// Versioned project-global background stage and source-card framing settings.
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct BackgroundFramingSettings {
+pub struct AudioLevels {
     /// Note: inline enums are not fully supported by openapi-generator
-    #[serde(rename = "version")]
-    pub version: f64,
+    #[serde(rename = "kind")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub kind: String,
 
-    #[serde(rename = "enabled")]
-    pub enabled: bool,
+    #[serde(rename = "peakDbfs")]
+    pub peak_dbfs: f64,
 
-    #[serde(rename = "backgroundColor")]
-    #[validate(custom(function = "check_xss_string"))]
-    pub background_color: String,
+    #[serde(rename = "rmsDbfs")]
+    pub rms_dbfs: f64,
 
-    #[serde(rename = "paddingFraction")]
-    pub padding_fraction: f64,
+    #[serde(rename = "clippedSamples")]
+    pub clipped_samples: i32,
 
-    #[serde(rename = "cornerRadiusFraction")]
-    pub corner_radius_fraction: f64,
+    #[serde(rename = "silentSeconds")]
+    pub silent_seconds: f64,
 
-    #[serde(rename = "shadowStrength")]
-    pub shadow_strength: f64,
+    #[serde(rename = "silenceWarning")]
+    pub silence_warning: bool,
 }
 
-impl BackgroundFramingSettings {
+impl AudioLevels {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
     pub fn new(
-        version: f64,
-        enabled: bool,
-        background_color: String,
-        padding_fraction: f64,
-        corner_radius_fraction: f64,
-        shadow_strength: f64,
-    ) -> BackgroundFramingSettings {
-        BackgroundFramingSettings {
-            version,
-            enabled,
-            background_color,
-            padding_fraction,
-            corner_radius_fraction,
-            shadow_strength,
+        kind: String,
+        peak_dbfs: f64,
+        rms_dbfs: f64,
+        clipped_samples: i32,
+        silent_seconds: f64,
+        silence_warning: bool,
+    ) -> AudioLevels {
+        AudioLevels {
+            kind,
+            peak_dbfs,
+            rms_dbfs,
+            clipped_samples,
+            silent_seconds,
+            silence_warning,
         }
     }
 }
 
-/// Converts the BackgroundFramingSettings value to the Query Parameters representation (style=form, explode=false)
+/// Converts the AudioLevels value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for BackgroundFramingSettings {
+impl std::fmt::Display for AudioLevels {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
-            Some("version".to_string()),
-            Some(self.version.to_string()),
-            Some("enabled".to_string()),
-            Some(self.enabled.to_string()),
-            Some("backgroundColor".to_string()),
-            Some(self.background_color.to_string()),
-            Some("paddingFraction".to_string()),
-            Some(self.padding_fraction.to_string()),
-            Some("cornerRadiusFraction".to_string()),
-            Some(self.corner_radius_fraction.to_string()),
-            Some("shadowStrength".to_string()),
-            Some(self.shadow_strength.to_string()),
+            Some("kind".to_string()),
+            Some(self.kind.to_string()),
+            Some("peakDbfs".to_string()),
+            Some(self.peak_dbfs.to_string()),
+            Some("rmsDbfs".to_string()),
+            Some(self.rms_dbfs.to_string()),
+            Some("clippedSamples".to_string()),
+            Some(self.clipped_samples.to_string()),
+            Some("silentSeconds".to_string()),
+            Some(self.silent_seconds.to_string()),
+            Some("silenceWarning".to_string()),
+            Some(self.silence_warning.to_string()),
         ];
 
         write!(
@@ -2092,10 +2097,10 @@ impl std::fmt::Display for BackgroundFramingSettings {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a BackgroundFramingSettings value
+/// Converts Query Parameters representation (style=form, explode=false) to a AudioLevels value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for BackgroundFramingSettings {
+impl std::str::FromStr for AudioLevels {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -2103,12 +2108,12 @@ impl std::str::FromStr for BackgroundFramingSettings {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub version: Vec<f64>,
-            pub enabled: Vec<bool>,
-            pub background_color: Vec<String>,
-            pub padding_fraction: Vec<f64>,
-            pub corner_radius_fraction: Vec<f64>,
-            pub shadow_strength: Vec<f64>,
+            pub kind: Vec<String>,
+            pub peak_dbfs: Vec<f64>,
+            pub rms_dbfs: Vec<f64>,
+            pub clipped_samples: Vec<i32>,
+            pub silent_seconds: Vec<f64>,
+            pub silence_warning: Vec<bool>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -2122,7 +2127,7 @@ impl std::str::FromStr for BackgroundFramingSettings {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing BackgroundFramingSettings".to_string(),
+                        "Missing value while parsing AudioLevels".to_string(),
                     );
                 }
             };
@@ -2131,32 +2136,32 @@ impl std::str::FromStr for BackgroundFramingSettings {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "version" => intermediate_rep.version.push(
-                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "kind" => intermediate_rep.kind.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "enabled" => intermediate_rep.enabled.push(
-                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "peakDbfs" => intermediate_rep.peak_dbfs.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "backgroundColor" => intermediate_rep.background_color.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "rmsDbfs" => intermediate_rep.rms_dbfs.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "paddingFraction" => intermediate_rep.padding_fraction.push(
-                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "clippedSamples" => intermediate_rep.clipped_samples.push(
+                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "cornerRadiusFraction" => intermediate_rep.corner_radius_fraction.push(
+                    "silentSeconds" => intermediate_rep.silent_seconds.push(
                         <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "shadowStrength" => intermediate_rep.shadow_strength.push(
-                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "silenceWarning" => intermediate_rep.silence_warning.push(
+                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing BackgroundFramingSettings".to_string(),
+                            "Unexpected key while parsing AudioLevels".to_string(),
                         );
                     }
                 }
@@ -2167,79 +2172,73 @@ impl std::str::FromStr for BackgroundFramingSettings {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(BackgroundFramingSettings {
-            version: intermediate_rep
-                .version
+        std::result::Result::Ok(AudioLevels {
+            kind: intermediate_rep
+                .kind
                 .into_iter()
                 .next()
-                .ok_or_else(|| "version missing in BackgroundFramingSettings".to_string())?,
-            enabled: intermediate_rep
-                .enabled
+                .ok_or_else(|| "kind missing in AudioLevels".to_string())?,
+            peak_dbfs: intermediate_rep
+                .peak_dbfs
                 .into_iter()
                 .next()
-                .ok_or_else(|| "enabled missing in BackgroundFramingSettings".to_string())?,
-            background_color: intermediate_rep
-                .background_color
+                .ok_or_else(|| "peakDbfs missing in AudioLevels".to_string())?,
+            rms_dbfs: intermediate_rep
+                .rms_dbfs
                 .into_iter()
                 .next()
-                .ok_or_else(|| {
-                    "backgroundColor missing in BackgroundFramingSettings".to_string()
-                })?,
-            padding_fraction: intermediate_rep
-                .padding_fraction
+                .ok_or_else(|| "rmsDbfs missing in AudioLevels".to_string())?,
+            clipped_samples: intermediate_rep
+                .clipped_samples
                 .into_iter()
                 .next()
-                .ok_or_else(|| {
-                    "paddingFraction missing in BackgroundFramingSettings".to_string()
-                })?,
-            corner_radius_fraction: intermediate_rep
-                .corner_radius_fraction
+                .ok_or_else(|| "clippedSamples missing in AudioLevels".to_string())?,
+            silent_seconds: intermediate_rep
+                .silent_seconds
                 .into_iter()
                 .next()
-                .ok_or_else(|| {
-                    "cornerRadiusFraction missing in BackgroundFramingSettings".to_string()
-                })?,
-            shadow_strength: intermediate_rep
-                .shadow_strength
+                .ok_or_else(|| "silentSeconds missing in AudioLevels".to_string())?,
+            silence_warning: intermediate_rep
+                .silence_warning
                 .into_iter()
                 .next()
-                .ok_or_else(|| "shadowStrength missing in BackgroundFramingSettings".to_string())?,
+                .ok_or_else(|| "silenceWarning missing in AudioLevels".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<BackgroundFramingSettings> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<AudioLevels> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<BackgroundFramingSettings>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<AudioLevels>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<BackgroundFramingSettings>,
+        hdr_value: header::IntoHeaderValue<AudioLevels>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                r#"Invalid header value for BackgroundFramingSettings - value: {hdr_value} is invalid {e}"#
+                r#"Invalid header value for AudioLevels - value: {hdr_value} is invalid {e}"#
             )),
         }
     }
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<BackgroundFramingSettings> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<AudioLevels> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <BackgroundFramingSettings as std::str::FromStr>::from_str(value) {
+                match <AudioLevels as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        r#"Unable to convert header value '{value}' into BackgroundFramingSettings - {err}"#
+                        r#"Unable to convert header value '{value}' into AudioLevels - {err}"#
                     )),
                 }
             }
@@ -2252,68 +2251,58 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<BackgroundFr
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct CapabilitiesAgent {
-    #[serde(rename = "preflight")]
-    pub preflight: bool,
-
-    #[serde(rename = "run")]
-    pub run: bool,
-
-    #[serde(rename = "status")]
-    pub status: bool,
+pub struct AudioTrack {
+    /// Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "kind")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub kind: String,
 
-    #[serde(rename = "apply")]
-    pub apply: bool,
+    #[serde(rename = "recordingURL")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub recording_url: String,
 
-    #[serde(rename = "localOnly")]
+    #[serde(rename = "deviceId")]
+    #[validate(custom(function = "check_xss_string"))]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub local_only: Option<bool>,
+    pub device_id: Option<String>,
 
-    #[serde(rename = "runtimeBudgetMinutes")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub runtime_budget_minutes: Option<i32>,
+    #[serde(rename = "sampleRate")]
+    pub sample_rate: i32,
+
+    #[serde(rename = "channels")]
+    pub channels: i32,
 }
 
-impl CapabilitiesAgent {
+impl AudioTrack {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new(preflight: bool, run: bool, status: bool, apply: bool) -> CapabilitiesAgent {
-        CapabilitiesAgent {
-            preflight,
-            run,
-            status,
-            apply,
-            local_only: None,
-            runtime_budget_minutes: None,
+    pub fn new(kind: String, recording_url: String, sample_rate: i32, channels: i32) -> AudioTrack {
+        AudioTrack {
+            kind,
+            recording_url,
+            device_id: None,
+            sample_rate,
+            channels,
         }
     }
 }
 
-/// Converts the CapabilitiesAgent value to the Query Parameters representation (style=form, explode=false)
+/// Converts the AudioTrack value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for CapabilitiesAgent {
+impl std::fmt::Display for AudioTrack {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
-            Some("preflight".to_string()),
-            Some(self.preflight.to_string()),
-            Some("run".to_string()),
-            Some(self.run.to_string()),
-            Some("status".to_string()),
-            Some(self.status.to_string()),
-            Some("apply".to_string()),
-            Some(self.apply.to_string()),
-            self.local_only
-                .as_ref()
-                .map(|local_only| ["localOnly".to_string(), local_only.to_string()].join(",")),
-            self.runtime_budget_minutes
+            Some("kind".to_string()),
+            Some(self.kind.to_string()),
+            Some("recordingURL".to_string()),
+            Some(self.recording_url.to_string()),
+            self.device_id
                 .as_ref()
-                .map(|runtime_budget_minutes| {
-                    [
-                        "runtimeBudgetMinutes".to_string(),
-                        runtime_budget_minutes.to_string(),
-                    ]
-                    .join(",")
-                }),
+                .map(|device_id| ["deviceId".to_string(), device_id.to_string()].join(",")),
+            Some("sampleRate".to_string()),
+            Some(self.sample_rate.to_string()),
+            Some("channels".to_string()),
+            Some(self.channels.to_string()),
         ];
 
         write!(
@@ -2324,10 +2313,10 @@ impl std::fmt::Display for CapabilitiesAgent {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a CapabilitiesAgent value
+/// Converts Query Parameters representation (style=form, explode=false) to a AudioTrack value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for CapabilitiesAgent {
+impl std::str::FromStr for AudioTrack {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -2335,12 +2324,11 @@ impl std::str::FromStr for CapabilitiesAgent {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub preflight: Vec<bool>,
-            pub run: Vec<bool>,
-            pub status: Vec<bool>,
-            pub apply: Vec<bool>,
-            pub local_only: Vec<bool>,
-            pub runtime_budget_minutes: Vec<i32>,
+            pub kind: Vec<String>,
+            pub recording_url: Vec<String>,
+            pub device_id: Vec<String>,
+            pub sample_rate: Vec<i32>,
+            pub channels: Vec<i32>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -2354,7 +2342,7 @@ impl std::str::FromStr for CapabilitiesAgent {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing CapabilitiesAgent".to_string(),
+                        "Missing value while parsing AudioTrack".to_string(),
                     );
                 }
             };
@@ -2363,32 +2351,28 @@ impl std::str::FromStr for CapabilitiesAgent {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "preflight" => intermediate_rep.preflight.push(
-                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "run" => intermediate_rep.run.push(
-                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "kind" => intermediate_rep.kind.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "status" => intermediate_rep.status.push(
-                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "recordingURL" => intermediate_rep.recording_url.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "apply" => intermediate_rep.apply.push(
-                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "deviceId" => intermediate_rep.device_id.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "localOnly" => intermediate_rep.local_only.push(
-                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "sampleRate" => intermediate_rep.sample_rate.push(
+                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "runtimeBudgetMinutes" => intermediate_rep.runtime_budget_minutes.push(
+                    "channels" => intermediate_rep.channels.push(
                         <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing CapabilitiesAgent".to_string(),
+                            "Unexpected key while parsing AudioTrack".to_string(),
                         );
                     }
                 }
@@ -2399,65 +2383,64 @@ impl std::str::FromStr for CapabilitiesAgent {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(CapabilitiesAgent {
-            preflight: intermediate_rep
-                .preflight
+        std::result::Result::Ok(AudioTrack {
+            kind: intermediate_rep
+                .kind
                 .into_iter()
                 .next()
-                .ok_or_else(|| "preflight missing in CapabilitiesAgent".to_string())?,
-            run: intermediate_rep
-                .run
+                .ok_or_else(|| "kind missing in AudioTrack".to_string())?,
+            recording_url: intermediate_rep
+                .recording_url
                 .into_iter()
                 .next()
-                .ok_or_else(|| "run missing in CapabilitiesAgent".to_string())?,
-            status: intermediate_rep
-                .status
+                .ok_or_else(|| "recordingURL missing in AudioTrack".to_string())?,
+            device_id: intermediate_rep.device_id.into_iter().next(),
+            sample_rate: intermediate_rep
+                .sample_rate
                 .into_iter()
                 .next()
-                .ok_or_else(|| "status missing in CapabilitiesAgent".to_string())?,
-            apply: intermediate_rep
-                .apply
+                .ok_or_else(|| "sampleRate missing in AudioTrack".to_string())?,
+            channels: intermediate_rep
+                .channels
                 .into_iter()
                 .next()
-                .ok_or_else(|| "apply missing in CapabilitiesAgent".to_string())?,
-            local_only: intermediate_rep.local_only.into_iter().next(),
-            runtime_budget_minutes: intermediate_rep.runtime_budget_minutes.into_iter().next(),
+                .ok_or_else(|| "channels missing in AudioTrack".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<CapabilitiesAgent> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<AudioTrack> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<CapabilitiesAgent>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<AudioTrack>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<CapabilitiesAgent>,
+        hdr_value: header::IntoHeaderValue<AudioTrack>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                r#"Invalid header value for CapabilitiesAgent - value: {hdr_value} is invalid {e}"#
+                r#"Invalid header value for AudioTrack - value: {hdr_value} is invalid {e}"#
             )),
         }
     }
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<CapabilitiesAgent> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<AudioTrack> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <CapabilitiesAgent as std::str::FromStr>::from_str(value) {
+                match <AudioTrack as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        r#"Unable to convert header value '{value}' into CapabilitiesAgent - {err}"#
+                        r#"Unable to convert header value '{value}' into AudioTrack - {err}"#
                     )),
                 }
             }
@@ -2470,87 +2453,67 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<Capabilities
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct CapabilitiesResult {
-    #[serde(rename = "protocolVersion")]
-    #[validate(custom(function = "check_xss_string"))]
-    pub protocol_version: String,
-
-    #[serde(rename = "platform")]
-    #[validate(custom(function = "check_xss_string"))]
-    pub platform: String,
-
+pub struct AudioTrackTelemetry {
     /// Note: inline enums are not fully supported by openapi-generator
-    #[serde(rename = "phase")]
+    #[serde(rename = "kind")]
     #[validate(custom(function = "check_xss_string"))]
-    pub phase: String,
+    pub kind: String,
 
-    #[serde(rename = "capture")]
-    #[validate(nested)]
-    pub capture: models::CapabilitiesResultCapture,
+    #[serde(rename = "capturedSeconds")]
+    pub captured_seconds: f64,
 
-    #[serde(rename = "recording")]
-    #[validate(nested)]
-    pub recording: models::CapabilitiesResultRecording,
+    #[serde(rename = "peakDbfs")]
+    pub peak_dbfs: f64,
 
-    #[serde(rename = "export")]
-    #[validate(nested)]
-    pub export: models::CapabilitiesResultExport,
+    #[serde(rename = "rmsDbfs")]
+    pub rms_dbfs: f64,
 
-    #[serde(rename = "project")]
-    #[validate(nested)]
-    pub project: models::CapabilitiesResultProject,
+    #[serde(rename = "clippedSamples")]
+    pub clipped_samples: i32,
 
-    #[serde(rename = "agent")]
-    #[validate(nested)]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub agent: Option<models::CapabilitiesAgent>,
+    #[serde(rename = "readErrors")]
+    pub read_errors: i32,
 }
 
-impl CapabilitiesResult {
+impl AudioTrackTelemetry {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
     pub fn new(
-        protocol_version: String,
-        platform: String,
-        phase: String,
-        capture: models::CapabilitiesResultCapture,
-        recording: models::CapabilitiesResultRecording,
-        export: models::CapabilitiesResultExport,
-        project: models::CapabilitiesResultProject,
-    ) -> CapabilitiesResult {
-        CapabilitiesResult {
-            protocol_version,
-            platform,
-            phase,
-            capture,
-            recording,
-            export,
-            project,
-            agent: None,
+        kind: String,
+        captured_seconds: f64,
+        peak_dbfs: f64,
+        rms_dbfs: f64,
+        clipped_samples: i32,
+        read_errors: i32,
+    ) -> AudioTrackTelemetry {
+        AudioTrackTelemetry {
+            kind,
+            captured_seconds,
+            peak_dbfs,
+            rms_dbfs,
+            clipped_samples,
+            read_errors,
         }
     }
 }
 
-/// Converts the CapabilitiesResult value to the Query Parameters representation (style=form, explode=false)
+/// Converts the AudioTrackTelemetry value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for CapabilitiesResult {
+impl std::fmt::Display for AudioTrackTelemetry {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
-            Some("protocolVersion".to_string()),
-            Some(self.protocol_version.to_string()),
-            Some("platform".to_string()),
-            Some(self.platform.to_string()),
-            Some("phase".to_string()),
-            Some(self.phase.to_string()),
-            // Skipping capture in query parameter serialization
-
-            // Skipping recording in query parameter serialization
-
-            // Skipping export in query parameter serialization
-
-            // Skipping project in query parameter serialization
-
-            // Skipping agent in query parameter serialization
+            Some("kind".to_string()),
+            Some(self.kind.to_string()),
+            Some("capturedSeconds".to_string()),
+            Some(self.captured_seconds.to_string()),
+            Some("peakDbfs".to_string()),
+            Some(self.peak_dbfs.to_string()),
+            Some("rmsDbfs".to_string()),
+            Some(self.rms_dbfs.to_string()),
+            Some("clippedSamples".to_string()),
+            Some(self.clipped_samples.to_string()),
+            Some("readErrors".to_string()),
+            Some(self.read_errors.to_string()),
         ];
 
         write!(
@@ -2561,10 +2524,10 @@ impl std::fmt::Display for CapabilitiesResult {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a CapabilitiesResult value
+/// Converts Query Parameters representation (style=form, explode=false) to a AudioTrackTelemetry value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for CapabilitiesResult {
+impl std::str::FromStr for AudioTrackTelemetry {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -2572,14 +2535,12 @@ impl std::str::FromStr for CapabilitiesResult {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub protocol_version: Vec<String>,
-            pub platform: Vec<String>,
-            pub phase: Vec<String>,
-            pub capture: Vec<models::CapabilitiesResultCapture>,
-            pub recording: Vec<models::CapabilitiesResultRecording>,
-            pub export: Vec<models::CapabilitiesResultExport>,
-            pub project: Vec<models::CapabilitiesResultProject>,
-            pub agent: Vec<models::CapabilitiesAgent>,
+            pub kind: Vec<String>,
+            pub captured_seconds: Vec<f64>,
+            pub peak_dbfs: Vec<f64>,
+            pub rms_dbfs: Vec<f64>,
+            pub clipped_samples: Vec<i32>,
+            pub read_errors: Vec<i32>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -2593,7 +2554,7 @@ impl std::str::FromStr for CapabilitiesResult {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing CapabilitiesResult".to_string(),
+                        "Missing value while parsing AudioTrackTelemetry".to_string(),
                     );
                 }
             };
@@ -2602,45 +2563,32 @@ impl std::str::FromStr for CapabilitiesResult {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "protocolVersion" => intermediate_rep.protocol_version.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "platform" => intermediate_rep.platform.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "phase" => intermediate_rep.phase.push(
+                    "kind" => intermediate_rep.kind.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "capture" => intermediate_rep.capture.push(
-                        <models::CapabilitiesResultCapture as std::str::FromStr>::from_str(val)
-                            .map_err(|x| x.to_string())?,
+                    "capturedSeconds" => intermediate_rep.captured_seconds.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "recording" => intermediate_rep.recording.push(
-                        <models::CapabilitiesResultRecording as std::str::FromStr>::from_str(val)
-                            .map_err(|x| x.to_string())?,
+                    "peakDbfs" => intermediate_rep.peak_dbfs.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "export" => intermediate_rep.export.push(
-                        <models::CapabilitiesResultExport as std::str::FromStr>::from_str(val)
-                            .map_err(|x| x.to_string())?,
+                    "rmsDbfs" => intermediate_rep.rms_dbfs.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "project" => intermediate_rep.project.push(
-                        <models::CapabilitiesResultProject as std::str::FromStr>::from_str(val)
-                            .map_err(|x| x.to_string())?,
+                    "clippedSamples" => intermediate_rep.clipped_samples.push(
+                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "agent" => intermediate_rep.agent.push(
-                        <models::CapabilitiesAgent as std::str::FromStr>::from_str(val)
-                            .map_err(|x| x.to_string())?,
+                    "readErrors" => intermediate_rep.read_errors.push(
+                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing CapabilitiesResult".to_string(),
+                            "Unexpected key while parsing AudioTrackTelemetry".to_string(),
                         );
                     }
                 }
@@ -2651,79 +2599,73 @@ impl std::str::FromStr for CapabilitiesResult {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(CapabilitiesResult {
-            protocol_version: intermediate_rep
-                .protocol_version
-                .into_iter()
-                .next()
-                .ok_or_else(|| "protocolVersion missing in CapabilitiesResult".to_string())?,
-            platform: intermediate_rep
-                .platform
+        std::result::Result::Ok(AudioTrackTelemetry {
+            kind: intermediate_rep
+                .kind
                 .into_iter()
                 .next()
-                .ok_or_else(|| "platform missing in CapabilitiesResult".to_string())?,
-            phase: intermediate_rep
-                .phase
+                .ok_or_else(|| "kind missing in AudioTrackTelemetry".to_string())?,
+            captured_seconds: intermediate_rep
+                .captured_seconds
                 .into_iter()
                 .next()
-                .ok_or_else(|| "phase missing in CapabilitiesResult".to_string())?,
-            capture: intermediate_rep
-                .capture
+                .ok_or_else(|| "capturedSeconds missing in AudioTrackTelemetry".to_string())?,
+            peak_dbfs: intermediate_rep
+                .peak_dbfs
                 .into_iter()
                 .next()
-                .ok_or_else(|| "capture missing in CapabilitiesResult".to_string())?,
-            recording: intermediate_rep
-                .recording
+                .ok_or_else(|| "peakDbfs missing in AudioTrackTelemetry".to_string())?,
+            rms_dbfs: intermediate_rep
+                .rms_dbfs
                 .into_iter()
                 .next()
-                .ok_or_else(|| "recording missing in CapabilitiesResult".to_string())?,
-            export: intermediate_rep
-                .export
+                .ok_or_else(|| "rmsDbfs missing in AudioTrackTelemetry".to_string())?,
+            clipped_samples: intermediate_rep
+                .clipped_samples
                 .into_iter()
                 .next()
-                .ok_or_else(|| "export missing in CapabilitiesResult".to_string())?,
-            project: intermediate_rep
-                .project
+                .ok_or_else(|| "clippedSamples missing in AudioTrackTelemetry".to_string())?,
+            read_errors: intermediate_rep
+                .read_errors
                 .into_iter()
                 .next()
-                .ok_or_else(|| "project missing in CapabilitiesResult".to_string())?,
-            agent: intermediate_rep.agent.into_iter().next(),
+                .ok_or_else(|| "readErrors missing in AudioTrackTelemetry".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<CapabilitiesResult> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<AudioTrackTelemetry> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<CapabilitiesResult>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<AudioTrackTelemetry>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<CapabilitiesResult>,
+        hdr_value: header::IntoHeaderValue<AudioTrackTelemetry>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                r#"Invalid header value for CapabilitiesResult - value: {hdr_value} is invalid {e}"#
+                r#"Invalid header value for AudioTrackTelemetry - value: {hdr_value} is invalid {e}"#
             )),
         }
     }
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<CapabilitiesResult> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<AudioTrackTelemetry> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <CapabilitiesResult as std::str::FromStr>::from_str(value) {
+                match <AudioTrackTelemetry as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        r#"Unable to convert header value '{value}' into CapabilitiesResult - {err}"#
+                        r#"Unable to convert header value '{value}' into AudioTrackTelemetry - {err}"#
                     )),
                 }
             }
@@ -2734,53 +2676,47 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<Capabilities
     }
 }
 
+/// User-configurable automatic zoom settings stored with a project or export override.
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct CapabilitiesResultCapture {
-    #[serde(rename = "display")]
-    pub display: bool,
-
-    #[serde(rename = "window")]
-    pub window: bool,
+pub struct AutoZoomSettings {
+    #[serde(rename = "isEnabled")]
+    pub is_enabled: bool,
 
-    #[serde(rename = "systemAudio")]
-    pub system_audio: bool,
+    #[serde(rename = "intensity")]
+    pub intensity: f64,
 
-    #[serde(rename = "microphone")]
-    pub microphone: bool,
+    #[serde(rename = "minimumKeyframeInterval")]
+    pub minimum_keyframe_interval: f64,
 }
 
-impl CapabilitiesResultCapture {
+impl AutoZoomSettings {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
     pub fn new(
-        display: bool,
-        window: bool,
-        system_audio: bool,
-        microphone: bool,
-    ) -> CapabilitiesResultCapture {
-        CapabilitiesResultCapture {
-            display,
-            window,
-            system_audio,
-            microphone,
+        is_enabled: bool,
+        intensity: f64,
+        minimum_keyframe_interval: f64,
+    ) -> AutoZoomSettings {
+        AutoZoomSettings {
+            is_enabled,
+            intensity,
+            minimum_keyframe_interval,
         }
     }
 }
 
-/// Converts the CapabilitiesResultCapture value to the Query Parameters representation (style=form, explode=false)
+/// Converts the AutoZoomSettings value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for CapabilitiesResultCapture {
+impl std::fmt::Display for AutoZoomSettings {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
-            Some("display".to_string()),
-            Some(self.display.to_string()),
-            Some("window".to_string()),
-            Some(self.window.to_string()),
-            Some("systemAudio".to_string()),
-            Some(self.system_audio.to_string()),
-            Some("microphone".to_string()),
-            Some(self.microphone.to_string()),
+            Some("isEnabled".to_string()),
+            Some(self.is_enabled.to_string()),
+            Some("intensity".to_string()),
+            Some(self.intensity.to_string()),
+            Some("minimumKeyframeInterval".to_string()),
+            Some(self.minimum_keyframe_interval.to_string()),
         ];
 
         write!(
@@ -2791,10 +2727,10 @@ impl std::fmt::Display for CapabilitiesResultCapture {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a CapabilitiesResultCapture value
+/// Converts Query Parameters representation (style=form, explode=false) to a AutoZoomSettings value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for CapabilitiesResultCapture {
+impl std::str::FromStr for AutoZoomSettings {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -2802,10 +2738,9 @@ impl std::str::FromStr for CapabilitiesResultCapture {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub display: Vec<bool>,
-            pub window: Vec<bool>,
-            pub system_audio: Vec<bool>,
-            pub microphone: Vec<bool>,
+            pub is_enabled: Vec<bool>,
+            pub intensity: Vec<f64>,
+            pub minimum_keyframe_interval: Vec<f64>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -2819,7 +2754,7 @@ impl std::str::FromStr for CapabilitiesResultCapture {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing CapabilitiesResultCapture".to_string(),
+                        "Missing value while parsing AutoZoomSettings".to_string(),
                     );
                 }
             };
@@ -2828,24 +2763,20 @@ impl std::str::FromStr for CapabilitiesResultCapture {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "display" => intermediate_rep.display.push(
-                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "window" => intermediate_rep.window.push(
+                    "isEnabled" => intermediate_rep.is_enabled.push(
                         <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "systemAudio" => intermediate_rep.system_audio.push(
-                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "intensity" => intermediate_rep.intensity.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "microphone" => intermediate_rep.microphone.push(
-                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "minimumKeyframeInterval" => intermediate_rep.minimum_keyframe_interval.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing CapabilitiesResultCapture".to_string(),
+                            "Unexpected key while parsing AutoZoomSettings".to_string(),
                         );
                     }
                 }
@@ -2856,63 +2787,58 @@ impl std::str::FromStr for CapabilitiesResultCapture {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(CapabilitiesResultCapture {
-            display: intermediate_rep
-                .display
-                .into_iter()
-                .next()
-                .ok_or_else(|| "display missing in CapabilitiesResultCapture".to_string())?,
-            window: intermediate_rep
-                .window
+        std::result::Result::Ok(AutoZoomSettings {
+            is_enabled: intermediate_rep
+                .is_enabled
                 .into_iter()
                 .next()
-                .ok_or_else(|| "window missing in CapabilitiesResultCapture".to_string())?,
-            system_audio: intermediate_rep
-                .system_audio
+                .ok_or_else(|| "isEnabled missing in AutoZoomSettings".to_string())?,
+            intensity: intermediate_rep
+                .intensity
                 .into_iter()
                 .next()
-                .ok_or_else(|| "systemAudio missing in CapabilitiesResultCapture".to_string())?,
-            microphone: intermediate_rep
-                .microphone
+                .ok_or_else(|| "intensity missing in AutoZoomSettings".to_string())?,
+            minimum_keyframe_interval: intermediate_rep
+                .minimum_keyframe_interval
                 .into_iter()
                 .next()
-                .ok_or_else(|| "microphone missing in CapabilitiesResultCapture".to_string())?,
+                .ok_or_else(|| "minimumKeyframeInterval missing in AutoZoomSettings".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<CapabilitiesResultCapture> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<AutoZoomSettings> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<CapabilitiesResultCapture>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<AutoZoomSettings>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<CapabilitiesResultCapture>,
+        hdr_value: header::IntoHeaderValue<AutoZoomSettings>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                r#"Invalid header value for CapabilitiesResultCapture - value: {hdr_value} is invalid {e}"#
+                r#"Invalid header value for AutoZoomSettings - value: {hdr_value} is invalid {e}"#
             )),
         }
     }
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<CapabilitiesResultCapture> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<AutoZoomSettings> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <CapabilitiesResultCapture as std::str::FromStr>::from_str(value) {
+                match <AutoZoomSettings as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        r#"Unable to convert header value '{value}' into CapabilitiesResultCapture - {err}"#
+                        r#"Unable to convert header value '{value}' into AutoZoomSettings - {err}"#
                     )),
                 }
             }
@@ -2923,50 +2849,70 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<Capabilities
     }
 }
 
+/// Versioned project-global background stage and source-card framing settings.
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct CapabilitiesResultExport {
-    #[serde(rename = "presets")]
-    pub presets: bool,
+pub struct BackgroundFramingSettings {
+    /// Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "version")]
+    pub version: f64,
 
-    #[serde(rename = "cutPlan")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub cut_plan: Option<bool>,
+    #[serde(rename = "enabled")]
+    pub enabled: bool,
 
-    #[serde(rename = "backgroundFraming")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub background_framing: Option<bool>,
+    #[serde(rename = "backgroundColor")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub background_color: String,
+
+    #[serde(rename = "paddingFraction")]
+    pub padding_fraction: f64,
+
+    #[serde(rename = "cornerRadiusFraction")]
+    pub corner_radius_fraction: f64,
+
+    #[serde(rename = "shadowStrength")]
+    pub shadow_strength: f64,
 }
 
-impl CapabilitiesResultExport {
+impl BackgroundFramingSettings {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new(presets: bool) -> CapabilitiesResultExport {
-        CapabilitiesResultExport {
-            presets,
-            cut_plan: None,
-            background_framing: None,
+    pub fn new(
+        version: f64,
+        enabled: bool,
+        background_color: String,
+        padding_fraction: f64,
+        corner_radius_fraction: f64,
+        shadow_strength: f64,
+    ) -> BackgroundFramingSettings {
+        BackgroundFramingSettings {
+            version,
+            enabled,
+            background_color,
+            padding_fraction,
+            corner_radius_fraction,
+            shadow_strength,
         }
     }
 }
 
-/// Converts the CapabilitiesResultExport value to the Query Parameters representation (style=form, explode=false)
+/// Converts the BackgroundFramingSettings value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for CapabilitiesResultExport {
+impl std::fmt::Display for BackgroundFramingSettings {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
-            Some("presets".to_string()),
-            Some(self.presets.to_string()),
-            self.cut_plan
-                .as_ref()
-                .map(|cut_plan| ["cutPlan".to_string(), cut_plan.to_string()].join(",")),
-            self.background_framing.as_ref().map(|background_framing| {
-                [
-                    "backgroundFraming".to_string(),
-                    background_framing.to_string(),
-                ]
-                .join(",")
-            }),
+            Some("version".to_string()),
+            Some(self.version.to_string()),
+            Some("enabled".to_string()),
+            Some(self.enabled.to_string()),
+            Some("backgroundColor".to_string()),
+            Some(self.background_color.to_string()),
+            Some("paddingFraction".to_string()),
+            Some(self.padding_fraction.to_string()),
+            Some("cornerRadiusFraction".to_string()),
+            Some(self.corner_radius_fraction.to_string()),
+            Some("shadowStrength".to_string()),
+            Some(self.shadow_strength.to_string()),
         ];
 
         write!(
@@ -2977,10 +2923,10 @@ impl std::fmt::Display for CapabilitiesResultExport {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a CapabilitiesResultExport value
+/// Converts Query Parameters representation (style=form, explode=false) to a BackgroundFramingSettings value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for CapabilitiesResultExport {
+impl std::str::FromStr for BackgroundFramingSettings {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -2988,9 +2934,12 @@ impl std::str::FromStr for CapabilitiesResultExport {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub presets: Vec<bool>,
-            pub cut_plan: Vec<bool>,
-            pub background_framing: Vec<bool>,
+            pub version: Vec<f64>,
+            pub enabled: Vec<bool>,
+            pub background_color: Vec<String>,
+            pub padding_fraction: Vec<f64>,
+            pub corner_radius_fraction: Vec<f64>,
+            pub shadow_strength: Vec<f64>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -3004,7 +2953,7 @@ impl std::str::FromStr for CapabilitiesResultExport {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing CapabilitiesResultExport".to_string(),
+                        "Missing value while parsing BackgroundFramingSettings".to_string(),
                     );
                 }
             };
@@ -3013,20 +2962,32 @@ impl std::str::FromStr for CapabilitiesResultExport {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "presets" => intermediate_rep.presets.push(
-                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "version" => intermediate_rep.version.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "cutPlan" => intermediate_rep.cut_plan.push(
+                    "enabled" => intermediate_rep.enabled.push(
                         <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "backgroundFraming" => intermediate_rep.background_framing.push(
-                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "backgroundColor" => intermediate_rep.background_color.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "paddingFraction" => intermediate_rep.padding_fraction.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "cornerRadiusFraction" => intermediate_rep.corner_radius_fraction.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "shadowStrength" => intermediate_rep.shadow_strength.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing CapabilitiesResultExport".to_string(),
+                            "Unexpected key while parsing BackgroundFramingSettings".to_string(),
                         );
                     }
                 }
@@ -3037,50 +2998,79 @@ impl std::str::FromStr for CapabilitiesResultExport {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(CapabilitiesResultExport {
-            presets: intermediate_rep
-                .presets
+        std::result::Result::Ok(BackgroundFramingSettings {
+            version: intermediate_rep
+                .version
                 .into_iter()
                 .next()
-                .ok_or_else(|| "presets missing in CapabilitiesResultExport".to_string())?,
-            cut_plan: intermediate_rep.cut_plan.into_iter().next(),
-            background_framing: intermediate_rep.background_framing.into_iter().next(),
+                .ok_or_else(|| "version missing in BackgroundFramingSettings".to_string())?,
+            enabled: intermediate_rep
+                .enabled
+                .into_iter()
+                .next()
+                .ok_or_else(|| "enabled missing in BackgroundFramingSettings".to_string())?,
+            background_color: intermediate_rep
+                .background_color
+                .into_iter()
+                .next()
+                .ok_or_else(|| {
+                    "backgroundColor missing in BackgroundFramingSettings".to_string()
+                })?,
+            padding_fraction: intermediate_rep
+                .padding_fraction
+                .into_iter()
+                .next()
+                .ok_or_else(|| {
+                    "paddingFraction missing in BackgroundFramingSettings".to_string()
+                })?,
+            corner_radius_fraction: intermediate_rep
+                .corner_radius_fraction
+                .into_iter()
+                .next()
+                .ok_or_else(|| {
+                    "cornerRadiusFraction missing in BackgroundFramingSettings".to_string()
+                })?,
+            shadow_strength: intermediate_rep
+                .shadow_strength
+                .into_iter()
+                .next()
+                .ok_or_else(|| "shadowStrength missing in BackgroundFramingSettings".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<CapabilitiesResultExport> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<BackgroundFramingSettings> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<CapabilitiesResultExport>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<BackgroundFramingSettings>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<CapabilitiesResultExport>,
+        hdr_value: header::IntoHeaderValue<BackgroundFramingSettings>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                r#"Invalid header value for CapabilitiesResultExport - value: {hdr_value} is invalid {e}"#
+                r#"Invalid header value for BackgroundFramingSettings - value: {hdr_value} is invalid {e}"#
             )),
         }
     }
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<CapabilitiesResultExport> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<BackgroundFramingSettings> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <CapabilitiesResultExport as std::str::FromStr>::from_str(value) {
+                match <BackgroundFramingSettings as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        r#"Unable to convert header value '{value}' into CapabilitiesResultExport - {err}"#
+                        r#"Unable to convert header value '{value}' into BackgroundFramingSettings - {err}"#
                     )),
                 }
             }
@@ -3093,26 +3083,77 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<Capabilities
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct CapabilitiesResultProject {
-    #[serde(rename = "openSave")]
-    pub open_save: bool,
-}
+pub struct CameraKeyframe {
+    #[serde(rename = "id")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub id: String,
 
-impl CapabilitiesResultProject {
-    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new(open_save: bool) -> CapabilitiesResultProject {
-        CapabilitiesResultProject { open_save }
-    }
+    #[serde(rename = "time")]
+    pub time: f64,
+
+    #[serde(rename = "center")]
+    #[validate(nested)]
+    pub center: models::CameraKeyframeCenter,
+
+    #[serde(rename = "zoom")]
+    pub zoom: f64,
+
+    /// Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "easing")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub easing: String,
+
+    /// Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "origin")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub origin: String,
+
+    #[serde(rename = "locked")]
+    pub locked: bool,
 }
 
-/// Converts the CapabilitiesResultProject value to the Query Parameters representation (style=form, explode=false)
+impl CameraKeyframe {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(
+        id: String,
+        time: f64,
+        center: models::CameraKeyframeCenter,
+        zoom: f64,
+        easing: String,
+        origin: String,
+        locked: bool,
+    ) -> CameraKeyframe {
+        CameraKeyframe {
+            id,
+            time,
+            center,
+            zoom,
+            easing,
+            origin,
+            locked,
+        }
+    }
+}
+
+/// Converts the CameraKeyframe value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for CapabilitiesResultProject {
+impl std::fmt::Display for CameraKeyframe {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
-            Some("openSave".to_string()),
-            Some(self.open_save.to_string()),
+            Some("id".to_string()),
+            Some(self.id.to_string()),
+            Some("time".to_string()),
+            Some(self.time.to_string()),
+            // Skipping center in query parameter serialization
+            Some("zoom".to_string()),
+            Some(self.zoom.to_string()),
+            Some("easing".to_string()),
+            Some(self.easing.to_string()),
+            Some("origin".to_string()),
+            Some(self.origin.to_string()),
+            Some("locked".to_string()),
+            Some(self.locked.to_string()),
         ];
 
         write!(
@@ -3123,10 +3164,10 @@ impl std::fmt::Display for CapabilitiesResultProject {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a CapabilitiesResultProject value
+/// Converts Query Parameters representation (style=form, explode=false) to a CameraKeyframe value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for CapabilitiesResultProject {
+impl std::str::FromStr for CameraKeyframe {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -3134,7 +3175,13 @@ impl std::str::FromStr for CapabilitiesResultProject {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub open_save: Vec<bool>,
+            pub id: Vec<String>,
+            pub time: Vec<f64>,
+            pub center: Vec<models::CameraKeyframeCenter>,
+            pub zoom: Vec<f64>,
+            pub easing: Vec<String>,
+            pub origin: Vec<String>,
+            pub locked: Vec<bool>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -3148,7 +3195,7 @@ impl std::str::FromStr for CapabilitiesResultProject {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing CapabilitiesResultProject".to_string(),
+                        "Missing value while parsing CameraKeyframe".to_string(),
                     );
                 }
             };
@@ -3157,12 +3204,37 @@ impl std::str::FromStr for CapabilitiesResultProject {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "openSave" => intermediate_rep.open_save.push(
+                    "id" => intermediate_rep.id.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "time" => intermediate_rep.time.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "center" => intermediate_rep.center.push(
+                        <models::CameraKeyframeCenter as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "zoom" => intermediate_rep.zoom.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "easing" => intermediate_rep.easing.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "origin" => intermediate_rep.origin.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "locked" => intermediate_rep.locked.push(
                         <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing CapabilitiesResultProject".to_string(),
+                            "Unexpected key while parsing CameraKeyframe".to_string(),
                         );
                     }
                 }
@@ -3173,48 +3245,78 @@ impl std::str::FromStr for CapabilitiesResultProject {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(CapabilitiesResultProject {
-            open_save: intermediate_rep
-                .open_save
+        std::result::Result::Ok(CameraKeyframe {
+            id: intermediate_rep
+                .id
                 .into_iter()
                 .next()
-                .ok_or_else(|| "openSave missing in CapabilitiesResultProject".to_string())?,
+                .ok_or_else(|| "id missing in CameraKeyframe".to_string())?,
+            time: intermediate_rep
+                .time
+                .into_iter()
+                .next()
+                .ok_or_else(|| "time missing in CameraKeyframe".to_string())?,
+            center: intermediate_rep
+                .center
+                .into_iter()
+                .next()
+                .ok_or_else(|| "center missing in CameraKeyframe".to_string())?,
+            zoom: intermediate_rep
+                .zoom
+                .into_iter()
+                .next()
+                .ok_or_else(|| "zoom missing in CameraKeyframe".to_string())?,
+            easing: intermediate_rep
+                .easing
+                .into_iter()
+                .next()
+                .ok_or_else(|| "easing missing in CameraKeyframe".to_string())?,
+            origin: intermediate_rep
+                .origin
+                .into_iter()
+                .next()
+                .ok_or_else(|| "origin missing in CameraKeyframe".to_string())?,
+            locked: intermediate_rep
+                .locked
+                .into_iter()
+                .next()
+                .ok_or_else(|| "locked missing in CameraKeyframe".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<CapabilitiesResultProject> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<CameraKeyframe> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<CapabilitiesResultProject>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<CameraKeyframe>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<CapabilitiesResultProject>,
+        hdr_value: header::IntoHeaderValue<CameraKeyframe>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                r#"Invalid header value for CapabilitiesResultProject - value: {hdr_value} is invalid {e}"#
+                r#"Invalid header value for CameraKeyframe - value: {hdr_value} is invalid {e}"#
             )),
         }
     }
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<CapabilitiesResultProject> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<CameraKeyframe> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <CapabilitiesResultProject as std::str::FromStr>::from_str(value) {
+                match <CameraKeyframe as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        r#"Unable to convert header value '{value}' into CapabilitiesResultProject - {err}"#
+                        r#"Unable to convert header value '{value}' into CameraKeyframe - {err}"#
                     )),
                 }
             }
@@ -3227,26 +3329,31 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<Capabilities
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct CapabilitiesResultRecording {
-    #[serde(rename = "inputTracking")]
-    pub input_tracking: bool,
+pub struct CameraKeyframeCenter {
+    #[serde(rename = "x")]
+    pub x: f64,
+
+    #[serde(rename = "y")]
+    pub y: f64,
 }
 
-impl CapabilitiesResultRecording {
+impl CameraKeyframeCenter {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new(input_tracking: bool) -> CapabilitiesResultRecording {
-        CapabilitiesResultRecording { input_tracking }
+    pub fn new(x: f64, y: f64) -> CameraKeyframeCenter {
+        CameraKeyframeCenter { x, y }
     }
 }
 
-/// Converts the CapabilitiesResultRecording value to the Query Parameters representation (style=form, explode=false)
+/// Converts the CameraKeyframeCenter value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for CapabilitiesResultRecording {
+impl std::fmt::Display for CameraKeyframeCenter {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
-            Some("inputTracking".to_string()),
-            Some(self.input_tracking.to_string()),
+            Some("x".to_string()),
+            Some(self.x.to_string()),
+            Some("y".to_string()),
+            Some(self.y.to_string()),
         ];
 
         write!(
@@ -3257,10 +3364,10 @@ impl std::fmt::Display for CapabilitiesResultRecording {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a CapabilitiesResultRecording value
+/// Converts Query Parameters representation (style=form, explode=false) to a CameraKeyframeCenter value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for CapabilitiesResultRecording {
+impl std::str::FromStr for CameraKeyframeCenter {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -3268,7 +3375,8 @@ impl std::str::FromStr for CapabilitiesResultRecording {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub input_tracking: Vec<bool>,
+            pub x: Vec<f64>,
+            pub y: Vec<f64>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -3282,7 +3390,7 @@ impl std::str::FromStr for CapabilitiesResultRecording {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing CapabilitiesResultRecording".to_string(),
+                        "Missing value while parsing CameraKeyframeCenter".to_string(),
                     );
                 }
             };
@@ -3291,12 +3399,16 @@ impl std::str::FromStr for CapabilitiesResultRecording {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "inputTracking" => intermediate_rep.input_tracking.push(
-                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "x" => intermediate_rep.x.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "y" => intermediate_rep.y.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing CapabilitiesResultRecording".to_string(),
+                            "Unexpected key while parsing CameraKeyframeCenter".to_string(),
                         );
                     }
                 }
@@ -3307,50 +3419,53 @@ impl std::str::FromStr for CapabilitiesResultRecording {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(CapabilitiesResultRecording {
-            input_tracking: intermediate_rep
-                .input_tracking
+        std::result::Result::Ok(CameraKeyframeCenter {
+            x: intermediate_rep
+                .x
                 .into_iter()
                 .next()
-                .ok_or_else(|| {
-                    "inputTracking missing in CapabilitiesResultRecording".to_string()
-                })?,
+                .ok_or_else(|| "x missing in CameraKeyframeCenter".to_string())?,
+            y: intermediate_rep
+                .y
+                .into_iter()
+                .next()
+                .ok_or_else(|| "y missing in CameraKeyframeCenter".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<CapabilitiesResultRecording> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<CameraKeyframeCenter> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<CapabilitiesResultRecording>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<CameraKeyframeCenter>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<CapabilitiesResultRecording>,
+        hdr_value: header::IntoHeaderValue<CameraKeyframeCenter>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                r#"Invalid header value for CapabilitiesResultRecording - value: {hdr_value} is invalid {e}"#
+                r#"Invalid header value for CameraKeyframeCenter - value: {hdr_value} is invalid {e}"#
             )),
         }
     }
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<CapabilitiesResultRecording> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<CameraKeyframeCenter> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <CapabilitiesResultRecording as std::str::FromStr>::from_str(value) {
+                match <CameraKeyframeCenter as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        r#"Unable to convert header value '{value}' into CapabilitiesResultRecording - {err}"#
+                        r#"Unable to convert header value '{value}' into CameraKeyframeCenter - {err}"#
                     )),
                 }
             }
@@ -3363,35 +3478,63 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<Capabilities
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct CapturePreviewFrame {
-    #[serde(rename = "frameId")]
-    pub frame_id: i32,
+pub struct CameraKeyframeEdit {
+    /// Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "action")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub action: String,
 
-    #[serde(rename = "bytesBase64")]
+    #[serde(rename = "time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<f64>,
+
+    #[serde(rename = "center")]
+    #[validate(nested)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub center: Option<models::CameraKeyframeCenter>,
+
+    #[serde(rename = "zoom")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub zoom: Option<f64>,
+
+    /// Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "easing")]
     #[validate(custom(function = "check_xss_string"))]
-    pub bytes_base64: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub easing: Option<String>,
 }
 
-impl CapturePreviewFrame {
+impl CameraKeyframeEdit {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new(frame_id: i32, bytes_base64: String) -> CapturePreviewFrame {
-        CapturePreviewFrame {
-            frame_id,
-            bytes_base64,
+    pub fn new(action: String) -> CameraKeyframeEdit {
+        CameraKeyframeEdit {
+            action,
+            time: None,
+            center: None,
+            zoom: None,
+            easing: None,
         }
     }
 }
 
-/// Converts the CapturePreviewFrame value to the Query Parameters representation (style=form, explode=false)
+/// Converts the CameraKeyframeEdit value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for CapturePreviewFrame {
+impl std::fmt::Display for CameraKeyframeEdit {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
-            Some("frameId".to_string()),
-            Some(self.frame_id.to_string()),
-            Some("bytesBase64".to_string()),
-            Some(self.bytes_base64.to_string()),
+            Some("action".to_string()),
+            Some(self.action.to_string()),
+            self.time
+                .as_ref()
+                .map(|time| ["time".to_string(), time.to_string()].join(",")),
+            // Skipping center in query parameter serialization
+            self.zoom
+                .as_ref()
+                .map(|zoom| ["zoom".to_string(), zoom.to_string()].join(",")),
+            self.easing
+                .as_ref()
+                .map(|easing| ["easing".to_string(), easing.to_string()].join(",")),
         ];
 
         write!(
@@ -3402,10 +3545,10 @@ impl std::fmt::Display for CapturePreviewFrame {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a CapturePreviewFrame value
+/// Converts Query Parameters representation (style=form, explode=false) to a CameraKeyframeEdit value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for CapturePreviewFrame {
+impl std::str::FromStr for CameraKeyframeEdit {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -3413,8 +3556,11 @@ impl std::str::FromStr for CapturePreviewFrame {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub frame_id: Vec<i32>,
-            pub bytes_base64: Vec<String>,
+            pub action: Vec<String>,
+            pub time: Vec<f64>,
+            pub center: Vec<models::CameraKeyframeCenter>,
+            pub zoom: Vec<f64>,
+            pub easing: Vec<String>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -3428,7 +3574,7 @@ impl std::str::FromStr for CapturePreviewFrame {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing CapturePreviewFrame".to_string(),
+                        "Missing value while parsing CameraKeyframeEdit".to_string(),
                     );
                 }
             };
@@ -3437,16 +3583,29 @@ impl std::str::FromStr for CapturePreviewFrame {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "frameId" => intermediate_rep.frame_id.push(
-                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "action" => intermediate_rep.action.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "bytesBase64" => intermediate_rep.bytes_base64.push(
+                    "time" => intermediate_rep.time.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "center" => intermediate_rep.center.push(
+                        <models::CameraKeyframeCenter as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "zoom" => intermediate_rep.zoom.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "easing" => intermediate_rep.easing.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing CapturePreviewFrame".to_string(),
+                            "Unexpected key while parsing CameraKeyframeEdit".to_string(),
                         );
                     }
                 }
@@ -3457,53 +3616,52 @@ impl std::str::FromStr for CapturePreviewFrame {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(CapturePreviewFrame {
-            frame_id: intermediate_rep
-                .frame_id
-                .into_iter()
-                .next()
-                .ok_or_else(|| "frameId missing in CapturePreviewFrame".to_string())?,
-            bytes_base64: intermediate_rep
-                .bytes_base64
+        std::result::Result::Ok(CameraKeyframeEdit {
+            action: intermediate_rep
+                .action
                 .into_iter()
                 .next()
-                .ok_or_else(|| "bytesBase64 missing in CapturePreviewFrame".to_string())?,
+                .ok_or_else(|| "action missing in CameraKeyframeEdit".to_string())?,
+            time: intermediate_rep.time.into_iter().next(),
+            center: intermediate_rep.center.into_iter().next(),
+            zoom: intermediate_rep.zoom.into_iter().next(),
+            easing: intermediate_rep.easing.into_iter().next(),
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<CapturePreviewFrame> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<CameraKeyframeEdit> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<CapturePreviewFrame>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<CameraKeyframeEdit>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<CapturePreviewFrame>,
+        hdr_value: header::IntoHeaderValue<CameraKeyframeEdit>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                r#"Invalid header value for CapturePreviewFrame - value: {hdr_value} is invalid {e}"#
+                r#"Invalid header value for CameraKeyframeEdit - value: {hdr_value} is invalid {e}"#
             )),
         }
     }
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<CapturePreviewFrame> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<CameraKeyframeEdit> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <CapturePreviewFrame as std::str::FromStr>::from_str(value) {
+                match <CameraKeyframeEdit as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        r#"Unable to convert header value '{value}' into CapturePreviewFrame - {err}"#
+                        r#"Unable to convert header value '{value}' into CameraKeyframeEdit - {err}"#
                     )),
                 }
             }
@@ -3516,28 +3674,44 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<CapturePrevi
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct CapturePreviewFrameResult {
-    #[serde(rename = "frame")]
+pub struct CameraKeyframesEditPayload {
+    #[serde(rename = "startSeconds")]
+    pub start_seconds: f64,
+
+    #[serde(rename = "endSeconds")]
+    pub end_seconds: f64,
+
+    #[serde(rename = "edits")]
     #[validate(nested)]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub frame: Option<models::CapturePreviewFrame>,
+    pub edits: Vec<models::CameraKeyframeEdit>,
 }
 
-impl CapturePreviewFrameResult {
+impl CameraKeyframesEditPayload {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new() -> CapturePreviewFrameResult {
-        CapturePreviewFrameResult { frame: None }
+    pub fn new(
+        start_seconds: f64,
+        end_seconds: f64,
+        edits: Vec<models::CameraKeyframeEdit>,
+    ) -> CameraKeyframesEditPayload {
+        CameraKeyframesEditPayload {
+            start_seconds,
+            end_seconds,
+            edits,
+        }
     }
 }
 
-/// Converts the CapturePreviewFrameResult value to the Query Parameters representation (style=form, explode=false)
+/// Converts the CameraKeyframesEditPayload value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for CapturePreviewFrameResult {
+impl std::fmt::Display for CameraKeyframesEditPayload {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
-            // Skipping frame in query parameter serialization
-
+            Some("startSeconds".to_string()),
+            Some(self.start_seconds.to_string()),
+            Some("endSeconds".to_string()),
+            Some(self.end_seconds.to_string()),
+            // Skipping edits in query parameter serialization
         ];
 
         write!(
@@ -3548,10 +3722,10 @@ impl std::fmt::Display for CapturePreviewFrameResult {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a CapturePreviewFrameResult value
+/// Converts Query Parameters representation (style=form, explode=false) to a CameraKeyframesEditPayload value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for CapturePreviewFrameResult {
+impl std::str::FromStr for CameraKeyframesEditPayload {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -3559,7 +3733,9 @@ impl std::str::FromStr for CapturePreviewFrameResult {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub frame: Vec<models::CapturePreviewFrame>,
+            pub start_seconds: Vec<f64>,
+            pub end_seconds: Vec<f64>,
+            pub edits: Vec<Vec<models::CameraKeyframeEdit>>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -3573,7 +3749,7 @@ impl std::str::FromStr for CapturePreviewFrameResult {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing CapturePreviewFrameResult".to_string(),
+                        "Missing value while parsing CameraKeyframesEditPayload".to_string(),
                     );
                 }
             };
@@ -3582,15 +3758,11 @@ impl std::str::FromStr for CapturePreviewFrameResult {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "frame" => intermediate_rep.frame.push(
-                        <models::CapturePreviewFrame as std::str::FromStr>::from_str(val)
-                            .map_err(|x| x.to_string())?,
-                    ),
-                    _ => {
-                        return std::result::Result::Err(
-                            "Unexpected key while parsing CapturePreviewFrameResult".to_string(),
-                        );
-                    }
+                    "startSeconds" => intermediate_rep.start_seconds.push(<f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "endSeconds" => intermediate_rep.end_seconds.push(<f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    "edits" => return std::result::Result::Err("Parsing a container in this style is not supported in CameraKeyframesEditPayload".to_string()),
+                    _ => return std::result::Result::Err("Unexpected key while parsing CameraKeyframesEditPayload".to_string())
                 }
             }
 
@@ -3599,44 +3771,58 @@ impl std::str::FromStr for CapturePreviewFrameResult {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(CapturePreviewFrameResult {
-            frame: intermediate_rep.frame.into_iter().next(),
+        std::result::Result::Ok(CameraKeyframesEditPayload {
+            start_seconds: intermediate_rep
+                .start_seconds
+                .into_iter()
+                .next()
+                .ok_or_else(|| "startSeconds missing in CameraKeyframesEditPayload".to_string())?,
+            end_seconds: intermediate_rep
+                .end_seconds
+                .into_iter()
+                .next()
+                .ok_or_else(|| "endSeconds missing in CameraKeyframesEditPayload".to_string())?,
+            edits: intermediate_rep
+                .edits
+                .into_iter()
+                .next()
+                .ok_or_else(|| "edits missing in CameraKeyframesEditPayload".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<CapturePreviewFrameResult> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<CameraKeyframesEditPayload> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<CapturePreviewFrameResult>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<CameraKeyframesEditPayload>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<CapturePreviewFrameResult>,
+        hdr_value: header::IntoHeaderValue<CameraKeyframesEditPayload>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                r#"Invalid header value for CapturePreviewFrameResult - value: {hdr_value} is invalid {e}"#
+                r#"Invalid header value for CameraKeyframesEditPayload - value: {hdr_value} is invalid {e}"#
             )),
         }
     }
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<CapturePreviewFrameResult> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<CameraKeyframesEditPayload> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <CapturePreviewFrameResult as std::str::FromStr>::from_str(value) {
+                match <CameraKeyframesEditPayload as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        r#"Unable to convert header value '{value}' into CapturePreviewFrameResult - {err}"#
+                        r#"Unable to convert header value '{value}' into CameraKeyframesEditPayload - {err}"#
                     )),
                 }
             }
@@ -3649,47 +3835,52 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<CapturePrevi
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct CaptureStartCurrentWindowPayload {
-    #[serde(rename = "enableMic")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub enable_mic: Option<bool>,
+pub struct CameraKeyframesResult {
+    #[serde(rename = "startSeconds")]
+    pub start_seconds: f64,
 
-    #[serde(rename = "enablePreview")]
+    #[serde(rename = "endSeconds")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub enable_preview: Option<bool>,
+    pub end_seconds: Option<f64>,
 
-    /// Note: inline enums are not fully supported by openapi-generator
-    #[serde(rename = "captureFps")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub capture_fps: Option<f64>,
+    #[serde(rename = "keyframes")]
+    #[validate(nested)]
+    pub keyframes: Vec<models::CameraKeyframe>,
+
+    #[serde(rename = "keyframeCount")]
+    pub keyframe_count: i32,
 }
 
-impl CaptureStartCurrentWindowPayload {
+impl CameraKeyframesResult {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new() -> CaptureStartCurrentWindowPayload {
-        CaptureStartCurrentWindowPayload {
-            enable_mic: None,
-            enable_preview: None,
-            capture_fps: None,
+    pub fn new(
+        start_seconds: f64,
+        keyframes: Vec<models::CameraKeyframe>,
+        keyframe_count: i32,
+    ) -> CameraKeyframesResult {
+        CameraKeyframesResult {
+            start_seconds,
+            end_seconds: None,
+            keyframes,
+            keyframe_count,
         }
     }
 }
 
-/// Converts the CaptureStartCurrentWindowPayload value to the Query Parameters representation (style=form, explode=false)
+/// Converts the CameraKeyframesResult value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for CaptureStartCurrentWindowPayload {
+impl std::fmt::Display for CameraKeyframesResult {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
-            self.enable_mic
-                .as_ref()
-                .map(|enable_mic| ["enableMic".to_string(), enable_mic.to_string()].join(",")),
-            self.enable_preview.as_ref().map(|enable_preview| {
-                ["enablePreview".to_string(), enable_preview.to_string()].join(",")
-            }),
-            self.capture_fps
+            Some("startSeconds".to_string()),
+            Some(self.start_seconds.to_string()),
+            self.end_seconds
                 .as_ref()
-                .map(|capture_fps| ["captureFps".to_string(), capture_fps.to_string()].join(",")),
+                .map(|end_seconds| ["endSeconds".to_string(), end_seconds.to_string()].join(",")),
+            // Skipping keyframes in query parameter serialization
+            Some("keyframeCount".to_string()),
+            Some(self.keyframe_count.to_string()),
         ];
 
         write!(
@@ -3700,10 +3891,10 @@ impl std::fmt::Display for CaptureStartCurrentWindowPayload {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a CaptureStartCurrentWindowPayload value
+/// Converts Query Parameters representation (style=form, explode=false) to a CameraKeyframesResult value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for CaptureStartCurrentWindowPayload {
+impl std::str::FromStr for CameraKeyframesResult {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -3711,9 +3902,10 @@ impl std::str::FromStr for CaptureStartCurrentWindowPayload {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub enable_mic: Vec<bool>,
-            pub enable_preview: Vec<bool>,
-            pub capture_fps: Vec<f64>,
+            pub start_seconds: Vec<f64>,
+            pub end_seconds: Vec<f64>,
+            pub keyframes: Vec<Vec<models::CameraKeyframe>>,
+            pub keyframe_count: Vec<i32>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -3727,7 +3919,7 @@ impl std::str::FromStr for CaptureStartCurrentWindowPayload {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing CaptureStartCurrentWindowPayload".to_string(),
+                        "Missing value while parsing CameraKeyframesResult".to_string(),
                     );
                 }
             };
@@ -3736,23 +3928,13 @@ impl std::str::FromStr for CaptureStartCurrentWindowPayload {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "enableMic" => intermediate_rep.enable_mic.push(
-                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
+                    "startSeconds" => intermediate_rep.start_seconds.push(<f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
                     #[allow(clippy::redundant_clone)]
-                    "enablePreview" => intermediate_rep.enable_preview.push(
-                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
+                    "endSeconds" => intermediate_rep.end_seconds.push(<f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    "keyframes" => return std::result::Result::Err("Parsing a container in this style is not supported in CameraKeyframesResult".to_string()),
                     #[allow(clippy::redundant_clone)]
-                    "captureFps" => intermediate_rep.capture_fps.push(
-                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    _ => {
-                        return std::result::Result::Err(
-                            "Unexpected key while parsing CaptureStartCurrentWindowPayload"
-                                .to_string(),
-                        );
-                    }
+                    "keyframeCount" => intermediate_rep.keyframe_count.push(<i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing CameraKeyframesResult".to_string())
                 }
             }
 
@@ -3761,50 +3943,59 @@ impl std::str::FromStr for CaptureStartCurrentWindowPayload {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(CaptureStartCurrentWindowPayload {
-            enable_mic: intermediate_rep.enable_mic.into_iter().next(),
-            enable_preview: intermediate_rep.enable_preview.into_iter().next(),
-            capture_fps: intermediate_rep.capture_fps.into_iter().next(),
+        std::result::Result::Ok(CameraKeyframesResult {
+            start_seconds: intermediate_rep
+                .start_seconds
+                .into_iter()
+                .next()
+                .ok_or_else(|| "startSeconds missing in CameraKeyframesResult".to_string())?,
+            end_seconds: intermediate_rep.end_seconds.into_iter().next(),
+            keyframes: intermediate_rep
+                .keyframes
+                .into_iter()
+                .next()
+                .ok_or_else(|| "keyframes missing in CameraKeyframesResult".to_string())?,
+            keyframe_count: intermediate_rep
+                .keyframe_count
+                .into_iter()
+                .next()
+                .ok_or_else(|| "keyframeCount missing in CameraKeyframesResult".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<CaptureStartCurrentWindowPayload> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<CameraKeyframesResult> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<CaptureStartCurrentWindowPayload>>
-    for HeaderValue
-{
+impl std::convert::TryFrom<header::IntoHeaderValue<CameraKeyframesResult>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<CaptureStartCurrentWindowPayload>,
+        hdr_value: header::IntoHeaderValue<CameraKeyframesResult>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                r#"Invalid header value for CaptureStartCurrentWindowPayload - value: {hdr_value} is invalid {e}"#
+                r#"Invalid header value for CameraKeyframesResult - value: {hdr_value} is invalid {e}"#
             )),
         }
     }
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue>
-    for header::IntoHeaderValue<CaptureStartCurrentWindowPayload>
-{
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<CameraKeyframesResult> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <CaptureStartCurrentWindowPayload as std::str::FromStr>::from_str(value) {
+                match <CameraKeyframesResult as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        r#"Unable to convert header value '{value}' into CaptureStartCurrentWindowPayload - {err}"#
+                        r#"Unable to convert header value '{value}' into CameraKeyframesResult - {err}"#
                     )),
                 }
             }
@@ -3817,55 +4008,41 @@ impl std::convert::TryFrom<HeaderValue>
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct CaptureStartDisplayPayload {
-    #[serde(rename = "displayId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub display_id: Option<i32>,
-
-    #[serde(rename = "enableMic")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub enable_mic: Option<bool>,
+pub struct CameraTrack {
+    #[serde(rename = "recordingURL")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub recording_url: String,
 
-    #[serde(rename = "enablePreview")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub enable_preview: Option<bool>,
+    #[serde(rename = "width")]
+    pub width: i32,
 
-    /// Note: inline enums are not fully supported by openapi-generator
-    #[serde(rename = "captureFps")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub capture_fps: Option<f64>,
+    #[serde(rename = "height")]
+    pub height: i32,
 }
 
-impl CaptureStartDisplayPayload {
+impl CameraTrack {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new() -> CaptureStartDisplayPayload {
-        CaptureStartDisplayPayload {
-            display_id: None,
-            enable_mic: None,
-            enable_preview: None,
-            capture_fps: None,
+    pub fn new(recording_url: String, width: i32, height: i32) -> CameraTrack {
+        CameraTrack {
+            recording_url,
+            width,
+            height,
         }
     }
 }
 
-/// Converts the CaptureStartDisplayPayload value to the Query Parameters representation (style=form, explode=false)
+/// Converts the CameraTrack value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for CaptureStartDisplayPayload {
+impl std::fmt::Display for CameraTrack {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
-            self.display_id
-                .as_ref()
-                .map(|display_id| ["displayId".to_string(), display_id.to_string()].join(",")),
-            self.enable_mic
-                .as_ref()
-                .map(|enable_mic| ["enableMic".to_string(), enable_mic.to_string()].join(",")),
-            self.enable_preview.as_ref().map(|enable_preview| {
-                ["enablePreview".to_string(), enable_preview.to_string()].join(",")
-            }),
-            self.capture_fps
-                .as_ref()
-                .map(|capture_fps| ["captureFps".to_string(), capture_fps.to_string()].join(",")),
+            Some("recordingURL".to_string()),
+            Some(self.recording_url.to_string()),
+            Some("width".to_string()),
+            Some(self.width.to_string()),
+            Some("height".to_string()),
+            Some(self.height.to_string()),
         ];
 
         write!(
@@ -3876,10 +4053,10 @@ impl std::fmt::Display for CaptureStartDisplayPayload {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a CaptureStartDisplayPayload value
+/// Converts Query Parameters representation (style=form, explode=false) to a CameraTrack value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for CaptureStartDisplayPayload {
+impl std::str::FromStr for CameraTrack {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -3887,10 +4064,9 @@ impl std::str::FromStr for CaptureStartDisplayPayload {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub display_id: Vec<i32>,
-            pub enable_mic: Vec<bool>,
-            pub enable_preview: Vec<bool>,
-            pub capture_fps: Vec<f64>,
+            pub recording_url: Vec<String>,
+            pub width: Vec<i32>,
+            pub height: Vec<i32>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -3904,7 +4080,7 @@ impl std::str::FromStr for CaptureStartDisplayPayload {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing CaptureStartDisplayPayload".to_string(),
+                        "Missing value while parsing CameraTrack".to_string(),
                     );
                 }
             };
@@ -3913,24 +4089,20 @@ impl std::str::FromStr for CaptureStartDisplayPayload {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "displayId" => intermediate_rep.display_id.push(
-                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "enableMic" => intermediate_rep.enable_mic.push(
-                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "recordingURL" => intermediate_rep.recording_url.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "enablePreview" => intermediate_rep.enable_preview.push(
-                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "width" => intermediate_rep.width.push(
+                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "captureFps" => intermediate_rep.capture_fps.push(
-                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "height" => intermediate_rep.height.push(
+                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing CaptureStartDisplayPayload".to_string(),
+                            "Unexpected key while parsing CameraTrack".to_string(),
                         );
                     }
                 }
@@ -3941,47 +4113,58 @@ impl std::str::FromStr for CaptureStartDisplayPayload {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(CaptureStartDisplayPayload {
-            display_id: intermediate_rep.display_id.into_iter().next(),
-            enable_mic: intermediate_rep.enable_mic.into_iter().next(),
-            enable_preview: intermediate_rep.enable_preview.into_iter().next(),
-            capture_fps: intermediate_rep.capture_fps.into_iter().next(),
+        std::result::Result::Ok(CameraTrack {
+            recording_url: intermediate_rep
+                .recording_url
+                .into_iter()
+                .next()
+                .ok_or_else(|| "recordingURL missing in CameraTrack".to_string())?,
+            width: intermediate_rep
+                .width
+                .into_iter()
+                .next()
+                .ok_or_else(|| "width missing in CameraTrack".to_string())?,
+            height: intermediate_rep
+                .height
+                .into_iter()
+                .next()
+                .ok_or_else(|| "height missing in CameraTrack".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<CaptureStartDisplayPayload> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<CameraTrack> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<CaptureStartDisplayPayload>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<CameraTrack>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<CaptureStartDisplayPayload>,
+        hdr_value: header::IntoHeaderValue<CameraTrack>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                r#"Invalid header value for CaptureStartDisplayPayload - value: {hdr_value} is invalid {e}"#
+                r#"Invalid header value for CameraTrack - value: {hdr_value} is invalid {e}"#
             )),
         }
     }
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<CaptureStartDisplayPayload> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<CameraTrack> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <CaptureStartDisplayPayload as std::str::FromStr>::from_str(value) {
+                match <CameraTrack as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        r#"Unable to convert header value '{value}' into CaptureStartDisplayPayload - {err}"#
+                        r#"Unable to convert header value '{value}' into CameraTrack - {err}"#
                     )),
                 }
             }
@@ -3994,53 +4177,68 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<CaptureStart
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct CaptureStartWindowPayload {
-    #[serde(rename = "windowId")]
-    pub window_id: i32,
+pub struct CapabilitiesAgent {
+    #[serde(rename = "preflight")]
+    pub preflight: bool,
 
-    #[serde(rename = "enableMic")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub enable_mic: Option<bool>,
+    #[serde(rename = "run")]
+    pub run: bool,
 
-    #[serde(rename = "enablePreview")]
+    #[serde(rename = "status")]
+    pub status: bool,
+
+    #[serde(rename = "apply")]
+    pub apply: bool,
+
+    #[serde(rename = "localOnly")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub enable_preview: Option<bool>,
+    pub local_only: Option<bool>,
 
-    /// Note: inline enums are not fully supported by openapi-generator
-    #[serde(rename = "captureFps")]
+    #[serde(rename = "runtimeBudgetMinutes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub capture_fps: Option<f64>,
+    pub runtime_budget_minutes: Option<i32>,
 }
 
-impl CaptureStartWindowPayload {
+impl CapabilitiesAgent {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new(window_id: i32) -> CaptureStartWindowPayload {
-        CaptureStartWindowPayload {
-            window_id,
-            enable_mic: None,
-            enable_preview: None,
-            capture_fps: None,
+    pub fn new(preflight: bool, run: bool, status: bool, apply: bool) -> CapabilitiesAgent {
+        CapabilitiesAgent {
+            preflight,
+            run,
+            status,
+            apply,
+            local_only: None,
+            runtime_budget_minutes: None,
         }
     }
 }
 
-/// Converts the CaptureStartWindowPayload value to the Query Parameters representation (style=form, explode=false)
+/// Converts the CapabilitiesAgent value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for CaptureStartWindowPayload {
+impl std::fmt::Display for CapabilitiesAgent {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
-            Some("windowId".to_string()),
-            Some(self.window_id.to_string()),
-            self.enable_mic
+            Some("preflight".to_string()),
+            Some(self.preflight.to_string()),
+            Some("run".to_string()),
+            Some(self.run.to_string()),
+            Some("status".to_string()),
+            Some(self.status.to_string()),
+            Some("apply".to_string()),
+            Some(self.apply.to_string()),
+            self.local_only
                 .as_ref()
-                .map(|enable_mic| ["enableMic".to_string(), enable_mic.to_string()].join(",")),
-            self.enable_preview.as_ref().map(|enable_preview| {
-                ["enablePreview".to_string(), enable_preview.to_string()].join(",")
-            }),
-            self.capture_fps
+                .map(|local_only| ["localOnly".to_string(), local_only.to_string()].join(",")),
+            self.runtime_budget_minutes
                 .as_ref()
-                .map(|capture_fps| ["captureFps".to_string(), capture_fps.to_string()].join(",")),
+                .map(|runtime_budget_minutes| {
+                    [
+                        "runtimeBudgetMinutes".to_string(),
+                        runtime_budget_minutes.to_string(),
+                    ]
+                    .join(",")
+                }),
         ];
 
         write!(
@@ -4051,10 +4249,10 @@ impl std::fmt::Display for CaptureStartWindowPayload {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a CaptureStartWindowPayload value
+/// Converts Query Parameters representation (style=form, explode=false) to a CapabilitiesAgent value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for CaptureStartWindowPayload {
+impl std::str::FromStr for CapabilitiesAgent {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -4062,10 +4260,12 @@ impl std::str::FromStr for CaptureStartWindowPayload {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub window_id: Vec<i32>,
-            pub enable_mic: Vec<bool>,
-            pub enable_preview: Vec<bool>,
-            pub capture_fps: Vec<f64>,
+            pub preflight: Vec<bool>,
+            pub run: Vec<bool>,
+            pub status: Vec<bool>,
+            pub apply: Vec<bool>,
+            pub local_only: Vec<bool>,
+            pub runtime_budget_minutes: Vec<i32>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -4079,7 +4279,7 @@ impl std::str::FromStr for CaptureStartWindowPayload {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing CaptureStartWindowPayload".to_string(),
+                        "Missing value while parsing CapabilitiesAgent".to_string(),
                     );
                 }
             };
@@ -4088,24 +4288,32 @@ impl std::str::FromStr for CaptureStartWindowPayload {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "windowId" => intermediate_rep.window_id.push(
-                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "preflight" => intermediate_rep.preflight.push(
+                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "enableMic" => intermediate_rep.enable_mic.push(
+                    "run" => intermediate_rep.run.push(
                         <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "enablePreview" => intermediate_rep.enable_preview.push(
+                    "status" => intermediate_rep.status.push(
                         <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "captureFps" => intermediate_rep.capture_fps.push(
-                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "apply" => intermediate_rep.apply.push(
+                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "localOnly" => intermediate_rep.local_only.push(
+                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "runtimeBudgetMinutes" => intermediate_rep.runtime_budget_minutes.push(
+                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing CaptureStartWindowPayload".to_string(),
+                            "Unexpected key while parsing CapabilitiesAgent".to_string(),
                         );
                     }
                 }
@@ -4116,51 +4324,65 @@ impl std::str::FromStr for CaptureStartWindowPayload {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(CaptureStartWindowPayload {
-            window_id: intermediate_rep
-                .window_id
+        std::result::Result::Ok(CapabilitiesAgent {
+            preflight: intermediate_rep
+                .preflight
                 .into_iter()
                 .next()
-                .ok_or_else(|| "windowId missing in CaptureStartWindowPayload".to_string())?,
-            enable_mic: intermediate_rep.enable_mic.into_iter().next(),
-            enable_preview: intermediate_rep.enable_preview.into_iter().next(),
-            capture_fps: intermediate_rep.capture_fps.into_iter().next(),
+                .ok_or_else(|| "preflight missing in CapabilitiesAgent".to_string())?,
+            run: intermediate_rep
+                .run
+                .into_iter()
+                .next()
+                .ok_or_else(|| "run missing in CapabilitiesAgent".to_string())?,
+            status: intermediate_rep
+                .status
+                .into_iter()
+                .next()
+                .ok_or_else(|| "status missing in CapabilitiesAgent".to_string())?,
+            apply: intermediate_rep
+                .apply
+                .into_iter()
+                .next()
+                .ok_or_else(|| "apply missing in CapabilitiesAgent".to_string())?,
+            local_only: intermediate_rep.local_only.into_iter().next(),
+            runtime_budget_minutes: intermediate_rep.runtime_budget_minutes.into_iter().next(),
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<CaptureStartWindowPayload> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<CapabilitiesAgent> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<CaptureStartWindowPayload>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<CapabilitiesAgent>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<CaptureStartWindowPayload>,
+        hdr_value: header::IntoHeaderValue<CapabilitiesAgent>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                r#"Invalid header value for CaptureStartWindowPayload - value: {hdr_value} is invalid {e}"#
+                r#"Invalid header value for CapabilitiesAgent - value: {hdr_value} is invalid {e}"#
             )),
         }
     }
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<CaptureStartWindowPayload> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<CapabilitiesAgent> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <CaptureStartWindowPayload as std::str::FromStr>::from_str(value) {
+                match <CapabilitiesAgent as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        r#"Unable to convert header value '{value}' into CaptureStartWindowPayload - {err}"#
+                        r#"Unable to convert header value '{value}' into CapabilitiesAgent - {err}"#
                     )),
                 }
             }
@@ -4173,105 +4395,9925 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<CaptureStart
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct CaptureStatusResult {
-    #[serde(rename = "isRunning")]
-    pub is_running: bool,
+pub struct CapabilitiesResult {
+    #[serde(rename = "protocolVersion")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub protocol_version: String,
 
-    #[serde(rename = "isRecording")]
-    pub is_recording: bool,
+    #[serde(rename = "platform")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub platform: String,
 
-    #[serde(rename = "captureSessionId")]
+    /// Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "phase")]
     #[validate(custom(function = "check_xss_string"))]
+    pub phase: String,
+
+    #[serde(rename = "capture")]
+    #[validate(nested)]
+    pub capture: models::CapabilitiesResultCapture,
+
+    #[serde(rename = "recording")]
+    #[validate(nested)]
+    pub recording: models::CapabilitiesResultRecording,
+
+    #[serde(rename = "export")]
+    #[validate(nested)]
+    pub export: models::CapabilitiesResultExport,
+
+    #[serde(rename = "project")]
+    #[validate(nested)]
+    pub project: models::CapabilitiesResultProject,
+
+    #[serde(rename = "agent")]
+    #[validate(nested)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub capture_session_id: Option<String>,
+    pub agent: Option<models::CapabilitiesAgent>,
+}
 
-    #[serde(rename = "recordingDurationSeconds")]
-    pub recording_duration_seconds: f64,
+impl CapabilitiesResult {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(
+        protocol_version: String,
+        platform: String,
+        phase: String,
+        capture: models::CapabilitiesResultCapture,
+        recording: models::CapabilitiesResultRecording,
+        export: models::CapabilitiesResultExport,
+        project: models::CapabilitiesResultProject,
+    ) -> CapabilitiesResult {
+        CapabilitiesResult {
+            protocol_version,
+            platform,
+            phase,
+            capture,
+            recording,
+            export,
+            project,
+            agent: None,
+        }
+    }
+}
 
-    #[serde(rename = "recordingURL")]
+/// Converts the CapabilitiesResult value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for CapabilitiesResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("protocolVersion".to_string()),
+            Some(self.protocol_version.to_string()),
+            Some("platform".to_string()),
+            Some(self.platform.to_string()),
+            Some("phase".to_string()),
+            Some(self.phase.to_string()),
+            // Skipping capture in query parameter serialization
+
+            // Skipping recording in query parameter serialization
+
+            // Skipping export in query parameter serialization
+
+            // Skipping project in query parameter serialization
+
+            // Skipping agent in query parameter serialization
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a CapabilitiesResult value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for CapabilitiesResult {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub protocol_version: Vec<String>,
+            pub platform: Vec<String>,
+            pub phase: Vec<String>,
+            pub capture: Vec<models::CapabilitiesResultCapture>,
+            pub recording: Vec<models::CapabilitiesResultRecording>,
+            pub export: Vec<models::CapabilitiesResultExport>,
+            pub project: Vec<models::CapabilitiesResultProject>,
+            pub agent: Vec<models::CapabilitiesAgent>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing CapabilitiesResult".to_string(),
+                    );
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "protocolVersion" => intermediate_rep.protocol_version.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "platform" => intermediate_rep.platform.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "phase" => intermediate_rep.phase.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "capture" => intermediate_rep.capture.push(
+                        <models::CapabilitiesResultCapture as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "recording" => intermediate_rep.recording.push(
+                        <models::CapabilitiesResultRecording as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "export" => intermediate_rep.export.push(
+                        <models::CapabilitiesResultExport as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "project" => intermediate_rep.project.push(
+                        <models::CapabilitiesResultProject as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "agent" => intermediate_rep.agent.push(
+                        <models::CapabilitiesAgent as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing CapabilitiesResult".to_string(),
+                        );
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(CapabilitiesResult {
+            protocol_version: intermediate_rep
+                .protocol_version
+                .into_iter()
+                .next()
+                .ok_or_else(|| "protocolVersion missing in CapabilitiesResult".to_string())?,
+            platform: intermediate_rep
+                .platform
+                .into_iter()
+                .next()
+                .ok_or_else(|| "platform missing in CapabilitiesResult".to_string())?,
+            phase: intermediate_rep
+                .phase
+                .into_iter()
+                .next()
+                .ok_or_else(|| "phase missing in CapabilitiesResult".to_string())?,
+            capture: intermediate_rep
+                .capture
+                .into_iter()
+                .next()
+                .ok_or_else(|| "capture missing in CapabilitiesResult".to_string())?,
+            recording: intermediate_rep
+                .recording
+                .into_iter()
+                .next()
+                .ok_or_else(|| "recording missing in CapabilitiesResult".to_string())?,
+            export: intermediate_rep
+                .export
+                .into_iter()
+                .next()
+                .ok_or_else(|| "export missing in CapabilitiesResult".to_string())?,
+            project: intermediate_rep
+                .project
+                .into_iter()
+                .next()
+                .ok_or_else(|| "project missing in CapabilitiesResult".to_string())?,
+            agent: intermediate_rep.agent.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<CapabilitiesResult> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<CapabilitiesResult>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<CapabilitiesResult>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Invalid header value for CapabilitiesResult - value: {hdr_value} is invalid {e}"#
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<CapabilitiesResult> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <CapabilitiesResult as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        r#"Unable to convert header value '{value}' into CapabilitiesResult - {err}"#
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Unable to convert header: {hdr_value:?} to string: {e}"#
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct CapabilitiesResultCapture {
+    #[serde(rename = "display")]
+    pub display: bool,
+
+    #[serde(rename = "window")]
+    pub window: bool,
+
+    #[serde(rename = "systemAudio")]
+    pub system_audio: bool,
+
+    #[serde(rename = "microphone")]
+    pub microphone: bool,
+}
+
+impl CapabilitiesResultCapture {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(
+        display: bool,
+        window: bool,
+        system_audio: bool,
+        microphone: bool,
+    ) -> CapabilitiesResultCapture {
+        CapabilitiesResultCapture {
+            display,
+            window,
+            system_audio,
+            microphone,
+        }
+    }
+}
+
+/// Converts the CapabilitiesResultCapture value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for CapabilitiesResultCapture {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("display".to_string()),
+            Some(self.display.to_string()),
+            Some("window".to_string()),
+            Some(self.window.to_string()),
+            Some("systemAudio".to_string()),
+            Some(self.system_audio.to_string()),
+            Some("microphone".to_string()),
+            Some(self.microphone.to_string()),
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a CapabilitiesResultCapture value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for CapabilitiesResultCapture {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub display: Vec<bool>,
+            pub window: Vec<bool>,
+            pub system_audio: Vec<bool>,
+            pub microphone: Vec<bool>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing CapabilitiesResultCapture".to_string(),
+                    );
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "display" => intermediate_rep.display.push(
+                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "window" => intermediate_rep.window.push(
+                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "systemAudio" => intermediate_rep.system_audio.push(
+                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "microphone" => intermediate_rep.microphone.push(
+                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing CapabilitiesResultCapture".to_string(),
+                        );
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(CapabilitiesResultCapture {
+            display: intermediate_rep
+                .display
+                .into_iter()
+                .next()
+                .ok_or_else(|| "display missing in CapabilitiesResultCapture".to_string())?,
+            window: intermediate_rep
+                .window
+                .into_iter()
+                .next()
+                .ok_or_else(|| "window missing in CapabilitiesResultCapture".to_string())?,
+            system_audio: intermediate_rep
+                .system_audio
+                .into_iter()
+                .next()
+                .ok_or_else(|| "systemAudio missing in CapabilitiesResultCapture".to_string())?,
+            microphone: intermediate_rep
+                .microphone
+                .into_iter()
+                .next()
+                .ok_or_else(|| "microphone missing in CapabilitiesResultCapture".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<CapabilitiesResultCapture> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<CapabilitiesResultCapture>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<CapabilitiesResultCapture>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Invalid header value for CapabilitiesResultCapture - value: {hdr_value} is invalid {e}"#
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<CapabilitiesResultCapture> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <CapabilitiesResultCapture as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        r#"Unable to convert header value '{value}' into CapabilitiesResultCapture - {err}"#
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Unable to convert header: {hdr_value:?} to string: {e}"#
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct CapabilitiesResultExport {
+    #[serde(rename = "presets")]
+    pub presets: bool,
+
+    #[serde(rename = "cutPlan")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cut_plan: Option<bool>,
+
+    #[serde(rename = "backgroundFraming")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub background_framing: Option<bool>,
+}
+
+impl CapabilitiesResultExport {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(presets: bool) -> CapabilitiesResultExport {
+        CapabilitiesResultExport {
+            presets,
+            cut_plan: None,
+            background_framing: None,
+        }
+    }
+}
+
+/// Converts the CapabilitiesResultExport value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for CapabilitiesResultExport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("presets".to_string()),
+            Some(self.presets.to_string()),
+            self.cut_plan
+                .as_ref()
+                .map(|cut_plan| ["cutPlan".to_string(), cut_plan.to_string()].join(",")),
+            self.background_framing.as_ref().map(|background_framing| {
+                [
+                    "backgroundFraming".to_string(),
+                    background_framing.to_string(),
+                ]
+                .join(",")
+            }),
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a CapabilitiesResultExport value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for CapabilitiesResultExport {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub presets: Vec<bool>,
+            pub cut_plan: Vec<bool>,
+            pub background_framing: Vec<bool>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing CapabilitiesResultExport".to_string(),
+                    );
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "presets" => intermediate_rep.presets.push(
+                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "cutPlan" => intermediate_rep.cut_plan.push(
+                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "backgroundFraming" => intermediate_rep.background_framing.push(
+                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing CapabilitiesResultExport".to_string(),
+                        );
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(CapabilitiesResultExport {
+            presets: intermediate_rep
+                .presets
+                .into_iter()
+                .next()
+                .ok_or_else(|| "presets missing in CapabilitiesResultExport".to_string())?,
+            cut_plan: intermediate_rep.cut_plan.into_iter().next(),
+            background_framing: intermediate_rep.background_framing.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<CapabilitiesResultExport> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<CapabilitiesResultExport>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<CapabilitiesResultExport>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Invalid header value for CapabilitiesResultExport - value: {hdr_value} is invalid {e}"#
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<CapabilitiesResultExport> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <CapabilitiesResultExport as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        r#"Unable to convert header value '{value}' into CapabilitiesResultExport - {err}"#
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Unable to convert header: {hdr_value:?} to string: {e}"#
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct CapabilitiesResultProject {
+    #[serde(rename = "openSave")]
+    pub open_save: bool,
+}
+
+impl CapabilitiesResultProject {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(open_save: bool) -> CapabilitiesResultProject {
+        CapabilitiesResultProject { open_save }
+    }
+}
+
+/// Converts the CapabilitiesResultProject value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for CapabilitiesResultProject {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("openSave".to_string()),
+            Some(self.open_save.to_string()),
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a CapabilitiesResultProject value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for CapabilitiesResultProject {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub open_save: Vec<bool>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing CapabilitiesResultProject".to_string(),
+                    );
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "openSave" => intermediate_rep.open_save.push(
+                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing CapabilitiesResultProject".to_string(),
+                        );
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(CapabilitiesResultProject {
+            open_save: intermediate_rep
+                .open_save
+                .into_iter()
+                .next()
+                .ok_or_else(|| "openSave missing in CapabilitiesResultProject".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<CapabilitiesResultProject> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<CapabilitiesResultProject>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<CapabilitiesResultProject>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Invalid header value for CapabilitiesResultProject - value: {hdr_value} is invalid {e}"#
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<CapabilitiesResultProject> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <CapabilitiesResultProject as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        r#"Unable to convert header value '{value}' into CapabilitiesResultProject - {err}"#
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Unable to convert header: {hdr_value:?} to string: {e}"#
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct CapabilitiesResultRecording {
+    #[serde(rename = "inputTracking")]
+    pub input_tracking: bool,
+}
+
+impl CapabilitiesResultRecording {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(input_tracking: bool) -> CapabilitiesResultRecording {
+        CapabilitiesResultRecording { input_tracking }
+    }
+}
+
+/// Converts the CapabilitiesResultRecording value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for CapabilitiesResultRecording {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("inputTracking".to_string()),
+            Some(self.input_tracking.to_string()),
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a CapabilitiesResultRecording value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for CapabilitiesResultRecording {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub input_tracking: Vec<bool>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing CapabilitiesResultRecording".to_string(),
+                    );
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "inputTracking" => intermediate_rep.input_tracking.push(
+                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing CapabilitiesResultRecording".to_string(),
+                        );
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(CapabilitiesResultRecording {
+            input_tracking: intermediate_rep
+                .input_tracking
+                .into_iter()
+                .next()
+                .ok_or_else(|| {
+                    "inputTracking missing in CapabilitiesResultRecording".to_string()
+                })?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<CapabilitiesResultRecording> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<CapabilitiesResultRecording>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<CapabilitiesResultRecording>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Invalid header value for CapabilitiesResultRecording - value: {hdr_value} is invalid {e}"#
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<CapabilitiesResultRecording> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <CapabilitiesResultRecording as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        r#"Unable to convert header value '{value}' into CapabilitiesResultRecording - {err}"#
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Unable to convert header: {hdr_value:?} to string: {e}"#
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct CapturePreviewFrame {
+    #[serde(rename = "frameId")]
+    pub frame_id: i32,
+
+    #[serde(rename = "bytesBase64")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub bytes_base64: String,
+}
+
+impl CapturePreviewFrame {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(frame_id: i32, bytes_base64: String) -> CapturePreviewFrame {
+        CapturePreviewFrame {
+            frame_id,
+            bytes_base64,
+        }
+    }
+}
+
+/// Converts the CapturePreviewFrame value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for CapturePreviewFrame {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("frameId".to_string()),
+            Some(self.frame_id.to_string()),
+            Some("bytesBase64".to_string()),
+            Some(self.bytes_base64.to_string()),
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a CapturePreviewFrame value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for CapturePreviewFrame {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub frame_id: Vec<i32>,
+            pub bytes_base64: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing CapturePreviewFrame".to_string(),
+                    );
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "frameId" => intermediate_rep.frame_id.push(
+                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "bytesBase64" => intermediate_rep.bytes_base64.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing CapturePreviewFrame".to_string(),
+                        );
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(CapturePreviewFrame {
+            frame_id: intermediate_rep
+                .frame_id
+                .into_iter()
+                .next()
+                .ok_or_else(|| "frameId missing in CapturePreviewFrame".to_string())?,
+            bytes_base64: intermediate_rep
+                .bytes_base64
+                .into_iter()
+                .next()
+                .ok_or_else(|| "bytesBase64 missing in CapturePreviewFrame".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<CapturePreviewFrame> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<CapturePreviewFrame>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<CapturePreviewFrame>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Invalid header value for CapturePreviewFrame - value: {hdr_value} is invalid {e}"#
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<CapturePreviewFrame> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <CapturePreviewFrame as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        r#"Unable to convert header value '{value}' into CapturePreviewFrame - {err}"#
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Unable to convert header: {hdr_value:?} to string: {e}"#
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct CapturePreviewFrameResult {
+    #[serde(rename = "frame")]
+    #[validate(nested)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub frame: Option<models::CapturePreviewFrame>,
+}
+
+impl CapturePreviewFrameResult {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new() -> CapturePreviewFrameResult {
+        CapturePreviewFrameResult { frame: None }
+    }
+}
+
+/// Converts the CapturePreviewFrameResult value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for CapturePreviewFrameResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            // Skipping frame in query parameter serialization
+
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a CapturePreviewFrameResult value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for CapturePreviewFrameResult {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub frame: Vec<models::CapturePreviewFrame>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing CapturePreviewFrameResult".to_string(),
+                    );
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "frame" => intermediate_rep.frame.push(
+                        <models::CapturePreviewFrame as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing CapturePreviewFrameResult".to_string(),
+                        );
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(CapturePreviewFrameResult {
+            frame: intermediate_rep.frame.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<CapturePreviewFrameResult> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<CapturePreviewFrameResult>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<CapturePreviewFrameResult>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Invalid header value for CapturePreviewFrameResult - value: {hdr_value} is invalid {e}"#
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<CapturePreviewFrameResult> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <CapturePreviewFrameResult as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        r#"Unable to convert header value '{value}' into CapturePreviewFrameResult - {err}"#
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Unable to convert header: {hdr_value:?} to string: {e}"#
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct CaptureStartCameraPayload {
+    #[serde(rename = "cameraId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub camera_id: Option<i32>,
+
+    /// Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "captureFps")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capture_fps: Option<f64>,
+}
+
+impl CaptureStartCameraPayload {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new() -> CaptureStartCameraPayload {
+        CaptureStartCameraPayload {
+            camera_id: None,
+            capture_fps: None,
+        }
+    }
+}
+
+/// Converts the CaptureStartCameraPayload value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for CaptureStartCameraPayload {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            self.camera_id
+                .as_ref()
+                .map(|camera_id| ["cameraId".to_string(), camera_id.to_string()].join(",")),
+            self.capture_fps
+                .as_ref()
+                .map(|capture_fps| ["captureFps".to_string(), capture_fps.to_string()].join(",")),
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a CaptureStartCameraPayload value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for CaptureStartCameraPayload {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub camera_id: Vec<i32>,
+            pub capture_fps: Vec<f64>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing CaptureStartCameraPayload".to_string(),
+                    );
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "cameraId" => intermediate_rep.camera_id.push(
+                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "captureFps" => intermediate_rep.capture_fps.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing CaptureStartCameraPayload".to_string(),
+                        );
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(CaptureStartCameraPayload {
+            camera_id: intermediate_rep.camera_id.into_iter().next(),
+            capture_fps: intermediate_rep.capture_fps.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<CaptureStartCameraPayload> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<CaptureStartCameraPayload>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<CaptureStartCameraPayload>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Invalid header value for CaptureStartCameraPayload - value: {hdr_value} is invalid {e}"#
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<CaptureStartCameraPayload> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <CaptureStartCameraPayload as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        r#"Unable to convert header value '{value}' into CaptureStartCameraPayload - {err}"#
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Unable to convert header: {hdr_value:?} to string: {e}"#
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct CaptureStartCurrentWindowPayload {
+    #[serde(rename = "enableMic")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_mic: Option<bool>,
+
+    #[serde(rename = "enablePreview")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_preview: Option<bool>,
+
+    /// Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "captureFps")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capture_fps: Option<f64>,
+}
+
+impl CaptureStartCurrentWindowPayload {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new() -> CaptureStartCurrentWindowPayload {
+        CaptureStartCurrentWindowPayload {
+            enable_mic: None,
+            enable_preview: None,
+            capture_fps: None,
+        }
+    }
+}
+
+/// Converts the CaptureStartCurrentWindowPayload value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for CaptureStartCurrentWindowPayload {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            self.enable_mic
+                .as_ref()
+                .map(|enable_mic| ["enableMic".to_string(), enable_mic.to_string()].join(",")),
+            self.enable_preview.as_ref().map(|enable_preview| {
+                ["enablePreview".to_string(), enable_preview.to_string()].join(",")
+            }),
+            self.capture_fps
+                .as_ref()
+                .map(|capture_fps| ["captureFps".to_string(), capture_fps.to_string()].join(",")),
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a CaptureStartCurrentWindowPayload value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for CaptureStartCurrentWindowPayload {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub enable_mic: Vec<bool>,
+            pub enable_preview: Vec<bool>,
+            pub capture_fps: Vec<f64>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing CaptureStartCurrentWindowPayload".to_string(),
+                    );
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "enableMic" => intermediate_rep.enable_mic.push(
+                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "enablePreview" => intermediate_rep.enable_preview.push(
+                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "captureFps" => intermediate_rep.capture_fps.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing CaptureStartCurrentWindowPayload"
+                                .to_string(),
+                        );
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(CaptureStartCurrentWindowPayload {
+            enable_mic: intermediate_rep.enable_mic.into_iter().next(),
+            enable_preview: intermediate_rep.enable_preview.into_iter().next(),
+            capture_fps: intermediate_rep.capture_fps.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<CaptureStartCurrentWindowPayload> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<CaptureStartCurrentWindowPayload>>
+    for HeaderValue
+{
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<CaptureStartCurrentWindowPayload>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Invalid header value for CaptureStartCurrentWindowPayload - value: {hdr_value} is invalid {e}"#
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue>
+    for header::IntoHeaderValue<CaptureStartCurrentWindowPayload>
+{
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <CaptureStartCurrentWindowPayload as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        r#"Unable to convert header value '{value}' into CaptureStartCurrentWindowPayload - {err}"#
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Unable to convert header: {hdr_value:?} to string: {e}"#
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct CaptureStartDisplayPayload {
+    #[serde(rename = "displayId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_id: Option<i32>,
+
+    #[serde(rename = "enableMic")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_mic: Option<bool>,
+
+    #[serde(rename = "enablePreview")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_preview: Option<bool>,
+
+    /// Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "captureFps")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capture_fps: Option<f64>,
+}
+
+impl CaptureStartDisplayPayload {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new() -> CaptureStartDisplayPayload {
+        CaptureStartDisplayPayload {
+            display_id: None,
+            enable_mic: None,
+            enable_preview: None,
+            capture_fps: None,
+        }
+    }
+}
+
+/// Converts the CaptureStartDisplayPayload value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for CaptureStartDisplayPayload {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            self.display_id
+                .as_ref()
+                .map(|display_id| ["displayId".to_string(), display_id.to_string()].join(",")),
+            self.enable_mic
+                .as_ref()
+                .map(|enable_mic| ["enableMic".to_string(), enable_mic.to_string()].join(",")),
+            self.enable_preview.as_ref().map(|enable_preview| {
+                ["enablePreview".to_string(), enable_preview.to_string()].join(",")
+            }),
+            self.capture_fps
+                .as_ref()
+                .map(|capture_fps| ["captureFps".to_string(), capture_fps.to_string()].join(",")),
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a CaptureStartDisplayPayload value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for CaptureStartDisplayPayload {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub display_id: Vec<i32>,
+            pub enable_mic: Vec<bool>,
+            pub enable_preview: Vec<bool>,
+            pub capture_fps: Vec<f64>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing CaptureStartDisplayPayload".to_string(),
+                    );
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "displayId" => intermediate_rep.display_id.push(
+                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "enableMic" => intermediate_rep.enable_mic.push(
+                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "enablePreview" => intermediate_rep.enable_preview.push(
+                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "captureFps" => intermediate_rep.capture_fps.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing CaptureStartDisplayPayload".to_string(),
+                        );
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(CaptureStartDisplayPayload {
+            display_id: intermediate_rep.display_id.into_iter().next(),
+            enable_mic: intermediate_rep.enable_mic.into_iter().next(),
+            enable_preview: intermediate_rep.enable_preview.into_iter().next(),
+            capture_fps: intermediate_rep.capture_fps.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<CaptureStartDisplayPayload> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<CaptureStartDisplayPayload>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<CaptureStartDisplayPayload>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Invalid header value for CaptureStartDisplayPayload - value: {hdr_value} is invalid {e}"#
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<CaptureStartDisplayPayload> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <CaptureStartDisplayPayload as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        r#"Unable to convert header value '{value}' into CaptureStartDisplayPayload - {err}"#
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Unable to convert header: {hdr_value:?} to string: {e}"#
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct CaptureStartRegionPayload {
+    #[serde(rename = "displayId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_id: Option<i32>,
+
+    #[serde(rename = "rect")]
+    #[validate(nested)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rect: Option<models::CaptureStatusResultCaptureMetadataContentRect>,
+
+    /// Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "captureFps")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capture_fps: Option<f64>,
+}
+
+impl CaptureStartRegionPayload {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new() -> CaptureStartRegionPayload {
+        CaptureStartRegionPayload {
+            display_id: None,
+            rect: None,
+            capture_fps: None,
+        }
+    }
+}
+
+/// Converts the CaptureStartRegionPayload value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for CaptureStartRegionPayload {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            self.display_id
+                .as_ref()
+                .map(|display_id| ["displayId".to_string(), display_id.to_string()].join(",")),
+            // Skipping rect in query parameter serialization
+            self.capture_fps
+                .as_ref()
+                .map(|capture_fps| ["captureFps".to_string(), capture_fps.to_string()].join(",")),
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a CaptureStartRegionPayload value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for CaptureStartRegionPayload {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub display_id: Vec<i32>,
+            pub rect: Vec<models::CaptureStatusResultCaptureMetadataContentRect>,
+            pub capture_fps: Vec<f64>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing CaptureStartRegionPayload".to_string(),
+                    );
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "displayId" => intermediate_rep.display_id.push(<i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "rect" => intermediate_rep.rect.push(<models::CaptureStatusResultCaptureMetadataContentRect as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "captureFps" => intermediate_rep.capture_fps.push(<f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing CaptureStartRegionPayload".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(CaptureStartRegionPayload {
+            display_id: intermediate_rep.display_id.into_iter().next(),
+            rect: intermediate_rep.rect.into_iter().next(),
+            capture_fps: intermediate_rep.capture_fps.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<CaptureStartRegionPayload> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<CaptureStartRegionPayload>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<CaptureStartRegionPayload>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Invalid header value for CaptureStartRegionPayload - value: {hdr_value} is invalid {e}"#
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<CaptureStartRegionPayload> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <CaptureStartRegionPayload as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        r#"Unable to convert header value '{value}' into CaptureStartRegionPayload - {err}"#
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Unable to convert header: {hdr_value:?} to string: {e}"#
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct CaptureStartWindowPayload {
+    #[serde(rename = "windowId")]
+    pub window_id: i32,
+
+    #[serde(rename = "enableMic")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_mic: Option<bool>,
+
+    #[serde(rename = "enablePreview")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_preview: Option<bool>,
+
+    /// Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "captureFps")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capture_fps: Option<f64>,
+}
+
+impl CaptureStartWindowPayload {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(window_id: i32) -> CaptureStartWindowPayload {
+        CaptureStartWindowPayload {
+            window_id,
+            enable_mic: None,
+            enable_preview: None,
+            capture_fps: None,
+        }
+    }
+}
+
+/// Converts the CaptureStartWindowPayload value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for CaptureStartWindowPayload {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("windowId".to_string()),
+            Some(self.window_id.to_string()),
+            self.enable_mic
+                .as_ref()
+                .map(|enable_mic| ["enableMic".to_string(), enable_mic.to_string()].join(",")),
+            self.enable_preview.as_ref().map(|enable_preview| {
+                ["enablePreview".to_string(), enable_preview.to_string()].join(",")
+            }),
+            self.capture_fps
+                .as_ref()
+                .map(|capture_fps| ["captureFps".to_string(), capture_fps.to_string()].join(",")),
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a CaptureStartWindowPayload value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for CaptureStartWindowPayload {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub window_id: Vec<i32>,
+            pub enable_mic: Vec<bool>,
+            pub enable_preview: Vec<bool>,
+            pub capture_fps: Vec<f64>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing CaptureStartWindowPayload".to_string(),
+                    );
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "windowId" => intermediate_rep.window_id.push(
+                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "enableMic" => intermediate_rep.enable_mic.push(
+                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "enablePreview" => intermediate_rep.enable_preview.push(
+                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "captureFps" => intermediate_rep.capture_fps.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing CaptureStartWindowPayload".to_string(),
+                        );
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(CaptureStartWindowPayload {
+            window_id: intermediate_rep
+                .window_id
+                .into_iter()
+                .next()
+                .ok_or_else(|| "windowId missing in CaptureStartWindowPayload".to_string())?,
+            enable_mic: intermediate_rep.enable_mic.into_iter().next(),
+            enable_preview: intermediate_rep.enable_preview.into_iter().next(),
+            capture_fps: intermediate_rep.capture_fps.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<CaptureStartWindowPayload> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<CaptureStartWindowPayload>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<CaptureStartWindowPayload>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Invalid header value for CaptureStartWindowPayload - value: {hdr_value} is invalid {e}"#
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<CaptureStartWindowPayload> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <CaptureStartWindowPayload as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        r#"Unable to convert header value '{value}' into CaptureStartWindowPayload - {err}"#
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Unable to convert header: {hdr_value:?} to string: {e}"#
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct CaptureStatusResult {
+    #[serde(rename = "isRunning")]
+    pub is_running: bool,
+
+    #[serde(rename = "isRecording")]
+    pub is_recording: bool,
+
+    #[serde(rename = "isPaused")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_paused: Option<bool>,
+
+    #[serde(rename = "isCountingDown")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_counting_down: Option<bool>,
+
+    #[serde(rename = "countdownRemainingSeconds")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub countdown_remaining_seconds: Option<f64>,
+
+    #[serde(rename = "recordingRemainingSeconds")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recording_remaining_seconds: Option<f64>,
+
+    #[serde(rename = "captureSessionId")]
+    #[validate(custom(function = "check_xss_string"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capture_session_id: Option<String>,
+
+    #[serde(rename = "sourcesRevision")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sources_revision: Option<i32>,
+
+    #[serde(rename = "recordingDurationSeconds")]
+    pub recording_duration_seconds: f64,
+
+    #[serde(rename = "recordingURL")]
+    #[validate(custom(function = "check_xss_string"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recording_url: Option<String>,
+
+    #[serde(rename = "cameraId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub camera_id: Option<i32>,
+
+    #[serde(rename = "cameraTrack")]
+    #[validate(nested)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub camera_track: Option<models::CameraTrack>,
+
+    #[serde(rename = "audioTracks")]
+    #[validate(nested)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audio_tracks: Option<Vec<models::AudioTrack>>,
+
+    #[serde(rename = "audioLevels")]
+    #[validate(nested)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audio_levels: Option<Vec<models::AudioLevels>>,
+
+    #[serde(rename = "privacyMask")]
+    #[validate(nested)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub privacy_mask: Option<models::PrivacyMaskStatus>,
+
+    #[serde(rename = "captureMetadata")]
+    #[validate(nested)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capture_metadata: Option<models::CaptureStatusResultCaptureMetadata>,
+
+    #[serde(rename = "recordingMetadata")]
+    #[validate(nested)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recording_metadata: Option<models::RecordingMetadata>,
+
+    #[serde(rename = "lastError")]
+    #[validate(nested)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_error: Option<models::EngineBadRequestError>,
+
+    #[serde(rename = "eventsURL")]
+    #[validate(custom(function = "check_xss_string"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub events_url: Option<String>,
+
+    #[serde(rename = "lastRecordingTelemetry")]
+    #[validate(nested)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_recording_telemetry: Option<models::CaptureTelemetry>,
+
+    #[serde(rename = "telemetry")]
+    #[validate(nested)]
+    pub telemetry: models::CaptureTelemetry,
+}
+
+impl CaptureStatusResult {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(
+        is_running: bool,
+        is_recording: bool,
+        recording_duration_seconds: f64,
+        telemetry: models::CaptureTelemetry,
+    ) -> CaptureStatusResult {
+        CaptureStatusResult {
+            is_running,
+            is_recording,
+            is_paused: None,
+            is_counting_down: None,
+            countdown_remaining_seconds: None,
+            recording_remaining_seconds: None,
+            capture_session_id: None,
+            sources_revision: None,
+            recording_duration_seconds,
+            recording_url: None,
+            camera_id: None,
+            camera_track: None,
+            audio_tracks: None,
+            audio_levels: None,
+            privacy_mask: None,
+            capture_metadata: None,
+            recording_metadata: None,
+            last_error: None,
+            events_url: None,
+            last_recording_telemetry: None,
+            telemetry,
+        }
+    }
+}
+
+/// Converts the CaptureStatusResult value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for CaptureStatusResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("isRunning".to_string()),
+            Some(self.is_running.to_string()),
+            Some("isRecording".to_string()),
+            Some(self.is_recording.to_string()),
+            self.is_paused
+                .as_ref()
+                .map(|is_paused| ["isPaused".to_string(), is_paused.to_string()].join(",")),
+            self.is_counting_down.as_ref().map(|is_counting_down| {
+                ["isCountingDown".to_string(), is_counting_down.to_string()].join(",")
+            }),
+            self.countdown_remaining_seconds
+                .as_ref()
+                .map(|countdown_remaining_seconds| {
+                    [
+                        "countdownRemainingSeconds".to_string(),
+                        countdown_remaining_seconds.to_string(),
+                    ]
+                    .join(",")
+                }),
+            self.recording_remaining_seconds
+                .as_ref()
+                .map(|recording_remaining_seconds| {
+                    [
+                        "recordingRemainingSeconds".to_string(),
+                        recording_remaining_seconds.to_string(),
+                    ]
+                    .join(",")
+                }),
+            self.capture_session_id.as_ref().map(|capture_session_id| {
+                [
+                    "captureSessionId".to_string(),
+                    capture_session_id.to_string(),
+                ]
+                .join(",")
+            }),
+            self.sources_revision.as_ref().map(|sources_revision| {
+                ["sourcesRevision".to_string(), sources_revision.to_string()].join(",")
+            }),
+            Some("recordingDurationSeconds".to_string()),
+            Some(self.recording_duration_seconds.to_string()),
+            self.recording_url.as_ref().map(|recording_url| {
+                ["recordingURL".to_string(), recording_url.to_string()].join(",")
+            }),
+            self.camera_id
+                .as_ref()
+                .map(|camera_id| ["cameraId".to_string(), camera_id.to_string()].join(",")),
+            // Skipping cameraTrack in query parameter serialization
+
+            // Skipping audioTracks in query parameter serialization
+
+            // Skipping audioLevels in query parameter serialization
+
+            // Skipping privacyMask in query parameter serialization
+
+            // Skipping captureMetadata in query parameter serialization
+
+            // Skipping recordingMetadata in query parameter serialization
+
+            // Skipping lastError in query parameter serialization
+            self.events_url
+                .as_ref()
+                .map(|events_url| ["eventsURL".to_string(), events_url.to_string()].join(",")),
+            // Skipping lastRecordingTelemetry in query parameter serialization
+
+            // Skipping telemetry in query parameter serialization
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a CaptureStatusResult value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for CaptureStatusResult {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub is_running: Vec<bool>,
+            pub is_recording: Vec<bool>,
+            pub is_paused: Vec<bool>,
+            pub is_counting_down: Vec<bool>,
+            pub countdown_remaining_seconds: Vec<f64>,
+            pub recording_remaining_seconds: Vec<f64>,
+            pub capture_session_id: Vec<String>,
+            pub sources_revision: Vec<i32>,
+            pub recording_duration_seconds: Vec<f64>,
+            pub recording_url: Vec<String>,
+            pub camera_id: Vec<i32>,
+            pub camera_track: Vec<models::CameraTrack>,
+            pub audio_tracks: Vec<Vec<models::AudioTrack>>,
+            pub audio_levels: Vec<Vec<models::AudioLevels>>,
+            pub privacy_mask: Vec<models::PrivacyMaskStatus>,
+            pub capture_metadata: Vec<models::CaptureStatusResultCaptureMetadata>,
+            pub recording_metadata: Vec<models::RecordingMetadata>,
+            pub last_error: Vec<models::EngineBadRequestError>,
+            pub events_url: Vec<String>,
+            pub last_recording_telemetry: Vec<models::CaptureTelemetry>,
+            pub telemetry: Vec<models::CaptureTelemetry>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing CaptureStatusResult".to_string(),
+                    );
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "isRunning" => intermediate_rep.is_running.push(<bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "isRecording" => intermediate_rep.is_recording.push(<bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "isPaused" => intermediate_rep.is_paused.push(<bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "isCountingDown" => intermediate_rep.is_counting_down.push(<bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "countdownRemainingSeconds" => intermediate_rep.countdown_remaining_seconds.push(<f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "recordingRemainingSeconds" => intermediate_rep.recording_remaining_seconds.push(<f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "captureSessionId" => intermediate_rep.capture_session_id.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "sourcesRevision" => intermediate_rep.sources_revision.push(<i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "recordingDurationSeconds" => intermediate_rep.recording_duration_seconds.push(<f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "recordingURL" => intermediate_rep.recording_url.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "cameraId" => intermediate_rep.camera_id.push(<i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "cameraTrack" => intermediate_rep.camera_track.push(<models::CameraTrack as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    "audioTracks" => return std::result::Result::Err("Parsing a container in this style is not supported in CaptureStatusResult".to_string()),
+                    "audioLevels" => return std::result::Result::Err("Parsing a container in this style is not supported in CaptureStatusResult".to_string()),
+                    #[allow(clippy::redundant_clone)]
+                    "privacyMask" => intermediate_rep.privacy_mask.push(<models::PrivacyMaskStatus as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "captureMetadata" => intermediate_rep.capture_metadata.push(<models::CaptureStatusResultCaptureMetadata as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "recordingMetadata" => intermediate_rep.recording_metadata.push(<models::RecordingMetadata as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "lastError" => intermediate_rep.last_error.push(<models::EngineBadRequestError as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "eventsURL" => intermediate_rep.events_url.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "lastRecordingTelemetry" => intermediate_rep.last_recording_telemetry.push(<models::CaptureTelemetry as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "telemetry" => intermediate_rep.telemetry.push(<models::CaptureTelemetry as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing CaptureStatusResult".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(CaptureStatusResult {
+            is_running: intermediate_rep
+                .is_running
+                .into_iter()
+                .next()
+                .ok_or_else(|| "isRunning missing in CaptureStatusResult".to_string())?,
+            is_recording: intermediate_rep
+                .is_recording
+                .into_iter()
+                .next()
+                .ok_or_else(|| "isRecording missing in CaptureStatusResult".to_string())?,
+            is_paused: intermediate_rep.is_paused.into_iter().next(),
+            is_counting_down: intermediate_rep.is_counting_down.into_iter().next(),
+            countdown_remaining_seconds: intermediate_rep
+                .countdown_remaining_seconds
+                .into_iter()
+                .next(),
+            recording_remaining_seconds: intermediate_rep
+                .recording_remaining_seconds
+                .into_iter()
+                .next(),
+            capture_session_id: intermediate_rep.capture_session_id.into_iter().next(),
+            sources_revision: intermediate_rep.sources_revision.into_iter().next(),
+            recording_duration_seconds: intermediate_rep
+                .recording_duration_seconds
+                .into_iter()
+                .next()
+                .ok_or_else(|| {
+                    "recordingDurationSeconds missing in CaptureStatusResult".to_string()
+                })?,
+            recording_url: intermediate_rep.recording_url.into_iter().next(),
+            camera_id: intermediate_rep.camera_id.into_iter().next(),
+            camera_track: intermediate_rep.camera_track.into_iter().next(),
+            audio_tracks: intermediate_rep.audio_tracks.into_iter().next(),
+            audio_levels: intermediate_rep.audio_levels.into_iter().next(),
+            privacy_mask: intermediate_rep.privacy_mask.into_iter().next(),
+            capture_metadata: intermediate_rep.capture_metadata.into_iter().next(),
+            recording_metadata: intermediate_rep.recording_metadata.into_iter().next(),
+            last_error: intermediate_rep.last_error.into_iter().next(),
+            events_url: intermediate_rep.events_url.into_iter().next(),
+            last_recording_telemetry: intermediate_rep.last_recording_telemetry.into_iter().next(),
+            telemetry: intermediate_rep
+                .telemetry
+                .into_iter()
+                .next()
+                .ok_or_else(|| "telemetry missing in CaptureStatusResult".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<CaptureStatusResult> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<CaptureStatusResult>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<CaptureStatusResult>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Invalid header value for CaptureStatusResult - value: {hdr_value} is invalid {e}"#
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<CaptureStatusResult> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <CaptureStatusResult as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        r#"Unable to convert header value '{value}' into CaptureStatusResult - {err}"#
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Unable to convert header: {hdr_value:?} to string: {e}"#
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct CaptureStatusResultCaptureMetadata {
+    #[serde(rename = "window")]
+    #[validate(nested)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub window: Option<models::CaptureStatusResultCaptureMetadataWindow>,
+
+    /// Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "source")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub source: String,
+
+    #[serde(rename = "contentRect")]
+    #[validate(nested)]
+    pub content_rect: models::CaptureStatusResultCaptureMetadataContentRect,
+
+    #[serde(rename = "pixelScale")]
+    pub pixel_scale: f64,
+
+    #[serde(rename = "fps")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fps: Option<f64>,
+}
+
+impl CaptureStatusResultCaptureMetadata {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(
+        source: String,
+        content_rect: models::CaptureStatusResultCaptureMetadataContentRect,
+        pixel_scale: f64,
+    ) -> CaptureStatusResultCaptureMetadata {
+        CaptureStatusResultCaptureMetadata {
+            window: None,
+            source,
+            content_rect,
+            pixel_scale,
+            fps: None,
+        }
+    }
+}
+
+/// Converts the CaptureStatusResultCaptureMetadata value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for CaptureStatusResultCaptureMetadata {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            // Skipping window in query parameter serialization
+            Some("source".to_string()),
+            Some(self.source.to_string()),
+            // Skipping contentRect in query parameter serialization
+            Some("pixelScale".to_string()),
+            Some(self.pixel_scale.to_string()),
+            self.fps
+                .as_ref()
+                .map(|fps| ["fps".to_string(), fps.to_string()].join(",")),
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a CaptureStatusResultCaptureMetadata value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for CaptureStatusResultCaptureMetadata {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub window: Vec<models::CaptureStatusResultCaptureMetadataWindow>,
+            pub source: Vec<String>,
+            pub content_rect: Vec<models::CaptureStatusResultCaptureMetadataContentRect>,
+            pub pixel_scale: Vec<f64>,
+            pub fps: Vec<f64>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing CaptureStatusResultCaptureMetadata"
+                            .to_string(),
+                    );
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "window" => intermediate_rep.window.push(<models::CaptureStatusResultCaptureMetadataWindow as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "source" => intermediate_rep.source.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "contentRect" => intermediate_rep.content_rect.push(<models::CaptureStatusResultCaptureMetadataContentRect as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "pixelScale" => intermediate_rep.pixel_scale.push(<f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "fps" => intermediate_rep.fps.push(<f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing CaptureStatusResultCaptureMetadata".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(CaptureStatusResultCaptureMetadata {
+            window: intermediate_rep.window.into_iter().next(),
+            source: intermediate_rep.source.into_iter().next().ok_or_else(|| {
+                "source missing in CaptureStatusResultCaptureMetadata".to_string()
+            })?,
+            content_rect: intermediate_rep
+                .content_rect
+                .into_iter()
+                .next()
+                .ok_or_else(|| {
+                    "contentRect missing in CaptureStatusResultCaptureMetadata".to_string()
+                })?,
+            pixel_scale: intermediate_rep
+                .pixel_scale
+                .into_iter()
+                .next()
+                .ok_or_else(|| {
+                    "pixelScale missing in CaptureStatusResultCaptureMetadata".to_string()
+                })?,
+            fps: intermediate_rep.fps.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<CaptureStatusResultCaptureMetadata> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<CaptureStatusResultCaptureMetadata>>
+    for HeaderValue
+{
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<CaptureStatusResultCaptureMetadata>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Invalid header value for CaptureStatusResultCaptureMetadata - value: {hdr_value} is invalid {e}"#
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue>
+    for header::IntoHeaderValue<CaptureStatusResultCaptureMetadata>
+{
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <CaptureStatusResultCaptureMetadata as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        r#"Unable to convert header value '{value}' into CaptureStatusResultCaptureMetadata - {err}"#
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Unable to convert header: {hdr_value:?} to string: {e}"#
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct CaptureStatusResultCaptureMetadataContentRect {
+    #[serde(rename = "x")]
+    pub x: f64,
+
+    #[serde(rename = "y")]
+    pub y: f64,
+
+    #[serde(rename = "width")]
+    pub width: f64,
+
+    #[serde(rename = "height")]
+    pub height: f64,
+}
+
+impl CaptureStatusResultCaptureMetadataContentRect {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+    ) -> CaptureStatusResultCaptureMetadataContentRect {
+        CaptureStatusResultCaptureMetadataContentRect {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+}
+
+/// Converts the CaptureStatusResultCaptureMetadataContentRect value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for CaptureStatusResultCaptureMetadataContentRect {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("x".to_string()),
+            Some(self.x.to_string()),
+            Some("y".to_string()),
+            Some(self.y.to_string()),
+            Some("width".to_string()),
+            Some(self.width.to_string()),
+            Some("height".to_string()),
+            Some(self.height.to_string()),
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a CaptureStatusResultCaptureMetadataContentRect value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for CaptureStatusResultCaptureMetadataContentRect {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub x: Vec<f64>,
+            pub y: Vec<f64>,
+            pub width: Vec<f64>,
+            pub height: Vec<f64>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val =
+                match string_iter.next() {
+                    Some(x) => x,
+                    None => return std::result::Result::Err(
+                        "Missing value while parsing CaptureStatusResultCaptureMetadataContentRect"
+                            .to_string(),
+                    ),
+                };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "x" => intermediate_rep.x.push(<f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "y" => intermediate_rep.y.push(<f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "width" => intermediate_rep.width.push(<f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "height" => intermediate_rep.height.push(<f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing CaptureStatusResultCaptureMetadataContentRect".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(CaptureStatusResultCaptureMetadataContentRect {
+            x: intermediate_rep.x.into_iter().next().ok_or_else(|| {
+                "x missing in CaptureStatusResultCaptureMetadataContentRect".to_string()
+            })?,
+            y: intermediate_rep.y.into_iter().next().ok_or_else(|| {
+                "y missing in CaptureStatusResultCaptureMetadataContentRect".to_string()
+            })?,
+            width: intermediate_rep.width.into_iter().next().ok_or_else(|| {
+                "width missing in CaptureStatusResultCaptureMetadataContentRect".to_string()
+            })?,
+            height: intermediate_rep.height.into_iter().next().ok_or_else(|| {
+                "height missing in CaptureStatusResultCaptureMetadataContentRect".to_string()
+            })?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<CaptureStatusResultCaptureMetadataContentRect> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<CaptureStatusResultCaptureMetadataContentRect>>
+    for HeaderValue
+{
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<CaptureStatusResultCaptureMetadataContentRect>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Invalid header value for CaptureStatusResultCaptureMetadataContentRect - value: {hdr_value} is invalid {e}"#
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue>
+    for header::IntoHeaderValue<CaptureStatusResultCaptureMetadataContentRect>
+{
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <CaptureStatusResultCaptureMetadataContentRect as std::str::FromStr>::from_str(
+                    value,
+                ) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        r#"Unable to convert header value '{value}' into CaptureStatusResultCaptureMetadataContentRect - {err}"#
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Unable to convert header: {hdr_value:?} to string: {e}"#
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct CaptureStatusResultCaptureMetadataWindow {
+    #[serde(rename = "id")]
+    pub id: i32,
+
+    #[serde(rename = "title")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub title: String,
+
+    #[serde(rename = "appName")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub app_name: String,
+}
+
+impl CaptureStatusResultCaptureMetadataWindow {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(
+        id: i32,
+        title: String,
+        app_name: String,
+    ) -> CaptureStatusResultCaptureMetadataWindow {
+        CaptureStatusResultCaptureMetadataWindow {
+            id,
+            title,
+            app_name,
+        }
+    }
+}
+
+/// Converts the CaptureStatusResultCaptureMetadataWindow value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for CaptureStatusResultCaptureMetadataWindow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("id".to_string()),
+            Some(self.id.to_string()),
+            Some("title".to_string()),
+            Some(self.title.to_string()),
+            Some("appName".to_string()),
+            Some(self.app_name.to_string()),
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a CaptureStatusResultCaptureMetadataWindow value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for CaptureStatusResultCaptureMetadataWindow {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub id: Vec<i32>,
+            pub title: Vec<String>,
+            pub app_name: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing CaptureStatusResultCaptureMetadataWindow"
+                            .to_string(),
+                    );
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "id" => intermediate_rep.id.push(
+                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "title" => intermediate_rep.title.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "appName" => intermediate_rep.app_name.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing CaptureStatusResultCaptureMetadataWindow"
+                                .to_string(),
+                        );
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(CaptureStatusResultCaptureMetadataWindow {
+            id: intermediate_rep.id.into_iter().next().ok_or_else(|| {
+                "id missing in CaptureStatusResultCaptureMetadataWindow".to_string()
+            })?,
+            title: intermediate_rep.title.into_iter().next().ok_or_else(|| {
+                "title missing in CaptureStatusResultCaptureMetadataWindow".to_string()
+            })?,
+            app_name: intermediate_rep
+                .app_name
+                .into_iter()
+                .next()
+                .ok_or_else(|| {
+                    "appName missing in CaptureStatusResultCaptureMetadataWindow".to_string()
+                })?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<CaptureStatusResultCaptureMetadataWindow> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<CaptureStatusResultCaptureMetadataWindow>>
+    for HeaderValue
+{
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<CaptureStatusResultCaptureMetadataWindow>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Invalid header value for CaptureStatusResultCaptureMetadataWindow - value: {hdr_value} is invalid {e}"#
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue>
+    for header::IntoHeaderValue<CaptureStatusResultCaptureMetadataWindow>
+{
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <CaptureStatusResultCaptureMetadataWindow as std::str::FromStr>::from_str(
+                    value,
+                ) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        r#"Unable to convert header value '{value}' into CaptureStatusResultCaptureMetadataWindow - {err}"#
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Unable to convert header: {hdr_value:?} to string: {e}"#
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct CaptureTelemetry {
+    #[serde(rename = "sourceDroppedFrames")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_dropped_frames: Option<i32>,
+
+    #[serde(rename = "writerDroppedFrames")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub writer_dropped_frames: Option<i32>,
+
+    #[serde(rename = "writerBackpressureDrops")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub writer_backpressure_drops: Option<i32>,
+
+    #[serde(rename = "achievedFps")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub achieved_fps: Option<f64>,
+
+    #[serde(rename = "cpuPercent")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cpu_percent: Option<f64>,
+
+    #[serde(rename = "memoryBytes")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory_bytes: Option<f64>,
+
+    #[serde(rename = "recordingBitrateMbps")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recording_bitrate_mbps: Option<f64>,
+
+    #[serde(rename = "captureCallbackMs")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capture_callback_ms: Option<f64>,
+
+    #[serde(rename = "recordQueueLagMs")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub record_queue_lag_ms: Option<f64>,
+
+    #[serde(rename = "writerAppendMs")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub writer_append_ms: Option<f64>,
+
+    #[serde(rename = "previewEncodeMs")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preview_encode_ms: Option<f64>,
+
+    #[serde(rename = "audioTracks")]
+    #[validate(nested)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audio_tracks: Option<Vec<models::AudioTrackTelemetry>>,
+}
+
+impl CaptureTelemetry {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new() -> CaptureTelemetry {
+        CaptureTelemetry {
+            source_dropped_frames: None,
+            writer_dropped_frames: None,
+            writer_backpressure_drops: None,
+            achieved_fps: None,
+            cpu_percent: None,
+            memory_bytes: None,
+            recording_bitrate_mbps: None,
+            capture_callback_ms: None,
+            record_queue_lag_ms: None,
+            writer_append_ms: None,
+            preview_encode_ms: None,
+            audio_tracks: None,
+        }
+    }
+}
+
+/// Converts the CaptureTelemetry value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for CaptureTelemetry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            self.source_dropped_frames
+                .as_ref()
+                .map(|source_dropped_frames| {
+                    [
+                        "sourceDroppedFrames".to_string(),
+                        source_dropped_frames.to_string(),
+                    ]
+                    .join(",")
+                }),
+            self.writer_dropped_frames
+                .as_ref()
+                .map(|writer_dropped_frames| {
+                    [
+                        "writerDroppedFrames".to_string(),
+                        writer_dropped_frames.to_string(),
+                    ]
+                    .join(",")
+                }),
+            self.writer_backpressure_drops
+                .as_ref()
+                .map(|writer_backpressure_drops| {
+                    [
+                        "writerBackpressureDrops".to_string(),
+                        writer_backpressure_drops.to_string(),
+                    ]
+                    .join(",")
+                }),
+            self.achieved_fps.as_ref().map(|achieved_fps| {
+                ["achievedFps".to_string(), achieved_fps.to_string()].join(",")
+            }),
+            self.cpu_percent
+                .as_ref()
+                .map(|cpu_percent| ["cpuPercent".to_string(), cpu_percent.to_string()].join(",")),
+            self.memory_bytes.as_ref().map(|memory_bytes| {
+                ["memoryBytes".to_string(), memory_bytes.to_string()].join(",")
+            }),
+            self.recording_bitrate_mbps
+                .as_ref()
+                .map(|recording_bitrate_mbps| {
+                    [
+                        "recordingBitrateMbps".to_string(),
+                        recording_bitrate_mbps.to_string(),
+                    ]
+                    .join(",")
+                }),
+            self.capture_callback_ms
+                .as_ref()
+                .map(|capture_callback_ms| {
+                    [
+                        "captureCallbackMs".to_string(),
+                        capture_callback_ms.to_string(),
+                    ]
+                    .join(",")
+                }),
+            self.record_queue_lag_ms
+                .as_ref()
+                .map(|record_queue_lag_ms| {
+                    [
+                        "recordQueueLagMs".to_string(),
+                        record_queue_lag_ms.to_string(),
+                    ]
+                    .join(",")
+                }),
+            self.writer_append_ms.as_ref().map(|writer_append_ms| {
+                ["writerAppendMs".to_string(), writer_append_ms.to_string()].join(",")
+            }),
+            self.preview_encode_ms.as_ref().map(|preview_encode_ms| {
+                ["previewEncodeMs".to_string(), preview_encode_ms.to_string()].join(",")
+            }),
+            // Skipping audioTracks in query parameter serialization
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a CaptureTelemetry value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for CaptureTelemetry {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub source_dropped_frames: Vec<i32>,
+            pub writer_dropped_frames: Vec<i32>,
+            pub writer_backpressure_drops: Vec<i32>,
+            pub achieved_fps: Vec<f64>,
+            pub cpu_percent: Vec<f64>,
+            pub memory_bytes: Vec<f64>,
+            pub recording_bitrate_mbps: Vec<f64>,
+            pub capture_callback_ms: Vec<f64>,
+            pub record_queue_lag_ms: Vec<f64>,
+            pub writer_append_ms: Vec<f64>,
+            pub preview_encode_ms: Vec<f64>,
+            pub audio_tracks: Vec<Vec<models::AudioTrackTelemetry>>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing CaptureTelemetry".to_string(),
+                    );
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "sourceDroppedFrames" => intermediate_rep.source_dropped_frames.push(
+                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "writerDroppedFrames" => intermediate_rep.writer_dropped_frames.push(
+                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "writerBackpressureDrops" => intermediate_rep.writer_backpressure_drops.push(
+                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "achievedFps" => intermediate_rep.achieved_fps.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "cpuPercent" => intermediate_rep.cpu_percent.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "memoryBytes" => intermediate_rep.memory_bytes.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "recordingBitrateMbps" => intermediate_rep.recording_bitrate_mbps.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "captureCallbackMs" => intermediate_rep.capture_callback_ms.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "recordQueueLagMs" => intermediate_rep.record_queue_lag_ms.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "writerAppendMs" => intermediate_rep.writer_append_ms.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "previewEncodeMs" => intermediate_rep.preview_encode_ms.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    "audioTracks" => return std::result::Result::Err(
+                        "Parsing a container in this style is not supported in CaptureTelemetry"
+                            .to_string(),
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing CaptureTelemetry".to_string(),
+                        );
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(CaptureTelemetry {
+            source_dropped_frames: intermediate_rep.source_dropped_frames.into_iter().next(),
+            writer_dropped_frames: intermediate_rep.writer_dropped_frames.into_iter().next(),
+            writer_backpressure_drops: intermediate_rep
+                .writer_backpressure_drops
+                .into_iter()
+                .next(),
+            achieved_fps: intermediate_rep.achieved_fps.into_iter().next(),
+            cpu_percent: intermediate_rep.cpu_percent.into_iter().next(),
+            memory_bytes: intermediate_rep.memory_bytes.into_iter().next(),
+            recording_bitrate_mbps: intermediate_rep.recording_bitrate_mbps.into_iter().next(),
+            capture_callback_ms: intermediate_rep.capture_callback_ms.into_iter().next(),
+            record_queue_lag_ms: intermediate_rep.record_queue_lag_ms.into_iter().next(),
+            writer_append_ms: intermediate_rep.writer_append_ms.into_iter().next(),
+            preview_encode_ms: intermediate_rep.preview_encode_ms.into_iter().next(),
+            audio_tracks: intermediate_rep.audio_tracks.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<CaptureTelemetry> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<CaptureTelemetry>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<CaptureTelemetry>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Invalid header value for CaptureTelemetry - value: {hdr_value} is invalid {e}"#
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<CaptureTelemetry> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <CaptureTelemetry as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        r#"Unable to convert header value '{value}' into CaptureTelemetry - {err}"#
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Unable to convert header: {hdr_value:?} to string: {e}"#
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct ChapterSuggestion {
+    #[serde(rename = "id")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub id: String,
+
+    #[serde(rename = "title")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub title: String,
+
+    #[serde(rename = "startSeconds")]
+    pub start_seconds: f64,
+
+    #[serde(rename = "endSeconds")]
+    pub end_seconds: f64,
+
+    #[serde(rename = "activity")]
+    pub activity: f64,
+
+    #[serde(rename = "thumbnailSeconds")]
+    pub thumbnail_seconds: f64,
+}
+
+impl ChapterSuggestion {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(
+        id: String,
+        title: String,
+        start_seconds: f64,
+        end_seconds: f64,
+        activity: f64,
+        thumbnail_seconds: f64,
+    ) -> ChapterSuggestion {
+        ChapterSuggestion {
+            id,
+            title,
+            start_seconds,
+            end_seconds,
+            activity,
+            thumbnail_seconds,
+        }
+    }
+}
+
+/// Converts the ChapterSuggestion value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for ChapterSuggestion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("id".to_string()),
+            Some(self.id.to_string()),
+            Some("title".to_string()),
+            Some(self.title.to_string()),
+            Some("startSeconds".to_string()),
+            Some(self.start_seconds.to_string()),
+            Some("endSeconds".to_string()),
+            Some(self.end_seconds.to_string()),
+            Some("activity".to_string()),
+            Some(self.activity.to_string()),
+            Some("thumbnailSeconds".to_string()),
+            Some(self.thumbnail_seconds.to_string()),
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a ChapterSuggestion value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for ChapterSuggestion {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub id: Vec<String>,
+            pub title: Vec<String>,
+            pub start_seconds: Vec<f64>,
+            pub end_seconds: Vec<f64>,
+            pub activity: Vec<f64>,
+            pub thumbnail_seconds: Vec<f64>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing ChapterSuggestion".to_string(),
+                    );
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "id" => intermediate_rep.id.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "title" => intermediate_rep.title.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "startSeconds" => intermediate_rep.start_seconds.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "endSeconds" => intermediate_rep.end_seconds.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "activity" => intermediate_rep.activity.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "thumbnailSeconds" => intermediate_rep.thumbnail_seconds.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing ChapterSuggestion".to_string(),
+                        );
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(ChapterSuggestion {
+            id: intermediate_rep
+                .id
+                .into_iter()
+                .next()
+                .ok_or_else(|| "id missing in ChapterSuggestion".to_string())?,
+            title: intermediate_rep
+                .title
+                .into_iter()
+                .next()
+                .ok_or_else(|| "title missing in ChapterSuggestion".to_string())?,
+            start_seconds: intermediate_rep
+                .start_seconds
+                .into_iter()
+                .next()
+                .ok_or_else(|| "startSeconds missing in ChapterSuggestion".to_string())?,
+            end_seconds: intermediate_rep
+                .end_seconds
+                .into_iter()
+                .next()
+                .ok_or_else(|| "endSeconds missing in ChapterSuggestion".to_string())?,
+            activity: intermediate_rep
+                .activity
+                .into_iter()
+                .next()
+                .ok_or_else(|| "activity missing in ChapterSuggestion".to_string())?,
+            thumbnail_seconds: intermediate_rep
+                .thumbnail_seconds
+                .into_iter()
+                .next()
+                .ok_or_else(|| "thumbnailSeconds missing in ChapterSuggestion".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<ChapterSuggestion> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<ChapterSuggestion>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<ChapterSuggestion>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Invalid header value for ChapterSuggestion - value: {hdr_value} is invalid {e}"#
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<ChapterSuggestion> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <ChapterSuggestion as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        r#"Unable to convert header value '{value}' into ChapterSuggestion - {err}"#
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Unable to convert header: {hdr_value:?} to string: {e}"#
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct ClickEffectSegment {
+    #[serde(rename = "clipId")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub clip_id: String,
+
+    #[serde(rename = "ripple")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ripple: Option<bool>,
+
+    #[serde(rename = "spotlight")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spotlight: Option<bool>,
+
+    #[serde(rename = "clickSound")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub click_sound: Option<bool>,
+}
+
+impl ClickEffectSegment {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(clip_id: String) -> ClickEffectSegment {
+        ClickEffectSegment {
+            clip_id,
+            ripple: None,
+            spotlight: None,
+            click_sound: None,
+        }
+    }
+}
+
+/// Converts the ClickEffectSegment value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for ClickEffectSegment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("clipId".to_string()),
+            Some(self.clip_id.to_string()),
+            self.ripple
+                .as_ref()
+                .map(|ripple| ["ripple".to_string(), ripple.to_string()].join(",")),
+            self.spotlight
+                .as_ref()
+                .map(|spotlight| ["spotlight".to_string(), spotlight.to_string()].join(",")),
+            self.click_sound
+                .as_ref()
+                .map(|click_sound| ["clickSound".to_string(), click_sound.to_string()].join(",")),
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a ClickEffectSegment value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for ClickEffectSegment {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub clip_id: Vec<String>,
+            pub ripple: Vec<bool>,
+            pub spotlight: Vec<bool>,
+            pub click_sound: Vec<bool>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing ClickEffectSegment".to_string(),
+                    );
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "clipId" => intermediate_rep.clip_id.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "ripple" => intermediate_rep.ripple.push(
+                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "spotlight" => intermediate_rep.spotlight.push(
+                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "clickSound" => intermediate_rep.click_sound.push(
+                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing ClickEffectSegment".to_string(),
+                        );
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(ClickEffectSegment {
+            clip_id: intermediate_rep
+                .clip_id
+                .into_iter()
+                .next()
+                .ok_or_else(|| "clipId missing in ClickEffectSegment".to_string())?,
+            ripple: intermediate_rep.ripple.into_iter().next(),
+            spotlight: intermediate_rep.spotlight.into_iter().next(),
+            click_sound: intermediate_rep.click_sound.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<ClickEffectSegment> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<ClickEffectSegment>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<ClickEffectSegment>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Invalid header value for ClickEffectSegment - value: {hdr_value} is invalid {e}"#
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<ClickEffectSegment> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <ClickEffectSegment as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        r#"Unable to convert header value '{value}' into ClickEffectSegment - {err}"#
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Unable to convert header: {hdr_value:?} to string: {e}"#
+            )),
+        }
+    }
+}
+
+/// Effects synthesized from recorded clicks and pointer movement.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct ClickEffectsSettings {
+    /// Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "version")]
+    pub version: f64,
+
+    #[serde(rename = "ripple")]
+    #[validate(nested)]
+    pub ripple: models::ClickEffectsSettingsRipple,
+
+    #[serde(rename = "spotlight")]
+    #[validate(nested)]
+    pub spotlight: models::ClickEffectsSettingsSpotlight,
+
+    #[serde(rename = "clickSound")]
+    #[validate(nested)]
+    pub click_sound: models::ClickEffectsSettingsClickSound,
+
+    #[serde(rename = "segments")]
+    #[validate(nested)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub segments: Option<Vec<models::ClickEffectSegment>>,
+}
+
+impl ClickEffectsSettings {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(
+        version: f64,
+        ripple: models::ClickEffectsSettingsRipple,
+        spotlight: models::ClickEffectsSettingsSpotlight,
+        click_sound: models::ClickEffectsSettingsClickSound,
+    ) -> ClickEffectsSettings {
+        ClickEffectsSettings {
+            version,
+            ripple,
+            spotlight,
+            click_sound,
+            segments: None,
+        }
+    }
+}
+
+/// Converts the ClickEffectsSettings value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for ClickEffectsSettings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("version".to_string()),
+            Some(self.version.to_string()),
+            // Skipping ripple in query parameter serialization
+
+            // Skipping spotlight in query parameter serialization
+
+            // Skipping clickSound in query parameter serialization
+
+            // Skipping segments in query parameter serialization
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a ClickEffectsSettings value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for ClickEffectsSettings {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub version: Vec<f64>,
+            pub ripple: Vec<models::ClickEffectsSettingsRipple>,
+            pub spotlight: Vec<models::ClickEffectsSettingsSpotlight>,
+            pub click_sound: Vec<models::ClickEffectsSettingsClickSound>,
+            pub segments: Vec<Vec<models::ClickEffectSegment>>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing ClickEffectsSettings".to_string(),
+                    );
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "version" => intermediate_rep.version.push(<f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "ripple" => intermediate_rep.ripple.push(<models::ClickEffectsSettingsRipple as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "spotlight" => intermediate_rep.spotlight.push(<models::ClickEffectsSettingsSpotlight as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "clickSound" => intermediate_rep.click_sound.push(<models::ClickEffectsSettingsClickSound as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    "segments" => return std::result::Result::Err("Parsing a container in this style is not supported in ClickEffectsSettings".to_string()),
+                    _ => return std::result::Result::Err("Unexpected key while parsing ClickEffectsSettings".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(ClickEffectsSettings {
+            version: intermediate_rep
+                .version
+                .into_iter()
+                .next()
+                .ok_or_else(|| "version missing in ClickEffectsSettings".to_string())?,
+            ripple: intermediate_rep
+                .ripple
+                .into_iter()
+                .next()
+                .ok_or_else(|| "ripple missing in ClickEffectsSettings".to_string())?,
+            spotlight: intermediate_rep
+                .spotlight
+                .into_iter()
+                .next()
+                .ok_or_else(|| "spotlight missing in ClickEffectsSettings".to_string())?,
+            click_sound: intermediate_rep
+                .click_sound
+                .into_iter()
+                .next()
+                .ok_or_else(|| "clickSound missing in ClickEffectsSettings".to_string())?,
+            segments: intermediate_rep.segments.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<ClickEffectsSettings> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<ClickEffectsSettings>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<ClickEffectsSettings>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Invalid header value for ClickEffectsSettings - value: {hdr_value} is invalid {e}"#
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<ClickEffectsSettings> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <ClickEffectsSettings as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        r#"Unable to convert header value '{value}' into ClickEffectsSettings - {err}"#
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Unable to convert header: {hdr_value:?} to string: {e}"#
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct ClickEffectsSettingsClickSound {
+    #[serde(rename = "enabled")]
+    pub enabled: bool,
+
+    #[serde(rename = "volume")]
+    pub volume: f64,
+}
+
+impl ClickEffectsSettingsClickSound {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(enabled: bool, volume: f64) -> ClickEffectsSettingsClickSound {
+        ClickEffectsSettingsClickSound { enabled, volume }
+    }
+}
+
+/// Converts the ClickEffectsSettingsClickSound value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for ClickEffectsSettingsClickSound {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("enabled".to_string()),
+            Some(self.enabled.to_string()),
+            Some("volume".to_string()),
+            Some(self.volume.to_string()),
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a ClickEffectsSettingsClickSound value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for ClickEffectsSettingsClickSound {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub enabled: Vec<bool>,
+            pub volume: Vec<f64>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing ClickEffectsSettingsClickSound".to_string(),
+                    );
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "enabled" => intermediate_rep.enabled.push(
+                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "volume" => intermediate_rep.volume.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing ClickEffectsSettingsClickSound"
+                                .to_string(),
+                        );
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(ClickEffectsSettingsClickSound {
+            enabled: intermediate_rep
+                .enabled
+                .into_iter()
+                .next()
+                .ok_or_else(|| "enabled missing in ClickEffectsSettingsClickSound".to_string())?,
+            volume: intermediate_rep
+                .volume
+                .into_iter()
+                .next()
+                .ok_or_else(|| "volume missing in ClickEffectsSettingsClickSound".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<ClickEffectsSettingsClickSound> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<ClickEffectsSettingsClickSound>>
+    for HeaderValue
+{
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<ClickEffectsSettingsClickSound>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Invalid header value for ClickEffectsSettingsClickSound - value: {hdr_value} is invalid {e}"#
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue>
+    for header::IntoHeaderValue<ClickEffectsSettingsClickSound>
+{
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <ClickEffectsSettingsClickSound as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        r#"Unable to convert header value '{value}' into ClickEffectsSettingsClickSound - {err}"#
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Unable to convert header: {hdr_value:?} to string: {e}"#
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct ClickEffectsSettingsRipple {
+    #[serde(rename = "enabled")]
+    pub enabled: bool,
+
+    #[serde(rename = "color")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub color: String,
+
+    #[serde(rename = "maxRadius")]
+    pub max_radius: f64,
+
+    #[serde(rename = "durationSeconds")]
+    pub duration_seconds: f64,
+}
+
+impl ClickEffectsSettingsRipple {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(
+        enabled: bool,
+        color: String,
+        max_radius: f64,
+        duration_seconds: f64,
+    ) -> ClickEffectsSettingsRipple {
+        ClickEffectsSettingsRipple {
+            enabled,
+            color,
+            max_radius,
+            duration_seconds,
+        }
+    }
+}
+
+/// Converts the ClickEffectsSettingsRipple value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for ClickEffectsSettingsRipple {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("enabled".to_string()),
+            Some(self.enabled.to_string()),
+            Some("color".to_string()),
+            Some(self.color.to_string()),
+            Some("maxRadius".to_string()),
+            Some(self.max_radius.to_string()),
+            Some("durationSeconds".to_string()),
+            Some(self.duration_seconds.to_string()),
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a ClickEffectsSettingsRipple value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for ClickEffectsSettingsRipple {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub enabled: Vec<bool>,
+            pub color: Vec<String>,
+            pub max_radius: Vec<f64>,
+            pub duration_seconds: Vec<f64>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing ClickEffectsSettingsRipple".to_string(),
+                    );
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "enabled" => intermediate_rep.enabled.push(
+                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "color" => intermediate_rep.color.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "maxRadius" => intermediate_rep.max_radius.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "durationSeconds" => intermediate_rep.duration_seconds.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing ClickEffectsSettingsRipple".to_string(),
+                        );
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(ClickEffectsSettingsRipple {
+            enabled: intermediate_rep
+                .enabled
+                .into_iter()
+                .next()
+                .ok_or_else(|| "enabled missing in ClickEffectsSettingsRipple".to_string())?,
+            color: intermediate_rep
+                .color
+                .into_iter()
+                .next()
+                .ok_or_else(|| "color missing in ClickEffectsSettingsRipple".to_string())?,
+            max_radius: intermediate_rep
+                .max_radius
+                .into_iter()
+                .next()
+                .ok_or_else(|| "maxRadius missing in ClickEffectsSettingsRipple".to_string())?,
+            duration_seconds: intermediate_rep
+                .duration_seconds
+                .into_iter()
+                .next()
+                .ok_or_else(|| {
+                    "durationSeconds missing in ClickEffectsSettingsRipple".to_string()
+                })?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<ClickEffectsSettingsRipple> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<ClickEffectsSettingsRipple>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<ClickEffectsSettingsRipple>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Invalid header value for ClickEffectsSettingsRipple - value: {hdr_value} is invalid {e}"#
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<ClickEffectsSettingsRipple> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <ClickEffectsSettingsRipple as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        r#"Unable to convert header value '{value}' into ClickEffectsSettingsRipple - {err}"#
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Unable to convert header: {hdr_value:?} to string: {e}"#
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct ClickEffectsSettingsSpotlight {
+    #[serde(rename = "enabled")]
+    pub enabled: bool,
+
+    #[serde(rename = "radius")]
+    pub radius: f64,
+
+    #[serde(rename = "feather")]
+    pub feather: f64,
+
+    #[serde(rename = "dimOpacity")]
+    pub dim_opacity: f64,
+}
+
+impl ClickEffectsSettingsSpotlight {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(
+        enabled: bool,
+        radius: f64,
+        feather: f64,
+        dim_opacity: f64,
+    ) -> ClickEffectsSettingsSpotlight {
+        ClickEffectsSettingsSpotlight {
+            enabled,
+            radius,
+            feather,
+            dim_opacity,
+        }
+    }
+}
+
+/// Converts the ClickEffectsSettingsSpotlight value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for ClickEffectsSettingsSpotlight {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("enabled".to_string()),
+            Some(self.enabled.to_string()),
+            Some("radius".to_string()),
+            Some(self.radius.to_string()),
+            Some("feather".to_string()),
+            Some(self.feather.to_string()),
+            Some("dimOpacity".to_string()),
+            Some(self.dim_opacity.to_string()),
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a ClickEffectsSettingsSpotlight value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for ClickEffectsSettingsSpotlight {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub enabled: Vec<bool>,
+            pub radius: Vec<f64>,
+            pub feather: Vec<f64>,
+            pub dim_opacity: Vec<f64>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing ClickEffectsSettingsSpotlight".to_string(),
+                    );
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "enabled" => intermediate_rep.enabled.push(
+                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "radius" => intermediate_rep.radius.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "feather" => intermediate_rep.feather.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "dimOpacity" => intermediate_rep.dim_opacity.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing ClickEffectsSettingsSpotlight"
+                                .to_string(),
+                        );
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(ClickEffectsSettingsSpotlight {
+            enabled: intermediate_rep
+                .enabled
+                .into_iter()
+                .next()
+                .ok_or_else(|| "enabled missing in ClickEffectsSettingsSpotlight".to_string())?,
+            radius: intermediate_rep
+                .radius
+                .into_iter()
+                .next()
+                .ok_or_else(|| "radius missing in ClickEffectsSettingsSpotlight".to_string())?,
+            feather: intermediate_rep
+                .feather
+                .into_iter()
+                .next()
+                .ok_or_else(|| "feather missing in ClickEffectsSettingsSpotlight".to_string())?,
+            dim_opacity: intermediate_rep
+                .dim_opacity
+                .into_iter()
+                .next()
+                .ok_or_else(|| "dimOpacity missing in ClickEffectsSettingsSpotlight".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<ClickEffectsSettingsSpotlight> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<ClickEffectsSettingsSpotlight>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<ClickEffectsSettingsSpotlight>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Invalid header value for ClickEffectsSettingsSpotlight - value: {hdr_value} is invalid {e}"#
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<ClickEffectsSettingsSpotlight> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <ClickEffectsSettingsSpotlight as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        r#"Unable to convert header value '{value}' into ClickEffectsSettingsSpotlight - {err}"#
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Unable to convert header: {hdr_value:?} to string: {e}"#
+            )),
+        }
+    }
+}
+
+/// How export redraws the cursor from the recorded input in place of the captured one.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct CursorRenderingSettings {
+    /// Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "version")]
+    pub version: f64,
+
+    #[serde(rename = "enabled")]
+    pub enabled: bool,
+
+    #[serde(rename = "smoothing")]
+    pub smoothing: f64,
+
+    #[serde(rename = "scale")]
+    pub scale: f64,
+
+    #[serde(rename = "hideAfterIdleSeconds")]
+    pub hide_after_idle_seconds: f64,
+}
+
+impl CursorRenderingSettings {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(
+        version: f64,
+        enabled: bool,
+        smoothing: f64,
+        scale: f64,
+        hide_after_idle_seconds: f64,
+    ) -> CursorRenderingSettings {
+        CursorRenderingSettings {
+            version,
+            enabled,
+            smoothing,
+            scale,
+            hide_after_idle_seconds,
+        }
+    }
+}
+
+/// Converts the CursorRenderingSettings value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for CursorRenderingSettings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("version".to_string()),
+            Some(self.version.to_string()),
+            Some("enabled".to_string()),
+            Some(self.enabled.to_string()),
+            Some("smoothing".to_string()),
+            Some(self.smoothing.to_string()),
+            Some("scale".to_string()),
+            Some(self.scale.to_string()),
+            Some("hideAfterIdleSeconds".to_string()),
+            Some(self.hide_after_idle_seconds.to_string()),
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a CursorRenderingSettings value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for CursorRenderingSettings {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub version: Vec<f64>,
+            pub enabled: Vec<bool>,
+            pub smoothing: Vec<f64>,
+            pub scale: Vec<f64>,
+            pub hide_after_idle_seconds: Vec<f64>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing CursorRenderingSettings".to_string(),
+                    );
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "version" => intermediate_rep.version.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "enabled" => intermediate_rep.enabled.push(
+                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "smoothing" => intermediate_rep.smoothing.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "scale" => intermediate_rep.scale.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "hideAfterIdleSeconds" => intermediate_rep.hide_after_idle_seconds.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing CursorRenderingSettings".to_string(),
+                        );
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(CursorRenderingSettings {
+            version: intermediate_rep
+                .version
+                .into_iter()
+                .next()
+                .ok_or_else(|| "version missing in CursorRenderingSettings".to_string())?,
+            enabled: intermediate_rep
+                .enabled
+                .into_iter()
+                .next()
+                .ok_or_else(|| "enabled missing in CursorRenderingSettings".to_string())?,
+            smoothing: intermediate_rep
+                .smoothing
+                .into_iter()
+                .next()
+                .ok_or_else(|| "smoothing missing in CursorRenderingSettings".to_string())?,
+            scale: intermediate_rep
+                .scale
+                .into_iter()
+                .next()
+                .ok_or_else(|| "scale missing in CursorRenderingSettings".to_string())?,
+            hide_after_idle_seconds: intermediate_rep
+                .hide_after_idle_seconds
+                .into_iter()
+                .next()
+                .ok_or_else(|| {
+                    "hideAfterIdleSeconds missing in CursorRenderingSettings".to_string()
+                })?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<CursorRenderingSettings> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<CursorRenderingSettings>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<CursorRenderingSettings>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Invalid header value for CursorRenderingSettings - value: {hdr_value} is invalid {e}"#
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<CursorRenderingSettings> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <CursorRenderingSettings as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        r#"Unable to convert header value '{value}' into CursorRenderingSettings - {err}"#
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Unable to convert header: {hdr_value:?} to string: {e}"#
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct DisplaySource {
+    #[serde(rename = "id")]
+    pub id: i32,
+
+    #[serde(rename = "displayName")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub display_name: String,
+
+    #[serde(rename = "isPrimary")]
+    pub is_primary: bool,
+
+    #[serde(rename = "width")]
+    pub width: i32,
+
+    #[serde(rename = "height")]
+    pub height: i32,
+
+    #[serde(rename = "pixelScale")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pixel_scale: Option<f64>,
+
+    #[serde(rename = "refreshHz")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refresh_hz: Option<f64>,
+
+    /// Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "supportedCaptureFrameRates")]
+    pub supported_capture_frame_rates: Vec<f64>,
+}
+
+impl DisplaySource {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(
+        id: i32,
+        display_name: String,
+        is_primary: bool,
+        width: i32,
+        height: i32,
+        supported_capture_frame_rates: Vec<f64>,
+    ) -> DisplaySource {
+        DisplaySource {
+            id,
+            display_name,
+            is_primary,
+            width,
+            height,
+            pixel_scale: None,
+            refresh_hz: None,
+            supported_capture_frame_rates,
+        }
+    }
+}
+
+/// Converts the DisplaySource value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for DisplaySource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("id".to_string()),
+            Some(self.id.to_string()),
+            Some("displayName".to_string()),
+            Some(self.display_name.to_string()),
+            Some("isPrimary".to_string()),
+            Some(self.is_primary.to_string()),
+            Some("width".to_string()),
+            Some(self.width.to_string()),
+            Some("height".to_string()),
+            Some(self.height.to_string()),
+            self.pixel_scale
+                .as_ref()
+                .map(|pixel_scale| ["pixelScale".to_string(), pixel_scale.to_string()].join(",")),
+            self.refresh_hz
+                .as_ref()
+                .map(|refresh_hz| ["refreshHz".to_string(), refresh_hz.to_string()].join(",")),
+            Some("supportedCaptureFrameRates".to_string()),
+            Some(
+                self.supported_capture_frame_rates
+                    .iter()
+                    .map(|x| x.to_string())
+                    .collect::<Vec<_>>()
+                    .join(","),
+            ),
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a DisplaySource value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for DisplaySource {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub id: Vec<i32>,
+            pub display_name: Vec<String>,
+            pub is_primary: Vec<bool>,
+            pub width: Vec<i32>,
+            pub height: Vec<i32>,
+            pub pixel_scale: Vec<f64>,
+            pub refresh_hz: Vec<f64>,
+            pub supported_capture_frame_rates: Vec<Vec<f64>>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing DisplaySource".to_string(),
+                    );
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "id" => intermediate_rep.id.push(
+                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "displayName" => intermediate_rep.display_name.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "isPrimary" => intermediate_rep.is_primary.push(
+                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "width" => intermediate_rep.width.push(
+                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "height" => intermediate_rep.height.push(
+                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "pixelScale" => intermediate_rep.pixel_scale.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "refreshHz" => intermediate_rep.refresh_hz.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    "supportedCaptureFrameRates" => {
+                        return std::result::Result::Err(
+                            "Parsing a container in this style is not supported in DisplaySource"
+                                .to_string(),
+                        );
+                    }
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing DisplaySource".to_string(),
+                        );
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(DisplaySource {
+            id: intermediate_rep
+                .id
+                .into_iter()
+                .next()
+                .ok_or_else(|| "id missing in DisplaySource".to_string())?,
+            display_name: intermediate_rep
+                .display_name
+                .into_iter()
+                .next()
+                .ok_or_else(|| "displayName missing in DisplaySource".to_string())?,
+            is_primary: intermediate_rep
+                .is_primary
+                .into_iter()
+                .next()
+                .ok_or_else(|| "isPrimary missing in DisplaySource".to_string())?,
+            width: intermediate_rep
+                .width
+                .into_iter()
+                .next()
+                .ok_or_else(|| "width missing in DisplaySource".to_string())?,
+            height: intermediate_rep
+                .height
+                .into_iter()
+                .next()
+                .ok_or_else(|| "height missing in DisplaySource".to_string())?,
+            pixel_scale: intermediate_rep.pixel_scale.into_iter().next(),
+            refresh_hz: intermediate_rep.refresh_hz.into_iter().next(),
+            supported_capture_frame_rates: intermediate_rep
+                .supported_capture_frame_rates
+                .into_iter()
+                .next()
+                .ok_or_else(|| "supportedCaptureFrameRates missing in DisplaySource".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<DisplaySource> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<DisplaySource>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<DisplaySource>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Invalid header value for DisplaySource - value: {hdr_value} is invalid {e}"#
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<DisplaySource> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <DisplaySource as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        r#"Unable to convert header value '{value}' into DisplaySource - {err}"#
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Unable to convert header: {hdr_value:?} to string: {e}"#
+            )),
+        }
+    }
+}
+
+/// EngineBadRequestError response body.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct EngineBadRequestError {
+    /// Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "code")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub code: String,
+
+    #[serde(rename = "message")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub message: String,
+}
+
+impl EngineBadRequestError {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(code: String, message: String) -> EngineBadRequestError {
+        EngineBadRequestError { code, message }
+    }
+}
+
+/// Converts the EngineBadRequestError value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for EngineBadRequestError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("code".to_string()),
+            Some(self.code.to_string()),
+            Some("message".to_string()),
+            Some(self.message.to_string()),
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a EngineBadRequestError value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for EngineBadRequestError {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub code: Vec<String>,
+            pub message: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing EngineBadRequestError".to_string(),
+                    );
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "code" => intermediate_rep.code.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "message" => intermediate_rep.message.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing EngineBadRequestError".to_string(),
+                        );
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(EngineBadRequestError {
+            code: intermediate_rep
+                .code
+                .into_iter()
+                .next()
+                .ok_or_else(|| "code missing in EngineBadRequestError".to_string())?,
+            message: intermediate_rep
+                .message
+                .into_iter()
+                .next()
+                .ok_or_else(|| "message missing in EngineBadRequestError".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<EngineBadRequestError> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<EngineBadRequestError>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<EngineBadRequestError>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Invalid header value for EngineBadRequestError - value: {hdr_value} is invalid {e}"#
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<EngineBadRequestError> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <EngineBadRequestError as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        r#"Unable to convert header value '{value}' into EngineBadRequestError - {err}"#
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Unable to convert header: {hdr_value:?} to string: {e}"#
+            )),
+        }
+    }
+}
+
+/// EngineConflictError response body.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct EngineConflictError {
+    /// Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "code")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub code: String,
+
+    #[serde(rename = "message")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub message: String,
+}
+
+impl EngineConflictError {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(code: String, message: String) -> EngineConflictError {
+        EngineConflictError { code, message }
+    }
+}
+
+/// Converts the EngineConflictError value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for EngineConflictError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("code".to_string()),
+            Some(self.code.to_string()),
+            Some("message".to_string()),
+            Some(self.message.to_string()),
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a EngineConflictError value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for EngineConflictError {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub code: Vec<String>,
+            pub message: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing EngineConflictError".to_string(),
+                    );
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "code" => intermediate_rep.code.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "message" => intermediate_rep.message.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing EngineConflictError".to_string(),
+                        );
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(EngineConflictError {
+            code: intermediate_rep
+                .code
+                .into_iter()
+                .next()
+                .ok_or_else(|| "code missing in EngineConflictError".to_string())?,
+            message: intermediate_rep
+                .message
+                .into_iter()
+                .next()
+                .ok_or_else(|| "message missing in EngineConflictError".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<EngineConflictError> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<EngineConflictError>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<EngineConflictError>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Invalid header value for EngineConflictError - value: {hdr_value} is invalid {e}"#
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<EngineConflictError> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <EngineConflictError as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        r#"Unable to convert header value '{value}' into EngineConflictError - {err}"#
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Unable to convert header: {hdr_value:?} to string: {e}"#
+            )),
+        }
+    }
+}
+
+/// EngineForbiddenError response body.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct EngineForbiddenError {
+    /// Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "code")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub code: String,
+
+    #[serde(rename = "message")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub message: String,
+}
+
+impl EngineForbiddenError {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(code: String, message: String) -> EngineForbiddenError {
+        EngineForbiddenError { code, message }
+    }
+}
+
+/// Converts the EngineForbiddenError value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for EngineForbiddenError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("code".to_string()),
+            Some(self.code.to_string()),
+            Some("message".to_string()),
+            Some(self.message.to_string()),
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a EngineForbiddenError value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for EngineForbiddenError {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub code: Vec<String>,
+            pub message: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing EngineForbiddenError".to_string(),
+                    );
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "code" => intermediate_rep.code.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "message" => intermediate_rep.message.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing EngineForbiddenError".to_string(),
+                        );
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(EngineForbiddenError {
+            code: intermediate_rep
+                .code
+                .into_iter()
+                .next()
+                .ok_or_else(|| "code missing in EngineForbiddenError".to_string())?,
+            message: intermediate_rep
+                .message
+                .into_iter()
+                .next()
+                .ok_or_else(|| "message missing in EngineForbiddenError".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<EngineForbiddenError> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<EngineForbiddenError>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<EngineForbiddenError>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Invalid header value for EngineForbiddenError - value: {hdr_value} is invalid {e}"#
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<EngineForbiddenError> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <EngineForbiddenError as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        r#"Unable to convert header value '{value}' into EngineForbiddenError - {err}"#
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Unable to convert header: {hdr_value:?} to string: {e}"#
+            )),
+        }
+    }
+}
+
+/// EngineNotFoundError response body.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct EngineNotFoundError {
+    /// Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "code")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub code: String,
+
+    #[serde(rename = "message")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub message: String,
+}
+
+impl EngineNotFoundError {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(code: String, message: String) -> EngineNotFoundError {
+        EngineNotFoundError { code, message }
+    }
+}
+
+/// Converts the EngineNotFoundError value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for EngineNotFoundError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("code".to_string()),
+            Some(self.code.to_string()),
+            Some("message".to_string()),
+            Some(self.message.to_string()),
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a EngineNotFoundError value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for EngineNotFoundError {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub code: Vec<String>,
+            pub message: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing EngineNotFoundError".to_string(),
+                    );
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "code" => intermediate_rep.code.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "message" => intermediate_rep.message.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing EngineNotFoundError".to_string(),
+                        );
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(EngineNotFoundError {
+            code: intermediate_rep
+                .code
+                .into_iter()
+                .next()
+                .ok_or_else(|| "code missing in EngineNotFoundError".to_string())?,
+            message: intermediate_rep
+                .message
+                .into_iter()
+                .next()
+                .ok_or_else(|| "message missing in EngineNotFoundError".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<EngineNotFoundError> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<EngineNotFoundError>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<EngineNotFoundError>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Invalid header value for EngineNotFoundError - value: {hdr_value} is invalid {e}"#
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<EngineNotFoundError> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <EngineNotFoundError as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        r#"Unable to convert header value '{value}' into EngineNotFoundError - {err}"#
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Unable to convert header: {hdr_value:?} to string: {e}"#
+            )),
+        }
+    }
+}
+
+/// EngineRuntimeError response body.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct EngineRuntimeError {
+    /// Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "code")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub code: String,
+
+    #[serde(rename = "message")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub message: String,
+}
+
+impl EngineRuntimeError {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(code: String, message: String) -> EngineRuntimeError {
+        EngineRuntimeError { code, message }
+    }
+}
+
+/// Converts the EngineRuntimeError value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for EngineRuntimeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("code".to_string()),
+            Some(self.code.to_string()),
+            Some("message".to_string()),
+            Some(self.message.to_string()),
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a EngineRuntimeError value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for EngineRuntimeError {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub code: Vec<String>,
+            pub message: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing EngineRuntimeError".to_string(),
+                    );
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "code" => intermediate_rep.code.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "message" => intermediate_rep.message.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing EngineRuntimeError".to_string(),
+                        );
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(EngineRuntimeError {
+            code: intermediate_rep
+                .code
+                .into_iter()
+                .next()
+                .ok_or_else(|| "code missing in EngineRuntimeError".to_string())?,
+            message: intermediate_rep
+                .message
+                .into_iter()
+                .next()
+                .ok_or_else(|| "message missing in EngineRuntimeError".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<EngineRuntimeError> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<EngineRuntimeError>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<EngineRuntimeError>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Invalid header value for EngineRuntimeError - value: {hdr_value} is invalid {e}"#
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<EngineRuntimeError> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <EngineRuntimeError as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        r#"Unable to convert header value '{value}' into EngineRuntimeError - {err}"#
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Unable to convert header: {hdr_value:?} to string: {e}"#
+            )),
+        }
+    }
+}
+
+/// EngineUnauthorizedError response body.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct EngineUnauthorizedError {
+    /// Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "code")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub code: String,
+
+    #[serde(rename = "message")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub message: String,
+}
+
+impl EngineUnauthorizedError {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(code: String, message: String) -> EngineUnauthorizedError {
+        EngineUnauthorizedError { code, message }
+    }
+}
+
+/// Converts the EngineUnauthorizedError value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for EngineUnauthorizedError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("code".to_string()),
+            Some(self.code.to_string()),
+            Some("message".to_string()),
+            Some(self.message.to_string()),
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a EngineUnauthorizedError value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for EngineUnauthorizedError {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub code: Vec<String>,
+            pub message: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing EngineUnauthorizedError".to_string(),
+                    );
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "code" => intermediate_rep.code.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "message" => intermediate_rep.message.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing EngineUnauthorizedError".to_string(),
+                        );
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(EngineUnauthorizedError {
+            code: intermediate_rep
+                .code
+                .into_iter()
+                .next()
+                .ok_or_else(|| "code missing in EngineUnauthorizedError".to_string())?,
+            message: intermediate_rep
+                .message
+                .into_iter()
+                .next()
+                .ok_or_else(|| "message missing in EngineUnauthorizedError".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<EngineUnauthorizedError> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<EngineUnauthorizedError>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<EngineUnauthorizedError>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Invalid header value for EngineUnauthorizedError - value: {hdr_value} is invalid {e}"#
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<EngineUnauthorizedError> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <EngineUnauthorizedError as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        r#"Unable to convert header value '{value}' into EngineUnauthorizedError - {err}"#
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Unable to convert header: {hdr_value:?} to string: {e}"#
+            )),
+        }
+    }
+}
+
+/// EngineUnprocessableError response body.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct EngineUnprocessableError {
+    /// Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "code")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub code: String,
+
+    #[serde(rename = "message")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub message: String,
+}
+
+impl EngineUnprocessableError {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(code: String, message: String) -> EngineUnprocessableError {
+        EngineUnprocessableError { code, message }
+    }
+}
+
+/// Converts the EngineUnprocessableError value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for EngineUnprocessableError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("code".to_string()),
+            Some(self.code.to_string()),
+            Some("message".to_string()),
+            Some(self.message.to_string()),
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a EngineUnprocessableError value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for EngineUnprocessableError {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub code: Vec<String>,
+            pub message: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing EngineUnprocessableError".to_string(),
+                    );
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "code" => intermediate_rep.code.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "message" => intermediate_rep.message.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing EngineUnprocessableError".to_string(),
+                        );
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(EngineUnprocessableError {
+            code: intermediate_rep
+                .code
+                .into_iter()
+                .next()
+                .ok_or_else(|| "code missing in EngineUnprocessableError".to_string())?,
+            message: intermediate_rep
+                .message
+                .into_iter()
+                .next()
+                .ok_or_else(|| "message missing in EngineUnprocessableError".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<EngineUnprocessableError> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<EngineUnprocessableError>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<EngineUnprocessableError>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Invalid header value for EngineUnprocessableError - value: {hdr_value} is invalid {e}"#
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<EngineUnprocessableError> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <EngineUnprocessableError as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        r#"Unable to convert header value '{value}' into EngineUnprocessableError - {err}"#
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Unable to convert header: {hdr_value:?} to string: {e}"#
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct ExportGuidePayload {
+    #[serde(rename = "outputURL")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub output_url: String,
+
+    #[serde(rename = "title")]
+    #[validate(custom(function = "check_xss_string"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    #[serde(rename = "importedTranscriptPath")]
+    #[validate(custom(function = "check_xss_string"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub imported_transcript_path: Option<String>,
+
+    #[serde(rename = "zoom")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub zoom: Option<f64>,
+
+    #[serde(rename = "highlightColor")]
+    #[validate(custom(function = "check_xss_string"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub highlight_color: Option<String>,
+}
+
+impl ExportGuidePayload {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(output_url: String) -> ExportGuidePayload {
+        ExportGuidePayload {
+            output_url,
+            title: None,
+            imported_transcript_path: None,
+            zoom: None,
+            highlight_color: None,
+        }
+    }
+}
+
+/// Converts the ExportGuidePayload value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for ExportGuidePayload {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("outputURL".to_string()),
+            Some(self.output_url.to_string()),
+            self.title
+                .as_ref()
+                .map(|title| ["title".to_string(), title.to_string()].join(",")),
+            self.imported_transcript_path
+                .as_ref()
+                .map(|imported_transcript_path| {
+                    [
+                        "importedTranscriptPath".to_string(),
+                        imported_transcript_path.to_string(),
+                    ]
+                    .join(",")
+                }),
+            self.zoom
+                .as_ref()
+                .map(|zoom| ["zoom".to_string(), zoom.to_string()].join(",")),
+            self.highlight_color.as_ref().map(|highlight_color| {
+                ["highlightColor".to_string(), highlight_color.to_string()].join(",")
+            }),
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a ExportGuidePayload value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for ExportGuidePayload {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub output_url: Vec<String>,
+            pub title: Vec<String>,
+            pub imported_transcript_path: Vec<String>,
+            pub zoom: Vec<f64>,
+            pub highlight_color: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing ExportGuidePayload".to_string(),
+                    );
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "outputURL" => intermediate_rep.output_url.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "title" => intermediate_rep.title.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "importedTranscriptPath" => intermediate_rep.imported_transcript_path.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "zoom" => intermediate_rep.zoom.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "highlightColor" => intermediate_rep.highlight_color.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing ExportGuidePayload".to_string(),
+                        );
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(ExportGuidePayload {
+            output_url: intermediate_rep
+                .output_url
+                .into_iter()
+                .next()
+                .ok_or_else(|| "outputURL missing in ExportGuidePayload".to_string())?,
+            title: intermediate_rep.title.into_iter().next(),
+            imported_transcript_path: intermediate_rep.imported_transcript_path.into_iter().next(),
+            zoom: intermediate_rep.zoom.into_iter().next(),
+            highlight_color: intermediate_rep.highlight_color.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<ExportGuidePayload> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<ExportGuidePayload>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<ExportGuidePayload>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Invalid header value for ExportGuidePayload - value: {hdr_value} is invalid {e}"#
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<ExportGuidePayload> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <ExportGuidePayload as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        r#"Unable to convert header value '{value}' into ExportGuidePayload - {err}"#
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Unable to convert header: {hdr_value:?} to string: {e}"#
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct ExportGuideResult {
+    #[serde(rename = "outputURL")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub output_url: String,
+
+    #[serde(rename = "markdownURL")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub markdown_url: String,
+
+    #[serde(rename = "htmlURL")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub html_url: String,
+
+    #[serde(rename = "title")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub title: String,
+
+    /// Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "source")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub source: String,
+
+    #[serde(rename = "steps")]
+    #[validate(nested)]
+    pub steps: Vec<models::GuideStep>,
+}
+
+impl ExportGuideResult {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(
+        output_url: String,
+        markdown_url: String,
+        html_url: String,
+        title: String,
+        source: String,
+        steps: Vec<models::GuideStep>,
+    ) -> ExportGuideResult {
+        ExportGuideResult {
+            output_url,
+            markdown_url,
+            html_url,
+            title,
+            source,
+            steps,
+        }
+    }
+}
+
+/// Converts the ExportGuideResult value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for ExportGuideResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("outputURL".to_string()),
+            Some(self.output_url.to_string()),
+            Some("markdownURL".to_string()),
+            Some(self.markdown_url.to_string()),
+            Some("htmlURL".to_string()),
+            Some(self.html_url.to_string()),
+            Some("title".to_string()),
+            Some(self.title.to_string()),
+            Some("source".to_string()),
+            Some(self.source.to_string()),
+            // Skipping steps in query parameter serialization
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a ExportGuideResult value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for ExportGuideResult {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub output_url: Vec<String>,
+            pub markdown_url: Vec<String>,
+            pub html_url: Vec<String>,
+            pub title: Vec<String>,
+            pub source: Vec<String>,
+            pub steps: Vec<Vec<models::GuideStep>>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing ExportGuideResult".to_string(),
+                    );
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "outputURL" => intermediate_rep.output_url.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "markdownURL" => intermediate_rep.markdown_url.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "htmlURL" => intermediate_rep.html_url.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "title" => intermediate_rep.title.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "source" => intermediate_rep.source.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    "steps" => return std::result::Result::Err(
+                        "Parsing a container in this style is not supported in ExportGuideResult"
+                            .to_string(),
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing ExportGuideResult".to_string(),
+                        );
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(ExportGuideResult {
+            output_url: intermediate_rep
+                .output_url
+                .into_iter()
+                .next()
+                .ok_or_else(|| "outputURL missing in ExportGuideResult".to_string())?,
+            markdown_url: intermediate_rep
+                .markdown_url
+                .into_iter()
+                .next()
+                .ok_or_else(|| "markdownURL missing in ExportGuideResult".to_string())?,
+            html_url: intermediate_rep
+                .html_url
+                .into_iter()
+                .next()
+                .ok_or_else(|| "htmlURL missing in ExportGuideResult".to_string())?,
+            title: intermediate_rep
+                .title
+                .into_iter()
+                .next()
+                .ok_or_else(|| "title missing in ExportGuideResult".to_string())?,
+            source: intermediate_rep
+                .source
+                .into_iter()
+                .next()
+                .ok_or_else(|| "source missing in ExportGuideResult".to_string())?,
+            steps: intermediate_rep
+                .steps
+                .into_iter()
+                .next()
+                .ok_or_else(|| "steps missing in ExportGuideResult".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<ExportGuideResult> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<ExportGuideResult>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<ExportGuideResult>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Invalid header value for ExportGuideResult - value: {hdr_value} is invalid {e}"#
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<ExportGuideResult> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <ExportGuideResult as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        r#"Unable to convert header value '{value}' into ExportGuideResult - {err}"#
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Unable to convert header: {hdr_value:?} to string: {e}"#
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct ExportInfoResult {
+    #[serde(rename = "presets")]
+    #[validate(nested)]
+    pub presets: Vec<models::ExportPreset>,
+}
+
+impl ExportInfoResult {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(presets: Vec<models::ExportPreset>) -> ExportInfoResult {
+        ExportInfoResult { presets }
+    }
+}
+
+/// Converts the ExportInfoResult value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for ExportInfoResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            // Skipping presets in query parameter serialization
+
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a ExportInfoResult value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for ExportInfoResult {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub presets: Vec<Vec<models::ExportPreset>>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing ExportInfoResult".to_string(),
+                    );
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    "presets" => return std::result::Result::Err(
+                        "Parsing a container in this style is not supported in ExportInfoResult"
+                            .to_string(),
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing ExportInfoResult".to_string(),
+                        );
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(ExportInfoResult {
+            presets: intermediate_rep
+                .presets
+                .into_iter()
+                .next()
+                .ok_or_else(|| "presets missing in ExportInfoResult".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<ExportInfoResult> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<ExportInfoResult>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<ExportInfoResult>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Invalid header value for ExportInfoResult - value: {hdr_value} is invalid {e}"#
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<ExportInfoResult> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <ExportInfoResult as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        r#"Unable to convert header value '{value}' into ExportInfoResult - {err}"#
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Unable to convert header: {hdr_value:?} to string: {e}"#
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct ExportPreset {
+    #[serde(rename = "id")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub id: String,
+
+    #[serde(rename = "name")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub name: String,
+
+    #[serde(rename = "width")]
+    pub width: i32,
+
+    #[serde(rename = "height")]
+    pub height: i32,
+
+    #[serde(rename = "fps")]
+    pub fps: i32,
+
+    /// Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "fileType")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub file_type: String,
+}
+
+impl ExportPreset {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(
+        id: String,
+        name: String,
+        width: i32,
+        height: i32,
+        fps: i32,
+        file_type: String,
+    ) -> ExportPreset {
+        ExportPreset {
+            id,
+            name,
+            width,
+            height,
+            fps,
+            file_type,
+        }
+    }
+}
+
+/// Converts the ExportPreset value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for ExportPreset {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("id".to_string()),
+            Some(self.id.to_string()),
+            Some("name".to_string()),
+            Some(self.name.to_string()),
+            Some("width".to_string()),
+            Some(self.width.to_string()),
+            Some("height".to_string()),
+            Some(self.height.to_string()),
+            Some("fps".to_string()),
+            Some(self.fps.to_string()),
+            Some("fileType".to_string()),
+            Some(self.file_type.to_string()),
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a ExportPreset value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for ExportPreset {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub id: Vec<String>,
+            pub name: Vec<String>,
+            pub width: Vec<i32>,
+            pub height: Vec<i32>,
+            pub fps: Vec<i32>,
+            pub file_type: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing ExportPreset".to_string(),
+                    );
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "id" => intermediate_rep.id.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "name" => intermediate_rep.name.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "width" => intermediate_rep.width.push(
+                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "height" => intermediate_rep.height.push(
+                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "fps" => intermediate_rep.fps.push(
+                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "fileType" => intermediate_rep.file_type.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing ExportPreset".to_string(),
+                        );
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(ExportPreset {
+            id: intermediate_rep
+                .id
+                .into_iter()
+                .next()
+                .ok_or_else(|| "id missing in ExportPreset".to_string())?,
+            name: intermediate_rep
+                .name
+                .into_iter()
+                .next()
+                .ok_or_else(|| "name missing in ExportPreset".to_string())?,
+            width: intermediate_rep
+                .width
+                .into_iter()
+                .next()
+                .ok_or_else(|| "width missing in ExportPreset".to_string())?,
+            height: intermediate_rep
+                .height
+                .into_iter()
+                .next()
+                .ok_or_else(|| "height missing in ExportPreset".to_string())?,
+            fps: intermediate_rep
+                .fps
+                .into_iter()
+                .next()
+                .ok_or_else(|| "fps missing in ExportPreset".to_string())?,
+            file_type: intermediate_rep
+                .file_type
+                .into_iter()
+                .next()
+                .ok_or_else(|| "fileType missing in ExportPreset".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<ExportPreset> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<ExportPreset>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<ExportPreset>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Invalid header value for ExportPreset - value: {hdr_value} is invalid {e}"#
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<ExportPreset> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <ExportPreset as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        r#"Unable to convert header value '{value}' into ExportPreset - {err}"#
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Unable to convert header: {hdr_value:?} to string: {e}"#
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct ExportRunCutPlanPayload {
+    #[serde(rename = "outputURL")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub output_url: String,
+
+    #[serde(rename = "presetId")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub preset_id: String,
+
+    #[serde(rename = "jobId")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub job_id: String,
+}
+
+impl ExportRunCutPlanPayload {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(output_url: String, preset_id: String, job_id: String) -> ExportRunCutPlanPayload {
+        ExportRunCutPlanPayload {
+            output_url,
+            preset_id,
+            job_id,
+        }
+    }
+}
+
+/// Converts the ExportRunCutPlanPayload value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for ExportRunCutPlanPayload {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("outputURL".to_string()),
+            Some(self.output_url.to_string()),
+            Some("presetId".to_string()),
+            Some(self.preset_id.to_string()),
+            Some("jobId".to_string()),
+            Some(self.job_id.to_string()),
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a ExportRunCutPlanPayload value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for ExportRunCutPlanPayload {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub output_url: Vec<String>,
+            pub preset_id: Vec<String>,
+            pub job_id: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing ExportRunCutPlanPayload".to_string(),
+                    );
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "outputURL" => intermediate_rep.output_url.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "presetId" => intermediate_rep.preset_id.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "jobId" => intermediate_rep.job_id.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing ExportRunCutPlanPayload".to_string(),
+                        );
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(ExportRunCutPlanPayload {
+            output_url: intermediate_rep
+                .output_url
+                .into_iter()
+                .next()
+                .ok_or_else(|| "outputURL missing in ExportRunCutPlanPayload".to_string())?,
+            preset_id: intermediate_rep
+                .preset_id
+                .into_iter()
+                .next()
+                .ok_or_else(|| "presetId missing in ExportRunCutPlanPayload".to_string())?,
+            job_id: intermediate_rep
+                .job_id
+                .into_iter()
+                .next()
+                .ok_or_else(|| "jobId missing in ExportRunCutPlanPayload".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<ExportRunCutPlanPayload> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<ExportRunCutPlanPayload>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<ExportRunCutPlanPayload>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Invalid header value for ExportRunCutPlanPayload - value: {hdr_value} is invalid {e}"#
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<ExportRunCutPlanPayload> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <ExportRunCutPlanPayload as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        r#"Unable to convert header value '{value}' into ExportRunCutPlanPayload - {err}"#
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Unable to convert header: {hdr_value:?} to string: {e}"#
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct ExportRunCutPlanResult {
+    #[serde(rename = "jobId")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub job_id: String,
+
+    /// Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "status")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub status: String,
+
+    #[serde(rename = "outputURL")]
+    #[validate(custom(function = "check_xss_string"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output_url: Option<String>,
+
+    #[serde(rename = "appliedSegments")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub applied_segments: Option<i32>,
+}
+
+impl ExportRunCutPlanResult {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(job_id: String, status: String) -> ExportRunCutPlanResult {
+        ExportRunCutPlanResult {
+            job_id,
+            status,
+            output_url: None,
+            applied_segments: None,
+        }
+    }
+}
+
+/// Converts the ExportRunCutPlanResult value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for ExportRunCutPlanResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("jobId".to_string()),
+            Some(self.job_id.to_string()),
+            Some("status".to_string()),
+            Some(self.status.to_string()),
+            self.output_url
+                .as_ref()
+                .map(|output_url| ["outputURL".to_string(), output_url.to_string()].join(",")),
+            self.applied_segments.as_ref().map(|applied_segments| {
+                ["appliedSegments".to_string(), applied_segments.to_string()].join(",")
+            }),
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a ExportRunCutPlanResult value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for ExportRunCutPlanResult {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub job_id: Vec<String>,
+            pub status: Vec<String>,
+            pub output_url: Vec<String>,
+            pub applied_segments: Vec<i32>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing ExportRunCutPlanResult".to_string(),
+                    );
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "jobId" => intermediate_rep.job_id.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "status" => intermediate_rep.status.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "outputURL" => intermediate_rep.output_url.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "appliedSegments" => intermediate_rep.applied_segments.push(
+                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing ExportRunCutPlanResult".to_string(),
+                        );
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(ExportRunCutPlanResult {
+            job_id: intermediate_rep
+                .job_id
+                .into_iter()
+                .next()
+                .ok_or_else(|| "jobId missing in ExportRunCutPlanResult".to_string())?,
+            status: intermediate_rep
+                .status
+                .into_iter()
+                .next()
+                .ok_or_else(|| "status missing in ExportRunCutPlanResult".to_string())?,
+            output_url: intermediate_rep.output_url.into_iter().next(),
+            applied_segments: intermediate_rep.applied_segments.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<ExportRunCutPlanResult> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<ExportRunCutPlanResult>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<ExportRunCutPlanResult>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Invalid header value for ExportRunCutPlanResult - value: {hdr_value} is invalid {e}"#
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<ExportRunCutPlanResult> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <ExportRunCutPlanResult as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        r#"Unable to convert header value '{value}' into ExportRunCutPlanResult - {err}"#
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Unable to convert header: {hdr_value:?} to string: {e}"#
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct ExportRunPayload {
+    #[serde(rename = "outputURL")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub output_url: String,
+
+    #[serde(rename = "presetId")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub preset_id: String,
+
+    #[serde(rename = "trimStartSeconds")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trim_start_seconds: Option<f64>,
+
+    #[serde(rename = "trimEndSeconds")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trim_end_seconds: Option<f64>,
+
+    #[serde(rename = "timeline")]
+    #[validate(nested)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeline: Option<models::ExportRunPayloadTimeline>,
+
+    #[serde(rename = "autoZoom")]
+    #[validate(nested)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_zoom: Option<models::AutoZoomSettings>,
+
+    #[serde(rename = "backgroundFraming")]
+    #[validate(nested)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub background_framing: Option<models::BackgroundFramingSettings>,
+
+    #[serde(rename = "shortcutOverlay")]
+    #[validate(nested)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shortcut_overlay: Option<models::ShortcutOverlaySettings>,
+
+    #[serde(rename = "pictureInPicture")]
+    #[validate(nested)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub picture_in_picture: Option<models::PictureInPictureSettings>,
+
+    #[serde(rename = "cursorRendering")]
+    #[validate(nested)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cursor_rendering: Option<models::CursorRenderingSettings>,
+
+    #[serde(rename = "clickEffects")]
+    #[validate(nested)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub click_effects: Option<models::ClickEffectsSettings>,
+
+    #[serde(rename = "reframe")]
+    #[validate(nested)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reframe: Option<models::ReframeSettings>,
+}
+
+impl ExportRunPayload {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(output_url: String, preset_id: String) -> ExportRunPayload {
+        ExportRunPayload {
+            output_url,
+            preset_id,
+            trim_start_seconds: None,
+            trim_end_seconds: None,
+            timeline: None,
+            auto_zoom: None,
+            background_framing: None,
+            shortcut_overlay: None,
+            picture_in_picture: None,
+            cursor_rendering: None,
+            click_effects: None,
+            reframe: None,
+        }
+    }
+}
+
+/// Converts the ExportRunPayload value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for ExportRunPayload {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("outputURL".to_string()),
+            Some(self.output_url.to_string()),
+            Some("presetId".to_string()),
+            Some(self.preset_id.to_string()),
+            self.trim_start_seconds.as_ref().map(|trim_start_seconds| {
+                [
+                    "trimStartSeconds".to_string(),
+                    trim_start_seconds.to_string(),
+                ]
+                .join(",")
+            }),
+            self.trim_end_seconds.as_ref().map(|trim_end_seconds| {
+                ["trimEndSeconds".to_string(), trim_end_seconds.to_string()].join(",")
+            }),
+            // Skipping timeline in query parameter serialization
+
+            // Skipping autoZoom in query parameter serialization
+
+            // Skipping backgroundFraming in query parameter serialization
+
+            // Skipping shortcutOverlay in query parameter serialization
+
+            // Skipping pictureInPicture in query parameter serialization
+
+            // Skipping cursorRendering in query parameter serialization
+
+            // Skipping clickEffects in query parameter serialization
+
+            // Skipping reframe in query parameter serialization
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a ExportRunPayload value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for ExportRunPayload {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub output_url: Vec<String>,
+            pub preset_id: Vec<String>,
+            pub trim_start_seconds: Vec<f64>,
+            pub trim_end_seconds: Vec<f64>,
+            pub timeline: Vec<models::ExportRunPayloadTimeline>,
+            pub auto_zoom: Vec<models::AutoZoomSettings>,
+            pub background_framing: Vec<models::BackgroundFramingSettings>,
+            pub shortcut_overlay: Vec<models::ShortcutOverlaySettings>,
+            pub picture_in_picture: Vec<models::PictureInPictureSettings>,
+            pub cursor_rendering: Vec<models::CursorRenderingSettings>,
+            pub click_effects: Vec<models::ClickEffectsSettings>,
+            pub reframe: Vec<models::ReframeSettings>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing ExportRunPayload".to_string(),
+                    );
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "outputURL" => intermediate_rep.output_url.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "presetId" => intermediate_rep.preset_id.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "trimStartSeconds" => intermediate_rep.trim_start_seconds.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "trimEndSeconds" => intermediate_rep.trim_end_seconds.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "timeline" => intermediate_rep.timeline.push(
+                        <models::ExportRunPayloadTimeline as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "autoZoom" => intermediate_rep.auto_zoom.push(
+                        <models::AutoZoomSettings as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "backgroundFraming" => intermediate_rep.background_framing.push(
+                        <models::BackgroundFramingSettings as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "shortcutOverlay" => intermediate_rep.shortcut_overlay.push(
+                        <models::ShortcutOverlaySettings as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "pictureInPicture" => intermediate_rep.picture_in_picture.push(
+                        <models::PictureInPictureSettings as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "cursorRendering" => intermediate_rep.cursor_rendering.push(
+                        <models::CursorRenderingSettings as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "clickEffects" => intermediate_rep.click_effects.push(
+                        <models::ClickEffectsSettings as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "reframe" => intermediate_rep.reframe.push(
+                        <models::ReframeSettings as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing ExportRunPayload".to_string(),
+                        );
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(ExportRunPayload {
+            output_url: intermediate_rep
+                .output_url
+                .into_iter()
+                .next()
+                .ok_or_else(|| "outputURL missing in ExportRunPayload".to_string())?,
+            preset_id: intermediate_rep
+                .preset_id
+                .into_iter()
+                .next()
+                .ok_or_else(|| "presetId missing in ExportRunPayload".to_string())?,
+            trim_start_seconds: intermediate_rep.trim_start_seconds.into_iter().next(),
+            trim_end_seconds: intermediate_rep.trim_end_seconds.into_iter().next(),
+            timeline: intermediate_rep.timeline.into_iter().next(),
+            auto_zoom: intermediate_rep.auto_zoom.into_iter().next(),
+            background_framing: intermediate_rep.background_framing.into_iter().next(),
+            shortcut_overlay: intermediate_rep.shortcut_overlay.into_iter().next(),
+            picture_in_picture: intermediate_rep.picture_in_picture.into_iter().next(),
+            cursor_rendering: intermediate_rep.cursor_rendering.into_iter().next(),
+            click_effects: intermediate_rep.click_effects.into_iter().next(),
+            reframe: intermediate_rep.reframe.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<ExportRunPayload> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<ExportRunPayload>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<ExportRunPayload>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Invalid header value for ExportRunPayload - value: {hdr_value} is invalid {e}"#
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<ExportRunPayload> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <ExportRunPayload as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        r#"Unable to convert header value '{value}' into ExportRunPayload - {err}"#
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Unable to convert header: {hdr_value:?} to string: {e}"#
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct ExportRunPayloadTimeline {
+    /// Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "version")]
+    pub version: f64,
+
+    #[serde(rename = "items")]
+    #[validate(nested)]
+    pub items: Vec<models::ExportRunPayloadTimelineItemsInner>,
+
+    #[serde(rename = "updatedAt")]
+    #[validate(custom(function = "check_xss_string"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+}
+
+impl ExportRunPayloadTimeline {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(
+        version: f64,
+        items: Vec<models::ExportRunPayloadTimelineItemsInner>,
+    ) -> ExportRunPayloadTimeline {
+        ExportRunPayloadTimeline {
+            version,
+            items,
+            updated_at: None,
+        }
+    }
+}
+
+/// Converts the ExportRunPayloadTimeline value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for ExportRunPayloadTimeline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("version".to_string()),
+            Some(self.version.to_string()),
+            // Skipping items in query parameter serialization
+            self.updated_at
+                .as_ref()
+                .map(|updated_at| ["updatedAt".to_string(), updated_at.to_string()].join(",")),
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a ExportRunPayloadTimeline value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for ExportRunPayloadTimeline {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub version: Vec<f64>,
+            pub items: Vec<Vec<models::ExportRunPayloadTimelineItemsInner>>,
+            pub updated_at: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing ExportRunPayloadTimeline".to_string(),
+                    );
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "version" => intermediate_rep.version.push(<f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    "items" => return std::result::Result::Err("Parsing a container in this style is not supported in ExportRunPayloadTimeline".to_string()),
+                    #[allow(clippy::redundant_clone)]
+                    "updatedAt" => intermediate_rep.updated_at.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing ExportRunPayloadTimeline".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(ExportRunPayloadTimeline {
+            version: intermediate_rep
+                .version
+                .into_iter()
+                .next()
+                .ok_or_else(|| "version missing in ExportRunPayloadTimeline".to_string())?,
+            items: intermediate_rep
+                .items
+                .into_iter()
+                .next()
+                .ok_or_else(|| "items missing in ExportRunPayloadTimeline".to_string())?,
+            updated_at: intermediate_rep.updated_at.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<ExportRunPayloadTimeline> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<ExportRunPayloadTimeline>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<ExportRunPayloadTimeline>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Invalid header value for ExportRunPayloadTimeline - value: {hdr_value} is invalid {e}"#
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<ExportRunPayloadTimeline> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <ExportRunPayloadTimeline as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        r#"Unable to convert header value '{value}' into ExportRunPayloadTimeline - {err}"#
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Unable to convert header: {hdr_value:?} to string: {e}"#
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(untagged)]
+#[allow(non_camel_case_types, clippy::large_enum_variant)]
+pub enum ExportRunPayloadTimelineItemsInner {
+    ExportRunPayloadTimelineItemsInnerAnyOf(models::ExportRunPayloadTimelineItemsInnerAnyOf),
+    ExportRunPayloadTimelineItemsInnerAnyOf1(models::ExportRunPayloadTimelineItemsInnerAnyOf1),
+}
+
+impl validator::Validate for ExportRunPayloadTimelineItemsInner {
+    fn validate(&self) -> std::result::Result<(), validator::ValidationErrors> {
+        match self {
+            Self::ExportRunPayloadTimelineItemsInnerAnyOf(v) => v.validate(),
+            Self::ExportRunPayloadTimelineItemsInnerAnyOf1(v) => v.validate(),
+        }
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a ExportRunPayloadTimelineItemsInner value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for ExportRunPayloadTimelineItemsInner {
+    type Err = serde_json::Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        serde_json::from_str(s)
+    }
+}
+
+impl From<models::ExportRunPayloadTimelineItemsInnerAnyOf> for ExportRunPayloadTimelineItemsInner {
+    fn from(value: models::ExportRunPayloadTimelineItemsInnerAnyOf) -> Self {
+        Self::ExportRunPayloadTimelineItemsInnerAnyOf(value)
+    }
+}
+impl From<models::ExportRunPayloadTimelineItemsInnerAnyOf1> for ExportRunPayloadTimelineItemsInner {
+    fn from(value: models::ExportRunPayloadTimelineItemsInnerAnyOf1) -> Self {
+        Self::ExportRunPayloadTimelineItemsInnerAnyOf1(value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct ExportRunPayloadTimelineItemsInnerAnyOf {
+    /// Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "kind")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub kind: String,
+
+    #[serde(rename = "id")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub id: String,
+
+    /// Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "sourceAssetId")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub source_asset_id: String,
+
+    #[serde(rename = "sourceStartSeconds")]
+    pub source_start_seconds: f64,
+
+    #[serde(rename = "sourceEndSeconds")]
+    pub source_end_seconds: f64,
+
+    #[serde(rename = "playbackRate")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub playback_rate: Option<f64>,
+
+    #[serde(rename = "rampInSeconds")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ramp_in_seconds: Option<f64>,
+
+    #[serde(rename = "rampOutSeconds")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ramp_out_seconds: Option<f64>,
+
+    /// Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "speedAudio")]
+    #[validate(custom(function = "check_xss_string"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub speed_audio: Option<String>,
+}
+
+impl ExportRunPayloadTimelineItemsInnerAnyOf {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(
+        kind: String,
+        id: String,
+        source_asset_id: String,
+        source_start_seconds: f64,
+        source_end_seconds: f64,
+    ) -> ExportRunPayloadTimelineItemsInnerAnyOf {
+        ExportRunPayloadTimelineItemsInnerAnyOf {
+            kind,
+            id,
+            source_asset_id,
+            source_start_seconds,
+            source_end_seconds,
+            playback_rate: None,
+            ramp_in_seconds: None,
+            ramp_out_seconds: None,
+            speed_audio: None,
+        }
+    }
+}
+
+/// Converts the ExportRunPayloadTimelineItemsInnerAnyOf value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for ExportRunPayloadTimelineItemsInnerAnyOf {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("kind".to_string()),
+            Some(self.kind.to_string()),
+            Some("id".to_string()),
+            Some(self.id.to_string()),
+            Some("sourceAssetId".to_string()),
+            Some(self.source_asset_id.to_string()),
+            Some("sourceStartSeconds".to_string()),
+            Some(self.source_start_seconds.to_string()),
+            Some("sourceEndSeconds".to_string()),
+            Some(self.source_end_seconds.to_string()),
+            self.playback_rate.as_ref().map(|playback_rate| {
+                ["playbackRate".to_string(), playback_rate.to_string()].join(",")
+            }),
+            self.ramp_in_seconds.as_ref().map(|ramp_in_seconds| {
+                ["rampInSeconds".to_string(), ramp_in_seconds.to_string()].join(",")
+            }),
+            self.ramp_out_seconds.as_ref().map(|ramp_out_seconds| {
+                ["rampOutSeconds".to_string(), ramp_out_seconds.to_string()].join(",")
+            }),
+            self.speed_audio
+                .as_ref()
+                .map(|speed_audio| ["speedAudio".to_string(), speed_audio.to_string()].join(",")),
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a ExportRunPayloadTimelineItemsInnerAnyOf value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for ExportRunPayloadTimelineItemsInnerAnyOf {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub kind: Vec<String>,
+            pub id: Vec<String>,
+            pub source_asset_id: Vec<String>,
+            pub source_start_seconds: Vec<f64>,
+            pub source_end_seconds: Vec<f64>,
+            pub playback_rate: Vec<f64>,
+            pub ramp_in_seconds: Vec<f64>,
+            pub ramp_out_seconds: Vec<f64>,
+            pub speed_audio: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing ExportRunPayloadTimelineItemsInnerAnyOf"
+                            .to_string(),
+                    );
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "kind" => intermediate_rep.kind.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "id" => intermediate_rep.id.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "sourceAssetId" => intermediate_rep.source_asset_id.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "sourceStartSeconds" => intermediate_rep.source_start_seconds.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "sourceEndSeconds" => intermediate_rep.source_end_seconds.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "playbackRate" => intermediate_rep.playback_rate.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "rampInSeconds" => intermediate_rep.ramp_in_seconds.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "rampOutSeconds" => intermediate_rep.ramp_out_seconds.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "speedAudio" => intermediate_rep.speed_audio.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing ExportRunPayloadTimelineItemsInnerAnyOf"
+                                .to_string(),
+                        );
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(ExportRunPayloadTimelineItemsInnerAnyOf {
+            kind: intermediate_rep.kind.into_iter().next().ok_or_else(|| {
+                "kind missing in ExportRunPayloadTimelineItemsInnerAnyOf".to_string()
+            })?,
+            id: intermediate_rep.id.into_iter().next().ok_or_else(|| {
+                "id missing in ExportRunPayloadTimelineItemsInnerAnyOf".to_string()
+            })?,
+            source_asset_id: intermediate_rep
+                .source_asset_id
+                .into_iter()
+                .next()
+                .ok_or_else(|| {
+                    "sourceAssetId missing in ExportRunPayloadTimelineItemsInnerAnyOf".to_string()
+                })?,
+            source_start_seconds: intermediate_rep
+                .source_start_seconds
+                .into_iter()
+                .next()
+                .ok_or_else(|| {
+                    "sourceStartSeconds missing in ExportRunPayloadTimelineItemsInnerAnyOf"
+                        .to_string()
+                })?,
+            source_end_seconds: intermediate_rep
+                .source_end_seconds
+                .into_iter()
+                .next()
+                .ok_or_else(|| {
+                    "sourceEndSeconds missing in ExportRunPayloadTimelineItemsInnerAnyOf"
+                        .to_string()
+                })?,
+            playback_rate: intermediate_rep.playback_rate.into_iter().next(),
+            ramp_in_seconds: intermediate_rep.ramp_in_seconds.into_iter().next(),
+            ramp_out_seconds: intermediate_rep.ramp_out_seconds.into_iter().next(),
+            speed_audio: intermediate_rep.speed_audio.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<ExportRunPayloadTimelineItemsInnerAnyOf> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<ExportRunPayloadTimelineItemsInnerAnyOf>>
+    for HeaderValue
+{
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<ExportRunPayloadTimelineItemsInnerAnyOf>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Invalid header value for ExportRunPayloadTimelineItemsInnerAnyOf - value: {hdr_value} is invalid {e}"#
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue>
+    for header::IntoHeaderValue<ExportRunPayloadTimelineItemsInnerAnyOf>
+{
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <ExportRunPayloadTimelineItemsInnerAnyOf as std::str::FromStr>::from_str(
+                    value,
+                ) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        r#"Unable to convert header value '{value}' into ExportRunPayloadTimelineItemsInnerAnyOf - {err}"#
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Unable to convert header: {hdr_value:?} to string: {e}"#
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct ExportRunPayloadTimelineItemsInnerAnyOf1 {
+    /// Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "kind")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub kind: String,
+
+    #[serde(rename = "id")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub id: String,
+
+    #[serde(rename = "durationSeconds")]
+    pub duration_seconds: f64,
+}
+
+impl ExportRunPayloadTimelineItemsInnerAnyOf1 {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(
+        kind: String,
+        id: String,
+        duration_seconds: f64,
+    ) -> ExportRunPayloadTimelineItemsInnerAnyOf1 {
+        ExportRunPayloadTimelineItemsInnerAnyOf1 {
+            kind,
+            id,
+            duration_seconds,
+        }
+    }
+}
+
+/// Converts the ExportRunPayloadTimelineItemsInnerAnyOf1 value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for ExportRunPayloadTimelineItemsInnerAnyOf1 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("kind".to_string()),
+            Some(self.kind.to_string()),
+            Some("id".to_string()),
+            Some(self.id.to_string()),
+            Some("durationSeconds".to_string()),
+            Some(self.duration_seconds.to_string()),
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a ExportRunPayloadTimelineItemsInnerAnyOf1 value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for ExportRunPayloadTimelineItemsInnerAnyOf1 {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub kind: Vec<String>,
+            pub id: Vec<String>,
+            pub duration_seconds: Vec<f64>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing ExportRunPayloadTimelineItemsInnerAnyOf1"
+                            .to_string(),
+                    );
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "kind" => intermediate_rep.kind.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "id" => intermediate_rep.id.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "durationSeconds" => intermediate_rep.duration_seconds.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing ExportRunPayloadTimelineItemsInnerAnyOf1"
+                                .to_string(),
+                        );
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(ExportRunPayloadTimelineItemsInnerAnyOf1 {
+            kind: intermediate_rep.kind.into_iter().next().ok_or_else(|| {
+                "kind missing in ExportRunPayloadTimelineItemsInnerAnyOf1".to_string()
+            })?,
+            id: intermediate_rep.id.into_iter().next().ok_or_else(|| {
+                "id missing in ExportRunPayloadTimelineItemsInnerAnyOf1".to_string()
+            })?,
+            duration_seconds: intermediate_rep
+                .duration_seconds
+                .into_iter()
+                .next()
+                .ok_or_else(|| {
+                    "durationSeconds missing in ExportRunPayloadTimelineItemsInnerAnyOf1"
+                        .to_string()
+                })?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<ExportRunPayloadTimelineItemsInnerAnyOf1> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<ExportRunPayloadTimelineItemsInnerAnyOf1>>
+    for HeaderValue
+{
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<ExportRunPayloadTimelineItemsInnerAnyOf1>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Invalid header value for ExportRunPayloadTimelineItemsInnerAnyOf1 - value: {hdr_value} is invalid {e}"#
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue>
+    for header::IntoHeaderValue<ExportRunPayloadTimelineItemsInnerAnyOf1>
+{
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <ExportRunPayloadTimelineItemsInnerAnyOf1 as std::str::FromStr>::from_str(
+                    value,
+                ) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        r#"Unable to convert header value '{value}' into ExportRunPayloadTimelineItemsInnerAnyOf1 - {err}"#
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Unable to convert header: {hdr_value:?} to string: {e}"#
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct ExportRunResult {
+    #[serde(rename = "jobId")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub job_id: String,
+
+    /// Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "status")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub status: String,
+
+    #[serde(rename = "outputURL")]
+    #[validate(custom(function = "check_xss_string"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output_url: Option<String>,
+}
+
+impl ExportRunResult {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(job_id: String, status: String) -> ExportRunResult {
+        ExportRunResult {
+            job_id,
+            status,
+            output_url: None,
+        }
+    }
+}
+
+/// Converts the ExportRunResult value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for ExportRunResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("jobId".to_string()),
+            Some(self.job_id.to_string()),
+            Some("status".to_string()),
+            Some(self.status.to_string()),
+            self.output_url
+                .as_ref()
+                .map(|output_url| ["outputURL".to_string(), output_url.to_string()].join(",")),
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a ExportRunResult value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for ExportRunResult {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub job_id: Vec<String>,
+            pub status: Vec<String>,
+            pub output_url: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing ExportRunResult".to_string(),
+                    );
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "jobId" => intermediate_rep.job_id.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "status" => intermediate_rep.status.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "outputURL" => intermediate_rep.output_url.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing ExportRunResult".to_string(),
+                        );
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(ExportRunResult {
+            job_id: intermediate_rep
+                .job_id
+                .into_iter()
+                .next()
+                .ok_or_else(|| "jobId missing in ExportRunResult".to_string())?,
+            status: intermediate_rep
+                .status
+                .into_iter()
+                .next()
+                .ok_or_else(|| "status missing in ExportRunResult".to_string())?,
+            output_url: intermediate_rep.output_url.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<ExportRunResult> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<ExportRunResult>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<ExportRunResult>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Invalid header value for ExportRunResult - value: {hdr_value} is invalid {e}"#
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<ExportRunResult> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <ExportRunResult as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        r#"Unable to convert header value '{value}' into ExportRunResult - {err}"#
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Unable to convert header: {hdr_value:?} to string: {e}"#
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct GuideStep {
+    #[serde(rename = "index")]
+    pub index: i32,
+
+    #[serde(rename = "timeSeconds")]
+    pub time_seconds: f64,
+
+    #[serde(rename = "timestamp")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub timestamp: String,
+
+    #[serde(rename = "action")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub action: String,
+
+    #[serde(rename = "imageURL")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub image_url: String,
+
+    #[serde(rename = "click")]
+    #[validate(nested)]
+    pub click: models::GuideStepClick,
+
+    #[serde(rename = "transcript")]
+    #[validate(custom(function = "check_xss_string"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transcript: Option<String>,
+}
+
+impl GuideStep {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(
+        index: i32,
+        time_seconds: f64,
+        timestamp: String,
+        action: String,
+        image_url: String,
+        click: models::GuideStepClick,
+    ) -> GuideStep {
+        GuideStep {
+            index,
+            time_seconds,
+            timestamp,
+            action,
+            image_url,
+            click,
+            transcript: None,
+        }
+    }
+}
+
+/// Converts the GuideStep value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for GuideStep {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("index".to_string()),
+            Some(self.index.to_string()),
+            Some("timeSeconds".to_string()),
+            Some(self.time_seconds.to_string()),
+            Some("timestamp".to_string()),
+            Some(self.timestamp.to_string()),
+            Some("action".to_string()),
+            Some(self.action.to_string()),
+            Some("imageURL".to_string()),
+            Some(self.image_url.to_string()),
+            // Skipping click in query parameter serialization
+            self.transcript
+                .as_ref()
+                .map(|transcript| ["transcript".to_string(), transcript.to_string()].join(",")),
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a GuideStep value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for GuideStep {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub index: Vec<i32>,
+            pub time_seconds: Vec<f64>,
+            pub timestamp: Vec<String>,
+            pub action: Vec<String>,
+            pub image_url: Vec<String>,
+            pub click: Vec<models::GuideStepClick>,
+            pub transcript: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing GuideStep".to_string(),
+                    );
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "index" => intermediate_rep.index.push(
+                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "timeSeconds" => intermediate_rep.time_seconds.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "timestamp" => intermediate_rep.timestamp.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "action" => intermediate_rep.action.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "imageURL" => intermediate_rep.image_url.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "click" => intermediate_rep.click.push(
+                        <models::GuideStepClick as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "transcript" => intermediate_rep.transcript.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing GuideStep".to_string(),
+                        );
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(GuideStep {
+            index: intermediate_rep
+                .index
+                .into_iter()
+                .next()
+                .ok_or_else(|| "index missing in GuideStep".to_string())?,
+            time_seconds: intermediate_rep
+                .time_seconds
+                .into_iter()
+                .next()
+                .ok_or_else(|| "timeSeconds missing in GuideStep".to_string())?,
+            timestamp: intermediate_rep
+                .timestamp
+                .into_iter()
+                .next()
+                .ok_or_else(|| "timestamp missing in GuideStep".to_string())?,
+            action: intermediate_rep
+                .action
+                .into_iter()
+                .next()
+                .ok_or_else(|| "action missing in GuideStep".to_string())?,
+            image_url: intermediate_rep
+                .image_url
+                .into_iter()
+                .next()
+                .ok_or_else(|| "imageURL missing in GuideStep".to_string())?,
+            click: intermediate_rep
+                .click
+                .into_iter()
+                .next()
+                .ok_or_else(|| "click missing in GuideStep".to_string())?,
+            transcript: intermediate_rep.transcript.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<GuideStep> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<GuideStep>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<GuideStep>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Invalid header value for GuideStep - value: {hdr_value} is invalid {e}"#
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<GuideStep> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <GuideStep as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        r#"Unable to convert header value '{value}' into GuideStep - {err}"#
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Unable to convert header: {hdr_value:?} to string: {e}"#
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct GuideStepClick {
+    #[serde(rename = "x")]
+    pub x: f64,
+
+    #[serde(rename = "y")]
+    pub y: f64,
+}
+
+impl GuideStepClick {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(x: f64, y: f64) -> GuideStepClick {
+        GuideStepClick { x, y }
+    }
+}
+
+/// Converts the GuideStepClick value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for GuideStepClick {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("x".to_string()),
+            Some(self.x.to_string()),
+            Some("y".to_string()),
+            Some(self.y.to_string()),
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a GuideStepClick value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for GuideStepClick {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub x: Vec<f64>,
+            pub y: Vec<f64>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing GuideStepClick".to_string(),
+                    );
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "x" => intermediate_rep.x.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "y" => intermediate_rep.y.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing GuideStepClick".to_string(),
+                        );
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(GuideStepClick {
+            x: intermediate_rep
+                .x
+                .into_iter()
+                .next()
+                .ok_or_else(|| "x missing in GuideStepClick".to_string())?,
+            y: intermediate_rep
+                .y
+                .into_iter()
+                .next()
+                .ok_or_else(|| "y missing in GuideStepClick".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<GuideStepClick> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<GuideStepClick>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<GuideStepClick>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Invalid header value for GuideStepClick - value: {hdr_value} is invalid {e}"#
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<GuideStepClick> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <GuideStepClick as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        r#"Unable to convert header value '{value}' into GuideStepClick - {err}"#
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Unable to convert header: {hdr_value:?} to string: {e}"#
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct IdleCutSignals {
+    #[serde(rename = "input")]
+    pub input: bool,
+
+    #[serde(rename = "frames")]
+    pub frames: bool,
+}
+
+impl IdleCutSignals {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(input: bool, frames: bool) -> IdleCutSignals {
+        IdleCutSignals { input, frames }
+    }
+}
+
+/// Converts the IdleCutSignals value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for IdleCutSignals {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("input".to_string()),
+            Some(self.input.to_string()),
+            Some("frames".to_string()),
+            Some(self.frames.to_string()),
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a IdleCutSignals value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for IdleCutSignals {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub input: Vec<bool>,
+            pub frames: Vec<bool>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing IdleCutSignals".to_string(),
+                    );
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "input" => intermediate_rep.input.push(
+                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "frames" => intermediate_rep.frames.push(
+                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing IdleCutSignals".to_string(),
+                        );
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(IdleCutSignals {
+            input: intermediate_rep
+                .input
+                .into_iter()
+                .next()
+                .ok_or_else(|| "input missing in IdleCutSignals".to_string())?,
+            frames: intermediate_rep
+                .frames
+                .into_iter()
+                .next()
+                .ok_or_else(|| "frames missing in IdleCutSignals".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<IdleCutSignals> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<IdleCutSignals>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<IdleCutSignals>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Invalid header value for IdleCutSignals - value: {hdr_value} is invalid {e}"#
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<IdleCutSignals> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <IdleCutSignals as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        r#"Unable to convert header value '{value}' into IdleCutSignals - {err}"#
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Unable to convert header: {hdr_value:?} to string: {e}"#
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct LibraryDefaultsResult {
+    #[serde(rename = "windowExclusions")]
+    #[validate(nested)]
+    pub window_exclusions: models::WindowExclusionSettings,
+}
+
+impl LibraryDefaultsResult {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(window_exclusions: models::WindowExclusionSettings) -> LibraryDefaultsResult {
+        LibraryDefaultsResult { window_exclusions }
+    }
+}
+
+/// Converts the LibraryDefaultsResult value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for LibraryDefaultsResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            // Skipping windowExclusions in query parameter serialization
+
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a LibraryDefaultsResult value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for LibraryDefaultsResult {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub window_exclusions: Vec<models::WindowExclusionSettings>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing LibraryDefaultsResult".to_string(),
+                    );
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "windowExclusions" => intermediate_rep.window_exclusions.push(
+                        <models::WindowExclusionSettings as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing LibraryDefaultsResult".to_string(),
+                        );
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(LibraryDefaultsResult {
+            window_exclusions: intermediate_rep
+                .window_exclusions
+                .into_iter()
+                .next()
+                .ok_or_else(|| "windowExclusions missing in LibraryDefaultsResult".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<LibraryDefaultsResult> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<LibraryDefaultsResult>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<LibraryDefaultsResult>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Invalid header value for LibraryDefaultsResult - value: {hdr_value} is invalid {e}"#
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<LibraryDefaultsResult> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <LibraryDefaultsResult as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        r#"Unable to convert header value '{value}' into LibraryDefaultsResult - {err}"#
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Unable to convert header: {hdr_value:?} to string: {e}"#
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct LibrarySaveDefaultsPayload {
+    #[serde(rename = "windowExclusions")]
+    #[validate(nested)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub window_exclusions: Option<models::WindowExclusionSettings>,
+}
+
+impl LibrarySaveDefaultsPayload {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new() -> LibrarySaveDefaultsPayload {
+        LibrarySaveDefaultsPayload {
+            window_exclusions: None,
+        }
+    }
+}
+
+/// Converts the LibrarySaveDefaultsPayload value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for LibrarySaveDefaultsPayload {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            // Skipping windowExclusions in query parameter serialization
+
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a LibrarySaveDefaultsPayload value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for LibrarySaveDefaultsPayload {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub window_exclusions: Vec<models::WindowExclusionSettings>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing LibrarySaveDefaultsPayload".to_string(),
+                    );
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "windowExclusions" => intermediate_rep.window_exclusions.push(
+                        <models::WindowExclusionSettings as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing LibrarySaveDefaultsPayload".to_string(),
+                        );
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(LibrarySaveDefaultsPayload {
+            window_exclusions: intermediate_rep.window_exclusions.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<LibrarySaveDefaultsPayload> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<LibrarySaveDefaultsPayload>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<LibrarySaveDefaultsPayload>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Invalid header value for LibrarySaveDefaultsPayload - value: {hdr_value} is invalid {e}"#
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<LibrarySaveDefaultsPayload> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <LibrarySaveDefaultsPayload as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        r#"Unable to convert header value '{value}' into LibrarySaveDefaultsPayload - {err}"#
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Unable to convert header: {hdr_value:?} to string: {e}"#
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct PermissionsResult {
+    #[serde(rename = "screenRecordingGranted")]
+    pub screen_recording_granted: bool,
+
+    #[serde(rename = "microphoneGranted")]
+    pub microphone_granted: bool,
+
+    /// Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "inputMonitoring")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub input_monitoring: String,
+}
+
+impl PermissionsResult {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(
+        screen_recording_granted: bool,
+        microphone_granted: bool,
+        input_monitoring: String,
+    ) -> PermissionsResult {
+        PermissionsResult {
+            screen_recording_granted,
+            microphone_granted,
+            input_monitoring,
+        }
+    }
+}
+
+/// Converts the PermissionsResult value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for PermissionsResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("screenRecordingGranted".to_string()),
+            Some(self.screen_recording_granted.to_string()),
+            Some("microphoneGranted".to_string()),
+            Some(self.microphone_granted.to_string()),
+            Some("inputMonitoring".to_string()),
+            Some(self.input_monitoring.to_string()),
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a PermissionsResult value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for PermissionsResult {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub screen_recording_granted: Vec<bool>,
+            pub microphone_granted: Vec<bool>,
+            pub input_monitoring: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing PermissionsResult".to_string(),
+                    );
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "screenRecordingGranted" => intermediate_rep.screen_recording_granted.push(
+                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "microphoneGranted" => intermediate_rep.microphone_granted.push(
+                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "inputMonitoring" => intermediate_rep.input_monitoring.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing PermissionsResult".to_string(),
+                        );
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(PermissionsResult {
+            screen_recording_granted: intermediate_rep
+                .screen_recording_granted
+                .into_iter()
+                .next()
+                .ok_or_else(|| "screenRecordingGranted missing in PermissionsResult".to_string())?,
+            microphone_granted: intermediate_rep
+                .microphone_granted
+                .into_iter()
+                .next()
+                .ok_or_else(|| "microphoneGranted missing in PermissionsResult".to_string())?,
+            input_monitoring: intermediate_rep
+                .input_monitoring
+                .into_iter()
+                .next()
+                .ok_or_else(|| "inputMonitoring missing in PermissionsResult".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<PermissionsResult> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<PermissionsResult>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<PermissionsResult>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Invalid header value for PermissionsResult - value: {hdr_value} is invalid {e}"#
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<PermissionsResult> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <PermissionsResult as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        r#"Unable to convert header value '{value}' into PermissionsResult - {err}"#
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Unable to convert header: {hdr_value:?} to string: {e}"#
+            )),
+        }
+    }
+}
+
+/// Where and how the camera track is composited over the screen recording on export.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct PictureInPictureSettings {
+    /// Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "version")]
+    pub version: f64,
+
+    #[serde(rename = "enabled")]
+    pub enabled: bool,
+
+    /// Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "corner")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub corner: String,
+
+    #[serde(rename = "sizeFraction")]
+    pub size_fraction: f64,
+
+    #[serde(rename = "marginFraction")]
+    pub margin_fraction: f64,
+
+    /// Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "shape")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub shape: String,
+
+    #[serde(rename = "borderWidthFraction")]
+    pub border_width_fraction: f64,
+
+    #[serde(rename = "borderColor")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub border_color: String,
+}
+
+impl PictureInPictureSettings {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(
+        version: f64,
+        enabled: bool,
+        corner: String,
+        size_fraction: f64,
+        margin_fraction: f64,
+        shape: String,
+        border_width_fraction: f64,
+        border_color: String,
+    ) -> PictureInPictureSettings {
+        PictureInPictureSettings {
+            version,
+            enabled,
+            corner,
+            size_fraction,
+            margin_fraction,
+            shape,
+            border_width_fraction,
+            border_color,
+        }
+    }
+}
+
+/// Converts the PictureInPictureSettings value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for PictureInPictureSettings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("version".to_string()),
+            Some(self.version.to_string()),
+            Some("enabled".to_string()),
+            Some(self.enabled.to_string()),
+            Some("corner".to_string()),
+            Some(self.corner.to_string()),
+            Some("sizeFraction".to_string()),
+            Some(self.size_fraction.to_string()),
+            Some("marginFraction".to_string()),
+            Some(self.margin_fraction.to_string()),
+            Some("shape".to_string()),
+            Some(self.shape.to_string()),
+            Some("borderWidthFraction".to_string()),
+            Some(self.border_width_fraction.to_string()),
+            Some("borderColor".to_string()),
+            Some(self.border_color.to_string()),
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a PictureInPictureSettings value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for PictureInPictureSettings {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub version: Vec<f64>,
+            pub enabled: Vec<bool>,
+            pub corner: Vec<String>,
+            pub size_fraction: Vec<f64>,
+            pub margin_fraction: Vec<f64>,
+            pub shape: Vec<String>,
+            pub border_width_fraction: Vec<f64>,
+            pub border_color: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing PictureInPictureSettings".to_string(),
+                    );
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "version" => intermediate_rep.version.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "enabled" => intermediate_rep.enabled.push(
+                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "corner" => intermediate_rep.corner.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "sizeFraction" => intermediate_rep.size_fraction.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "marginFraction" => intermediate_rep.margin_fraction.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "shape" => intermediate_rep.shape.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "borderWidthFraction" => intermediate_rep.border_width_fraction.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "borderColor" => intermediate_rep.border_color.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing PictureInPictureSettings".to_string(),
+                        );
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(PictureInPictureSettings {
+            version: intermediate_rep
+                .version
+                .into_iter()
+                .next()
+                .ok_or_else(|| "version missing in PictureInPictureSettings".to_string())?,
+            enabled: intermediate_rep
+                .enabled
+                .into_iter()
+                .next()
+                .ok_or_else(|| "enabled missing in PictureInPictureSettings".to_string())?,
+            corner: intermediate_rep
+                .corner
+                .into_iter()
+                .next()
+                .ok_or_else(|| "corner missing in PictureInPictureSettings".to_string())?,
+            size_fraction: intermediate_rep
+                .size_fraction
+                .into_iter()
+                .next()
+                .ok_or_else(|| "sizeFraction missing in PictureInPictureSettings".to_string())?,
+            margin_fraction: intermediate_rep
+                .margin_fraction
+                .into_iter()
+                .next()
+                .ok_or_else(|| "marginFraction missing in PictureInPictureSettings".to_string())?,
+            shape: intermediate_rep
+                .shape
+                .into_iter()
+                .next()
+                .ok_or_else(|| "shape missing in PictureInPictureSettings".to_string())?,
+            border_width_fraction: intermediate_rep
+                .border_width_fraction
+                .into_iter()
+                .next()
+                .ok_or_else(|| {
+                    "borderWidthFraction missing in PictureInPictureSettings".to_string()
+                })?,
+            border_color: intermediate_rep
+                .border_color
+                .into_iter()
+                .next()
+                .ok_or_else(|| "borderColor missing in PictureInPictureSettings".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<PictureInPictureSettings> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<PictureInPictureSettings>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<PictureInPictureSettings>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Invalid header value for PictureInPictureSettings - value: {hdr_value} is invalid {e}"#
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<PictureInPictureSettings> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <PictureInPictureSettings as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        r#"Unable to convert header value '{value}' into PictureInPictureSettings - {err}"#
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Unable to convert header: {hdr_value:?} to string: {e}"#
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct PingResult {
+    #[serde(rename = "app")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub app: String,
+
+    #[serde(rename = "engineVersion")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub engine_version: String,
+
+    #[serde(rename = "protocolVersion")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub protocol_version: String,
+
+    #[serde(rename = "platform")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub platform: String,
+}
+
+impl PingResult {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(
+        app: String,
+        engine_version: String,
+        protocol_version: String,
+        platform: String,
+    ) -> PingResult {
+        PingResult {
+            app,
+            engine_version,
+            protocol_version,
+            platform,
+        }
+    }
+}
+
+/// Converts the PingResult value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for PingResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("app".to_string()),
+            Some(self.app.to_string()),
+            Some("engineVersion".to_string()),
+            Some(self.engine_version.to_string()),
+            Some("protocolVersion".to_string()),
+            Some(self.protocol_version.to_string()),
+            Some("platform".to_string()),
+            Some(self.platform.to_string()),
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a PingResult value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for PingResult {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub app: Vec<String>,
+            pub engine_version: Vec<String>,
+            pub protocol_version: Vec<String>,
+            pub platform: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing PingResult".to_string(),
+                    );
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "app" => intermediate_rep.app.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "engineVersion" => intermediate_rep.engine_version.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "protocolVersion" => intermediate_rep.protocol_version.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "platform" => intermediate_rep.platform.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing PingResult".to_string(),
+                        );
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(PingResult {
+            app: intermediate_rep
+                .app
+                .into_iter()
+                .next()
+                .ok_or_else(|| "app missing in PingResult".to_string())?,
+            engine_version: intermediate_rep
+                .engine_version
+                .into_iter()
+                .next()
+                .ok_or_else(|| "engineVersion missing in PingResult".to_string())?,
+            protocol_version: intermediate_rep
+                .protocol_version
+                .into_iter()
+                .next()
+                .ok_or_else(|| "protocolVersion missing in PingResult".to_string())?,
+            platform: intermediate_rep
+                .platform
+                .into_iter()
+                .next()
+                .ok_or_else(|| "platform missing in PingResult".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<PingResult> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<PingResult>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<PingResult>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Invalid header value for PingResult - value: {hdr_value} is invalid {e}"#
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<PingResult> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <PingResult as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        r#"Unable to convert header value '{value}' into PingResult - {err}"#
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Unable to convert header: {hdr_value:?} to string: {e}"#
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct PrivacyMaskStatus {
+    #[serde(rename = "ruleCount")]
+    pub rule_count: i32,
+
+    /// Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "maskStyle")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub mask_style: String,
+
+    #[serde(rename = "maskedWindowIds")]
+    pub masked_window_ids: Vec<i32>,
+}
+
+impl PrivacyMaskStatus {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(
+        rule_count: i32,
+        mask_style: String,
+        masked_window_ids: Vec<i32>,
+    ) -> PrivacyMaskStatus {
+        PrivacyMaskStatus {
+            rule_count,
+            mask_style,
+            masked_window_ids,
+        }
+    }
+}
+
+/// Converts the PrivacyMaskStatus value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for PrivacyMaskStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("ruleCount".to_string()),
+            Some(self.rule_count.to_string()),
+            Some("maskStyle".to_string()),
+            Some(self.mask_style.to_string()),
+            Some("maskedWindowIds".to_string()),
+            Some(
+                self.masked_window_ids
+                    .iter()
+                    .map(|x| x.to_string())
+                    .collect::<Vec<_>>()
+                    .join(","),
+            ),
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a PrivacyMaskStatus value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for PrivacyMaskStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub rule_count: Vec<i32>,
+            pub mask_style: Vec<String>,
+            pub masked_window_ids: Vec<Vec<i32>>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing PrivacyMaskStatus".to_string(),
+                    );
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "ruleCount" => intermediate_rep.rule_count.push(
+                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "maskStyle" => intermediate_rep.mask_style.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    "maskedWindowIds" => return std::result::Result::Err(
+                        "Parsing a container in this style is not supported in PrivacyMaskStatus"
+                            .to_string(),
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing PrivacyMaskStatus".to_string(),
+                        );
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(PrivacyMaskStatus {
+            rule_count: intermediate_rep
+                .rule_count
+                .into_iter()
+                .next()
+                .ok_or_else(|| "ruleCount missing in PrivacyMaskStatus".to_string())?,
+            mask_style: intermediate_rep
+                .mask_style
+                .into_iter()
+                .next()
+                .ok_or_else(|| "maskStyle missing in PrivacyMaskStatus".to_string())?,
+            masked_window_ids: intermediate_rep
+                .masked_window_ids
+                .into_iter()
+                .next()
+                .ok_or_else(|| "maskedWindowIds missing in PrivacyMaskStatus".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<PrivacyMaskStatus> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<PrivacyMaskStatus>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<PrivacyMaskStatus>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Invalid header value for PrivacyMaskStatus - value: {hdr_value} is invalid {e}"#
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<PrivacyMaskStatus> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <PrivacyMaskStatus as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        r#"Unable to convert header value '{value}' into PrivacyMaskStatus - {err}"#
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Unable to convert header: {hdr_value:?} to string: {e}"#
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct ProjectAgentAnalysisSummary {
+    #[serde(rename = "latestJobId")]
     #[validate(custom(function = "check_xss_string"))]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub recording_url: Option<String>,
+    pub latest_job_id: Option<String>,
 
-    #[serde(rename = "captureMetadata")]
-    #[validate(nested)]
+    /// Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "latestStatus")]
+    #[validate(custom(function = "check_xss_string"))]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub capture_metadata: Option<models::CaptureStatusResultCaptureMetadata>,
+    pub latest_status: Option<String>,
 
-    #[serde(rename = "lastError")]
-    #[validate(nested)]
+    #[serde(rename = "qaPassed")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_error: Option<models::EngineBadRequestError>,
+    pub qa_passed: Option<bool>,
 
-    #[serde(rename = "eventsURL")]
+    #[serde(rename = "updatedAt")]
     #[validate(custom(function = "check_xss_string"))]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub events_url: Option<String>,
-
-    #[serde(rename = "lastRecordingTelemetry")]
-    #[validate(nested)]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_recording_telemetry: Option<models::CaptureTelemetry>,
-
-    #[serde(rename = "telemetry")]
-    #[validate(nested)]
-    pub telemetry: models::CaptureTelemetry,
+    pub updated_at: Option<String>,
 }
 
-impl CaptureStatusResult {
+impl ProjectAgentAnalysisSummary {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new(
-        is_running: bool,
-        is_recording: bool,
-        recording_duration_seconds: f64,
-        telemetry: models::CaptureTelemetry,
-    ) -> CaptureStatusResult {
-        CaptureStatusResult {
-            is_running,
-            is_recording,
-            capture_session_id: None,
-            recording_duration_seconds,
-            recording_url: None,
-            capture_metadata: None,
-            last_error: None,
-            events_url: None,
-            last_recording_telemetry: None,
-            telemetry,
+    pub fn new() -> ProjectAgentAnalysisSummary {
+        ProjectAgentAnalysisSummary {
+            latest_job_id: None,
+            latest_status: None,
+            qa_passed: None,
+            updated_at: None,
         }
     }
 }
 
-/// Converts the CaptureStatusResult value to the Query Parameters representation (style=form, explode=false)
+/// Converts the ProjectAgentAnalysisSummary value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for CaptureStatusResult {
+impl std::fmt::Display for ProjectAgentAnalysisSummary {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
-            Some("isRunning".to_string()),
-            Some(self.is_running.to_string()),
-            Some("isRecording".to_string()),
-            Some(self.is_recording.to_string()),
-            self.capture_session_id.as_ref().map(|capture_session_id| {
-                [
-                    "captureSessionId".to_string(),
-                    capture_session_id.to_string(),
-                ]
-                .join(",")
+            self.latest_job_id.as_ref().map(|latest_job_id| {
+                ["latestJobId".to_string(), latest_job_id.to_string()].join(",")
             }),
-            Some("recordingDurationSeconds".to_string()),
-            Some(self.recording_duration_seconds.to_string()),
-            self.recording_url.as_ref().map(|recording_url| {
-                ["recordingURL".to_string(), recording_url.to_string()].join(",")
+            self.latest_status.as_ref().map(|latest_status| {
+                ["latestStatus".to_string(), latest_status.to_string()].join(",")
             }),
-            // Skipping captureMetadata in query parameter serialization
-
-            // Skipping lastError in query parameter serialization
-            self.events_url
+            self.qa_passed
                 .as_ref()
-                .map(|events_url| ["eventsURL".to_string(), events_url.to_string()].join(",")),
-            // Skipping lastRecordingTelemetry in query parameter serialization
-
-            // Skipping telemetry in query parameter serialization
+                .map(|qa_passed| ["qaPassed".to_string(), qa_passed.to_string()].join(",")),
+            self.updated_at
+                .as_ref()
+                .map(|updated_at| ["updatedAt".to_string(), updated_at.to_string()].join(",")),
         ];
 
         write!(
@@ -4282,10 +14324,10 @@ impl std::fmt::Display for CaptureStatusResult {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a CaptureStatusResult value
+/// Converts Query Parameters representation (style=form, explode=false) to a ProjectAgentAnalysisSummary value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for CaptureStatusResult {
+impl std::str::FromStr for ProjectAgentAnalysisSummary {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -4293,16 +14335,10 @@ impl std::str::FromStr for CaptureStatusResult {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub is_running: Vec<bool>,
-            pub is_recording: Vec<bool>,
-            pub capture_session_id: Vec<String>,
-            pub recording_duration_seconds: Vec<f64>,
-            pub recording_url: Vec<String>,
-            pub capture_metadata: Vec<models::CaptureStatusResultCaptureMetadata>,
-            pub last_error: Vec<models::EngineBadRequestError>,
-            pub events_url: Vec<String>,
-            pub last_recording_telemetry: Vec<models::CaptureTelemetry>,
-            pub telemetry: Vec<models::CaptureTelemetry>,
+            pub latest_job_id: Vec<String>,
+            pub latest_status: Vec<String>,
+            pub qa_passed: Vec<bool>,
+            pub updated_at: Vec<String>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -4316,7 +14352,7 @@ impl std::str::FromStr for CaptureStatusResult {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing CaptureStatusResult".to_string(),
+                        "Missing value while parsing ProjectAgentAnalysisSummary".to_string(),
                     );
                 }
             };
@@ -4325,26 +14361,26 @@ impl std::str::FromStr for CaptureStatusResult {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "isRunning" => intermediate_rep.is_running.push(<bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
-                    #[allow(clippy::redundant_clone)]
-                    "isRecording" => intermediate_rep.is_recording.push(<bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
-                    #[allow(clippy::redundant_clone)]
-                    "captureSessionId" => intermediate_rep.capture_session_id.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
-                    #[allow(clippy::redundant_clone)]
-                    "recordingDurationSeconds" => intermediate_rep.recording_duration_seconds.push(<f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
-                    #[allow(clippy::redundant_clone)]
-                    "recordingURL" => intermediate_rep.recording_url.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
-                    #[allow(clippy::redundant_clone)]
-                    "captureMetadata" => intermediate_rep.capture_metadata.push(<models::CaptureStatusResultCaptureMetadata as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
-                    #[allow(clippy::redundant_clone)]
-                    "lastError" => intermediate_rep.last_error.push(<models::EngineBadRequestError as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    "latestJobId" => intermediate_rep.latest_job_id.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
                     #[allow(clippy::redundant_clone)]
-                    "eventsURL" => intermediate_rep.events_url.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    "latestStatus" => intermediate_rep.latest_status.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
                     #[allow(clippy::redundant_clone)]
-                    "lastRecordingTelemetry" => intermediate_rep.last_recording_telemetry.push(<models::CaptureTelemetry as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    "qaPassed" => intermediate_rep.qa_passed.push(
+                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
                     #[allow(clippy::redundant_clone)]
-                    "telemetry" => intermediate_rep.telemetry.push(<models::CaptureTelemetry as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
-                    _ => return std::result::Result::Err("Unexpected key while parsing CaptureStatusResult".to_string())
+                    "updatedAt" => intermediate_rep.updated_at.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing ProjectAgentAnalysisSummary".to_string(),
+                        );
+                    }
                 }
             }
 
@@ -4353,71 +14389,47 @@ impl std::str::FromStr for CaptureStatusResult {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(CaptureStatusResult {
-            is_running: intermediate_rep
-                .is_running
-                .into_iter()
-                .next()
-                .ok_or_else(|| "isRunning missing in CaptureStatusResult".to_string())?,
-            is_recording: intermediate_rep
-                .is_recording
-                .into_iter()
-                .next()
-                .ok_or_else(|| "isRecording missing in CaptureStatusResult".to_string())?,
-            capture_session_id: intermediate_rep.capture_session_id.into_iter().next(),
-            recording_duration_seconds: intermediate_rep
-                .recording_duration_seconds
-                .into_iter()
-                .next()
-                .ok_or_else(|| {
-                    "recordingDurationSeconds missing in CaptureStatusResult".to_string()
-                })?,
-            recording_url: intermediate_rep.recording_url.into_iter().next(),
-            capture_metadata: intermediate_rep.capture_metadata.into_iter().next(),
-            last_error: intermediate_rep.last_error.into_iter().next(),
-            events_url: intermediate_rep.events_url.into_iter().next(),
-            last_recording_telemetry: intermediate_rep.last_recording_telemetry.into_iter().next(),
-            telemetry: intermediate_rep
-                .telemetry
-                .into_iter()
-                .next()
-                .ok_or_else(|| "telemetry missing in CaptureStatusResult".to_string())?,
+        std::result::Result::Ok(ProjectAgentAnalysisSummary {
+            latest_job_id: intermediate_rep.latest_job_id.into_iter().next(),
+            latest_status: intermediate_rep.latest_status.into_iter().next(),
+            qa_passed: intermediate_rep.qa_passed.into_iter().next(),
+            updated_at: intermediate_rep.updated_at.into_iter().next(),
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<CaptureStatusResult> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<ProjectAgentAnalysisSummary> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<CaptureStatusResult>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<ProjectAgentAnalysisSummary>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<CaptureStatusResult>,
+        hdr_value: header::IntoHeaderValue<ProjectAgentAnalysisSummary>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                r#"Invalid header value for CaptureStatusResult - value: {hdr_value} is invalid {e}"#
+                r#"Invalid header value for ProjectAgentAnalysisSummary - value: {hdr_value} is invalid {e}"#
             )),
         }
     }
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<CaptureStatusResult> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<ProjectAgentAnalysisSummary> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <CaptureStatusResult as std::str::FromStr>::from_str(value) {
+                match <ProjectAgentAnalysisSummary as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        r#"Unable to convert header value '{value}' into CaptureStatusResult - {err}"#
+                        r#"Unable to convert header value '{value}' into ProjectAgentAnalysisSummary - {err}"#
                     )),
                 }
             }
@@ -4430,61 +14442,27 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<CaptureStatu
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct CaptureStatusResultCaptureMetadata {
-    #[serde(rename = "window")]
-    #[validate(nested)]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub window: Option<models::CaptureStatusResultCaptureMetadataWindow>,
-
-    /// Note: inline enums are not fully supported by openapi-generator
-    #[serde(rename = "source")]
+pub struct ProjectOpenPayload {
+    #[serde(rename = "projectPath")]
     #[validate(custom(function = "check_xss_string"))]
-    pub source: String,
-
-    #[serde(rename = "contentRect")]
-    #[validate(nested)]
-    pub content_rect: models::CaptureStatusResultCaptureMetadataContentRect,
-
-    #[serde(rename = "pixelScale")]
-    pub pixel_scale: f64,
-
-    #[serde(rename = "fps")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub fps: Option<f64>,
+    pub project_path: String,
 }
 
-impl CaptureStatusResultCaptureMetadata {
+impl ProjectOpenPayload {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new(
-        source: String,
-        content_rect: models::CaptureStatusResultCaptureMetadataContentRect,
-        pixel_scale: f64,
-    ) -> CaptureStatusResultCaptureMetadata {
-        CaptureStatusResultCaptureMetadata {
-            window: None,
-            source,
-            content_rect,
-            pixel_scale,
-            fps: None,
-        }
+    pub fn new(project_path: String) -> ProjectOpenPayload {
+        ProjectOpenPayload { project_path }
     }
 }
 
-/// Converts the CaptureStatusResultCaptureMetadata value to the Query Parameters representation (style=form, explode=false)
+/// Converts the ProjectOpenPayload value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for CaptureStatusResultCaptureMetadata {
+impl std::fmt::Display for ProjectOpenPayload {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
-            // Skipping window in query parameter serialization
-            Some("source".to_string()),
-            Some(self.source.to_string()),
-            // Skipping contentRect in query parameter serialization
-            Some("pixelScale".to_string()),
-            Some(self.pixel_scale.to_string()),
-            self.fps
-                .as_ref()
-                .map(|fps| ["fps".to_string(), fps.to_string()].join(",")),
+            Some("projectPath".to_string()),
+            Some(self.project_path.to_string()),
         ];
 
         write!(
@@ -4495,10 +14473,10 @@ impl std::fmt::Display for CaptureStatusResultCaptureMetadata {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a CaptureStatusResultCaptureMetadata value
+/// Converts Query Parameters representation (style=form, explode=false) to a ProjectOpenPayload value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for CaptureStatusResultCaptureMetadata {
+impl std::str::FromStr for ProjectOpenPayload {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -4506,11 +14484,7 @@ impl std::str::FromStr for CaptureStatusResultCaptureMetadata {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub window: Vec<models::CaptureStatusResultCaptureMetadataWindow>,
-            pub source: Vec<String>,
-            pub content_rect: Vec<models::CaptureStatusResultCaptureMetadataContentRect>,
-            pub pixel_scale: Vec<f64>,
-            pub fps: Vec<f64>,
+            pub project_path: Vec<String>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -4524,8 +14498,7 @@ impl std::str::FromStr for CaptureStatusResultCaptureMetadata {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing CaptureStatusResultCaptureMetadata"
-                            .to_string(),
+                        "Missing value while parsing ProjectOpenPayload".to_string(),
                     );
                 }
             };
@@ -4534,16 +14507,14 @@ impl std::str::FromStr for CaptureStatusResultCaptureMetadata {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "window" => intermediate_rep.window.push(<models::CaptureStatusResultCaptureMetadataWindow as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
-                    #[allow(clippy::redundant_clone)]
-                    "source" => intermediate_rep.source.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
-                    #[allow(clippy::redundant_clone)]
-                    "contentRect" => intermediate_rep.content_rect.push(<models::CaptureStatusResultCaptureMetadataContentRect as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
-                    #[allow(clippy::redundant_clone)]
-                    "pixelScale" => intermediate_rep.pixel_scale.push(<f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
-                    #[allow(clippy::redundant_clone)]
-                    "fps" => intermediate_rep.fps.push(<f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
-                    _ => return std::result::Result::Err("Unexpected key while parsing CaptureStatusResultCaptureMetadata".to_string())
+                    "projectPath" => intermediate_rep.project_path.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing ProjectOpenPayload".to_string(),
+                        );
+                    }
                 }
             }
 
@@ -4552,66 +14523,48 @@ impl std::str::FromStr for CaptureStatusResultCaptureMetadata {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(CaptureStatusResultCaptureMetadata {
-            window: intermediate_rep.window.into_iter().next(),
-            source: intermediate_rep.source.into_iter().next().ok_or_else(|| {
-                "source missing in CaptureStatusResultCaptureMetadata".to_string()
-            })?,
-            content_rect: intermediate_rep
-                .content_rect
-                .into_iter()
-                .next()
-                .ok_or_else(|| {
-                    "contentRect missing in CaptureStatusResultCaptureMetadata".to_string()
-                })?,
-            pixel_scale: intermediate_rep
-                .pixel_scale
+        std::result::Result::Ok(ProjectOpenPayload {
+            project_path: intermediate_rep
+                .project_path
                 .into_iter()
                 .next()
-                .ok_or_else(|| {
-                    "pixelScale missing in CaptureStatusResultCaptureMetadata".to_string()
-                })?,
-            fps: intermediate_rep.fps.into_iter().next(),
+                .ok_or_else(|| "projectPath missing in ProjectOpenPayload".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<CaptureStatusResultCaptureMetadata> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<ProjectOpenPayload> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<CaptureStatusResultCaptureMetadata>>
-    for HeaderValue
-{
+impl std::convert::TryFrom<header::IntoHeaderValue<ProjectOpenPayload>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<CaptureStatusResultCaptureMetadata>,
+        hdr_value: header::IntoHeaderValue<ProjectOpenPayload>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                r#"Invalid header value for CaptureStatusResultCaptureMetadata - value: {hdr_value} is invalid {e}"#
+                r#"Invalid header value for ProjectOpenPayload - value: {hdr_value} is invalid {e}"#
             )),
         }
     }
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue>
-    for header::IntoHeaderValue<CaptureStatusResultCaptureMetadata>
-{
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<ProjectOpenPayload> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <CaptureStatusResultCaptureMetadata as std::str::FromStr>::from_str(value) {
+                match <ProjectOpenPayload as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        r#"Unable to convert header value '{value}' into CaptureStatusResultCaptureMetadata - {err}"#
+                        r#"Unable to convert header value '{value}' into ProjectOpenPayload - {err}"#
                     )),
                 }
             }
@@ -4624,51 +14577,47 @@ impl std::convert::TryFrom<HeaderValue>
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct CaptureStatusResultCaptureMetadataContentRect {
-    #[serde(rename = "x")]
-    pub x: f64,
-
-    #[serde(rename = "y")]
-    pub y: f64,
+pub struct ProjectRecentItem {
+    #[serde(rename = "projectPath")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub project_path: String,
 
-    #[serde(rename = "width")]
-    pub width: f64,
+    #[serde(rename = "displayName")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub display_name: String,
 
-    #[serde(rename = "height")]
-    pub height: f64,
+    #[serde(rename = "lastOpenedAt")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub last_opened_at: String,
 }
 
-impl CaptureStatusResultCaptureMetadataContentRect {
+impl ProjectRecentItem {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
     pub fn new(
-        x: f64,
-        y: f64,
-        width: f64,
-        height: f64,
-    ) -> CaptureStatusResultCaptureMetadataContentRect {
-        CaptureStatusResultCaptureMetadataContentRect {
-            x,
-            y,
-            width,
-            height,
+        project_path: String,
+        display_name: String,
+        last_opened_at: String,
+    ) -> ProjectRecentItem {
+        ProjectRecentItem {
+            project_path,
+            display_name,
+            last_opened_at,
         }
     }
 }
 
-/// Converts the CaptureStatusResultCaptureMetadataContentRect value to the Query Parameters representation (style=form, explode=false)
+/// Converts the ProjectRecentItem value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for CaptureStatusResultCaptureMetadataContentRect {
+impl std::fmt::Display for ProjectRecentItem {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
-            Some("x".to_string()),
-            Some(self.x.to_string()),
-            Some("y".to_string()),
-            Some(self.y.to_string()),
-            Some("width".to_string()),
-            Some(self.width.to_string()),
-            Some("height".to_string()),
-            Some(self.height.to_string()),
+            Some("projectPath".to_string()),
+            Some(self.project_path.to_string()),
+            Some("displayName".to_string()),
+            Some(self.display_name.to_string()),
+            Some("lastOpenedAt".to_string()),
+            Some(self.last_opened_at.to_string()),
         ];
 
         write!(
@@ -4679,10 +14628,10 @@ impl std::fmt::Display for CaptureStatusResultCaptureMetadataContentRect {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a CaptureStatusResultCaptureMetadataContentRect value
+/// Converts Query Parameters representation (style=form, explode=false) to a ProjectRecentItem value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for CaptureStatusResultCaptureMetadataContentRect {
+impl std::str::FromStr for ProjectRecentItem {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -4690,10 +14639,9 @@ impl std::str::FromStr for CaptureStatusResultCaptureMetadataContentRect {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub x: Vec<f64>,
-            pub y: Vec<f64>,
-            pub width: Vec<f64>,
-            pub height: Vec<f64>,
+            pub project_path: Vec<String>,
+            pub display_name: Vec<String>,
+            pub last_opened_at: Vec<String>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -4703,27 +14651,35 @@ impl std::str::FromStr for CaptureStatusResultCaptureMetadataContentRect {
         let mut key_result = string_iter.next();
 
         while key_result.is_some() {
-            let val =
-                match string_iter.next() {
-                    Some(x) => x,
-                    None => return std::result::Result::Err(
-                        "Missing value while parsing CaptureStatusResultCaptureMetadataContentRect"
-                            .to_string(),
-                    ),
-                };
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing ProjectRecentItem".to_string(),
+                    );
+                }
+            };
 
             if let Some(key) = key_result {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "x" => intermediate_rep.x.push(<f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
-                    #[allow(clippy::redundant_clone)]
-                    "y" => intermediate_rep.y.push(<f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    "projectPath" => intermediate_rep.project_path.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
                     #[allow(clippy::redundant_clone)]
-                    "width" => intermediate_rep.width.push(<f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    "displayName" => intermediate_rep.display_name.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
                     #[allow(clippy::redundant_clone)]
-                    "height" => intermediate_rep.height.push(<f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
-                    _ => return std::result::Result::Err("Unexpected key while parsing CaptureStatusResultCaptureMetadataContentRect".to_string())
+                    "lastOpenedAt" => intermediate_rep.last_opened_at.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing ProjectRecentItem".to_string(),
+                        );
+                    }
                 }
             }
 
@@ -4732,61 +14688,58 @@ impl std::str::FromStr for CaptureStatusResultCaptureMetadataContentRect {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(CaptureStatusResultCaptureMetadataContentRect {
-            x: intermediate_rep.x.into_iter().next().ok_or_else(|| {
-                "x missing in CaptureStatusResultCaptureMetadataContentRect".to_string()
-            })?,
-            y: intermediate_rep.y.into_iter().next().ok_or_else(|| {
-                "y missing in CaptureStatusResultCaptureMetadataContentRect".to_string()
-            })?,
-            width: intermediate_rep.width.into_iter().next().ok_or_else(|| {
-                "width missing in CaptureStatusResultCaptureMetadataContentRect".to_string()
-            })?,
-            height: intermediate_rep.height.into_iter().next().ok_or_else(|| {
-                "height missing in CaptureStatusResultCaptureMetadataContentRect".to_string()
-            })?,
+        std::result::Result::Ok(ProjectRecentItem {
+            project_path: intermediate_rep
+                .project_path
+                .into_iter()
+                .next()
+                .ok_or_else(|| "projectPath missing in ProjectRecentItem".to_string())?,
+            display_name: intermediate_rep
+                .display_name
+                .into_iter()
+                .next()
+                .ok_or_else(|| "displayName missing in ProjectRecentItem".to_string())?,
+            last_opened_at: intermediate_rep
+                .last_opened_at
+                .into_iter()
+                .next()
+                .ok_or_else(|| "lastOpenedAt missing in ProjectRecentItem".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<CaptureStatusResultCaptureMetadataContentRect> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<ProjectRecentItem> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<CaptureStatusResultCaptureMetadataContentRect>>
-    for HeaderValue
-{
+impl std::convert::TryFrom<header::IntoHeaderValue<ProjectRecentItem>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<CaptureStatusResultCaptureMetadataContentRect>,
+        hdr_value: header::IntoHeaderValue<ProjectRecentItem>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                r#"Invalid header value for CaptureStatusResultCaptureMetadataContentRect - value: {hdr_value} is invalid {e}"#
+                r#"Invalid header value for ProjectRecentItem - value: {hdr_value} is invalid {e}"#
             )),
         }
     }
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue>
-    for header::IntoHeaderValue<CaptureStatusResultCaptureMetadataContentRect>
-{
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<ProjectRecentItem> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <CaptureStatusResultCaptureMetadataContentRect as std::str::FromStr>::from_str(
-                    value,
-                ) {
+                match <ProjectRecentItem as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        r#"Unable to convert header value '{value}' into CaptureStatusResultCaptureMetadataContentRect - {err}"#
+                        r#"Unable to convert header value '{value}' into ProjectRecentItem - {err}"#
                     )),
                 }
             }
@@ -4799,46 +14752,27 @@ impl std::convert::TryFrom<HeaderValue>
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct CaptureStatusResultCaptureMetadataWindow {
-    #[serde(rename = "id")]
-    pub id: i32,
-
-    #[serde(rename = "title")]
-    #[validate(custom(function = "check_xss_string"))]
-    pub title: String,
-
-    #[serde(rename = "appName")]
-    #[validate(custom(function = "check_xss_string"))]
-    pub app_name: String,
+pub struct ProjectRecentsResult {
+    #[serde(rename = "items")]
+    #[validate(nested)]
+    pub items: Vec<models::ProjectRecentItem>,
 }
 
-impl CaptureStatusResultCaptureMetadataWindow {
+impl ProjectRecentsResult {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new(
-        id: i32,
-        title: String,
-        app_name: String,
-    ) -> CaptureStatusResultCaptureMetadataWindow {
-        CaptureStatusResultCaptureMetadataWindow {
-            id,
-            title,
-            app_name,
-        }
+    pub fn new(items: Vec<models::ProjectRecentItem>) -> ProjectRecentsResult {
+        ProjectRecentsResult { items }
     }
 }
 
-/// Converts the CaptureStatusResultCaptureMetadataWindow value to the Query Parameters representation (style=form, explode=false)
+/// Converts the ProjectRecentsResult value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for CaptureStatusResultCaptureMetadataWindow {
+impl std::fmt::Display for ProjectRecentsResult {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let params: Vec<Option<String>> = vec![
-            Some("id".to_string()),
-            Some(self.id.to_string()),
-            Some("title".to_string()),
-            Some(self.title.to_string()),
-            Some("appName".to_string()),
-            Some(self.app_name.to_string()),
+        let params: Vec<Option<String>> = vec![
+            // Skipping items in query parameter serialization
+
         ];
 
         write!(
@@ -4849,10 +14783,10 @@ impl std::fmt::Display for CaptureStatusResultCaptureMetadataWindow {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a CaptureStatusResultCaptureMetadataWindow value
+/// Converts Query Parameters representation (style=form, explode=false) to a ProjectRecentsResult value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for CaptureStatusResultCaptureMetadataWindow {
+impl std::str::FromStr for ProjectRecentsResult {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -4860,9 +14794,7 @@ impl std::str::FromStr for CaptureStatusResultCaptureMetadataWindow {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub id: Vec<i32>,
-            pub title: Vec<String>,
-            pub app_name: Vec<String>,
+            pub items: Vec<Vec<models::ProjectRecentItem>>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -4876,8 +14808,7 @@ impl std::str::FromStr for CaptureStatusResultCaptureMetadataWindow {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing CaptureStatusResultCaptureMetadataWindow"
-                            .to_string(),
+                        "Missing value while parsing ProjectRecentsResult".to_string(),
                     );
                 }
             };
@@ -4885,24 +14816,8 @@ impl std::str::FromStr for CaptureStatusResultCaptureMetadataWindow {
             if let Some(key) = key_result {
                 #[allow(clippy::match_single_binding)]
                 match key {
-                    #[allow(clippy::redundant_clone)]
-                    "id" => intermediate_rep.id.push(
-                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "title" => intermediate_rep.title.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "appName" => intermediate_rep.app_name.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    _ => {
-                        return std::result::Result::Err(
-                            "Unexpected key while parsing CaptureStatusResultCaptureMetadataWindow"
-                                .to_string(),
-                        );
-                    }
+                    "items" => return std::result::Result::Err("Parsing a container in this style is not supported in ProjectRecentsResult".to_string()),
+                    _ => return std::result::Result::Err("Unexpected key while parsing ProjectRecentsResult".to_string())
                 }
             }
 
@@ -4911,62 +14826,48 @@ impl std::str::FromStr for CaptureStatusResultCaptureMetadataWindow {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(CaptureStatusResultCaptureMetadataWindow {
-            id: intermediate_rep.id.into_iter().next().ok_or_else(|| {
-                "id missing in CaptureStatusResultCaptureMetadataWindow".to_string()
-            })?,
-            title: intermediate_rep.title.into_iter().next().ok_or_else(|| {
-                "title missing in CaptureStatusResultCaptureMetadataWindow".to_string()
-            })?,
-            app_name: intermediate_rep
-                .app_name
+        std::result::Result::Ok(ProjectRecentsResult {
+            items: intermediate_rep
+                .items
                 .into_iter()
                 .next()
-                .ok_or_else(|| {
-                    "appName missing in CaptureStatusResultCaptureMetadataWindow".to_string()
-                })?,
+                .ok_or_else(|| "items missing in ProjectRecentsResult".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<CaptureStatusResultCaptureMetadataWindow> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<ProjectRecentsResult> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<CaptureStatusResultCaptureMetadataWindow>>
-    for HeaderValue
-{
+impl std::convert::TryFrom<header::IntoHeaderValue<ProjectRecentsResult>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<CaptureStatusResultCaptureMetadataWindow>,
+        hdr_value: header::IntoHeaderValue<ProjectRecentsResult>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                r#"Invalid header value for CaptureStatusResultCaptureMetadataWindow - value: {hdr_value} is invalid {e}"#
+                r#"Invalid header value for ProjectRecentsResult - value: {hdr_value} is invalid {e}"#
             )),
         }
     }
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue>
-    for header::IntoHeaderValue<CaptureStatusResultCaptureMetadataWindow>
-{
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<ProjectRecentsResult> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <CaptureStatusResultCaptureMetadataWindow as std::str::FromStr>::from_str(
-                    value,
-                ) {
+                match <ProjectRecentsResult as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        r#"Unable to convert header value '{value}' into CaptureStatusResultCaptureMetadataWindow - {err}"#
+                        r#"Unable to convert header value '{value}' into ProjectRecentsResult - {err}"#
                     )),
                 }
             }
@@ -4979,146 +14880,102 @@ impl std::convert::TryFrom<HeaderValue>
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct CaptureTelemetry {
-    #[serde(rename = "sourceDroppedFrames")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub source_dropped_frames: Option<i32>,
-
-    #[serde(rename = "writerDroppedFrames")]
+pub struct ProjectSavePayload {
+    #[serde(rename = "projectPath")]
+    #[validate(custom(function = "check_xss_string"))]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub writer_dropped_frames: Option<i32>,
+    pub project_path: Option<String>,
 
-    #[serde(rename = "writerBackpressureDrops")]
+    #[serde(rename = "autoZoom")]
+    #[validate(nested)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub writer_backpressure_drops: Option<i32>,
+    pub auto_zoom: Option<models::AutoZoomSettings>,
 
-    #[serde(rename = "achievedFps")]
+    #[serde(rename = "backgroundFraming")]
+    #[validate(nested)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub achieved_fps: Option<f64>,
+    pub background_framing: Option<models::BackgroundFramingSettings>,
 
-    #[serde(rename = "cpuPercent")]
+    #[serde(rename = "shortcutOverlay")]
+    #[validate(nested)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub cpu_percent: Option<f64>,
+    pub shortcut_overlay: Option<models::ShortcutOverlaySettings>,
 
-    #[serde(rename = "memoryBytes")]
+    #[serde(rename = "pictureInPicture")]
+    #[validate(nested)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub memory_bytes: Option<f64>,
+    pub picture_in_picture: Option<models::PictureInPictureSettings>,
 
-    #[serde(rename = "recordingBitrateMbps")]
+    #[serde(rename = "cursorRendering")]
+    #[validate(nested)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub recording_bitrate_mbps: Option<f64>,
+    pub cursor_rendering: Option<models::CursorRenderingSettings>,
 
-    #[serde(rename = "captureCallbackMs")]
+    #[serde(rename = "clickEffects")]
+    #[validate(nested)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub capture_callback_ms: Option<f64>,
+    pub click_effects: Option<models::ClickEffectsSettings>,
 
-    #[serde(rename = "recordQueueLagMs")]
+    #[serde(rename = "reframe")]
+    #[validate(nested)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub record_queue_lag_ms: Option<f64>,
+    pub reframe: Option<models::ReframeSettings>,
 
-    #[serde(rename = "writerAppendMs")]
+    #[serde(rename = "windowExclusions")]
+    #[validate(nested)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub writer_append_ms: Option<f64>,
+    pub window_exclusions: Option<models::WindowExclusionSettings>,
 
-    #[serde(rename = "previewEncodeMs")]
+    #[serde(rename = "timeline")]
+    #[validate(nested)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub preview_encode_ms: Option<f64>,
+    pub timeline: Option<models::ExportRunPayloadTimeline>,
 }
 
-impl CaptureTelemetry {
+impl ProjectSavePayload {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new() -> CaptureTelemetry {
-        CaptureTelemetry {
-            source_dropped_frames: None,
-            writer_dropped_frames: None,
-            writer_backpressure_drops: None,
-            achieved_fps: None,
-            cpu_percent: None,
-            memory_bytes: None,
-            recording_bitrate_mbps: None,
-            capture_callback_ms: None,
-            record_queue_lag_ms: None,
-            writer_append_ms: None,
-            preview_encode_ms: None,
+    pub fn new() -> ProjectSavePayload {
+        ProjectSavePayload {
+            project_path: None,
+            auto_zoom: None,
+            background_framing: None,
+            shortcut_overlay: None,
+            picture_in_picture: None,
+            cursor_rendering: None,
+            click_effects: None,
+            reframe: None,
+            window_exclusions: None,
+            timeline: None,
         }
     }
 }
 
-/// Converts the CaptureTelemetry value to the Query Parameters representation (style=form, explode=false)
+/// Converts the ProjectSavePayload value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for CaptureTelemetry {
+impl std::fmt::Display for ProjectSavePayload {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
-            self.source_dropped_frames
-                .as_ref()
-                .map(|source_dropped_frames| {
-                    [
-                        "sourceDroppedFrames".to_string(),
-                        source_dropped_frames.to_string(),
-                    ]
-                    .join(",")
-                }),
-            self.writer_dropped_frames
-                .as_ref()
-                .map(|writer_dropped_frames| {
-                    [
-                        "writerDroppedFrames".to_string(),
-                        writer_dropped_frames.to_string(),
-                    ]
-                    .join(",")
-                }),
-            self.writer_backpressure_drops
-                .as_ref()
-                .map(|writer_backpressure_drops| {
-                    [
-                        "writerBackpressureDrops".to_string(),
-                        writer_backpressure_drops.to_string(),
-                    ]
-                    .join(",")
-                }),
-            self.achieved_fps.as_ref().map(|achieved_fps| {
-                ["achievedFps".to_string(), achieved_fps.to_string()].join(",")
-            }),
-            self.cpu_percent
-                .as_ref()
-                .map(|cpu_percent| ["cpuPercent".to_string(), cpu_percent.to_string()].join(",")),
-            self.memory_bytes.as_ref().map(|memory_bytes| {
-                ["memoryBytes".to_string(), memory_bytes.to_string()].join(",")
-            }),
-            self.recording_bitrate_mbps
-                .as_ref()
-                .map(|recording_bitrate_mbps| {
-                    [
-                        "recordingBitrateMbps".to_string(),
-                        recording_bitrate_mbps.to_string(),
-                    ]
-                    .join(",")
-                }),
-            self.capture_callback_ms
-                .as_ref()
-                .map(|capture_callback_ms| {
-                    [
-                        "captureCallbackMs".to_string(),
-                        capture_callback_ms.to_string(),
-                    ]
-                    .join(",")
-                }),
-            self.record_queue_lag_ms
-                .as_ref()
-                .map(|record_queue_lag_ms| {
-                    [
-                        "recordQueueLagMs".to_string(),
-                        record_queue_lag_ms.to_string(),
-                    ]
-                    .join(",")
-                }),
-            self.writer_append_ms.as_ref().map(|writer_append_ms| {
-                ["writerAppendMs".to_string(), writer_append_ms.to_string()].join(",")
-            }),
-            self.preview_encode_ms.as_ref().map(|preview_encode_ms| {
-                ["previewEncodeMs".to_string(), preview_encode_ms.to_string()].join(",")
+            self.project_path.as_ref().map(|project_path| {
+                ["projectPath".to_string(), project_path.to_string()].join(",")
             }),
+            // Skipping autoZoom in query parameter serialization
+
+            // Skipping backgroundFraming in query parameter serialization
+
+            // Skipping shortcutOverlay in query parameter serialization
+
+            // Skipping pictureInPicture in query parameter serialization
+
+            // Skipping cursorRendering in query parameter serialization
+
+            // Skipping clickEffects in query parameter serialization
+
+            // Skipping reframe in query parameter serialization
+
+            // Skipping windowExclusions in query parameter serialization
+
+            // Skipping timeline in query parameter serialization
         ];
 
         write!(
@@ -5129,10 +14986,10 @@ impl std::fmt::Display for CaptureTelemetry {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a CaptureTelemetry value
+/// Converts Query Parameters representation (style=form, explode=false) to a ProjectSavePayload value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for CaptureTelemetry {
+impl std::str::FromStr for ProjectSavePayload {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -5140,17 +14997,16 @@ impl std::str::FromStr for CaptureTelemetry {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub source_dropped_frames: Vec<i32>,
-            pub writer_dropped_frames: Vec<i32>,
-            pub writer_backpressure_drops: Vec<i32>,
-            pub achieved_fps: Vec<f64>,
-            pub cpu_percent: Vec<f64>,
-            pub memory_bytes: Vec<f64>,
-            pub recording_bitrate_mbps: Vec<f64>,
-            pub capture_callback_ms: Vec<f64>,
-            pub record_queue_lag_ms: Vec<f64>,
-            pub writer_append_ms: Vec<f64>,
-            pub preview_encode_ms: Vec<f64>,
+            pub project_path: Vec<String>,
+            pub auto_zoom: Vec<models::AutoZoomSettings>,
+            pub background_framing: Vec<models::BackgroundFramingSettings>,
+            pub shortcut_overlay: Vec<models::ShortcutOverlaySettings>,
+            pub picture_in_picture: Vec<models::PictureInPictureSettings>,
+            pub cursor_rendering: Vec<models::CursorRenderingSettings>,
+            pub click_effects: Vec<models::ClickEffectsSettings>,
+            pub reframe: Vec<models::ReframeSettings>,
+            pub window_exclusions: Vec<models::WindowExclusionSettings>,
+            pub timeline: Vec<models::ExportRunPayloadTimeline>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -5164,7 +15020,7 @@ impl std::str::FromStr for CaptureTelemetry {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing CaptureTelemetry".to_string(),
+                        "Missing value while parsing ProjectSavePayload".to_string(),
                     );
                 }
             };
@@ -5173,52 +15029,57 @@ impl std::str::FromStr for CaptureTelemetry {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "sourceDroppedFrames" => intermediate_rep.source_dropped_frames.push(
-                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "writerDroppedFrames" => intermediate_rep.writer_dropped_frames.push(
-                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "projectPath" => intermediate_rep.project_path.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "writerBackpressureDrops" => intermediate_rep.writer_backpressure_drops.push(
-                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "autoZoom" => intermediate_rep.auto_zoom.push(
+                        <models::AutoZoomSettings as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "achievedFps" => intermediate_rep.achieved_fps.push(
-                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "backgroundFraming" => intermediate_rep.background_framing.push(
+                        <models::BackgroundFramingSettings as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "cpuPercent" => intermediate_rep.cpu_percent.push(
-                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "shortcutOverlay" => intermediate_rep.shortcut_overlay.push(
+                        <models::ShortcutOverlaySettings as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "memoryBytes" => intermediate_rep.memory_bytes.push(
-                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "pictureInPicture" => intermediate_rep.picture_in_picture.push(
+                        <models::PictureInPictureSettings as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "recordingBitrateMbps" => intermediate_rep.recording_bitrate_mbps.push(
-                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "cursorRendering" => intermediate_rep.cursor_rendering.push(
+                        <models::CursorRenderingSettings as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "captureCallbackMs" => intermediate_rep.capture_callback_ms.push(
-                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "clickEffects" => intermediate_rep.click_effects.push(
+                        <models::ClickEffectsSettings as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "recordQueueLagMs" => intermediate_rep.record_queue_lag_ms.push(
-                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "reframe" => intermediate_rep.reframe.push(
+                        <models::ReframeSettings as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "writerAppendMs" => intermediate_rep.writer_append_ms.push(
-                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "windowExclusions" => intermediate_rep.window_exclusions.push(
+                        <models::WindowExclusionSettings as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "previewEncodeMs" => intermediate_rep.preview_encode_ms.push(
-                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "timeline" => intermediate_rep.timeline.push(
+                        <models::ExportRunPayloadTimeline as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing CaptureTelemetry".to_string(),
+                            "Unexpected key while parsing ProjectSavePayload".to_string(),
                         );
                     }
                 }
@@ -5229,57 +15090,53 @@ impl std::str::FromStr for CaptureTelemetry {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(CaptureTelemetry {
-            source_dropped_frames: intermediate_rep.source_dropped_frames.into_iter().next(),
-            writer_dropped_frames: intermediate_rep.writer_dropped_frames.into_iter().next(),
-            writer_backpressure_drops: intermediate_rep
-                .writer_backpressure_drops
-                .into_iter()
-                .next(),
-            achieved_fps: intermediate_rep.achieved_fps.into_iter().next(),
-            cpu_percent: intermediate_rep.cpu_percent.into_iter().next(),
-            memory_bytes: intermediate_rep.memory_bytes.into_iter().next(),
-            recording_bitrate_mbps: intermediate_rep.recording_bitrate_mbps.into_iter().next(),
-            capture_callback_ms: intermediate_rep.capture_callback_ms.into_iter().next(),
-            record_queue_lag_ms: intermediate_rep.record_queue_lag_ms.into_iter().next(),
-            writer_append_ms: intermediate_rep.writer_append_ms.into_iter().next(),
-            preview_encode_ms: intermediate_rep.preview_encode_ms.into_iter().next(),
+        std::result::Result::Ok(ProjectSavePayload {
+            project_path: intermediate_rep.project_path.into_iter().next(),
+            auto_zoom: intermediate_rep.auto_zoom.into_iter().next(),
+            background_framing: intermediate_rep.background_framing.into_iter().next(),
+            shortcut_overlay: intermediate_rep.shortcut_overlay.into_iter().next(),
+            picture_in_picture: intermediate_rep.picture_in_picture.into_iter().next(),
+            cursor_rendering: intermediate_rep.cursor_rendering.into_iter().next(),
+            click_effects: intermediate_rep.click_effects.into_iter().next(),
+            reframe: intermediate_rep.reframe.into_iter().next(),
+            window_exclusions: intermediate_rep.window_exclusions.into_iter().next(),
+            timeline: intermediate_rep.timeline.into_iter().next(),
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<CaptureTelemetry> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<ProjectSavePayload> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<CaptureTelemetry>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<ProjectSavePayload>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<CaptureTelemetry>,
+        hdr_value: header::IntoHeaderValue<ProjectSavePayload>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                r#"Invalid header value for CaptureTelemetry - value: {hdr_value} is invalid {e}"#
+                r#"Invalid header value for ProjectSavePayload - value: {hdr_value} is invalid {e}"#
             )),
         }
     }
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<CaptureTelemetry> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<ProjectSavePayload> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <CaptureTelemetry as std::str::FromStr>::from_str(value) {
+                match <ProjectSavePayload as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        r#"Unable to convert header value '{value}' into CaptureTelemetry - {err}"#
+                        r#"Unable to convert header value '{value}' into ProjectSavePayload - {err}"#
                     )),
                 }
             }
@@ -5292,89 +15149,145 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<CaptureTelem
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct DisplaySource {
-    #[serde(rename = "id")]
-    pub id: i32,
+pub struct ProjectState {
+    #[serde(rename = "projectPath")]
+    #[validate(custom(function = "check_xss_string"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project_path: Option<String>,
 
-    #[serde(rename = "displayName")]
+    #[serde(rename = "recordingURL")]
     #[validate(custom(function = "check_xss_string"))]
-    pub display_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recording_url: Option<String>,
 
-    #[serde(rename = "isPrimary")]
-    pub is_primary: bool,
+    #[serde(rename = "eventsURL")]
+    #[validate(custom(function = "check_xss_string"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub events_url: Option<String>,
 
-    #[serde(rename = "width")]
-    pub width: i32,
+    #[serde(rename = "lastRecordingTelemetry")]
+    #[validate(nested)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_recording_telemetry: Option<models::CaptureTelemetry>,
 
-    #[serde(rename = "height")]
-    pub height: i32,
+    #[serde(rename = "autoZoom")]
+    #[validate(nested)]
+    pub auto_zoom: models::AutoZoomSettings,
 
-    #[serde(rename = "pixelScale")]
+    #[serde(rename = "backgroundFraming")]
+    #[validate(nested)]
+    pub background_framing: models::BackgroundFramingSettings,
+
+    #[serde(rename = "shortcutOverlay")]
+    #[validate(nested)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub pixel_scale: Option<f64>,
+    pub shortcut_overlay: Option<models::ShortcutOverlaySettings>,
 
-    #[serde(rename = "refreshHz")]
+    #[serde(rename = "pictureInPicture")]
+    #[validate(nested)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub refresh_hz: Option<f64>,
+    pub picture_in_picture: Option<models::PictureInPictureSettings>,
 
-    /// Note: inline enums are not fully supported by openapi-generator
-    #[serde(rename = "supportedCaptureFrameRates")]
-    pub supported_capture_frame_rates: Vec<f64>,
+    #[serde(rename = "cursorRendering")]
+    #[validate(nested)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cursor_rendering: Option<models::CursorRenderingSettings>,
+
+    #[serde(rename = "clickEffects")]
+    #[validate(nested)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub click_effects: Option<models::ClickEffectsSettings>,
+
+    #[serde(rename = "reframe")]
+    #[validate(nested)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reframe: Option<models::ReframeSettings>,
+
+    #[serde(rename = "windowExclusions")]
+    #[validate(nested)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub window_exclusions: Option<models::WindowExclusionSettings>,
+
+    #[serde(rename = "timeline")]
+    #[validate(nested)]
+    pub timeline: models::ExportRunPayloadTimeline,
+
+    #[serde(rename = "captureMetadata")]
+    #[validate(nested)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capture_metadata: Option<models::CaptureStatusResultCaptureMetadata>,
+
+    #[serde(rename = "agentAnalysis")]
+    #[validate(nested)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub agent_analysis: Option<models::ProjectAgentAnalysisSummary>,
 }
 
-impl DisplaySource {
+impl ProjectState {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
     pub fn new(
-        id: i32,
-        display_name: String,
-        is_primary: bool,
-        width: i32,
-        height: i32,
-        supported_capture_frame_rates: Vec<f64>,
-    ) -> DisplaySource {
-        DisplaySource {
-            id,
-            display_name,
-            is_primary,
-            width,
-            height,
-            pixel_scale: None,
-            refresh_hz: None,
-            supported_capture_frame_rates,
+        auto_zoom: models::AutoZoomSettings,
+        background_framing: models::BackgroundFramingSettings,
+        timeline: models::ExportRunPayloadTimeline,
+    ) -> ProjectState {
+        ProjectState {
+            project_path: None,
+            recording_url: None,
+            events_url: None,
+            last_recording_telemetry: None,
+            auto_zoom,
+            background_framing,
+            shortcut_overlay: None,
+            picture_in_picture: None,
+            cursor_rendering: None,
+            click_effects: None,
+            reframe: None,
+            window_exclusions: None,
+            timeline,
+            capture_metadata: None,
+            agent_analysis: None,
         }
     }
 }
 
-/// Converts the DisplaySource value to the Query Parameters representation (style=form, explode=false)
+/// Converts the ProjectState value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for DisplaySource {
+impl std::fmt::Display for ProjectState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
-            Some("id".to_string()),
-            Some(self.id.to_string()),
-            Some("displayName".to_string()),
-            Some(self.display_name.to_string()),
-            Some("isPrimary".to_string()),
-            Some(self.is_primary.to_string()),
-            Some("width".to_string()),
-            Some(self.width.to_string()),
-            Some("height".to_string()),
-            Some(self.height.to_string()),
-            self.pixel_scale
-                .as_ref()
-                .map(|pixel_scale| ["pixelScale".to_string(), pixel_scale.to_string()].join(",")),
-            self.refresh_hz
+            self.project_path.as_ref().map(|project_path| {
+                ["projectPath".to_string(), project_path.to_string()].join(",")
+            }),
+            self.recording_url.as_ref().map(|recording_url| {
+                ["recordingURL".to_string(), recording_url.to_string()].join(",")
+            }),
+            self.events_url
                 .as_ref()
-                .map(|refresh_hz| ["refreshHz".to_string(), refresh_hz.to_string()].join(",")),
-            Some("supportedCaptureFrameRates".to_string()),
-            Some(
-                self.supported_capture_frame_rates
-                    .iter()
-                    .map(|x| x.to_string())
-                    .collect::<Vec<_>>()
-                    .join(","),
-            ),
+                .map(|events_url| ["eventsURL".to_string(), events_url.to_string()].join(",")),
+            // Skipping lastRecordingTelemetry in query parameter serialization
+
+            // Skipping autoZoom in query parameter serialization
+
+            // Skipping backgroundFraming in query parameter serialization
+
+            // Skipping shortcutOverlay in query parameter serialization
+
+            // Skipping pictureInPicture in query parameter serialization
+
+            // Skipping cursorRendering in query parameter serialization
+
+            // Skipping clickEffects in query parameter serialization
+
+            // Skipping reframe in query parameter serialization
+
+            // Skipping windowExclusions in query parameter serialization
+
+            // Skipping timeline in query parameter serialization
+
+            // Skipping captureMetadata in query parameter serialization
+
+            // Skipping agentAnalysis in query parameter serialization
         ];
 
         write!(
@@ -5385,10 +15298,10 @@ impl std::fmt::Display for DisplaySource {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a DisplaySource value
+/// Converts Query Parameters representation (style=form, explode=false) to a ProjectState value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for DisplaySource {
+impl std::str::FromStr for ProjectState {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -5396,14 +15309,21 @@ impl std::str::FromStr for DisplaySource {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub id: Vec<i32>,
-            pub display_name: Vec<String>,
-            pub is_primary: Vec<bool>,
-            pub width: Vec<i32>,
-            pub height: Vec<i32>,
-            pub pixel_scale: Vec<f64>,
-            pub refresh_hz: Vec<f64>,
-            pub supported_capture_frame_rates: Vec<Vec<f64>>,
+            pub project_path: Vec<String>,
+            pub recording_url: Vec<String>,
+            pub events_url: Vec<String>,
+            pub last_recording_telemetry: Vec<models::CaptureTelemetry>,
+            pub auto_zoom: Vec<models::AutoZoomSettings>,
+            pub background_framing: Vec<models::BackgroundFramingSettings>,
+            pub shortcut_overlay: Vec<models::ShortcutOverlaySettings>,
+            pub picture_in_picture: Vec<models::PictureInPictureSettings>,
+            pub cursor_rendering: Vec<models::CursorRenderingSettings>,
+            pub click_effects: Vec<models::ClickEffectsSettings>,
+            pub reframe: Vec<models::ReframeSettings>,
+            pub window_exclusions: Vec<models::WindowExclusionSettings>,
+            pub timeline: Vec<models::ExportRunPayloadTimeline>,
+            pub capture_metadata: Vec<models::CaptureStatusResultCaptureMetadata>,
+            pub agent_analysis: Vec<models::ProjectAgentAnalysisSummary>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -5417,7 +15337,7 @@ impl std::str::FromStr for DisplaySource {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing DisplaySource".to_string(),
+                        "Missing value while parsing ProjectState".to_string(),
                     );
                 }
             };
@@ -5426,44 +15346,36 @@ impl std::str::FromStr for DisplaySource {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "id" => intermediate_rep.id.push(
-                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
+                    "projectPath" => intermediate_rep.project_path.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
                     #[allow(clippy::redundant_clone)]
-                    "displayName" => intermediate_rep.display_name.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
+                    "recordingURL" => intermediate_rep.recording_url.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "eventsURL" => intermediate_rep.events_url.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "lastRecordingTelemetry" => intermediate_rep.last_recording_telemetry.push(<models::CaptureTelemetry as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "autoZoom" => intermediate_rep.auto_zoom.push(<models::AutoZoomSettings as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "backgroundFraming" => intermediate_rep.background_framing.push(<models::BackgroundFramingSettings as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "shortcutOverlay" => intermediate_rep.shortcut_overlay.push(<models::ShortcutOverlaySettings as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "pictureInPicture" => intermediate_rep.picture_in_picture.push(<models::PictureInPictureSettings as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "cursorRendering" => intermediate_rep.cursor_rendering.push(<models::CursorRenderingSettings as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
                     #[allow(clippy::redundant_clone)]
-                    "isPrimary" => intermediate_rep.is_primary.push(
-                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
+                    "clickEffects" => intermediate_rep.click_effects.push(<models::ClickEffectsSettings as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
                     #[allow(clippy::redundant_clone)]
-                    "width" => intermediate_rep.width.push(
-                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
+                    "reframe" => intermediate_rep.reframe.push(<models::ReframeSettings as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
                     #[allow(clippy::redundant_clone)]
-                    "height" => intermediate_rep.height.push(
-                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
+                    "windowExclusions" => intermediate_rep.window_exclusions.push(<models::WindowExclusionSettings as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
                     #[allow(clippy::redundant_clone)]
-                    "pixelScale" => intermediate_rep.pixel_scale.push(
-                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
+                    "timeline" => intermediate_rep.timeline.push(<models::ExportRunPayloadTimeline as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
                     #[allow(clippy::redundant_clone)]
-                    "refreshHz" => intermediate_rep.refresh_hz.push(
-                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    "supportedCaptureFrameRates" => {
-                        return std::result::Result::Err(
-                            "Parsing a container in this style is not supported in DisplaySource"
-                                .to_string(),
-                        );
-                    }
-                    _ => {
-                        return std::result::Result::Err(
-                            "Unexpected key while parsing DisplaySource".to_string(),
-                        );
-                    }
+                    "captureMetadata" => intermediate_rep.capture_metadata.push(<models::CaptureStatusResultCaptureMetadata as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "agentAnalysis" => intermediate_rep.agent_analysis.push(<models::ProjectAgentAnalysisSummary as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing ProjectState".to_string())
                 }
             }
 
@@ -5472,75 +15384,70 @@ impl std::str::FromStr for DisplaySource {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(DisplaySource {
-            id: intermediate_rep
-                .id
-                .into_iter()
-                .next()
-                .ok_or_else(|| "id missing in DisplaySource".to_string())?,
-            display_name: intermediate_rep
-                .display_name
-                .into_iter()
-                .next()
-                .ok_or_else(|| "displayName missing in DisplaySource".to_string())?,
-            is_primary: intermediate_rep
-                .is_primary
-                .into_iter()
-                .next()
-                .ok_or_else(|| "isPrimary missing in DisplaySource".to_string())?,
-            width: intermediate_rep
-                .width
+        std::result::Result::Ok(ProjectState {
+            project_path: intermediate_rep.project_path.into_iter().next(),
+            recording_url: intermediate_rep.recording_url.into_iter().next(),
+            events_url: intermediate_rep.events_url.into_iter().next(),
+            last_recording_telemetry: intermediate_rep.last_recording_telemetry.into_iter().next(),
+            auto_zoom: intermediate_rep
+                .auto_zoom
                 .into_iter()
                 .next()
-                .ok_or_else(|| "width missing in DisplaySource".to_string())?,
-            height: intermediate_rep
-                .height
+                .ok_or_else(|| "autoZoom missing in ProjectState".to_string())?,
+            background_framing: intermediate_rep
+                .background_framing
                 .into_iter()
                 .next()
-                .ok_or_else(|| "height missing in DisplaySource".to_string())?,
-            pixel_scale: intermediate_rep.pixel_scale.into_iter().next(),
-            refresh_hz: intermediate_rep.refresh_hz.into_iter().next(),
-            supported_capture_frame_rates: intermediate_rep
-                .supported_capture_frame_rates
+                .ok_or_else(|| "backgroundFraming missing in ProjectState".to_string())?,
+            shortcut_overlay: intermediate_rep.shortcut_overlay.into_iter().next(),
+            picture_in_picture: intermediate_rep.picture_in_picture.into_iter().next(),
+            cursor_rendering: intermediate_rep.cursor_rendering.into_iter().next(),
+            click_effects: intermediate_rep.click_effects.into_iter().next(),
+            reframe: intermediate_rep.reframe.into_iter().next(),
+            window_exclusions: intermediate_rep.window_exclusions.into_iter().next(),
+            timeline: intermediate_rep
+                .timeline
                 .into_iter()
                 .next()
-                .ok_or_else(|| "supportedCaptureFrameRates missing in DisplaySource".to_string())?,
+                .ok_or_else(|| "timeline missing in ProjectState".to_string())?,
+            capture_metadata: intermediate_rep.capture_metadata.into_iter().next(),
+            agent_analysis: intermediate_rep.agent_analysis.into_iter().next(),
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<DisplaySource> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<ProjectState> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<DisplaySource>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<ProjectState>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<DisplaySource>,
+        hdr_value: header::IntoHeaderValue<ProjectState>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                r#"Invalid header value for DisplaySource - value: {hdr_value} is invalid {e}"#
+                r#"Invalid header value for ProjectState - value: {hdr_value} is invalid {e}"#
             )),
         }
     }
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<DisplaySource> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<ProjectState> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <DisplaySource as std::str::FromStr>::from_str(value) {
+                match <ProjectState as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        r#"Unable to convert header value '{value}' into DisplaySource - {err}"#
+                        r#"Unable to convert header value '{value}' into ProjectState - {err}"#
                     )),
                 }
             }
@@ -5551,37 +15458,42 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<DisplaySourc
     }
 }
 
-/// EngineBadRequestError response body.
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct EngineBadRequestError {
-    /// Note: inline enums are not fully supported by openapi-generator
-    #[serde(rename = "code")]
+pub struct RecordingAddMarkerPayload {
+    #[serde(rename = "label")]
     #[validate(custom(function = "check_xss_string"))]
-    pub code: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
 
-    #[serde(rename = "message")]
+    #[serde(rename = "color")]
     #[validate(custom(function = "check_xss_string"))]
-    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
 }
 
-impl EngineBadRequestError {
+impl RecordingAddMarkerPayload {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new(code: String, message: String) -> EngineBadRequestError {
-        EngineBadRequestError { code, message }
+    pub fn new() -> RecordingAddMarkerPayload {
+        RecordingAddMarkerPayload {
+            label: None,
+            color: None,
+        }
     }
 }
 
-/// Converts the EngineBadRequestError value to the Query Parameters representation (style=form, explode=false)
+/// Converts the RecordingAddMarkerPayload value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for EngineBadRequestError {
+impl std::fmt::Display for RecordingAddMarkerPayload {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
-            Some("code".to_string()),
-            Some(self.code.to_string()),
-            Some("message".to_string()),
-            Some(self.message.to_string()),
+            self.label
+                .as_ref()
+                .map(|label| ["label".to_string(), label.to_string()].join(",")),
+            self.color
+                .as_ref()
+                .map(|color| ["color".to_string(), color.to_string()].join(",")),
         ];
 
         write!(
@@ -5592,10 +15504,10 @@ impl std::fmt::Display for EngineBadRequestError {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a EngineBadRequestError value
+/// Converts Query Parameters representation (style=form, explode=false) to a RecordingAddMarkerPayload value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for EngineBadRequestError {
+impl std::str::FromStr for RecordingAddMarkerPayload {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -5603,8 +15515,8 @@ impl std::str::FromStr for EngineBadRequestError {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub code: Vec<String>,
-            pub message: Vec<String>,
+            pub label: Vec<String>,
+            pub color: Vec<String>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -5618,7 +15530,7 @@ impl std::str::FromStr for EngineBadRequestError {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing EngineBadRequestError".to_string(),
+                        "Missing value while parsing RecordingAddMarkerPayload".to_string(),
                     );
                 }
             };
@@ -5627,16 +15539,16 @@ impl std::str::FromStr for EngineBadRequestError {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "code" => intermediate_rep.code.push(
+                    "label" => intermediate_rep.label.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "message" => intermediate_rep.message.push(
+                    "color" => intermediate_rep.color.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing EngineBadRequestError".to_string(),
+                            "Unexpected key while parsing RecordingAddMarkerPayload".to_string(),
                         );
                     }
                 }
@@ -5647,53 +15559,45 @@ impl std::str::FromStr for EngineBadRequestError {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(EngineBadRequestError {
-            code: intermediate_rep
-                .code
-                .into_iter()
-                .next()
-                .ok_or_else(|| "code missing in EngineBadRequestError".to_string())?,
-            message: intermediate_rep
-                .message
-                .into_iter()
-                .next()
-                .ok_or_else(|| "message missing in EngineBadRequestError".to_string())?,
+        std::result::Result::Ok(RecordingAddMarkerPayload {
+            label: intermediate_rep.label.into_iter().next(),
+            color: intermediate_rep.color.into_iter().next(),
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<EngineBadRequestError> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<RecordingAddMarkerPayload> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<EngineBadRequestError>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<RecordingAddMarkerPayload>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<EngineBadRequestError>,
+        hdr_value: header::IntoHeaderValue<RecordingAddMarkerPayload>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                r#"Invalid header value for EngineBadRequestError - value: {hdr_value} is invalid {e}"#
+                r#"Invalid header value for RecordingAddMarkerPayload - value: {hdr_value} is invalid {e}"#
             )),
         }
     }
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<EngineBadRequestError> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<RecordingAddMarkerPayload> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <EngineBadRequestError as std::str::FromStr>::from_str(value) {
+                match <RecordingAddMarkerPayload as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        r#"Unable to convert header value '{value}' into EngineBadRequestError - {err}"#
+                        r#"Unable to convert header value '{value}' into RecordingAddMarkerPayload - {err}"#
                     )),
                 }
             }
@@ -5704,37 +15608,29 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<EngineBadReq
     }
 }
 
-/// EngineConflictError response body.
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct EngineConflictError {
-    /// Note: inline enums are not fully supported by openapi-generator
-    #[serde(rename = "code")]
-    #[validate(custom(function = "check_xss_string"))]
-    pub code: String,
-
-    #[serde(rename = "message")]
-    #[validate(custom(function = "check_xss_string"))]
-    pub message: String,
+pub struct RecordingAddMarkerResult {
+    #[serde(rename = "marker")]
+    #[validate(nested)]
+    pub marker: models::RecordingMarker,
 }
 
-impl EngineConflictError {
+impl RecordingAddMarkerResult {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new(code: String, message: String) -> EngineConflictError {
-        EngineConflictError { code, message }
+    pub fn new(marker: models::RecordingMarker) -> RecordingAddMarkerResult {
+        RecordingAddMarkerResult { marker }
     }
 }
 
-/// Converts the EngineConflictError value to the Query Parameters representation (style=form, explode=false)
+/// Converts the RecordingAddMarkerResult value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for EngineConflictError {
+impl std::fmt::Display for RecordingAddMarkerResult {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
-            Some("code".to_string()),
-            Some(self.code.to_string()),
-            Some("message".to_string()),
-            Some(self.message.to_string()),
+            // Skipping marker in query parameter serialization
+
         ];
 
         write!(
@@ -5745,10 +15641,10 @@ impl std::fmt::Display for EngineConflictError {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a EngineConflictError value
+/// Converts Query Parameters representation (style=form, explode=false) to a RecordingAddMarkerResult value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for EngineConflictError {
+impl std::str::FromStr for RecordingAddMarkerResult {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -5756,8 +15652,7 @@ impl std::str::FromStr for EngineConflictError {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub code: Vec<String>,
-            pub message: Vec<String>,
+            pub marker: Vec<models::RecordingMarker>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -5771,7 +15666,7 @@ impl std::str::FromStr for EngineConflictError {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing EngineConflictError".to_string(),
+                        "Missing value while parsing RecordingAddMarkerResult".to_string(),
                     );
                 }
             };
@@ -5780,16 +15675,13 @@ impl std::str::FromStr for EngineConflictError {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "code" => intermediate_rep.code.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "message" => intermediate_rep.message.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "marker" => intermediate_rep.marker.push(
+                        <models::RecordingMarker as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing EngineConflictError".to_string(),
+                            "Unexpected key while parsing RecordingAddMarkerResult".to_string(),
                         );
                     }
                 }
@@ -5800,53 +15692,48 @@ impl std::str::FromStr for EngineConflictError {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(EngineConflictError {
-            code: intermediate_rep
-                .code
-                .into_iter()
-                .next()
-                .ok_or_else(|| "code missing in EngineConflictError".to_string())?,
-            message: intermediate_rep
-                .message
+        std::result::Result::Ok(RecordingAddMarkerResult {
+            marker: intermediate_rep
+                .marker
                 .into_iter()
                 .next()
-                .ok_or_else(|| "message missing in EngineConflictError".to_string())?,
+                .ok_or_else(|| "marker missing in RecordingAddMarkerResult".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<EngineConflictError> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<RecordingAddMarkerResult> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<EngineConflictError>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<RecordingAddMarkerResult>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<EngineConflictError>,
+        hdr_value: header::IntoHeaderValue<RecordingAddMarkerResult>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                r#"Invalid header value for EngineConflictError - value: {hdr_value} is invalid {e}"#
+                r#"Invalid header value for RecordingAddMarkerResult - value: {hdr_value} is invalid {e}"#
             )),
         }
     }
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<EngineConflictError> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<RecordingAddMarkerResult> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <EngineConflictError as std::str::FromStr>::from_str(value) {
+                match <RecordingAddMarkerResult as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        r#"Unable to convert header value '{value}' into EngineConflictError - {err}"#
+                        r#"Unable to convert header value '{value}' into RecordingAddMarkerResult - {err}"#
                     )),
                 }
             }
@@ -5857,37 +15744,53 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<EngineConfli
     }
 }
 
-/// EngineForbiddenError response body.
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct EngineForbiddenError {
-    /// Note: inline enums are not fully supported by openapi-generator
-    #[serde(rename = "code")]
+pub struct RecordingMarker {
+    #[serde(rename = "id")]
     #[validate(custom(function = "check_xss_string"))]
-    pub code: String,
+    pub id: String,
 
-    #[serde(rename = "message")]
+    #[serde(rename = "atSeconds")]
+    pub at_seconds: f64,
+
+    #[serde(rename = "label")]
     #[validate(custom(function = "check_xss_string"))]
-    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+
+    #[serde(rename = "color")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub color: String,
 }
 
-impl EngineForbiddenError {
+impl RecordingMarker {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new(code: String, message: String) -> EngineForbiddenError {
-        EngineForbiddenError { code, message }
+    pub fn new(id: String, at_seconds: f64, color: String) -> RecordingMarker {
+        RecordingMarker {
+            id,
+            at_seconds,
+            label: None,
+            color,
+        }
     }
 }
 
-/// Converts the EngineForbiddenError value to the Query Parameters representation (style=form, explode=false)
+/// Converts the RecordingMarker value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for EngineForbiddenError {
+impl std::fmt::Display for RecordingMarker {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
-            Some("code".to_string()),
-            Some(self.code.to_string()),
-            Some("message".to_string()),
-            Some(self.message.to_string()),
+            Some("id".to_string()),
+            Some(self.id.to_string()),
+            Some("atSeconds".to_string()),
+            Some(self.at_seconds.to_string()),
+            self.label
+                .as_ref()
+                .map(|label| ["label".to_string(), label.to_string()].join(",")),
+            Some("color".to_string()),
+            Some(self.color.to_string()),
         ];
 
         write!(
@@ -5898,10 +15801,10 @@ impl std::fmt::Display for EngineForbiddenError {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a EngineForbiddenError value
+/// Converts Query Parameters representation (style=form, explode=false) to a RecordingMarker value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for EngineForbiddenError {
+impl std::str::FromStr for RecordingMarker {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -5909,8 +15812,10 @@ impl std::str::FromStr for EngineForbiddenError {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub code: Vec<String>,
-            pub message: Vec<String>,
+            pub id: Vec<String>,
+            pub at_seconds: Vec<f64>,
+            pub label: Vec<String>,
+            pub color: Vec<String>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -5924,7 +15829,7 @@ impl std::str::FromStr for EngineForbiddenError {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing EngineForbiddenError".to_string(),
+                        "Missing value while parsing RecordingMarker".to_string(),
                     );
                 }
             };
@@ -5933,16 +15838,24 @@ impl std::str::FromStr for EngineForbiddenError {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "code" => intermediate_rep.code.push(
+                    "id" => intermediate_rep.id.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "message" => intermediate_rep.message.push(
+                    "atSeconds" => intermediate_rep.at_seconds.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "label" => intermediate_rep.label.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "color" => intermediate_rep.color.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing EngineForbiddenError".to_string(),
+                            "Unexpected key while parsing RecordingMarker".to_string(),
                         );
                     }
                 }
@@ -5953,53 +15866,59 @@ impl std::str::FromStr for EngineForbiddenError {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(EngineForbiddenError {
-            code: intermediate_rep
-                .code
+        std::result::Result::Ok(RecordingMarker {
+            id: intermediate_rep
+                .id
                 .into_iter()
                 .next()
-                .ok_or_else(|| "code missing in EngineForbiddenError".to_string())?,
-            message: intermediate_rep
-                .message
+                .ok_or_else(|| "id missing in RecordingMarker".to_string())?,
+            at_seconds: intermediate_rep
+                .at_seconds
                 .into_iter()
                 .next()
-                .ok_or_else(|| "message missing in EngineForbiddenError".to_string())?,
+                .ok_or_else(|| "atSeconds missing in RecordingMarker".to_string())?,
+            label: intermediate_rep.label.into_iter().next(),
+            color: intermediate_rep
+                .color
+                .into_iter()
+                .next()
+                .ok_or_else(|| "color missing in RecordingMarker".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<EngineForbiddenError> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<RecordingMarker> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<EngineForbiddenError>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<RecordingMarker>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<EngineForbiddenError>,
+        hdr_value: header::IntoHeaderValue<RecordingMarker>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                r#"Invalid header value for EngineForbiddenError - value: {hdr_value} is invalid {e}"#
+                r#"Invalid header value for RecordingMarker - value: {hdr_value} is invalid {e}"#
             )),
         }
     }
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<EngineForbiddenError> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<RecordingMarker> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <EngineForbiddenError as std::str::FromStr>::from_str(value) {
+                match <RecordingMarker as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        r#"Unable to convert header value '{value}' into EngineForbiddenError - {err}"#
+                        r#"Unable to convert header value '{value}' into RecordingMarker - {err}"#
                     )),
                 }
             }
@@ -6010,37 +15929,47 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<EngineForbid
     }
 }
 
-/// EngineNotFoundError response body.
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct EngineNotFoundError {
-    /// Note: inline enums are not fully supported by openapi-generator
-    #[serde(rename = "code")]
-    #[validate(custom(function = "check_xss_string"))]
-    pub code: String,
+pub struct RecordingMetadata {
+    #[serde(rename = "pauses")]
+    #[validate(nested)]
+    pub pauses: Vec<models::RecordingPauseRange>,
 
-    #[serde(rename = "message")]
-    #[validate(custom(function = "check_xss_string"))]
-    pub message: String,
+    #[serde(rename = "markers")]
+    #[validate(nested)]
+    pub markers: Vec<models::RecordingMarker>,
+
+    #[serde(rename = "recovered")]
+    pub recovered: bool,
 }
 
-impl EngineNotFoundError {
+impl RecordingMetadata {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new(code: String, message: String) -> EngineNotFoundError {
-        EngineNotFoundError { code, message }
+    pub fn new(
+        pauses: Vec<models::RecordingPauseRange>,
+        markers: Vec<models::RecordingMarker>,
+        recovered: bool,
+    ) -> RecordingMetadata {
+        RecordingMetadata {
+            pauses,
+            markers,
+            recovered,
+        }
     }
 }
 
-/// Converts the EngineNotFoundError value to the Query Parameters representation (style=form, explode=false)
+/// Converts the RecordingMetadata value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for EngineNotFoundError {
+impl std::fmt::Display for RecordingMetadata {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
-            Some("code".to_string()),
-            Some(self.code.to_string()),
-            Some("message".to_string()),
-            Some(self.message.to_string()),
+            // Skipping pauses in query parameter serialization
+
+            // Skipping markers in query parameter serialization
+            Some("recovered".to_string()),
+            Some(self.recovered.to_string()),
         ];
 
         write!(
@@ -6051,10 +15980,10 @@ impl std::fmt::Display for EngineNotFoundError {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a EngineNotFoundError value
+/// Converts Query Parameters representation (style=form, explode=false) to a RecordingMetadata value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for EngineNotFoundError {
+impl std::str::FromStr for RecordingMetadata {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -6062,8 +15991,9 @@ impl std::str::FromStr for EngineNotFoundError {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub code: Vec<String>,
-            pub message: Vec<String>,
+            pub pauses: Vec<Vec<models::RecordingPauseRange>>,
+            pub markers: Vec<Vec<models::RecordingMarker>>,
+            pub recovered: Vec<bool>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -6077,7 +16007,7 @@ impl std::str::FromStr for EngineNotFoundError {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing EngineNotFoundError".to_string(),
+                        "Missing value while parsing RecordingMetadata".to_string(),
                     );
                 }
             };
@@ -6085,17 +16015,21 @@ impl std::str::FromStr for EngineNotFoundError {
             if let Some(key) = key_result {
                 #[allow(clippy::match_single_binding)]
                 match key {
-                    #[allow(clippy::redundant_clone)]
-                    "code" => intermediate_rep.code.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "pauses" => return std::result::Result::Err(
+                        "Parsing a container in this style is not supported in RecordingMetadata"
+                            .to_string(),
+                    ),
+                    "markers" => return std::result::Result::Err(
+                        "Parsing a container in this style is not supported in RecordingMetadata"
+                            .to_string(),
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "message" => intermediate_rep.message.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "recovered" => intermediate_rep.recovered.push(
+                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing EngineNotFoundError".to_string(),
+                            "Unexpected key while parsing RecordingMetadata".to_string(),
                         );
                     }
                 }
@@ -6106,53 +16040,58 @@ impl std::str::FromStr for EngineNotFoundError {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(EngineNotFoundError {
-            code: intermediate_rep
-                .code
+        std::result::Result::Ok(RecordingMetadata {
+            pauses: intermediate_rep
+                .pauses
                 .into_iter()
                 .next()
-                .ok_or_else(|| "code missing in EngineNotFoundError".to_string())?,
-            message: intermediate_rep
-                .message
+                .ok_or_else(|| "pauses missing in RecordingMetadata".to_string())?,
+            markers: intermediate_rep
+                .markers
                 .into_iter()
                 .next()
-                .ok_or_else(|| "message missing in EngineNotFoundError".to_string())?,
+                .ok_or_else(|| "markers missing in RecordingMetadata".to_string())?,
+            recovered: intermediate_rep
+                .recovered
+                .into_iter()
+                .next()
+                .ok_or_else(|| "recovered missing in RecordingMetadata".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<EngineNotFoundError> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<RecordingMetadata> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<EngineNotFoundError>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<RecordingMetadata>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<EngineNotFoundError>,
+        hdr_value: header::IntoHeaderValue<RecordingMetadata>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                r#"Invalid header value for EngineNotFoundError - value: {hdr_value} is invalid {e}"#
+                r#"Invalid header value for RecordingMetadata - value: {hdr_value} is invalid {e}"#
             )),
         }
     }
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<EngineNotFoundError> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<RecordingMetadata> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <EngineNotFoundError as std::str::FromStr>::from_str(value) {
+                match <RecordingMetadata as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        r#"Unable to convert header value '{value}' into EngineNotFoundError - {err}"#
+                        r#"Unable to convert header value '{value}' into RecordingMetadata - {err}"#
                     )),
                 }
             }
@@ -6163,37 +16102,36 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<EngineNotFou
     }
 }
 
-/// EngineRuntimeError response body.
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct EngineRuntimeError {
-    /// Note: inline enums are not fully supported by openapi-generator
-    #[serde(rename = "code")]
-    #[validate(custom(function = "check_xss_string"))]
-    pub code: String,
+pub struct RecordingPauseRange {
+    #[serde(rename = "atSeconds")]
+    pub at_seconds: f64,
 
-    #[serde(rename = "message")]
-    #[validate(custom(function = "check_xss_string"))]
-    pub message: String,
+    #[serde(rename = "durationSeconds")]
+    pub duration_seconds: f64,
 }
 
-impl EngineRuntimeError {
+impl RecordingPauseRange {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new(code: String, message: String) -> EngineRuntimeError {
-        EngineRuntimeError { code, message }
+    pub fn new(at_seconds: f64, duration_seconds: f64) -> RecordingPauseRange {
+        RecordingPauseRange {
+            at_seconds,
+            duration_seconds,
+        }
     }
 }
 
-/// Converts the EngineRuntimeError value to the Query Parameters representation (style=form, explode=false)
+/// Converts the RecordingPauseRange value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for EngineRuntimeError {
+impl std::fmt::Display for RecordingPauseRange {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
-            Some("code".to_string()),
-            Some(self.code.to_string()),
-            Some("message".to_string()),
-            Some(self.message.to_string()),
+            Some("atSeconds".to_string()),
+            Some(self.at_seconds.to_string()),
+            Some("durationSeconds".to_string()),
+            Some(self.duration_seconds.to_string()),
         ];
 
         write!(
@@ -6204,10 +16142,10 @@ impl std::fmt::Display for EngineRuntimeError {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a EngineRuntimeError value
+/// Converts Query Parameters representation (style=form, explode=false) to a RecordingPauseRange value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for EngineRuntimeError {
+impl std::str::FromStr for RecordingPauseRange {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -6215,8 +16153,8 @@ impl std::str::FromStr for EngineRuntimeError {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub code: Vec<String>,
-            pub message: Vec<String>,
+            pub at_seconds: Vec<f64>,
+            pub duration_seconds: Vec<f64>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -6230,7 +16168,7 @@ impl std::str::FromStr for EngineRuntimeError {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing EngineRuntimeError".to_string(),
+                        "Missing value while parsing RecordingPauseRange".to_string(),
                     );
                 }
             };
@@ -6239,16 +16177,16 @@ impl std::str::FromStr for EngineRuntimeError {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "code" => intermediate_rep.code.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "atSeconds" => intermediate_rep.at_seconds.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "message" => intermediate_rep.message.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "durationSeconds" => intermediate_rep.duration_seconds.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing EngineRuntimeError".to_string(),
+                            "Unexpected key while parsing RecordingPauseRange".to_string(),
                         );
                     }
                 }
@@ -6259,53 +16197,53 @@ impl std::str::FromStr for EngineRuntimeError {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(EngineRuntimeError {
-            code: intermediate_rep
-                .code
+        std::result::Result::Ok(RecordingPauseRange {
+            at_seconds: intermediate_rep
+                .at_seconds
                 .into_iter()
                 .next()
-                .ok_or_else(|| "code missing in EngineRuntimeError".to_string())?,
-            message: intermediate_rep
-                .message
+                .ok_or_else(|| "atSeconds missing in RecordingPauseRange".to_string())?,
+            duration_seconds: intermediate_rep
+                .duration_seconds
                 .into_iter()
                 .next()
-                .ok_or_else(|| "message missing in EngineRuntimeError".to_string())?,
+                .ok_or_else(|| "durationSeconds missing in RecordingPauseRange".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<EngineRuntimeError> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<RecordingPauseRange> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<EngineRuntimeError>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<RecordingPauseRange>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<EngineRuntimeError>,
+        hdr_value: header::IntoHeaderValue<RecordingPauseRange>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                r#"Invalid header value for EngineRuntimeError - value: {hdr_value} is invalid {e}"#
+                r#"Invalid header value for RecordingPauseRange - value: {hdr_value} is invalid {e}"#
             )),
         }
     }
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<EngineRuntimeError> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<RecordingPauseRange> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <EngineRuntimeError as std::str::FromStr>::from_str(value) {
+                match <RecordingPauseRange as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        r#"Unable to convert header value '{value}' into EngineRuntimeError - {err}"#
+                        r#"Unable to convert header value '{value}' into RecordingPauseRange - {err}"#
                     )),
                 }
             }
@@ -6316,37 +16254,29 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<EngineRuntim
     }
 }
 
-/// EngineUnauthorizedError response body.
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct EngineUnauthorizedError {
-    /// Note: inline enums are not fully supported by openapi-generator
-    #[serde(rename = "code")]
-    #[validate(custom(function = "check_xss_string"))]
-    pub code: String,
-
-    #[serde(rename = "message")]
-    #[validate(custom(function = "check_xss_string"))]
-    pub message: String,
+pub struct RecordingRecoverResult {
+    #[serde(rename = "recordings")]
+    #[validate(nested)]
+    pub recordings: Vec<models::RecoveredRecording>,
 }
 
-impl EngineUnauthorizedError {
+impl RecordingRecoverResult {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new(code: String, message: String) -> EngineUnauthorizedError {
-        EngineUnauthorizedError { code, message }
+    pub fn new(recordings: Vec<models::RecoveredRecording>) -> RecordingRecoverResult {
+        RecordingRecoverResult { recordings }
     }
 }
 
-/// Converts the EngineUnauthorizedError value to the Query Parameters representation (style=form, explode=false)
+/// Converts the RecordingRecoverResult value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for EngineUnauthorizedError {
+impl std::fmt::Display for RecordingRecoverResult {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
-            Some("code".to_string()),
-            Some(self.code.to_string()),
-            Some("message".to_string()),
-            Some(self.message.to_string()),
+            // Skipping recordings in query parameter serialization
+
         ];
 
         write!(
@@ -6357,10 +16287,10 @@ impl std::fmt::Display for EngineUnauthorizedError {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a EngineUnauthorizedError value
+/// Converts Query Parameters representation (style=form, explode=false) to a RecordingRecoverResult value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for EngineUnauthorizedError {
+impl std::str::FromStr for RecordingRecoverResult {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -6368,8 +16298,7 @@ impl std::str::FromStr for EngineUnauthorizedError {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub code: Vec<String>,
-            pub message: Vec<String>,
+            pub recordings: Vec<Vec<models::RecoveredRecording>>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -6383,7 +16312,7 @@ impl std::str::FromStr for EngineUnauthorizedError {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing EngineUnauthorizedError".to_string(),
+                        "Missing value while parsing RecordingRecoverResult".to_string(),
                     );
                 }
             };
@@ -6391,19 +16320,8 @@ impl std::str::FromStr for EngineUnauthorizedError {
             if let Some(key) = key_result {
                 #[allow(clippy::match_single_binding)]
                 match key {
-                    #[allow(clippy::redundant_clone)]
-                    "code" => intermediate_rep.code.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "message" => intermediate_rep.message.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    _ => {
-                        return std::result::Result::Err(
-                            "Unexpected key while parsing EngineUnauthorizedError".to_string(),
-                        );
-                    }
+                    "recordings" => return std::result::Result::Err("Parsing a container in this style is not supported in RecordingRecoverResult".to_string()),
+                    _ => return std::result::Result::Err("Unexpected key while parsing RecordingRecoverResult".to_string())
                 }
             }
 
@@ -6412,53 +16330,48 @@ impl std::str::FromStr for EngineUnauthorizedError {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(EngineUnauthorizedError {
-            code: intermediate_rep
-                .code
-                .into_iter()
-                .next()
-                .ok_or_else(|| "code missing in EngineUnauthorizedError".to_string())?,
-            message: intermediate_rep
-                .message
+        std::result::Result::Ok(RecordingRecoverResult {
+            recordings: intermediate_rep
+                .recordings
                 .into_iter()
                 .next()
-                .ok_or_else(|| "message missing in EngineUnauthorizedError".to_string())?,
+                .ok_or_else(|| "recordings missing in RecordingRecoverResult".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<EngineUnauthorizedError> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<RecordingRecoverResult> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<EngineUnauthorizedError>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<RecordingRecoverResult>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<EngineUnauthorizedError>,
+        hdr_value: header::IntoHeaderValue<RecordingRecoverResult>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                r#"Invalid header value for EngineUnauthorizedError - value: {hdr_value} is invalid {e}"#
+                r#"Invalid header value for RecordingRecoverResult - value: {hdr_value} is invalid {e}"#
             )),
         }
     }
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<EngineUnauthorizedError> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<RecordingRecoverResult> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <EngineUnauthorizedError as std::str::FromStr>::from_str(value) {
+                match <RecordingRecoverResult as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        r#"Unable to convert header value '{value}' into EngineUnauthorizedError - {err}"#
+                        r#"Unable to convert header value '{value}' into RecordingRecoverResult - {err}"#
                     )),
                 }
             }
@@ -6469,37 +16382,155 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<EngineUnauth
     }
 }
 
-/// EngineUnprocessableError response body.
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct EngineUnprocessableError {
-    /// Note: inline enums are not fully supported by openapi-generator
-    #[serde(rename = "code")]
+pub struct RecordingStartPayload {
+    #[serde(rename = "trackInputEvents")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub track_input_events: Option<bool>,
+
+    #[serde(rename = "trackKeyboardShortcuts")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub track_keyboard_shortcuts: Option<bool>,
+
+    #[serde(rename = "captureMicrophone")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capture_microphone: Option<bool>,
+
+    #[serde(rename = "microphoneDeviceId")]
     #[validate(custom(function = "check_xss_string"))]
-    pub code: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub microphone_device_id: Option<String>,
 
-    #[serde(rename = "message")]
+    #[serde(rename = "captureSystemAudio")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capture_system_audio: Option<bool>,
+
+    #[serde(rename = "systemAudioDeviceId")]
     #[validate(custom(function = "check_xss_string"))]
-    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub system_audio_device_id: Option<String>,
+
+    #[serde(rename = "silenceWarningSeconds")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub silence_warning_seconds: Option<f64>,
+
+    #[serde(rename = "countdownSeconds")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub countdown_seconds: Option<f64>,
+
+    #[serde(rename = "maxDurationSeconds")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_duration_seconds: Option<f64>,
+
+    #[serde(rename = "stopAt")]
+    #[validate(custom(function = "check_xss_string"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop_at: Option<String>,
 }
 
-impl EngineUnprocessableError {
+impl RecordingStartPayload {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new(code: String, message: String) -> EngineUnprocessableError {
-        EngineUnprocessableError { code, message }
+    pub fn new() -> RecordingStartPayload {
+        RecordingStartPayload {
+            track_input_events: None,
+            track_keyboard_shortcuts: None,
+            capture_microphone: None,
+            microphone_device_id: None,
+            capture_system_audio: None,
+            system_audio_device_id: None,
+            silence_warning_seconds: None,
+            countdown_seconds: None,
+            max_duration_seconds: None,
+            stop_at: None,
+        }
     }
 }
 
-/// Converts the EngineUnprocessableError value to the Query Parameters representation (style=form, explode=false)
+/// Converts the RecordingStartPayload value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for EngineUnprocessableError {
+impl std::fmt::Display for RecordingStartPayload {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
-            Some("code".to_string()),
-            Some(self.code.to_string()),
-            Some("message".to_string()),
-            Some(self.message.to_string()),
+            self.track_input_events.as_ref().map(|track_input_events| {
+                [
+                    "trackInputEvents".to_string(),
+                    track_input_events.to_string(),
+                ]
+                .join(",")
+            }),
+            self.track_keyboard_shortcuts
+                .as_ref()
+                .map(|track_keyboard_shortcuts| {
+                    [
+                        "trackKeyboardShortcuts".to_string(),
+                        track_keyboard_shortcuts.to_string(),
+                    ]
+                    .join(",")
+                }),
+            self.capture_microphone.as_ref().map(|capture_microphone| {
+                [
+                    "captureMicrophone".to_string(),
+                    capture_microphone.to_string(),
+                ]
+                .join(",")
+            }),
+            self.microphone_device_id
+                .as_ref()
+                .map(|microphone_device_id| {
+                    [
+                        "microphoneDeviceId".to_string(),
+                        microphone_device_id.to_string(),
+                    ]
+                    .join(",")
+                }),
+            self.capture_system_audio
+                .as_ref()
+                .map(|capture_system_audio| {
+                    [
+                        "captureSystemAudio".to_string(),
+                        capture_system_audio.to_string(),
+                    ]
+                    .join(",")
+                }),
+            self.system_audio_device_id
+                .as_ref()
+                .map(|system_audio_device_id| {
+                    [
+                        "systemAudioDeviceId".to_string(),
+                        system_audio_device_id.to_string(),
+                    ]
+                    .join(",")
+                }),
+            self.silence_warning_seconds
+                .as_ref()
+                .map(|silence_warning_seconds| {
+                    [
+                        "silenceWarningSeconds".to_string(),
+                        silence_warning_seconds.to_string(),
+                    ]
+                    .join(",")
+                }),
+            self.countdown_seconds.as_ref().map(|countdown_seconds| {
+                [
+                    "countdownSeconds".to_string(),
+                    countdown_seconds.to_string(),
+                ]
+                .join(",")
+            }),
+            self.max_duration_seconds
+                .as_ref()
+                .map(|max_duration_seconds| {
+                    [
+                        "maxDurationSeconds".to_string(),
+                        max_duration_seconds.to_string(),
+                    ]
+                    .join(",")
+                }),
+            self.stop_at
+                .as_ref()
+                .map(|stop_at| ["stopAt".to_string(), stop_at.to_string()].join(",")),
         ];
 
         write!(
@@ -6510,10 +16541,10 @@ impl std::fmt::Display for EngineUnprocessableError {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a EngineUnprocessableError value
+/// Converts Query Parameters representation (style=form, explode=false) to a RecordingStartPayload value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for EngineUnprocessableError {
+impl std::str::FromStr for RecordingStartPayload {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -6521,8 +16552,16 @@ impl std::str::FromStr for EngineUnprocessableError {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub code: Vec<String>,
-            pub message: Vec<String>,
+            pub track_input_events: Vec<bool>,
+            pub track_keyboard_shortcuts: Vec<bool>,
+            pub capture_microphone: Vec<bool>,
+            pub microphone_device_id: Vec<String>,
+            pub capture_system_audio: Vec<bool>,
+            pub system_audio_device_id: Vec<String>,
+            pub silence_warning_seconds: Vec<f64>,
+            pub countdown_seconds: Vec<f64>,
+            pub max_duration_seconds: Vec<f64>,
+            pub stop_at: Vec<String>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -6536,7 +16575,7 @@ impl std::str::FromStr for EngineUnprocessableError {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing EngineUnprocessableError".to_string(),
+                        "Missing value while parsing RecordingStartPayload".to_string(),
                     );
                 }
             };
@@ -6545,16 +16584,48 @@ impl std::str::FromStr for EngineUnprocessableError {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "code" => intermediate_rep.code.push(
+                    "trackInputEvents" => intermediate_rep.track_input_events.push(
+                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "trackKeyboardShortcuts" => intermediate_rep.track_keyboard_shortcuts.push(
+                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "captureMicrophone" => intermediate_rep.capture_microphone.push(
+                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "microphoneDeviceId" => intermediate_rep.microphone_device_id.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "message" => intermediate_rep.message.push(
+                    "captureSystemAudio" => intermediate_rep.capture_system_audio.push(
+                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "systemAudioDeviceId" => intermediate_rep.system_audio_device_id.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "silenceWarningSeconds" => intermediate_rep.silence_warning_seconds.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "countdownSeconds" => intermediate_rep.countdown_seconds.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "maxDurationSeconds" => intermediate_rep.max_duration_seconds.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "stopAt" => intermediate_rep.stop_at.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing EngineUnprocessableError".to_string(),
+                            "Unexpected key while parsing RecordingStartPayload".to_string(),
                         );
                     }
                 }
@@ -6565,53 +16636,53 @@ impl std::str::FromStr for EngineUnprocessableError {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(EngineUnprocessableError {
-            code: intermediate_rep
-                .code
-                .into_iter()
-                .next()
-                .ok_or_else(|| "code missing in EngineUnprocessableError".to_string())?,
-            message: intermediate_rep
-                .message
-                .into_iter()
-                .next()
-                .ok_or_else(|| "message missing in EngineUnprocessableError".to_string())?,
+        std::result::Result::Ok(RecordingStartPayload {
+            track_input_events: intermediate_rep.track_input_events.into_iter().next(),
+            track_keyboard_shortcuts: intermediate_rep.track_keyboard_shortcuts.into_iter().next(),
+            capture_microphone: intermediate_rep.capture_microphone.into_iter().next(),
+            microphone_device_id: intermediate_rep.microphone_device_id.into_iter().next(),
+            capture_system_audio: intermediate_rep.capture_system_audio.into_iter().next(),
+            system_audio_device_id: intermediate_rep.system_audio_device_id.into_iter().next(),
+            silence_warning_seconds: intermediate_rep.silence_warning_seconds.into_iter().next(),
+            countdown_seconds: intermediate_rep.countdown_seconds.into_iter().next(),
+            max_duration_seconds: intermediate_rep.max_duration_seconds.into_iter().next(),
+            stop_at: intermediate_rep.stop_at.into_iter().next(),
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<EngineUnprocessableError> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<RecordingStartPayload> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<EngineUnprocessableError>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<RecordingStartPayload>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<EngineUnprocessableError>,
+        hdr_value: header::IntoHeaderValue<RecordingStartPayload>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                r#"Invalid header value for EngineUnprocessableError - value: {hdr_value} is invalid {e}"#
+                r#"Invalid header value for RecordingStartPayload - value: {hdr_value} is invalid {e}"#
             )),
         }
     }
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<EngineUnprocessableError> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<RecordingStartPayload> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <EngineUnprocessableError as std::str::FromStr>::from_str(value) {
+                match <RecordingStartPayload as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        r#"Unable to convert header value '{value}' into EngineUnprocessableError - {err}"#
+                        r#"Unable to convert header value '{value}' into RecordingStartPayload - {err}"#
                     )),
                 }
             }
@@ -6624,27 +16695,82 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<EngineUnproc
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct ExportInfoResult {
-    #[serde(rename = "presets")]
+pub struct RecoveredRecording {
+    #[serde(rename = "recordingId")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub recording_id: String,
+
+    #[serde(rename = "recordingURL")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub recording_url: String,
+
+    #[serde(rename = "cameraRecordingURL")]
+    #[validate(custom(function = "check_xss_string"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub camera_recording_url: Option<String>,
+
+    #[serde(rename = "audioTracks")]
     #[validate(nested)]
-    pub presets: Vec<models::ExportPreset>,
+    pub audio_tracks: Vec<models::AudioTrack>,
+
+    #[serde(rename = "frameCount")]
+    pub frame_count: i32,
+
+    #[serde(rename = "durationSeconds")]
+    pub duration_seconds: f64,
+
+    #[serde(rename = "recovered")]
+    pub recovered: bool,
 }
 
-impl ExportInfoResult {
+impl RecoveredRecording {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new(presets: Vec<models::ExportPreset>) -> ExportInfoResult {
-        ExportInfoResult { presets }
+    pub fn new(
+        recording_id: String,
+        recording_url: String,
+        audio_tracks: Vec<models::AudioTrack>,
+        frame_count: i32,
+        duration_seconds: f64,
+        recovered: bool,
+    ) -> RecoveredRecording {
+        RecoveredRecording {
+            recording_id,
+            recording_url,
+            camera_recording_url: None,
+            audio_tracks,
+            frame_count,
+            duration_seconds,
+            recovered,
+        }
     }
 }
 
-/// Converts the ExportInfoResult value to the Query Parameters representation (style=form, explode=false)
+/// Converts the RecoveredRecording value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for ExportInfoResult {
+impl std::fmt::Display for RecoveredRecording {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
-            // Skipping presets in query parameter serialization
-
+            Some("recordingId".to_string()),
+            Some(self.recording_id.to_string()),
+            Some("recordingURL".to_string()),
+            Some(self.recording_url.to_string()),
+            self.camera_recording_url
+                .as_ref()
+                .map(|camera_recording_url| {
+                    [
+                        "cameraRecordingURL".to_string(),
+                        camera_recording_url.to_string(),
+                    ]
+                    .join(",")
+                }),
+            // Skipping audioTracks in query parameter serialization
+            Some("frameCount".to_string()),
+            Some(self.frame_count.to_string()),
+            Some("durationSeconds".to_string()),
+            Some(self.duration_seconds.to_string()),
+            Some("recovered".to_string()),
+            Some(self.recovered.to_string()),
         ];
 
         write!(
@@ -6655,10 +16781,10 @@ impl std::fmt::Display for ExportInfoResult {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a ExportInfoResult value
+/// Converts Query Parameters representation (style=form, explode=false) to a RecoveredRecording value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for ExportInfoResult {
+impl std::str::FromStr for RecoveredRecording {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -6666,7 +16792,13 @@ impl std::str::FromStr for ExportInfoResult {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub presets: Vec<Vec<models::ExportPreset>>,
+            pub recording_id: Vec<String>,
+            pub recording_url: Vec<String>,
+            pub camera_recording_url: Vec<String>,
+            pub audio_tracks: Vec<Vec<models::AudioTrack>>,
+            pub frame_count: Vec<i32>,
+            pub duration_seconds: Vec<f64>,
+            pub recovered: Vec<bool>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -6680,7 +16812,7 @@ impl std::str::FromStr for ExportInfoResult {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing ExportInfoResult".to_string(),
+                        "Missing value while parsing RecoveredRecording".to_string(),
                     );
                 }
             };
@@ -6688,13 +16820,37 @@ impl std::str::FromStr for ExportInfoResult {
             if let Some(key) = key_result {
                 #[allow(clippy::match_single_binding)]
                 match key {
-                    "presets" => return std::result::Result::Err(
-                        "Parsing a container in this style is not supported in ExportInfoResult"
+                    #[allow(clippy::redundant_clone)]
+                    "recordingId" => intermediate_rep.recording_id.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "recordingURL" => intermediate_rep.recording_url.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "cameraRecordingURL" => intermediate_rep.camera_recording_url.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    "audioTracks" => return std::result::Result::Err(
+                        "Parsing a container in this style is not supported in RecoveredRecording"
                             .to_string(),
                     ),
+                    #[allow(clippy::redundant_clone)]
+                    "frameCount" => intermediate_rep.frame_count.push(
+                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "durationSeconds" => intermediate_rep.duration_seconds.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "recovered" => intermediate_rep.recovered.push(
+                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing ExportInfoResult".to_string(),
+                            "Unexpected key while parsing RecoveredRecording".to_string(),
                         );
                     }
                 }
@@ -6705,48 +16861,74 @@ impl std::str::FromStr for ExportInfoResult {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(ExportInfoResult {
-            presets: intermediate_rep
-                .presets
+        std::result::Result::Ok(RecoveredRecording {
+            recording_id: intermediate_rep
+                .recording_id
                 .into_iter()
                 .next()
-                .ok_or_else(|| "presets missing in ExportInfoResult".to_string())?,
+                .ok_or_else(|| "recordingId missing in RecoveredRecording".to_string())?,
+            recording_url: intermediate_rep
+                .recording_url
+                .into_iter()
+                .next()
+                .ok_or_else(|| "recordingURL missing in RecoveredRecording".to_string())?,
+            camera_recording_url: intermediate_rep.camera_recording_url.into_iter().next(),
+            audio_tracks: intermediate_rep
+                .audio_tracks
+                .into_iter()
+                .next()
+                .ok_or_else(|| "audioTracks missing in RecoveredRecording".to_string())?,
+            frame_count: intermediate_rep
+                .frame_count
+                .into_iter()
+                .next()
+                .ok_or_else(|| "frameCount missing in RecoveredRecording".to_string())?,
+            duration_seconds: intermediate_rep
+                .duration_seconds
+                .into_iter()
+                .next()
+                .ok_or_else(|| "durationSeconds missing in RecoveredRecording".to_string())?,
+            recovered: intermediate_rep
+                .recovered
+                .into_iter()
+                .next()
+                .ok_or_else(|| "recovered missing in RecoveredRecording".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<ExportInfoResult> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<RecoveredRecording> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<ExportInfoResult>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<RecoveredRecording>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<ExportInfoResult>,
+        hdr_value: header::IntoHeaderValue<RecoveredRecording>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                r#"Invalid header value for ExportInfoResult - value: {hdr_value} is invalid {e}"#
+                r#"Invalid header value for RecoveredRecording - value: {hdr_value} is invalid {e}"#
             )),
         }
     }
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<ExportInfoResult> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<RecoveredRecording> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <ExportInfoResult as std::str::FromStr>::from_str(value) {
+                match <RecoveredRecording as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        r#"Unable to convert header value '{value}' into ExportInfoResult - {err}"#
+                        r#"Unable to convert header value '{value}' into RecoveredRecording - {err}"#
                     )),
                 }
             }
@@ -6757,71 +16939,55 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<ExportInfoRe
     }
 }
 
+/// How presets narrower than the recording pan their window across it to follow the cursor.
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct ExportPreset {
-    #[serde(rename = "id")]
-    #[validate(custom(function = "check_xss_string"))]
-    pub id: String,
-
-    #[serde(rename = "name")]
-    #[validate(custom(function = "check_xss_string"))]
-    pub name: String,
-
-    #[serde(rename = "width")]
-    pub width: i32,
+pub struct ReframeSettings {
+    /// Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "version")]
+    pub version: f64,
 
-    #[serde(rename = "height")]
-    pub height: i32,
+    #[serde(rename = "enabled")]
+    pub enabled: bool,
 
-    #[serde(rename = "fps")]
-    pub fps: i32,
+    #[serde(rename = "smoothing")]
+    pub smoothing: f64,
 
-    /// Note: inline enums are not fully supported by openapi-generator
-    #[serde(rename = "fileType")]
-    #[validate(custom(function = "check_xss_string"))]
-    pub file_type: String,
+    #[serde(rename = "safeMarginFraction")]
+    pub safe_margin_fraction: f64,
 }
 
-impl ExportPreset {
+impl ReframeSettings {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
     pub fn new(
-        id: String,
-        name: String,
-        width: i32,
-        height: i32,
-        fps: i32,
-        file_type: String,
-    ) -> ExportPreset {
-        ExportPreset {
-            id,
-            name,
-            width,
-            height,
-            fps,
-            file_type,
+        version: f64,
+        enabled: bool,
+        smoothing: f64,
+        safe_margin_fraction: f64,
+    ) -> ReframeSettings {
+        ReframeSettings {
+            version,
+            enabled,
+            smoothing,
+            safe_margin_fraction,
         }
     }
 }
 
-/// Converts the ExportPreset value to the Query Parameters representation (style=form, explode=false)
+/// Converts the ReframeSettings value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for ExportPreset {
+impl std::fmt::Display for ReframeSettings {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
-            Some("id".to_string()),
-            Some(self.id.to_string()),
-            Some("name".to_string()),
-            Some(self.name.to_string()),
-            Some("width".to_string()),
-            Some(self.width.to_string()),
-            Some("height".to_string()),
-            Some(self.height.to_string()),
-            Some("fps".to_string()),
-            Some(self.fps.to_string()),
-            Some("fileType".to_string()),
-            Some(self.file_type.to_string()),
+            Some("version".to_string()),
+            Some(self.version.to_string()),
+            Some("enabled".to_string()),
+            Some(self.enabled.to_string()),
+            Some("smoothing".to_string()),
+            Some(self.smoothing.to_string()),
+            Some("safeMarginFraction".to_string()),
+            Some(self.safe_margin_fraction.to_string()),
         ];
 
         write!(
@@ -6832,10 +16998,10 @@ impl std::fmt::Display for ExportPreset {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a ExportPreset value
+/// Converts Query Parameters representation (style=form, explode=false) to a ReframeSettings value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for ExportPreset {
+impl std::str::FromStr for ReframeSettings {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -6843,12 +17009,10 @@ impl std::str::FromStr for ExportPreset {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub id: Vec<String>,
-            pub name: Vec<String>,
-            pub width: Vec<i32>,
-            pub height: Vec<i32>,
-            pub fps: Vec<i32>,
-            pub file_type: Vec<String>,
+            pub version: Vec<f64>,
+            pub enabled: Vec<bool>,
+            pub smoothing: Vec<f64>,
+            pub safe_margin_fraction: Vec<f64>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -6862,7 +17026,7 @@ impl std::str::FromStr for ExportPreset {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing ExportPreset".to_string(),
+                        "Missing value while parsing ReframeSettings".to_string(),
                     );
                 }
             };
@@ -6871,32 +17035,24 @@ impl std::str::FromStr for ExportPreset {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "id" => intermediate_rep.id.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "name" => intermediate_rep.name.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "width" => intermediate_rep.width.push(
-                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "version" => intermediate_rep.version.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "height" => intermediate_rep.height.push(
-                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "enabled" => intermediate_rep.enabled.push(
+                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "fps" => intermediate_rep.fps.push(
-                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "smoothing" => intermediate_rep.smoothing.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "fileType" => intermediate_rep.file_type.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "safeMarginFraction" => intermediate_rep.safe_margin_fraction.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing ExportPreset".to_string(),
+                            "Unexpected key while parsing ReframeSettings".to_string(),
                         );
                     }
                 }
@@ -6907,73 +17063,63 @@ impl std::str::FromStr for ExportPreset {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(ExportPreset {
-            id: intermediate_rep
-                .id
-                .into_iter()
-                .next()
-                .ok_or_else(|| "id missing in ExportPreset".to_string())?,
-            name: intermediate_rep
-                .name
-                .into_iter()
-                .next()
-                .ok_or_else(|| "name missing in ExportPreset".to_string())?,
-            width: intermediate_rep
-                .width
+        std::result::Result::Ok(ReframeSettings {
+            version: intermediate_rep
+                .version
                 .into_iter()
                 .next()
-                .ok_or_else(|| "width missing in ExportPreset".to_string())?,
-            height: intermediate_rep
-                .height
+                .ok_or_else(|| "version missing in ReframeSettings".to_string())?,
+            enabled: intermediate_rep
+                .enabled
                 .into_iter()
                 .next()
-                .ok_or_else(|| "height missing in ExportPreset".to_string())?,
-            fps: intermediate_rep
-                .fps
+                .ok_or_else(|| "enabled missing in ReframeSettings".to_string())?,
+            smoothing: intermediate_rep
+                .smoothing
                 .into_iter()
                 .next()
-                .ok_or_else(|| "fps missing in ExportPreset".to_string())?,
-            file_type: intermediate_rep
-                .file_type
+                .ok_or_else(|| "smoothing missing in ReframeSettings".to_string())?,
+            safe_margin_fraction: intermediate_rep
+                .safe_margin_fraction
                 .into_iter()
                 .next()
-                .ok_or_else(|| "fileType missing in ExportPreset".to_string())?,
+                .ok_or_else(|| "safeMarginFraction missing in ReframeSettings".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<ExportPreset> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<ReframeSettings> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<ExportPreset>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<ReframeSettings>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<ExportPreset>,
+        hdr_value: header::IntoHeaderValue<ReframeSettings>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                r#"Invalid header value for ExportPreset - value: {hdr_value} is invalid {e}"#
+                r#"Invalid header value for ReframeSettings - value: {hdr_value} is invalid {e}"#
             )),
         }
     }
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<ExportPreset> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<ReframeSettings> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <ExportPreset as std::str::FromStr>::from_str(value) {
+                match <ReframeSettings as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        r#"Unable to convert header value '{value}' into ExportPreset - {err}"#
+                        r#"Unable to convert header value '{value}' into ReframeSettings - {err}"#
                     )),
                 }
             }
@@ -6986,43 +17132,34 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<ExportPreset
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct ExportRunCutPlanPayload {
-    #[serde(rename = "outputURL")]
-    #[validate(custom(function = "check_xss_string"))]
-    pub output_url: String,
-
-    #[serde(rename = "presetId")]
-    #[validate(custom(function = "check_xss_string"))]
-    pub preset_id: String,
+pub struct SceneChange {
+    #[serde(rename = "timeSeconds")]
+    pub time_seconds: f64,
 
-    #[serde(rename = "jobId")]
-    #[validate(custom(function = "check_xss_string"))]
-    pub job_id: String,
+    #[serde(rename = "score")]
+    pub score: f64,
 }
 
-impl ExportRunCutPlanPayload {
+impl SceneChange {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new(output_url: String, preset_id: String, job_id: String) -> ExportRunCutPlanPayload {
-        ExportRunCutPlanPayload {
-            output_url,
-            preset_id,
-            job_id,
+    pub fn new(time_seconds: f64, score: f64) -> SceneChange {
+        SceneChange {
+            time_seconds,
+            score,
         }
     }
 }
 
-/// Converts the ExportRunCutPlanPayload value to the Query Parameters representation (style=form, explode=false)
+/// Converts the SceneChange value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for ExportRunCutPlanPayload {
+impl std::fmt::Display for SceneChange {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
-            Some("outputURL".to_string()),
-            Some(self.output_url.to_string()),
-            Some("presetId".to_string()),
-            Some(self.preset_id.to_string()),
-            Some("jobId".to_string()),
-            Some(self.job_id.to_string()),
+            Some("timeSeconds".to_string()),
+            Some(self.time_seconds.to_string()),
+            Some("score".to_string()),
+            Some(self.score.to_string()),
         ];
 
         write!(
@@ -7033,10 +17170,10 @@ impl std::fmt::Display for ExportRunCutPlanPayload {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a ExportRunCutPlanPayload value
+/// Converts Query Parameters representation (style=form, explode=false) to a SceneChange value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for ExportRunCutPlanPayload {
+impl std::str::FromStr for SceneChange {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -7044,9 +17181,8 @@ impl std::str::FromStr for ExportRunCutPlanPayload {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub output_url: Vec<String>,
-            pub preset_id: Vec<String>,
-            pub job_id: Vec<String>,
+            pub time_seconds: Vec<f64>,
+            pub score: Vec<f64>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -7060,7 +17196,7 @@ impl std::str::FromStr for ExportRunCutPlanPayload {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing ExportRunCutPlanPayload".to_string(),
+                        "Missing value while parsing SceneChange".to_string(),
                     );
                 }
             };
@@ -7069,20 +17205,16 @@ impl std::str::FromStr for ExportRunCutPlanPayload {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "outputURL" => intermediate_rep.output_url.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "presetId" => intermediate_rep.preset_id.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "timeSeconds" => intermediate_rep.time_seconds.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "jobId" => intermediate_rep.job_id.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "score" => intermediate_rep.score.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing ExportRunCutPlanPayload".to_string(),
+                            "Unexpected key while parsing SceneChange".to_string(),
                         );
                     }
                 }
@@ -7093,58 +17225,53 @@ impl std::str::FromStr for ExportRunCutPlanPayload {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(ExportRunCutPlanPayload {
-            output_url: intermediate_rep
-                .output_url
-                .into_iter()
-                .next()
-                .ok_or_else(|| "outputURL missing in ExportRunCutPlanPayload".to_string())?,
-            preset_id: intermediate_rep
-                .preset_id
+        std::result::Result::Ok(SceneChange {
+            time_seconds: intermediate_rep
+                .time_seconds
                 .into_iter()
                 .next()
-                .ok_or_else(|| "presetId missing in ExportRunCutPlanPayload".to_string())?,
-            job_id: intermediate_rep
-                .job_id
+                .ok_or_else(|| "timeSeconds missing in SceneChange".to_string())?,
+            score: intermediate_rep
+                .score
                 .into_iter()
                 .next()
-                .ok_or_else(|| "jobId missing in ExportRunCutPlanPayload".to_string())?,
+                .ok_or_else(|| "score missing in SceneChange".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<ExportRunCutPlanPayload> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<SceneChange> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<ExportRunCutPlanPayload>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<SceneChange>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<ExportRunCutPlanPayload>,
+        hdr_value: header::IntoHeaderValue<SceneChange>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                r#"Invalid header value for ExportRunCutPlanPayload - value: {hdr_value} is invalid {e}"#
+                r#"Invalid header value for SceneChange - value: {hdr_value} is invalid {e}"#
             )),
         }
     }
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<ExportRunCutPlanPayload> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<SceneChange> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <ExportRunCutPlanPayload as std::str::FromStr>::from_str(value) {
+                match <SceneChange as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        r#"Unable to convert header value '{value}' into ExportRunCutPlanPayload - {err}"#
+                        r#"Unable to convert header value '{value}' into SceneChange - {err}"#
                     )),
                 }
             }
@@ -7155,56 +17282,87 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<ExportRunCut
     }
 }
 
+/// Styling for the keyboard-shortcut badges drawn over exported video.
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct ExportRunCutPlanResult {
-    #[serde(rename = "jobId")]
-    #[validate(custom(function = "check_xss_string"))]
-    pub job_id: String,
+pub struct ShortcutOverlaySettings {
+    /// Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "version")]
+    pub version: f64,
+
+    #[serde(rename = "enabled")]
+    pub enabled: bool,
+
+    #[serde(rename = "displayDurationSeconds")]
+    pub display_duration_seconds: f64,
 
     /// Note: inline enums are not fully supported by openapi-generator
-    #[serde(rename = "status")]
+    #[serde(rename = "position")]
     #[validate(custom(function = "check_xss_string"))]
-    pub status: String,
+    pub position: String,
 
-    #[serde(rename = "outputURL")]
+    #[serde(rename = "backgroundColor")]
     #[validate(custom(function = "check_xss_string"))]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub output_url: Option<String>,
+    pub background_color: String,
 
-    #[serde(rename = "appliedSegments")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub applied_segments: Option<i32>,
+    #[serde(rename = "textColor")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub text_color: String,
+
+    #[serde(rename = "backgroundOpacity")]
+    pub background_opacity: f64,
+
+    #[serde(rename = "fontScale")]
+    pub font_scale: f64,
 }
 
-impl ExportRunCutPlanResult {
+impl ShortcutOverlaySettings {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new(job_id: String, status: String) -> ExportRunCutPlanResult {
-        ExportRunCutPlanResult {
-            job_id,
-            status,
-            output_url: None,
-            applied_segments: None,
+    pub fn new(
+        version: f64,
+        enabled: bool,
+        display_duration_seconds: f64,
+        position: String,
+        background_color: String,
+        text_color: String,
+        background_opacity: f64,
+        font_scale: f64,
+    ) -> ShortcutOverlaySettings {
+        ShortcutOverlaySettings {
+            version,
+            enabled,
+            display_duration_seconds,
+            position,
+            background_color,
+            text_color,
+            background_opacity,
+            font_scale,
         }
     }
 }
 
-/// Converts the ExportRunCutPlanResult value to the Query Parameters representation (style=form, explode=false)
+/// Converts the ShortcutOverlaySettings value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for ExportRunCutPlanResult {
+impl std::fmt::Display for ShortcutOverlaySettings {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
-            Some("jobId".to_string()),
-            Some(self.job_id.to_string()),
-            Some("status".to_string()),
-            Some(self.status.to_string()),
-            self.output_url
-                .as_ref()
-                .map(|output_url| ["outputURL".to_string(), output_url.to_string()].join(",")),
-            self.applied_segments.as_ref().map(|applied_segments| {
-                ["appliedSegments".to_string(), applied_segments.to_string()].join(",")
-            }),
+            Some("version".to_string()),
+            Some(self.version.to_string()),
+            Some("enabled".to_string()),
+            Some(self.enabled.to_string()),
+            Some("displayDurationSeconds".to_string()),
+            Some(self.display_duration_seconds.to_string()),
+            Some("position".to_string()),
+            Some(self.position.to_string()),
+            Some("backgroundColor".to_string()),
+            Some(self.background_color.to_string()),
+            Some("textColor".to_string()),
+            Some(self.text_color.to_string()),
+            Some("backgroundOpacity".to_string()),
+            Some(self.background_opacity.to_string()),
+            Some("fontScale".to_string()),
+            Some(self.font_scale.to_string()),
         ];
 
         write!(
@@ -7215,10 +17373,10 @@ impl std::fmt::Display for ExportRunCutPlanResult {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a ExportRunCutPlanResult value
+/// Converts Query Parameters representation (style=form, explode=false) to a ShortcutOverlaySettings value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for ExportRunCutPlanResult {
+impl std::str::FromStr for ShortcutOverlaySettings {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -7226,10 +17384,14 @@ impl std::str::FromStr for ExportRunCutPlanResult {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub job_id: Vec<String>,
-            pub status: Vec<String>,
-            pub output_url: Vec<String>,
-            pub applied_segments: Vec<i32>,
+            pub version: Vec<f64>,
+            pub enabled: Vec<bool>,
+            pub display_duration_seconds: Vec<f64>,
+            pub position: Vec<String>,
+            pub background_color: Vec<String>,
+            pub text_color: Vec<String>,
+            pub background_opacity: Vec<f64>,
+            pub font_scale: Vec<f64>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -7243,7 +17405,7 @@ impl std::str::FromStr for ExportRunCutPlanResult {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing ExportRunCutPlanResult".to_string(),
+                        "Missing value while parsing ShortcutOverlaySettings".to_string(),
                     );
                 }
             };
@@ -7252,24 +17414,40 @@ impl std::str::FromStr for ExportRunCutPlanResult {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "jobId" => intermediate_rep.job_id.push(
+                    "version" => intermediate_rep.version.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "enabled" => intermediate_rep.enabled.push(
+                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "displayDurationSeconds" => intermediate_rep.display_duration_seconds.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "position" => intermediate_rep.position.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "status" => intermediate_rep.status.push(
+                    "backgroundColor" => intermediate_rep.background_color.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "outputURL" => intermediate_rep.output_url.push(
+                    "textColor" => intermediate_rep.text_color.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "appliedSegments" => intermediate_rep.applied_segments.push(
-                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "backgroundOpacity" => intermediate_rep.background_opacity.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "fontScale" => intermediate_rep.font_scale.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing ExportRunCutPlanResult".to_string(),
+                            "Unexpected key while parsing ShortcutOverlaySettings".to_string(),
                         );
                     }
                 }
@@ -7280,55 +17458,87 @@ impl std::str::FromStr for ExportRunCutPlanResult {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(ExportRunCutPlanResult {
-            job_id: intermediate_rep
-                .job_id
+        std::result::Result::Ok(ShortcutOverlaySettings {
+            version: intermediate_rep
+                .version
                 .into_iter()
                 .next()
-                .ok_or_else(|| "jobId missing in ExportRunCutPlanResult".to_string())?,
-            status: intermediate_rep
-                .status
+                .ok_or_else(|| "version missing in ShortcutOverlaySettings".to_string())?,
+            enabled: intermediate_rep
+                .enabled
                 .into_iter()
                 .next()
-                .ok_or_else(|| "status missing in ExportRunCutPlanResult".to_string())?,
-            output_url: intermediate_rep.output_url.into_iter().next(),
-            applied_segments: intermediate_rep.applied_segments.into_iter().next(),
+                .ok_or_else(|| "enabled missing in ShortcutOverlaySettings".to_string())?,
+            display_duration_seconds: intermediate_rep
+                .display_duration_seconds
+                .into_iter()
+                .next()
+                .ok_or_else(|| {
+                    "displayDurationSeconds missing in ShortcutOverlaySettings".to_string()
+                })?,
+            position: intermediate_rep
+                .position
+                .into_iter()
+                .next()
+                .ok_or_else(|| "position missing in ShortcutOverlaySettings".to_string())?,
+            background_color: intermediate_rep
+                .background_color
+                .into_iter()
+                .next()
+                .ok_or_else(|| "backgroundColor missing in ShortcutOverlaySettings".to_string())?,
+            text_color: intermediate_rep
+                .text_color
+                .into_iter()
+                .next()
+                .ok_or_else(|| "textColor missing in ShortcutOverlaySettings".to_string())?,
+            background_opacity: intermediate_rep
+                .background_opacity
+                .into_iter()
+                .next()
+                .ok_or_else(|| {
+                    "backgroundOpacity missing in ShortcutOverlaySettings".to_string()
+                })?,
+            font_scale: intermediate_rep
+                .font_scale
+                .into_iter()
+                .next()
+                .ok_or_else(|| "fontScale missing in ShortcutOverlaySettings".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<ExportRunCutPlanResult> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<ShortcutOverlaySettings> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<ExportRunCutPlanResult>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<ShortcutOverlaySettings>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<ExportRunCutPlanResult>,
+        hdr_value: header::IntoHeaderValue<ShortcutOverlaySettings>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                r#"Invalid header value for ExportRunCutPlanResult - value: {hdr_value} is invalid {e}"#
+                r#"Invalid header value for ShortcutOverlaySettings - value: {hdr_value} is invalid {e}"#
             )),
         }
     }
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<ExportRunCutPlanResult> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<ShortcutOverlaySettings> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <ExportRunCutPlanResult as std::str::FromStr>::from_str(value) {
+                match <ShortcutOverlaySettings as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        r#"Unable to convert header value '{value}' into ExportRunCutPlanResult - {err}"#
+                        r#"Unable to convert header value '{value}' into ShortcutOverlaySettings - {err}"#
                     )),
                 }
             }
@@ -7341,79 +17551,36 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<ExportRunCut
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct ExportRunPayload {
-    #[serde(rename = "outputURL")]
-    #[validate(custom(function = "check_xss_string"))]
-    pub output_url: String,
-
-    #[serde(rename = "presetId")]
-    #[validate(custom(function = "check_xss_string"))]
-    pub preset_id: String,
-
-    #[serde(rename = "trimStartSeconds")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub trim_start_seconds: Option<f64>,
-
-    #[serde(rename = "trimEndSeconds")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub trim_end_seconds: Option<f64>,
-
-    #[serde(rename = "timeline")]
-    #[validate(nested)]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub timeline: Option<models::ExportRunPayloadTimeline>,
-
-    #[serde(rename = "autoZoom")]
+pub struct SourcesResult {
+    #[serde(rename = "displays")]
     #[validate(nested)]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub auto_zoom: Option<models::AutoZoomSettings>,
+    pub displays: Vec<models::DisplaySource>,
 
-    #[serde(rename = "backgroundFraming")]
+    #[serde(rename = "windows")]
     #[validate(nested)]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub background_framing: Option<models::BackgroundFramingSettings>,
+    pub windows: Vec<models::WindowSource>,
 }
 
-impl ExportRunPayload {
+impl SourcesResult {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new(output_url: String, preset_id: String) -> ExportRunPayload {
-        ExportRunPayload {
-            output_url,
-            preset_id,
-            trim_start_seconds: None,
-            trim_end_seconds: None,
-            timeline: None,
-            auto_zoom: None,
-            background_framing: None,
-        }
+    pub fn new(
+        displays: Vec<models::DisplaySource>,
+        windows: Vec<models::WindowSource>,
+    ) -> SourcesResult {
+        SourcesResult { displays, windows }
     }
 }
 
-/// Converts the ExportRunPayload value to the Query Parameters representation (style=form, explode=false)
+/// Converts the SourcesResult value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for ExportRunPayload {
+impl std::fmt::Display for SourcesResult {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
-            Some("outputURL".to_string()),
-            Some(self.output_url.to_string()),
-            Some("presetId".to_string()),
-            Some(self.preset_id.to_string()),
-            self.trim_start_seconds.as_ref().map(|trim_start_seconds| {
-                [
-                    "trimStartSeconds".to_string(),
-                    trim_start_seconds.to_string(),
-                ]
-                .join(",")
-            }),
-            self.trim_end_seconds.as_ref().map(|trim_end_seconds| {
-                ["trimEndSeconds".to_string(), trim_end_seconds.to_string()].join(",")
-            }),
-            // Skipping timeline in query parameter serialization
+            // Skipping displays in query parameter serialization
 
-            // Skipping autoZoom in query parameter serialization
+            // Skipping windows in query parameter serialization
 
-            // Skipping backgroundFraming in query parameter serialization
         ];
 
         write!(
@@ -7424,10 +17591,10 @@ impl std::fmt::Display for ExportRunPayload {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a ExportRunPayload value
+/// Converts Query Parameters representation (style=form, explode=false) to a SourcesResult value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for ExportRunPayload {
+impl std::str::FromStr for SourcesResult {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -7435,13 +17602,8 @@ impl std::str::FromStr for ExportRunPayload {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub output_url: Vec<String>,
-            pub preset_id: Vec<String>,
-            pub trim_start_seconds: Vec<f64>,
-            pub trim_end_seconds: Vec<f64>,
-            pub timeline: Vec<models::ExportRunPayloadTimeline>,
-            pub auto_zoom: Vec<models::AutoZoomSettings>,
-            pub background_framing: Vec<models::BackgroundFramingSettings>,
+            pub displays: Vec<Vec<models::DisplaySource>>,
+            pub windows: Vec<Vec<models::WindowSource>>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -7455,7 +17617,7 @@ impl std::str::FromStr for ExportRunPayload {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing ExportRunPayload".to_string(),
+                        "Missing value while parsing SourcesResult".to_string(),
                     );
                 }
             };
@@ -7463,40 +17625,21 @@ impl std::str::FromStr for ExportRunPayload {
             if let Some(key) = key_result {
                 #[allow(clippy::match_single_binding)]
                 match key {
-                    #[allow(clippy::redundant_clone)]
-                    "outputURL" => intermediate_rep.output_url.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "presetId" => intermediate_rep.preset_id.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "trimStartSeconds" => intermediate_rep.trim_start_seconds.push(
-                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "trimEndSeconds" => intermediate_rep.trim_end_seconds.push(
-                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "timeline" => intermediate_rep.timeline.push(
-                        <models::ExportRunPayloadTimeline as std::str::FromStr>::from_str(val)
-                            .map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "autoZoom" => intermediate_rep.auto_zoom.push(
-                        <models::AutoZoomSettings as std::str::FromStr>::from_str(val)
-                            .map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "backgroundFraming" => intermediate_rep.background_framing.push(
-                        <models::BackgroundFramingSettings as std::str::FromStr>::from_str(val)
-                            .map_err(|x| x.to_string())?,
-                    ),
+                    "displays" => {
+                        return std::result::Result::Err(
+                            "Parsing a container in this style is not supported in SourcesResult"
+                                .to_string(),
+                        );
+                    }
+                    "windows" => {
+                        return std::result::Result::Err(
+                            "Parsing a container in this style is not supported in SourcesResult"
+                                .to_string(),
+                        );
+                    }
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing ExportRunPayload".to_string(),
+                            "Unexpected key while parsing SourcesResult".to_string(),
                         );
                     }
                 }
@@ -7507,58 +17650,53 @@ impl std::str::FromStr for ExportRunPayload {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(ExportRunPayload {
-            output_url: intermediate_rep
-                .output_url
+        std::result::Result::Ok(SourcesResult {
+            displays: intermediate_rep
+                .displays
                 .into_iter()
                 .next()
-                .ok_or_else(|| "outputURL missing in ExportRunPayload".to_string())?,
-            preset_id: intermediate_rep
-                .preset_id
+                .ok_or_else(|| "displays missing in SourcesResult".to_string())?,
+            windows: intermediate_rep
+                .windows
                 .into_iter()
                 .next()
-                .ok_or_else(|| "presetId missing in ExportRunPayload".to_string())?,
-            trim_start_seconds: intermediate_rep.trim_start_seconds.into_iter().next(),
-            trim_end_seconds: intermediate_rep.trim_end_seconds.into_iter().next(),
-            timeline: intermediate_rep.timeline.into_iter().next(),
-            auto_zoom: intermediate_rep.auto_zoom.into_iter().next(),
-            background_framing: intermediate_rep.background_framing.into_iter().next(),
+                .ok_or_else(|| "windows missing in SourcesResult".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<ExportRunPayload> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<SourcesResult> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<ExportRunPayload>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<SourcesResult>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<ExportRunPayload>,
+        hdr_value: header::IntoHeaderValue<SourcesResult>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                r#"Invalid header value for ExportRunPayload - value: {hdr_value} is invalid {e}"#
+                r#"Invalid header value for SourcesResult - value: {hdr_value} is invalid {e}"#
             )),
         }
     }
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<ExportRunPayload> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<SourcesResult> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <ExportRunPayload as std::str::FromStr>::from_str(value) {
+                match <SourcesResult as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        r#"Unable to convert header value '{value}' into ExportRunPayload - {err}"#
+                        r#"Unable to convert header value '{value}' into SourcesResult - {err}"#
                     )),
                 }
             }
@@ -7571,47 +17709,70 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<ExportRunPay
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct ExportRunPayloadTimeline {
-    /// Note: inline enums are not fully supported by openapi-generator
-    #[serde(rename = "version")]
-    pub version: f64,
+pub struct SuggestedCut {
+    #[serde(rename = "id")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub id: String,
 
-    #[serde(rename = "items")]
-    #[validate(nested)]
-    pub items: Vec<models::ExportRunPayloadTimelineItemsInner>,
+    #[serde(rename = "takeId")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub take_id: String,
 
-    #[serde(rename = "updatedAt")]
+    #[serde(rename = "startSeconds")]
+    pub start_seconds: f64,
+
+    #[serde(rename = "endSeconds")]
+    pub end_seconds: f64,
+
+    /// Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "operation")]
     #[validate(custom(function = "check_xss_string"))]
+    pub operation: String,
+
+    #[serde(rename = "playbackRate")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_at: Option<String>,
+    pub playback_rate: Option<f64>,
 }
 
-impl ExportRunPayloadTimeline {
+impl SuggestedCut {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
     pub fn new(
-        version: f64,
-        items: Vec<models::ExportRunPayloadTimelineItemsInner>,
-    ) -> ExportRunPayloadTimeline {
-        ExportRunPayloadTimeline {
-            version,
-            items,
-            updated_at: None,
+        id: String,
+        take_id: String,
+        start_seconds: f64,
+        end_seconds: f64,
+        operation: String,
+    ) -> SuggestedCut {
+        SuggestedCut {
+            id,
+            take_id,
+            start_seconds,
+            end_seconds,
+            operation,
+            playback_rate: None,
         }
     }
 }
 
-/// Converts the ExportRunPayloadTimeline value to the Query Parameters representation (style=form, explode=false)
+/// Converts the SuggestedCut value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for ExportRunPayloadTimeline {
+impl std::fmt::Display for SuggestedCut {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
-            Some("version".to_string()),
-            Some(self.version.to_string()),
-            // Skipping items in query parameter serialization
-            self.updated_at
-                .as_ref()
-                .map(|updated_at| ["updatedAt".to_string(), updated_at.to_string()].join(",")),
+            Some("id".to_string()),
+            Some(self.id.to_string()),
+            Some("takeId".to_string()),
+            Some(self.take_id.to_string()),
+            Some("startSeconds".to_string()),
+            Some(self.start_seconds.to_string()),
+            Some("endSeconds".to_string()),
+            Some(self.end_seconds.to_string()),
+            Some("operation".to_string()),
+            Some(self.operation.to_string()),
+            self.playback_rate.as_ref().map(|playback_rate| {
+                ["playbackRate".to_string(), playback_rate.to_string()].join(",")
+            }),
         ];
 
         write!(
@@ -7622,10 +17783,10 @@ impl std::fmt::Display for ExportRunPayloadTimeline {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a ExportRunPayloadTimeline value
+/// Converts Query Parameters representation (style=form, explode=false) to a SuggestedCut value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for ExportRunPayloadTimeline {
+impl std::str::FromStr for SuggestedCut {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -7633,9 +17794,12 @@ impl std::str::FromStr for ExportRunPayloadTimeline {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub version: Vec<f64>,
-            pub items: Vec<Vec<models::ExportRunPayloadTimelineItemsInner>>,
-            pub updated_at: Vec<String>,
+            pub id: Vec<String>,
+            pub take_id: Vec<String>,
+            pub start_seconds: Vec<f64>,
+            pub end_seconds: Vec<f64>,
+            pub operation: Vec<String>,
+            pub playback_rate: Vec<f64>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -7649,7 +17813,7 @@ impl std::str::FromStr for ExportRunPayloadTimeline {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing ExportRunPayloadTimeline".to_string(),
+                        "Missing value while parsing SuggestedCut".to_string(),
                     );
                 }
             };
@@ -7658,11 +17822,34 @@ impl std::str::FromStr for ExportRunPayloadTimeline {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "version" => intermediate_rep.version.push(<f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
-                    "items" => return std::result::Result::Err("Parsing a container in this style is not supported in ExportRunPayloadTimeline".to_string()),
+                    "id" => intermediate_rep.id.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
                     #[allow(clippy::redundant_clone)]
-                    "updatedAt" => intermediate_rep.updated_at.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
-                    _ => return std::result::Result::Err("Unexpected key while parsing ExportRunPayloadTimeline".to_string())
+                    "takeId" => intermediate_rep.take_id.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "startSeconds" => intermediate_rep.start_seconds.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "endSeconds" => intermediate_rep.end_seconds.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "operation" => intermediate_rep.operation.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "playbackRate" => intermediate_rep.playback_rate.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing SuggestedCut".to_string(),
+                        );
+                    }
                 }
             }
 
@@ -7671,162 +17858,183 @@ impl std::str::FromStr for ExportRunPayloadTimeline {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(ExportRunPayloadTimeline {
-            version: intermediate_rep
-                .version
+        std::result::Result::Ok(SuggestedCut {
+            id: intermediate_rep
+                .id
                 .into_iter()
                 .next()
-                .ok_or_else(|| "version missing in ExportRunPayloadTimeline".to_string())?,
-            items: intermediate_rep
-                .items
+                .ok_or_else(|| "id missing in SuggestedCut".to_string())?,
+            take_id: intermediate_rep
+                .take_id
                 .into_iter()
                 .next()
-                .ok_or_else(|| "items missing in ExportRunPayloadTimeline".to_string())?,
-            updated_at: intermediate_rep.updated_at.into_iter().next(),
+                .ok_or_else(|| "takeId missing in SuggestedCut".to_string())?,
+            start_seconds: intermediate_rep
+                .start_seconds
+                .into_iter()
+                .next()
+                .ok_or_else(|| "startSeconds missing in SuggestedCut".to_string())?,
+            end_seconds: intermediate_rep
+                .end_seconds
+                .into_iter()
+                .next()
+                .ok_or_else(|| "endSeconds missing in SuggestedCut".to_string())?,
+            operation: intermediate_rep
+                .operation
+                .into_iter()
+                .next()
+                .ok_or_else(|| "operation missing in SuggestedCut".to_string())?,
+            playback_rate: intermediate_rep.playback_rate.into_iter().next(),
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<ExportRunPayloadTimeline> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<SuggestedCut> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<ExportRunPayloadTimeline>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<SuggestedCut>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<ExportRunPayloadTimeline>,
+        hdr_value: header::IntoHeaderValue<SuggestedCut>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                r#"Invalid header value for ExportRunPayloadTimeline - value: {hdr_value} is invalid {e}"#
+                r#"Invalid header value for SuggestedCut - value: {hdr_value} is invalid {e}"#
             )),
         }
     }
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<ExportRunPayloadTimeline> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<SuggestedCut> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <ExportRunPayloadTimeline as std::str::FromStr>::from_str(value) {
+                match <SuggestedCut as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        r#"Unable to convert header value '{value}' into ExportRunPayloadTimeline - {err}"#
+                        r#"Unable to convert header value '{value}' into SuggestedCut - {err}"#
                     )),
                 }
             }
             std::result::Result::Err(e) => std::result::Result::Err(format!(
                 r#"Unable to convert header: {hdr_value:?} to string: {e}"#
             )),
-        }
-    }
-}
-
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-#[serde(untagged)]
-#[allow(non_camel_case_types, clippy::large_enum_variant)]
-pub enum ExportRunPayloadTimelineItemsInner {
-    ExportRunPayloadTimelineItemsInnerAnyOf(models::ExportRunPayloadTimelineItemsInnerAnyOf),
-    ExportRunPayloadTimelineItemsInnerAnyOf1(models::ExportRunPayloadTimelineItemsInnerAnyOf1),
-}
-
-impl validator::Validate for ExportRunPayloadTimelineItemsInner {
-    fn validate(&self) -> std::result::Result<(), validator::ValidationErrors> {
-        match self {
-            Self::ExportRunPayloadTimelineItemsInnerAnyOf(v) => v.validate(),
-            Self::ExportRunPayloadTimelineItemsInnerAnyOf1(v) => v.validate(),
-        }
-    }
-}
-
-/// Converts Query Parameters representation (style=form, explode=false) to a ExportRunPayloadTimelineItemsInner value
-/// as specified in https://swagger.io/docs/specification/serialization/
-/// Should be implemented in a serde deserializer
-impl std::str::FromStr for ExportRunPayloadTimelineItemsInner {
-    type Err = serde_json::Error;
-
-    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
-        serde_json::from_str(s)
-    }
-}
-
-impl From<models::ExportRunPayloadTimelineItemsInnerAnyOf> for ExportRunPayloadTimelineItemsInner {
-    fn from(value: models::ExportRunPayloadTimelineItemsInnerAnyOf) -> Self {
-        Self::ExportRunPayloadTimelineItemsInnerAnyOf(value)
-    }
-}
-impl From<models::ExportRunPayloadTimelineItemsInnerAnyOf1> for ExportRunPayloadTimelineItemsInner {
-    fn from(value: models::ExportRunPayloadTimelineItemsInnerAnyOf1) -> Self {
-        Self::ExportRunPayloadTimelineItemsInnerAnyOf1(value)
-    }
-}
-
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
-#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct ExportRunPayloadTimelineItemsInnerAnyOf {
-    /// Note: inline enums are not fully supported by openapi-generator
-    #[serde(rename = "kind")]
-    #[validate(custom(function = "check_xss_string"))]
-    pub kind: String,
+        }
+    }
+}
 
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct Take {
     #[serde(rename = "id")]
     #[validate(custom(function = "check_xss_string"))]
     pub id: String,
 
-    /// Note: inline enums are not fully supported by openapi-generator
-    #[serde(rename = "sourceAssetId")]
+    #[serde(rename = "name")]
     #[validate(custom(function = "check_xss_string"))]
-    pub source_asset_id: String,
+    pub name: String,
 
-    #[serde(rename = "sourceStartSeconds")]
-    pub source_start_seconds: f64,
+    #[serde(rename = "recordingURL")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub recording_url: String,
 
-    #[serde(rename = "sourceEndSeconds")]
-    pub source_end_seconds: f64,
+    #[serde(rename = "cameraTrack")]
+    #[validate(nested)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub camera_track: Option<models::CameraTrack>,
+
+    #[serde(rename = "audioTracks")]
+    #[validate(nested)]
+    pub audio_tracks: Vec<models::AudioTrack>,
+
+    #[serde(rename = "eventsURL")]
+    #[validate(custom(function = "check_xss_string"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub events_url: Option<String>,
+
+    #[serde(rename = "captureMetadata")]
+    #[validate(nested)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capture_metadata: Option<models::CaptureStatusResultCaptureMetadata>,
+
+    #[serde(rename = "recordingMetadata")]
+    #[validate(nested)]
+    pub recording_metadata: models::RecordingMetadata,
+
+    #[serde(rename = "durationSeconds")]
+    pub duration_seconds: f64,
+
+    #[serde(rename = "telemetry")]
+    #[validate(nested)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub telemetry: Option<models::TakeTelemetry>,
+
+    #[serde(rename = "createdAt")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub created_at: String,
 }
 
-impl ExportRunPayloadTimelineItemsInnerAnyOf {
+impl Take {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
     pub fn new(
-        kind: String,
         id: String,
-        source_asset_id: String,
-        source_start_seconds: f64,
-        source_end_seconds: f64,
-    ) -> ExportRunPayloadTimelineItemsInnerAnyOf {
-        ExportRunPayloadTimelineItemsInnerAnyOf {
-            kind,
+        name: String,
+        recording_url: String,
+        audio_tracks: Vec<models::AudioTrack>,
+        recording_metadata: models::RecordingMetadata,
+        duration_seconds: f64,
+        created_at: String,
+    ) -> Take {
+        Take {
             id,
-            source_asset_id,
-            source_start_seconds,
-            source_end_seconds,
+            name,
+            recording_url,
+            camera_track: None,
+            audio_tracks,
+            events_url: None,
+            capture_metadata: None,
+            recording_metadata,
+            duration_seconds,
+            telemetry: None,
+            created_at,
         }
     }
 }
 
-/// Converts the ExportRunPayloadTimelineItemsInnerAnyOf value to the Query Parameters representation (style=form, explode=false)
+/// Converts the Take value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for ExportRunPayloadTimelineItemsInnerAnyOf {
+impl std::fmt::Display for Take {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
-            Some("kind".to_string()),
-            Some(self.kind.to_string()),
             Some("id".to_string()),
             Some(self.id.to_string()),
-            Some("sourceAssetId".to_string()),
-            Some(self.source_asset_id.to_string()),
-            Some("sourceStartSeconds".to_string()),
-            Some(self.source_start_seconds.to_string()),
-            Some("sourceEndSeconds".to_string()),
-            Some(self.source_end_seconds.to_string()),
+            Some("name".to_string()),
+            Some(self.name.to_string()),
+            Some("recordingURL".to_string()),
+            Some(self.recording_url.to_string()),
+            // Skipping cameraTrack in query parameter serialization
+
+            // Skipping audioTracks in query parameter serialization
+            self.events_url
+                .as_ref()
+                .map(|events_url| ["eventsURL".to_string(), events_url.to_string()].join(",")),
+            // Skipping captureMetadata in query parameter serialization
+
+            // Skipping recordingMetadata in query parameter serialization
+            Some("durationSeconds".to_string()),
+            Some(self.duration_seconds.to_string()),
+            // Skipping telemetry in query parameter serialization
+            Some("createdAt".to_string()),
+            Some(self.created_at.to_string()),
         ];
 
         write!(
@@ -7837,10 +18045,10 @@ impl std::fmt::Display for ExportRunPayloadTimelineItemsInnerAnyOf {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a ExportRunPayloadTimelineItemsInnerAnyOf value
+/// Converts Query Parameters representation (style=form, explode=false) to a Take value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for ExportRunPayloadTimelineItemsInnerAnyOf {
+impl std::str::FromStr for Take {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -7848,11 +18056,17 @@ impl std::str::FromStr for ExportRunPayloadTimelineItemsInnerAnyOf {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub kind: Vec<String>,
             pub id: Vec<String>,
-            pub source_asset_id: Vec<String>,
-            pub source_start_seconds: Vec<f64>,
-            pub source_end_seconds: Vec<f64>,
+            pub name: Vec<String>,
+            pub recording_url: Vec<String>,
+            pub camera_track: Vec<models::CameraTrack>,
+            pub audio_tracks: Vec<Vec<models::AudioTrack>>,
+            pub events_url: Vec<String>,
+            pub capture_metadata: Vec<models::CaptureStatusResultCaptureMetadata>,
+            pub recording_metadata: Vec<models::RecordingMetadata>,
+            pub duration_seconds: Vec<f64>,
+            pub telemetry: Vec<models::TakeTelemetry>,
+            pub created_at: Vec<String>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -7866,8 +18080,7 @@ impl std::str::FromStr for ExportRunPayloadTimelineItemsInnerAnyOf {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing ExportRunPayloadTimelineItemsInnerAnyOf"
-                            .to_string(),
+                        "Missing value while parsing Take".to_string(),
                     );
                 }
             };
@@ -7876,31 +18089,27 @@ impl std::str::FromStr for ExportRunPayloadTimelineItemsInnerAnyOf {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "kind" => intermediate_rep.kind.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
+                    "id" => intermediate_rep.id.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
                     #[allow(clippy::redundant_clone)]
-                    "id" => intermediate_rep.id.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
+                    "name" => intermediate_rep.name.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
                     #[allow(clippy::redundant_clone)]
-                    "sourceAssetId" => intermediate_rep.source_asset_id.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
+                    "recordingURL" => intermediate_rep.recording_url.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
                     #[allow(clippy::redundant_clone)]
-                    "sourceStartSeconds" => intermediate_rep.source_start_seconds.push(
-                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
+                    "cameraTrack" => intermediate_rep.camera_track.push(<models::CameraTrack as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    "audioTracks" => return std::result::Result::Err("Parsing a container in this style is not supported in Take".to_string()),
                     #[allow(clippy::redundant_clone)]
-                    "sourceEndSeconds" => intermediate_rep.source_end_seconds.push(
-                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    _ => {
-                        return std::result::Result::Err(
-                            "Unexpected key while parsing ExportRunPayloadTimelineItemsInnerAnyOf"
-                                .to_string(),
-                        );
-                    }
+                    "eventsURL" => intermediate_rep.events_url.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "captureMetadata" => intermediate_rep.capture_metadata.push(<models::CaptureStatusResultCaptureMetadata as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "recordingMetadata" => intermediate_rep.recording_metadata.push(<models::RecordingMetadata as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "durationSeconds" => intermediate_rep.duration_seconds.push(<f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "telemetry" => intermediate_rep.telemetry.push(<models::TakeTelemetry as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "createdAt" => intermediate_rep.created_at.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing Take".to_string())
                 }
             }
 
@@ -7909,81 +18118,83 @@ impl std::str::FromStr for ExportRunPayloadTimelineItemsInnerAnyOf {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(ExportRunPayloadTimelineItemsInnerAnyOf {
-            kind: intermediate_rep.kind.into_iter().next().ok_or_else(|| {
-                "kind missing in ExportRunPayloadTimelineItemsInnerAnyOf".to_string()
-            })?,
-            id: intermediate_rep.id.into_iter().next().ok_or_else(|| {
-                "id missing in ExportRunPayloadTimelineItemsInnerAnyOf".to_string()
-            })?,
-            source_asset_id: intermediate_rep
-                .source_asset_id
+        std::result::Result::Ok(Take {
+            id: intermediate_rep
+                .id
                 .into_iter()
                 .next()
-                .ok_or_else(|| {
-                    "sourceAssetId missing in ExportRunPayloadTimelineItemsInnerAnyOf".to_string()
-                })?,
-            source_start_seconds: intermediate_rep
-                .source_start_seconds
+                .ok_or_else(|| "id missing in Take".to_string())?,
+            name: intermediate_rep
+                .name
                 .into_iter()
                 .next()
-                .ok_or_else(|| {
-                    "sourceStartSeconds missing in ExportRunPayloadTimelineItemsInnerAnyOf"
-                        .to_string()
-                })?,
-            source_end_seconds: intermediate_rep
-                .source_end_seconds
+                .ok_or_else(|| "name missing in Take".to_string())?,
+            recording_url: intermediate_rep
+                .recording_url
                 .into_iter()
                 .next()
-                .ok_or_else(|| {
-                    "sourceEndSeconds missing in ExportRunPayloadTimelineItemsInnerAnyOf"
-                        .to_string()
-                })?,
+                .ok_or_else(|| "recordingURL missing in Take".to_string())?,
+            camera_track: intermediate_rep.camera_track.into_iter().next(),
+            audio_tracks: intermediate_rep
+                .audio_tracks
+                .into_iter()
+                .next()
+                .ok_or_else(|| "audioTracks missing in Take".to_string())?,
+            events_url: intermediate_rep.events_url.into_iter().next(),
+            capture_metadata: intermediate_rep.capture_metadata.into_iter().next(),
+            recording_metadata: intermediate_rep
+                .recording_metadata
+                .into_iter()
+                .next()
+                .ok_or_else(|| "recordingMetadata missing in Take".to_string())?,
+            duration_seconds: intermediate_rep
+                .duration_seconds
+                .into_iter()
+                .next()
+                .ok_or_else(|| "durationSeconds missing in Take".to_string())?,
+            telemetry: intermediate_rep.telemetry.into_iter().next(),
+            created_at: intermediate_rep
+                .created_at
+                .into_iter()
+                .next()
+                .ok_or_else(|| "createdAt missing in Take".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<ExportRunPayloadTimelineItemsInnerAnyOf> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<Take> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<ExportRunPayloadTimelineItemsInnerAnyOf>>
-    for HeaderValue
-{
+impl std::convert::TryFrom<header::IntoHeaderValue<Take>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<ExportRunPayloadTimelineItemsInnerAnyOf>,
+        hdr_value: header::IntoHeaderValue<Take>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                r#"Invalid header value for ExportRunPayloadTimelineItemsInnerAnyOf - value: {hdr_value} is invalid {e}"#
+                r#"Invalid header value for Take - value: {hdr_value} is invalid {e}"#
             )),
         }
     }
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue>
-    for header::IntoHeaderValue<ExportRunPayloadTimelineItemsInnerAnyOf>
-{
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<Take> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
-            std::result::Result::Ok(value) => {
-                match <ExportRunPayloadTimelineItemsInnerAnyOf as std::str::FromStr>::from_str(
-                    value,
-                ) {
-                    std::result::Result::Ok(value) => {
-                        std::result::Result::Ok(header::IntoHeaderValue(value))
-                    }
-                    std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        r#"Unable to convert header value '{value}' into ExportRunPayloadTimelineItemsInnerAnyOf - {err}"#
-                    )),
+            std::result::Result::Ok(value) => match <Take as std::str::FromStr>::from_str(value) {
+                std::result::Result::Ok(value) => {
+                    std::result::Result::Ok(header::IntoHeaderValue(value))
                 }
-            }
+                std::result::Result::Err(err) => std::result::Result::Err(format!(
+                    r#"Unable to convert header value '{value}' into Take - {err}"#
+                )),
+            },
             std::result::Result::Err(e) => std::result::Result::Err(format!(
                 r#"Unable to convert header: {hdr_value:?} to string: {e}"#
             )),
@@ -7993,47 +18204,65 @@ impl std::convert::TryFrom<HeaderValue>
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct ExportRunPayloadTimelineItemsInnerAnyOf1 {
-    /// Note: inline enums are not fully supported by openapi-generator
-    #[serde(rename = "kind")]
-    #[validate(custom(function = "check_xss_string"))]
-    pub kind: String,
+pub struct TakeTelemetry {
+    #[serde(rename = "achievedFps")]
+    pub achieved_fps: f64,
 
-    #[serde(rename = "id")]
-    #[validate(custom(function = "check_xss_string"))]
-    pub id: String,
+    #[serde(rename = "sourceDroppedFrames")]
+    pub source_dropped_frames: i32,
 
-    #[serde(rename = "durationSeconds")]
-    pub duration_seconds: f64,
+    #[serde(rename = "writerDroppedFrames")]
+    pub writer_dropped_frames: i32,
+
+    #[serde(rename = "writerBackpressureDrops")]
+    pub writer_backpressure_drops: i32,
+
+    #[serde(rename = "recordingBitrateMbps")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recording_bitrate_mbps: Option<f64>,
 }
 
-impl ExportRunPayloadTimelineItemsInnerAnyOf1 {
+impl TakeTelemetry {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
     pub fn new(
-        kind: String,
-        id: String,
-        duration_seconds: f64,
-    ) -> ExportRunPayloadTimelineItemsInnerAnyOf1 {
-        ExportRunPayloadTimelineItemsInnerAnyOf1 {
-            kind,
-            id,
-            duration_seconds,
+        achieved_fps: f64,
+        source_dropped_frames: i32,
+        writer_dropped_frames: i32,
+        writer_backpressure_drops: i32,
+    ) -> TakeTelemetry {
+        TakeTelemetry {
+            achieved_fps,
+            source_dropped_frames,
+            writer_dropped_frames,
+            writer_backpressure_drops,
+            recording_bitrate_mbps: None,
         }
     }
 }
 
-/// Converts the ExportRunPayloadTimelineItemsInnerAnyOf1 value to the Query Parameters representation (style=form, explode=false)
+/// Converts the TakeTelemetry value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for ExportRunPayloadTimelineItemsInnerAnyOf1 {
+impl std::fmt::Display for TakeTelemetry {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
-            Some("kind".to_string()),
-            Some(self.kind.to_string()),
-            Some("id".to_string()),
-            Some(self.id.to_string()),
-            Some("durationSeconds".to_string()),
-            Some(self.duration_seconds.to_string()),
+            Some("achievedFps".to_string()),
+            Some(self.achieved_fps.to_string()),
+            Some("sourceDroppedFrames".to_string()),
+            Some(self.source_dropped_frames.to_string()),
+            Some("writerDroppedFrames".to_string()),
+            Some(self.writer_dropped_frames.to_string()),
+            Some("writerBackpressureDrops".to_string()),
+            Some(self.writer_backpressure_drops.to_string()),
+            self.recording_bitrate_mbps
+                .as_ref()
+                .map(|recording_bitrate_mbps| {
+                    [
+                        "recordingBitrateMbps".to_string(),
+                        recording_bitrate_mbps.to_string(),
+                    ]
+                    .join(",")
+                }),
         ];
 
         write!(
@@ -8044,10 +18273,10 @@ impl std::fmt::Display for ExportRunPayloadTimelineItemsInnerAnyOf1 {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a ExportRunPayloadTimelineItemsInnerAnyOf1 value
+/// Converts Query Parameters representation (style=form, explode=false) to a TakeTelemetry value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for ExportRunPayloadTimelineItemsInnerAnyOf1 {
+impl std::str::FromStr for TakeTelemetry {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -8055,9 +18284,11 @@ impl std::str::FromStr for ExportRunPayloadTimelineItemsInnerAnyOf1 {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub kind: Vec<String>,
-            pub id: Vec<String>,
-            pub duration_seconds: Vec<f64>,
+            pub achieved_fps: Vec<f64>,
+            pub source_dropped_frames: Vec<i32>,
+            pub writer_dropped_frames: Vec<i32>,
+            pub writer_backpressure_drops: Vec<i32>,
+            pub recording_bitrate_mbps: Vec<f64>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -8071,8 +18302,7 @@ impl std::str::FromStr for ExportRunPayloadTimelineItemsInnerAnyOf1 {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing ExportRunPayloadTimelineItemsInnerAnyOf1"
-                            .to_string(),
+                        "Missing value while parsing TakeTelemetry".to_string(),
                     );
                 }
             };
@@ -8081,21 +18311,28 @@ impl std::str::FromStr for ExportRunPayloadTimelineItemsInnerAnyOf1 {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "kind" => intermediate_rep.kind.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "achievedFps" => intermediate_rep.achieved_fps.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "id" => intermediate_rep.id.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "sourceDroppedFrames" => intermediate_rep.source_dropped_frames.push(
+                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "durationSeconds" => intermediate_rep.duration_seconds.push(
+                    "writerDroppedFrames" => intermediate_rep.writer_dropped_frames.push(
+                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "writerBackpressureDrops" => intermediate_rep.writer_backpressure_drops.push(
+                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "recordingBitrateMbps" => intermediate_rep.recording_bitrate_mbps.push(
                         <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing ExportRunPayloadTimelineItemsInnerAnyOf1"
-                                .to_string(),
+                            "Unexpected key while parsing TakeTelemetry".to_string(),
                         );
                     }
                 }
@@ -8106,63 +18343,64 @@ impl std::str::FromStr for ExportRunPayloadTimelineItemsInnerAnyOf1 {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(ExportRunPayloadTimelineItemsInnerAnyOf1 {
-            kind: intermediate_rep.kind.into_iter().next().ok_or_else(|| {
-                "kind missing in ExportRunPayloadTimelineItemsInnerAnyOf1".to_string()
-            })?,
-            id: intermediate_rep.id.into_iter().next().ok_or_else(|| {
-                "id missing in ExportRunPayloadTimelineItemsInnerAnyOf1".to_string()
-            })?,
-            duration_seconds: intermediate_rep
-                .duration_seconds
+        std::result::Result::Ok(TakeTelemetry {
+            achieved_fps: intermediate_rep
+                .achieved_fps
                 .into_iter()
                 .next()
-                .ok_or_else(|| {
-                    "durationSeconds missing in ExportRunPayloadTimelineItemsInnerAnyOf1"
-                        .to_string()
-                })?,
+                .ok_or_else(|| "achievedFps missing in TakeTelemetry".to_string())?,
+            source_dropped_frames: intermediate_rep
+                .source_dropped_frames
+                .into_iter()
+                .next()
+                .ok_or_else(|| "sourceDroppedFrames missing in TakeTelemetry".to_string())?,
+            writer_dropped_frames: intermediate_rep
+                .writer_dropped_frames
+                .into_iter()
+                .next()
+                .ok_or_else(|| "writerDroppedFrames missing in TakeTelemetry".to_string())?,
+            writer_backpressure_drops: intermediate_rep
+                .writer_backpressure_drops
+                .into_iter()
+                .next()
+                .ok_or_else(|| "writerBackpressureDrops missing in TakeTelemetry".to_string())?,
+            recording_bitrate_mbps: intermediate_rep.recording_bitrate_mbps.into_iter().next(),
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<ExportRunPayloadTimelineItemsInnerAnyOf1> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<TakeTelemetry> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<ExportRunPayloadTimelineItemsInnerAnyOf1>>
-    for HeaderValue
-{
+impl std::convert::TryFrom<header::IntoHeaderValue<TakeTelemetry>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<ExportRunPayloadTimelineItemsInnerAnyOf1>,
+        hdr_value: header::IntoHeaderValue<TakeTelemetry>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                r#"Invalid header value for ExportRunPayloadTimelineItemsInnerAnyOf1 - value: {hdr_value} is invalid {e}"#
+                r#"Invalid header value for TakeTelemetry - value: {hdr_value} is invalid {e}"#
             )),
         }
     }
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue>
-    for header::IntoHeaderValue<ExportRunPayloadTimelineItemsInnerAnyOf1>
-{
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<TakeTelemetry> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <ExportRunPayloadTimelineItemsInnerAnyOf1 as std::str::FromStr>::from_str(
-                    value,
-                ) {
+                match <TakeTelemetry as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        r#"Unable to convert header value '{value}' into ExportRunPayloadTimelineItemsInnerAnyOf1 - {err}"#
+                        r#"Unable to convert header value '{value}' into TakeTelemetry - {err}"#
                     )),
                 }
             }
@@ -8175,47 +18413,34 @@ impl std::convert::TryFrom<HeaderValue>
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct ExportRunResult {
-    #[serde(rename = "jobId")]
-    #[validate(custom(function = "check_xss_string"))]
-    pub job_id: String,
-
-    /// Note: inline enums are not fully supported by openapi-generator
-    #[serde(rename = "status")]
-    #[validate(custom(function = "check_xss_string"))]
-    pub status: String,
-
-    #[serde(rename = "outputURL")]
-    #[validate(custom(function = "check_xss_string"))]
+pub struct TakesDeletePayload {
+    #[serde(rename = "destructiveIntent")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub output_url: Option<String>,
+    pub destructive_intent: Option<bool>,
 }
 
-impl ExportRunResult {
+impl TakesDeletePayload {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new(job_id: String, status: String) -> ExportRunResult {
-        ExportRunResult {
-            job_id,
-            status,
-            output_url: None,
+    pub fn new() -> TakesDeletePayload {
+        TakesDeletePayload {
+            destructive_intent: None,
         }
     }
 }
 
-/// Converts the ExportRunResult value to the Query Parameters representation (style=form, explode=false)
+/// Converts the TakesDeletePayload value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for ExportRunResult {
+impl std::fmt::Display for TakesDeletePayload {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let params: Vec<Option<String>> = vec![
-            Some("jobId".to_string()),
-            Some(self.job_id.to_string()),
-            Some("status".to_string()),
-            Some(self.status.to_string()),
-            self.output_url
-                .as_ref()
-                .map(|output_url| ["outputURL".to_string(), output_url.to_string()].join(",")),
-        ];
+        let params: Vec<Option<String>> =
+            vec![self.destructive_intent.as_ref().map(|destructive_intent| {
+                [
+                    "destructiveIntent".to_string(),
+                    destructive_intent.to_string(),
+                ]
+                .join(",")
+            })];
 
         write!(
             f,
@@ -8225,10 +18450,10 @@ impl std::fmt::Display for ExportRunResult {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a ExportRunResult value
+/// Converts Query Parameters representation (style=form, explode=false) to a TakesDeletePayload value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for ExportRunResult {
+impl std::str::FromStr for TakesDeletePayload {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -8236,9 +18461,7 @@ impl std::str::FromStr for ExportRunResult {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub job_id: Vec<String>,
-            pub status: Vec<String>,
-            pub output_url: Vec<String>,
+            pub destructive_intent: Vec<bool>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -8252,7 +18475,7 @@ impl std::str::FromStr for ExportRunResult {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing ExportRunResult".to_string(),
+                        "Missing value while parsing TakesDeletePayload".to_string(),
                     );
                 }
             };
@@ -8261,20 +18484,12 @@ impl std::str::FromStr for ExportRunResult {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "jobId" => intermediate_rep.job_id.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "status" => intermediate_rep.status.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "outputURL" => intermediate_rep.output_url.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "destructiveIntent" => intermediate_rep.destructive_intent.push(
+                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing ExportRunResult".to_string(),
+                            "Unexpected key while parsing TakesDeletePayload".to_string(),
                         );
                     }
                 }
@@ -8285,54 +18500,44 @@ impl std::str::FromStr for ExportRunResult {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(ExportRunResult {
-            job_id: intermediate_rep
-                .job_id
-                .into_iter()
-                .next()
-                .ok_or_else(|| "jobId missing in ExportRunResult".to_string())?,
-            status: intermediate_rep
-                .status
-                .into_iter()
-                .next()
-                .ok_or_else(|| "status missing in ExportRunResult".to_string())?,
-            output_url: intermediate_rep.output_url.into_iter().next(),
+        std::result::Result::Ok(TakesDeletePayload {
+            destructive_intent: intermediate_rep.destructive_intent.into_iter().next(),
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<ExportRunResult> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<TakesDeletePayload> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<ExportRunResult>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<TakesDeletePayload>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<ExportRunResult>,
+        hdr_value: header::IntoHeaderValue<TakesDeletePayload>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                r#"Invalid header value for ExportRunResult - value: {hdr_value} is invalid {e}"#
+                r#"Invalid header value for TakesDeletePayload - value: {hdr_value} is invalid {e}"#
             )),
         }
     }
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<ExportRunResult> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<TakesDeletePayload> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <ExportRunResult as std::str::FromStr>::from_str(value) {
+                match <TakesDeletePayload as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        r#"Unable to convert header value '{value}' into ExportRunResult - {err}"#
+                        r#"Unable to convert header value '{value}' into TakesDeletePayload - {err}"#
                     )),
                 }
             }
@@ -8345,47 +18550,26 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<ExportRunRes
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct PermissionsResult {
-    #[serde(rename = "screenRecordingGranted")]
-    pub screen_recording_granted: bool,
-
-    #[serde(rename = "microphoneGranted")]
-    pub microphone_granted: bool,
-
-    /// Note: inline enums are not fully supported by openapi-generator
-    #[serde(rename = "inputMonitoring")]
+pub struct TakesRenamePayload {
+    #[serde(rename = "name")]
     #[validate(custom(function = "check_xss_string"))]
-    pub input_monitoring: String,
+    pub name: String,
 }
 
-impl PermissionsResult {
+impl TakesRenamePayload {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new(
-        screen_recording_granted: bool,
-        microphone_granted: bool,
-        input_monitoring: String,
-    ) -> PermissionsResult {
-        PermissionsResult {
-            screen_recording_granted,
-            microphone_granted,
-            input_monitoring,
-        }
+    pub fn new(name: String) -> TakesRenamePayload {
+        TakesRenamePayload { name }
     }
 }
 
-/// Converts the PermissionsResult value to the Query Parameters representation (style=form, explode=false)
+/// Converts the TakesRenamePayload value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for PermissionsResult {
+impl std::fmt::Display for TakesRenamePayload {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let params: Vec<Option<String>> = vec![
-            Some("screenRecordingGranted".to_string()),
-            Some(self.screen_recording_granted.to_string()),
-            Some("microphoneGranted".to_string()),
-            Some(self.microphone_granted.to_string()),
-            Some("inputMonitoring".to_string()),
-            Some(self.input_monitoring.to_string()),
-        ];
+        let params: Vec<Option<String>> =
+            vec![Some("name".to_string()), Some(self.name.to_string())];
 
         write!(
             f,
@@ -8395,10 +18579,10 @@ impl std::fmt::Display for PermissionsResult {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a PermissionsResult value
+/// Converts Query Parameters representation (style=form, explode=false) to a TakesRenamePayload value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for PermissionsResult {
+impl std::str::FromStr for TakesRenamePayload {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -8406,9 +18590,7 @@ impl std::str::FromStr for PermissionsResult {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub screen_recording_granted: Vec<bool>,
-            pub microphone_granted: Vec<bool>,
-            pub input_monitoring: Vec<String>,
+            pub name: Vec<String>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -8422,7 +18604,7 @@ impl std::str::FromStr for PermissionsResult {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing PermissionsResult".to_string(),
+                        "Missing value while parsing TakesRenamePayload".to_string(),
                     );
                 }
             };
@@ -8431,20 +18613,12 @@ impl std::str::FromStr for PermissionsResult {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "screenRecordingGranted" => intermediate_rep.screen_recording_granted.push(
-                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "microphoneGranted" => intermediate_rep.microphone_granted.push(
-                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "inputMonitoring" => intermediate_rep.input_monitoring.push(
+                    "name" => intermediate_rep.name.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing PermissionsResult".to_string(),
+                            "Unexpected key while parsing TakesRenamePayload".to_string(),
                         );
                     }
                 }
@@ -8455,58 +18629,48 @@ impl std::str::FromStr for PermissionsResult {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(PermissionsResult {
-            screen_recording_granted: intermediate_rep
-                .screen_recording_granted
-                .into_iter()
-                .next()
-                .ok_or_else(|| "screenRecordingGranted missing in PermissionsResult".to_string())?,
-            microphone_granted: intermediate_rep
-                .microphone_granted
-                .into_iter()
-                .next()
-                .ok_or_else(|| "microphoneGranted missing in PermissionsResult".to_string())?,
-            input_monitoring: intermediate_rep
-                .input_monitoring
+        std::result::Result::Ok(TakesRenamePayload {
+            name: intermediate_rep
+                .name
                 .into_iter()
                 .next()
-                .ok_or_else(|| "inputMonitoring missing in PermissionsResult".to_string())?,
+                .ok_or_else(|| "name missing in TakesRenamePayload".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<PermissionsResult> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<TakesRenamePayload> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<PermissionsResult>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<TakesRenamePayload>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<PermissionsResult>,
+        hdr_value: header::IntoHeaderValue<TakesRenamePayload>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                r#"Invalid header value for PermissionsResult - value: {hdr_value} is invalid {e}"#
+                r#"Invalid header value for TakesRenamePayload - value: {hdr_value} is invalid {e}"#
             )),
         }
     }
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<PermissionsResult> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<TakesRenamePayload> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <PermissionsResult as std::str::FromStr>::from_str(value) {
+                match <TakesRenamePayload as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        r#"Unable to convert header value '{value}' into PermissionsResult - {err}"#
+                        r#"Unable to convert header value '{value}' into TakesRenamePayload - {err}"#
                     )),
                 }
             }
@@ -8519,55 +18683,37 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<PermissionsR
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct PingResult {
-    #[serde(rename = "app")]
-    #[validate(custom(function = "check_xss_string"))]
-    pub app: String,
-
-    #[serde(rename = "engineVersion")]
-    #[validate(custom(function = "check_xss_string"))]
-    pub engine_version: String,
-
-    #[serde(rename = "protocolVersion")]
-    #[validate(custom(function = "check_xss_string"))]
-    pub protocol_version: String,
+pub struct TakesResult {
+    #[serde(rename = "takes")]
+    #[validate(nested)]
+    pub takes: Vec<models::Take>,
 
-    #[serde(rename = "platform")]
+    #[serde(rename = "activeTakeId")]
     #[validate(custom(function = "check_xss_string"))]
-    pub platform: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active_take_id: Option<String>,
 }
 
-impl PingResult {
+impl TakesResult {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new(
-        app: String,
-        engine_version: String,
-        protocol_version: String,
-        platform: String,
-    ) -> PingResult {
-        PingResult {
-            app,
-            engine_version,
-            protocol_version,
-            platform,
+    pub fn new(takes: Vec<models::Take>) -> TakesResult {
+        TakesResult {
+            takes,
+            active_take_id: None,
         }
     }
 }
 
-/// Converts the PingResult value to the Query Parameters representation (style=form, explode=false)
+/// Converts the TakesResult value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for PingResult {
+impl std::fmt::Display for TakesResult {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
-            Some("app".to_string()),
-            Some(self.app.to_string()),
-            Some("engineVersion".to_string()),
-            Some(self.engine_version.to_string()),
-            Some("protocolVersion".to_string()),
-            Some(self.protocol_version.to_string()),
-            Some("platform".to_string()),
-            Some(self.platform.to_string()),
+            // Skipping takes in query parameter serialization
+            self.active_take_id.as_ref().map(|active_take_id| {
+                ["activeTakeId".to_string(), active_take_id.to_string()].join(",")
+            }),
         ];
 
         write!(
@@ -8578,10 +18724,10 @@ impl std::fmt::Display for PingResult {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a PingResult value
+/// Converts Query Parameters representation (style=form, explode=false) to a TakesResult value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for PingResult {
+impl std::str::FromStr for TakesResult {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -8589,10 +18735,8 @@ impl std::str::FromStr for PingResult {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub app: Vec<String>,
-            pub engine_version: Vec<String>,
-            pub protocol_version: Vec<String>,
-            pub platform: Vec<String>,
+            pub takes: Vec<Vec<models::Take>>,
+            pub active_take_id: Vec<String>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -8606,7 +18750,7 @@ impl std::str::FromStr for PingResult {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing PingResult".to_string(),
+                        "Missing value while parsing TakesResult".to_string(),
                     );
                 }
             };
@@ -8614,25 +18758,19 @@ impl std::str::FromStr for PingResult {
             if let Some(key) = key_result {
                 #[allow(clippy::match_single_binding)]
                 match key {
+                    "takes" => {
+                        return std::result::Result::Err(
+                            "Parsing a container in this style is not supported in TakesResult"
+                                .to_string(),
+                        );
+                    }
                     #[allow(clippy::redundant_clone)]
-                    "app" => intermediate_rep.app.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "engineVersion" => intermediate_rep.engine_version.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "protocolVersion" => intermediate_rep.protocol_version.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "platform" => intermediate_rep.platform.push(
+                    "activeTakeId" => intermediate_rep.active_take_id.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing PingResult".to_string(),
+                            "Unexpected key while parsing TakesResult".to_string(),
                         );
                     }
                 }
@@ -8643,63 +18781,49 @@ impl std::str::FromStr for PingResult {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(PingResult {
-            app: intermediate_rep
-                .app
-                .into_iter()
-                .next()
-                .ok_or_else(|| "app missing in PingResult".to_string())?,
-            engine_version: intermediate_rep
-                .engine_version
-                .into_iter()
-                .next()
-                .ok_or_else(|| "engineVersion missing in PingResult".to_string())?,
-            protocol_version: intermediate_rep
-                .protocol_version
-                .into_iter()
-                .next()
-                .ok_or_else(|| "protocolVersion missing in PingResult".to_string())?,
-            platform: intermediate_rep
-                .platform
+        std::result::Result::Ok(TakesResult {
+            takes: intermediate_rep
+                .takes
                 .into_iter()
                 .next()
-                .ok_or_else(|| "platform missing in PingResult".to_string())?,
+                .ok_or_else(|| "takes missing in TakesResult".to_string())?,
+            active_take_id: intermediate_rep.active_take_id.into_iter().next(),
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<PingResult> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<TakesResult> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<PingResult>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<TakesResult>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<PingResult>,
+        hdr_value: header::IntoHeaderValue<TakesResult>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                r#"Invalid header value for PingResult - value: {hdr_value} is invalid {e}"#
+                r#"Invalid header value for TakesResult - value: {hdr_value} is invalid {e}"#
             )),
         }
     }
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<PingResult> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<TakesResult> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <PingResult as std::str::FromStr>::from_str(value) {
+                match <TakesResult as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        r#"Unable to convert header value '{value}' into PingResult - {err}"#
+                        r#"Unable to convert header value '{value}' into TakesResult - {err}"#
                     )),
                 }
             }
@@ -8712,58 +18836,45 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<PingResult>
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct ProjectAgentAnalysisSummary {
-    #[serde(rename = "latestJobId")]
-    #[validate(custom(function = "check_xss_string"))]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub latest_job_id: Option<String>,
+pub struct TimelineApplyCutsPayload {
+    #[serde(rename = "suggestionIds")]
+    #[validate(custom(function = "check_xss_vec_string"))]
+    pub suggestion_ids: Vec<String>,
 
     /// Note: inline enums are not fully supported by openapi-generator
-    #[serde(rename = "latestStatus")]
-    #[validate(custom(function = "check_xss_string"))]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub latest_status: Option<String>,
-
-    #[serde(rename = "qaPassed")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub qa_passed: Option<bool>,
-
-    #[serde(rename = "updatedAt")]
+    #[serde(rename = "operation")]
     #[validate(custom(function = "check_xss_string"))]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_at: Option<String>,
+    pub operation: Option<String>,
 }
 
-impl ProjectAgentAnalysisSummary {
+impl TimelineApplyCutsPayload {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new() -> ProjectAgentAnalysisSummary {
-        ProjectAgentAnalysisSummary {
-            latest_job_id: None,
-            latest_status: None,
-            qa_passed: None,
-            updated_at: None,
+    pub fn new(suggestion_ids: Vec<String>) -> TimelineApplyCutsPayload {
+        TimelineApplyCutsPayload {
+            suggestion_ids,
+            operation: None,
         }
     }
 }
 
-/// Converts the ProjectAgentAnalysisSummary value to the Query Parameters representation (style=form, explode=false)
+/// Converts the TimelineApplyCutsPayload value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for ProjectAgentAnalysisSummary {
+impl std::fmt::Display for TimelineApplyCutsPayload {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
-            self.latest_job_id.as_ref().map(|latest_job_id| {
-                ["latestJobId".to_string(), latest_job_id.to_string()].join(",")
-            }),
-            self.latest_status.as_ref().map(|latest_status| {
-                ["latestStatus".to_string(), latest_status.to_string()].join(",")
-            }),
-            self.qa_passed
-                .as_ref()
-                .map(|qa_passed| ["qaPassed".to_string(), qa_passed.to_string()].join(",")),
-            self.updated_at
+            Some("suggestionIds".to_string()),
+            Some(
+                self.suggestion_ids
+                    .iter()
+                    .map(|x| x.to_string())
+                    .collect::<Vec<_>>()
+                    .join(","),
+            ),
+            self.operation
                 .as_ref()
-                .map(|updated_at| ["updatedAt".to_string(), updated_at.to_string()].join(",")),
+                .map(|operation| ["operation".to_string(), operation.to_string()].join(",")),
         ];
 
         write!(
@@ -8774,10 +18885,10 @@ impl std::fmt::Display for ProjectAgentAnalysisSummary {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a ProjectAgentAnalysisSummary value
+/// Converts Query Parameters representation (style=form, explode=false) to a TimelineApplyCutsPayload value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for ProjectAgentAnalysisSummary {
+impl std::str::FromStr for TimelineApplyCutsPayload {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -8785,10 +18896,8 @@ impl std::str::FromStr for ProjectAgentAnalysisSummary {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub latest_job_id: Vec<String>,
-            pub latest_status: Vec<String>,
-            pub qa_passed: Vec<bool>,
-            pub updated_at: Vec<String>,
+            pub suggestion_ids: Vec<Vec<String>>,
+            pub operation: Vec<String>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -8802,7 +18911,7 @@ impl std::str::FromStr for ProjectAgentAnalysisSummary {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing ProjectAgentAnalysisSummary".to_string(),
+                        "Missing value while parsing TimelineApplyCutsPayload".to_string(),
                     );
                 }
             };
@@ -8810,27 +18919,10 @@ impl std::str::FromStr for ProjectAgentAnalysisSummary {
             if let Some(key) = key_result {
                 #[allow(clippy::match_single_binding)]
                 match key {
+                    "suggestionIds" => return std::result::Result::Err("Parsing a container in this style is not supported in TimelineApplyCutsPayload".to_string()),
                     #[allow(clippy::redundant_clone)]
-                    "latestJobId" => intermediate_rep.latest_job_id.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "latestStatus" => intermediate_rep.latest_status.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "qaPassed" => intermediate_rep.qa_passed.push(
-                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "updatedAt" => intermediate_rep.updated_at.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    _ => {
-                        return std::result::Result::Err(
-                            "Unexpected key while parsing ProjectAgentAnalysisSummary".to_string(),
-                        );
-                    }
+                    "operation" => intermediate_rep.operation.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing TimelineApplyCutsPayload".to_string())
                 }
             }
 
@@ -8839,47 +18931,49 @@ impl std::str::FromStr for ProjectAgentAnalysisSummary {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(ProjectAgentAnalysisSummary {
-            latest_job_id: intermediate_rep.latest_job_id.into_iter().next(),
-            latest_status: intermediate_rep.latest_status.into_iter().next(),
-            qa_passed: intermediate_rep.qa_passed.into_iter().next(),
-            updated_at: intermediate_rep.updated_at.into_iter().next(),
+        std::result::Result::Ok(TimelineApplyCutsPayload {
+            suggestion_ids: intermediate_rep
+                .suggestion_ids
+                .into_iter()
+                .next()
+                .ok_or_else(|| "suggestionIds missing in TimelineApplyCutsPayload".to_string())?,
+            operation: intermediate_rep.operation.into_iter().next(),
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<ProjectAgentAnalysisSummary> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<TimelineApplyCutsPayload> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<ProjectAgentAnalysisSummary>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<TimelineApplyCutsPayload>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<ProjectAgentAnalysisSummary>,
+        hdr_value: header::IntoHeaderValue<TimelineApplyCutsPayload>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                r#"Invalid header value for ProjectAgentAnalysisSummary - value: {hdr_value} is invalid {e}"#
+                r#"Invalid header value for TimelineApplyCutsPayload - value: {hdr_value} is invalid {e}"#
             )),
         }
     }
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<ProjectAgentAnalysisSummary> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<TimelineApplyCutsPayload> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <ProjectAgentAnalysisSummary as std::str::FromStr>::from_str(value) {
+                match <TimelineApplyCutsPayload as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        r#"Unable to convert header value '{value}' into ProjectAgentAnalysisSummary - {err}"#
+                        r#"Unable to convert header value '{value}' into TimelineApplyCutsPayload - {err}"#
                     )),
                 }
             }
@@ -8892,27 +18986,51 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<ProjectAgent
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct ProjectOpenPayload {
-    #[serde(rename = "projectPath")]
-    #[validate(custom(function = "check_xss_string"))]
-    pub project_path: String,
+pub struct TimelineApplyCutsResult {
+    #[serde(rename = "timeline")]
+    #[validate(nested)]
+    pub timeline: models::ExportRunPayloadTimeline,
+
+    #[serde(rename = "appliedSuggestionIds")]
+    #[validate(custom(function = "check_xss_vec_string"))]
+    pub applied_suggestion_ids: Vec<String>,
+
+    #[serde(rename = "suggestions")]
+    #[validate(nested)]
+    pub suggestions: Vec<models::SuggestedCut>,
 }
 
-impl ProjectOpenPayload {
+impl TimelineApplyCutsResult {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new(project_path: String) -> ProjectOpenPayload {
-        ProjectOpenPayload { project_path }
+    pub fn new(
+        timeline: models::ExportRunPayloadTimeline,
+        applied_suggestion_ids: Vec<String>,
+        suggestions: Vec<models::SuggestedCut>,
+    ) -> TimelineApplyCutsResult {
+        TimelineApplyCutsResult {
+            timeline,
+            applied_suggestion_ids,
+            suggestions,
+        }
     }
 }
 
-/// Converts the ProjectOpenPayload value to the Query Parameters representation (style=form, explode=false)
+/// Converts the TimelineApplyCutsResult value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for ProjectOpenPayload {
+impl std::fmt::Display for TimelineApplyCutsResult {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
-            Some("projectPath".to_string()),
-            Some(self.project_path.to_string()),
+            // Skipping timeline in query parameter serialization
+            Some("appliedSuggestionIds".to_string()),
+            Some(
+                self.applied_suggestion_ids
+                    .iter()
+                    .map(|x| x.to_string())
+                    .collect::<Vec<_>>()
+                    .join(","),
+            ),
+            // Skipping suggestions in query parameter serialization
         ];
 
         write!(
@@ -8923,10 +19041,10 @@ impl std::fmt::Display for ProjectOpenPayload {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a ProjectOpenPayload value
+/// Converts Query Parameters representation (style=form, explode=false) to a TimelineApplyCutsResult value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for ProjectOpenPayload {
+impl std::str::FromStr for TimelineApplyCutsResult {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -8934,7 +19052,9 @@ impl std::str::FromStr for ProjectOpenPayload {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub project_path: Vec<String>,
+            pub timeline: Vec<models::ExportRunPayloadTimeline>,
+            pub applied_suggestion_ids: Vec<Vec<String>>,
+            pub suggestions: Vec<Vec<models::SuggestedCut>>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -8948,7 +19068,7 @@ impl std::str::FromStr for ProjectOpenPayload {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing ProjectOpenPayload".to_string(),
+                        "Missing value while parsing TimelineApplyCutsResult".to_string(),
                     );
                 }
             };
@@ -8957,14 +19077,10 @@ impl std::str::FromStr for ProjectOpenPayload {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "projectPath" => intermediate_rep.project_path.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    _ => {
-                        return std::result::Result::Err(
-                            "Unexpected key while parsing ProjectOpenPayload".to_string(),
-                        );
-                    }
+                    "timeline" => intermediate_rep.timeline.push(<models::ExportRunPayloadTimeline as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    "appliedSuggestionIds" => return std::result::Result::Err("Parsing a container in this style is not supported in TimelineApplyCutsResult".to_string()),
+                    "suggestions" => return std::result::Result::Err("Parsing a container in this style is not supported in TimelineApplyCutsResult".to_string()),
+                    _ => return std::result::Result::Err("Unexpected key while parsing TimelineApplyCutsResult".to_string())
                 }
             }
 
@@ -8973,48 +19089,60 @@ impl std::str::FromStr for ProjectOpenPayload {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(ProjectOpenPayload {
-            project_path: intermediate_rep
-                .project_path
+        std::result::Result::Ok(TimelineApplyCutsResult {
+            timeline: intermediate_rep
+                .timeline
                 .into_iter()
                 .next()
-                .ok_or_else(|| "projectPath missing in ProjectOpenPayload".to_string())?,
+                .ok_or_else(|| "timeline missing in TimelineApplyCutsResult".to_string())?,
+            applied_suggestion_ids: intermediate_rep
+                .applied_suggestion_ids
+                .into_iter()
+                .next()
+                .ok_or_else(|| {
+                    "appliedSuggestionIds missing in TimelineApplyCutsResult".to_string()
+                })?,
+            suggestions: intermediate_rep
+                .suggestions
+                .into_iter()
+                .next()
+                .ok_or_else(|| "suggestions missing in TimelineApplyCutsResult".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<ProjectOpenPayload> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<TimelineApplyCutsResult> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<ProjectOpenPayload>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<TimelineApplyCutsResult>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<ProjectOpenPayload>,
+        hdr_value: header::IntoHeaderValue<TimelineApplyCutsResult>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                r#"Invalid header value for ProjectOpenPayload - value: {hdr_value} is invalid {e}"#
+                r#"Invalid header value for TimelineApplyCutsResult - value: {hdr_value} is invalid {e}"#
             )),
         }
     }
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<ProjectOpenPayload> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<TimelineApplyCutsResult> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <ProjectOpenPayload as std::str::FromStr>::from_str(value) {
+                match <TimelineApplyCutsResult as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        r#"Unable to convert header value '{value}' into ProjectOpenPayload - {err}"#
+                        r#"Unable to convert header value '{value}' into TimelineApplyCutsResult - {err}"#
                     )),
                 }
             }
@@ -9027,47 +19155,70 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<ProjectOpenP
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct ProjectRecentItem {
-    #[serde(rename = "projectPath")]
-    #[validate(custom(function = "check_xss_string"))]
-    pub project_path: String,
+pub struct TimelineSuggestCutsPayload {
+    #[serde(rename = "minIdleSeconds")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_idle_seconds: Option<f64>,
 
-    #[serde(rename = "displayName")]
-    #[validate(custom(function = "check_xss_string"))]
-    pub display_name: String,
+    #[serde(rename = "stillnessThreshold")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stillness_threshold: Option<f64>,
 
-    #[serde(rename = "lastOpenedAt")]
+    #[serde(rename = "paddingSeconds")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub padding_seconds: Option<f64>,
+
+    /// Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "operation")]
     #[validate(custom(function = "check_xss_string"))]
-    pub last_opened_at: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub operation: Option<String>,
+
+    #[serde(rename = "playbackRate")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub playback_rate: Option<f64>,
 }
 
-impl ProjectRecentItem {
+impl TimelineSuggestCutsPayload {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new(
-        project_path: String,
-        display_name: String,
-        last_opened_at: String,
-    ) -> ProjectRecentItem {
-        ProjectRecentItem {
-            project_path,
-            display_name,
-            last_opened_at,
+    pub fn new() -> TimelineSuggestCutsPayload {
+        TimelineSuggestCutsPayload {
+            min_idle_seconds: None,
+            stillness_threshold: None,
+            padding_seconds: None,
+            operation: None,
+            playback_rate: None,
         }
     }
 }
 
-/// Converts the ProjectRecentItem value to the Query Parameters representation (style=form, explode=false)
+/// Converts the TimelineSuggestCutsPayload value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for ProjectRecentItem {
+impl std::fmt::Display for TimelineSuggestCutsPayload {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
-            Some("projectPath".to_string()),
-            Some(self.project_path.to_string()),
-            Some("displayName".to_string()),
-            Some(self.display_name.to_string()),
-            Some("lastOpenedAt".to_string()),
-            Some(self.last_opened_at.to_string()),
+            self.min_idle_seconds.as_ref().map(|min_idle_seconds| {
+                ["minIdleSeconds".to_string(), min_idle_seconds.to_string()].join(",")
+            }),
+            self.stillness_threshold
+                .as_ref()
+                .map(|stillness_threshold| {
+                    [
+                        "stillnessThreshold".to_string(),
+                        stillness_threshold.to_string(),
+                    ]
+                    .join(",")
+                }),
+            self.padding_seconds.as_ref().map(|padding_seconds| {
+                ["paddingSeconds".to_string(), padding_seconds.to_string()].join(",")
+            }),
+            self.operation
+                .as_ref()
+                .map(|operation| ["operation".to_string(), operation.to_string()].join(",")),
+            self.playback_rate.as_ref().map(|playback_rate| {
+                ["playbackRate".to_string(), playback_rate.to_string()].join(",")
+            }),
         ];
 
         write!(
@@ -9078,10 +19229,10 @@ impl std::fmt::Display for ProjectRecentItem {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a ProjectRecentItem value
+/// Converts Query Parameters representation (style=form, explode=false) to a TimelineSuggestCutsPayload value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for ProjectRecentItem {
+impl std::str::FromStr for TimelineSuggestCutsPayload {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -9089,9 +19240,11 @@ impl std::str::FromStr for ProjectRecentItem {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub project_path: Vec<String>,
-            pub display_name: Vec<String>,
-            pub last_opened_at: Vec<String>,
+            pub min_idle_seconds: Vec<f64>,
+            pub stillness_threshold: Vec<f64>,
+            pub padding_seconds: Vec<f64>,
+            pub operation: Vec<String>,
+            pub playback_rate: Vec<f64>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -9105,7 +19258,7 @@ impl std::str::FromStr for ProjectRecentItem {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing ProjectRecentItem".to_string(),
+                        "Missing value while parsing TimelineSuggestCutsPayload".to_string(),
                     );
                 }
             };
@@ -9114,20 +19267,28 @@ impl std::str::FromStr for ProjectRecentItem {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "projectPath" => intermediate_rep.project_path.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "minIdleSeconds" => intermediate_rep.min_idle_seconds.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "displayName" => intermediate_rep.display_name.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "stillnessThreshold" => intermediate_rep.stillness_threshold.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "lastOpenedAt" => intermediate_rep.last_opened_at.push(
+                    "paddingSeconds" => intermediate_rep.padding_seconds.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "operation" => intermediate_rep.operation.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
+                    #[allow(clippy::redundant_clone)]
+                    "playbackRate" => intermediate_rep.playback_rate.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing ProjectRecentItem".to_string(),
+                            "Unexpected key while parsing TimelineSuggestCutsPayload".to_string(),
                         );
                     }
                 }
@@ -9138,58 +19299,48 @@ impl std::str::FromStr for ProjectRecentItem {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(ProjectRecentItem {
-            project_path: intermediate_rep
-                .project_path
-                .into_iter()
-                .next()
-                .ok_or_else(|| "projectPath missing in ProjectRecentItem".to_string())?,
-            display_name: intermediate_rep
-                .display_name
-                .into_iter()
-                .next()
-                .ok_or_else(|| "displayName missing in ProjectRecentItem".to_string())?,
-            last_opened_at: intermediate_rep
-                .last_opened_at
-                .into_iter()
-                .next()
-                .ok_or_else(|| "lastOpenedAt missing in ProjectRecentItem".to_string())?,
+        std::result::Result::Ok(TimelineSuggestCutsPayload {
+            min_idle_seconds: intermediate_rep.min_idle_seconds.into_iter().next(),
+            stillness_threshold: intermediate_rep.stillness_threshold.into_iter().next(),
+            padding_seconds: intermediate_rep.padding_seconds.into_iter().next(),
+            operation: intermediate_rep.operation.into_iter().next(),
+            playback_rate: intermediate_rep.playback_rate.into_iter().next(),
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<ProjectRecentItem> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<TimelineSuggestCutsPayload> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<ProjectRecentItem>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<TimelineSuggestCutsPayload>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<ProjectRecentItem>,
+        hdr_value: header::IntoHeaderValue<TimelineSuggestCutsPayload>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                r#"Invalid header value for ProjectRecentItem - value: {hdr_value} is invalid {e}"#
+                r#"Invalid header value for TimelineSuggestCutsPayload - value: {hdr_value} is invalid {e}"#
             )),
         }
     }
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<ProjectRecentItem> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<TimelineSuggestCutsPayload> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <ProjectRecentItem as std::str::FromStr>::from_str(value) {
+                match <TimelineSuggestCutsPayload as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        r#"Unable to convert header value '{value}' into ProjectRecentItem - {err}"#
+                        r#"Unable to convert header value '{value}' into TimelineSuggestCutsPayload - {err}"#
                     )),
                 }
             }
@@ -9202,27 +19353,59 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<ProjectRecen
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct ProjectRecentsResult {
-    #[serde(rename = "items")]
+pub struct TimelineSuggestCutsResult {
+    #[serde(rename = "takeId")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub take_id: String,
+
+    #[serde(rename = "durationSeconds")]
+    pub duration_seconds: f64,
+
+    #[serde(rename = "signals")]
     #[validate(nested)]
-    pub items: Vec<models::ProjectRecentItem>,
+    pub signals: models::IdleCutSignals,
+
+    #[serde(rename = "idleSeconds")]
+    pub idle_seconds: f64,
+
+    #[serde(rename = "suggestions")]
+    #[validate(nested)]
+    pub suggestions: Vec<models::SuggestedCut>,
 }
 
-impl ProjectRecentsResult {
+impl TimelineSuggestCutsResult {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new(items: Vec<models::ProjectRecentItem>) -> ProjectRecentsResult {
-        ProjectRecentsResult { items }
+    pub fn new(
+        take_id: String,
+        duration_seconds: f64,
+        signals: models::IdleCutSignals,
+        idle_seconds: f64,
+        suggestions: Vec<models::SuggestedCut>,
+    ) -> TimelineSuggestCutsResult {
+        TimelineSuggestCutsResult {
+            take_id,
+            duration_seconds,
+            signals,
+            idle_seconds,
+            suggestions,
+        }
     }
 }
 
-/// Converts the ProjectRecentsResult value to the Query Parameters representation (style=form, explode=false)
+/// Converts the TimelineSuggestCutsResult value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for ProjectRecentsResult {
+impl std::fmt::Display for TimelineSuggestCutsResult {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
-            // Skipping items in query parameter serialization
-
+            Some("takeId".to_string()),
+            Some(self.take_id.to_string()),
+            Some("durationSeconds".to_string()),
+            Some(self.duration_seconds.to_string()),
+            // Skipping signals in query parameter serialization
+            Some("idleSeconds".to_string()),
+            Some(self.idle_seconds.to_string()),
+            // Skipping suggestions in query parameter serialization
         ];
 
         write!(
@@ -9233,10 +19416,10 @@ impl std::fmt::Display for ProjectRecentsResult {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a ProjectRecentsResult value
+/// Converts Query Parameters representation (style=form, explode=false) to a TimelineSuggestCutsResult value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for ProjectRecentsResult {
+impl std::str::FromStr for TimelineSuggestCutsResult {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -9244,7 +19427,11 @@ impl std::str::FromStr for ProjectRecentsResult {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub items: Vec<Vec<models::ProjectRecentItem>>,
+            pub take_id: Vec<String>,
+            pub duration_seconds: Vec<f64>,
+            pub signals: Vec<models::IdleCutSignals>,
+            pub idle_seconds: Vec<f64>,
+            pub suggestions: Vec<Vec<models::SuggestedCut>>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -9258,7 +19445,7 @@ impl std::str::FromStr for ProjectRecentsResult {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing ProjectRecentsResult".to_string(),
+                        "Missing value while parsing TimelineSuggestCutsResult".to_string(),
                     );
                 }
             };
@@ -9266,8 +19453,16 @@ impl std::str::FromStr for ProjectRecentsResult {
             if let Some(key) = key_result {
                 #[allow(clippy::match_single_binding)]
                 match key {
-                    "items" => return std::result::Result::Err("Parsing a container in this style is not supported in ProjectRecentsResult".to_string()),
-                    _ => return std::result::Result::Err("Unexpected key while parsing ProjectRecentsResult".to_string())
+                    #[allow(clippy::redundant_clone)]
+                    "takeId" => intermediate_rep.take_id.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "durationSeconds" => intermediate_rep.duration_seconds.push(<f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "signals" => intermediate_rep.signals.push(<models::IdleCutSignals as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "idleSeconds" => intermediate_rep.idle_seconds.push(<f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    "suggestions" => return std::result::Result::Err("Parsing a container in this style is not supported in TimelineSuggestCutsResult".to_string()),
+                    _ => return std::result::Result::Err("Unexpected key while parsing TimelineSuggestCutsResult".to_string())
                 }
             }
 
@@ -9276,48 +19471,70 @@ impl std::str::FromStr for ProjectRecentsResult {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(ProjectRecentsResult {
-            items: intermediate_rep
-                .items
+        std::result::Result::Ok(TimelineSuggestCutsResult {
+            take_id: intermediate_rep
+                .take_id
                 .into_iter()
                 .next()
-                .ok_or_else(|| "items missing in ProjectRecentsResult".to_string())?,
+                .ok_or_else(|| "takeId missing in TimelineSuggestCutsResult".to_string())?,
+            duration_seconds: intermediate_rep
+                .duration_seconds
+                .into_iter()
+                .next()
+                .ok_or_else(|| {
+                    "durationSeconds missing in TimelineSuggestCutsResult".to_string()
+                })?,
+            signals: intermediate_rep
+                .signals
+                .into_iter()
+                .next()
+                .ok_or_else(|| "signals missing in TimelineSuggestCutsResult".to_string())?,
+            idle_seconds: intermediate_rep
+                .idle_seconds
+                .into_iter()
+                .next()
+                .ok_or_else(|| "idleSeconds missing in TimelineSuggestCutsResult".to_string())?,
+            suggestions: intermediate_rep
+                .suggestions
+                .into_iter()
+                .next()
+                .ok_or_else(|| "suggestions missing in TimelineSuggestCutsResult".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<ProjectRecentsResult> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<TimelineSuggestCutsResult> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<ProjectRecentsResult>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<TimelineSuggestCutsResult>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<ProjectRecentsResult>,
+        hdr_value: header::IntoHeaderValue<TimelineSuggestCutsResult>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                r#"Invalid header value for ProjectRecentsResult - value: {hdr_value} is invalid {e}"#
+                r#"Invalid header value for TimelineSuggestCutsResult - value: {hdr_value} is invalid {e}"#
             )),
         }
     }
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<ProjectRecentsResult> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<TimelineSuggestCutsResult> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <ProjectRecentsResult as std::str::FromStr>::from_str(value) {
+                match <TimelineSuggestCutsResult as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        r#"Unable to convert header value '{value}' into ProjectRecentsResult - {err}"#
+                        r#"Unable to convert header value '{value}' into TimelineSuggestCutsResult - {err}"#
                     )),
                 }
             }
@@ -9330,54 +19547,104 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<ProjectRecen
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct ProjectSavePayload {
-    #[serde(rename = "projectPath")]
+pub struct VisualActivityResult {
+    /// Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "version")]
+    pub version: f64,
+
+    #[serde(rename = "takeId")]
     #[validate(custom(function = "check_xss_string"))]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub project_path: Option<String>,
+    pub take_id: String,
 
-    #[serde(rename = "autoZoom")]
+    /// Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "source")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub source: String,
+
+    #[serde(rename = "settings")]
     #[validate(nested)]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub auto_zoom: Option<models::AutoZoomSettings>,
+    pub settings: models::VisualActivitySettings,
 
-    #[serde(rename = "backgroundFraming")]
+    #[serde(rename = "fps")]
+    pub fps: f64,
+
+    #[serde(rename = "durationSeconds")]
+    pub duration_seconds: f64,
+
+    #[serde(rename = "frameDifferences")]
+    pub frame_differences: Vec<f64>,
+
+    #[serde(rename = "sceneChanges")]
     #[validate(nested)]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub background_framing: Option<models::BackgroundFramingSettings>,
+    pub scene_changes: Vec<models::SceneChange>,
 
-    #[serde(rename = "timeline")]
+    #[serde(rename = "chapters")]
     #[validate(nested)]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub timeline: Option<models::ExportRunPayloadTimeline>,
+    pub chapters: Vec<models::ChapterSuggestion>,
+
+    #[serde(rename = "thumbnailSeconds")]
+    pub thumbnail_seconds: f64,
 }
 
-impl ProjectSavePayload {
+impl VisualActivityResult {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new() -> ProjectSavePayload {
-        ProjectSavePayload {
-            project_path: None,
-            auto_zoom: None,
-            background_framing: None,
-            timeline: None,
+    pub fn new(
+        version: f64,
+        take_id: String,
+        source: String,
+        settings: models::VisualActivitySettings,
+        fps: f64,
+        duration_seconds: f64,
+        frame_differences: Vec<f64>,
+        scene_changes: Vec<models::SceneChange>,
+        chapters: Vec<models::ChapterSuggestion>,
+        thumbnail_seconds: f64,
+    ) -> VisualActivityResult {
+        VisualActivityResult {
+            version,
+            take_id,
+            source,
+            settings,
+            fps,
+            duration_seconds,
+            frame_differences,
+            scene_changes,
+            chapters,
+            thumbnail_seconds,
         }
     }
 }
 
-/// Converts the ProjectSavePayload value to the Query Parameters representation (style=form, explode=false)
+/// Converts the VisualActivityResult value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for ProjectSavePayload {
+impl std::fmt::Display for VisualActivityResult {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
-            self.project_path.as_ref().map(|project_path| {
-                ["projectPath".to_string(), project_path.to_string()].join(",")
-            }),
-            // Skipping autoZoom in query parameter serialization
-
-            // Skipping backgroundFraming in query parameter serialization
+            Some("version".to_string()),
+            Some(self.version.to_string()),
+            Some("takeId".to_string()),
+            Some(self.take_id.to_string()),
+            Some("source".to_string()),
+            Some(self.source.to_string()),
+            // Skipping settings in query parameter serialization
+            Some("fps".to_string()),
+            Some(self.fps.to_string()),
+            Some("durationSeconds".to_string()),
+            Some(self.duration_seconds.to_string()),
+            Some("frameDifferences".to_string()),
+            Some(
+                self.frame_differences
+                    .iter()
+                    .map(|x| x.to_string())
+                    .collect::<Vec<_>>()
+                    .join(","),
+            ),
+            // Skipping sceneChanges in query parameter serialization
 
-            // Skipping timeline in query parameter serialization
+            // Skipping chapters in query parameter serialization
+            Some("thumbnailSeconds".to_string()),
+            Some(self.thumbnail_seconds.to_string()),
         ];
 
         write!(
@@ -9388,10 +19655,10 @@ impl std::fmt::Display for ProjectSavePayload {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a ProjectSavePayload value
+/// Converts Query Parameters representation (style=form, explode=false) to a VisualActivityResult value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for ProjectSavePayload {
+impl std::str::FromStr for VisualActivityResult {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -9399,10 +19666,16 @@ impl std::str::FromStr for ProjectSavePayload {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub project_path: Vec<String>,
-            pub auto_zoom: Vec<models::AutoZoomSettings>,
-            pub background_framing: Vec<models::BackgroundFramingSettings>,
-            pub timeline: Vec<models::ExportRunPayloadTimeline>,
+            pub version: Vec<f64>,
+            pub take_id: Vec<String>,
+            pub source: Vec<String>,
+            pub settings: Vec<models::VisualActivitySettings>,
+            pub fps: Vec<f64>,
+            pub duration_seconds: Vec<f64>,
+            pub frame_differences: Vec<Vec<f64>>,
+            pub scene_changes: Vec<Vec<models::SceneChange>>,
+            pub chapters: Vec<Vec<models::ChapterSuggestion>>,
+            pub thumbnail_seconds: Vec<f64>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -9416,7 +19689,7 @@ impl std::str::FromStr for ProjectSavePayload {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing ProjectSavePayload".to_string(),
+                        "Missing value while parsing VisualActivityResult".to_string(),
                     );
                 }
             };
@@ -9425,29 +19698,23 @@ impl std::str::FromStr for ProjectSavePayload {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "projectPath" => intermediate_rep.project_path.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
+                    "version" => intermediate_rep.version.push(<f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
                     #[allow(clippy::redundant_clone)]
-                    "autoZoom" => intermediate_rep.auto_zoom.push(
-                        <models::AutoZoomSettings as std::str::FromStr>::from_str(val)
-                            .map_err(|x| x.to_string())?,
-                    ),
+                    "takeId" => intermediate_rep.take_id.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
                     #[allow(clippy::redundant_clone)]
-                    "backgroundFraming" => intermediate_rep.background_framing.push(
-                        <models::BackgroundFramingSettings as std::str::FromStr>::from_str(val)
-                            .map_err(|x| x.to_string())?,
-                    ),
+                    "source" => intermediate_rep.source.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
                     #[allow(clippy::redundant_clone)]
-                    "timeline" => intermediate_rep.timeline.push(
-                        <models::ExportRunPayloadTimeline as std::str::FromStr>::from_str(val)
-                            .map_err(|x| x.to_string())?,
-                    ),
-                    _ => {
-                        return std::result::Result::Err(
-                            "Unexpected key while parsing ProjectSavePayload".to_string(),
-                        );
-                    }
+                    "settings" => intermediate_rep.settings.push(<models::VisualActivitySettings as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "fps" => intermediate_rep.fps.push(<f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "durationSeconds" => intermediate_rep.duration_seconds.push(<f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    "frameDifferences" => return std::result::Result::Err("Parsing a container in this style is not supported in VisualActivityResult".to_string()),
+                    "sceneChanges" => return std::result::Result::Err("Parsing a container in this style is not supported in VisualActivityResult".to_string()),
+                    "chapters" => return std::result::Result::Err("Parsing a container in this style is not supported in VisualActivityResult".to_string()),
+                    #[allow(clippy::redundant_clone)]
+                    "thumbnailSeconds" => intermediate_rep.thumbnail_seconds.push(<f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing VisualActivityResult".to_string())
                 }
             }
 
@@ -9456,150 +19723,133 @@ impl std::str::FromStr for ProjectSavePayload {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(ProjectSavePayload {
-            project_path: intermediate_rep.project_path.into_iter().next(),
-            auto_zoom: intermediate_rep.auto_zoom.into_iter().next(),
-            background_framing: intermediate_rep.background_framing.into_iter().next(),
-            timeline: intermediate_rep.timeline.into_iter().next(),
+        std::result::Result::Ok(VisualActivityResult {
+            version: intermediate_rep
+                .version
+                .into_iter()
+                .next()
+                .ok_or_else(|| "version missing in VisualActivityResult".to_string())?,
+            take_id: intermediate_rep
+                .take_id
+                .into_iter()
+                .next()
+                .ok_or_else(|| "takeId missing in VisualActivityResult".to_string())?,
+            source: intermediate_rep
+                .source
+                .into_iter()
+                .next()
+                .ok_or_else(|| "source missing in VisualActivityResult".to_string())?,
+            settings: intermediate_rep
+                .settings
+                .into_iter()
+                .next()
+                .ok_or_else(|| "settings missing in VisualActivityResult".to_string())?,
+            fps: intermediate_rep
+                .fps
+                .into_iter()
+                .next()
+                .ok_or_else(|| "fps missing in VisualActivityResult".to_string())?,
+            duration_seconds: intermediate_rep
+                .duration_seconds
+                .into_iter()
+                .next()
+                .ok_or_else(|| "durationSeconds missing in VisualActivityResult".to_string())?,
+            frame_differences: intermediate_rep
+                .frame_differences
+                .into_iter()
+                .next()
+                .ok_or_else(|| "frameDifferences missing in VisualActivityResult".to_string())?,
+            scene_changes: intermediate_rep
+                .scene_changes
+                .into_iter()
+                .next()
+                .ok_or_else(|| "sceneChanges missing in VisualActivityResult".to_string())?,
+            chapters: intermediate_rep
+                .chapters
+                .into_iter()
+                .next()
+                .ok_or_else(|| "chapters missing in VisualActivityResult".to_string())?,
+            thumbnail_seconds: intermediate_rep
+                .thumbnail_seconds
+                .into_iter()
+                .next()
+                .ok_or_else(|| "thumbnailSeconds missing in VisualActivityResult".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<ProjectSavePayload> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<VisualActivityResult> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<ProjectSavePayload>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<VisualActivityResult>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<ProjectSavePayload>,
+        hdr_value: header::IntoHeaderValue<VisualActivityResult>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                r#"Invalid header value for ProjectSavePayload - value: {hdr_value} is invalid {e}"#
+                r#"Invalid header value for VisualActivityResult - value: {hdr_value} is invalid {e}"#
             )),
         }
     }
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<ProjectSavePayload> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<VisualActivityResult> {
     type Error = String;
 
-    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
-        match hdr_value.to_str() {
-            std::result::Result::Ok(value) => {
-                match <ProjectSavePayload as std::str::FromStr>::from_str(value) {
-                    std::result::Result::Ok(value) => {
-                        std::result::Result::Ok(header::IntoHeaderValue(value))
-                    }
-                    std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        r#"Unable to convert header value '{value}' into ProjectSavePayload - {err}"#
-                    )),
-                }
-            }
-            std::result::Result::Err(e) => std::result::Result::Err(format!(
-                r#"Unable to convert header: {hdr_value:?} to string: {e}"#
-            )),
-        }
-    }
-}
-
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
-#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct ProjectState {
-    #[serde(rename = "projectPath")]
-    #[validate(custom(function = "check_xss_string"))]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub project_path: Option<String>,
-
-    #[serde(rename = "recordingURL")]
-    #[validate(custom(function = "check_xss_string"))]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub recording_url: Option<String>,
-
-    #[serde(rename = "eventsURL")]
-    #[validate(custom(function = "check_xss_string"))]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub events_url: Option<String>,
-
-    #[serde(rename = "lastRecordingTelemetry")]
-    #[validate(nested)]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_recording_telemetry: Option<models::CaptureTelemetry>,
-
-    #[serde(rename = "autoZoom")]
-    #[validate(nested)]
-    pub auto_zoom: models::AutoZoomSettings,
-
-    #[serde(rename = "backgroundFraming")]
-    #[validate(nested)]
-    pub background_framing: models::BackgroundFramingSettings,
-
-    #[serde(rename = "timeline")]
-    #[validate(nested)]
-    pub timeline: models::ExportRunPayloadTimeline,
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <VisualActivityResult as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        r#"Unable to convert header value '{value}' into VisualActivityResult - {err}"#
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                r#"Unable to convert header: {hdr_value:?} to string: {e}"#
+            )),
+        }
+    }
+}
 
-    #[serde(rename = "captureMetadata")]
-    #[validate(nested)]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub capture_metadata: Option<models::CaptureStatusResultCaptureMetadata>,
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct VisualActivitySettings {
+    #[serde(rename = "sceneThreshold")]
+    pub scene_threshold: f64,
 
-    #[serde(rename = "agentAnalysis")]
-    #[validate(nested)]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub agent_analysis: Option<models::ProjectAgentAnalysisSummary>,
+    #[serde(rename = "minSceneSeconds")]
+    pub min_scene_seconds: f64,
 }
 
-impl ProjectState {
+impl VisualActivitySettings {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new(
-        auto_zoom: models::AutoZoomSettings,
-        background_framing: models::BackgroundFramingSettings,
-        timeline: models::ExportRunPayloadTimeline,
-    ) -> ProjectState {
-        ProjectState {
-            project_path: None,
-            recording_url: None,
-            events_url: None,
-            last_recording_telemetry: None,
-            auto_zoom,
-            background_framing,
-            timeline,
-            capture_metadata: None,
-            agent_analysis: None,
+    pub fn new(scene_threshold: f64, min_scene_seconds: f64) -> VisualActivitySettings {
+        VisualActivitySettings {
+            scene_threshold,
+            min_scene_seconds,
         }
     }
 }
 
-/// Converts the ProjectState value to the Query Parameters representation (style=form, explode=false)
+/// Converts the VisualActivitySettings value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for ProjectState {
+impl std::fmt::Display for VisualActivitySettings {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
-            self.project_path.as_ref().map(|project_path| {
-                ["projectPath".to_string(), project_path.to_string()].join(",")
-            }),
-            self.recording_url.as_ref().map(|recording_url| {
-                ["recordingURL".to_string(), recording_url.to_string()].join(",")
-            }),
-            self.events_url
-                .as_ref()
-                .map(|events_url| ["eventsURL".to_string(), events_url.to_string()].join(",")),
-            // Skipping lastRecordingTelemetry in query parameter serialization
-
-            // Skipping autoZoom in query parameter serialization
-
-            // Skipping backgroundFraming in query parameter serialization
-
-            // Skipping timeline in query parameter serialization
-
-            // Skipping captureMetadata in query parameter serialization
-
-            // Skipping agentAnalysis in query parameter serialization
+            Some("sceneThreshold".to_string()),
+            Some(self.scene_threshold.to_string()),
+            Some("minSceneSeconds".to_string()),
+            Some(self.min_scene_seconds.to_string()),
         ];
 
         write!(
@@ -9610,10 +19860,10 @@ impl std::fmt::Display for ProjectState {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a ProjectState value
+/// Converts Query Parameters representation (style=form, explode=false) to a VisualActivitySettings value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for ProjectState {
+impl std::str::FromStr for VisualActivitySettings {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -9621,15 +19871,8 @@ impl std::str::FromStr for ProjectState {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub project_path: Vec<String>,
-            pub recording_url: Vec<String>,
-            pub events_url: Vec<String>,
-            pub last_recording_telemetry: Vec<models::CaptureTelemetry>,
-            pub auto_zoom: Vec<models::AutoZoomSettings>,
-            pub background_framing: Vec<models::BackgroundFramingSettings>,
-            pub timeline: Vec<models::ExportRunPayloadTimeline>,
-            pub capture_metadata: Vec<models::CaptureStatusResultCaptureMetadata>,
-            pub agent_analysis: Vec<models::ProjectAgentAnalysisSummary>,
+            pub scene_threshold: Vec<f64>,
+            pub min_scene_seconds: Vec<f64>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -9643,7 +19886,7 @@ impl std::str::FromStr for ProjectState {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing ProjectState".to_string(),
+                        "Missing value while parsing VisualActivitySettings".to_string(),
                     );
                 }
             };
@@ -9652,24 +19895,18 @@ impl std::str::FromStr for ProjectState {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "projectPath" => intermediate_rep.project_path.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
-                    #[allow(clippy::redundant_clone)]
-                    "recordingURL" => intermediate_rep.recording_url.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
-                    #[allow(clippy::redundant_clone)]
-                    "eventsURL" => intermediate_rep.events_url.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
-                    #[allow(clippy::redundant_clone)]
-                    "lastRecordingTelemetry" => intermediate_rep.last_recording_telemetry.push(<models::CaptureTelemetry as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
-                    #[allow(clippy::redundant_clone)]
-                    "autoZoom" => intermediate_rep.auto_zoom.push(<models::AutoZoomSettings as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
-                    #[allow(clippy::redundant_clone)]
-                    "backgroundFraming" => intermediate_rep.background_framing.push(<models::BackgroundFramingSettings as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
-                    #[allow(clippy::redundant_clone)]
-                    "timeline" => intermediate_rep.timeline.push(<models::ExportRunPayloadTimeline as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
-                    #[allow(clippy::redundant_clone)]
-                    "captureMetadata" => intermediate_rep.capture_metadata.push(<models::CaptureStatusResultCaptureMetadata as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    "sceneThreshold" => intermediate_rep.scene_threshold.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
                     #[allow(clippy::redundant_clone)]
-                    "agentAnalysis" => intermediate_rep.agent_analysis.push(<models::ProjectAgentAnalysisSummary as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
-                    _ => return std::result::Result::Err("Unexpected key while parsing ProjectState".to_string())
+                    "minSceneSeconds" => intermediate_rep.min_scene_seconds.push(
+                        <f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing VisualActivitySettings".to_string(),
+                        );
+                    }
                 }
             }
 
@@ -9678,64 +19915,53 @@ impl std::str::FromStr for ProjectState {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(ProjectState {
-            project_path: intermediate_rep.project_path.into_iter().next(),
-            recording_url: intermediate_rep.recording_url.into_iter().next(),
-            events_url: intermediate_rep.events_url.into_iter().next(),
-            last_recording_telemetry: intermediate_rep.last_recording_telemetry.into_iter().next(),
-            auto_zoom: intermediate_rep
-                .auto_zoom
-                .into_iter()
-                .next()
-                .ok_or_else(|| "autoZoom missing in ProjectState".to_string())?,
-            background_framing: intermediate_rep
-                .background_framing
+        std::result::Result::Ok(VisualActivitySettings {
+            scene_threshold: intermediate_rep
+                .scene_threshold
                 .into_iter()
                 .next()
-                .ok_or_else(|| "backgroundFraming missing in ProjectState".to_string())?,
-            timeline: intermediate_rep
-                .timeline
+                .ok_or_else(|| "sceneThreshold missing in VisualActivitySettings".to_string())?,
+            min_scene_seconds: intermediate_rep
+                .min_scene_seconds
                 .into_iter()
                 .next()
-                .ok_or_else(|| "timeline missing in ProjectState".to_string())?,
-            capture_metadata: intermediate_rep.capture_metadata.into_iter().next(),
-            agent_analysis: intermediate_rep.agent_analysis.into_iter().next(),
+                .ok_or_else(|| "minSceneSeconds missing in VisualActivitySettings".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<ProjectState> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<VisualActivitySettings> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<ProjectState>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<VisualActivitySettings>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<ProjectState>,
+        hdr_value: header::IntoHeaderValue<VisualActivitySettings>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                r#"Invalid header value for ProjectState - value: {hdr_value} is invalid {e}"#
+                r#"Invalid header value for VisualActivitySettings - value: {hdr_value} is invalid {e}"#
             )),
         }
     }
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<ProjectState> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<VisualActivitySettings> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <ProjectState as std::str::FromStr>::from_str(value) {
+                match <VisualActivitySettings as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        r#"Unable to convert header value '{value}' into ProjectState - {err}"#
+                        r#"Unable to convert header value '{value}' into VisualActivitySettings - {err}"#
                     )),
                 }
             }
@@ -9748,34 +19974,49 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<ProjectState
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct RecordingStartPayload {
-    #[serde(rename = "trackInputEvents")]
+pub struct WindowExclusionRule {
+    #[serde(rename = "windowId")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub track_input_events: Option<bool>,
+    pub window_id: Option<i32>,
+
+    #[serde(rename = "titlePattern")]
+    #[validate(custom(function = "check_xss_string"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title_pattern: Option<String>,
+
+    #[serde(rename = "wmClass")]
+    #[validate(custom(function = "check_xss_string"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wm_class: Option<String>,
 }
 
-impl RecordingStartPayload {
+impl WindowExclusionRule {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new() -> RecordingStartPayload {
-        RecordingStartPayload {
-            track_input_events: None,
+    pub fn new() -> WindowExclusionRule {
+        WindowExclusionRule {
+            window_id: None,
+            title_pattern: None,
+            wm_class: None,
         }
     }
 }
 
-/// Converts the RecordingStartPayload value to the Query Parameters representation (style=form, explode=false)
+/// Converts the WindowExclusionRule value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for RecordingStartPayload {
+impl std::fmt::Display for WindowExclusionRule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let params: Vec<Option<String>> =
-            vec![self.track_input_events.as_ref().map(|track_input_events| {
-                [
-                    "trackInputEvents".to_string(),
-                    track_input_events.to_string(),
-                ]
-                .join(",")
-            })];
+        let params: Vec<Option<String>> = vec![
+            self.window_id
+                .as_ref()
+                .map(|window_id| ["windowId".to_string(), window_id.to_string()].join(",")),
+            self.title_pattern.as_ref().map(|title_pattern| {
+                ["titlePattern".to_string(), title_pattern.to_string()].join(",")
+            }),
+            self.wm_class
+                .as_ref()
+                .map(|wm_class| ["wmClass".to_string(), wm_class.to_string()].join(",")),
+        ];
 
         write!(
             f,
@@ -9785,10 +20026,10 @@ impl std::fmt::Display for RecordingStartPayload {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a RecordingStartPayload value
+/// Converts Query Parameters representation (style=form, explode=false) to a WindowExclusionRule value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for RecordingStartPayload {
+impl std::str::FromStr for WindowExclusionRule {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -9796,7 +20037,9 @@ impl std::str::FromStr for RecordingStartPayload {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub track_input_events: Vec<bool>,
+            pub window_id: Vec<i32>,
+            pub title_pattern: Vec<String>,
+            pub wm_class: Vec<String>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -9810,7 +20053,7 @@ impl std::str::FromStr for RecordingStartPayload {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing RecordingStartPayload".to_string(),
+                        "Missing value while parsing WindowExclusionRule".to_string(),
                     );
                 }
             };
@@ -9819,12 +20062,20 @@ impl std::str::FromStr for RecordingStartPayload {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "trackInputEvents" => intermediate_rep.track_input_events.push(
-                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "windowId" => intermediate_rep.window_id.push(
+                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "titlePattern" => intermediate_rep.title_pattern.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "wmClass" => intermediate_rep.wm_class.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing RecordingStartPayload".to_string(),
+                            "Unexpected key while parsing WindowExclusionRule".to_string(),
                         );
                     }
                 }
@@ -9835,44 +20086,46 @@ impl std::str::FromStr for RecordingStartPayload {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(RecordingStartPayload {
-            track_input_events: intermediate_rep.track_input_events.into_iter().next(),
+        std::result::Result::Ok(WindowExclusionRule {
+            window_id: intermediate_rep.window_id.into_iter().next(),
+            title_pattern: intermediate_rep.title_pattern.into_iter().next(),
+            wm_class: intermediate_rep.wm_class.into_iter().next(),
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<RecordingStartPayload> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<WindowExclusionRule> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<RecordingStartPayload>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<WindowExclusionRule>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<RecordingStartPayload>,
+        hdr_value: header::IntoHeaderValue<WindowExclusionRule>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                r#"Invalid header value for RecordingStartPayload - value: {hdr_value} is invalid {e}"#
+                r#"Invalid header value for WindowExclusionRule - value: {hdr_value} is invalid {e}"#
             )),
         }
     }
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<RecordingStartPayload> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<WindowExclusionRule> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <RecordingStartPayload as std::str::FromStr>::from_str(value) {
+                match <WindowExclusionRule as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        r#"Unable to convert header value '{value}' into RecordingStartPayload - {err}"#
+                        r#"Unable to convert header value '{value}' into WindowExclusionRule - {err}"#
                     )),
                 }
             }
@@ -9885,36 +20138,55 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<RecordingSta
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct SourcesResult {
-    #[serde(rename = "displays")]
-    #[validate(nested)]
-    pub displays: Vec<models::DisplaySource>,
+pub struct WindowExclusionSettings {
+    /// Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "version")]
+    pub version: f64,
 
-    #[serde(rename = "windows")]
+    #[serde(rename = "rules")]
     #[validate(nested)]
-    pub windows: Vec<models::WindowSource>,
+    pub rules: Vec<models::WindowExclusionRule>,
+
+    /// Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "maskStyle")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub mask_style: String,
+
+    #[serde(rename = "fillColor")]
+    #[validate(custom(function = "check_xss_string"))]
+    pub fill_color: String,
 }
 
-impl SourcesResult {
+impl WindowExclusionSettings {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
     pub fn new(
-        displays: Vec<models::DisplaySource>,
-        windows: Vec<models::WindowSource>,
-    ) -> SourcesResult {
-        SourcesResult { displays, windows }
+        version: f64,
+        rules: Vec<models::WindowExclusionRule>,
+        mask_style: String,
+        fill_color: String,
+    ) -> WindowExclusionSettings {
+        WindowExclusionSettings {
+            version,
+            rules,
+            mask_style,
+            fill_color,
+        }
     }
 }
 
-/// Converts the SourcesResult value to the Query Parameters representation (style=form, explode=false)
+/// Converts the WindowExclusionSettings value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for SourcesResult {
+impl std::fmt::Display for WindowExclusionSettings {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
-            // Skipping displays in query parameter serialization
-
-            // Skipping windows in query parameter serialization
-
+            Some("version".to_string()),
+            Some(self.version.to_string()),
+            // Skipping rules in query parameter serialization
+            Some("maskStyle".to_string()),
+            Some(self.mask_style.to_string()),
+            Some("fillColor".to_string()),
+            Some(self.fill_color.to_string()),
         ];
 
         write!(
@@ -9925,10 +20197,10 @@ impl std::fmt::Display for SourcesResult {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a SourcesResult value
+/// Converts Query Parameters representation (style=form, explode=false) to a WindowExclusionSettings value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for SourcesResult {
+impl std::str::FromStr for WindowExclusionSettings {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -9936,8 +20208,10 @@ impl std::str::FromStr for SourcesResult {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub displays: Vec<Vec<models::DisplaySource>>,
-            pub windows: Vec<Vec<models::WindowSource>>,
+            pub version: Vec<f64>,
+            pub rules: Vec<Vec<models::WindowExclusionRule>>,
+            pub mask_style: Vec<String>,
+            pub fill_color: Vec<String>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -9951,7 +20225,7 @@ impl std::str::FromStr for SourcesResult {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing SourcesResult".to_string(),
+                        "Missing value while parsing WindowExclusionSettings".to_string(),
                     );
                 }
             };
@@ -9959,23 +20233,14 @@ impl std::str::FromStr for SourcesResult {
             if let Some(key) = key_result {
                 #[allow(clippy::match_single_binding)]
                 match key {
-                    "displays" => {
-                        return std::result::Result::Err(
-                            "Parsing a container in this style is not supported in SourcesResult"
-                                .to_string(),
-                        );
-                    }
-                    "windows" => {
-                        return std::result::Result::Err(
-                            "Parsing a container in this style is not supported in SourcesResult"
-                                .to_string(),
-                        );
-                    }
-                    _ => {
-                        return std::result::Result::Err(
-                            "Unexpected key while parsing SourcesResult".to_string(),
-                        );
-                    }
+                    #[allow(clippy::redundant_clone)]
+                    "version" => intermediate_rep.version.push(<f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    "rules" => return std::result::Result::Err("Parsing a container in this style is not supported in WindowExclusionSettings".to_string()),
+                    #[allow(clippy::redundant_clone)]
+                    "maskStyle" => intermediate_rep.mask_style.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "fillColor" => intermediate_rep.fill_color.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing WindowExclusionSettings".to_string())
                 }
             }
 
@@ -9984,53 +20249,63 @@ impl std::str::FromStr for SourcesResult {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(SourcesResult {
-            displays: intermediate_rep
-                .displays
+        std::result::Result::Ok(WindowExclusionSettings {
+            version: intermediate_rep
+                .version
                 .into_iter()
                 .next()
-                .ok_or_else(|| "displays missing in SourcesResult".to_string())?,
-            windows: intermediate_rep
-                .windows
+                .ok_or_else(|| "version missing in WindowExclusionSettings".to_string())?,
+            rules: intermediate_rep
+                .rules
                 .into_iter()
                 .next()
-                .ok_or_else(|| "windows missing in SourcesResult".to_string())?,
+                .ok_or_else(|| "rules missing in WindowExclusionSettings".to_string())?,
+            mask_style: intermediate_rep
+                .mask_style
+                .into_iter()
+                .next()
+                .ok_or_else(|| "maskStyle missing in WindowExclusionSettings".to_string())?,
+            fill_color: intermediate_rep
+                .fill_color
+                .into_iter()
+                .next()
+                .ok_or_else(|| "fillColor missing in WindowExclusionSettings".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<SourcesResult> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<WindowExclusionSettings> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<SourcesResult>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<WindowExclusionSettings>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<SourcesResult>,
+        hdr_value: header::IntoHeaderValue<WindowExclusionSettings>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                r#"Invalid header value for SourcesResult - value: {hdr_value} is invalid {e}"#
+                r#"Invalid header value for WindowExclusionSettings - value: {hdr_value} is invalid {e}"#
             )),
         }
     }
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<SourcesResult> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<WindowExclusionSettings> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <SourcesResult as std::str::FromStr>::from_str(value) {
+                match <WindowExclusionSettings as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        r#"Unable to convert header value '{value}' into SourcesResult - {err}"#
+                        r#"Unable to convert header value '{value}' into WindowExclusionSettings - {err}"#
                     )),
                 }
             }
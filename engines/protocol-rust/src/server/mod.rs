@@ -25,13 +25,18 @@ pub fn new<I, A, E, C>(api_impl: I) -> Router
 where
     I: AsRef<A> + Clone + Send + Sync + 'static,
     A: apis::agent::Agent<E, Claims = C>
+        + apis::analysis::Analysis<E, Claims = C>
+        + apis::camera_keyframes::CameraKeyframes<E, Claims = C>
         + apis::capture::Capture<E, Claims = C>
         + apis::export::Export<E, Claims = C>
+        + apis::library::Library<E, Claims = C>
         + apis::permissions::Permissions<E, Claims = C>
         + apis::project::Project<E, Claims = C>
         + apis::recording::Recording<E, Claims = C>
         + apis::sources::Sources<E, Claims = C>
         + apis::system::System<E, Claims = C>
+        + apis::takes::Takes<E, Claims = C>
+        + apis::timeline::Timeline<E, Claims = C>
         + apis::ApiAuthBasic<Claims = C>
         + Send
         + Sync
@@ -54,10 +59,30 @@ where
             "/v1/agent/runs/{job_id}/apply",
             post(agent_agent_apply::<I, A, E, C>),
         )
+        .route(
+            "/v1/analysis/visual-activity",
+            post(analysis_analysis_visual_activity::<I, A, E, C>),
+        )
+        .route(
+            "/v1/camera-keyframes",
+            get(camera_keyframes_camera_keyframes_list::<I, A, E, C>),
+        )
+        .route(
+            "/v1/camera-keyframes/edit",
+            post(camera_keyframes_camera_keyframes_edit::<I, A, E, C>),
+        )
+        .route(
+            "/v1/camera-keyframes/replan",
+            post(camera_keyframes_camera_keyframes_replan::<I, A, E, C>),
+        )
         .route(
             "/v1/capture/preview-frame",
             get(capture_capture_preview_frame::<I, A, E, C>),
         )
+        .route(
+            "/v1/capture/start-camera",
+            post(capture_capture_start_camera::<I, A, E, C>),
+        )
         .route(
             "/v1/capture/start-current-window",
             post(capture_capture_start_current_window::<I, A, E, C>),
@@ -66,6 +91,10 @@ where
             "/v1/capture/start-display",
             post(capture_capture_start_display::<I, A, E, C>),
         )
+        .route(
+            "/v1/capture/start-region",
+            post(capture_capture_start_region::<I, A, E, C>),
+        )
         .route(
             "/v1/capture/start-window",
             post(capture_capture_start_window::<I, A, E, C>),
@@ -75,6 +104,10 @@ where
             get(capture_capture_status::<I, A, E, C>),
         )
         .route("/v1/capture/stop", post(capture_capture_stop::<I, A, E, C>))
+        .route(
+            "/v1/capture/stop-camera",
+            post(capture_capture_stop_camera::<I, A, E, C>),
+        )
         .route(
             "/v1/engine/capabilities",
             get(system_engine_capabilities::<I, A, E, C>),
@@ -85,7 +118,16 @@ where
             "/v1/exports/from-cut-plan",
             post(export_export_run_cut_plan::<I, A, E, C>),
         )
+        .route("/v1/exports/guide", post(export_export_guide::<I, A, E, C>))
         .route("/v1/exports/{job_id}", get(export_export_get::<I, A, E, C>))
+        .route(
+            "/v1/library/defaults",
+            get(library_library_defaults::<I, A, E, C>),
+        )
+        .route(
+            "/v1/library/defaults/save",
+            post(library_library_save_defaults::<I, A, E, C>),
+        )
         .route(
             "/v1/permissions",
             get(permissions_permissions_get::<I, A, E, C>),
@@ -116,6 +158,22 @@ where
             get(project_project_recents::<I, A, E, C>),
         )
         .route("/v1/project/save", post(project_project_save::<I, A, E, C>))
+        .route(
+            "/v1/recording/markers",
+            post(recording_recording_add_marker::<I, A, E, C>),
+        )
+        .route(
+            "/v1/recording/pause",
+            post(recording_recording_pause::<I, A, E, C>),
+        )
+        .route(
+            "/v1/recording/recover",
+            post(recording_recording_recover::<I, A, E, C>),
+        )
+        .route(
+            "/v1/recording/resume",
+            post(recording_recording_resume::<I, A, E, C>),
+        )
         .route(
             "/v1/recording/start",
             post(recording_recording_start::<I, A, E, C>),
@@ -126,6 +184,27 @@ where
         )
         .route("/v1/sources", get(sources_sources_list::<I, A, E, C>))
         .route("/v1/system/ping", get(system_system_ping::<I, A, E, C>))
+        .route("/v1/takes", get(takes_takes_list::<I, A, E, C>))
+        .route(
+            "/v1/takes/{take_id}/activate",
+            post(takes_takes_activate::<I, A, E, C>),
+        )
+        .route(
+            "/v1/takes/{take_id}/delete",
+            post(takes_takes_delete::<I, A, E, C>),
+        )
+        .route(
+            "/v1/takes/{take_id}/rename",
+            post(takes_takes_rename::<I, A, E, C>),
+        )
+        .route(
+            "/v1/timeline/apply-cuts",
+            post(timeline_timeline_apply_cuts::<I, A, E, C>),
+        )
+        .route(
+            "/v1/timeline/suggest-cuts",
+            post(timeline_timeline_suggest_cuts::<I, A, E, C>),
+        )
         .with_state(api_impl)
 }
 
@@ -948,22 +1027,35 @@ where
     })
 }
 
+#[derive(validator::Validate)]
+#[allow(dead_code)]
+struct AnalysisAnalysisVisualActivityBodyValidator<'a> {
+    #[validate(nested)]
+    body: &'a models::AnalysisVisualActivityPayload,
+}
+
 #[tracing::instrument(skip_all)]
-fn capture_capture_preview_frame_validation() -> std::result::Result<(), ValidationErrors> {
-    Ok(())
+fn analysis_analysis_visual_activity_validation(
+    body: models::AnalysisVisualActivityPayload,
+) -> std::result::Result<(models::AnalysisVisualActivityPayload,), ValidationErrors> {
+    let b = AnalysisAnalysisVisualActivityBodyValidator { body: &body };
+    b.validate()?;
+
+    Ok((body,))
 }
-/// CaptureCapturePreviewFrame - GET /v1/capture/preview-frame
+/// AnalysisAnalysisVisualActivity - POST /v1/analysis/visual-activity
 #[tracing::instrument(skip_all)]
-async fn capture_capture_preview_frame<I, A, E, C>(
+async fn analysis_analysis_visual_activity<I, A, E, C>(
     method: Method,
     TypedHeader(host): TypedHeader<Host>,
     cookies: CookieJar,
     headers: HeaderMap,
     State(api_impl): State<I>,
+    Json(body): Json<models::AnalysisVisualActivityPayload>,
 ) -> Result<Response, StatusCode>
 where
     I: AsRef<A> + Send + Sync,
-    A: apis::capture::Capture<E, Claims = C> + apis::ApiAuthBasic<Claims = C> + Send + Sync,
+    A: apis::analysis::Analysis<E, Claims = C> + apis::ApiAuthBasic<Claims = C> + Send + Sync,
     E: std::fmt::Debug + Send + Sync + 'static,
 {
     // Authentication
@@ -978,11 +1070,11 @@ where
 
     #[allow(clippy::redundant_closure)]
     let validation =
-        tokio::task::spawn_blocking(move || capture_capture_preview_frame_validation())
+        tokio::task::spawn_blocking(move || analysis_analysis_visual_activity_validation(body))
             .await
             .unwrap();
 
-    let Ok(()) = validation else {
+    let Ok((body,)) = validation else {
         return Response::builder()
             .status(StatusCode::BAD_REQUEST)
             .body(Body::from(validation.unwrap_err().to_string()))
@@ -991,14 +1083,14 @@ where
 
     let result = api_impl
         .as_ref()
-        .capture_capture_preview_frame(&method, &host, &cookies, &claims)
+        .analysis_analysis_visual_activity(&method, &host, &cookies, &claims, &body)
         .await;
 
     let mut response = Response::builder();
 
     let resp = match result {
                                             Ok(rsp) => match rsp {
-                                                apis::capture::CaptureCapturePreviewFrameResponse::Status200_CapturePreviewFrameResult
+                                                apis::analysis::AnalysisAnalysisVisualActivityResponse::Status200_VisualActivityResult
                                                     (body)
                                                 => {
                                                   let mut response = response.status(200);
@@ -1016,7 +1108,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::capture::CaptureCapturePreviewFrameResponse::Status400_EngineBadRequestErrorResponseBody
+                                                apis::analysis::AnalysisAnalysisVisualActivityResponse::Status400_EngineBadRequestErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(400);
@@ -1034,7 +1126,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::capture::CaptureCapturePreviewFrameResponse::Status401_EngineUnauthorizedErrorResponseBody
+                                                apis::analysis::AnalysisAnalysisVisualActivityResponse::Status401_EngineUnauthorizedErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(401);
@@ -1052,7 +1144,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::capture::CaptureCapturePreviewFrameResponse::Status403_EngineForbiddenErrorResponseBody
+                                                apis::analysis::AnalysisAnalysisVisualActivityResponse::Status403_EngineForbiddenErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(403);
@@ -1070,7 +1162,43 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::capture::CaptureCapturePreviewFrameResponse::Status500_EngineRuntimeErrorResponseBody
+                                                apis::analysis::AnalysisAnalysisVisualActivityResponse::Status409_EngineConflictErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(409);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::analysis::AnalysisAnalysisVisualActivityResponse::Status422_EngineUnprocessableErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(422);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::analysis::AnalysisAnalysisVisualActivityResponse::Status500_EngineRuntimeErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(500);
@@ -1104,33 +1232,36 @@ where
 
 #[derive(validator::Validate)]
 #[allow(dead_code)]
-struct CaptureCaptureStartCurrentWindowBodyValidator<'a> {
+struct CameraKeyframesCameraKeyframesEditBodyValidator<'a> {
     #[validate(nested)]
-    body: &'a models::CaptureStartCurrentWindowPayload,
+    body: &'a models::CameraKeyframesEditPayload,
 }
 
 #[tracing::instrument(skip_all)]
-fn capture_capture_start_current_window_validation(
-    body: models::CaptureStartCurrentWindowPayload,
-) -> std::result::Result<(models::CaptureStartCurrentWindowPayload,), ValidationErrors> {
-    let b = CaptureCaptureStartCurrentWindowBodyValidator { body: &body };
+fn camera_keyframes_camera_keyframes_edit_validation(
+    body: models::CameraKeyframesEditPayload,
+) -> std::result::Result<(models::CameraKeyframesEditPayload,), ValidationErrors> {
+    let b = CameraKeyframesCameraKeyframesEditBodyValidator { body: &body };
     b.validate()?;
 
     Ok((body,))
 }
-/// CaptureCaptureStartCurrentWindow - POST /v1/capture/start-current-window
+/// CameraKeyframesCameraKeyframesEdit - POST /v1/camera-keyframes/edit
 #[tracing::instrument(skip_all)]
-async fn capture_capture_start_current_window<I, A, E, C>(
+async fn camera_keyframes_camera_keyframes_edit<I, A, E, C>(
     method: Method,
     TypedHeader(host): TypedHeader<Host>,
     cookies: CookieJar,
     headers: HeaderMap,
     State(api_impl): State<I>,
-    Json(body): Json<models::CaptureStartCurrentWindowPayload>,
+    Json(body): Json<models::CameraKeyframesEditPayload>,
 ) -> Result<Response, StatusCode>
 where
     I: AsRef<A> + Send + Sync,
-    A: apis::capture::Capture<E, Claims = C> + apis::ApiAuthBasic<Claims = C> + Send + Sync,
+    A: apis::camera_keyframes::CameraKeyframes<E, Claims = C>
+        + apis::ApiAuthBasic<Claims = C>
+        + Send
+        + Sync,
     E: std::fmt::Debug + Send + Sync + 'static,
 {
     // Authentication
@@ -1144,10 +1275,11 @@ where
     };
 
     #[allow(clippy::redundant_closure)]
-    let validation =
-        tokio::task::spawn_blocking(move || capture_capture_start_current_window_validation(body))
-            .await
-            .unwrap();
+    let validation = tokio::task::spawn_blocking(move || {
+        camera_keyframes_camera_keyframes_edit_validation(body)
+    })
+    .await
+    .unwrap();
 
     let Ok((body,)) = validation else {
         return Response::builder()
@@ -1158,14 +1290,14 @@ where
 
     let result = api_impl
         .as_ref()
-        .capture_capture_start_current_window(&method, &host, &cookies, &claims, &body)
+        .camera_keyframes_camera_keyframes_edit(&method, &host, &cookies, &claims, &body)
         .await;
 
     let mut response = Response::builder();
 
     let resp = match result {
                                             Ok(rsp) => match rsp {
-                                                apis::capture::CaptureCaptureStartCurrentWindowResponse::Status200_CaptureStatusResult
+                                                apis::camera_keyframes::CameraKeyframesCameraKeyframesEditResponse::Status200_CameraKeyframesResult
                                                     (body)
                                                 => {
                                                   let mut response = response.status(200);
@@ -1183,7 +1315,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::capture::CaptureCaptureStartCurrentWindowResponse::Status400_EngineBadRequestErrorResponseBody
+                                                apis::camera_keyframes::CameraKeyframesCameraKeyframesEditResponse::Status400_EngineBadRequestErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(400);
@@ -1201,7 +1333,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::capture::CaptureCaptureStartCurrentWindowResponse::Status401_EngineUnauthorizedErrorResponseBody
+                                                apis::camera_keyframes::CameraKeyframesCameraKeyframesEditResponse::Status401_EngineUnauthorizedErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(401);
@@ -1219,7 +1351,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::capture::CaptureCaptureStartCurrentWindowResponse::Status403_EngineForbiddenErrorResponseBody
+                                                apis::camera_keyframes::CameraKeyframesCameraKeyframesEditResponse::Status403_EngineForbiddenErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(403);
@@ -1237,7 +1369,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::capture::CaptureCaptureStartCurrentWindowResponse::Status409_EngineConflictErrorResponseBody
+                                                apis::camera_keyframes::CameraKeyframesCameraKeyframesEditResponse::Status409_EngineConflictErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(409);
@@ -1255,7 +1387,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::capture::CaptureCaptureStartCurrentWindowResponse::Status422_EngineUnprocessableErrorResponseBody
+                                                apis::camera_keyframes::CameraKeyframesCameraKeyframesEditResponse::Status422_EngineUnprocessableErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(422);
@@ -1273,7 +1405,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::capture::CaptureCaptureStartCurrentWindowResponse::Status500_EngineRuntimeErrorResponseBody
+                                                apis::camera_keyframes::CameraKeyframesCameraKeyframesEditResponse::Status500_EngineRuntimeErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(500);
@@ -1305,35 +1437,31 @@ where
     })
 }
 
-#[derive(validator::Validate)]
-#[allow(dead_code)]
-struct CaptureCaptureStartDisplayBodyValidator<'a> {
-    #[validate(nested)]
-    body: &'a models::CaptureStartDisplayPayload,
-}
-
 #[tracing::instrument(skip_all)]
-fn capture_capture_start_display_validation(
-    body: models::CaptureStartDisplayPayload,
-) -> std::result::Result<(models::CaptureStartDisplayPayload,), ValidationErrors> {
-    let b = CaptureCaptureStartDisplayBodyValidator { body: &body };
-    b.validate()?;
+fn camera_keyframes_camera_keyframes_list_validation(
+    query_params: models::CameraKeyframesCameraKeyframesListQueryParams,
+) -> std::result::Result<(models::CameraKeyframesCameraKeyframesListQueryParams,), ValidationErrors>
+{
+    query_params.validate()?;
 
-    Ok((body,))
+    Ok((query_params,))
 }
-/// CaptureCaptureStartDisplay - POST /v1/capture/start-display
+/// CameraKeyframesCameraKeyframesList - GET /v1/camera-keyframes
 #[tracing::instrument(skip_all)]
-async fn capture_capture_start_display<I, A, E, C>(
+async fn camera_keyframes_camera_keyframes_list<I, A, E, C>(
     method: Method,
     TypedHeader(host): TypedHeader<Host>,
     cookies: CookieJar,
     headers: HeaderMap,
+    QueryExtra(query_params): QueryExtra<models::CameraKeyframesCameraKeyframesListQueryParams>,
     State(api_impl): State<I>,
-    Json(body): Json<models::CaptureStartDisplayPayload>,
 ) -> Result<Response, StatusCode>
 where
     I: AsRef<A> + Send + Sync,
-    A: apis::capture::Capture<E, Claims = C> + apis::ApiAuthBasic<Claims = C> + Send + Sync,
+    A: apis::camera_keyframes::CameraKeyframes<E, Claims = C>
+        + apis::ApiAuthBasic<Claims = C>
+        + Send
+        + Sync,
     E: std::fmt::Debug + Send + Sync + 'static,
 {
     // Authentication
@@ -1347,12 +1475,13 @@ where
     };
 
     #[allow(clippy::redundant_closure)]
-    let validation =
-        tokio::task::spawn_blocking(move || capture_capture_start_display_validation(body))
-            .await
-            .unwrap();
+    let validation = tokio::task::spawn_blocking(move || {
+        camera_keyframes_camera_keyframes_list_validation(query_params)
+    })
+    .await
+    .unwrap();
 
-    let Ok((body,)) = validation else {
+    let Ok((query_params,)) = validation else {
         return Response::builder()
             .status(StatusCode::BAD_REQUEST)
             .body(Body::from(validation.unwrap_err().to_string()))
@@ -1361,14 +1490,14 @@ where
 
     let result = api_impl
         .as_ref()
-        .capture_capture_start_display(&method, &host, &cookies, &claims, &body)
+        .camera_keyframes_camera_keyframes_list(&method, &host, &cookies, &claims, &query_params)
         .await;
 
     let mut response = Response::builder();
 
     let resp = match result {
                                             Ok(rsp) => match rsp {
-                                                apis::capture::CaptureCaptureStartDisplayResponse::Status200_CaptureStatusResult
+                                                apis::camera_keyframes::CameraKeyframesCameraKeyframesListResponse::Status200_CameraKeyframesResult
                                                     (body)
                                                 => {
                                                   let mut response = response.status(200);
@@ -1386,7 +1515,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::capture::CaptureCaptureStartDisplayResponse::Status400_EngineBadRequestErrorResponseBody
+                                                apis::camera_keyframes::CameraKeyframesCameraKeyframesListResponse::Status400_EngineBadRequestErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(400);
@@ -1404,7 +1533,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::capture::CaptureCaptureStartDisplayResponse::Status401_EngineUnauthorizedErrorResponseBody
+                                                apis::camera_keyframes::CameraKeyframesCameraKeyframesListResponse::Status401_EngineUnauthorizedErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(401);
@@ -1422,7 +1551,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::capture::CaptureCaptureStartDisplayResponse::Status403_EngineForbiddenErrorResponseBody
+                                                apis::camera_keyframes::CameraKeyframesCameraKeyframesListResponse::Status403_EngineForbiddenErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(403);
@@ -1440,43 +1569,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::capture::CaptureCaptureStartDisplayResponse::Status409_EngineConflictErrorResponseBody
-                                                    (body)
-                                                => {
-                                                  let mut response = response.status(409);
-                                                  {
-                                                    let mut response_headers = response.headers_mut().unwrap();
-                                                    response_headers.insert(
-                                                        CONTENT_TYPE,
-                                                        HeaderValue::from_static("application/json"));
-                                                  }
-
-                                                  let body_content =  tokio::task::spawn_blocking(move ||
-                                                      serde_json::to_vec(&body).map_err(|e| {
-                                                        error!(error = ?e);
-                                                        StatusCode::INTERNAL_SERVER_ERROR
-                                                      })).await.unwrap()?;
-                                                  response.body(Body::from(body_content))
-                                                },
-                                                apis::capture::CaptureCaptureStartDisplayResponse::Status422_EngineUnprocessableErrorResponseBody
-                                                    (body)
-                                                => {
-                                                  let mut response = response.status(422);
-                                                  {
-                                                    let mut response_headers = response.headers_mut().unwrap();
-                                                    response_headers.insert(
-                                                        CONTENT_TYPE,
-                                                        HeaderValue::from_static("application/json"));
-                                                  }
-
-                                                  let body_content =  tokio::task::spawn_blocking(move ||
-                                                      serde_json::to_vec(&body).map_err(|e| {
-                                                        error!(error = ?e);
-                                                        StatusCode::INTERNAL_SERVER_ERROR
-                                                      })).await.unwrap()?;
-                                                  response.body(Body::from(body_content))
-                                                },
-                                                apis::capture::CaptureCaptureStartDisplayResponse::Status500_EngineRuntimeErrorResponseBody
+                                                apis::camera_keyframes::CameraKeyframesCameraKeyframesListResponse::Status500_EngineRuntimeErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(500);
@@ -1508,35 +1601,26 @@ where
     })
 }
 
-#[derive(validator::Validate)]
-#[allow(dead_code)]
-struct CaptureCaptureStartWindowBodyValidator<'a> {
-    #[validate(nested)]
-    body: &'a models::CaptureStartWindowPayload,
-}
-
 #[tracing::instrument(skip_all)]
-fn capture_capture_start_window_validation(
-    body: models::CaptureStartWindowPayload,
-) -> std::result::Result<(models::CaptureStartWindowPayload,), ValidationErrors> {
-    let b = CaptureCaptureStartWindowBodyValidator { body: &body };
-    b.validate()?;
-
-    Ok((body,))
+fn camera_keyframes_camera_keyframes_replan_validation() -> std::result::Result<(), ValidationErrors>
+{
+    Ok(())
 }
-/// CaptureCaptureStartWindow - POST /v1/capture/start-window
+/// CameraKeyframesCameraKeyframesReplan - POST /v1/camera-keyframes/replan
 #[tracing::instrument(skip_all)]
-async fn capture_capture_start_window<I, A, E, C>(
+async fn camera_keyframes_camera_keyframes_replan<I, A, E, C>(
     method: Method,
     TypedHeader(host): TypedHeader<Host>,
     cookies: CookieJar,
     headers: HeaderMap,
     State(api_impl): State<I>,
-    Json(body): Json<models::CaptureStartWindowPayload>,
 ) -> Result<Response, StatusCode>
 where
     I: AsRef<A> + Send + Sync,
-    A: apis::capture::Capture<E, Claims = C> + apis::ApiAuthBasic<Claims = C> + Send + Sync,
+    A: apis::camera_keyframes::CameraKeyframes<E, Claims = C>
+        + apis::ApiAuthBasic<Claims = C>
+        + Send
+        + Sync,
     E: std::fmt::Debug + Send + Sync + 'static,
 {
     // Authentication
@@ -1551,11 +1635,11 @@ where
 
     #[allow(clippy::redundant_closure)]
     let validation =
-        tokio::task::spawn_blocking(move || capture_capture_start_window_validation(body))
+        tokio::task::spawn_blocking(move || camera_keyframes_camera_keyframes_replan_validation())
             .await
             .unwrap();
 
-    let Ok((body,)) = validation else {
+    let Ok(()) = validation else {
         return Response::builder()
             .status(StatusCode::BAD_REQUEST)
             .body(Body::from(validation.unwrap_err().to_string()))
@@ -1564,14 +1648,14 @@ where
 
     let result = api_impl
         .as_ref()
-        .capture_capture_start_window(&method, &host, &cookies, &claims, &body)
+        .camera_keyframes_camera_keyframes_replan(&method, &host, &cookies, &claims)
         .await;
 
     let mut response = Response::builder();
 
     let resp = match result {
                                             Ok(rsp) => match rsp {
-                                                apis::capture::CaptureCaptureStartWindowResponse::Status200_CaptureStatusResult
+                                                apis::camera_keyframes::CameraKeyframesCameraKeyframesReplanResponse::Status200_CameraKeyframesResult
                                                     (body)
                                                 => {
                                                   let mut response = response.status(200);
@@ -1589,7 +1673,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::capture::CaptureCaptureStartWindowResponse::Status400_EngineBadRequestErrorResponseBody
+                                                apis::camera_keyframes::CameraKeyframesCameraKeyframesReplanResponse::Status400_EngineBadRequestErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(400);
@@ -1607,7 +1691,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::capture::CaptureCaptureStartWindowResponse::Status401_EngineUnauthorizedErrorResponseBody
+                                                apis::camera_keyframes::CameraKeyframesCameraKeyframesReplanResponse::Status401_EngineUnauthorizedErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(401);
@@ -1625,7 +1709,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::capture::CaptureCaptureStartWindowResponse::Status403_EngineForbiddenErrorResponseBody
+                                                apis::camera_keyframes::CameraKeyframesCameraKeyframesReplanResponse::Status403_EngineForbiddenErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(403);
@@ -1643,7 +1727,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::capture::CaptureCaptureStartWindowResponse::Status409_EngineConflictErrorResponseBody
+                                                apis::camera_keyframes::CameraKeyframesCameraKeyframesReplanResponse::Status409_EngineConflictErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(409);
@@ -1661,7 +1745,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::capture::CaptureCaptureStartWindowResponse::Status422_EngineUnprocessableErrorResponseBody
+                                                apis::camera_keyframes::CameraKeyframesCameraKeyframesReplanResponse::Status422_EngineUnprocessableErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(422);
@@ -1679,7 +1763,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::capture::CaptureCaptureStartWindowResponse::Status500_EngineRuntimeErrorResponseBody
+                                                apis::camera_keyframes::CameraKeyframesCameraKeyframesReplanResponse::Status500_EngineRuntimeErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(500);
@@ -1712,12 +1796,12 @@ where
 }
 
 #[tracing::instrument(skip_all)]
-fn capture_capture_status_validation() -> std::result::Result<(), ValidationErrors> {
+fn capture_capture_preview_frame_validation() -> std::result::Result<(), ValidationErrors> {
     Ok(())
 }
-/// CaptureCaptureStatus - GET /v1/capture/status
+/// CaptureCapturePreviewFrame - GET /v1/capture/preview-frame
 #[tracing::instrument(skip_all)]
-async fn capture_capture_status<I, A, E, C>(
+async fn capture_capture_preview_frame<I, A, E, C>(
     method: Method,
     TypedHeader(host): TypedHeader<Host>,
     cookies: CookieJar,
@@ -1740,9 +1824,10 @@ where
     };
 
     #[allow(clippy::redundant_closure)]
-    let validation = tokio::task::spawn_blocking(move || capture_capture_status_validation())
-        .await
-        .unwrap();
+    let validation =
+        tokio::task::spawn_blocking(move || capture_capture_preview_frame_validation())
+            .await
+            .unwrap();
 
     let Ok(()) = validation else {
         return Response::builder()
@@ -1753,14 +1838,14 @@ where
 
     let result = api_impl
         .as_ref()
-        .capture_capture_status(&method, &host, &cookies, &claims)
+        .capture_capture_preview_frame(&method, &host, &cookies, &claims)
         .await;
 
     let mut response = Response::builder();
 
     let resp = match result {
                                             Ok(rsp) => match rsp {
-                                                apis::capture::CaptureCaptureStatusResponse::Status200_CaptureStatusResult
+                                                apis::capture::CaptureCapturePreviewFrameResponse::Status200_CapturePreviewFrameResult
                                                     (body)
                                                 => {
                                                   let mut response = response.status(200);
@@ -1778,7 +1863,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::capture::CaptureCaptureStatusResponse::Status400_EngineBadRequestErrorResponseBody
+                                                apis::capture::CaptureCapturePreviewFrameResponse::Status400_EngineBadRequestErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(400);
@@ -1796,7 +1881,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::capture::CaptureCaptureStatusResponse::Status401_EngineUnauthorizedErrorResponseBody
+                                                apis::capture::CaptureCapturePreviewFrameResponse::Status401_EngineUnauthorizedErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(401);
@@ -1814,7 +1899,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::capture::CaptureCaptureStatusResponse::Status403_EngineForbiddenErrorResponseBody
+                                                apis::capture::CaptureCapturePreviewFrameResponse::Status403_EngineForbiddenErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(403);
@@ -1832,7 +1917,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::capture::CaptureCaptureStatusResponse::Status500_EngineRuntimeErrorResponseBody
+                                                apis::capture::CaptureCapturePreviewFrameResponse::Status500_EngineRuntimeErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(500);
@@ -1864,18 +1949,31 @@ where
     })
 }
 
+#[derive(validator::Validate)]
+#[allow(dead_code)]
+struct CaptureCaptureStartCameraBodyValidator<'a> {
+    #[validate(nested)]
+    body: &'a models::CaptureStartCameraPayload,
+}
+
 #[tracing::instrument(skip_all)]
-fn capture_capture_stop_validation() -> std::result::Result<(), ValidationErrors> {
-    Ok(())
-}
-/// CaptureCaptureStop - POST /v1/capture/stop
-#[tracing::instrument(skip_all)]
-async fn capture_capture_stop<I, A, E, C>(
+fn capture_capture_start_camera_validation(
+    body: models::CaptureStartCameraPayload,
+) -> std::result::Result<(models::CaptureStartCameraPayload,), ValidationErrors> {
+    let b = CaptureCaptureStartCameraBodyValidator { body: &body };
+    b.validate()?;
+
+    Ok((body,))
+}
+/// CaptureCaptureStartCamera - POST /v1/capture/start-camera
+#[tracing::instrument(skip_all)]
+async fn capture_capture_start_camera<I, A, E, C>(
     method: Method,
     TypedHeader(host): TypedHeader<Host>,
     cookies: CookieJar,
     headers: HeaderMap,
     State(api_impl): State<I>,
+    Json(body): Json<models::CaptureStartCameraPayload>,
 ) -> Result<Response, StatusCode>
 where
     I: AsRef<A> + Send + Sync,
@@ -1893,11 +1991,12 @@ where
     };
 
     #[allow(clippy::redundant_closure)]
-    let validation = tokio::task::spawn_blocking(move || capture_capture_stop_validation())
-        .await
-        .unwrap();
+    let validation =
+        tokio::task::spawn_blocking(move || capture_capture_start_camera_validation(body))
+            .await
+            .unwrap();
 
-    let Ok(()) = validation else {
+    let Ok((body,)) = validation else {
         return Response::builder()
             .status(StatusCode::BAD_REQUEST)
             .body(Body::from(validation.unwrap_err().to_string()))
@@ -1906,14 +2005,14 @@ where
 
     let result = api_impl
         .as_ref()
-        .capture_capture_stop(&method, &host, &cookies, &claims)
+        .capture_capture_start_camera(&method, &host, &cookies, &claims, &body)
         .await;
 
     let mut response = Response::builder();
 
     let resp = match result {
                                             Ok(rsp) => match rsp {
-                                                apis::capture::CaptureCaptureStopResponse::Status200_CaptureStatusResult
+                                                apis::capture::CaptureCaptureStartCameraResponse::Status200_CaptureStatusResult
                                                     (body)
                                                 => {
                                                   let mut response = response.status(200);
@@ -1931,7 +2030,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::capture::CaptureCaptureStopResponse::Status400_EngineBadRequestErrorResponseBody
+                                                apis::capture::CaptureCaptureStartCameraResponse::Status400_EngineBadRequestErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(400);
@@ -1949,7 +2048,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::capture::CaptureCaptureStopResponse::Status401_EngineUnauthorizedErrorResponseBody
+                                                apis::capture::CaptureCaptureStartCameraResponse::Status401_EngineUnauthorizedErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(401);
@@ -1967,7 +2066,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::capture::CaptureCaptureStopResponse::Status403_EngineForbiddenErrorResponseBody
+                                                apis::capture::CaptureCaptureStartCameraResponse::Status403_EngineForbiddenErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(403);
@@ -1985,7 +2084,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::capture::CaptureCaptureStopResponse::Status409_EngineConflictErrorResponseBody
+                                                apis::capture::CaptureCaptureStartCameraResponse::Status409_EngineConflictErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(409);
@@ -2003,7 +2102,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::capture::CaptureCaptureStopResponse::Status422_EngineUnprocessableErrorResponseBody
+                                                apis::capture::CaptureCaptureStartCameraResponse::Status422_EngineUnprocessableErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(422);
@@ -2021,7 +2120,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::capture::CaptureCaptureStopResponse::Status500_EngineRuntimeErrorResponseBody
+                                                apis::capture::CaptureCaptureStartCameraResponse::Status500_EngineRuntimeErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(500);
@@ -2053,27 +2152,35 @@ where
     })
 }
 
+#[derive(validator::Validate)]
+#[allow(dead_code)]
+struct CaptureCaptureStartCurrentWindowBodyValidator<'a> {
+    #[validate(nested)]
+    body: &'a models::CaptureStartCurrentWindowPayload,
+}
+
 #[tracing::instrument(skip_all)]
-fn export_export_get_validation(
-    path_params: models::ExportExportGetPathParams,
-) -> std::result::Result<(models::ExportExportGetPathParams,), ValidationErrors> {
-    path_params.validate()?;
+fn capture_capture_start_current_window_validation(
+    body: models::CaptureStartCurrentWindowPayload,
+) -> std::result::Result<(models::CaptureStartCurrentWindowPayload,), ValidationErrors> {
+    let b = CaptureCaptureStartCurrentWindowBodyValidator { body: &body };
+    b.validate()?;
 
-    Ok((path_params,))
+    Ok((body,))
 }
-/// ExportExportGet - GET /v1/exports/{jobId}
+/// CaptureCaptureStartCurrentWindow - POST /v1/capture/start-current-window
 #[tracing::instrument(skip_all)]
-async fn export_export_get<I, A, E, C>(
+async fn capture_capture_start_current_window<I, A, E, C>(
     method: Method,
     TypedHeader(host): TypedHeader<Host>,
     cookies: CookieJar,
     headers: HeaderMap,
-    Path(path_params): Path<models::ExportExportGetPathParams>,
     State(api_impl): State<I>,
+    Json(body): Json<models::CaptureStartCurrentWindowPayload>,
 ) -> Result<Response, StatusCode>
 where
     I: AsRef<A> + Send + Sync,
-    A: apis::export::Export<E, Claims = C> + apis::ApiAuthBasic<Claims = C> + Send + Sync,
+    A: apis::capture::Capture<E, Claims = C> + apis::ApiAuthBasic<Claims = C> + Send + Sync,
     E: std::fmt::Debug + Send + Sync + 'static,
 {
     // Authentication
@@ -2087,11 +2194,12 @@ where
     };
 
     #[allow(clippy::redundant_closure)]
-    let validation = tokio::task::spawn_blocking(move || export_export_get_validation(path_params))
-        .await
-        .unwrap();
+    let validation =
+        tokio::task::spawn_blocking(move || capture_capture_start_current_window_validation(body))
+            .await
+            .unwrap();
 
-    let Ok((path_params,)) = validation else {
+    let Ok((body,)) = validation else {
         return Response::builder()
             .status(StatusCode::BAD_REQUEST)
             .body(Body::from(validation.unwrap_err().to_string()))
@@ -2100,14 +2208,14 @@ where
 
     let result = api_impl
         .as_ref()
-        .export_export_get(&method, &host, &cookies, &claims, &path_params)
+        .capture_capture_start_current_window(&method, &host, &cookies, &claims, &body)
         .await;
 
     let mut response = Response::builder();
 
     let resp = match result {
                                             Ok(rsp) => match rsp {
-                                                apis::export::ExportExportGetResponse::Status200_ExportRunResult
+                                                apis::capture::CaptureCaptureStartCurrentWindowResponse::Status200_CaptureStatusResult
                                                     (body)
                                                 => {
                                                   let mut response = response.status(200);
@@ -2125,7 +2233,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::export::ExportExportGetResponse::Status400_EngineBadRequestErrorResponseBody
+                                                apis::capture::CaptureCaptureStartCurrentWindowResponse::Status400_EngineBadRequestErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(400);
@@ -2143,7 +2251,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::export::ExportExportGetResponse::Status401_EngineUnauthorizedErrorResponseBody
+                                                apis::capture::CaptureCaptureStartCurrentWindowResponse::Status401_EngineUnauthorizedErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(401);
@@ -2161,7 +2269,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::export::ExportExportGetResponse::Status403_EngineForbiddenErrorResponseBody
+                                                apis::capture::CaptureCaptureStartCurrentWindowResponse::Status403_EngineForbiddenErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(403);
@@ -2179,10 +2287,10 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::export::ExportExportGetResponse::Status404_EngineNotFoundErrorResponseBody
+                                                apis::capture::CaptureCaptureStartCurrentWindowResponse::Status409_EngineConflictErrorResponseBody
                                                     (body)
                                                 => {
-                                                  let mut response = response.status(404);
+                                                  let mut response = response.status(409);
                                                   {
                                                     let mut response_headers = response.headers_mut().unwrap();
                                                     response_headers.insert(
@@ -2197,7 +2305,25 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::export::ExportExportGetResponse::Status500_EngineRuntimeErrorResponseBody
+                                                apis::capture::CaptureCaptureStartCurrentWindowResponse::Status422_EngineUnprocessableErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(422);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::capture::CaptureCaptureStartCurrentWindowResponse::Status500_EngineRuntimeErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(500);
@@ -2229,22 +2355,35 @@ where
     })
 }
 
+#[derive(validator::Validate)]
+#[allow(dead_code)]
+struct CaptureCaptureStartDisplayBodyValidator<'a> {
+    #[validate(nested)]
+    body: &'a models::CaptureStartDisplayPayload,
+}
+
 #[tracing::instrument(skip_all)]
-fn export_export_info_validation() -> std::result::Result<(), ValidationErrors> {
-    Ok(())
+fn capture_capture_start_display_validation(
+    body: models::CaptureStartDisplayPayload,
+) -> std::result::Result<(models::CaptureStartDisplayPayload,), ValidationErrors> {
+    let b = CaptureCaptureStartDisplayBodyValidator { body: &body };
+    b.validate()?;
+
+    Ok((body,))
 }
-/// ExportExportInfo - GET /v1/export/info
+/// CaptureCaptureStartDisplay - POST /v1/capture/start-display
 #[tracing::instrument(skip_all)]
-async fn export_export_info<I, A, E, C>(
+async fn capture_capture_start_display<I, A, E, C>(
     method: Method,
     TypedHeader(host): TypedHeader<Host>,
     cookies: CookieJar,
     headers: HeaderMap,
     State(api_impl): State<I>,
+    Json(body): Json<models::CaptureStartDisplayPayload>,
 ) -> Result<Response, StatusCode>
 where
     I: AsRef<A> + Send + Sync,
-    A: apis::export::Export<E, Claims = C> + apis::ApiAuthBasic<Claims = C> + Send + Sync,
+    A: apis::capture::Capture<E, Claims = C> + apis::ApiAuthBasic<Claims = C> + Send + Sync,
     E: std::fmt::Debug + Send + Sync + 'static,
 {
     // Authentication
@@ -2258,11 +2397,12 @@ where
     };
 
     #[allow(clippy::redundant_closure)]
-    let validation = tokio::task::spawn_blocking(move || export_export_info_validation())
-        .await
-        .unwrap();
+    let validation =
+        tokio::task::spawn_blocking(move || capture_capture_start_display_validation(body))
+            .await
+            .unwrap();
 
-    let Ok(()) = validation else {
+    let Ok((body,)) = validation else {
         return Response::builder()
             .status(StatusCode::BAD_REQUEST)
             .body(Body::from(validation.unwrap_err().to_string()))
@@ -2271,14 +2411,14 @@ where
 
     let result = api_impl
         .as_ref()
-        .export_export_info(&method, &host, &cookies, &claims)
+        .capture_capture_start_display(&method, &host, &cookies, &claims, &body)
         .await;
 
     let mut response = Response::builder();
 
     let resp = match result {
                                             Ok(rsp) => match rsp {
-                                                apis::export::ExportExportInfoResponse::Status200_ExportInfoResult
+                                                apis::capture::CaptureCaptureStartDisplayResponse::Status200_CaptureStatusResult
                                                     (body)
                                                 => {
                                                   let mut response = response.status(200);
@@ -2296,7 +2436,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::export::ExportExportInfoResponse::Status400_EngineBadRequestErrorResponseBody
+                                                apis::capture::CaptureCaptureStartDisplayResponse::Status400_EngineBadRequestErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(400);
@@ -2314,7 +2454,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::export::ExportExportInfoResponse::Status401_EngineUnauthorizedErrorResponseBody
+                                                apis::capture::CaptureCaptureStartDisplayResponse::Status401_EngineUnauthorizedErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(401);
@@ -2332,7 +2472,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::export::ExportExportInfoResponse::Status403_EngineForbiddenErrorResponseBody
+                                                apis::capture::CaptureCaptureStartDisplayResponse::Status403_EngineForbiddenErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(403);
@@ -2350,7 +2490,43 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::export::ExportExportInfoResponse::Status500_EngineRuntimeErrorResponseBody
+                                                apis::capture::CaptureCaptureStartDisplayResponse::Status409_EngineConflictErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(409);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::capture::CaptureCaptureStartDisplayResponse::Status422_EngineUnprocessableErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(422);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::capture::CaptureCaptureStartDisplayResponse::Status500_EngineRuntimeErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(500);
@@ -2384,33 +2560,33 @@ where
 
 #[derive(validator::Validate)]
 #[allow(dead_code)]
-struct ExportExportRunBodyValidator<'a> {
+struct CaptureCaptureStartRegionBodyValidator<'a> {
     #[validate(nested)]
-    body: &'a models::ExportRunPayload,
+    body: &'a models::CaptureStartRegionPayload,
 }
 
 #[tracing::instrument(skip_all)]
-fn export_export_run_validation(
-    body: models::ExportRunPayload,
-) -> std::result::Result<(models::ExportRunPayload,), ValidationErrors> {
-    let b = ExportExportRunBodyValidator { body: &body };
+fn capture_capture_start_region_validation(
+    body: models::CaptureStartRegionPayload,
+) -> std::result::Result<(models::CaptureStartRegionPayload,), ValidationErrors> {
+    let b = CaptureCaptureStartRegionBodyValidator { body: &body };
     b.validate()?;
 
     Ok((body,))
 }
-/// ExportExportRun - POST /v1/exports
+/// CaptureCaptureStartRegion - POST /v1/capture/start-region
 #[tracing::instrument(skip_all)]
-async fn export_export_run<I, A, E, C>(
+async fn capture_capture_start_region<I, A, E, C>(
     method: Method,
     TypedHeader(host): TypedHeader<Host>,
     cookies: CookieJar,
     headers: HeaderMap,
     State(api_impl): State<I>,
-    Json(body): Json<models::ExportRunPayload>,
+    Json(body): Json<models::CaptureStartRegionPayload>,
 ) -> Result<Response, StatusCode>
 where
     I: AsRef<A> + Send + Sync,
-    A: apis::export::Export<E, Claims = C> + apis::ApiAuthBasic<Claims = C> + Send + Sync,
+    A: apis::capture::Capture<E, Claims = C> + apis::ApiAuthBasic<Claims = C> + Send + Sync,
     E: std::fmt::Debug + Send + Sync + 'static,
 {
     // Authentication
@@ -2424,9 +2600,10 @@ where
     };
 
     #[allow(clippy::redundant_closure)]
-    let validation = tokio::task::spawn_blocking(move || export_export_run_validation(body))
-        .await
-        .unwrap();
+    let validation =
+        tokio::task::spawn_blocking(move || capture_capture_start_region_validation(body))
+            .await
+            .unwrap();
 
     let Ok((body,)) = validation else {
         return Response::builder()
@@ -2437,14 +2614,14 @@ where
 
     let result = api_impl
         .as_ref()
-        .export_export_run(&method, &host, &cookies, &claims, &body)
+        .capture_capture_start_region(&method, &host, &cookies, &claims, &body)
         .await;
 
     let mut response = Response::builder();
 
     let resp = match result {
                                             Ok(rsp) => match rsp {
-                                                apis::export::ExportExportRunResponse::Status200_ExportRunResult
+                                                apis::capture::CaptureCaptureStartRegionResponse::Status200_CaptureStatusResult
                                                     (body)
                                                 => {
                                                   let mut response = response.status(200);
@@ -2462,7 +2639,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::export::ExportExportRunResponse::Status400_EngineBadRequestErrorResponseBody
+                                                apis::capture::CaptureCaptureStartRegionResponse::Status400_EngineBadRequestErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(400);
@@ -2480,7 +2657,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::export::ExportExportRunResponse::Status401_EngineUnauthorizedErrorResponseBody
+                                                apis::capture::CaptureCaptureStartRegionResponse::Status401_EngineUnauthorizedErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(401);
@@ -2498,7 +2675,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::export::ExportExportRunResponse::Status403_EngineForbiddenErrorResponseBody
+                                                apis::capture::CaptureCaptureStartRegionResponse::Status403_EngineForbiddenErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(403);
@@ -2516,7 +2693,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::export::ExportExportRunResponse::Status409_EngineConflictErrorResponseBody
+                                                apis::capture::CaptureCaptureStartRegionResponse::Status409_EngineConflictErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(409);
@@ -2534,7 +2711,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::export::ExportExportRunResponse::Status422_EngineUnprocessableErrorResponseBody
+                                                apis::capture::CaptureCaptureStartRegionResponse::Status422_EngineUnprocessableErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(422);
@@ -2552,7 +2729,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::export::ExportExportRunResponse::Status500_EngineRuntimeErrorResponseBody
+                                                apis::capture::CaptureCaptureStartRegionResponse::Status500_EngineRuntimeErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(500);
@@ -2586,33 +2763,33 @@ where
 
 #[derive(validator::Validate)]
 #[allow(dead_code)]
-struct ExportExportRunCutPlanBodyValidator<'a> {
+struct CaptureCaptureStartWindowBodyValidator<'a> {
     #[validate(nested)]
-    body: &'a models::ExportRunCutPlanPayload,
+    body: &'a models::CaptureStartWindowPayload,
 }
 
 #[tracing::instrument(skip_all)]
-fn export_export_run_cut_plan_validation(
-    body: models::ExportRunCutPlanPayload,
-) -> std::result::Result<(models::ExportRunCutPlanPayload,), ValidationErrors> {
-    let b = ExportExportRunCutPlanBodyValidator { body: &body };
+fn capture_capture_start_window_validation(
+    body: models::CaptureStartWindowPayload,
+) -> std::result::Result<(models::CaptureStartWindowPayload,), ValidationErrors> {
+    let b = CaptureCaptureStartWindowBodyValidator { body: &body };
     b.validate()?;
 
     Ok((body,))
 }
-/// ExportExportRunCutPlan - POST /v1/exports/from-cut-plan
+/// CaptureCaptureStartWindow - POST /v1/capture/start-window
 #[tracing::instrument(skip_all)]
-async fn export_export_run_cut_plan<I, A, E, C>(
+async fn capture_capture_start_window<I, A, E, C>(
     method: Method,
     TypedHeader(host): TypedHeader<Host>,
     cookies: CookieJar,
     headers: HeaderMap,
     State(api_impl): State<I>,
-    Json(body): Json<models::ExportRunCutPlanPayload>,
+    Json(body): Json<models::CaptureStartWindowPayload>,
 ) -> Result<Response, StatusCode>
 where
     I: AsRef<A> + Send + Sync,
-    A: apis::export::Export<E, Claims = C> + apis::ApiAuthBasic<Claims = C> + Send + Sync,
+    A: apis::capture::Capture<E, Claims = C> + apis::ApiAuthBasic<Claims = C> + Send + Sync,
     E: std::fmt::Debug + Send + Sync + 'static,
 {
     // Authentication
@@ -2627,7 +2804,7 @@ where
 
     #[allow(clippy::redundant_closure)]
     let validation =
-        tokio::task::spawn_blocking(move || export_export_run_cut_plan_validation(body))
+        tokio::task::spawn_blocking(move || capture_capture_start_window_validation(body))
             .await
             .unwrap();
 
@@ -2640,14 +2817,14 @@ where
 
     let result = api_impl
         .as_ref()
-        .export_export_run_cut_plan(&method, &host, &cookies, &claims, &body)
+        .capture_capture_start_window(&method, &host, &cookies, &claims, &body)
         .await;
 
     let mut response = Response::builder();
 
     let resp = match result {
                                             Ok(rsp) => match rsp {
-                                                apis::export::ExportExportRunCutPlanResponse::Status200_ExportRunCutPlanResult
+                                                apis::capture::CaptureCaptureStartWindowResponse::Status200_CaptureStatusResult
                                                     (body)
                                                 => {
                                                   let mut response = response.status(200);
@@ -2665,7 +2842,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::export::ExportExportRunCutPlanResponse::Status400_EngineBadRequestErrorResponseBody
+                                                apis::capture::CaptureCaptureStartWindowResponse::Status400_EngineBadRequestErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(400);
@@ -2683,7 +2860,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::export::ExportExportRunCutPlanResponse::Status401_EngineUnauthorizedErrorResponseBody
+                                                apis::capture::CaptureCaptureStartWindowResponse::Status401_EngineUnauthorizedErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(401);
@@ -2701,7 +2878,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::export::ExportExportRunCutPlanResponse::Status403_EngineForbiddenErrorResponseBody
+                                                apis::capture::CaptureCaptureStartWindowResponse::Status403_EngineForbiddenErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(403);
@@ -2719,7 +2896,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::export::ExportExportRunCutPlanResponse::Status409_EngineConflictErrorResponseBody
+                                                apis::capture::CaptureCaptureStartWindowResponse::Status409_EngineConflictErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(409);
@@ -2737,7 +2914,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::export::ExportExportRunCutPlanResponse::Status422_EngineUnprocessableErrorResponseBody
+                                                apis::capture::CaptureCaptureStartWindowResponse::Status422_EngineUnprocessableErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(422);
@@ -2755,7 +2932,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::export::ExportExportRunCutPlanResponse::Status500_EngineRuntimeErrorResponseBody
+                                                apis::capture::CaptureCaptureStartWindowResponse::Status500_EngineRuntimeErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(500);
@@ -2788,12 +2965,12 @@ where
 }
 
 #[tracing::instrument(skip_all)]
-fn permissions_permissions_get_validation() -> std::result::Result<(), ValidationErrors> {
+fn capture_capture_status_validation() -> std::result::Result<(), ValidationErrors> {
     Ok(())
 }
-/// PermissionsPermissionsGet - GET /v1/permissions
+/// CaptureCaptureStatus - GET /v1/capture/status
 #[tracing::instrument(skip_all)]
-async fn permissions_permissions_get<I, A, E, C>(
+async fn capture_capture_status<I, A, E, C>(
     method: Method,
     TypedHeader(host): TypedHeader<Host>,
     cookies: CookieJar,
@@ -2802,7 +2979,7 @@ async fn permissions_permissions_get<I, A, E, C>(
 ) -> Result<Response, StatusCode>
 where
     I: AsRef<A> + Send + Sync,
-    A: apis::permissions::Permissions<E, Claims = C> + apis::ApiAuthBasic<Claims = C> + Send + Sync,
+    A: apis::capture::Capture<E, Claims = C> + apis::ApiAuthBasic<Claims = C> + Send + Sync,
     E: std::fmt::Debug + Send + Sync + 'static,
 {
     // Authentication
@@ -2816,7 +2993,7 @@ where
     };
 
     #[allow(clippy::redundant_closure)]
-    let validation = tokio::task::spawn_blocking(move || permissions_permissions_get_validation())
+    let validation = tokio::task::spawn_blocking(move || capture_capture_status_validation())
         .await
         .unwrap();
 
@@ -2829,14 +3006,14 @@ where
 
     let result = api_impl
         .as_ref()
-        .permissions_permissions_get(&method, &host, &cookies, &claims)
+        .capture_capture_status(&method, &host, &cookies, &claims)
         .await;
 
     let mut response = Response::builder();
 
     let resp = match result {
                                             Ok(rsp) => match rsp {
-                                                apis::permissions::PermissionsPermissionsGetResponse::Status200_PermissionsResult
+                                                apis::capture::CaptureCaptureStatusResponse::Status200_CaptureStatusResult
                                                     (body)
                                                 => {
                                                   let mut response = response.status(200);
@@ -2854,7 +3031,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::permissions::PermissionsPermissionsGetResponse::Status400_EngineBadRequestErrorResponseBody
+                                                apis::capture::CaptureCaptureStatusResponse::Status400_EngineBadRequestErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(400);
@@ -2872,7 +3049,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::permissions::PermissionsPermissionsGetResponse::Status401_EngineUnauthorizedErrorResponseBody
+                                                apis::capture::CaptureCaptureStatusResponse::Status401_EngineUnauthorizedErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(401);
@@ -2890,7 +3067,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::permissions::PermissionsPermissionsGetResponse::Status403_EngineForbiddenErrorResponseBody
+                                                apis::capture::CaptureCaptureStatusResponse::Status403_EngineForbiddenErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(403);
@@ -2908,7 +3085,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::permissions::PermissionsPermissionsGetResponse::Status500_EngineRuntimeErrorResponseBody
+                                                apis::capture::CaptureCaptureStatusResponse::Status500_EngineRuntimeErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(500);
@@ -2941,13 +3118,12 @@ where
 }
 
 #[tracing::instrument(skip_all)]
-fn permissions_permissions_open_input_monitoring_settings_validation()
--> std::result::Result<(), ValidationErrors> {
+fn capture_capture_stop_validation() -> std::result::Result<(), ValidationErrors> {
     Ok(())
 }
-/// PermissionsPermissionsOpenInputMonitoringSettings - POST /v1/permissions/input-monitoring/open-settings
+/// CaptureCaptureStop - POST /v1/capture/stop
 #[tracing::instrument(skip_all)]
-async fn permissions_permissions_open_input_monitoring_settings<I, A, E, C>(
+async fn capture_capture_stop<I, A, E, C>(
     method: Method,
     TypedHeader(host): TypedHeader<Host>,
     cookies: CookieJar,
@@ -2956,7 +3132,7 @@ async fn permissions_permissions_open_input_monitoring_settings<I, A, E, C>(
 ) -> Result<Response, StatusCode>
 where
     I: AsRef<A> + Send + Sync,
-    A: apis::permissions::Permissions<E, Claims = C> + apis::ApiAuthBasic<Claims = C> + Send + Sync,
+    A: apis::capture::Capture<E, Claims = C> + apis::ApiAuthBasic<Claims = C> + Send + Sync,
     E: std::fmt::Debug + Send + Sync + 'static,
 {
     // Authentication
@@ -2970,11 +3146,9 @@ where
     };
 
     #[allow(clippy::redundant_closure)]
-    let validation = tokio::task::spawn_blocking(move || {
-        permissions_permissions_open_input_monitoring_settings_validation()
-    })
-    .await
-    .unwrap();
+    let validation = tokio::task::spawn_blocking(move || capture_capture_stop_validation())
+        .await
+        .unwrap();
 
     let Ok(()) = validation else {
         return Response::builder()
@@ -2985,14 +3159,14 @@ where
 
     let result = api_impl
         .as_ref()
-        .permissions_permissions_open_input_monitoring_settings(&method, &host, &cookies, &claims)
+        .capture_capture_stop(&method, &host, &cookies, &claims)
         .await;
 
     let mut response = Response::builder();
 
     let resp = match result {
                                             Ok(rsp) => match rsp {
-                                                apis::permissions::PermissionsPermissionsOpenInputMonitoringSettingsResponse::Status200_ActionResult
+                                                apis::capture::CaptureCaptureStopResponse::Status200_CaptureStatusResult
                                                     (body)
                                                 => {
                                                   let mut response = response.status(200);
@@ -3010,7 +3184,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::permissions::PermissionsPermissionsOpenInputMonitoringSettingsResponse::Status400_EngineBadRequestErrorResponseBody
+                                                apis::capture::CaptureCaptureStopResponse::Status400_EngineBadRequestErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(400);
@@ -3028,7 +3202,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::permissions::PermissionsPermissionsOpenInputMonitoringSettingsResponse::Status401_EngineUnauthorizedErrorResponseBody
+                                                apis::capture::CaptureCaptureStopResponse::Status401_EngineUnauthorizedErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(401);
@@ -3046,7 +3220,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::permissions::PermissionsPermissionsOpenInputMonitoringSettingsResponse::Status403_EngineForbiddenErrorResponseBody
+                                                apis::capture::CaptureCaptureStopResponse::Status403_EngineForbiddenErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(403);
@@ -3064,7 +3238,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::permissions::PermissionsPermissionsOpenInputMonitoringSettingsResponse::Status409_EngineConflictErrorResponseBody
+                                                apis::capture::CaptureCaptureStopResponse::Status409_EngineConflictErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(409);
@@ -3082,7 +3256,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::permissions::PermissionsPermissionsOpenInputMonitoringSettingsResponse::Status422_EngineUnprocessableErrorResponseBody
+                                                apis::capture::CaptureCaptureStopResponse::Status422_EngineUnprocessableErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(422);
@@ -3100,7 +3274,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::permissions::PermissionsPermissionsOpenInputMonitoringSettingsResponse::Status500_EngineRuntimeErrorResponseBody
+                                                apis::capture::CaptureCaptureStopResponse::Status500_EngineRuntimeErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(500);
@@ -3133,13 +3307,12 @@ where
 }
 
 #[tracing::instrument(skip_all)]
-fn permissions_permissions_request_input_monitoring_validation()
--> std::result::Result<(), ValidationErrors> {
+fn capture_capture_stop_camera_validation() -> std::result::Result<(), ValidationErrors> {
     Ok(())
 }
-/// PermissionsPermissionsRequestInputMonitoring - POST /v1/permissions/input-monitoring/request
+/// CaptureCaptureStopCamera - POST /v1/capture/stop-camera
 #[tracing::instrument(skip_all)]
-async fn permissions_permissions_request_input_monitoring<I, A, E, C>(
+async fn capture_capture_stop_camera<I, A, E, C>(
     method: Method,
     TypedHeader(host): TypedHeader<Host>,
     cookies: CookieJar,
@@ -3148,7 +3321,7 @@ async fn permissions_permissions_request_input_monitoring<I, A, E, C>(
 ) -> Result<Response, StatusCode>
 where
     I: AsRef<A> + Send + Sync,
-    A: apis::permissions::Permissions<E, Claims = C> + apis::ApiAuthBasic<Claims = C> + Send + Sync,
+    A: apis::capture::Capture<E, Claims = C> + apis::ApiAuthBasic<Claims = C> + Send + Sync,
     E: std::fmt::Debug + Send + Sync + 'static,
 {
     // Authentication
@@ -3162,11 +3335,9 @@ where
     };
 
     #[allow(clippy::redundant_closure)]
-    let validation = tokio::task::spawn_blocking(move || {
-        permissions_permissions_request_input_monitoring_validation()
-    })
-    .await
-    .unwrap();
+    let validation = tokio::task::spawn_blocking(move || capture_capture_stop_camera_validation())
+        .await
+        .unwrap();
 
     let Ok(()) = validation else {
         return Response::builder()
@@ -3177,14 +3348,14 @@ where
 
     let result = api_impl
         .as_ref()
-        .permissions_permissions_request_input_monitoring(&method, &host, &cookies, &claims)
+        .capture_capture_stop_camera(&method, &host, &cookies, &claims)
         .await;
 
     let mut response = Response::builder();
 
     let resp = match result {
                                             Ok(rsp) => match rsp {
-                                                apis::permissions::PermissionsPermissionsRequestInputMonitoringResponse::Status200_ActionResult
+                                                apis::capture::CaptureCaptureStopCameraResponse::Status200_CaptureStatusResult
                                                     (body)
                                                 => {
                                                   let mut response = response.status(200);
@@ -3202,7 +3373,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::permissions::PermissionsPermissionsRequestInputMonitoringResponse::Status400_EngineBadRequestErrorResponseBody
+                                                apis::capture::CaptureCaptureStopCameraResponse::Status400_EngineBadRequestErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(400);
@@ -3220,7 +3391,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::permissions::PermissionsPermissionsRequestInputMonitoringResponse::Status401_EngineUnauthorizedErrorResponseBody
+                                                apis::capture::CaptureCaptureStopCameraResponse::Status401_EngineUnauthorizedErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(401);
@@ -3238,7 +3409,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::permissions::PermissionsPermissionsRequestInputMonitoringResponse::Status403_EngineForbiddenErrorResponseBody
+                                                apis::capture::CaptureCaptureStopCameraResponse::Status403_EngineForbiddenErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(403);
@@ -3256,7 +3427,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::permissions::PermissionsPermissionsRequestInputMonitoringResponse::Status409_EngineConflictErrorResponseBody
+                                                apis::capture::CaptureCaptureStopCameraResponse::Status409_EngineConflictErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(409);
@@ -3274,7 +3445,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::permissions::PermissionsPermissionsRequestInputMonitoringResponse::Status422_EngineUnprocessableErrorResponseBody
+                                                apis::capture::CaptureCaptureStopCameraResponse::Status422_EngineUnprocessableErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(422);
@@ -3292,7 +3463,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::permissions::PermissionsPermissionsRequestInputMonitoringResponse::Status500_EngineRuntimeErrorResponseBody
+                                                apis::capture::CaptureCaptureStopCameraResponse::Status500_EngineRuntimeErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(500);
@@ -3325,22 +3496,26 @@ where
 }
 
 #[tracing::instrument(skip_all)]
-fn permissions_permissions_request_microphone_validation()
--> std::result::Result<(), ValidationErrors> {
-    Ok(())
+fn export_export_get_validation(
+    path_params: models::ExportExportGetPathParams,
+) -> std::result::Result<(models::ExportExportGetPathParams,), ValidationErrors> {
+    path_params.validate()?;
+
+    Ok((path_params,))
 }
-/// PermissionsPermissionsRequestMicrophone - POST /v1/permissions/microphone/request
+/// ExportExportGet - GET /v1/exports/{jobId}
 #[tracing::instrument(skip_all)]
-async fn permissions_permissions_request_microphone<I, A, E, C>(
+async fn export_export_get<I, A, E, C>(
     method: Method,
     TypedHeader(host): TypedHeader<Host>,
     cookies: CookieJar,
     headers: HeaderMap,
+    Path(path_params): Path<models::ExportExportGetPathParams>,
     State(api_impl): State<I>,
 ) -> Result<Response, StatusCode>
 where
     I: AsRef<A> + Send + Sync,
-    A: apis::permissions::Permissions<E, Claims = C> + apis::ApiAuthBasic<Claims = C> + Send + Sync,
+    A: apis::export::Export<E, Claims = C> + apis::ApiAuthBasic<Claims = C> + Send + Sync,
     E: std::fmt::Debug + Send + Sync + 'static,
 {
     // Authentication
@@ -3354,13 +3529,11 @@ where
     };
 
     #[allow(clippy::redundant_closure)]
-    let validation = tokio::task::spawn_blocking(move || {
-        permissions_permissions_request_microphone_validation()
-    })
-    .await
-    .unwrap();
+    let validation = tokio::task::spawn_blocking(move || export_export_get_validation(path_params))
+        .await
+        .unwrap();
 
-    let Ok(()) = validation else {
+    let Ok((path_params,)) = validation else {
         return Response::builder()
             .status(StatusCode::BAD_REQUEST)
             .body(Body::from(validation.unwrap_err().to_string()))
@@ -3369,14 +3542,14 @@ where
 
     let result = api_impl
         .as_ref()
-        .permissions_permissions_request_microphone(&method, &host, &cookies, &claims)
+        .export_export_get(&method, &host, &cookies, &claims, &path_params)
         .await;
 
     let mut response = Response::builder();
 
     let resp = match result {
                                             Ok(rsp) => match rsp {
-                                                apis::permissions::PermissionsPermissionsRequestMicrophoneResponse::Status200_ActionResult
+                                                apis::export::ExportExportGetResponse::Status200_ExportRunResult
                                                     (body)
                                                 => {
                                                   let mut response = response.status(200);
@@ -3394,7 +3567,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::permissions::PermissionsPermissionsRequestMicrophoneResponse::Status400_EngineBadRequestErrorResponseBody
+                                                apis::export::ExportExportGetResponse::Status400_EngineBadRequestErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(400);
@@ -3412,7 +3585,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::permissions::PermissionsPermissionsRequestMicrophoneResponse::Status401_EngineUnauthorizedErrorResponseBody
+                                                apis::export::ExportExportGetResponse::Status401_EngineUnauthorizedErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(401);
@@ -3430,7 +3603,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::permissions::PermissionsPermissionsRequestMicrophoneResponse::Status403_EngineForbiddenErrorResponseBody
+                                                apis::export::ExportExportGetResponse::Status403_EngineForbiddenErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(403);
@@ -3448,28 +3621,10 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::permissions::PermissionsPermissionsRequestMicrophoneResponse::Status409_EngineConflictErrorResponseBody
-                                                    (body)
-                                                => {
-                                                  let mut response = response.status(409);
-                                                  {
-                                                    let mut response_headers = response.headers_mut().unwrap();
-                                                    response_headers.insert(
-                                                        CONTENT_TYPE,
-                                                        HeaderValue::from_static("application/json"));
-                                                  }
-
-                                                  let body_content =  tokio::task::spawn_blocking(move ||
-                                                      serde_json::to_vec(&body).map_err(|e| {
-                                                        error!(error = ?e);
-                                                        StatusCode::INTERNAL_SERVER_ERROR
-                                                      })).await.unwrap()?;
-                                                  response.body(Body::from(body_content))
-                                                },
-                                                apis::permissions::PermissionsPermissionsRequestMicrophoneResponse::Status422_EngineUnprocessableErrorResponseBody
+                                                apis::export::ExportExportGetResponse::Status404_EngineNotFoundErrorResponseBody
                                                     (body)
                                                 => {
-                                                  let mut response = response.status(422);
+                                                  let mut response = response.status(404);
                                                   {
                                                     let mut response_headers = response.headers_mut().unwrap();
                                                     response_headers.insert(
@@ -3484,7 +3639,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::permissions::PermissionsPermissionsRequestMicrophoneResponse::Status500_EngineRuntimeErrorResponseBody
+                                                apis::export::ExportExportGetResponse::Status500_EngineRuntimeErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(500);
@@ -3516,23 +3671,35 @@ where
     })
 }
 
+#[derive(validator::Validate)]
+#[allow(dead_code)]
+struct ExportExportGuideBodyValidator<'a> {
+    #[validate(nested)]
+    body: &'a models::ExportGuidePayload,
+}
+
 #[tracing::instrument(skip_all)]
-fn permissions_permissions_request_screen_recording_validation()
--> std::result::Result<(), ValidationErrors> {
-    Ok(())
+fn export_export_guide_validation(
+    body: models::ExportGuidePayload,
+) -> std::result::Result<(models::ExportGuidePayload,), ValidationErrors> {
+    let b = ExportExportGuideBodyValidator { body: &body };
+    b.validate()?;
+
+    Ok((body,))
 }
-/// PermissionsPermissionsRequestScreenRecording - POST /v1/permissions/screen-recording/request
+/// ExportExportGuide - POST /v1/exports/guide
 #[tracing::instrument(skip_all)]
-async fn permissions_permissions_request_screen_recording<I, A, E, C>(
+async fn export_export_guide<I, A, E, C>(
     method: Method,
     TypedHeader(host): TypedHeader<Host>,
     cookies: CookieJar,
     headers: HeaderMap,
     State(api_impl): State<I>,
+    Json(body): Json<models::ExportGuidePayload>,
 ) -> Result<Response, StatusCode>
 where
     I: AsRef<A> + Send + Sync,
-    A: apis::permissions::Permissions<E, Claims = C> + apis::ApiAuthBasic<Claims = C> + Send + Sync,
+    A: apis::export::Export<E, Claims = C> + apis::ApiAuthBasic<Claims = C> + Send + Sync,
     E: std::fmt::Debug + Send + Sync + 'static,
 {
     // Authentication
@@ -3546,13 +3713,11 @@ where
     };
 
     #[allow(clippy::redundant_closure)]
-    let validation = tokio::task::spawn_blocking(move || {
-        permissions_permissions_request_screen_recording_validation()
-    })
-    .await
-    .unwrap();
+    let validation = tokio::task::spawn_blocking(move || export_export_guide_validation(body))
+        .await
+        .unwrap();
 
-    let Ok(()) = validation else {
+    let Ok((body,)) = validation else {
         return Response::builder()
             .status(StatusCode::BAD_REQUEST)
             .body(Body::from(validation.unwrap_err().to_string()))
@@ -3561,14 +3726,14 @@ where
 
     let result = api_impl
         .as_ref()
-        .permissions_permissions_request_screen_recording(&method, &host, &cookies, &claims)
+        .export_export_guide(&method, &host, &cookies, &claims, &body)
         .await;
 
     let mut response = Response::builder();
 
     let resp = match result {
                                             Ok(rsp) => match rsp {
-                                                apis::permissions::PermissionsPermissionsRequestScreenRecordingResponse::Status200_ActionResult
+                                                apis::export::ExportExportGuideResponse::Status200_ExportGuideResult
                                                     (body)
                                                 => {
                                                   let mut response = response.status(200);
@@ -3586,7 +3751,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::permissions::PermissionsPermissionsRequestScreenRecordingResponse::Status400_EngineBadRequestErrorResponseBody
+                                                apis::export::ExportExportGuideResponse::Status400_EngineBadRequestErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(400);
@@ -3604,7 +3769,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::permissions::PermissionsPermissionsRequestScreenRecordingResponse::Status401_EngineUnauthorizedErrorResponseBody
+                                                apis::export::ExportExportGuideResponse::Status401_EngineUnauthorizedErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(401);
@@ -3622,7 +3787,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::permissions::PermissionsPermissionsRequestScreenRecordingResponse::Status403_EngineForbiddenErrorResponseBody
+                                                apis::export::ExportExportGuideResponse::Status403_EngineForbiddenErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(403);
@@ -3640,7 +3805,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::permissions::PermissionsPermissionsRequestScreenRecordingResponse::Status409_EngineConflictErrorResponseBody
+                                                apis::export::ExportExportGuideResponse::Status409_EngineConflictErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(409);
@@ -3658,7 +3823,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::permissions::PermissionsPermissionsRequestScreenRecordingResponse::Status422_EngineUnprocessableErrorResponseBody
+                                                apis::export::ExportExportGuideResponse::Status422_EngineUnprocessableErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(422);
@@ -3676,7 +3841,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::permissions::PermissionsPermissionsRequestScreenRecordingResponse::Status500_EngineRuntimeErrorResponseBody
+                                                apis::export::ExportExportGuideResponse::Status500_EngineRuntimeErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(500);
@@ -3709,12 +3874,12 @@ where
 }
 
 #[tracing::instrument(skip_all)]
-fn project_project_current_validation() -> std::result::Result<(), ValidationErrors> {
+fn export_export_info_validation() -> std::result::Result<(), ValidationErrors> {
     Ok(())
 }
-/// ProjectProjectCurrent - GET /v1/project/current
+/// ExportExportInfo - GET /v1/export/info
 #[tracing::instrument(skip_all)]
-async fn project_project_current<I, A, E, C>(
+async fn export_export_info<I, A, E, C>(
     method: Method,
     TypedHeader(host): TypedHeader<Host>,
     cookies: CookieJar,
@@ -3723,7 +3888,7 @@ async fn project_project_current<I, A, E, C>(
 ) -> Result<Response, StatusCode>
 where
     I: AsRef<A> + Send + Sync,
-    A: apis::project::Project<E, Claims = C> + apis::ApiAuthBasic<Claims = C> + Send + Sync,
+    A: apis::export::Export<E, Claims = C> + apis::ApiAuthBasic<Claims = C> + Send + Sync,
     E: std::fmt::Debug + Send + Sync + 'static,
 {
     // Authentication
@@ -3737,7 +3902,7 @@ where
     };
 
     #[allow(clippy::redundant_closure)]
-    let validation = tokio::task::spawn_blocking(move || project_project_current_validation())
+    let validation = tokio::task::spawn_blocking(move || export_export_info_validation())
         .await
         .unwrap();
 
@@ -3750,14 +3915,14 @@ where
 
     let result = api_impl
         .as_ref()
-        .project_project_current(&method, &host, &cookies, &claims)
+        .export_export_info(&method, &host, &cookies, &claims)
         .await;
 
     let mut response = Response::builder();
 
     let resp = match result {
                                             Ok(rsp) => match rsp {
-                                                apis::project::ProjectProjectCurrentResponse::Status200_ProjectState
+                                                apis::export::ExportExportInfoResponse::Status200_ExportInfoResult
                                                     (body)
                                                 => {
                                                   let mut response = response.status(200);
@@ -3775,7 +3940,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::project::ProjectProjectCurrentResponse::Status400_EngineBadRequestErrorResponseBody
+                                                apis::export::ExportExportInfoResponse::Status400_EngineBadRequestErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(400);
@@ -3793,7 +3958,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::project::ProjectProjectCurrentResponse::Status401_EngineUnauthorizedErrorResponseBody
+                                                apis::export::ExportExportInfoResponse::Status401_EngineUnauthorizedErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(401);
@@ -3811,7 +3976,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::project::ProjectProjectCurrentResponse::Status403_EngineForbiddenErrorResponseBody
+                                                apis::export::ExportExportInfoResponse::Status403_EngineForbiddenErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(403);
@@ -3829,7 +3994,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::project::ProjectProjectCurrentResponse::Status500_EngineRuntimeErrorResponseBody
+                                                apis::export::ExportExportInfoResponse::Status500_EngineRuntimeErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(500);
@@ -3863,33 +4028,33 @@ where
 
 #[derive(validator::Validate)]
 #[allow(dead_code)]
-struct ProjectProjectOpenBodyValidator<'a> {
+struct ExportExportRunBodyValidator<'a> {
     #[validate(nested)]
-    body: &'a models::ProjectOpenPayload,
+    body: &'a models::ExportRunPayload,
 }
 
 #[tracing::instrument(skip_all)]
-fn project_project_open_validation(
-    body: models::ProjectOpenPayload,
-) -> std::result::Result<(models::ProjectOpenPayload,), ValidationErrors> {
-    let b = ProjectProjectOpenBodyValidator { body: &body };
+fn export_export_run_validation(
+    body: models::ExportRunPayload,
+) -> std::result::Result<(models::ExportRunPayload,), ValidationErrors> {
+    let b = ExportExportRunBodyValidator { body: &body };
     b.validate()?;
 
     Ok((body,))
 }
-/// ProjectProjectOpen - POST /v1/project/open
+/// ExportExportRun - POST /v1/exports
 #[tracing::instrument(skip_all)]
-async fn project_project_open<I, A, E, C>(
+async fn export_export_run<I, A, E, C>(
     method: Method,
     TypedHeader(host): TypedHeader<Host>,
     cookies: CookieJar,
     headers: HeaderMap,
     State(api_impl): State<I>,
-    Json(body): Json<models::ProjectOpenPayload>,
+    Json(body): Json<models::ExportRunPayload>,
 ) -> Result<Response, StatusCode>
 where
     I: AsRef<A> + Send + Sync,
-    A: apis::project::Project<E, Claims = C> + apis::ApiAuthBasic<Claims = C> + Send + Sync,
+    A: apis::export::Export<E, Claims = C> + apis::ApiAuthBasic<Claims = C> + Send + Sync,
     E: std::fmt::Debug + Send + Sync + 'static,
 {
     // Authentication
@@ -3903,7 +4068,7 @@ where
     };
 
     #[allow(clippy::redundant_closure)]
-    let validation = tokio::task::spawn_blocking(move || project_project_open_validation(body))
+    let validation = tokio::task::spawn_blocking(move || export_export_run_validation(body))
         .await
         .unwrap();
 
@@ -3916,14 +4081,14 @@ where
 
     let result = api_impl
         .as_ref()
-        .project_project_open(&method, &host, &cookies, &claims, &body)
+        .export_export_run(&method, &host, &cookies, &claims, &body)
         .await;
 
     let mut response = Response::builder();
 
     let resp = match result {
                                             Ok(rsp) => match rsp {
-                                                apis::project::ProjectProjectOpenResponse::Status200_ProjectState
+                                                apis::export::ExportExportRunResponse::Status200_ExportRunResult
                                                     (body)
                                                 => {
                                                   let mut response = response.status(200);
@@ -3941,7 +4106,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::project::ProjectProjectOpenResponse::Status400_EngineBadRequestErrorResponseBody
+                                                apis::export::ExportExportRunResponse::Status400_EngineBadRequestErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(400);
@@ -3959,7 +4124,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::project::ProjectProjectOpenResponse::Status401_EngineUnauthorizedErrorResponseBody
+                                                apis::export::ExportExportRunResponse::Status401_EngineUnauthorizedErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(401);
@@ -3977,7 +4142,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::project::ProjectProjectOpenResponse::Status403_EngineForbiddenErrorResponseBody
+                                                apis::export::ExportExportRunResponse::Status403_EngineForbiddenErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(403);
@@ -3995,7 +4160,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::project::ProjectProjectOpenResponse::Status409_EngineConflictErrorResponseBody
+                                                apis::export::ExportExportRunResponse::Status409_EngineConflictErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(409);
@@ -4013,7 +4178,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::project::ProjectProjectOpenResponse::Status422_EngineUnprocessableErrorResponseBody
+                                                apis::export::ExportExportRunResponse::Status422_EngineUnprocessableErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(422);
@@ -4031,7 +4196,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::project::ProjectProjectOpenResponse::Status500_EngineRuntimeErrorResponseBody
+                                                apis::export::ExportExportRunResponse::Status500_EngineRuntimeErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(500);
@@ -4063,27 +4228,35 @@ where
     })
 }
 
+#[derive(validator::Validate)]
+#[allow(dead_code)]
+struct ExportExportRunCutPlanBodyValidator<'a> {
+    #[validate(nested)]
+    body: &'a models::ExportRunCutPlanPayload,
+}
+
 #[tracing::instrument(skip_all)]
-fn project_project_recents_validation(
-    query_params: models::ProjectProjectRecentsQueryParams,
-) -> std::result::Result<(models::ProjectProjectRecentsQueryParams,), ValidationErrors> {
-    query_params.validate()?;
+fn export_export_run_cut_plan_validation(
+    body: models::ExportRunCutPlanPayload,
+) -> std::result::Result<(models::ExportRunCutPlanPayload,), ValidationErrors> {
+    let b = ExportExportRunCutPlanBodyValidator { body: &body };
+    b.validate()?;
 
-    Ok((query_params,))
+    Ok((body,))
 }
-/// ProjectProjectRecents - GET /v1/project/recents
+/// ExportExportRunCutPlan - POST /v1/exports/from-cut-plan
 #[tracing::instrument(skip_all)]
-async fn project_project_recents<I, A, E, C>(
+async fn export_export_run_cut_plan<I, A, E, C>(
     method: Method,
     TypedHeader(host): TypedHeader<Host>,
     cookies: CookieJar,
     headers: HeaderMap,
-    QueryExtra(query_params): QueryExtra<models::ProjectProjectRecentsQueryParams>,
     State(api_impl): State<I>,
+    Json(body): Json<models::ExportRunCutPlanPayload>,
 ) -> Result<Response, StatusCode>
 where
     I: AsRef<A> + Send + Sync,
-    A: apis::project::Project<E, Claims = C> + apis::ApiAuthBasic<Claims = C> + Send + Sync,
+    A: apis::export::Export<E, Claims = C> + apis::ApiAuthBasic<Claims = C> + Send + Sync,
     E: std::fmt::Debug + Send + Sync + 'static,
 {
     // Authentication
@@ -4098,11 +4271,11 @@ where
 
     #[allow(clippy::redundant_closure)]
     let validation =
-        tokio::task::spawn_blocking(move || project_project_recents_validation(query_params))
+        tokio::task::spawn_blocking(move || export_export_run_cut_plan_validation(body))
             .await
             .unwrap();
 
-    let Ok((query_params,)) = validation else {
+    let Ok((body,)) = validation else {
         return Response::builder()
             .status(StatusCode::BAD_REQUEST)
             .body(Body::from(validation.unwrap_err().to_string()))
@@ -4111,14 +4284,14 @@ where
 
     let result = api_impl
         .as_ref()
-        .project_project_recents(&method, &host, &cookies, &claims, &query_params)
+        .export_export_run_cut_plan(&method, &host, &cookies, &claims, &body)
         .await;
 
     let mut response = Response::builder();
 
     let resp = match result {
                                             Ok(rsp) => match rsp {
-                                                apis::project::ProjectProjectRecentsResponse::Status200_ProjectRecentsResult
+                                                apis::export::ExportExportRunCutPlanResponse::Status200_ExportRunCutPlanResult
                                                     (body)
                                                 => {
                                                   let mut response = response.status(200);
@@ -4136,7 +4309,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::project::ProjectProjectRecentsResponse::Status400_EngineBadRequestErrorResponseBody
+                                                apis::export::ExportExportRunCutPlanResponse::Status400_EngineBadRequestErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(400);
@@ -4154,7 +4327,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::project::ProjectProjectRecentsResponse::Status401_EngineUnauthorizedErrorResponseBody
+                                                apis::export::ExportExportRunCutPlanResponse::Status401_EngineUnauthorizedErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(401);
@@ -4172,7 +4345,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::project::ProjectProjectRecentsResponse::Status403_EngineForbiddenErrorResponseBody
+                                                apis::export::ExportExportRunCutPlanResponse::Status403_EngineForbiddenErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(403);
@@ -4190,10 +4363,46 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::project::ProjectProjectRecentsResponse::Status500_EngineRuntimeErrorResponseBody
+                                                apis::export::ExportExportRunCutPlanResponse::Status409_EngineConflictErrorResponseBody
                                                     (body)
                                                 => {
-                                                  let mut response = response.status(500);
+                                                  let mut response = response.status(409);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::export::ExportExportRunCutPlanResponse::Status422_EngineUnprocessableErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(422);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::export::ExportExportRunCutPlanResponse::Status500_EngineRuntimeErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(500);
                                                   {
                                                     let mut response_headers = response.headers_mut().unwrap();
                                                     response_headers.insert(
@@ -4222,35 +4431,22 @@ where
     })
 }
 
-#[derive(validator::Validate)]
-#[allow(dead_code)]
-struct ProjectProjectSaveBodyValidator<'a> {
-    #[validate(nested)]
-    body: &'a models::ProjectSavePayload,
-}
-
 #[tracing::instrument(skip_all)]
-fn project_project_save_validation(
-    body: models::ProjectSavePayload,
-) -> std::result::Result<(models::ProjectSavePayload,), ValidationErrors> {
-    let b = ProjectProjectSaveBodyValidator { body: &body };
-    b.validate()?;
-
-    Ok((body,))
+fn library_library_defaults_validation() -> std::result::Result<(), ValidationErrors> {
+    Ok(())
 }
-/// ProjectProjectSave - POST /v1/project/save
+/// LibraryLibraryDefaults - GET /v1/library/defaults
 #[tracing::instrument(skip_all)]
-async fn project_project_save<I, A, E, C>(
+async fn library_library_defaults<I, A, E, C>(
     method: Method,
     TypedHeader(host): TypedHeader<Host>,
     cookies: CookieJar,
     headers: HeaderMap,
     State(api_impl): State<I>,
-    Json(body): Json<models::ProjectSavePayload>,
 ) -> Result<Response, StatusCode>
 where
     I: AsRef<A> + Send + Sync,
-    A: apis::project::Project<E, Claims = C> + apis::ApiAuthBasic<Claims = C> + Send + Sync,
+    A: apis::library::Library<E, Claims = C> + apis::ApiAuthBasic<Claims = C> + Send + Sync,
     E: std::fmt::Debug + Send + Sync + 'static,
 {
     // Authentication
@@ -4264,11 +4460,11 @@ where
     };
 
     #[allow(clippy::redundant_closure)]
-    let validation = tokio::task::spawn_blocking(move || project_project_save_validation(body))
+    let validation = tokio::task::spawn_blocking(move || library_library_defaults_validation())
         .await
         .unwrap();
 
-    let Ok((body,)) = validation else {
+    let Ok(()) = validation else {
         return Response::builder()
             .status(StatusCode::BAD_REQUEST)
             .body(Body::from(validation.unwrap_err().to_string()))
@@ -4277,14 +4473,14 @@ where
 
     let result = api_impl
         .as_ref()
-        .project_project_save(&method, &host, &cookies, &claims, &body)
+        .library_library_defaults(&method, &host, &cookies, &claims)
         .await;
 
     let mut response = Response::builder();
 
     let resp = match result {
                                             Ok(rsp) => match rsp {
-                                                apis::project::ProjectProjectSaveResponse::Status200_ProjectState
+                                                apis::library::LibraryLibraryDefaultsResponse::Status200_LibraryDefaultsResult
                                                     (body)
                                                 => {
                                                   let mut response = response.status(200);
@@ -4302,7 +4498,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::project::ProjectProjectSaveResponse::Status400_EngineBadRequestErrorResponseBody
+                                                apis::library::LibraryLibraryDefaultsResponse::Status400_EngineBadRequestErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(400);
@@ -4320,7 +4516,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::project::ProjectProjectSaveResponse::Status401_EngineUnauthorizedErrorResponseBody
+                                                apis::library::LibraryLibraryDefaultsResponse::Status401_EngineUnauthorizedErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(401);
@@ -4338,7 +4534,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::project::ProjectProjectSaveResponse::Status403_EngineForbiddenErrorResponseBody
+                                                apis::library::LibraryLibraryDefaultsResponse::Status403_EngineForbiddenErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(403);
@@ -4356,43 +4552,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::project::ProjectProjectSaveResponse::Status409_EngineConflictErrorResponseBody
-                                                    (body)
-                                                => {
-                                                  let mut response = response.status(409);
-                                                  {
-                                                    let mut response_headers = response.headers_mut().unwrap();
-                                                    response_headers.insert(
-                                                        CONTENT_TYPE,
-                                                        HeaderValue::from_static("application/json"));
-                                                  }
-
-                                                  let body_content =  tokio::task::spawn_blocking(move ||
-                                                      serde_json::to_vec(&body).map_err(|e| {
-                                                        error!(error = ?e);
-                                                        StatusCode::INTERNAL_SERVER_ERROR
-                                                      })).await.unwrap()?;
-                                                  response.body(Body::from(body_content))
-                                                },
-                                                apis::project::ProjectProjectSaveResponse::Status422_EngineUnprocessableErrorResponseBody
-                                                    (body)
-                                                => {
-                                                  let mut response = response.status(422);
-                                                  {
-                                                    let mut response_headers = response.headers_mut().unwrap();
-                                                    response_headers.insert(
-                                                        CONTENT_TYPE,
-                                                        HeaderValue::from_static("application/json"));
-                                                  }
-
-                                                  let body_content =  tokio::task::spawn_blocking(move ||
-                                                      serde_json::to_vec(&body).map_err(|e| {
-                                                        error!(error = ?e);
-                                                        StatusCode::INTERNAL_SERVER_ERROR
-                                                      })).await.unwrap()?;
-                                                  response.body(Body::from(body_content))
-                                                },
-                                                apis::project::ProjectProjectSaveResponse::Status500_EngineRuntimeErrorResponseBody
+                                                apis::library::LibraryLibraryDefaultsResponse::Status500_EngineRuntimeErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(500);
@@ -4426,33 +4586,33 @@ where
 
 #[derive(validator::Validate)]
 #[allow(dead_code)]
-struct RecordingRecordingStartBodyValidator<'a> {
+struct LibraryLibrarySaveDefaultsBodyValidator<'a> {
     #[validate(nested)]
-    body: &'a models::RecordingStartPayload,
+    body: &'a models::LibrarySaveDefaultsPayload,
 }
 
 #[tracing::instrument(skip_all)]
-fn recording_recording_start_validation(
-    body: models::RecordingStartPayload,
-) -> std::result::Result<(models::RecordingStartPayload,), ValidationErrors> {
-    let b = RecordingRecordingStartBodyValidator { body: &body };
+fn library_library_save_defaults_validation(
+    body: models::LibrarySaveDefaultsPayload,
+) -> std::result::Result<(models::LibrarySaveDefaultsPayload,), ValidationErrors> {
+    let b = LibraryLibrarySaveDefaultsBodyValidator { body: &body };
     b.validate()?;
 
     Ok((body,))
 }
-/// RecordingRecordingStart - POST /v1/recording/start
+/// LibraryLibrarySaveDefaults - POST /v1/library/defaults/save
 #[tracing::instrument(skip_all)]
-async fn recording_recording_start<I, A, E, C>(
+async fn library_library_save_defaults<I, A, E, C>(
     method: Method,
     TypedHeader(host): TypedHeader<Host>,
     cookies: CookieJar,
     headers: HeaderMap,
     State(api_impl): State<I>,
-    Json(body): Json<models::RecordingStartPayload>,
+    Json(body): Json<models::LibrarySaveDefaultsPayload>,
 ) -> Result<Response, StatusCode>
 where
     I: AsRef<A> + Send + Sync,
-    A: apis::recording::Recording<E, Claims = C> + apis::ApiAuthBasic<Claims = C> + Send + Sync,
+    A: apis::library::Library<E, Claims = C> + apis::ApiAuthBasic<Claims = C> + Send + Sync,
     E: std::fmt::Debug + Send + Sync + 'static,
 {
     // Authentication
@@ -4467,7 +4627,7 @@ where
 
     #[allow(clippy::redundant_closure)]
     let validation =
-        tokio::task::spawn_blocking(move || recording_recording_start_validation(body))
+        tokio::task::spawn_blocking(move || library_library_save_defaults_validation(body))
             .await
             .unwrap();
 
@@ -4480,14 +4640,14 @@ where
 
     let result = api_impl
         .as_ref()
-        .recording_recording_start(&method, &host, &cookies, &claims, &body)
+        .library_library_save_defaults(&method, &host, &cookies, &claims, &body)
         .await;
 
     let mut response = Response::builder();
 
     let resp = match result {
                                             Ok(rsp) => match rsp {
-                                                apis::recording::RecordingRecordingStartResponse::Status200_CaptureStatusResult
+                                                apis::library::LibraryLibrarySaveDefaultsResponse::Status200_LibraryDefaultsResult
                                                     (body)
                                                 => {
                                                   let mut response = response.status(200);
@@ -4505,7 +4665,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::recording::RecordingRecordingStartResponse::Status400_EngineBadRequestErrorResponseBody
+                                                apis::library::LibraryLibrarySaveDefaultsResponse::Status400_EngineBadRequestErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(400);
@@ -4523,7 +4683,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::recording::RecordingRecordingStartResponse::Status401_EngineUnauthorizedErrorResponseBody
+                                                apis::library::LibraryLibrarySaveDefaultsResponse::Status401_EngineUnauthorizedErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(401);
@@ -4541,7 +4701,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::recording::RecordingRecordingStartResponse::Status403_EngineForbiddenErrorResponseBody
+                                                apis::library::LibraryLibrarySaveDefaultsResponse::Status403_EngineForbiddenErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(403);
@@ -4559,7 +4719,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::recording::RecordingRecordingStartResponse::Status409_EngineConflictErrorResponseBody
+                                                apis::library::LibraryLibrarySaveDefaultsResponse::Status409_EngineConflictErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(409);
@@ -4577,7 +4737,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::recording::RecordingRecordingStartResponse::Status422_EngineUnprocessableErrorResponseBody
+                                                apis::library::LibraryLibrarySaveDefaultsResponse::Status422_EngineUnprocessableErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(422);
@@ -4595,7 +4755,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::recording::RecordingRecordingStartResponse::Status500_EngineRuntimeErrorResponseBody
+                                                apis::library::LibraryLibrarySaveDefaultsResponse::Status500_EngineRuntimeErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(500);
@@ -4628,12 +4788,12 @@ where
 }
 
 #[tracing::instrument(skip_all)]
-fn recording_recording_stop_validation() -> std::result::Result<(), ValidationErrors> {
+fn permissions_permissions_get_validation() -> std::result::Result<(), ValidationErrors> {
     Ok(())
 }
-/// RecordingRecordingStop - POST /v1/recording/stop
+/// PermissionsPermissionsGet - GET /v1/permissions
 #[tracing::instrument(skip_all)]
-async fn recording_recording_stop<I, A, E, C>(
+async fn permissions_permissions_get<I, A, E, C>(
     method: Method,
     TypedHeader(host): TypedHeader<Host>,
     cookies: CookieJar,
@@ -4642,7 +4802,7 @@ async fn recording_recording_stop<I, A, E, C>(
 ) -> Result<Response, StatusCode>
 where
     I: AsRef<A> + Send + Sync,
-    A: apis::recording::Recording<E, Claims = C> + apis::ApiAuthBasic<Claims = C> + Send + Sync,
+    A: apis::permissions::Permissions<E, Claims = C> + apis::ApiAuthBasic<Claims = C> + Send + Sync,
     E: std::fmt::Debug + Send + Sync + 'static,
 {
     // Authentication
@@ -4656,7 +4816,7 @@ where
     };
 
     #[allow(clippy::redundant_closure)]
-    let validation = tokio::task::spawn_blocking(move || recording_recording_stop_validation())
+    let validation = tokio::task::spawn_blocking(move || permissions_permissions_get_validation())
         .await
         .unwrap();
 
@@ -4669,14 +4829,14 @@ where
 
     let result = api_impl
         .as_ref()
-        .recording_recording_stop(&method, &host, &cookies, &claims)
+        .permissions_permissions_get(&method, &host, &cookies, &claims)
         .await;
 
     let mut response = Response::builder();
 
     let resp = match result {
                                             Ok(rsp) => match rsp {
-                                                apis::recording::RecordingRecordingStopResponse::Status200_CaptureStatusResult
+                                                apis::permissions::PermissionsPermissionsGetResponse::Status200_PermissionsResult
                                                     (body)
                                                 => {
                                                   let mut response = response.status(200);
@@ -4694,7 +4854,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::recording::RecordingRecordingStopResponse::Status400_EngineBadRequestErrorResponseBody
+                                                apis::permissions::PermissionsPermissionsGetResponse::Status400_EngineBadRequestErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(400);
@@ -4712,7 +4872,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::recording::RecordingRecordingStopResponse::Status401_EngineUnauthorizedErrorResponseBody
+                                                apis::permissions::PermissionsPermissionsGetResponse::Status401_EngineUnauthorizedErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(401);
@@ -4730,7 +4890,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::recording::RecordingRecordingStopResponse::Status403_EngineForbiddenErrorResponseBody
+                                                apis::permissions::PermissionsPermissionsGetResponse::Status403_EngineForbiddenErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(403);
@@ -4748,43 +4908,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::recording::RecordingRecordingStopResponse::Status409_EngineConflictErrorResponseBody
-                                                    (body)
-                                                => {
-                                                  let mut response = response.status(409);
-                                                  {
-                                                    let mut response_headers = response.headers_mut().unwrap();
-                                                    response_headers.insert(
-                                                        CONTENT_TYPE,
-                                                        HeaderValue::from_static("application/json"));
-                                                  }
-
-                                                  let body_content =  tokio::task::spawn_blocking(move ||
-                                                      serde_json::to_vec(&body).map_err(|e| {
-                                                        error!(error = ?e);
-                                                        StatusCode::INTERNAL_SERVER_ERROR
-                                                      })).await.unwrap()?;
-                                                  response.body(Body::from(body_content))
-                                                },
-                                                apis::recording::RecordingRecordingStopResponse::Status422_EngineUnprocessableErrorResponseBody
-                                                    (body)
-                                                => {
-                                                  let mut response = response.status(422);
-                                                  {
-                                                    let mut response_headers = response.headers_mut().unwrap();
-                                                    response_headers.insert(
-                                                        CONTENT_TYPE,
-                                                        HeaderValue::from_static("application/json"));
-                                                  }
-
-                                                  let body_content =  tokio::task::spawn_blocking(move ||
-                                                      serde_json::to_vec(&body).map_err(|e| {
-                                                        error!(error = ?e);
-                                                        StatusCode::INTERNAL_SERVER_ERROR
-                                                      })).await.unwrap()?;
-                                                  response.body(Body::from(body_content))
-                                                },
-                                                apis::recording::RecordingRecordingStopResponse::Status500_EngineRuntimeErrorResponseBody
+                                                apis::permissions::PermissionsPermissionsGetResponse::Status500_EngineRuntimeErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(500);
@@ -4817,12 +4941,13 @@ where
 }
 
 #[tracing::instrument(skip_all)]
-fn sources_sources_list_validation() -> std::result::Result<(), ValidationErrors> {
+fn permissions_permissions_open_input_monitoring_settings_validation()
+-> std::result::Result<(), ValidationErrors> {
     Ok(())
 }
-/// SourcesSourcesList - GET /v1/sources
+/// PermissionsPermissionsOpenInputMonitoringSettings - POST /v1/permissions/input-monitoring/open-settings
 #[tracing::instrument(skip_all)]
-async fn sources_sources_list<I, A, E, C>(
+async fn permissions_permissions_open_input_monitoring_settings<I, A, E, C>(
     method: Method,
     TypedHeader(host): TypedHeader<Host>,
     cookies: CookieJar,
@@ -4831,7 +4956,7 @@ async fn sources_sources_list<I, A, E, C>(
 ) -> Result<Response, StatusCode>
 where
     I: AsRef<A> + Send + Sync,
-    A: apis::sources::Sources<E, Claims = C> + apis::ApiAuthBasic<Claims = C> + Send + Sync,
+    A: apis::permissions::Permissions<E, Claims = C> + apis::ApiAuthBasic<Claims = C> + Send + Sync,
     E: std::fmt::Debug + Send + Sync + 'static,
 {
     // Authentication
@@ -4845,9 +4970,11 @@ where
     };
 
     #[allow(clippy::redundant_closure)]
-    let validation = tokio::task::spawn_blocking(move || sources_sources_list_validation())
-        .await
-        .unwrap();
+    let validation = tokio::task::spawn_blocking(move || {
+        permissions_permissions_open_input_monitoring_settings_validation()
+    })
+    .await
+    .unwrap();
 
     let Ok(()) = validation else {
         return Response::builder()
@@ -4858,14 +4985,14 @@ where
 
     let result = api_impl
         .as_ref()
-        .sources_sources_list(&method, &host, &cookies, &claims)
+        .permissions_permissions_open_input_monitoring_settings(&method, &host, &cookies, &claims)
         .await;
 
     let mut response = Response::builder();
 
     let resp = match result {
                                             Ok(rsp) => match rsp {
-                                                apis::sources::SourcesSourcesListResponse::Status200_SourcesResult
+                                                apis::permissions::PermissionsPermissionsOpenInputMonitoringSettingsResponse::Status200_ActionResult
                                                     (body)
                                                 => {
                                                   let mut response = response.status(200);
@@ -4883,7 +5010,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::sources::SourcesSourcesListResponse::Status400_EngineBadRequestErrorResponseBody
+                                                apis::permissions::PermissionsPermissionsOpenInputMonitoringSettingsResponse::Status400_EngineBadRequestErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(400);
@@ -4901,7 +5028,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::sources::SourcesSourcesListResponse::Status401_EngineUnauthorizedErrorResponseBody
+                                                apis::permissions::PermissionsPermissionsOpenInputMonitoringSettingsResponse::Status401_EngineUnauthorizedErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(401);
@@ -4919,7 +5046,7 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::sources::SourcesSourcesListResponse::Status403_EngineForbiddenErrorResponseBody
+                                                apis::permissions::PermissionsPermissionsOpenInputMonitoringSettingsResponse::Status403_EngineForbiddenErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(403);
@@ -4937,7 +5064,43 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::sources::SourcesSourcesListResponse::Status500_EngineRuntimeErrorResponseBody
+                                                apis::permissions::PermissionsPermissionsOpenInputMonitoringSettingsResponse::Status409_EngineConflictErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(409);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::permissions::PermissionsPermissionsOpenInputMonitoringSettingsResponse::Status422_EngineUnprocessableErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(422);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::permissions::PermissionsPermissionsOpenInputMonitoringSettingsResponse::Status500_EngineRuntimeErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(500);
@@ -4970,12 +5133,13 @@ where
 }
 
 #[tracing::instrument(skip_all)]
-fn system_engine_capabilities_validation() -> std::result::Result<(), ValidationErrors> {
+fn permissions_permissions_request_input_monitoring_validation()
+-> std::result::Result<(), ValidationErrors> {
     Ok(())
 }
-/// SystemEngineCapabilities - GET /v1/engine/capabilities
+/// PermissionsPermissionsRequestInputMonitoring - POST /v1/permissions/input-monitoring/request
 #[tracing::instrument(skip_all)]
-async fn system_engine_capabilities<I, A, E, C>(
+async fn permissions_permissions_request_input_monitoring<I, A, E, C>(
     method: Method,
     TypedHeader(host): TypedHeader<Host>,
     cookies: CookieJar,
@@ -4984,7 +5148,7 @@ async fn system_engine_capabilities<I, A, E, C>(
 ) -> Result<Response, StatusCode>
 where
     I: AsRef<A> + Send + Sync,
-    A: apis::system::System<E, Claims = C> + apis::ApiAuthBasic<Claims = C> + Send + Sync,
+    A: apis::permissions::Permissions<E, Claims = C> + apis::ApiAuthBasic<Claims = C> + Send + Sync,
     E: std::fmt::Debug + Send + Sync + 'static,
 {
     // Authentication
@@ -4998,11 +5162,13 @@ where
     };
 
     #[allow(clippy::redundant_closure)]
-    let validation = tokio::task::spawn_blocking(move || system_engine_capabilities_validation())
-        .await
-        .unwrap();
-
-    let Ok(()) = validation else {
+    let validation = tokio::task::spawn_blocking(move || {
+        permissions_permissions_request_input_monitoring_validation()
+    })
+    .await
+    .unwrap();
+
+    let Ok(()) = validation else {
         return Response::builder()
             .status(StatusCode::BAD_REQUEST)
             .body(Body::from(validation.unwrap_err().to_string()))
@@ -5011,14 +5177,14 @@ where
 
     let result = api_impl
         .as_ref()
-        .system_engine_capabilities(&method, &host, &cookies, &claims)
+        .permissions_permissions_request_input_monitoring(&method, &host, &cookies, &claims)
         .await;
 
     let mut response = Response::builder();
 
     let resp = match result {
                                             Ok(rsp) => match rsp {
-                                                apis::system::SystemEngineCapabilitiesResponse::Status200_CapabilitiesResult
+                                                apis::permissions::PermissionsPermissionsRequestInputMonitoringResponse::Status200_ActionResult
                                                     (body)
                                                 => {
                                                   let mut response = response.status(200);
@@ -5036,7 +5202,25 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::system::SystemEngineCapabilitiesResponse::Status401_EngineUnauthorizedErrorResponseBody
+                                                apis::permissions::PermissionsPermissionsRequestInputMonitoringResponse::Status400_EngineBadRequestErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(400);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::permissions::PermissionsPermissionsRequestInputMonitoringResponse::Status401_EngineUnauthorizedErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(401);
@@ -5054,7 +5238,61 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::system::SystemEngineCapabilitiesResponse::Status500_EngineRuntimeErrorResponseBody
+                                                apis::permissions::PermissionsPermissionsRequestInputMonitoringResponse::Status403_EngineForbiddenErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(403);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::permissions::PermissionsPermissionsRequestInputMonitoringResponse::Status409_EngineConflictErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(409);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::permissions::PermissionsPermissionsRequestInputMonitoringResponse::Status422_EngineUnprocessableErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(422);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::permissions::PermissionsPermissionsRequestInputMonitoringResponse::Status500_EngineRuntimeErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(500);
@@ -5087,12 +5325,13 @@ where
 }
 
 #[tracing::instrument(skip_all)]
-fn system_system_ping_validation() -> std::result::Result<(), ValidationErrors> {
+fn permissions_permissions_request_microphone_validation()
+-> std::result::Result<(), ValidationErrors> {
     Ok(())
 }
-/// SystemSystemPing - GET /v1/system/ping
+/// PermissionsPermissionsRequestMicrophone - POST /v1/permissions/microphone/request
 #[tracing::instrument(skip_all)]
-async fn system_system_ping<I, A, E, C>(
+async fn permissions_permissions_request_microphone<I, A, E, C>(
     method: Method,
     TypedHeader(host): TypedHeader<Host>,
     cookies: CookieJar,
@@ -5101,7 +5340,7 @@ async fn system_system_ping<I, A, E, C>(
 ) -> Result<Response, StatusCode>
 where
     I: AsRef<A> + Send + Sync,
-    A: apis::system::System<E, Claims = C> + apis::ApiAuthBasic<Claims = C> + Send + Sync,
+    A: apis::permissions::Permissions<E, Claims = C> + apis::ApiAuthBasic<Claims = C> + Send + Sync,
     E: std::fmt::Debug + Send + Sync + 'static,
 {
     // Authentication
@@ -5115,9 +5354,11 @@ where
     };
 
     #[allow(clippy::redundant_closure)]
-    let validation = tokio::task::spawn_blocking(move || system_system_ping_validation())
-        .await
-        .unwrap();
+    let validation = tokio::task::spawn_blocking(move || {
+        permissions_permissions_request_microphone_validation()
+    })
+    .await
+    .unwrap();
 
     let Ok(()) = validation else {
         return Response::builder()
@@ -5128,14 +5369,14 @@ where
 
     let result = api_impl
         .as_ref()
-        .system_system_ping(&method, &host, &cookies, &claims)
+        .permissions_permissions_request_microphone(&method, &host, &cookies, &claims)
         .await;
 
     let mut response = Response::builder();
 
     let resp = match result {
                                             Ok(rsp) => match rsp {
-                                                apis::system::SystemSystemPingResponse::Status200_PingResult
+                                                apis::permissions::PermissionsPermissionsRequestMicrophoneResponse::Status200_ActionResult
                                                     (body)
                                                 => {
                                                   let mut response = response.status(200);
@@ -5153,7 +5394,25 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::system::SystemSystemPingResponse::Status401_EngineUnauthorizedErrorResponseBody
+                                                apis::permissions::PermissionsPermissionsRequestMicrophoneResponse::Status400_EngineBadRequestErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(400);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::permissions::PermissionsPermissionsRequestMicrophoneResponse::Status401_EngineUnauthorizedErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(401);
@@ -5171,7 +5430,3759 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::system::SystemSystemPingResponse::Status500_EngineRuntimeErrorResponseBody
+                                                apis::permissions::PermissionsPermissionsRequestMicrophoneResponse::Status403_EngineForbiddenErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(403);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::permissions::PermissionsPermissionsRequestMicrophoneResponse::Status409_EngineConflictErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(409);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::permissions::PermissionsPermissionsRequestMicrophoneResponse::Status422_EngineUnprocessableErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(422);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::permissions::PermissionsPermissionsRequestMicrophoneResponse::Status500_EngineRuntimeErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(500);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                            },
+                                            Err(why) => {
+                                                    // Application code returned an error. This should not happen, as the implementation should
+                                                    // return a valid response.
+                                                    return api_impl.as_ref().handle_error(&method, &host, &cookies, why).await;
+                                            },
+                                        };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[tracing::instrument(skip_all)]
+fn permissions_permissions_request_screen_recording_validation()
+-> std::result::Result<(), ValidationErrors> {
+    Ok(())
+}
+/// PermissionsPermissionsRequestScreenRecording - POST /v1/permissions/screen-recording/request
+#[tracing::instrument(skip_all)]
+async fn permissions_permissions_request_screen_recording<I, A, E, C>(
+    method: Method,
+    TypedHeader(host): TypedHeader<Host>,
+    cookies: CookieJar,
+    headers: HeaderMap,
+    State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::permissions::Permissions<E, Claims = C> + apis::ApiAuthBasic<Claims = C> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+{
+    // Authentication
+    let claims_in_auth_header = api_impl
+        .as_ref()
+        .extract_claims_from_auth_header(apis::BasicAuthKind::Bearer, &headers, "authorization")
+        .await;
+    let claims = None.or(claims_in_auth_header);
+    let Some(claims) = claims else {
+        return response_with_status_code_only(StatusCode::UNAUTHORIZED);
+    };
+
+    #[allow(clippy::redundant_closure)]
+    let validation = tokio::task::spawn_blocking(move || {
+        permissions_permissions_request_screen_recording_validation()
+    })
+    .await
+    .unwrap();
+
+    let Ok(()) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl
+        .as_ref()
+        .permissions_permissions_request_screen_recording(&method, &host, &cookies, &claims)
+        .await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::permissions::PermissionsPermissionsRequestScreenRecordingResponse::Status200_ActionResult
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::permissions::PermissionsPermissionsRequestScreenRecordingResponse::Status400_EngineBadRequestErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(400);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::permissions::PermissionsPermissionsRequestScreenRecordingResponse::Status401_EngineUnauthorizedErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(401);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::permissions::PermissionsPermissionsRequestScreenRecordingResponse::Status403_EngineForbiddenErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(403);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::permissions::PermissionsPermissionsRequestScreenRecordingResponse::Status409_EngineConflictErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(409);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::permissions::PermissionsPermissionsRequestScreenRecordingResponse::Status422_EngineUnprocessableErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(422);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::permissions::PermissionsPermissionsRequestScreenRecordingResponse::Status500_EngineRuntimeErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(500);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                            },
+                                            Err(why) => {
+                                                    // Application code returned an error. This should not happen, as the implementation should
+                                                    // return a valid response.
+                                                    return api_impl.as_ref().handle_error(&method, &host, &cookies, why).await;
+                                            },
+                                        };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[tracing::instrument(skip_all)]
+fn project_project_current_validation() -> std::result::Result<(), ValidationErrors> {
+    Ok(())
+}
+/// ProjectProjectCurrent - GET /v1/project/current
+#[tracing::instrument(skip_all)]
+async fn project_project_current<I, A, E, C>(
+    method: Method,
+    TypedHeader(host): TypedHeader<Host>,
+    cookies: CookieJar,
+    headers: HeaderMap,
+    State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::project::Project<E, Claims = C> + apis::ApiAuthBasic<Claims = C> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+{
+    // Authentication
+    let claims_in_auth_header = api_impl
+        .as_ref()
+        .extract_claims_from_auth_header(apis::BasicAuthKind::Bearer, &headers, "authorization")
+        .await;
+    let claims = None.or(claims_in_auth_header);
+    let Some(claims) = claims else {
+        return response_with_status_code_only(StatusCode::UNAUTHORIZED);
+    };
+
+    #[allow(clippy::redundant_closure)]
+    let validation = tokio::task::spawn_blocking(move || project_project_current_validation())
+        .await
+        .unwrap();
+
+    let Ok(()) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl
+        .as_ref()
+        .project_project_current(&method, &host, &cookies, &claims)
+        .await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::project::ProjectProjectCurrentResponse::Status200_ProjectState
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::project::ProjectProjectCurrentResponse::Status400_EngineBadRequestErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(400);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::project::ProjectProjectCurrentResponse::Status401_EngineUnauthorizedErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(401);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::project::ProjectProjectCurrentResponse::Status403_EngineForbiddenErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(403);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::project::ProjectProjectCurrentResponse::Status500_EngineRuntimeErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(500);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                            },
+                                            Err(why) => {
+                                                    // Application code returned an error. This should not happen, as the implementation should
+                                                    // return a valid response.
+                                                    return api_impl.as_ref().handle_error(&method, &host, &cookies, why).await;
+                                            },
+                                        };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[derive(validator::Validate)]
+#[allow(dead_code)]
+struct ProjectProjectOpenBodyValidator<'a> {
+    #[validate(nested)]
+    body: &'a models::ProjectOpenPayload,
+}
+
+#[tracing::instrument(skip_all)]
+fn project_project_open_validation(
+    body: models::ProjectOpenPayload,
+) -> std::result::Result<(models::ProjectOpenPayload,), ValidationErrors> {
+    let b = ProjectProjectOpenBodyValidator { body: &body };
+    b.validate()?;
+
+    Ok((body,))
+}
+/// ProjectProjectOpen - POST /v1/project/open
+#[tracing::instrument(skip_all)]
+async fn project_project_open<I, A, E, C>(
+    method: Method,
+    TypedHeader(host): TypedHeader<Host>,
+    cookies: CookieJar,
+    headers: HeaderMap,
+    State(api_impl): State<I>,
+    Json(body): Json<models::ProjectOpenPayload>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::project::Project<E, Claims = C> + apis::ApiAuthBasic<Claims = C> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+{
+    // Authentication
+    let claims_in_auth_header = api_impl
+        .as_ref()
+        .extract_claims_from_auth_header(apis::BasicAuthKind::Bearer, &headers, "authorization")
+        .await;
+    let claims = None.or(claims_in_auth_header);
+    let Some(claims) = claims else {
+        return response_with_status_code_only(StatusCode::UNAUTHORIZED);
+    };
+
+    #[allow(clippy::redundant_closure)]
+    let validation = tokio::task::spawn_blocking(move || project_project_open_validation(body))
+        .await
+        .unwrap();
+
+    let Ok((body,)) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl
+        .as_ref()
+        .project_project_open(&method, &host, &cookies, &claims, &body)
+        .await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::project::ProjectProjectOpenResponse::Status200_ProjectState
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::project::ProjectProjectOpenResponse::Status400_EngineBadRequestErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(400);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::project::ProjectProjectOpenResponse::Status401_EngineUnauthorizedErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(401);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::project::ProjectProjectOpenResponse::Status403_EngineForbiddenErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(403);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::project::ProjectProjectOpenResponse::Status409_EngineConflictErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(409);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::project::ProjectProjectOpenResponse::Status422_EngineUnprocessableErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(422);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::project::ProjectProjectOpenResponse::Status500_EngineRuntimeErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(500);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                            },
+                                            Err(why) => {
+                                                    // Application code returned an error. This should not happen, as the implementation should
+                                                    // return a valid response.
+                                                    return api_impl.as_ref().handle_error(&method, &host, &cookies, why).await;
+                                            },
+                                        };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[tracing::instrument(skip_all)]
+fn project_project_recents_validation(
+    query_params: models::ProjectProjectRecentsQueryParams,
+) -> std::result::Result<(models::ProjectProjectRecentsQueryParams,), ValidationErrors> {
+    query_params.validate()?;
+
+    Ok((query_params,))
+}
+/// ProjectProjectRecents - GET /v1/project/recents
+#[tracing::instrument(skip_all)]
+async fn project_project_recents<I, A, E, C>(
+    method: Method,
+    TypedHeader(host): TypedHeader<Host>,
+    cookies: CookieJar,
+    headers: HeaderMap,
+    QueryExtra(query_params): QueryExtra<models::ProjectProjectRecentsQueryParams>,
+    State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::project::Project<E, Claims = C> + apis::ApiAuthBasic<Claims = C> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+{
+    // Authentication
+    let claims_in_auth_header = api_impl
+        .as_ref()
+        .extract_claims_from_auth_header(apis::BasicAuthKind::Bearer, &headers, "authorization")
+        .await;
+    let claims = None.or(claims_in_auth_header);
+    let Some(claims) = claims else {
+        return response_with_status_code_only(StatusCode::UNAUTHORIZED);
+    };
+
+    #[allow(clippy::redundant_closure)]
+    let validation =
+        tokio::task::spawn_blocking(move || project_project_recents_validation(query_params))
+            .await
+            .unwrap();
+
+    let Ok((query_params,)) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl
+        .as_ref()
+        .project_project_recents(&method, &host, &cookies, &claims, &query_params)
+        .await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::project::ProjectProjectRecentsResponse::Status200_ProjectRecentsResult
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::project::ProjectProjectRecentsResponse::Status400_EngineBadRequestErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(400);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::project::ProjectProjectRecentsResponse::Status401_EngineUnauthorizedErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(401);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::project::ProjectProjectRecentsResponse::Status403_EngineForbiddenErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(403);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::project::ProjectProjectRecentsResponse::Status500_EngineRuntimeErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(500);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                            },
+                                            Err(why) => {
+                                                    // Application code returned an error. This should not happen, as the implementation should
+                                                    // return a valid response.
+                                                    return api_impl.as_ref().handle_error(&method, &host, &cookies, why).await;
+                                            },
+                                        };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[derive(validator::Validate)]
+#[allow(dead_code)]
+struct ProjectProjectSaveBodyValidator<'a> {
+    #[validate(nested)]
+    body: &'a models::ProjectSavePayload,
+}
+
+#[tracing::instrument(skip_all)]
+fn project_project_save_validation(
+    body: models::ProjectSavePayload,
+) -> std::result::Result<(models::ProjectSavePayload,), ValidationErrors> {
+    let b = ProjectProjectSaveBodyValidator { body: &body };
+    b.validate()?;
+
+    Ok((body,))
+}
+/// ProjectProjectSave - POST /v1/project/save
+#[tracing::instrument(skip_all)]
+async fn project_project_save<I, A, E, C>(
+    method: Method,
+    TypedHeader(host): TypedHeader<Host>,
+    cookies: CookieJar,
+    headers: HeaderMap,
+    State(api_impl): State<I>,
+    Json(body): Json<models::ProjectSavePayload>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::project::Project<E, Claims = C> + apis::ApiAuthBasic<Claims = C> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+{
+    // Authentication
+    let claims_in_auth_header = api_impl
+        .as_ref()
+        .extract_claims_from_auth_header(apis::BasicAuthKind::Bearer, &headers, "authorization")
+        .await;
+    let claims = None.or(claims_in_auth_header);
+    let Some(claims) = claims else {
+        return response_with_status_code_only(StatusCode::UNAUTHORIZED);
+    };
+
+    #[allow(clippy::redundant_closure)]
+    let validation = tokio::task::spawn_blocking(move || project_project_save_validation(body))
+        .await
+        .unwrap();
+
+    let Ok((body,)) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl
+        .as_ref()
+        .project_project_save(&method, &host, &cookies, &claims, &body)
+        .await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::project::ProjectProjectSaveResponse::Status200_ProjectState
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::project::ProjectProjectSaveResponse::Status400_EngineBadRequestErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(400);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::project::ProjectProjectSaveResponse::Status401_EngineUnauthorizedErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(401);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::project::ProjectProjectSaveResponse::Status403_EngineForbiddenErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(403);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::project::ProjectProjectSaveResponse::Status409_EngineConflictErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(409);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::project::ProjectProjectSaveResponse::Status422_EngineUnprocessableErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(422);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::project::ProjectProjectSaveResponse::Status500_EngineRuntimeErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(500);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                            },
+                                            Err(why) => {
+                                                    // Application code returned an error. This should not happen, as the implementation should
+                                                    // return a valid response.
+                                                    return api_impl.as_ref().handle_error(&method, &host, &cookies, why).await;
+                                            },
+                                        };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[derive(validator::Validate)]
+#[allow(dead_code)]
+struct RecordingRecordingAddMarkerBodyValidator<'a> {
+    #[validate(nested)]
+    body: &'a models::RecordingAddMarkerPayload,
+}
+
+#[tracing::instrument(skip_all)]
+fn recording_recording_add_marker_validation(
+    body: models::RecordingAddMarkerPayload,
+) -> std::result::Result<(models::RecordingAddMarkerPayload,), ValidationErrors> {
+    let b = RecordingRecordingAddMarkerBodyValidator { body: &body };
+    b.validate()?;
+
+    Ok((body,))
+}
+/// RecordingRecordingAddMarker - POST /v1/recording/markers
+#[tracing::instrument(skip_all)]
+async fn recording_recording_add_marker<I, A, E, C>(
+    method: Method,
+    TypedHeader(host): TypedHeader<Host>,
+    cookies: CookieJar,
+    headers: HeaderMap,
+    State(api_impl): State<I>,
+    Json(body): Json<models::RecordingAddMarkerPayload>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::recording::Recording<E, Claims = C> + apis::ApiAuthBasic<Claims = C> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+{
+    // Authentication
+    let claims_in_auth_header = api_impl
+        .as_ref()
+        .extract_claims_from_auth_header(apis::BasicAuthKind::Bearer, &headers, "authorization")
+        .await;
+    let claims = None.or(claims_in_auth_header);
+    let Some(claims) = claims else {
+        return response_with_status_code_only(StatusCode::UNAUTHORIZED);
+    };
+
+    #[allow(clippy::redundant_closure)]
+    let validation =
+        tokio::task::spawn_blocking(move || recording_recording_add_marker_validation(body))
+            .await
+            .unwrap();
+
+    let Ok((body,)) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl
+        .as_ref()
+        .recording_recording_add_marker(&method, &host, &cookies, &claims, &body)
+        .await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::recording::RecordingRecordingAddMarkerResponse::Status200_RecordingAddMarkerResult
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::recording::RecordingRecordingAddMarkerResponse::Status400_EngineBadRequestErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(400);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::recording::RecordingRecordingAddMarkerResponse::Status401_EngineUnauthorizedErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(401);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::recording::RecordingRecordingAddMarkerResponse::Status403_EngineForbiddenErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(403);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::recording::RecordingRecordingAddMarkerResponse::Status409_EngineConflictErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(409);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::recording::RecordingRecordingAddMarkerResponse::Status422_EngineUnprocessableErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(422);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::recording::RecordingRecordingAddMarkerResponse::Status500_EngineRuntimeErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(500);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                            },
+                                            Err(why) => {
+                                                    // Application code returned an error. This should not happen, as the implementation should
+                                                    // return a valid response.
+                                                    return api_impl.as_ref().handle_error(&method, &host, &cookies, why).await;
+                                            },
+                                        };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[tracing::instrument(skip_all)]
+fn recording_recording_pause_validation() -> std::result::Result<(), ValidationErrors> {
+    Ok(())
+}
+/// RecordingRecordingPause - POST /v1/recording/pause
+#[tracing::instrument(skip_all)]
+async fn recording_recording_pause<I, A, E, C>(
+    method: Method,
+    TypedHeader(host): TypedHeader<Host>,
+    cookies: CookieJar,
+    headers: HeaderMap,
+    State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::recording::Recording<E, Claims = C> + apis::ApiAuthBasic<Claims = C> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+{
+    // Authentication
+    let claims_in_auth_header = api_impl
+        .as_ref()
+        .extract_claims_from_auth_header(apis::BasicAuthKind::Bearer, &headers, "authorization")
+        .await;
+    let claims = None.or(claims_in_auth_header);
+    let Some(claims) = claims else {
+        return response_with_status_code_only(StatusCode::UNAUTHORIZED);
+    };
+
+    #[allow(clippy::redundant_closure)]
+    let validation = tokio::task::spawn_blocking(move || recording_recording_pause_validation())
+        .await
+        .unwrap();
+
+    let Ok(()) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl
+        .as_ref()
+        .recording_recording_pause(&method, &host, &cookies, &claims)
+        .await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::recording::RecordingRecordingPauseResponse::Status200_CaptureStatusResult
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::recording::RecordingRecordingPauseResponse::Status400_EngineBadRequestErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(400);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::recording::RecordingRecordingPauseResponse::Status401_EngineUnauthorizedErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(401);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::recording::RecordingRecordingPauseResponse::Status403_EngineForbiddenErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(403);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::recording::RecordingRecordingPauseResponse::Status409_EngineConflictErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(409);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::recording::RecordingRecordingPauseResponse::Status422_EngineUnprocessableErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(422);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::recording::RecordingRecordingPauseResponse::Status500_EngineRuntimeErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(500);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                            },
+                                            Err(why) => {
+                                                    // Application code returned an error. This should not happen, as the implementation should
+                                                    // return a valid response.
+                                                    return api_impl.as_ref().handle_error(&method, &host, &cookies, why).await;
+                                            },
+                                        };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[tracing::instrument(skip_all)]
+fn recording_recording_recover_validation() -> std::result::Result<(), ValidationErrors> {
+    Ok(())
+}
+/// RecordingRecordingRecover - POST /v1/recording/recover
+#[tracing::instrument(skip_all)]
+async fn recording_recording_recover<I, A, E, C>(
+    method: Method,
+    TypedHeader(host): TypedHeader<Host>,
+    cookies: CookieJar,
+    headers: HeaderMap,
+    State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::recording::Recording<E, Claims = C> + apis::ApiAuthBasic<Claims = C> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+{
+    // Authentication
+    let claims_in_auth_header = api_impl
+        .as_ref()
+        .extract_claims_from_auth_header(apis::BasicAuthKind::Bearer, &headers, "authorization")
+        .await;
+    let claims = None.or(claims_in_auth_header);
+    let Some(claims) = claims else {
+        return response_with_status_code_only(StatusCode::UNAUTHORIZED);
+    };
+
+    #[allow(clippy::redundant_closure)]
+    let validation = tokio::task::spawn_blocking(move || recording_recording_recover_validation())
+        .await
+        .unwrap();
+
+    let Ok(()) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl
+        .as_ref()
+        .recording_recording_recover(&method, &host, &cookies, &claims)
+        .await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::recording::RecordingRecordingRecoverResponse::Status200_RecordingRecoverResult
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::recording::RecordingRecordingRecoverResponse::Status400_EngineBadRequestErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(400);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::recording::RecordingRecordingRecoverResponse::Status401_EngineUnauthorizedErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(401);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::recording::RecordingRecordingRecoverResponse::Status403_EngineForbiddenErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(403);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::recording::RecordingRecordingRecoverResponse::Status409_EngineConflictErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(409);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::recording::RecordingRecordingRecoverResponse::Status422_EngineUnprocessableErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(422);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::recording::RecordingRecordingRecoverResponse::Status500_EngineRuntimeErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(500);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                            },
+                                            Err(why) => {
+                                                    // Application code returned an error. This should not happen, as the implementation should
+                                                    // return a valid response.
+                                                    return api_impl.as_ref().handle_error(&method, &host, &cookies, why).await;
+                                            },
+                                        };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[tracing::instrument(skip_all)]
+fn recording_recording_resume_validation() -> std::result::Result<(), ValidationErrors> {
+    Ok(())
+}
+/// RecordingRecordingResume - POST /v1/recording/resume
+#[tracing::instrument(skip_all)]
+async fn recording_recording_resume<I, A, E, C>(
+    method: Method,
+    TypedHeader(host): TypedHeader<Host>,
+    cookies: CookieJar,
+    headers: HeaderMap,
+    State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::recording::Recording<E, Claims = C> + apis::ApiAuthBasic<Claims = C> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+{
+    // Authentication
+    let claims_in_auth_header = api_impl
+        .as_ref()
+        .extract_claims_from_auth_header(apis::BasicAuthKind::Bearer, &headers, "authorization")
+        .await;
+    let claims = None.or(claims_in_auth_header);
+    let Some(claims) = claims else {
+        return response_with_status_code_only(StatusCode::UNAUTHORIZED);
+    };
+
+    #[allow(clippy::redundant_closure)]
+    let validation = tokio::task::spawn_blocking(move || recording_recording_resume_validation())
+        .await
+        .unwrap();
+
+    let Ok(()) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl
+        .as_ref()
+        .recording_recording_resume(&method, &host, &cookies, &claims)
+        .await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::recording::RecordingRecordingResumeResponse::Status200_CaptureStatusResult
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::recording::RecordingRecordingResumeResponse::Status400_EngineBadRequestErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(400);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::recording::RecordingRecordingResumeResponse::Status401_EngineUnauthorizedErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(401);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::recording::RecordingRecordingResumeResponse::Status403_EngineForbiddenErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(403);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::recording::RecordingRecordingResumeResponse::Status409_EngineConflictErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(409);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::recording::RecordingRecordingResumeResponse::Status422_EngineUnprocessableErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(422);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::recording::RecordingRecordingResumeResponse::Status500_EngineRuntimeErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(500);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                            },
+                                            Err(why) => {
+                                                    // Application code returned an error. This should not happen, as the implementation should
+                                                    // return a valid response.
+                                                    return api_impl.as_ref().handle_error(&method, &host, &cookies, why).await;
+                                            },
+                                        };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[derive(validator::Validate)]
+#[allow(dead_code)]
+struct RecordingRecordingStartBodyValidator<'a> {
+    #[validate(nested)]
+    body: &'a models::RecordingStartPayload,
+}
+
+#[tracing::instrument(skip_all)]
+fn recording_recording_start_validation(
+    body: models::RecordingStartPayload,
+) -> std::result::Result<(models::RecordingStartPayload,), ValidationErrors> {
+    let b = RecordingRecordingStartBodyValidator { body: &body };
+    b.validate()?;
+
+    Ok((body,))
+}
+/// RecordingRecordingStart - POST /v1/recording/start
+#[tracing::instrument(skip_all)]
+async fn recording_recording_start<I, A, E, C>(
+    method: Method,
+    TypedHeader(host): TypedHeader<Host>,
+    cookies: CookieJar,
+    headers: HeaderMap,
+    State(api_impl): State<I>,
+    Json(body): Json<models::RecordingStartPayload>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::recording::Recording<E, Claims = C> + apis::ApiAuthBasic<Claims = C> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+{
+    // Authentication
+    let claims_in_auth_header = api_impl
+        .as_ref()
+        .extract_claims_from_auth_header(apis::BasicAuthKind::Bearer, &headers, "authorization")
+        .await;
+    let claims = None.or(claims_in_auth_header);
+    let Some(claims) = claims else {
+        return response_with_status_code_only(StatusCode::UNAUTHORIZED);
+    };
+
+    #[allow(clippy::redundant_closure)]
+    let validation =
+        tokio::task::spawn_blocking(move || recording_recording_start_validation(body))
+            .await
+            .unwrap();
+
+    let Ok((body,)) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl
+        .as_ref()
+        .recording_recording_start(&method, &host, &cookies, &claims, &body)
+        .await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::recording::RecordingRecordingStartResponse::Status200_CaptureStatusResult
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::recording::RecordingRecordingStartResponse::Status400_EngineBadRequestErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(400);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::recording::RecordingRecordingStartResponse::Status401_EngineUnauthorizedErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(401);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::recording::RecordingRecordingStartResponse::Status403_EngineForbiddenErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(403);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::recording::RecordingRecordingStartResponse::Status409_EngineConflictErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(409);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::recording::RecordingRecordingStartResponse::Status422_EngineUnprocessableErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(422);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::recording::RecordingRecordingStartResponse::Status500_EngineRuntimeErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(500);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                            },
+                                            Err(why) => {
+                                                    // Application code returned an error. This should not happen, as the implementation should
+                                                    // return a valid response.
+                                                    return api_impl.as_ref().handle_error(&method, &host, &cookies, why).await;
+                                            },
+                                        };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[tracing::instrument(skip_all)]
+fn recording_recording_stop_validation() -> std::result::Result<(), ValidationErrors> {
+    Ok(())
+}
+/// RecordingRecordingStop - POST /v1/recording/stop
+#[tracing::instrument(skip_all)]
+async fn recording_recording_stop<I, A, E, C>(
+    method: Method,
+    TypedHeader(host): TypedHeader<Host>,
+    cookies: CookieJar,
+    headers: HeaderMap,
+    State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::recording::Recording<E, Claims = C> + apis::ApiAuthBasic<Claims = C> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+{
+    // Authentication
+    let claims_in_auth_header = api_impl
+        .as_ref()
+        .extract_claims_from_auth_header(apis::BasicAuthKind::Bearer, &headers, "authorization")
+        .await;
+    let claims = None.or(claims_in_auth_header);
+    let Some(claims) = claims else {
+        return response_with_status_code_only(StatusCode::UNAUTHORIZED);
+    };
+
+    #[allow(clippy::redundant_closure)]
+    let validation = tokio::task::spawn_blocking(move || recording_recording_stop_validation())
+        .await
+        .unwrap();
+
+    let Ok(()) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl
+        .as_ref()
+        .recording_recording_stop(&method, &host, &cookies, &claims)
+        .await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::recording::RecordingRecordingStopResponse::Status200_CaptureStatusResult
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::recording::RecordingRecordingStopResponse::Status400_EngineBadRequestErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(400);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::recording::RecordingRecordingStopResponse::Status401_EngineUnauthorizedErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(401);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::recording::RecordingRecordingStopResponse::Status403_EngineForbiddenErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(403);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::recording::RecordingRecordingStopResponse::Status409_EngineConflictErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(409);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::recording::RecordingRecordingStopResponse::Status422_EngineUnprocessableErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(422);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::recording::RecordingRecordingStopResponse::Status500_EngineRuntimeErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(500);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                            },
+                                            Err(why) => {
+                                                    // Application code returned an error. This should not happen, as the implementation should
+                                                    // return a valid response.
+                                                    return api_impl.as_ref().handle_error(&method, &host, &cookies, why).await;
+                                            },
+                                        };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[tracing::instrument(skip_all)]
+fn sources_sources_list_validation() -> std::result::Result<(), ValidationErrors> {
+    Ok(())
+}
+/// SourcesSourcesList - GET /v1/sources
+#[tracing::instrument(skip_all)]
+async fn sources_sources_list<I, A, E, C>(
+    method: Method,
+    TypedHeader(host): TypedHeader<Host>,
+    cookies: CookieJar,
+    headers: HeaderMap,
+    State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::sources::Sources<E, Claims = C> + apis::ApiAuthBasic<Claims = C> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+{
+    // Authentication
+    let claims_in_auth_header = api_impl
+        .as_ref()
+        .extract_claims_from_auth_header(apis::BasicAuthKind::Bearer, &headers, "authorization")
+        .await;
+    let claims = None.or(claims_in_auth_header);
+    let Some(claims) = claims else {
+        return response_with_status_code_only(StatusCode::UNAUTHORIZED);
+    };
+
+    #[allow(clippy::redundant_closure)]
+    let validation = tokio::task::spawn_blocking(move || sources_sources_list_validation())
+        .await
+        .unwrap();
+
+    let Ok(()) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl
+        .as_ref()
+        .sources_sources_list(&method, &host, &cookies, &claims)
+        .await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::sources::SourcesSourcesListResponse::Status200_SourcesResult
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::sources::SourcesSourcesListResponse::Status400_EngineBadRequestErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(400);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::sources::SourcesSourcesListResponse::Status401_EngineUnauthorizedErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(401);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::sources::SourcesSourcesListResponse::Status403_EngineForbiddenErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(403);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::sources::SourcesSourcesListResponse::Status500_EngineRuntimeErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(500);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                            },
+                                            Err(why) => {
+                                                    // Application code returned an error. This should not happen, as the implementation should
+                                                    // return a valid response.
+                                                    return api_impl.as_ref().handle_error(&method, &host, &cookies, why).await;
+                                            },
+                                        };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[tracing::instrument(skip_all)]
+fn system_engine_capabilities_validation() -> std::result::Result<(), ValidationErrors> {
+    Ok(())
+}
+/// SystemEngineCapabilities - GET /v1/engine/capabilities
+#[tracing::instrument(skip_all)]
+async fn system_engine_capabilities<I, A, E, C>(
+    method: Method,
+    TypedHeader(host): TypedHeader<Host>,
+    cookies: CookieJar,
+    headers: HeaderMap,
+    State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::system::System<E, Claims = C> + apis::ApiAuthBasic<Claims = C> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+{
+    // Authentication
+    let claims_in_auth_header = api_impl
+        .as_ref()
+        .extract_claims_from_auth_header(apis::BasicAuthKind::Bearer, &headers, "authorization")
+        .await;
+    let claims = None.or(claims_in_auth_header);
+    let Some(claims) = claims else {
+        return response_with_status_code_only(StatusCode::UNAUTHORIZED);
+    };
+
+    #[allow(clippy::redundant_closure)]
+    let validation = tokio::task::spawn_blocking(move || system_engine_capabilities_validation())
+        .await
+        .unwrap();
+
+    let Ok(()) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl
+        .as_ref()
+        .system_engine_capabilities(&method, &host, &cookies, &claims)
+        .await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::system::SystemEngineCapabilitiesResponse::Status200_CapabilitiesResult
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::system::SystemEngineCapabilitiesResponse::Status401_EngineUnauthorizedErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(401);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::system::SystemEngineCapabilitiesResponse::Status500_EngineRuntimeErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(500);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                            },
+                                            Err(why) => {
+                                                    // Application code returned an error. This should not happen, as the implementation should
+                                                    // return a valid response.
+                                                    return api_impl.as_ref().handle_error(&method, &host, &cookies, why).await;
+                                            },
+                                        };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[tracing::instrument(skip_all)]
+fn system_system_ping_validation() -> std::result::Result<(), ValidationErrors> {
+    Ok(())
+}
+/// SystemSystemPing - GET /v1/system/ping
+#[tracing::instrument(skip_all)]
+async fn system_system_ping<I, A, E, C>(
+    method: Method,
+    TypedHeader(host): TypedHeader<Host>,
+    cookies: CookieJar,
+    headers: HeaderMap,
+    State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::system::System<E, Claims = C> + apis::ApiAuthBasic<Claims = C> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+{
+    // Authentication
+    let claims_in_auth_header = api_impl
+        .as_ref()
+        .extract_claims_from_auth_header(apis::BasicAuthKind::Bearer, &headers, "authorization")
+        .await;
+    let claims = None.or(claims_in_auth_header);
+    let Some(claims) = claims else {
+        return response_with_status_code_only(StatusCode::UNAUTHORIZED);
+    };
+
+    #[allow(clippy::redundant_closure)]
+    let validation = tokio::task::spawn_blocking(move || system_system_ping_validation())
+        .await
+        .unwrap();
+
+    let Ok(()) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl
+        .as_ref()
+        .system_system_ping(&method, &host, &cookies, &claims)
+        .await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::system::SystemSystemPingResponse::Status200_PingResult
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::system::SystemSystemPingResponse::Status401_EngineUnauthorizedErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(401);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::system::SystemSystemPingResponse::Status500_EngineRuntimeErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(500);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                            },
+                                            Err(why) => {
+                                                    // Application code returned an error. This should not happen, as the implementation should
+                                                    // return a valid response.
+                                                    return api_impl.as_ref().handle_error(&method, &host, &cookies, why).await;
+                                            },
+                                        };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[tracing::instrument(skip_all)]
+fn takes_takes_activate_validation(
+    path_params: models::TakesTakesActivatePathParams,
+) -> std::result::Result<(models::TakesTakesActivatePathParams,), ValidationErrors> {
+    path_params.validate()?;
+
+    Ok((path_params,))
+}
+/// TakesTakesActivate - POST /v1/takes/{takeId}/activate
+#[tracing::instrument(skip_all)]
+async fn takes_takes_activate<I, A, E, C>(
+    method: Method,
+    TypedHeader(host): TypedHeader<Host>,
+    cookies: CookieJar,
+    headers: HeaderMap,
+    Path(path_params): Path<models::TakesTakesActivatePathParams>,
+    State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::takes::Takes<E, Claims = C> + apis::ApiAuthBasic<Claims = C> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+{
+    // Authentication
+    let claims_in_auth_header = api_impl
+        .as_ref()
+        .extract_claims_from_auth_header(apis::BasicAuthKind::Bearer, &headers, "authorization")
+        .await;
+    let claims = None.or(claims_in_auth_header);
+    let Some(claims) = claims else {
+        return response_with_status_code_only(StatusCode::UNAUTHORIZED);
+    };
+
+    #[allow(clippy::redundant_closure)]
+    let validation =
+        tokio::task::spawn_blocking(move || takes_takes_activate_validation(path_params))
+            .await
+            .unwrap();
+
+    let Ok((path_params,)) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl
+        .as_ref()
+        .takes_takes_activate(&method, &host, &cookies, &claims, &path_params)
+        .await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::takes::TakesTakesActivateResponse::Status200_TakesResult
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::takes::TakesTakesActivateResponse::Status400_EngineBadRequestErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(400);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::takes::TakesTakesActivateResponse::Status401_EngineUnauthorizedErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(401);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::takes::TakesTakesActivateResponse::Status403_EngineForbiddenErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(403);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::takes::TakesTakesActivateResponse::Status404_EngineNotFoundErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(404);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::takes::TakesTakesActivateResponse::Status409_EngineConflictErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(409);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::takes::TakesTakesActivateResponse::Status422_EngineUnprocessableErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(422);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::takes::TakesTakesActivateResponse::Status500_EngineRuntimeErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(500);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                            },
+                                            Err(why) => {
+                                                    // Application code returned an error. This should not happen, as the implementation should
+                                                    // return a valid response.
+                                                    return api_impl.as_ref().handle_error(&method, &host, &cookies, why).await;
+                                            },
+                                        };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[derive(validator::Validate)]
+#[allow(dead_code)]
+struct TakesTakesDeleteBodyValidator<'a> {
+    #[validate(nested)]
+    body: &'a models::TakesDeletePayload,
+}
+
+#[tracing::instrument(skip_all)]
+fn takes_takes_delete_validation(
+    path_params: models::TakesTakesDeletePathParams,
+    body: models::TakesDeletePayload,
+) -> std::result::Result<
+    (
+        models::TakesTakesDeletePathParams,
+        models::TakesDeletePayload,
+    ),
+    ValidationErrors,
+> {
+    path_params.validate()?;
+    let b = TakesTakesDeleteBodyValidator { body: &body };
+    b.validate()?;
+
+    Ok((path_params, body))
+}
+/// TakesTakesDelete - POST /v1/takes/{takeId}/delete
+#[tracing::instrument(skip_all)]
+async fn takes_takes_delete<I, A, E, C>(
+    method: Method,
+    TypedHeader(host): TypedHeader<Host>,
+    cookies: CookieJar,
+    headers: HeaderMap,
+    Path(path_params): Path<models::TakesTakesDeletePathParams>,
+    State(api_impl): State<I>,
+    Json(body): Json<models::TakesDeletePayload>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::takes::Takes<E, Claims = C> + apis::ApiAuthBasic<Claims = C> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+{
+    // Authentication
+    let claims_in_auth_header = api_impl
+        .as_ref()
+        .extract_claims_from_auth_header(apis::BasicAuthKind::Bearer, &headers, "authorization")
+        .await;
+    let claims = None.or(claims_in_auth_header);
+    let Some(claims) = claims else {
+        return response_with_status_code_only(StatusCode::UNAUTHORIZED);
+    };
+
+    #[allow(clippy::redundant_closure)]
+    let validation =
+        tokio::task::spawn_blocking(move || takes_takes_delete_validation(path_params, body))
+            .await
+            .unwrap();
+
+    let Ok((path_params, body)) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl
+        .as_ref()
+        .takes_takes_delete(&method, &host, &cookies, &claims, &path_params, &body)
+        .await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::takes::TakesTakesDeleteResponse::Status200_TakesResult
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::takes::TakesTakesDeleteResponse::Status400_EngineBadRequestErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(400);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::takes::TakesTakesDeleteResponse::Status401_EngineUnauthorizedErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(401);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::takes::TakesTakesDeleteResponse::Status403_EngineForbiddenErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(403);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::takes::TakesTakesDeleteResponse::Status404_EngineNotFoundErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(404);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::takes::TakesTakesDeleteResponse::Status409_EngineConflictErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(409);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::takes::TakesTakesDeleteResponse::Status422_EngineUnprocessableErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(422);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::takes::TakesTakesDeleteResponse::Status500_EngineRuntimeErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(500);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                            },
+                                            Err(why) => {
+                                                    // Application code returned an error. This should not happen, as the implementation should
+                                                    // return a valid response.
+                                                    return api_impl.as_ref().handle_error(&method, &host, &cookies, why).await;
+                                            },
+                                        };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[tracing::instrument(skip_all)]
+fn takes_takes_list_validation() -> std::result::Result<(), ValidationErrors> {
+    Ok(())
+}
+/// TakesTakesList - GET /v1/takes
+#[tracing::instrument(skip_all)]
+async fn takes_takes_list<I, A, E, C>(
+    method: Method,
+    TypedHeader(host): TypedHeader<Host>,
+    cookies: CookieJar,
+    headers: HeaderMap,
+    State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::takes::Takes<E, Claims = C> + apis::ApiAuthBasic<Claims = C> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+{
+    // Authentication
+    let claims_in_auth_header = api_impl
+        .as_ref()
+        .extract_claims_from_auth_header(apis::BasicAuthKind::Bearer, &headers, "authorization")
+        .await;
+    let claims = None.or(claims_in_auth_header);
+    let Some(claims) = claims else {
+        return response_with_status_code_only(StatusCode::UNAUTHORIZED);
+    };
+
+    #[allow(clippy::redundant_closure)]
+    let validation = tokio::task::spawn_blocking(move || takes_takes_list_validation())
+        .await
+        .unwrap();
+
+    let Ok(()) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl
+        .as_ref()
+        .takes_takes_list(&method, &host, &cookies, &claims)
+        .await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+        Ok(rsp) => match rsp {
+            apis::takes::TakesTakesListResponse::Status200_TakesResult(body) => {
+                let mut response = response.status(200);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers
+                        .insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::takes::TakesTakesListResponse::Status400_EngineBadRequestErrorResponseBody(
+                body,
+            ) => {
+                let mut response = response.status(400);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers
+                        .insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::takes::TakesTakesListResponse::Status401_EngineUnauthorizedErrorResponseBody(
+                body,
+            ) => {
+                let mut response = response.status(401);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers
+                        .insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::takes::TakesTakesListResponse::Status403_EngineForbiddenErrorResponseBody(
+                body,
+            ) => {
+                let mut response = response.status(403);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers
+                        .insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::takes::TakesTakesListResponse::Status500_EngineRuntimeErrorResponseBody(body) => {
+                let mut response = response.status(500);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers
+                        .insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+        },
+        Err(why) => {
+            // Application code returned an error. This should not happen, as the implementation should
+            // return a valid response.
+            return api_impl
+                .as_ref()
+                .handle_error(&method, &host, &cookies, why)
+                .await;
+        }
+    };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[derive(validator::Validate)]
+#[allow(dead_code)]
+struct TakesTakesRenameBodyValidator<'a> {
+    #[validate(nested)]
+    body: &'a models::TakesRenamePayload,
+}
+
+#[tracing::instrument(skip_all)]
+fn takes_takes_rename_validation(
+    path_params: models::TakesTakesRenamePathParams,
+    body: models::TakesRenamePayload,
+) -> std::result::Result<
+    (
+        models::TakesTakesRenamePathParams,
+        models::TakesRenamePayload,
+    ),
+    ValidationErrors,
+> {
+    path_params.validate()?;
+    let b = TakesTakesRenameBodyValidator { body: &body };
+    b.validate()?;
+
+    Ok((path_params, body))
+}
+/// TakesTakesRename - POST /v1/takes/{takeId}/rename
+#[tracing::instrument(skip_all)]
+async fn takes_takes_rename<I, A, E, C>(
+    method: Method,
+    TypedHeader(host): TypedHeader<Host>,
+    cookies: CookieJar,
+    headers: HeaderMap,
+    Path(path_params): Path<models::TakesTakesRenamePathParams>,
+    State(api_impl): State<I>,
+    Json(body): Json<models::TakesRenamePayload>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::takes::Takes<E, Claims = C> + apis::ApiAuthBasic<Claims = C> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+{
+    // Authentication
+    let claims_in_auth_header = api_impl
+        .as_ref()
+        .extract_claims_from_auth_header(apis::BasicAuthKind::Bearer, &headers, "authorization")
+        .await;
+    let claims = None.or(claims_in_auth_header);
+    let Some(claims) = claims else {
+        return response_with_status_code_only(StatusCode::UNAUTHORIZED);
+    };
+
+    #[allow(clippy::redundant_closure)]
+    let validation =
+        tokio::task::spawn_blocking(move || takes_takes_rename_validation(path_params, body))
+            .await
+            .unwrap();
+
+    let Ok((path_params, body)) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl
+        .as_ref()
+        .takes_takes_rename(&method, &host, &cookies, &claims, &path_params, &body)
+        .await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::takes::TakesTakesRenameResponse::Status200_TakesResult
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::takes::TakesTakesRenameResponse::Status400_EngineBadRequestErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(400);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::takes::TakesTakesRenameResponse::Status401_EngineUnauthorizedErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(401);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::takes::TakesTakesRenameResponse::Status403_EngineForbiddenErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(403);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::takes::TakesTakesRenameResponse::Status404_EngineNotFoundErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(404);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::takes::TakesTakesRenameResponse::Status409_EngineConflictErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(409);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::takes::TakesTakesRenameResponse::Status422_EngineUnprocessableErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(422);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::takes::TakesTakesRenameResponse::Status500_EngineRuntimeErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(500);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                            },
+                                            Err(why) => {
+                                                    // Application code returned an error. This should not happen, as the implementation should
+                                                    // return a valid response.
+                                                    return api_impl.as_ref().handle_error(&method, &host, &cookies, why).await;
+                                            },
+                                        };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[derive(validator::Validate)]
+#[allow(dead_code)]
+struct TimelineTimelineApplyCutsBodyValidator<'a> {
+    #[validate(nested)]
+    body: &'a models::TimelineApplyCutsPayload,
+}
+
+#[tracing::instrument(skip_all)]
+fn timeline_timeline_apply_cuts_validation(
+    body: models::TimelineApplyCutsPayload,
+) -> std::result::Result<(models::TimelineApplyCutsPayload,), ValidationErrors> {
+    let b = TimelineTimelineApplyCutsBodyValidator { body: &body };
+    b.validate()?;
+
+    Ok((body,))
+}
+/// TimelineTimelineApplyCuts - POST /v1/timeline/apply-cuts
+#[tracing::instrument(skip_all)]
+async fn timeline_timeline_apply_cuts<I, A, E, C>(
+    method: Method,
+    TypedHeader(host): TypedHeader<Host>,
+    cookies: CookieJar,
+    headers: HeaderMap,
+    State(api_impl): State<I>,
+    Json(body): Json<models::TimelineApplyCutsPayload>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::timeline::Timeline<E, Claims = C> + apis::ApiAuthBasic<Claims = C> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+{
+    // Authentication
+    let claims_in_auth_header = api_impl
+        .as_ref()
+        .extract_claims_from_auth_header(apis::BasicAuthKind::Bearer, &headers, "authorization")
+        .await;
+    let claims = None.or(claims_in_auth_header);
+    let Some(claims) = claims else {
+        return response_with_status_code_only(StatusCode::UNAUTHORIZED);
+    };
+
+    #[allow(clippy::redundant_closure)]
+    let validation =
+        tokio::task::spawn_blocking(move || timeline_timeline_apply_cuts_validation(body))
+            .await
+            .unwrap();
+
+    let Ok((body,)) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl
+        .as_ref()
+        .timeline_timeline_apply_cuts(&method, &host, &cookies, &claims, &body)
+        .await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::timeline::TimelineTimelineApplyCutsResponse::Status200_TimelineApplyCutsResult
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::timeline::TimelineTimelineApplyCutsResponse::Status400_EngineBadRequestErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(400);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::timeline::TimelineTimelineApplyCutsResponse::Status401_EngineUnauthorizedErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(401);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::timeline::TimelineTimelineApplyCutsResponse::Status403_EngineForbiddenErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(403);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::timeline::TimelineTimelineApplyCutsResponse::Status409_EngineConflictErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(409);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::timeline::TimelineTimelineApplyCutsResponse::Status422_EngineUnprocessableErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(422);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::timeline::TimelineTimelineApplyCutsResponse::Status500_EngineRuntimeErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(500);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                            },
+                                            Err(why) => {
+                                                    // Application code returned an error. This should not happen, as the implementation should
+                                                    // return a valid response.
+                                                    return api_impl.as_ref().handle_error(&method, &host, &cookies, why).await;
+                                            },
+                                        };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[derive(validator::Validate)]
+#[allow(dead_code)]
+struct TimelineTimelineSuggestCutsBodyValidator<'a> {
+    #[validate(nested)]
+    body: &'a models::TimelineSuggestCutsPayload,
+}
+
+#[tracing::instrument(skip_all)]
+fn timeline_timeline_suggest_cuts_validation(
+    body: models::TimelineSuggestCutsPayload,
+) -> std::result::Result<(models::TimelineSuggestCutsPayload,), ValidationErrors> {
+    let b = TimelineTimelineSuggestCutsBodyValidator { body: &body };
+    b.validate()?;
+
+    Ok((body,))
+}
+/// TimelineTimelineSuggestCuts - POST /v1/timeline/suggest-cuts
+#[tracing::instrument(skip_all)]
+async fn timeline_timeline_suggest_cuts<I, A, E, C>(
+    method: Method,
+    TypedHeader(host): TypedHeader<Host>,
+    cookies: CookieJar,
+    headers: HeaderMap,
+    State(api_impl): State<I>,
+    Json(body): Json<models::TimelineSuggestCutsPayload>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::timeline::Timeline<E, Claims = C> + apis::ApiAuthBasic<Claims = C> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+{
+    // Authentication
+    let claims_in_auth_header = api_impl
+        .as_ref()
+        .extract_claims_from_auth_header(apis::BasicAuthKind::Bearer, &headers, "authorization")
+        .await;
+    let claims = None.or(claims_in_auth_header);
+    let Some(claims) = claims else {
+        return response_with_status_code_only(StatusCode::UNAUTHORIZED);
+    };
+
+    #[allow(clippy::redundant_closure)]
+    let validation =
+        tokio::task::spawn_blocking(move || timeline_timeline_suggest_cuts_validation(body))
+            .await
+            .unwrap();
+
+    let Ok((body,)) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl
+        .as_ref()
+        .timeline_timeline_suggest_cuts(&method, &host, &cookies, &claims, &body)
+        .await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::timeline::TimelineTimelineSuggestCutsResponse::Status200_TimelineSuggestCutsResult
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::timeline::TimelineTimelineSuggestCutsResponse::Status400_EngineBadRequestErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(400);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::timeline::TimelineTimelineSuggestCutsResponse::Status401_EngineUnauthorizedErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(401);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::timeline::TimelineTimelineSuggestCutsResponse::Status403_EngineForbiddenErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(403);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::timeline::TimelineTimelineSuggestCutsResponse::Status409_EngineConflictErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(409);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::timeline::TimelineTimelineSuggestCutsResponse::Status422_EngineUnprocessableErrorResponseBody
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(422);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_static("application/json"));
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::timeline::TimelineTimelineSuggestCutsResponse::Status500_EngineRuntimeErrorResponseBody
                                                     (body)
                                                 => {
                                                   let mut response = response.status(500);
@@ -105,6 +105,16 @@ impl apis::capture::Capture<()> for MockApi {
     ) -> Result<apis::capture::CaptureCapturePreviewFrameResponse, ()> {
         unused!()
     }
+    async fn capture_capture_start_camera(
+        &self,
+        _: &http::Method,
+        _: &headers::Host,
+        _: &axum_extra::extract::CookieJar,
+        _: &Self::Claims,
+        _: &models::CaptureStartCameraPayload,
+    ) -> Result<apis::capture::CaptureCaptureStartCameraResponse, ()> {
+        unused!()
+    }
     async fn capture_capture_start_current_window(
         &self,
         _: &http::Method,
@@ -137,6 +147,16 @@ impl apis::capture::Capture<()> for MockApi {
             ),
         )
     }
+    async fn capture_capture_start_region(
+        &self,
+        _: &http::Method,
+        _: &headers::Host,
+        _: &axum_extra::extract::CookieJar,
+        _: &Self::Claims,
+        _: &models::CaptureStartRegionPayload,
+    ) -> Result<apis::capture::CaptureCaptureStartRegionResponse, ()> {
+        unused!()
+    }
     async fn capture_capture_start_window(
         &self,
         _: &http::Method,
@@ -169,6 +189,15 @@ impl apis::capture::Capture<()> for MockApi {
     ) -> Result<apis::capture::CaptureCaptureStopResponse, ()> {
         unused!()
     }
+    async fn capture_capture_stop_camera(
+        &self,
+        _: &http::Method,
+        _: &headers::Host,
+        _: &axum_extra::extract::CookieJar,
+        _: &Self::Claims,
+    ) -> Result<apis::capture::CaptureCaptureStopCameraResponse, ()> {
+        unused!()
+    }
 }
 
 #[async_trait]
@@ -230,6 +259,16 @@ impl apis::export::Export<()> for MockApi {
     ) -> Result<apis::export::ExportExportGetResponse, ()> {
         unused!()
     }
+    async fn export_export_guide(
+        &self,
+        _: &http::Method,
+        _: &headers::Host,
+        _: &axum_extra::extract::CookieJar,
+        _: &Self::Claims,
+        _: &models::ExportGuidePayload,
+    ) -> Result<apis::export::ExportExportGuideResponse, ()> {
+        unused!()
+    }
     async fn export_export_info(
         &self,
         _: &http::Method,
@@ -359,6 +398,43 @@ impl apis::project::Project<()> for MockApi {
 #[async_trait]
 impl apis::recording::Recording<()> for MockApi {
     type Claims = ();
+    async fn recording_recording_add_marker(
+        &self,
+        _: &http::Method,
+        _: &headers::Host,
+        _: &axum_extra::extract::CookieJar,
+        _: &Self::Claims,
+        _: &models::RecordingAddMarkerPayload,
+    ) -> Result<apis::recording::RecordingRecordingAddMarkerResponse, ()> {
+        unused!()
+    }
+    async fn recording_recording_pause(
+        &self,
+        _: &http::Method,
+        _: &headers::Host,
+        _: &axum_extra::extract::CookieJar,
+        _: &Self::Claims,
+    ) -> Result<apis::recording::RecordingRecordingPauseResponse, ()> {
+        unused!()
+    }
+    async fn recording_recording_recover(
+        &self,
+        _: &http::Method,
+        _: &headers::Host,
+        _: &axum_extra::extract::CookieJar,
+        _: &Self::Claims,
+    ) -> Result<apis::recording::RecordingRecordingRecoverResponse, ()> {
+        unused!()
+    }
+    async fn recording_recording_resume(
+        &self,
+        _: &http::Method,
+        _: &headers::Host,
+        _: &axum_extra::extract::CookieJar,
+        _: &Self::Claims,
+    ) -> Result<apis::recording::RecordingRecordingResumeResponse, ()> {
+        unused!()
+    }
     async fn recording_recording_start(
         &self,
         _: &http::Method,
@@ -394,6 +470,150 @@ impl apis::sources::Sources<()> for MockApi {
     }
 }
 
+#[async_trait]
+impl apis::analysis::Analysis<()> for MockApi {
+    type Claims = ();
+    async fn analysis_analysis_visual_activity(
+        &self,
+        _: &http::Method,
+        _: &headers::Host,
+        _: &axum_extra::extract::CookieJar,
+        _: &Self::Claims,
+        _: &models::AnalysisVisualActivityPayload,
+    ) -> Result<apis::analysis::AnalysisAnalysisVisualActivityResponse, ()> {
+        unused!()
+    }
+}
+
+#[async_trait]
+impl apis::camera_keyframes::CameraKeyframes<()> for MockApi {
+    type Claims = ();
+    async fn camera_keyframes_camera_keyframes_edit(
+        &self,
+        _: &http::Method,
+        _: &headers::Host,
+        _: &axum_extra::extract::CookieJar,
+        _: &Self::Claims,
+        _: &models::CameraKeyframesEditPayload,
+    ) -> Result<apis::camera_keyframes::CameraKeyframesCameraKeyframesEditResponse, ()> {
+        unused!()
+    }
+    async fn camera_keyframes_camera_keyframes_list(
+        &self,
+        _: &http::Method,
+        _: &headers::Host,
+        _: &axum_extra::extract::CookieJar,
+        _: &Self::Claims,
+        _: &models::CameraKeyframesCameraKeyframesListQueryParams,
+    ) -> Result<apis::camera_keyframes::CameraKeyframesCameraKeyframesListResponse, ()> {
+        unused!()
+    }
+    async fn camera_keyframes_camera_keyframes_replan(
+        &self,
+        _: &http::Method,
+        _: &headers::Host,
+        _: &axum_extra::extract::CookieJar,
+        _: &Self::Claims,
+    ) -> Result<apis::camera_keyframes::CameraKeyframesCameraKeyframesReplanResponse, ()> {
+        unused!()
+    }
+}
+
+#[async_trait]
+impl apis::library::Library<()> for MockApi {
+    type Claims = ();
+    async fn library_library_defaults(
+        &self,
+        _: &http::Method,
+        _: &headers::Host,
+        _: &axum_extra::extract::CookieJar,
+        _: &Self::Claims,
+    ) -> Result<apis::library::LibraryLibraryDefaultsResponse, ()> {
+        unused!()
+    }
+    async fn library_library_save_defaults(
+        &self,
+        _: &http::Method,
+        _: &headers::Host,
+        _: &axum_extra::extract::CookieJar,
+        _: &Self::Claims,
+        _: &models::LibrarySaveDefaultsPayload,
+    ) -> Result<apis::library::LibraryLibrarySaveDefaultsResponse, ()> {
+        unused!()
+    }
+}
+
+#[async_trait]
+impl apis::takes::Takes<()> for MockApi {
+    type Claims = ();
+    async fn takes_takes_activate(
+        &self,
+        _: &http::Method,
+        _: &headers::Host,
+        _: &axum_extra::extract::CookieJar,
+        _: &Self::Claims,
+        _: &models::TakesTakesActivatePathParams,
+    ) -> Result<apis::takes::TakesTakesActivateResponse, ()> {
+        unused!()
+    }
+    async fn takes_takes_delete(
+        &self,
+        _: &http::Method,
+        _: &headers::Host,
+        _: &axum_extra::extract::CookieJar,
+        _: &Self::Claims,
+        _: &models::TakesTakesDeletePathParams,
+        _: &models::TakesDeletePayload,
+    ) -> Result<apis::takes::TakesTakesDeleteResponse, ()> {
+        unused!()
+    }
+    async fn takes_takes_list(
+        &self,
+        _: &http::Method,
+        _: &headers::Host,
+        _: &axum_extra::extract::CookieJar,
+        _: &Self::Claims,
+    ) -> Result<apis::takes::TakesTakesListResponse, ()> {
+        unused!()
+    }
+    async fn takes_takes_rename(
+        &self,
+        _: &http::Method,
+        _: &headers::Host,
+        _: &axum_extra::extract::CookieJar,
+        _: &Self::Claims,
+        _: &models::TakesTakesRenamePathParams,
+        _: &models::TakesRenamePayload,
+    ) -> Result<apis::takes::TakesTakesRenameResponse, ()> {
+        unused!()
+    }
+}
+
+#[async_trait]
+impl apis::timeline::Timeline<()> for MockApi {
+    type Claims = ();
+    async fn timeline_timeline_apply_cuts(
+        &self,
+        _: &http::Method,
+        _: &headers::Host,
+        _: &axum_extra::extract::CookieJar,
+        _: &Self::Claims,
+        _: &models::TimelineApplyCutsPayload,
+    ) -> Result<apis::timeline::TimelineTimelineApplyCutsResponse, ()> {
+        unused!()
+    }
+    async fn timeline_timeline_suggest_cuts(
+        &self,
+        _: &http::Method,
+        _: &headers::Host,
+        _: &axum_extra::extract::CookieJar,
+        _: &Self::Claims,
+        _: &models::TimelineSuggestCutsPayload,
+    ) -> Result<apis::timeline::TimelineTimelineSuggestCutsResponse, ()> {
+        unused!()
+    }
+}
+
 async fn send(request: Request<Body>) -> axum::response::Response {
     server::new(MockApi).oneshot(request).await.unwrap()
 }
@@ -318,6 +318,86 @@ extension APIProtocol {
     func project_period_projectRecents(_: Operations.project_period_projectRecents.Input) async throws -> Operations.project_period_projectRecents.Output {
         throw UnimplementedOperation()
     }
+
+    func capture_period_captureStartRegion(_: Operations.capture_period_captureStartRegion.Input) async throws -> Operations.capture_period_captureStartRegion.Output {
+        throw UnimplementedOperation()
+    }
+
+    func capture_period_captureStartCamera(_: Operations.capture_period_captureStartCamera.Input) async throws -> Operations.capture_period_captureStartCamera.Output {
+        throw UnimplementedOperation()
+    }
+
+    func capture_period_captureStopCamera(_: Operations.capture_period_captureStopCamera.Input) async throws -> Operations.capture_period_captureStopCamera.Output {
+        throw UnimplementedOperation()
+    }
+
+    func recording_period_recordingPause(_: Operations.recording_period_recordingPause.Input) async throws -> Operations.recording_period_recordingPause.Output {
+        throw UnimplementedOperation()
+    }
+
+    func recording_period_recordingResume(_: Operations.recording_period_recordingResume.Input) async throws -> Operations.recording_period_recordingResume.Output {
+        throw UnimplementedOperation()
+    }
+
+    func recording_period_recordingAddMarker(_: Operations.recording_period_recordingAddMarker.Input) async throws -> Operations.recording_period_recordingAddMarker.Output {
+        throw UnimplementedOperation()
+    }
+
+    func recording_period_recordingRecover(_: Operations.recording_period_recordingRecover.Input) async throws -> Operations.recording_period_recordingRecover.Output {
+        throw UnimplementedOperation()
+    }
+
+    func export_period_exportGuide(_: Operations.export_period_exportGuide.Input) async throws -> Operations.export_period_exportGuide.Output {
+        throw UnimplementedOperation()
+    }
+
+    func takes_period_takesList(_: Operations.takes_period_takesList.Input) async throws -> Operations.takes_period_takesList.Output {
+        throw UnimplementedOperation()
+    }
+
+    func takes_period_takesRename(_: Operations.takes_period_takesRename.Input) async throws -> Operations.takes_period_takesRename.Output {
+        throw UnimplementedOperation()
+    }
+
+    func takes_period_takesDelete(_: Operations.takes_period_takesDelete.Input) async throws -> Operations.takes_period_takesDelete.Output {
+        throw UnimplementedOperation()
+    }
+
+    func takes_period_takesActivate(_: Operations.takes_period_takesActivate.Input) async throws -> Operations.takes_period_takesActivate.Output {
+        throw UnimplementedOperation()
+    }
+
+    func cameraKeyframes_period_cameraKeyframesList(_: Operations.cameraKeyframes_period_cameraKeyframesList.Input) async throws -> Operations.cameraKeyframes_period_cameraKeyframesList.Output {
+        throw UnimplementedOperation()
+    }
+
+    func cameraKeyframes_period_cameraKeyframesEdit(_: Operations.cameraKeyframes_period_cameraKeyframesEdit.Input) async throws -> Operations.cameraKeyframes_period_cameraKeyframesEdit.Output {
+        throw UnimplementedOperation()
+    }
+
+    func cameraKeyframes_period_cameraKeyframesReplan(_: Operations.cameraKeyframes_period_cameraKeyframesReplan.Input) async throws -> Operations.cameraKeyframes_period_cameraKeyframesReplan.Output {
+        throw UnimplementedOperation()
+    }
+
+    func timeline_period_timelineSuggestCuts(_: Operations.timeline_period_timelineSuggestCuts.Input) async throws -> Operations.timeline_period_timelineSuggestCuts.Output {
+        throw UnimplementedOperation()
+    }
+
+    func timeline_period_timelineApplyCuts(_: Operations.timeline_period_timelineApplyCuts.Input) async throws -> Operations.timeline_period_timelineApplyCuts.Output {
+        throw UnimplementedOperation()
+    }
+
+    func analysis_period_analysisVisualActivity(_: Operations.analysis_period_analysisVisualActivity.Input) async throws -> Operations.analysis_period_analysisVisualActivity.Output {
+        throw UnimplementedOperation()
+    }
+
+    func library_period_libraryDefaults(_: Operations.library_period_libraryDefaults.Input) async throws -> Operations.library_period_libraryDefaults.Output {
+        throw UnimplementedOperation()
+    }
+
+    func library_period_librarySaveDefaults(_: Operations.library_period_librarySaveDefaults.Input) async throws -> Operations.library_period_librarySaveDefaults.Output {
+        throw UnimplementedOperation()
+    }
 }
 
 typealias TestHandler = @Sendable (HTTPRequest, HTTPBody?, ServerRequestMetadata) async throws -> (HTTPResponse, HTTPBody?)
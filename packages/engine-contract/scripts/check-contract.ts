@@ -62,8 +62,8 @@ for (const [path, pathItem] of Object.entries(openApi.paths)) {
   }
 }
 
-if (operationIds.size !== 28) {
-  failures.push(`expected 28 OpenAPI operations, found ${operationIds.size}`);
+if (operationIds.size !== 48) {
+  failures.push(`expected 48 OpenAPI operations, found ${operationIds.size}`);
 }
 
 if (failures.length > 0) {
@@ -0,0 +1,66 @@
+import { Schema } from "effect";
+import { NonEmptyString, NonNegativeNumber, between } from "../shared/helpers";
+
+/**
+ * Thresholds a visual analysis ran with.
+ */
+export const visualActivitySettingsSchema = Schema.Struct({
+  sceneThreshold: Schema.Finite.pipe(between(0.001, 1)),
+  minSceneSeconds: Schema.Finite.pipe(between(0.1, 600)),
+}).annotate({ identifier: "VisualActivitySettings" });
+
+/**
+ * Frame where the picture changes enough to start a new scene.
+ */
+export const sceneChangeSchema = Schema.Struct({
+  timeSeconds: NonNegativeNumber,
+  score: NonNegativeNumber,
+}).annotate({ identifier: "SceneChange" });
+
+/**
+ * Chapter proposed between scene changes, with a representative thumbnail time.
+ */
+export const chapterSuggestionSchema = Schema.Struct({
+  id: NonEmptyString,
+  title: NonEmptyString,
+  startSeconds: NonNegativeNumber,
+  endSeconds: NonNegativeNumber,
+  activity: NonNegativeNumber,
+  thumbnailSeconds: NonNegativeNumber,
+}).annotate({ identifier: "ChapterSuggestion" });
+
+/**
+ * Per-frame activity, scene changes, chapters and a thumbnail pick for the active take.
+ */
+export const visualActivityResultSchema = Schema.Struct({
+  version: Schema.Literal(1),
+  takeId: NonEmptyString,
+  source: Schema.Literals(["recording", "synthetic"]),
+  settings: visualActivitySettingsSchema,
+  fps: NonNegativeNumber,
+  durationSeconds: NonNegativeNumber,
+  frameDifferences: Schema.Array(NonNegativeNumber),
+  sceneChanges: Schema.Array(sceneChangeSchema),
+  chapters: Schema.Array(chapterSuggestionSchema),
+  thumbnailSeconds: NonNegativeNumber,
+}).annotate({ identifier: "VisualActivityResult" });
+
+/**
+ * Runtime TypeScript type for visual analysis settings.
+ */
+export type VisualActivitySettings = Schema.Schema.Type<typeof visualActivitySettingsSchema>;
+
+/**
+ * Runtime TypeScript type for a scene change.
+ */
+export type SceneChange = Schema.Schema.Type<typeof sceneChangeSchema>;
+
+/**
+ * Runtime TypeScript type for a chapter suggestion.
+ */
+export type ChapterSuggestion = Schema.Schema.Type<typeof chapterSuggestionSchema>;
+
+/**
+ * Runtime TypeScript type for visual activity responses.
+ */
+export type VisualActivityResult = Schema.Schema.Type<typeof visualActivityResultSchema>;
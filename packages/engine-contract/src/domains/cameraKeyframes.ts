@@ -0,0 +1,75 @@
+import { Schema } from "effect";
+import { NonEmptyString, NonNegativeInt, NonNegativeNumber, between } from "../shared/helpers";
+
+/**
+ * Motion from one camera keyframe to the next.
+ */
+export const cameraEasingSchema = Schema.Literals(["linear", "easeInOut"]);
+
+/**
+ * Point in recording coordinates the camera centers on.
+ */
+export const cameraKeyframeCenterSchema = Schema.Struct({
+  x: Schema.Finite,
+  y: Schema.Finite,
+}).annotate({ identifier: "CameraKeyframeCenter" });
+
+/**
+ * Keyframe of the project camera track, planned by auto-zoom or added by the user.
+ */
+export const cameraKeyframeSchema = Schema.Struct({
+  id: NonEmptyString,
+  time: NonNegativeNumber,
+  center: cameraKeyframeCenterSchema,
+  zoom: Schema.Finite.pipe(between(1, 10)),
+  easing: cameraEasingSchema,
+  origin: Schema.Literals(["auto", "manual"]),
+  locked: Schema.Boolean,
+}).annotate({ identifier: "CameraKeyframe" });
+
+/**
+ * Change to the keyframes inside a time range. `add` needs `time`, `center` and `zoom`,
+ * `setEasing` needs `easing`; `delete`, `lock` and `unlock` apply to every keyframe in range.
+ */
+export const cameraKeyframeEditSchema = Schema.Struct({
+  action: Schema.Literals(["add", "delete", "lock", "unlock", "setEasing"]),
+  time: Schema.optionalKey(NonNegativeNumber),
+  center: Schema.optionalKey(cameraKeyframeCenterSchema),
+  zoom: Schema.optionalKey(Schema.Finite.pipe(between(1, 10))),
+  easing: Schema.optionalKey(cameraEasingSchema),
+}).annotate({ identifier: "CameraKeyframeEdit" });
+
+/**
+ * Camera keyframes inside the requested range; `endSeconds` is omitted for an open range.
+ */
+export const cameraKeyframesResultSchema = Schema.Struct({
+  startSeconds: NonNegativeNumber,
+  endSeconds: Schema.optionalKey(NonNegativeNumber),
+  keyframes: Schema.Array(cameraKeyframeSchema),
+  keyframeCount: NonNegativeInt,
+}).annotate({ identifier: "CameraKeyframesResult" });
+
+/**
+ * Runtime TypeScript type for camera keyframe easing.
+ */
+export type CameraEasing = Schema.Schema.Type<typeof cameraEasingSchema>;
+
+/**
+ * Runtime TypeScript type for a camera keyframe center.
+ */
+export type CameraKeyframeCenter = Schema.Schema.Type<typeof cameraKeyframeCenterSchema>;
+
+/**
+ * Runtime TypeScript type for a camera keyframe.
+ */
+export type CameraKeyframe = Schema.Schema.Type<typeof cameraKeyframeSchema>;
+
+/**
+ * Runtime TypeScript type for a camera keyframe edit.
+ */
+export type CameraKeyframeEdit = Schema.Schema.Type<typeof cameraKeyframeEditSchema>;
+
+/**
+ * Runtime TypeScript type for camera keyframe responses.
+ */
+export type CameraKeyframesResult = Schema.Schema.Type<typeof cameraKeyframesResultSchema>;
@@ -1,9 +1,53 @@
 import { Schema } from "effect";
 import { NonEmptyString, NonNegativeInt, NonNegativeNumber } from "../shared/helpers";
-import { captureSessionIdSchema, eventsUrlSchema, recordingUrlSchema } from "../schema-primitives";
-import { captureMetadataSchema } from "../shared/valueObjects";
+import {
+  captureSessionIdSchema,
+  eventsUrlSchema,
+  recordingUrlSchema,
+  windowIdSchema,
+} from "../schema-primitives";
+import {
+  audioTrackKindSchema,
+  audioTrackSchema,
+  cameraTrackSchema,
+  captureMetadataSchema,
+  recordingMetadataSchema,
+} from "../shared/valueObjects";
 import { EngineBadRequestError } from "../errors";
 
+/**
+ * Whole-recording levels for one audio track.
+ */
+export const audioTrackTelemetrySchema = Schema.Struct({
+  kind: audioTrackKindSchema,
+  capturedSeconds: NonNegativeNumber,
+  peakDbfs: Schema.Finite,
+  rmsDbfs: Schema.Finite,
+  clippedSamples: NonNegativeInt,
+  readErrors: NonNegativeInt,
+}).annotate({ identifier: "AudioTrackTelemetry" });
+
+/**
+ * Live levels for one audio track, with how long it has stayed silent.
+ */
+export const audioLevelsSchema = Schema.Struct({
+  kind: audioTrackKindSchema,
+  peakDbfs: Schema.Finite,
+  rmsDbfs: Schema.Finite,
+  clippedSamples: NonNegativeInt,
+  silentSeconds: NonNegativeNumber,
+  silenceWarning: Schema.Boolean,
+}).annotate({ identifier: "AudioLevels" });
+
+/**
+ * Window exclusions applied to the running capture and the windows masked in the last frame.
+ */
+export const privacyMaskStatusSchema = Schema.Struct({
+  ruleCount: NonNegativeInt,
+  maskStyle: Schema.Literals(["solidFill", "blur"]),
+  maskedWindowIds: Schema.Array(windowIdSchema),
+}).annotate({ identifier: "PrivacyMaskStatus" });
+
 /**
  * Performance counters and timing metrics emitted by capture/recording pipelines.
  */
@@ -19,6 +63,7 @@ export const captureTelemetrySchema = Schema.Struct({
   recordQueueLagMs: Schema.optionalKey(NonNegativeNumber),
   writerAppendMs: Schema.optionalKey(NonNegativeNumber),
   previewEncodeMs: Schema.optionalKey(NonNegativeNumber),
+  audioTracks: Schema.optionalKey(Schema.Array(audioTrackTelemetrySchema)),
 }).annotate({ identifier: "CaptureTelemetry" });
 
 /**
@@ -35,10 +80,21 @@ export const capturePreviewFrameSchema = Schema.Struct({
 export const captureStatusResultSchema = Schema.Struct({
   isRunning: Schema.Boolean,
   isRecording: Schema.Boolean,
+  isPaused: Schema.optionalKey(Schema.Boolean),
+  isCountingDown: Schema.optionalKey(Schema.Boolean),
+  countdownRemainingSeconds: Schema.optionalKey(NonNegativeNumber),
+  recordingRemainingSeconds: Schema.optionalKey(NonNegativeNumber),
   captureSessionId: Schema.optionalKey(captureSessionIdSchema),
+  sourcesRevision: Schema.optionalKey(NonNegativeInt),
   recordingDurationSeconds: NonNegativeNumber,
   recordingURL: Schema.optionalKey(recordingUrlSchema),
+  cameraId: Schema.optionalKey(NonNegativeInt),
+  cameraTrack: Schema.optionalKey(cameraTrackSchema),
+  audioTracks: Schema.optionalKey(Schema.Array(audioTrackSchema)),
+  audioLevels: Schema.optionalKey(Schema.Array(audioLevelsSchema)),
+  privacyMask: Schema.optionalKey(privacyMaskStatusSchema),
   captureMetadata: Schema.optionalKey(captureMetadataSchema),
+  recordingMetadata: Schema.optionalKey(recordingMetadataSchema),
   lastError: Schema.optionalKey(EngineBadRequestError),
   eventsURL: Schema.optionalKey(eventsUrlSchema),
   lastRecordingTelemetry: Schema.optionalKey(captureTelemetrySchema),
@@ -52,6 +108,21 @@ export const capturePreviewFrameResultSchema = Schema.Struct({
   frame: Schema.optionalKey(capturePreviewFrameSchema),
 }).annotate({ identifier: "CapturePreviewFrameResult" });
 
+/**
+ * Runtime TypeScript type for whole-recording audio levels.
+ */
+export type AudioTrackTelemetry = Schema.Schema.Type<typeof audioTrackTelemetrySchema>;
+
+/**
+ * Runtime TypeScript type for live audio levels.
+ */
+export type AudioLevels = Schema.Schema.Type<typeof audioLevelsSchema>;
+
+/**
+ * Runtime TypeScript type for the privacy mask of a running capture.
+ */
+export type PrivacyMaskStatus = Schema.Schema.Type<typeof privacyMaskStatusSchema>;
+
 /**
  * Runtime TypeScript type for capture telemetry.
  */
@@ -1,6 +1,11 @@
 import { Schema } from "effect";
-import { NonEmptyString, NonNegativeInt, PositiveInt } from "../shared/helpers";
-import { exportJobIdSchema, exportPresetIdSchema, outputUrlSchema } from "../schema-primitives";
+import { NonEmptyString, NonNegativeInt, NonNegativeNumber, PositiveInt } from "../shared/helpers";
+import {
+  artifactPathSchema,
+  exportJobIdSchema,
+  exportPresetIdSchema,
+  outputUrlSchema,
+} from "../schema-primitives";
 
 /**
  * Engine-supported render preset advertised to clients.
@@ -40,6 +45,39 @@ export const exportRunCutPlanResultSchema = Schema.Struct({
   appliedSegments: Schema.optionalKey(NonNegativeInt),
 }).annotate({ identifier: "ExportRunCutPlanResult" });
 
+/**
+ * Recording-space position of the click a guide step documents.
+ */
+export const guideStepClickSchema = Schema.Struct({
+  x: Schema.Finite,
+  y: Schema.Finite,
+}).annotate({ identifier: "GuideStepClick" });
+
+/**
+ * One click of a step-by-step guide with its highlighted screenshot.
+ */
+export const guideStepSchema = Schema.Struct({
+  index: PositiveInt,
+  timeSeconds: NonNegativeNumber,
+  timestamp: NonEmptyString,
+  action: NonEmptyString,
+  imageURL: artifactPathSchema,
+  click: guideStepClickSchema,
+  transcript: Schema.optionalKey(NonEmptyString),
+}).annotate({ identifier: "GuideStep" });
+
+/**
+ * Step-by-step guide written as Markdown and HTML next to its screenshots.
+ */
+export const exportGuideResultSchema = Schema.Struct({
+  outputURL: outputUrlSchema,
+  markdownURL: artifactPathSchema,
+  htmlURL: artifactPathSchema,
+  title: NonEmptyString,
+  source: Schema.Literals(["recording", "synthetic"]),
+  steps: Schema.Array(guideStepSchema),
+}).annotate({ identifier: "ExportGuideResult" });
+
 /**
  * Runtime TypeScript type for an export preset.
  */
@@ -59,3 +97,18 @@ export type ExportRunResult = Schema.Schema.Type<typeof exportRunResultSchema>;
  * Runtime TypeScript type for cut-plan export job responses.
  */
 export type ExportRunCutPlanResult = Schema.Schema.Type<typeof exportRunCutPlanResultSchema>;
+
+/**
+ * Runtime TypeScript type for a guide step's click position.
+ */
+export type GuideStepClick = Schema.Schema.Type<typeof guideStepClickSchema>;
+
+/**
+ * Runtime TypeScript type for a guide step.
+ */
+export type GuideStep = Schema.Schema.Type<typeof guideStepSchema>;
+
+/**
+ * Runtime TypeScript type for guide export responses.
+ */
+export type ExportGuideResult = Schema.Schema.Type<typeof exportGuideResultSchema>;
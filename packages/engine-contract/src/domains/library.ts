@@ -0,0 +1,14 @@
+import { Schema } from "effect";
+import { windowExclusionSettingsSchema } from "../shared/valueObjects";
+
+/**
+ * Settings new projects start from, stored outside any project.
+ */
+export const libraryDefaultsResultSchema = Schema.Struct({
+  windowExclusions: windowExclusionSettingsSchema,
+}).annotate({ identifier: "LibraryDefaultsResult" });
+
+/**
+ * Runtime TypeScript type for library default responses.
+ */
+export type LibraryDefaultsResult = Schema.Schema.Type<typeof libraryDefaultsResultSchema>;
@@ -10,7 +10,13 @@ import {
   autoZoomSettingsSchema,
   backgroundFramingSettingsSchema,
   captureMetadataSchema,
+  clickEffectsSettingsSchema,
+  cursorRenderingSettingsSchema,
+  pictureInPictureSettingsSchema,
+  reframeSettingsSchema,
+  shortcutOverlaySettingsSchema,
   timelineDocumentSchema,
+  windowExclusionSettingsSchema,
 } from "../shared/valueObjects";
 import { captureTelemetrySchema } from "./capture";
 import { agentJobStatusSchema } from "./agent";
@@ -35,6 +41,12 @@ export const projectStateSchema = Schema.Struct({
   lastRecordingTelemetry: Schema.optionalKey(captureTelemetrySchema),
   autoZoom: autoZoomSettingsSchema,
   backgroundFraming: backgroundFramingSettingsSchema,
+  shortcutOverlay: Schema.optionalKey(shortcutOverlaySettingsSchema),
+  pictureInPicture: Schema.optionalKey(pictureInPictureSettingsSchema),
+  cursorRendering: Schema.optionalKey(cursorRenderingSettingsSchema),
+  clickEffects: Schema.optionalKey(clickEffectsSettingsSchema),
+  reframe: Schema.optionalKey(reframeSettingsSchema),
+  windowExclusions: Schema.optionalKey(windowExclusionSettingsSchema),
   timeline: timelineDocumentSchema,
   captureMetadata: Schema.optionalKey(captureMetadataSchema),
   agentAnalysis: Schema.optionalKey(projectAgentAnalysisSummarySchema),
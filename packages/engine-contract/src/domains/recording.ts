@@ -1,11 +1,89 @@
+import { Schema } from "effect";
+import { NonEmptyString, NonNegativeInt, NonNegativeNumber } from "../shared/helpers";
+import { recordingUrlSchema } from "../schema-primitives";
+import {
+  audioTrackSchema,
+  recordingMarkerSchema,
+  recordingMetadataSchema,
+  recordingPauseRangeSchema,
+} from "../shared/valueObjects";
 import { captureStatusResultSchema, type CaptureStatusResult } from "./capture";
 
+export {
+  audioTrackSchema,
+  recordingMarkerSchema,
+  recordingMetadataSchema,
+  recordingPauseRangeSchema,
+};
+
 /**
  * Recording lifecycle status; intentionally aliases capture status in v2.
  */
 export const recordingStatusResultSchema = captureStatusResultSchema;
 
+/**
+ * Response for a marker added to the active recording.
+ */
+export const recordingAddMarkerResultSchema = Schema.Struct({
+  marker: recordingMarkerSchema,
+}).annotate({ identifier: "RecordingAddMarkerResult" });
+
+/**
+ * Recording rebuilt from the segments of an interrupted session.
+ */
+export const recoveredRecordingSchema = Schema.Struct({
+  recordingId: NonEmptyString,
+  recordingURL: recordingUrlSchema,
+  cameraRecordingURL: Schema.optionalKey(recordingUrlSchema),
+  audioTracks: Schema.Array(audioTrackSchema),
+  frameCount: NonNegativeInt,
+  durationSeconds: NonNegativeNumber,
+  recovered: Schema.Boolean,
+}).annotate({ identifier: "RecoveredRecording" });
+
+/**
+ * Response envelope for recordings recovered from interrupted sessions.
+ */
+export const recordingRecoverResultSchema = Schema.Struct({
+  recordings: Schema.Array(recoveredRecordingSchema),
+}).annotate({ identifier: "RecordingRecoverResult" });
+
 /**
  * Runtime TypeScript type for recording status responses.
  */
 export type RecordingStatusResult = CaptureStatusResult;
+
+/**
+ * Runtime TypeScript type for a spliced-out pause.
+ */
+export type RecordingPauseRange = Schema.Schema.Type<typeof recordingPauseRangeSchema>;
+
+/**
+ * Runtime TypeScript type for a recording marker.
+ */
+export type RecordingMarker = Schema.Schema.Type<typeof recordingMarkerSchema>;
+
+/**
+ * Runtime TypeScript type for recording metadata.
+ */
+export type RecordingMetadata = Schema.Schema.Type<typeof recordingMetadataSchema>;
+
+/**
+ * Runtime TypeScript type for a recorded audio track.
+ */
+export type AudioTrack = Schema.Schema.Type<typeof audioTrackSchema>;
+
+/**
+ * Runtime TypeScript type for add-marker responses.
+ */
+export type RecordingAddMarkerResult = Schema.Schema.Type<typeof recordingAddMarkerResultSchema>;
+
+/**
+ * Runtime TypeScript type for a recovered recording.
+ */
+export type RecoveredRecording = Schema.Schema.Type<typeof recoveredRecordingSchema>;
+
+/**
+ * Runtime TypeScript type for recording-recover responses.
+ */
+export type RecordingRecoverResult = Schema.Schema.Type<typeof recordingRecoverResultSchema>;
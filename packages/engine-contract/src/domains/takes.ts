@@ -0,0 +1,67 @@
+import { Schema } from "effect";
+import { IsoDateTime, NonEmptyString, NonNegativeInt, NonNegativeNumber } from "../shared/helpers";
+import { eventsUrlSchema, recordingUrlSchema } from "../schema-primitives";
+import {
+  audioTrackSchema,
+  cameraTrackSchema,
+  captureMetadataSchema,
+  recordingMetadataSchema,
+} from "../shared/valueObjects";
+
+export { cameraTrackSchema };
+
+/**
+ * Capture health of a finished take, kept once the live telemetry is gone.
+ */
+export const takeTelemetrySchema = Schema.Struct({
+  achievedFps: NonNegativeNumber,
+  sourceDroppedFrames: NonNegativeInt,
+  writerDroppedFrames: NonNegativeInt,
+  writerBackpressureDrops: NonNegativeInt,
+  recordingBitrateMbps: Schema.optionalKey(NonNegativeNumber),
+}).annotate({ identifier: "TakeTelemetry" });
+
+/**
+ * One recording of a project with its tracks and capture facts.
+ */
+export const takeSchema = Schema.Struct({
+  id: NonEmptyString,
+  name: NonEmptyString,
+  recordingURL: recordingUrlSchema,
+  cameraTrack: Schema.optionalKey(cameraTrackSchema),
+  audioTracks: Schema.Array(audioTrackSchema),
+  eventsURL: Schema.optionalKey(eventsUrlSchema),
+  captureMetadata: Schema.optionalKey(captureMetadataSchema),
+  recordingMetadata: recordingMetadataSchema,
+  durationSeconds: NonNegativeNumber,
+  telemetry: Schema.optionalKey(takeTelemetrySchema),
+  createdAt: IsoDateTime,
+}).annotate({ identifier: "Take" });
+
+/**
+ * Takes of the current project and the one the editor works on.
+ */
+export const takesResultSchema = Schema.Struct({
+  takes: Schema.Array(takeSchema),
+  activeTakeId: Schema.optionalKey(NonEmptyString),
+}).annotate({ identifier: "TakesResult" });
+
+/**
+ * Runtime TypeScript type for a take's camera track.
+ */
+export type CameraTrack = Schema.Schema.Type<typeof cameraTrackSchema>;
+
+/**
+ * Runtime TypeScript type for a finished take's capture health.
+ */
+export type TakeTelemetry = Schema.Schema.Type<typeof takeTelemetrySchema>;
+
+/**
+ * Runtime TypeScript type for a take.
+ */
+export type Take = Schema.Schema.Type<typeof takeSchema>;
+
+/**
+ * Runtime TypeScript type for take list and mutation responses.
+ */
+export type TakesResult = Schema.Schema.Type<typeof takesResultSchema>;
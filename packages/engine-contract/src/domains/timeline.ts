@@ -1,3 +1,5 @@
+import { Schema } from "effect";
+import { NonEmptyString, NonNegativeNumber, between } from "../shared/helpers";
 import {
   timelineDocumentSchema,
   type TimelineDocument,
@@ -9,7 +11,77 @@ import {
  */
 export const timelineProjectDocumentSchema = timelineDocumentSchema;
 
+/**
+ * How a suggested cut changes the timeline.
+ */
+export const cutOperationSchema = Schema.Literals(["rippleDelete", "lift", "speedUp"]);
+
+/**
+ * Idle stretch of the active take proposed for removal, in source time.
+ */
+export const suggestedCutSchema = Schema.Struct({
+  id: NonEmptyString,
+  takeId: NonEmptyString,
+  startSeconds: NonNegativeNumber,
+  endSeconds: NonNegativeNumber,
+  operation: cutOperationSchema,
+  playbackRate: Schema.optionalKey(Schema.Finite.pipe(between(1, 16))),
+}).annotate({ identifier: "SuggestedCut" });
+
+/**
+ * Signals that fed idle-cut detection for a take.
+ */
+export const idleCutSignalsSchema = Schema.Struct({
+  input: Schema.Boolean,
+  frames: Schema.Boolean,
+}).annotate({ identifier: "IdleCutSignals" });
+
+/**
+ * Idle-cut suggestions for the active take.
+ */
+export const timelineSuggestCutsResultSchema = Schema.Struct({
+  takeId: NonEmptyString,
+  durationSeconds: NonNegativeNumber,
+  signals: idleCutSignalsSchema,
+  idleSeconds: NonNegativeNumber,
+  suggestions: Schema.Array(suggestedCutSchema),
+}).annotate({ identifier: "TimelineSuggestCutsResult" });
+
+/**
+ * Timeline after applying suggestions, with the suggestions still pending review.
+ */
+export const timelineApplyCutsResultSchema = Schema.Struct({
+  timeline: timelineDocumentSchema,
+  appliedSuggestionIds: Schema.Array(NonEmptyString),
+  suggestions: Schema.Array(suggestedCutSchema),
+}).annotate({ identifier: "TimelineApplyCutsResult" });
+
 /**
  * Runtime TypeScript types for timeline documents and segments.
  */
 export type { TimelineDocument, TimelineSegment };
+
+/**
+ * Runtime TypeScript type for a cut operation.
+ */
+export type CutOperation = Schema.Schema.Type<typeof cutOperationSchema>;
+
+/**
+ * Runtime TypeScript type for a suggested cut.
+ */
+export type SuggestedCut = Schema.Schema.Type<typeof suggestedCutSchema>;
+
+/**
+ * Runtime TypeScript type for idle-cut detection signals.
+ */
+export type IdleCutSignals = Schema.Schema.Type<typeof idleCutSignalsSchema>;
+
+/**
+ * Runtime TypeScript type for suggest-cuts responses.
+ */
+export type TimelineSuggestCutsResult = Schema.Schema.Type<typeof timelineSuggestCutsResultSchema>;
+
+/**
+ * Runtime TypeScript type for apply-cuts responses.
+ */
+export type TimelineApplyCutsResult = Schema.Schema.Type<typeof timelineApplyCutsResultSchema>;
@@ -8,17 +8,30 @@ import {
   exportPresetIdSchema,
   outputUrlSchema,
   projectPathSchema,
+  takeIdSchema,
   windowIdSchema,
 } from "./schema-primitives";
 import {
   RuntimeBudgetMinutesSchema,
   ProjectRecentsLimitSchema,
+  IsoDateTime,
+  NonEmptyString,
+  NonNegativeInt,
   NonNegativeNumber,
+  PositiveNumber,
+  between,
 } from "./shared/helpers";
 import {
   autoZoomSettingsSchema,
   backgroundFramingSettingsSchema,
+  clickEffectsSettingsSchema,
+  cursorRenderingSettingsSchema,
+  hexColorSchema,
+  pictureInPictureSettingsSchema,
+  reframeSettingsSchema,
+  shortcutOverlaySettingsSchema,
   timelineDocumentSchema,
+  windowExclusionSettingsSchema,
 } from "./shared/valueObjects";
 import {
   agentPreflightResultSchema,
@@ -30,11 +43,25 @@ import { capturePreviewFrameResultSchema, captureStatusResultSchema } from "./do
 import { captureFrameRateSchema, sourcesResultSchema } from "./domains/sources";
 import { actionResultSchema, permissionsResultSchema } from "./domains/permissions";
 import {
+  exportGuideResultSchema,
   exportInfoResultSchema,
   exportRunCutPlanResultSchema,
   exportRunResultSchema,
 } from "./domains/export";
 import { projectRecentsResultSchema, projectStateSchema } from "./domains/project";
+import {
+  recordingAddMarkerResultSchema,
+  recordingRecoverResultSchema,
+} from "./domains/recording";
+import { takesResultSchema } from "./domains/takes";
+import { cameraKeyframeEditSchema, cameraKeyframesResultSchema } from "./domains/cameraKeyframes";
+import {
+  cutOperationSchema,
+  timelineApplyCutsResultSchema,
+  timelineSuggestCutsResultSchema,
+} from "./domains/timeline";
+import { visualActivityResultSchema } from "./domains/analysis";
+import { libraryDefaultsResultSchema } from "./domains/library";
 import { capabilitiesResultSchema, pingResultSchema } from "./domains/system";
 import {
   EngineAuthMiddleware,
@@ -82,10 +109,42 @@ export const captureStartWindowPayloadSchema = Schema.Struct({
   captureFps: Schema.optionalKey(captureFrameRateSchema),
 }).annotate({ identifier: "CaptureStartWindowPayload" });
 
+export const captureStartRegionPayloadSchema = Schema.Struct({
+  displayId: Schema.optionalKey(displayIdSchema),
+  rect: Schema.optionalKey(
+    Schema.Struct({
+      x: Schema.Finite,
+      y: Schema.Finite,
+      width: PositiveNumber,
+      height: PositiveNumber,
+    }),
+  ),
+  captureFps: Schema.optionalKey(captureFrameRateSchema),
+}).annotate({ identifier: "CaptureStartRegionPayload" });
+
+export const captureStartCameraPayloadSchema = Schema.Struct({
+  cameraId: Schema.optionalKey(NonNegativeInt),
+  captureFps: Schema.optionalKey(captureFrameRateSchema),
+}).annotate({ identifier: "CaptureStartCameraPayload" });
+
 export const recordingStartPayloadSchema = Schema.Struct({
   trackInputEvents: Schema.optionalKey(Schema.Boolean),
+  trackKeyboardShortcuts: Schema.optionalKey(Schema.Boolean),
+  captureMicrophone: Schema.optionalKey(Schema.Boolean),
+  microphoneDeviceId: Schema.optionalKey(NonEmptyString),
+  captureSystemAudio: Schema.optionalKey(Schema.Boolean),
+  systemAudioDeviceId: Schema.optionalKey(NonEmptyString),
+  silenceWarningSeconds: Schema.optionalKey(PositiveNumber),
+  countdownSeconds: Schema.optionalKey(Schema.Finite.pipe(between(0, 60))),
+  maxDurationSeconds: Schema.optionalKey(PositiveNumber),
+  stopAt: Schema.optionalKey(IsoDateTime),
 }).annotate({ identifier: "RecordingStartPayload" });
 
+export const recordingAddMarkerPayloadSchema = Schema.Struct({
+  label: Schema.optionalKey(NonEmptyString.check(Schema.isMaxLength(120))),
+  color: Schema.optionalKey(hexColorSchema),
+}).annotate({ identifier: "RecordingAddMarkerPayload" });
+
 export const exportRunPayloadSchema = Schema.Struct({
   outputURL: outputUrlSchema,
   presetId: exportPresetIdSchema,
@@ -94,6 +153,11 @@ export const exportRunPayloadSchema = Schema.Struct({
   timeline: Schema.optionalKey(timelineDocumentSchema),
   autoZoom: Schema.optionalKey(autoZoomSettingsSchema),
   backgroundFraming: Schema.optionalKey(backgroundFramingSettingsSchema),
+  shortcutOverlay: Schema.optionalKey(shortcutOverlaySettingsSchema),
+  pictureInPicture: Schema.optionalKey(pictureInPictureSettingsSchema),
+  cursorRendering: Schema.optionalKey(cursorRenderingSettingsSchema),
+  clickEffects: Schema.optionalKey(clickEffectsSettingsSchema),
+  reframe: Schema.optionalKey(reframeSettingsSchema),
 }).annotate({ identifier: "ExportRunPayload" });
 
 export const exportRunCutPlanPayloadSchema = Schema.Struct({
@@ -102,6 +166,14 @@ export const exportRunCutPlanPayloadSchema = Schema.Struct({
   jobId: agentJobIdSchema,
 }).annotate({ identifier: "ExportRunCutPlanPayload" });
 
+export const exportGuidePayloadSchema = Schema.Struct({
+  outputURL: outputUrlSchema,
+  title: Schema.optionalKey(NonEmptyString.check(Schema.isMaxLength(200))),
+  importedTranscriptPath: Schema.optionalKey(projectPathSchema),
+  zoom: Schema.optionalKey(Schema.Finite.pipe(between(1, 4))),
+  highlightColor: Schema.optionalKey(hexColorSchema),
+}).annotate({ identifier: "ExportGuidePayload" });
+
 export const projectOpenPayloadSchema = Schema.Struct({
   projectPath: projectPathSchema,
 }).annotate({ identifier: "ProjectOpenPayload" });
@@ -110,9 +182,51 @@ export const projectSavePayloadSchema = Schema.Struct({
   projectPath: Schema.optionalKey(projectPathSchema),
   autoZoom: Schema.optionalKey(autoZoomSettingsSchema),
   backgroundFraming: Schema.optionalKey(backgroundFramingSettingsSchema),
+  shortcutOverlay: Schema.optionalKey(shortcutOverlaySettingsSchema),
+  pictureInPicture: Schema.optionalKey(pictureInPictureSettingsSchema),
+  cursorRendering: Schema.optionalKey(cursorRenderingSettingsSchema),
+  clickEffects: Schema.optionalKey(clickEffectsSettingsSchema),
+  reframe: Schema.optionalKey(reframeSettingsSchema),
+  windowExclusions: Schema.optionalKey(windowExclusionSettingsSchema),
   timeline: Schema.optionalKey(timelineDocumentSchema),
 }).annotate({ identifier: "ProjectSavePayload" });
 
+export const takesRenamePayloadSchema = Schema.Struct({
+  name: NonEmptyString.check(Schema.isMaxLength(120)),
+}).annotate({ identifier: "TakesRenamePayload" });
+
+export const takesDeletePayloadSchema = Schema.Struct({
+  destructiveIntent: Schema.optionalKey(Schema.Boolean),
+}).annotate({ identifier: "TakesDeletePayload" });
+
+export const cameraKeyframesEditPayloadSchema = Schema.Struct({
+  startSeconds: NonNegativeNumber,
+  endSeconds: NonNegativeNumber,
+  edits: Schema.Array(cameraKeyframeEditSchema),
+}).annotate({ identifier: "CameraKeyframesEditPayload" });
+
+export const timelineSuggestCutsPayloadSchema = Schema.Struct({
+  minIdleSeconds: Schema.optionalKey(Schema.Finite.pipe(between(0.5, 600))),
+  stillnessThreshold: Schema.optionalKey(Schema.Finite.pipe(between(0, 1))),
+  paddingSeconds: Schema.optionalKey(Schema.Finite.pipe(between(0, 5))),
+  operation: Schema.optionalKey(cutOperationSchema),
+  playbackRate: Schema.optionalKey(Schema.Finite.pipe(between(1, 16))),
+}).annotate({ identifier: "TimelineSuggestCutsPayload" });
+
+export const timelineApplyCutsPayloadSchema = Schema.Struct({
+  suggestionIds: Schema.Array(NonEmptyString),
+  operation: Schema.optionalKey(cutOperationSchema),
+}).annotate({ identifier: "TimelineApplyCutsPayload" });
+
+export const analysisVisualActivityPayloadSchema = Schema.Struct({
+  sceneThreshold: Schema.optionalKey(Schema.Finite.pipe(between(0.001, 1))),
+  minSceneSeconds: Schema.optionalKey(Schema.Finite.pipe(between(0.1, 600))),
+}).annotate({ identifier: "AnalysisVisualActivityPayload" });
+
+export const librarySaveDefaultsPayloadSchema = Schema.Struct({
+  windowExclusions: Schema.optionalKey(windowExclusionSettingsSchema),
+}).annotate({ identifier: "LibrarySaveDefaultsPayload" });
+
 const SystemGroup = HttpApiGroup.make("system").add(
   HttpApiEndpoint.get("systemPing", "/v1/system/ping", {
     success: pingResultSchema,
@@ -218,6 +332,20 @@ const CaptureGroup = HttpApiGroup.make("capture").add(
     success: capturePreviewFrameResultSchema,
     error: EngineCommonErrors,
   }),
+  HttpApiEndpoint.post("captureStartRegion", "/v1/capture/start-region", {
+    payload: captureStartRegionPayloadSchema,
+    success: captureStatusResultSchema,
+    error: EngineMutationErrors,
+  }),
+  HttpApiEndpoint.post("captureStartCamera", "/v1/capture/start-camera", {
+    payload: captureStartCameraPayloadSchema,
+    success: captureStatusResultSchema,
+    error: EngineMutationErrors,
+  }),
+  HttpApiEndpoint.post("captureStopCamera", "/v1/capture/stop-camera", {
+    success: captureStatusResultSchema,
+    error: EngineMutationErrors,
+  }),
 );
 
 const RecordingGroup = HttpApiGroup.make("recording").add(
@@ -230,6 +358,23 @@ const RecordingGroup = HttpApiGroup.make("recording").add(
     success: captureStatusResultSchema,
     error: EngineMutationErrors,
   }),
+  HttpApiEndpoint.post("recordingPause", "/v1/recording/pause", {
+    success: captureStatusResultSchema,
+    error: EngineMutationErrors,
+  }),
+  HttpApiEndpoint.post("recordingResume", "/v1/recording/resume", {
+    success: captureStatusResultSchema,
+    error: EngineMutationErrors,
+  }),
+  HttpApiEndpoint.post("recordingAddMarker", "/v1/recording/markers", {
+    payload: recordingAddMarkerPayloadSchema,
+    success: recordingAddMarkerResultSchema,
+    error: EngineMutationErrors,
+  }),
+  HttpApiEndpoint.post("recordingRecover", "/v1/recording/recover", {
+    success: recordingRecoverResultSchema,
+    error: EngineMutationErrors,
+  }),
 );
 
 const ExportGroup = HttpApiGroup.make("export").add(
@@ -252,6 +397,11 @@ const ExportGroup = HttpApiGroup.make("export").add(
     success: exportRunResultSchema,
     error: [...EngineCommonErrors, EngineNotFoundError],
   }),
+  HttpApiEndpoint.post("exportGuide", "/v1/exports/guide", {
+    payload: exportGuidePayloadSchema,
+    success: exportGuideResultSchema,
+    error: EngineMutationErrors,
+  }),
 );
 
 const ProjectGroup = HttpApiGroup.make("project").add(
@@ -276,6 +426,83 @@ const ProjectGroup = HttpApiGroup.make("project").add(
   }),
 );
 
+const TakesGroup = HttpApiGroup.make("takes").add(
+  HttpApiEndpoint.get("takesList", "/v1/takes", {
+    success: takesResultSchema,
+    error: EngineCommonErrors,
+  }),
+  HttpApiEndpoint.post("takesRename", "/v1/takes/:takeId/rename", {
+    params: { takeId: takeIdSchema },
+    payload: takesRenamePayloadSchema,
+    success: takesResultSchema,
+    error: [...EngineMutationErrors, EngineNotFoundError],
+  }),
+  HttpApiEndpoint.post("takesDelete", "/v1/takes/:takeId/delete", {
+    params: { takeId: takeIdSchema },
+    payload: takesDeletePayloadSchema,
+    success: takesResultSchema,
+    error: [...EngineMutationErrors, EngineNotFoundError],
+  }),
+  HttpApiEndpoint.post("takesActivate", "/v1/takes/:takeId/activate", {
+    params: { takeId: takeIdSchema },
+    success: takesResultSchema,
+    error: [...EngineMutationErrors, EngineNotFoundError],
+  }),
+);
+
+const CameraKeyframesGroup = HttpApiGroup.make("cameraKeyframes").add(
+  HttpApiEndpoint.get("cameraKeyframesList", "/v1/camera-keyframes", {
+    query: {
+      startSeconds: Schema.optionalKey(NonNegativeNumber),
+      endSeconds: Schema.optionalKey(NonNegativeNumber),
+    },
+    success: cameraKeyframesResultSchema,
+    error: EngineCommonErrors,
+  }),
+  HttpApiEndpoint.post("cameraKeyframesEdit", "/v1/camera-keyframes/edit", {
+    payload: cameraKeyframesEditPayloadSchema,
+    success: cameraKeyframesResultSchema,
+    error: EngineMutationErrors,
+  }),
+  HttpApiEndpoint.post("cameraKeyframesReplan", "/v1/camera-keyframes/replan", {
+    success: cameraKeyframesResultSchema,
+    error: EngineMutationErrors,
+  }),
+);
+
+const TimelineGroup = HttpApiGroup.make("timeline").add(
+  HttpApiEndpoint.post("timelineSuggestCuts", "/v1/timeline/suggest-cuts", {
+    payload: timelineSuggestCutsPayloadSchema,
+    success: timelineSuggestCutsResultSchema,
+    error: EngineMutationErrors,
+  }),
+  HttpApiEndpoint.post("timelineApplyCuts", "/v1/timeline/apply-cuts", {
+    payload: timelineApplyCutsPayloadSchema,
+    success: timelineApplyCutsResultSchema,
+    error: EngineMutationErrors,
+  }),
+);
+
+const AnalysisGroup = HttpApiGroup.make("analysis").add(
+  HttpApiEndpoint.post("analysisVisualActivity", "/v1/analysis/visual-activity", {
+    payload: analysisVisualActivityPayloadSchema,
+    success: visualActivityResultSchema,
+    error: EngineMutationErrors,
+  }),
+);
+
+const LibraryGroup = HttpApiGroup.make("library").add(
+  HttpApiEndpoint.get("libraryDefaults", "/v1/library/defaults", {
+    success: libraryDefaultsResultSchema,
+    error: EngineCommonErrors,
+  }),
+  HttpApiEndpoint.post("librarySaveDefaults", "/v1/library/defaults/save", {
+    payload: librarySaveDefaultsPayloadSchema,
+    success: libraryDefaultsResultSchema,
+    error: EngineMutationErrors,
+  }),
+);
+
 /**
  * Complete Effect HttpApi contract for the native engine v2 HTTP surface.
  *
@@ -294,5 +521,10 @@ export const EngineHttpApi = HttpApi.make("EngineHttpApi")
     RecordingGroup,
     ExportGroup,
     ProjectGroup,
+    TakesGroup,
+    CameraKeyframesGroup,
+    TimelineGroup,
+    AnalysisGroup,
+    LibraryGroup,
   )
   .middleware(EngineAuthMiddleware);
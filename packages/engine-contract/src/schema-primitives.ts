@@ -41,6 +41,11 @@ export const exportPresetIdSchema = NonEmptyString.pipe(Schema.brand("ExportPres
  */
 export const timelineSegmentIdSchema = NonEmptyString.pipe(Schema.brand("TimelineSegmentId"));
 
+/**
+ * Stable identifier for a recorded take within a project.
+ */
+export const takeIdSchema = NonEmptyString.pipe(Schema.brand("TakeId"));
+
 /**
  * Opaque identifier for an asynchronous Agent Mode job.
  */
@@ -117,6 +122,8 @@ export type EventsUrl = typeof eventsUrlSchema.Type;
 export type ExportPresetId = typeof exportPresetIdSchema.Type;
 /** Branded timeline segment identifier. */
 export type TimelineSegmentId = typeof timelineSegmentIdSchema.Type;
+/** Branded take identifier. */
+export type TakeId = typeof takeIdSchema.Type;
 /** Branded Agent Mode job identifier. */
 export type AgentJobId = typeof agentJobIdSchema.Type;
 /** Branded Agent Mode preflight token. */
@@ -1,6 +1,13 @@
 import { Schema, SchemaTransformation } from "effect";
-import { between, IsoDateTime, NonNegativeNumber, PositiveNumber } from "./helpers";
-import { timelineSegmentIdSchema, windowIdSchema } from "../schema-primitives";
+import {
+  between,
+  IsoDateTime,
+  NonEmptyString,
+  NonNegativeNumber,
+  PositiveInt,
+  PositiveNumber,
+} from "./helpers";
+import { recordingUrlSchema, timelineSegmentIdSchema, windowIdSchema } from "../schema-primitives";
 
 const captureWindowSchema = Schema.Struct({
   id: windowIdSchema,
@@ -20,7 +27,7 @@ const captureContentRectSchema = Schema.Struct({
  */
 export const captureMetadataSchema = Schema.Struct({
   window: Schema.optionalKey(captureWindowSchema),
-  source: Schema.Literals(["display", "window"]),
+  source: Schema.Literals(["display", "window", "region"]),
   contentRect: captureContentRectSchema,
   pixelScale: PositiveNumber,
   fps: Schema.optionalKey(PositiveNumber),
@@ -75,6 +82,262 @@ export const autoZoomSettingsSchema = Schema.Struct({
     "User-configurable automatic zoom settings stored with a project or export override.",
 });
 
+/**
+ * Color written as `#RRGGBB`.
+ */
+export const hexColorSchema = Schema.String.check(Schema.isPattern(/^#[0-9a-fA-F]{6}$/u));
+
+/**
+ * Default keyboard-shortcut overlay settings.
+ */
+export const defaultShortcutOverlaySettings = {
+  version: 1,
+  enabled: true,
+  displayDurationSeconds: 1.5,
+  position: "bottomCenter",
+  backgroundColor: "#18181B",
+  textColor: "#FAFAFA",
+  backgroundOpacity: 0.85,
+  fontScale: 1,
+} as const;
+
+/**
+ * Styling for the keyboard-shortcut badges drawn over exported video.
+ */
+export const shortcutOverlaySettingsSchema = Schema.Struct({
+  version: Schema.Literal(1),
+  enabled: Schema.Boolean,
+  displayDurationSeconds: Schema.Finite.pipe(between(0.25, 10)),
+  position: Schema.Literals([
+    "topLeft",
+    "topCenter",
+    "topRight",
+    "bottomLeft",
+    "bottomCenter",
+    "bottomRight",
+  ]),
+  backgroundColor: hexColorSchema,
+  textColor: hexColorSchema,
+  backgroundOpacity: Schema.Finite.pipe(between(0, 1)),
+  fontScale: Schema.Finite.pipe(between(0.5, 3)),
+}).annotate({
+  identifier: "ShortcutOverlaySettings",
+  description: "Styling for the keyboard-shortcut badges drawn over exported video.",
+});
+
+/**
+ * Default camera picture-in-picture settings.
+ */
+export const defaultPictureInPictureSettings = {
+  version: 1,
+  enabled: true,
+  corner: "bottomRight",
+  sizeFraction: 0.22,
+  marginFraction: 0.025,
+  shape: "roundedRectangle",
+  borderWidthFraction: 0.002,
+  borderColor: "#FFFFFF",
+} as const;
+
+/**
+ * Where and how the camera track is composited over the screen recording on export.
+ * Fractions are relative to the export width.
+ */
+export const pictureInPictureSettingsSchema = Schema.Struct({
+  version: Schema.Literal(1),
+  enabled: Schema.Boolean,
+  corner: Schema.Literals(["topLeft", "topRight", "bottomLeft", "bottomRight"]),
+  sizeFraction: Schema.Finite.pipe(between(0.1, 0.5)),
+  marginFraction: Schema.Finite.pipe(between(0, 0.1)),
+  shape: Schema.Literals(["rectangle", "roundedRectangle", "circle"]),
+  borderWidthFraction: Schema.Finite.pipe(between(0, 0.02)),
+  borderColor: hexColorSchema,
+}).annotate({
+  identifier: "PictureInPictureSettings",
+  description: "Where and how the camera track is composited over the screen recording on export.",
+});
+
+/**
+ * Default cursor rendering settings. Disabled keeps the captured cursor.
+ */
+export const defaultCursorRenderingSettings = {
+  version: 1,
+  enabled: false,
+  smoothing: 0.5,
+  scale: 1,
+  hideAfterIdleSeconds: 3,
+} as const;
+
+/**
+ * How export redraws the cursor from the recorded input in place of the captured one.
+ * `hideAfterIdleSeconds` of 0 keeps the cursor visible.
+ */
+export const cursorRenderingSettingsSchema = Schema.Struct({
+  version: Schema.Literal(1),
+  enabled: Schema.Boolean,
+  smoothing: Schema.Finite.pipe(between(0, 1)),
+  scale: Schema.Finite.pipe(between(0.5, 4)),
+  hideAfterIdleSeconds: Schema.Finite.pipe(between(0, 60)),
+}).annotate({
+  identifier: "CursorRenderingSettings",
+  description:
+    "How export redraws the cursor from the recorded input in place of the captured one.",
+});
+
+/**
+ * Default click effect settings. Every effect starts disabled.
+ */
+export const defaultClickEffectsSettings = {
+  version: 1,
+  ripple: { enabled: false, color: "#FACC15", maxRadius: 36, durationSeconds: 0.4 },
+  spotlight: { enabled: false, radius: 180, feather: 60, dimOpacity: 0.55 },
+  clickSound: { enabled: false, volume: 0.6 },
+  segments: [],
+} as const;
+
+/**
+ * Turns click effects on or off for one timeline clip; unset toggles follow the project settings.
+ */
+export const clickEffectSegmentSchema = Schema.Struct({
+  clipId: timelineSegmentIdSchema,
+  ripple: Schema.optionalKey(Schema.Boolean),
+  spotlight: Schema.optionalKey(Schema.Boolean),
+  clickSound: Schema.optionalKey(Schema.Boolean),
+}).annotate({ identifier: "ClickEffectSegment" });
+
+/**
+ * Effects synthesized from recorded clicks and pointer movement. Radii are in source pixels.
+ */
+export const clickEffectsSettingsSchema = Schema.Struct({
+  version: Schema.Literal(1),
+  ripple: Schema.Struct({
+    enabled: Schema.Boolean,
+    color: hexColorSchema,
+    maxRadius: Schema.Finite.pipe(between(8, 200)),
+    durationSeconds: Schema.Finite.pipe(between(0.1, 2)),
+  }),
+  spotlight: Schema.Struct({
+    enabled: Schema.Boolean,
+    radius: Schema.Finite.pipe(between(20, 1000)),
+    feather: Schema.Finite.pipe(between(0, 400)),
+    dimOpacity: Schema.Finite.pipe(between(0, 1)),
+  }),
+  clickSound: Schema.Struct({
+    enabled: Schema.Boolean,
+    volume: Schema.Finite.pipe(between(0, 1)),
+  }),
+  segments: Schema.optionalKey(Schema.Array(clickEffectSegmentSchema)),
+}).annotate({
+  identifier: "ClickEffectsSettings",
+  description: "Effects synthesized from recorded clicks and pointer movement.",
+});
+
+/**
+ * Default reframing settings for presets narrower than the recording.
+ */
+export const defaultReframeSettings = {
+  version: 1,
+  enabled: true,
+  smoothing: 0.5,
+  safeMarginFraction: 0.15,
+} as const;
+
+/**
+ * How presets narrower than the recording pan their window across it to follow the cursor.
+ */
+export const reframeSettingsSchema = Schema.Struct({
+  version: Schema.Literal(1),
+  enabled: Schema.Boolean,
+  smoothing: Schema.Finite.pipe(between(0, 1)),
+  safeMarginFraction: Schema.Finite.pipe(between(0, 0.25)),
+}).annotate({
+  identifier: "ReframeSettings",
+  description:
+    "How presets narrower than the recording pan their window across it to follow the cursor.",
+});
+
+/**
+ * Picks windows to hide from display and region capture; every field that is set has to match.
+ * `titlePattern` is a case-insensitive glob and `wmClass` matches either half of `WM_CLASS`.
+ */
+export const windowExclusionRuleSchema = Schema.Struct({
+  windowId: Schema.optionalKey(windowIdSchema),
+  titlePattern: Schema.optionalKey(NonEmptyString),
+  wmClass: Schema.optionalKey(NonEmptyString),
+}).annotate({ identifier: "WindowExclusionRule" });
+
+/**
+ * Default window exclusions: nothing is masked.
+ */
+export const defaultWindowExclusionSettings = {
+  version: 1,
+  rules: [],
+  maskStyle: "solidFill",
+  fillColor: "#000000",
+} as const;
+
+/**
+ * Windows masked out of captured frames, stored per project and as library defaults.
+ */
+export const windowExclusionSettingsSchema = Schema.Struct({
+  version: Schema.Literal(1),
+  rules: Schema.Array(windowExclusionRuleSchema).check(Schema.isMaxLength(64)),
+  maskStyle: Schema.Literals(["solidFill", "blur"]),
+  fillColor: hexColorSchema,
+}).annotate({ identifier: "WindowExclusionSettings" });
+
+/**
+ * Span removed from a recording by pause/resume, positioned in the gapless output.
+ */
+export const recordingPauseRangeSchema = Schema.Struct({
+  atSeconds: NonNegativeNumber,
+  durationSeconds: NonNegativeNumber,
+}).annotate({ identifier: "RecordingPauseRange" });
+
+/**
+ * Point of interest dropped while recording.
+ */
+export const recordingMarkerSchema = Schema.Struct({
+  id: NonEmptyString,
+  atSeconds: NonNegativeNumber,
+  label: Schema.optionalKey(NonEmptyString),
+  color: hexColorSchema,
+}).annotate({ identifier: "RecordingMarker" });
+
+/**
+ * Editor-facing facts about a recording: pauses, markers and whether it was recovered.
+ */
+export const recordingMetadataSchema = Schema.Struct({
+  pauses: Schema.Array(recordingPauseRangeSchema),
+  markers: Schema.Array(recordingMarkerSchema),
+  recovered: Schema.Boolean,
+}).annotate({ identifier: "RecordingMetadata" });
+
+/**
+ * Which input an audio track records.
+ */
+export const audioTrackKindSchema = Schema.Literals(["microphone", "systemAudio"]);
+
+/**
+ * Audio track recorded next to the screen recording.
+ */
+export const audioTrackSchema = Schema.Struct({
+  kind: audioTrackKindSchema,
+  recordingURL: recordingUrlSchema,
+  deviceId: Schema.optionalKey(NonEmptyString),
+  sampleRate: PositiveInt,
+  channels: PositiveInt,
+}).annotate({ identifier: "AudioTrack" });
+
+/**
+ * Camera recording that accompanies a take's screen recording.
+ */
+export const cameraTrackSchema = Schema.Struct({
+  recordingURL: recordingUrlSchema,
+  width: PositiveInt,
+  height: PositiveInt,
+}).annotate({ identifier: "CameraTrack" });
+
 /**
  * Input event payload captured during recording.
  */
@@ -105,6 +368,10 @@ export const timelineClipItemSchema = Schema.Struct({
   sourceAssetId: Schema.Literal("recording"),
   sourceStartSeconds: NonNegativeNumber,
   sourceEndSeconds: NonNegativeNumber,
+  playbackRate: Schema.optionalKey(Schema.Finite.pipe(between(0.25, 16))),
+  rampInSeconds: Schema.optionalKey(NonNegativeNumber),
+  rampOutSeconds: Schema.optionalKey(NonNegativeNumber),
+  speedAudio: Schema.optionalKey(Schema.Literals(["preservePitch", "mute"])),
 });
 
 /**
@@ -152,6 +419,46 @@ export type BackgroundFramingSettings = Schema.Schema.Type<typeof backgroundFram
  */
 export type AutoZoomSettings = Schema.Schema.Type<typeof autoZoomSettingsSchema>;
 
+/**
+ * Runtime TypeScript type for keyboard-shortcut overlay settings.
+ */
+export type ShortcutOverlaySettings = Schema.Schema.Type<typeof shortcutOverlaySettingsSchema>;
+
+/**
+ * Runtime TypeScript type for camera picture-in-picture settings.
+ */
+export type PictureInPictureSettings = Schema.Schema.Type<typeof pictureInPictureSettingsSchema>;
+
+/**
+ * Runtime TypeScript type for cursor rendering settings.
+ */
+export type CursorRenderingSettings = Schema.Schema.Type<typeof cursorRenderingSettingsSchema>;
+
+/**
+ * Runtime TypeScript type for a per-clip click effect override.
+ */
+export type ClickEffectSegment = Schema.Schema.Type<typeof clickEffectSegmentSchema>;
+
+/**
+ * Runtime TypeScript type for click effect settings.
+ */
+export type ClickEffectsSettings = Schema.Schema.Type<typeof clickEffectsSettingsSchema>;
+
+/**
+ * Runtime TypeScript type for reframing settings.
+ */
+export type ReframeSettings = Schema.Schema.Type<typeof reframeSettingsSchema>;
+
+/**
+ * Runtime TypeScript type for a window exclusion rule.
+ */
+export type WindowExclusionRule = Schema.Schema.Type<typeof windowExclusionRuleSchema>;
+
+/**
+ * Runtime TypeScript type for window exclusion settings.
+ */
+export type WindowExclusionSettings = Schema.Schema.Type<typeof windowExclusionSettingsSchema>;
+
 /**
  * Runtime TypeScript type for an input event.
  */